@@ -0,0 +1,137 @@
+//! Benchmarks of the code which runs around the winterfell prover: the helper functions which
+//! compute the results of the examples, building the execution trace of every example, and
+//! serialization round-trips of proofs, proof files, and bundles.
+//!
+//! Proving itself is measured by the `bench` command. Traces are built with the settings of the
+//! files in `configs/`, so that they match the sizes which are proven there.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use stark_examples::{
+    bundle::{BundleEntry, ProofBundle},
+    config::{ProofOptionsConfig, RunConfig, VdfConfig},
+    example::{self, Example},
+    fib,
+    proof_file::ProofFile,
+    rescue,
+    vdf::{self, VdfExample},
+    with_example, ExampleCommand, EXAMPLES,
+};
+use std::path::Path;
+use winterfell::{
+    math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions, StarkProof,
+};
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn helper_functions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("helpers");
+    group.bench_function("compute_fib_term(8190)", |b| {
+        b.iter(|| fib::compute_fib_term::<BaseElement>(black_box(8190)))
+    });
+    group.bench_function("vdf(1024)", |b| {
+        b.iter(|| vdf::vdf(black_box(BaseElement::from(3u8)), 1024))
+    });
+    group.bench_function("rescue hash chain(1024)", |b| {
+        let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+        b.iter(|| rescue::compute_hash_chain(black_box(seed), 1024))
+    });
+    group.finish();
+}
+
+// TRACE BUILDING
+// ================================================================================================
+
+struct BuildTrace<'a, 'b> {
+    group: &'a mut BenchmarkGroup<'b, WallTime>,
+    name: &'a str,
+}
+
+impl ExampleCommand for BuildTrace<'_, '_> {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, _options: ProofOptionsConfig) {
+        self.group
+            .bench_function(self.name, |b| b.iter(|| example.build_trace()));
+    }
+}
+
+fn trace_building(c: &mut Criterion) {
+    let configs = Path::new(env!("CARGO_MANIFEST_DIR")).join("configs");
+    let mut group = c.benchmark_group("build_trace");
+    // the largest traces take about a second to build
+    group.sample_size(10);
+    for name in EXAMPLES {
+        let config = RunConfig::load(&configs.join(format!("{}.toml", name)))
+            .unwrap_or_else(|err| panic!("{}", err));
+        let command = BuildTrace {
+            group: &mut group,
+            name,
+        };
+        with_example(&config, command).unwrap_or_else(|err| panic!("{}", err));
+    }
+    group.finish();
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+fn serialization(c: &mut Criterion) {
+    let example = VdfExample::<BaseElement>::new(&VdfConfig {
+        trace_length: 1024,
+        ..VdfConfig::default()
+    })
+    .unwrap();
+    let options = ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    );
+    let report = example::run(&example, options);
+    let proof_bytes = report.proof_bytes.clone();
+    let file_bytes = ProofFile::new("vdf", proof_bytes.clone())
+        .unwrap()
+        .to_bytes();
+    let entry = BundleEntry {
+        example: "vdf".to_string(),
+        proof_bytes: proof_bytes.clone(),
+        pub_inputs_bytes: report.pub_inputs_bytes.clone(),
+    };
+    let bundle_bytes = ProofBundle {
+        entries: vec![entry; 16],
+    }
+    .to_bytes();
+
+    let mut group = c.benchmark_group("serialization");
+    group.bench_function("proof", |b| {
+        b.iter(|| {
+            StarkProof::from_bytes(black_box(&proof_bytes))
+                .unwrap()
+                .to_bytes()
+        })
+    });
+    group.bench_function("proof file", |b| {
+        b.iter(|| {
+            ProofFile::from_bytes(black_box(&file_bytes))
+                .unwrap()
+                .to_bytes()
+        })
+    });
+    group.bench_function("bundle of 16 proofs", |b| {
+        b.iter(|| {
+            ProofBundle::from_bytes(black_box(&bundle_bytes))
+                .unwrap()
+                .to_bytes()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, helper_functions, trace_building, serialization);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+use std::{env, fs, path::Path, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    emit_build_info();
+
+    // the gRPC service is generated from its schema only when the `grpc` feature is enabled, so
+    // that other builds need neither the code generator nor a protobuf compiler
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/stark_examples.proto");
+        let descriptors = protox::compile(["proto/stark_examples.proto"], ["proto"])
+            .expect("failed to compile the protobuf schema");
+        // the generated client relies on the 2021 prelude, and clients are written in other
+        // languages anyway
+        tonic_build::configure()
+            .build_client(false)
+            .compile_fds(descriptors)
+            .expect("failed to generate the gRPC service");
+    }
+}
+
+/// Passes the facts about the build which `buildinfo` reports to the compiler as environment
+/// variables; any of them which cannot be determined is reported as unknown.
+fn emit_build_info() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    let profile = env::var("PROFILE").unwrap_or_default();
+    let target = env::var("TARGET").unwrap_or_default();
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let rustc_version = env::var("RUSTC")
+        .ok()
+        .and_then(|rustc| Command::new(rustc).arg("--version").output().ok())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_default();
+    let winterfell_version = env::var("CARGO_MANIFEST_DIR")
+        .ok()
+        .and_then(|dir| fs::read_to_string(Path::new(&dir).join("Cargo.lock")).ok())
+        .and_then(|lock| locked_version(&lock, "winterfell"))
+        .unwrap_or_default();
+    let commit = git_commit().unwrap_or_default();
+
+    println!("cargo:rustc-env=STARK_EXAMPLES_PROFILE={}", profile);
+    println!("cargo:rustc-env=STARK_EXAMPLES_TARGET={}", target);
+    println!(
+        "cargo:rustc-env=STARK_EXAMPLES_TARGET_FEATURES={}",
+        target_features
+    );
+    println!(
+        "cargo:rustc-env=STARK_EXAMPLES_RUSTC_VERSION={}",
+        rustc_version
+    );
+    println!(
+        "cargo:rustc-env=STARK_EXAMPLES_WINTERFELL_VERSION={}",
+        winterfell_version
+    );
+    println!("cargo:rustc-env=STARK_EXAMPLES_COMMIT={}", commit);
+}
+
+/// Returns the abbreviated hash of the commit the source tree is checked out at; `None` outside
+/// of a git checkout. Uncommitted changes are not reflected in it.
+fn git_commit() -> Option<String> {
+    // the build script runs again when HEAD moves to another commit or branch
+    let git_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").ok()?).join(".git");
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+    if let Some(head_ref) = head.trim().strip_prefix("ref: ") {
+        println!(
+            "cargo:rerun-if-changed={}",
+            git_dir.join(head_ref).display()
+        );
+    }
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|commit| commit.trim().to_string())
+}
+
+/// Returns the version of the package which the lock file resolves the specified name to.
+fn locked_version(lock: &str, name: &str) -> Option<String> {
+    let entry = format!("name = \"{}\"", name);
+    let mut lines = lock.lines();
+    lines.find(|line| *line == entry)?;
+    let version = lines.next()?.strip_prefix("version = \"")?;
+    Some(version.trim_end_matches('"').to_string())
+}
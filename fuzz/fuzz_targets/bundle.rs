@@ -0,0 +1,9 @@
+//! Parses proof bundles as `verify-bundle` does.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stark_examples::bundle::ProofBundle;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = ProofBundle::from_bytes(data);
+});
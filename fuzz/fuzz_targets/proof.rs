@@ -0,0 +1,14 @@
+//! Parses proofs as `verify` does: the first byte selects the example, and the rest of the input
+//! is the serialized proof. The public inputs are left empty, so a proof which parses is
+//! rejected before the verifier runs.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stark_examples::{verify::verify_proof, EXAMPLES};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((&selector, proof_bytes)) = data.split_first() {
+        let example = EXAMPLES[selector as usize % EXAMPLES.len()];
+        let _ = verify_proof(example, proof_bytes, &[]);
+    }
+});
@@ -0,0 +1,16 @@
+//! Parses public inputs as `verify` does once a proof has been parsed: the first byte selects the
+//! example, the second the base field, and the rest of the input is the serialized public inputs.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stark_examples::{field::FieldType, verify::parse_pub_inputs, EXAMPLES};
+
+const FIELDS: [FieldType; 3] = [FieldType::F128, FieldType::F64, FieldType::F62];
+
+fuzz_target!(|data: &[u8]| {
+    if let [example, field, pub_inputs_bytes @ ..] = data {
+        let example = EXAMPLES[*example as usize % EXAMPLES.len()];
+        let field = FIELDS[*field as usize % FIELDS.len()];
+        let _ = parse_pub_inputs(example, field, pub_inputs_bytes);
+    }
+});
@@ -0,0 +1,21 @@
+//! Parses witness files as `--witness` does: the first byte selects one of the examples which
+//! take a witness, and the rest of the input is the contents of the file.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stark_examples::{
+    config::RunConfig,
+    witness::{apply_witness_source, WITNESS_EXAMPLES},
+};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((&selector, source)) = data.split_first() {
+        if let Ok(source) = std::str::from_utf8(source) {
+            let mut config = RunConfig {
+                example: WITNESS_EXAMPLES[selector as usize % WITNESS_EXAMPLES.len()].to_string(),
+                ..RunConfig::default()
+            };
+            let _ = apply_witness_source(&mut config, source, "input");
+        }
+    }
+});
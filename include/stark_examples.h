@@ -0,0 +1,92 @@
+/*
+ * C interface to the proof verifiers of the STARK examples.
+ *
+ * Link against the `cdylib` built by `cargo build --release --lib`. Proofs and public inputs
+ * use the encodings written by `run --save-proof <file> --save-inputs <file>`. Every function
+ * returns VERIFY_OK if the proof is accepted and one of the other VERIFY_* codes otherwise.
+ */
+
+#ifndef STARK_EXAMPLES_H
+#define STARK_EXAMPLES_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define VERIFY_OK 0
+#define VERIFY_INVALID_ARGUMENT 1
+#define VERIFY_UNKNOWN_EXAMPLE 2
+#define VERIFY_MALFORMED_PROOF 3
+#define VERIFY_UNSUPPORTED_FIELD 4
+#define VERIFY_MALFORMED_PUBLIC_INPUTS 5
+#define VERIFY_REJECTED 6
+#define VERIFY_PANICKED 7
+
+int stark_examples_verify(const char *example, const uint8_t *proof, size_t proof_len,
+                          const uint8_t *pub_inputs, size_t pub_inputs_len);
+
+int verify_vdf_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_rescue_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_poseidon_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                          size_t pub_inputs_len);
+int verify_counter_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                         size_t pub_inputs_len);
+int verify_lamport_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                         size_t pub_inputs_len);
+int verify_exp_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_sum_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_vm_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                    size_t pub_inputs_len);
+int verify_fib_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_gcd_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_quad_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                      size_t pub_inputs_len);
+int verify_iszero_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_rom_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                     size_t pub_inputs_len);
+int verify_sqrt_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                      size_t pub_inputs_len);
+int verify_merkle_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_bitwise_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                         size_t pub_inputs_len);
+int verify_compare_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                         size_t pub_inputs_len);
+int verify_fibmod_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_sort_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                      size_t pub_inputs_len);
+int verify_fibchunk_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                          size_t pub_inputs_len);
+int verify_fixedexp_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                          size_t pub_inputs_len);
+int verify_modexp_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_bytecount_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                           size_t pub_inputs_len);
+int verify_runsum_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_horner_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_wrap_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                      size_t pub_inputs_len);
+int verify_window_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+int verify_fibdiv_proof(const uint8_t *proof, size_t proof_len, const uint8_t *pub_inputs,
+                        size_t pub_inputs_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* STARK_EXAMPLES_H */
@@ -0,0 +1,103 @@
+//! Building blocks shared by the AIRs of the examples: constraint helpers, transition degrees,
+//! and common boundary assertions.
+
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::{FieldElement, StarkField},
+    Assertion, TransitionConstraintDegree,
+};
+
+// CONSTRAINT HELPERS
+// ================================================================================================
+
+/// Returns zero only when `a` and `b` are equal.
+pub fn are_equal<E: FieldElement>(a: E, b: E) -> E {
+    a - b
+}
+
+/// Returns one when `a` is zero and zero when `a` is one.
+pub fn not<E: FieldElement>(a: E) -> E {
+    E::ONE - a
+}
+
+/// Returns zero only when `a` is either zero or one.
+pub fn is_binary<E: FieldElement>(a: E) -> E {
+    a.square() - a
+}
+
+/// Returns one when both binary values `a` and `b` are one, and zero otherwise.
+pub fn and<E: FieldElement>(a: E, b: E) -> E {
+    a * b
+}
+
+/// Returns the integer whose binary decomposition, least significant bit first, is `bits`.
+pub fn combine_bits<E: FieldElement>(bits: &[E]) -> E {
+    bits.iter()
+        .rev()
+        .fold(E::ZERO, |value, &bit| value.double() + bit)
+}
+
+/// Helpers for writing constraint evaluations into the result slice.
+pub trait EvaluationResult<E> {
+    /// Adds `value` to the constraint at `index`; the value takes effect only when `flag` is one.
+    fn agg_constraint(&mut self, index: usize, flag: E, value: E);
+
+    /// Writes a constraint keeping each of `values` binary, starting at `index`.
+    fn enforce_binary(&mut self, index: usize, values: &[E]);
+}
+
+impl<E: FieldElement> EvaluationResult<E> for [E] {
+    fn agg_constraint(&mut self, index: usize, flag: E, value: E) {
+        self[index] += flag * value;
+    }
+
+    fn enforce_binary(&mut self, index: usize, values: &[E]) {
+        for (result, &value) in self[index..index + values.len()].iter_mut().zip(values) {
+            *result = is_binary(value);
+        }
+    }
+}
+
+// TRANSITION DEGREES
+// ================================================================================================
+
+/// Returns the degrees of transition constraints which use no periodic columns.
+pub fn transition_degrees(degrees: &[usize]) -> Vec<TransitionConstraintDegree> {
+    degrees
+        .iter()
+        .map(|&degree| TransitionConstraintDegree::new(degree))
+        .collect()
+}
+
+/// Returns the degrees of transition constraints which each use periodic columns with a cycle
+/// of `cycle_length` steps.
+pub fn cyclic_degrees(degrees: &[usize], cycle_length: usize) -> Vec<TransitionConstraintDegree> {
+    degrees
+        .iter()
+        .map(|&degree| TransitionConstraintDegree::with_cycles(degree, vec![cycle_length]))
+        .collect()
+}
+
+// ASSERTIONS
+// ================================================================================================
+
+/// Returns an assertion that `column` holds `value` in the first row of the trace.
+pub fn first_row_equals<B: StarkField>(column: usize, value: B) -> Assertion<B> {
+    Assertion::single(column, 0, value)
+}
+
+/// Returns an assertion that `column` holds `value` in the last row of a trace of
+/// `trace_length` rows.
+pub fn last_row_equals<B: StarkField>(
+    column: usize,
+    trace_length: usize,
+    value: B,
+) -> Assertion<B> {
+    Assertion::single(column, trace_length - 1, value)
+}
+
+/// Returns an assertion that `column` is reset to `value` at the start of every block of
+/// `period` rows.
+pub fn periodic_reset<B: StarkField>(column: usize, period: usize, value: B) -> Assertion<B> {
+    Assertion::periodic(column, 0, period, value)
+}
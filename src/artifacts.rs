@@ -0,0 +1,353 @@
+//! A directory in which the files produced by runs are collected.
+//!
+//! `run --artifacts` writes the proof, the public inputs, and the report of a run into an
+//! [ArtifactStore] rather than into files named on the command line. Each file is named after
+//! the time it was written, the example, and its kind, such as
+//! `20261015T093012Z-vdf-proof.bin`, which sorts by time and is a valid file name on Windows,
+//! macOS, and Linux alike. An index file in the directory lists every artifact with its example
+//! and kind, one JSON object per line, and is appended to as artifacts are written.
+//!
+//! The store lives in the cache directory of the platform unless another directory is given:
+//! `%LOCALAPPDATA%\stark-examples` on Windows, `~/Library/Caches/stark-examples` on macOS, and
+//! `$XDG_CACHE_HOME/stark-examples` or `~/.cache/stark-examples` elsewhere.
+
+use crate::error::ExampleError;
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fmt,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Name of the directory created inside the cache directory of the platform.
+const STORE_NAME: &str = "stark-examples";
+
+/// Name of the index file of a store.
+pub const INDEX_FILE: &str = "index.jsonl";
+
+// ARTIFACT KIND
+// ================================================================================================
+
+/// The kinds of files kept in an [ArtifactStore].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    /// A proof file, as written by `--save-proof`.
+    Proof,
+    /// Serialized public inputs, as written by `--save-inputs`.
+    #[serde(rename = "inputs")]
+    PublicInputs,
+    /// A run report serialized as JSON.
+    Report,
+}
+
+impl ArtifactKind {
+    /// Returns the part of the file name which names the kind.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Proof => "proof",
+            Self::PublicInputs => "inputs",
+            Self::Report => "report",
+        }
+    }
+
+    /// Returns the extension of files of this kind.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Proof | Self::PublicInputs => "bin",
+            Self::Report => "json",
+        }
+    }
+}
+
+impl fmt::Display for ArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+// ARTIFACT ENTRY
+// ================================================================================================
+
+/// A line of the index of an [ArtifactStore].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactEntry {
+    /// Name of the file, relative to the directory of the store.
+    pub file: String,
+    /// Name of the example the artifact belongs to.
+    pub example: String,
+    pub kind: ArtifactKind,
+    /// Time at which the artifact was written, in seconds since the Unix epoch.
+    pub created: u64,
+}
+
+impl fmt::Display for ArtifactEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<10} {:<8} {}", self.example, self.kind, self.file)
+    }
+}
+
+// ARTIFACT STORE
+// ================================================================================================
+
+/// A directory of artifacts with timestamped names, listed in an index file.
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    dir: PathBuf,
+}
+
+impl ArtifactStore {
+    /// Opens the store in the specified directory, creating the directory if it does not exist.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the directory cannot be created.
+    pub fn open(dir: &Path) -> Result<Self, ExampleError> {
+        fs::create_dir_all(dir).map_err(|err| ExampleError::Io {
+            action: "create",
+            what: "artifacts directory".to_string(),
+            path: dir.to_path_buf(),
+            source: err,
+        })?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    /// Opens the store in the cache directory of the platform.
+    ///
+    /// Returns a [Config](ExampleError::Config) error if the environment does not say where the
+    /// cache directory is, and an [Io](ExampleError::Io) error if the store cannot be created
+    /// there.
+    pub fn open_default() -> Result<Self, ExampleError> {
+        let dir = default_dir().ok_or_else(|| {
+            ExampleError::Config(
+                "cannot locate the cache directory of this platform; pass --artifacts-dir"
+                    .to_string(),
+            )
+        })?;
+        Self::open(&dir)
+    }
+
+    /// Returns the directory of the store.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Writes an artifact of the named example into a new file, and adds it to the index.
+    /// Returns the path of the file.
+    ///
+    /// Artifacts written within the same second get a counter appended to their names, so that
+    /// no artifact overwrites another.
+    ///
+    /// Returns an error if the file or the index cannot be written.
+    pub fn write(
+        &self,
+        example: &str,
+        kind: ArtifactKind,
+        bytes: &[u8],
+    ) -> Result<PathBuf, ExampleError> {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        let stem = format!(
+            "{}-{}-{}",
+            timestamp(created),
+            sanitize(example),
+            kind.name()
+        );
+
+        let mut counter = 1;
+        let (path, mut file) = loop {
+            let name = match counter {
+                1 => format!("{}.{}", stem, kind.extension()),
+                _ => format!("{}-{}.{}", stem, counter, kind.extension()),
+            };
+            let path = self.dir.join(name);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => counter += 1,
+                Err(err) => return Err(ExampleError::write("artifact", path, err)),
+            }
+        };
+        file.write_all(bytes)
+            .map_err(|err| ExampleError::write("artifact", &path, err))?;
+
+        let entry = ArtifactEntry {
+            file: path
+                .file_name()
+                .expect("artifact path ends in a file name")
+                .to_string_lossy()
+                .into_owned(),
+            example: example.to_string(),
+            kind,
+            created,
+        };
+        let index_path = self.dir.join(INDEX_FILE);
+        let line = serde_json::to_string(&entry).expect("entry is serializable");
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&index_path)
+            .and_then(|mut index| writeln!(index, "{}", line))
+            .map_err(|err| ExampleError::write("artifact index", index_path, err))?;
+        Ok(path)
+    }
+
+    /// Returns the entries of the index in the order in which the artifacts were written; a
+    /// store without an index has no entries.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the index cannot be read, and a
+    /// [Config](ExampleError::Config) error if it contains a line which is not an entry.
+    pub fn entries(&self) -> Result<Vec<ArtifactEntry>, ExampleError> {
+        let index_path = self.dir.join(INDEX_FILE);
+        let index = match fs::read_to_string(&index_path) {
+            Ok(index) => index,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(ExampleError::read("artifact index", index_path, err)),
+        };
+        index
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|err| {
+                    ExampleError::Config(format!(
+                        "line {} of artifact index {} is invalid: {}",
+                        i + 1,
+                        index_path.display(),
+                        err
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+// TIMESTAMPS
+// ================================================================================================
+
+/// Formats seconds since the Unix epoch as a UTC time in the basic format of ISO 8601, such as
+/// `20261015T093012Z`, which has no colons and can therefore be part of a Windows file name.
+pub fn timestamp(secs: u64) -> String {
+    let (days, secs_of_day) = (secs / 86400, secs % 86400);
+    // converts days since the epoch into a civil date; see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z / 146097;
+    let day_of_era = z % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the directory of the store inside the cache directory of the platform.
+fn default_dir() -> Option<PathBuf> {
+    let absolute = |var: &str| {
+        env::var_os(var)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+    };
+    let cache_dir = if cfg!(windows) {
+        absolute("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        absolute("HOME").map(|home| home.join("Library").join("Caches"))
+    } else {
+        absolute("XDG_CACHE_HOME").or_else(|| absolute("HOME").map(|home| home.join(".cache")))
+    }?;
+    Some(cache_dir.join(STORE_NAME))
+}
+
+/// Replaces every character which is not an ASCII letter, digit, `-`, or `_` with `_`, so that
+/// the result is safe in a file name on every platform.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{timestamp, ArtifactKind, ArtifactStore};
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn artifacts_are_written_with_timestamped_names_and_indexed() {
+        use std::{env, fs, process};
+
+        assert_eq!("19700101T000000Z", timestamp(0));
+        assert_eq!("20000229T235959Z", timestamp(951868799));
+
+        let dir = env::temp_dir().join(format!("stark-examples-artifacts-{}", process::id()));
+        let store = ArtifactStore::open(&dir).unwrap();
+        assert!(store.entries().unwrap().is_empty());
+        let report = example::run(&vdf_example(), vdf_options());
+        let proof_path = store
+            .write("vdf", ArtifactKind::Proof, &report.proof_bytes)
+            .unwrap();
+        let inputs_path = store
+            .write("vdf", ArtifactKind::PublicInputs, &report.pub_inputs_bytes)
+            .unwrap();
+        // a second proof in the same second does not overwrite the first
+        let second_path = store
+            .write("vdf", ArtifactKind::Proof, &report.proof_bytes)
+            .unwrap();
+        assert_ne!(proof_path, second_path);
+
+        let entries = store.entries().unwrap();
+        let kinds = entries.iter().map(|entry| entry.kind).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ArtifactKind::Proof,
+                ArtifactKind::PublicInputs,
+                ArtifactKind::Proof
+            ],
+            kinds
+        );
+        for (entry, path) in entries
+            .iter()
+            .zip([&proof_path, &inputs_path, &second_path])
+        {
+            assert_eq!("vdf", entry.example);
+            assert_eq!(dir.join(&entry.file), *path);
+            assert!(entry.file.starts_with(&timestamp(entry.created)));
+        }
+        assert!(entries[0].file.ends_with("-vdf-proof.bin"));
+        assert!(entries[1].file.ends_with("-vdf-inputs.bin"));
+        assert_eq!(report.proof_bytes, fs::read(&proof_path).unwrap());
+        assert_eq!(report.pub_inputs_bytes, fs::read(&inputs_path).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1467 @@
+use crate::{
+    config::{ProofOptionsConfig, RunConfig},
+    error::ExampleError,
+    example::Example,
+    field::FieldType,
+    memory, plugins, run_example,
+    security::GRINDING_CONTRIBUTION_FLOOR,
+    tamper,
+    verify::VerifyError,
+};
+use log::info;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use winterfell::{math::log2, ProofOptions, Prover, StarkProof};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Grinding factors explored when none are specified on the command line.
+pub const DEFAULT_GRINDING_FACTORS: [u32; 5] = [0, 8, 16, 20, 24];
+
+/// Number of times the trace length is doubled in a trace length sweep when not specified on
+/// the command line.
+pub const DEFAULT_SWEEP_STEPS: u32 = 4;
+
+/// Number of unmeasured runs before every measured configuration when not specified on the
+/// command line.
+pub const DEFAULT_WARMUP: usize = 1;
+
+/// Number of measured runs of every configuration when not specified on the command line.
+pub const DEFAULT_SAMPLES: usize = 3;
+
+/// Coefficient of variation of the measured runs above which a time is flagged as noisy.
+pub const NOISY_VARIATION: f64 = 0.1;
+
+/// Percentage by which a time, size, or amount of memory may grow between two reports before a
+/// diff flags it as a regression, when not specified on the command line.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 5.0;
+
+// REPETITIONS
+// ================================================================================================
+
+/// How often every configuration of a benchmark is run.
+///
+/// The first proofs of a process run with cold caches, before the CPU has reached a steady
+/// clock speed, and pay for the memory the allocator first requests from the OS, so they are
+/// slower than the ones after them. Warm-up runs absorb this cost; the reported time is the mean
+/// of the measured runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repetitions {
+    /// Number of runs whose times are discarded.
+    pub warmup: usize,
+    /// Number of runs whose times are averaged; must be positive.
+    pub samples: usize,
+}
+
+impl Repetitions {
+    /// A single measured run without warm-up, as benchmarks were run before repetitions existed.
+    pub const SINGLE: Repetitions = Repetitions {
+        warmup: 0,
+        samples: 1,
+    };
+
+    /// Calls `run` for every warm-up run and then for every measured run, and returns the
+    /// results of the measured runs; the first error of any run is returned.
+    fn repeat<T>(
+        &self,
+        mut run: impl FnMut() -> Result<T, ExampleError>,
+    ) -> Result<Vec<T>, ExampleError> {
+        for _ in 0..self.warmup {
+            run()?;
+        }
+        (0..self.samples.max(1)).map(|_| run()).collect()
+    }
+}
+
+impl Default for Repetitions {
+    fn default() -> Self {
+        Repetitions {
+            warmup: DEFAULT_WARMUP,
+            samples: DEFAULT_SAMPLES,
+        }
+    }
+}
+
+/// Mean and spread of the times of the measured runs of a configuration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    /// Mean of the times.
+    pub mean: Duration,
+    /// Standard deviation of the times relative to their mean; zero for a single run.
+    pub variation: f64,
+}
+
+impl Timing {
+    /// Returns the timing of the runs which took `times`.
+    ///
+    /// # Panics
+    /// Panics if `times` is empty.
+    pub fn of(times: &[Duration]) -> Self {
+        assert!(!times.is_empty(), "no runs were measured");
+        let seconds = times.iter().map(Duration::as_secs_f64).collect::<Vec<_>>();
+        let mean = seconds.iter().sum::<f64>() / seconds.len() as f64;
+        let variance = seconds
+            .iter()
+            .map(|time| (time - mean) * (time - mean))
+            .sum::<f64>()
+            / seconds.len() as f64;
+        Timing {
+            mean: Duration::from_secs_f64(mean),
+            variation: if mean > 0f64 {
+                variance.sqrt() / mean
+            } else {
+                0f64
+            },
+        }
+    }
+
+    /// Returns true if the times vary by more than [NOISY_VARIATION] around their mean, so that
+    /// the mean may not be reproduced by another benchmark run.
+    pub fn is_noisy(&self) -> bool {
+        self.variation > NOISY_VARIATION
+    }
+}
+
+/// Restricts the benchmark to the CPU cores with the given indices.
+///
+/// Pinning keeps the OS from moving the prover between cores with different caches or clock
+/// speeds while it is measured. The affinity is set on the calling thread and inherited by the
+/// threads it starts later, so this must be called before the first proof creates the global
+/// thread pool.
+///
+/// Returns an error if a core does not exist, or if the platform is not Linux.
+#[cfg(target_os = "linux")]
+pub fn pin_to_cores(cores: &[usize]) -> Result<(), String> {
+    if cores.is_empty() {
+        return Err("no cores to pin the benchmark to".to_string());
+    }
+    let mut set = unsafe { std::mem::zeroed::<libc::cpu_set_t>() };
+    for &core in cores {
+        if core >= libc::CPU_SETSIZE as usize {
+            return Err(format!("core {} does not exist", core));
+        }
+        unsafe { libc::CPU_SET(core, &mut set) };
+    }
+    // a pid of zero selects the calling thread
+    if unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) } != 0 {
+        return Err(format!(
+            "failed to pin the benchmark to cores {:?}: {}",
+            cores,
+            std::io::Error::last_os_error()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_to_cores(_cores: &[usize]) -> Result<(), String> {
+    Err("pinning the benchmark to cores is only supported on Linux".to_string())
+}
+
+// GRINDING SWEEP
+// ================================================================================================
+
+/// Proves the example `repetitions` times for every grinding factor and prints how prover time,
+/// proof size, and conjectured security change.
+///
+/// Grinding makes the prover search for a nonce such that hashing it together with the query
+/// seed yields a value with `grinding_factor` leading zeros. This costs the prover roughly
+/// 2^grinding_factor hash invocations, costs the verifier a single hash, and does not change
+/// the size of the proof.
+pub fn grinding_sweep<E: Example>(
+    example: &E,
+    options: &ProofOptionsConfig,
+    grinding_factors: &[u32],
+    repetitions: Repetitions,
+) {
+    let base_options = options.to_proof_options();
+    let query_security = log2(base_options.blowup_factor()) * base_options.num_queries() as u32;
+
+    println!(
+        "Grinding sweep with {} queries at blowup factor {} ({} bits of query security), {}",
+        base_options.num_queries(),
+        base_options.blowup_factor(),
+        query_security,
+        describe_fri_options(&base_options)
+    );
+    println!(
+        "{:>8} | {:>16} | {:>16} | {:>15} | {:>15} | {:>16}",
+        "grinding",
+        "prover time (ms)",
+        "extra time (ms)",
+        "proof size (KB)",
+        "security (bits)",
+        "peak memory (MB)"
+    );
+
+    let mut baseline_ms = None;
+    let mut noisy = false;
+    for &grinding_factor in grinding_factors {
+        let options = ProofOptionsConfig {
+            grinding_factor: Some(grinding_factor),
+            ..options.clone()
+        }
+        .to_proof_options();
+        let prover = example.prover(options);
+
+        let runs = repetitions
+            .repeat(|| {
+                let trace = example.build_trace();
+                let now = Instant::now();
+                let (proof, peak_memory) = memory::measure(|| prover.prove(trace));
+                Ok((proof.unwrap(), peak_memory, now.elapsed()))
+            })
+            .expect("proving does not return errors");
+        let timing = Timing::of(&runs.iter().map(|run| run.2).collect::<Vec<_>>());
+        let (proof, peak_memory, _) = runs.last().expect("at least one run is measured");
+        let prover_ms = timing.mean.as_millis();
+        noisy |= timing.is_noisy();
+
+        // time spent on grinding is approximated by the difference with the first run
+        let baseline = *baseline_ms.get_or_insert(prover_ms);
+        println!(
+            "{:>8} | {:>16} | {:>16} | {:>15.1} | {:>15} | {:>16}",
+            grinding_factor,
+            format_time(&timing, 0),
+            prover_ms as i128 - baseline as i128,
+            proof.to_bytes().len() as f64 / 1024f64,
+            proof.security_level(true),
+            format_memory(*peak_memory)
+        );
+    }
+    print_noise_note(noisy, repetitions);
+
+    if query_security < GRINDING_CONTRIBUTION_FLOOR {
+        println!(
+            "Note: query security is below {} bits, so grinding does not add to the \
+            conjectured security level for these parameters.",
+            GRINDING_CONTRIBUTION_FLOOR
+        );
+    }
+}
+
+// TRACE LENGTH SWEEP
+// ================================================================================================
+
+/// Measurements of a single proof in a trace length sweep.
+#[derive(Debug, Clone)]
+pub struct SweepPoint {
+    /// Name of the proven example.
+    pub example: String,
+    /// Number of steps in the execution trace.
+    pub trace_length: usize,
+    /// Time it took to generate the proof.
+    pub prover_time: Duration,
+    /// Time it took to verify the proof.
+    pub verifier_time: Duration,
+    /// Size of the serialized proof in bytes.
+    pub proof_size: usize,
+    /// Number of FRI layers in the proof.
+    pub fri_layers: usize,
+    /// Peak heap memory of the prover in bytes; `None` unless memory is measured (see
+    /// [memory::measure()]).
+    pub peak_memory: Option<usize>,
+}
+
+/// Proves each of the examples at `steps + 1` sizes, starting from the size set in the config
+/// and doubling it at every step, and prints how prover time and proof size grow with the
+/// trace length; the measurements are returned in the order in which they were taken. Every
+/// size is proven `repetitions` times, and its times are the means of the measured runs.
+///
+/// Returns an error if an example is unknown, if its size cannot be scaled (see
+/// [RunConfig::scaled()]), or if any of the proofs is rejected.
+pub fn trace_length_sweep(
+    config: &RunConfig,
+    examples: &[String],
+    steps: u32,
+    repetitions: Repetitions,
+) -> Result<Vec<SweepPoint>, ExampleError> {
+    let points = Mutex::new(Vec::new());
+    trace_length_sweep_into(config, examples, steps, repetitions, &points)?;
+    Ok(points.into_inner().expect("sweep is complete"))
+}
+
+/// Same as [trace_length_sweep()], but appends each measurement to `points` as soon as it is
+/// taken, so that another thread can report the measurements of a sweep which does not
+/// complete, such as one interrupted with Ctrl-C (see [cancel](crate::cancel)).
+pub fn trace_length_sweep_into(
+    config: &RunConfig,
+    examples: &[String],
+    steps: u32,
+    repetitions: Repetitions,
+    points: &Mutex<Vec<SweepPoint>>,
+) -> Result<(), ExampleError> {
+    let mut noisy = false;
+    for example in examples.iter() {
+        let configs = (0..=steps)
+            .map(|doublings| {
+                RunConfig {
+                    example: example.clone(),
+                    ..config.clone()
+                }
+                .scaled(doublings)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ExampleError::Config)?;
+
+        for (index, config) in configs.iter().enumerate() {
+            let reports = repetitions.repeat(|| {
+                let report = run_example(config)?;
+                match report.verification_error {
+                    Some(err) => Err(VerifyError::Rejected(format!(
+                        "proof of {} with {} steps: {}",
+                        example, report.trace_length, err
+                    ))
+                    .into()),
+                    None => Ok(report),
+                }
+            })?;
+            let prover_timing = Timing::of(
+                &reports
+                    .iter()
+                    .map(|report| report.prover_time)
+                    .collect::<Vec<_>>(),
+            );
+            let verifier_timing = Timing::of(
+                &reports
+                    .iter()
+                    .map(|report| report.verifier_time)
+                    .collect::<Vec<_>>(),
+            );
+            noisy |= prover_timing.is_noisy() || verifier_timing.is_noisy();
+            let report = reports.last().expect("at least one run is measured");
+            // the FRI options of the example are known once it has been proven
+            if index == 0 {
+                println!(
+                    "Trace length sweep of {}, {}",
+                    example,
+                    describe_fri(report.fri_folding_factor, report.fri_max_remainder_size)
+                );
+                println!(
+                    "{:>12} | {:>16} | {:>15} | {:>10} | {:>18} | {:>16}",
+                    "trace length",
+                    "prover time (ms)",
+                    "proof size (KB)",
+                    "FRI layers",
+                    "verifier time (ms)",
+                    "peak memory (MB)"
+                );
+            }
+            info!("Proved {} with {} steps", example, report.trace_length);
+            println!(
+                "{:>12} | {:>16} | {:>15.1} | {:>10} | {:>18} | {:>16}",
+                report.trace_length,
+                format_time(&prover_timing, 0),
+                report.proof_size as f64 / 1024f64,
+                report.fri_layers,
+                format_time(&verifier_timing, 1),
+                format_memory(report.peak_memory)
+            );
+            let point = SweepPoint {
+                example: example.clone(),
+                trace_length: report.trace_length,
+                prover_time: prover_timing.mean,
+                verifier_time: verifier_timing.mean,
+                proof_size: report.proof_size,
+                fri_layers: report.fri_layers,
+                peak_memory: report.peak_memory,
+            };
+            points
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .push(point);
+        }
+    }
+    print_noise_note(noisy, repetitions);
+    Ok(())
+}
+
+// FIELD SWEEP
+// ================================================================================================
+
+/// Measurements of a single proof in a field sweep.
+#[derive(Debug, Clone)]
+pub struct FieldPoint {
+    /// Name of the proven example.
+    pub example: String,
+    /// Base field in which the example was proven.
+    pub field: FieldType,
+    /// Number of steps in the execution trace.
+    pub trace_length: usize,
+    /// Time it took to generate the proof.
+    pub prover_time: Duration,
+    /// Time it took to verify the proof.
+    pub verifier_time: Duration,
+    /// Size of the serialized proof in bytes.
+    pub proof_size: usize,
+}
+
+/// Proves each of the examples `repetitions` times in every one of the fields, with the inputs
+/// and proof options set in the config, and prints how prover time and proof size compare to
+/// those in the first field; the measurements are returned in the order in which they were
+/// taken.
+///
+/// Arithmetic in the 62-bit and 64-bit fields fits into machine words, so on 64-bit CPUs their
+/// proofs are expected to be faster than in the 128-bit field. The options must suit every
+/// field: constraints of the VDF and hash chain examples have degree 7 in `f64`, which needs a
+/// blowup factor of 8.
+///
+/// Returns an error if an example is unknown or registered by another crate, whose base field is
+/// fixed when it is registered (see [plugins]), if the prover panics, or if any of the proofs is
+/// rejected.
+pub fn field_sweep(
+    config: &RunConfig,
+    examples: &[String],
+    fields: &[FieldType],
+    repetitions: Repetitions,
+) -> Result<Vec<FieldPoint>, ExampleError> {
+    let mut points = Vec::new();
+    let mut noisy = false;
+    for example in examples.iter() {
+        if plugins::registered_example(example).is_some() {
+            return Err(ExampleError::Config(format!(
+                "the field of example '{}' cannot be changed, since it is registered by another \
+                 crate",
+                example
+            )));
+        }
+        println!("Field sweep of {}", example);
+        println!(
+            "{:>5} | {:>12} | {:>16} | {:>7} | {:>15} | {:>18}",
+            "field",
+            "modulus bits",
+            "prover time (ms)",
+            "speedup",
+            "proof size (KB)",
+            "verifier time (ms)"
+        );
+
+        let mut baseline = None;
+        for &field in fields {
+            let config = RunConfig {
+                example: example.clone(),
+                field,
+                ..config.clone()
+            };
+            let reports = repetitions.repeat(|| {
+                // proof options which do not suit the field make the prover panic
+                let report = tamper::catch_panic(|| run_example(&config)).map_err(|err| {
+                    ExampleError::Proving(format!("{} in {}: {}", example, field, err))
+                })??;
+                match report.verification_error {
+                    Some(err) => Err(VerifyError::Rejected(format!(
+                        "proof of {} in {}: {}",
+                        example, field, err
+                    ))
+                    .into()),
+                    None => Ok(report),
+                }
+            })?;
+            info!("Proved {} in {}", example, field);
+            let prover_timing = Timing::of(
+                &reports
+                    .iter()
+                    .map(|report| report.prover_time)
+                    .collect::<Vec<_>>(),
+            );
+            let verifier_timing = Timing::of(
+                &reports
+                    .iter()
+                    .map(|report| report.verifier_time)
+                    .collect::<Vec<_>>(),
+            );
+            noisy |= prover_timing.is_noisy() || verifier_timing.is_noisy();
+            let report = reports.last().expect("at least one run is measured");
+
+            let baseline = *baseline.get_or_insert(prover_timing.mean);
+            println!(
+                "{:>5} | {:>12} | {:>16} | {:>7.2} | {:>15.1} | {:>18}",
+                field.to_string(),
+                field.modulus_bits(),
+                format_time(&prover_timing, 0),
+                baseline.as_secs_f64() / prover_timing.mean.as_secs_f64(),
+                report.proof_size as f64 / 1024f64,
+                format_time(&verifier_timing, 1)
+            );
+            points.push(FieldPoint {
+                example: example.clone(),
+                field,
+                trace_length: report.trace_length,
+                prover_time: prover_timing.mean,
+                verifier_time: verifier_timing.mean,
+                proof_size: report.proof_size,
+            });
+        }
+    }
+    print_noise_note(noisy, repetitions);
+    Ok(points)
+}
+
+// THREAD SWEEP
+// ================================================================================================
+
+/// Proves the example `repetitions` times for every number of threads from 1 to `max_threads`
+/// and prints how prover time and the speedup over a single thread change.
+///
+/// Each proof runs in a thread pool of its own, so the sweep is not affected by the size of the
+/// global pool which `RAYON_NUM_THREADS` controls. The trace is built outside the pool, so only
+/// proving is measured.
+///
+/// Returns an error if a thread pool cannot be created.
+#[cfg(feature = "concurrent")]
+pub fn thread_sweep<E: Example>(
+    example: &E,
+    options: &ProofOptionsConfig,
+    max_threads: usize,
+    repetitions: Repetitions,
+) -> Result<(), ExampleError> {
+    let options = options.to_proof_options();
+    println!(
+        "Thread sweep with {} queries at blowup factor {}, {}",
+        options.num_queries(),
+        options.blowup_factor(),
+        describe_fri_options(&options)
+    );
+    println!(
+        "{:>7} | {:>16} | {:>7} | {:>10}",
+        "threads", "prover time (ms)", "speedup", "efficiency"
+    );
+
+    let mut baseline = None;
+    let mut noisy = false;
+    for num_threads in 1..=max_threads {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|err| {
+                ExampleError::Proving(format!(
+                    "failed to create a pool of {} threads: {}",
+                    num_threads, err
+                ))
+            })?;
+        let times = repetitions.repeat(|| {
+            let prover = example.prover(options.clone());
+            let trace = example.build_trace();
+            let now = Instant::now();
+            pool.install(move || prover.prove(trace)).unwrap();
+            Ok(now.elapsed())
+        })?;
+        let timing = Timing::of(&times);
+        noisy |= timing.is_noisy();
+
+        let baseline = *baseline.get_or_insert(timing.mean);
+        let speedup = baseline.as_secs_f64() / timing.mean.as_secs_f64();
+        println!(
+            "{:>7} | {:>16} | {:>7.2} | {:>9.0}%",
+            num_threads,
+            format_time(&timing, 0),
+            speedup,
+            100f64 * speedup / num_threads as f64
+        );
+    }
+    print_noise_note(noisy, repetitions);
+    Ok(())
+}
+
+// VERIFIER LATENCY
+// ================================================================================================
+
+/// Distribution of the time it took to verify the same proof many times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierLatency {
+    /// Number of times the proof was verified.
+    pub iterations: usize,
+    /// True if every iteration also deserialized the proof.
+    pub deserialize: bool,
+    pub mean: Duration,
+    pub median: Duration,
+    /// Time within which 99% of the iterations completed.
+    pub p99: Duration,
+}
+
+impl fmt::Display for VerifierLatency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |duration: Duration| duration.as_nanos() as f64 / 1_000_000f64;
+        write!(
+            f,
+            "Verified proof {} times{}: mean {:.3} ms, median {:.3} ms, p99 {:.3} ms",
+            self.iterations,
+            if self.deserialize {
+                " (deserializing it every time)"
+            } else {
+                ""
+            },
+            ms(self.mean),
+            ms(self.median),
+            ms(self.p99)
+        )
+    }
+}
+
+/// Verifies the serialized proof of the example `iterations` times and reports the distribution
+/// of verification times.
+///
+/// A single verification often takes less than a millisecond, so one measurement of it is
+/// dominated by noise. Unless `deserialize` is set, the proof is deserialized once and only
+/// verification is timed; otherwise every iteration parses the proof bytes as well. Preparing
+/// the public inputs is never timed.
+///
+/// Returns an error if the proof cannot be parsed or is rejected.
+///
+/// # Panics
+/// Panics if `iterations` is zero.
+pub fn verifier_latency<E: Example>(
+    example: &E,
+    proof_bytes: &[u8],
+    iterations: usize,
+    deserialize: bool,
+) -> Result<VerifierLatency, ExampleError> {
+    assert!(iterations > 0, "number of iterations must be positive");
+    let parse = |bytes: &[u8]| {
+        StarkProof::from_bytes(bytes)
+            .map_err(|err| ExampleError::from(VerifyError::MalformedProof(err.to_string())))
+    };
+    let proof = parse(proof_bytes)?;
+
+    let mut times = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let pub_inputs = example.pub_inputs();
+        let (proof, now) = if deserialize {
+            let now = Instant::now();
+            (parse(proof_bytes)?, now)
+        } else {
+            (proof.clone(), Instant::now())
+        };
+        winterfell::verify::<E::Air>(proof, pub_inputs)
+            .map_err(|err| VerifyError::Rejected(err.to_string()))?;
+        times.push(now.elapsed());
+    }
+
+    times.sort();
+    let total: Duration = times.iter().sum();
+    Ok(VerifierLatency {
+        iterations,
+        deserialize,
+        mean: total / iterations as u32,
+        median: times[iterations / 2],
+        p99: times[(iterations * 99).div_ceil(100) - 1],
+    })
+}
+
+// PROOF COMPRESSION
+// ================================================================================================
+
+/// Proves the example once and prints the size of the serialized proof in each supported
+/// encoding, together with the compression ratio and the time it took to compress the proof.
+///
+/// Most of a proof consists of Merkle paths and field elements queried at pseudo-random
+/// positions, which look like random bytes to a general-purpose compressor.
+#[cfg(feature = "compression")]
+pub fn compression_comparison<E: Example>(example: &E, options: &ProofOptionsConfig) {
+    use crate::compression::Encoding;
+
+    let options = options.to_proof_options();
+    println!(
+        "Proof compression with {} queries at blowup factor {} using {:?}, {}",
+        options.num_queries(),
+        options.blowup_factor(),
+        options.hash_fn(),
+        describe_fri_options(&options)
+    );
+    let prover = example.prover(options);
+    let proof_bytes = prover.prove(example.build_trace()).unwrap().to_bytes();
+
+    println!(
+        "{:>12} | {:>12} | {:>9} | {:>16}",
+        "encoding", "size (bytes)", "ratio", "encode time (ms)"
+    );
+    for encoding in Encoding::ALL.iter() {
+        let now = Instant::now();
+        let encoded = encoding.encode(&proof_bytes);
+        let encode_ms = now.elapsed().as_micros() as f64 / 1000f64;
+        println!(
+            "{:>12} | {:>12} | {:>9.3} | {:>16.1}",
+            encoding.to_string(),
+            encoded.len(),
+            proof_bytes.len() as f64 / encoded.len() as f64,
+            encode_ms
+        );
+    }
+}
+
+// MERGED REPORTS
+// ================================================================================================
+
+/// The measurements of a serialized [RunReport](crate::example::RunReport) which are merged or
+/// compared.
+#[derive(Debug, Clone, Deserialize)]
+struct ReportRecord {
+    /// Name of the example; `None` for reports written before reports named their example.
+    #[serde(default)]
+    example: Option<String>,
+    trace_length: usize,
+    trace_time_ms: f64,
+    prover_time_ms: f64,
+    verifier_time_ms: f64,
+    proof_size: usize,
+    peak_memory_bytes: Option<usize>,
+    conjectured_security: u32,
+    proven_security: u32,
+    build: ReportBuild,
+}
+
+impl ReportRecord {
+    /// Reads a report serialized as JSON from a file.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the file cannot be read, and a
+    /// [Config](ExampleError::Config) error if it does not hold a serialized report.
+    fn read(path: &Path) -> Result<Self, ExampleError> {
+        let json =
+            fs::read_to_string(path).map_err(|err| ExampleError::read("report", path, err))?;
+        serde_json::from_str(&json).map_err(|err| {
+            ExampleError::Config(format!("{} is not a run report: {}", path.display(), err))
+        })
+    }
+}
+
+/// The build info of a serialized report which tells builds apart (see
+/// [BuildInfo](crate::buildinfo::BuildInfo)); reports written before commits were recorded have
+/// an unknown commit.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReportBuild {
+    pub profile: String,
+    pub target: String,
+    pub rustc_version: String,
+    pub winterfell_version: String,
+    #[serde(default = "unknown")]
+    pub commit: String,
+    pub cpu_features: Vec<String>,
+}
+
+impl fmt::Display for ReportBuild {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "commit {}, {} profile for {}, {}, winterfell {}; CPU features: {}",
+            self.commit,
+            self.profile,
+            self.target,
+            self.rustc_version,
+            self.winterfell_version,
+            join_features(&self.cpu_features, ", ")
+        )
+    }
+}
+
+/// Mean measurements of the reports of one example at one trace length which were taken by the
+/// same build on machines with the same CPU features.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedRow {
+    /// Name of the proven example.
+    pub example: String,
+    /// Number of steps in the execution trace.
+    pub trace_length: usize,
+    /// Index of the build in [MergedReports::builds].
+    pub build: usize,
+    /// Number of reports the means are taken over.
+    pub num_reports: usize,
+    /// Mean time it took to generate the proof, in milliseconds.
+    pub prover_time_ms: f64,
+    /// Mean time it took to verify the proof, in milliseconds.
+    pub verifier_time_ms: f64,
+    /// Mean size of the serialized proof in bytes.
+    pub proof_size: f64,
+    /// Mean peak heap memory of the prover in bytes, over the reports which measured it; `None`
+    /// if none of them did.
+    pub peak_memory: Option<f64>,
+}
+
+/// Run reports merged into one row per example, trace length, and build.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergedReports {
+    /// Every distinct build, in the order in which the reports name them.
+    pub builds: Vec<ReportBuild>,
+    /// The rows, sorted by example and trace length; rows of the same example and trace length
+    /// are in the order of their builds.
+    pub rows: Vec<MergedRow>,
+}
+
+impl MergedReports {
+    /// Returns the rows as CSV: a header line followed by a line per row, in which the build is
+    /// spelled out in the columns `commit`, `profile`, `target`, `rustc_version`,
+    /// `winterfell_version`, and `cpu_features` (joined by `+`), and peak memory which was not
+    /// measured is left empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "example,trace_length,commit,profile,target,rustc_version,winterfell_version,\
+             cpu_features,reports,prover_time_ms,verifier_time_ms,proof_size,peak_memory_bytes\n",
+        );
+        for row in self.rows.iter() {
+            let build = &self.builds[row.build];
+            let build_fields = [
+                &build.commit,
+                &build.profile,
+                &build.target,
+                &build.rustc_version,
+                &build.winterfell_version,
+                &join_features(&build.cpu_features, "+"),
+            ];
+            csv.push_str(&format!(
+                "{},{},{},{},{:.3},{:.3},{:.0},{}\n",
+                csv_field(&row.example),
+                row.trace_length,
+                build_fields
+                    .iter()
+                    .map(|value| csv_field(value))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                row.num_reports,
+                row.prover_time_ms,
+                row.verifier_time_ms,
+                row.proof_size,
+                row.peak_memory
+                    .map_or(String::new(), |bytes| format!("{:.0}", bytes))
+            ));
+        }
+        csv
+    }
+}
+
+impl fmt::Display for MergedReports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Builds:")?;
+        for (i, build) in self.builds.iter().enumerate() {
+            writeln!(f, "{:>5}: {}", i + 1, build)?;
+        }
+        write!(
+            f,
+            "{:>10} | {:>12} | {:>5} | {:>7} | {:>16} | {:>8} | {:>15} | {:>18} | {:>16}",
+            "example",
+            "trace length",
+            "build",
+            "reports",
+            "prover time (ms)",
+            "vs first",
+            "proof size (KB)",
+            "verifier time (ms)",
+            "peak memory (MB)"
+        )?;
+        let mut baseline: Option<(&str, usize, f64)> = None;
+        for row in self.rows.iter() {
+            // prover times are compared with the first build of the same example and size
+            let baseline_time = match baseline {
+                Some((example, trace_length, time))
+                    if example == row.example && trace_length == row.trace_length =>
+                {
+                    time
+                }
+                _ => {
+                    baseline = Some((&row.example, row.trace_length, row.prover_time_ms));
+                    row.prover_time_ms
+                }
+            };
+            write!(
+                f,
+                "\n{:>10} | {:>12} | {:>5} | {:>7} | {:>16.1} | {:>7.2}x | {:>15.1} | {:>18.1} | \
+                 {:>16}",
+                row.example,
+                row.trace_length,
+                row.build + 1,
+                row.num_reports,
+                row.prover_time_ms,
+                row.prover_time_ms / baseline_time,
+                row.proof_size / 1024f64,
+                row.verifier_time_ms,
+                format_memory(row.peak_memory.map(|bytes| bytes as usize))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads run reports serialized as JSON, such as those written by `run --artifacts`, and merges
+/// them into one row per example, trace length, and build, holding the means of their
+/// measurements.
+///
+/// The example of a report is the one it names; reports written before reports named their
+/// example are merged under `unknown`. Builds are told apart by every field of their build info
+/// except the CPU features the compiler was allowed to use, which are implied by those of the
+/// machine.
+///
+/// Returns an error if a file cannot be read or is not a serialized report.
+pub fn merge_reports(paths: &[PathBuf]) -> Result<MergedReports, ExampleError> {
+    let mut builds = Vec::new();
+    let mut groups: BTreeMap<_, Vec<ReportRecord>> = BTreeMap::new();
+    for path in paths.iter() {
+        let record = ReportRecord::read(path)?;
+        let build = match builds.iter().position(|build| *build == record.build) {
+            Some(index) => index,
+            None => {
+                builds.push(record.build.clone());
+                builds.len() - 1
+            }
+        };
+        let example = record.example.clone().unwrap_or_else(unknown);
+        groups
+            .entry((example, record.trace_length, build))
+            .or_default()
+            .push(record);
+    }
+
+    let mut rows = Vec::new();
+    for ((example, trace_length, build), records) in groups.into_iter() {
+        let mean = |value: fn(&ReportRecord) -> f64| {
+            records.iter().map(value).sum::<f64>() / records.len() as f64
+        };
+        let peak_memory = records
+            .iter()
+            .filter_map(|record| record.peak_memory_bytes)
+            .collect::<Vec<_>>();
+        rows.push(MergedRow {
+            example,
+            trace_length,
+            build,
+            num_reports: records.len(),
+            prover_time_ms: mean(|record| record.prover_time_ms),
+            verifier_time_ms: mean(|record| record.verifier_time_ms),
+            proof_size: mean(|record| record.proof_size as f64),
+            peak_memory: match peak_memory.len() {
+                0 => None,
+                n => Some(peak_memory.iter().sum::<usize>() as f64 / n as f64),
+            },
+        });
+    }
+
+    Ok(MergedReports { builds, rows })
+}
+
+// REPORT DIFF
+// ================================================================================================
+
+/// A measurement of a run report which can be compared across reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    TraceTime,
+    ProverTime,
+    VerifierTime,
+    ProofSize,
+    PeakMemory,
+    ConjecturedSecurity,
+    ProvenSecurity,
+}
+
+impl Metric {
+    /// Returns the name of the metric with its unit, as printed in a diff.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::TraceTime => "trace time (ms)",
+            Metric::ProverTime => "prover time (ms)",
+            Metric::VerifierTime => "verifier time (ms)",
+            Metric::ProofSize => "proof size (KB)",
+            Metric::PeakMemory => "peak memory (MB)",
+            Metric::ConjecturedSecurity => "conjectured security (bits)",
+            Metric::ProvenSecurity => "proven security (bits)",
+        }
+    }
+
+    /// Returns true for the security levels, of which more is better; every other metric is a
+    /// cost.
+    pub fn is_security(&self) -> bool {
+        matches!(self, Metric::ConjecturedSecurity | Metric::ProvenSecurity)
+    }
+}
+
+/// Values of one metric in two reports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricDelta {
+    pub metric: Metric,
+    /// Value in the first report.
+    pub before: f64,
+    /// Value in the second report.
+    pub after: f64,
+}
+
+impl MetricDelta {
+    /// Returns the change relative to the value in the first report, in percent; `None` if that
+    /// value is zero.
+    pub fn percent(&self) -> Option<f64> {
+        if self.before == 0f64 {
+            None
+        } else {
+            Some(100f64 * (self.after - self.before) / self.before)
+        }
+    }
+
+    /// Returns true if the metric got worse: a cost which grew by more than `threshold` percent,
+    /// or a security level which lost any bits.
+    ///
+    /// Times and memory vary between runs of the same build, and proof sizes with the number of
+    /// distinct query positions, so small changes in them are noise; security levels are
+    /// computed from the proof options and the AIR, so every change in them is real.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        if self.metric.is_security() {
+            self.after < self.before
+        } else {
+            self.percent()
+                .map_or(self.after > self.before, |percent| percent > threshold)
+        }
+    }
+}
+
+/// The changes between two run reports, such as of the same example before and after a change
+/// to its constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDiff {
+    /// Names of the examples of the reports; `None` for a report written before reports named
+    /// their example.
+    pub examples: [Option<String>; 2],
+    /// Trace lengths of the reports.
+    pub trace_lengths: [usize; 2],
+    /// Builds which wrote the reports.
+    pub builds: [ReportBuild; 2],
+    /// Values of every metric in both reports; peak memory is left out unless both reports
+    /// measured it.
+    pub deltas: Vec<MetricDelta>,
+    /// Percentage by which a cost must grow to be a regression (see
+    /// [MetricDelta::is_regression()]).
+    pub threshold: f64,
+}
+
+impl ReportDiff {
+    /// Returns the metrics which got worse.
+    pub fn regressions(&self) -> Vec<&MetricDelta> {
+        self.deltas
+            .iter()
+            .filter(|delta| delta.is_regression(self.threshold))
+            .collect()
+    }
+}
+
+impl fmt::Display for ReportDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Before: {}", self.builds[0])?;
+        writeln!(f, "After:  {}", self.builds[1])?;
+        if let [Some(before), Some(after)] = &self.examples {
+            if before != after {
+                writeln!(
+                    f,
+                    "Note: the reports are of different examples ({} and {})",
+                    before, after
+                )?;
+            }
+        }
+        if self.trace_lengths[0] != self.trace_lengths[1] {
+            writeln!(
+                f,
+                "Note: the reports are of different trace lengths ({} and {})",
+                self.trace_lengths[0], self.trace_lengths[1]
+            )?;
+        }
+        writeln!(
+            f,
+            "{:>27} | {:>10} | {:>10} | {:>10} | {:>8}",
+            "metric", "before", "after", "change", "change %"
+        )?;
+        for delta in self.deltas.iter() {
+            let precision = if delta.metric.is_security() { 0 } else { 1 };
+            writeln!(
+                f,
+                "{:>27} | {:>10.*} | {:>10.*} | {:>+10.*} | {:>8}{}",
+                delta.metric.name(),
+                precision,
+                delta.before,
+                precision,
+                delta.after,
+                precision,
+                delta.after - delta.before,
+                delta
+                    .percent()
+                    .map_or("-".to_string(), |percent| format!("{:+.1}%", percent)),
+                if delta.is_regression(self.threshold) {
+                    "  <- regression"
+                } else {
+                    ""
+                }
+            )?;
+        }
+        let regressions = self.regressions();
+        if regressions.is_empty() {
+            write!(
+                f,
+                "No cost grew by more than {}% and no security level dropped",
+                self.threshold
+            )
+        } else {
+            let names = regressions
+                .iter()
+                .map(|delta| delta.metric.name())
+                .collect::<Vec<_>>();
+            write!(f, "Regressed: {}", names.join(", "))
+        }
+    }
+}
+
+/// Reads two run reports serialized as JSON and compares their measurements, flagging costs
+/// which grew by more than `threshold` percent and security levels which dropped.
+///
+/// Returns an [Io](ExampleError::Io) error if a file cannot be read, and a
+/// [Config](ExampleError::Config) error if it is not a serialized report.
+pub fn diff_reports(
+    before: &Path,
+    after: &Path,
+    threshold: f64,
+) -> Result<ReportDiff, ExampleError> {
+    let records = [ReportRecord::read(before)?, ReportRecord::read(after)?];
+    let value = |metric: Metric, record: &ReportRecord| match metric {
+        Metric::TraceTime => Some(record.trace_time_ms),
+        Metric::ProverTime => Some(record.prover_time_ms),
+        Metric::VerifierTime => Some(record.verifier_time_ms),
+        Metric::ProofSize => Some(record.proof_size as f64 / 1024f64),
+        Metric::PeakMemory => record
+            .peak_memory_bytes
+            .map(|bytes| bytes as f64 / (1024f64 * 1024f64)),
+        Metric::ConjecturedSecurity => Some(record.conjectured_security as f64),
+        Metric::ProvenSecurity => Some(record.proven_security as f64),
+    };
+    let metrics = [
+        Metric::TraceTime,
+        Metric::ProverTime,
+        Metric::VerifierTime,
+        Metric::ProofSize,
+        Metric::PeakMemory,
+        Metric::ConjecturedSecurity,
+        Metric::ProvenSecurity,
+    ];
+    let deltas = metrics
+        .iter()
+        .filter_map(|&metric| {
+            Some(MetricDelta {
+                metric,
+                before: value(metric, &records[0])?,
+                after: value(metric, &records[1])?,
+            })
+        })
+        .collect();
+
+    let [before_record, after_record] = records;
+    Ok(ReportDiff {
+        examples: [before_record.example, after_record.example],
+        trace_lengths: [before_record.trace_length, after_record.trace_length],
+        builds: [before_record.build, after_record.build],
+        deltas,
+        threshold,
+    })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Formats peak memory in megabytes for a table cell; memory which was not measured is shown
+/// as a dash.
+pub(crate) fn format_memory(peak_memory: Option<usize>) -> String {
+    match peak_memory {
+        Some(bytes) => format!("{:.1}", bytes as f64 / (1024f64 * 1024f64)),
+        None => "-".to_string(),
+    }
+}
+
+/// Formats the mean of a timing in milliseconds with `precision` decimals for a table cell, and
+/// marks it with an asterisk if it is noisy.
+fn format_time(timing: &Timing, precision: usize) -> String {
+    let millis = timing.mean.as_secs_f64() * 1000f64;
+    let mark = if timing.is_noisy() { "*" } else { "" };
+    format!("{:.*}{}", precision, millis, mark)
+}
+
+/// Prints what the asterisk of a noisy time means below a table with one.
+fn print_noise_note(noisy: bool, repetitions: Repetitions) {
+    if noisy {
+        println!(
+            "* varied by more than {:.0}% across {} measured runs; more warm-up runs or \
+            pinning the benchmark to cores may steady it.",
+            NOISY_VARIATION * 100f64,
+            repetitions.samples
+        );
+    }
+}
+
+/// Describes the FRI options for the title of a table.
+fn describe_fri(folding_factor: usize, max_remainder_size: usize) -> String {
+    format!(
+        "FRI folding factor {} and max remainder size {}",
+        folding_factor, max_remainder_size
+    )
+}
+
+fn describe_fri_options(options: &ProofOptions) -> String {
+    let fri_options = options.to_fri_options();
+    describe_fri(
+        fri_options.folding_factor(),
+        fri_options.max_remainder_size(),
+    )
+}
+
+fn join_features(features: &[String], separator: &str) -> String {
+    match features {
+        [] => "none".to_string(),
+        features => features.join(separator),
+    }
+}
+
+/// Quotes a CSV field if it contains a comma, a quote, or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn unknown() -> String {
+    "unknown".to_string()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        diff_reports, field_sweep, merge_reports, pin_to_cores, trace_length_sweep, Metric,
+        Repetitions, Timing,
+    };
+    use crate::{
+        config::{FibConfig, ProofOptionsConfig, RescueConfig, RunConfig},
+        error::ExampleError,
+        example,
+        field::FieldType,
+        test_utils::{vdf_example, vdf_options},
+    };
+    use std::time::Duration;
+    use winterfell::math::fields::f64;
+
+    #[test]
+    fn bench_merge_averages_reports_of_each_build() {
+        use crate::artifacts::{ArtifactKind, ArtifactStore};
+        use std::{env, fs, process};
+
+        let dir = env::temp_dir().join(format!("stark-examples-merge-{}", process::id()));
+        let store = ArtifactStore::open(&dir).unwrap();
+        let report = example::run(&vdf_example(), vdf_options());
+        let mut json = serde_json::to_value(&report).unwrap();
+        let mut paths = Vec::new();
+        for prover_time_ms in [10.0, 20.0] {
+            json["prover_time_ms"] = prover_time_ms.into();
+            let bytes = serde_json::to_vec(&json).unwrap();
+            paths.push(store.write("vdf", ArtifactKind::Report, &bytes).unwrap());
+        }
+        // the same example measured by another commit, in a file named by hand
+        json["build"]["commit"] = "0000000".into();
+        json["prover_time_ms"] = 45.0.into();
+        let other_path = dir.join("vdf.json");
+        fs::write(&other_path, serde_json::to_vec(&json).unwrap()).unwrap();
+        paths.push(other_path);
+
+        let merged = merge_reports(&paths).unwrap();
+        assert_eq!(2, merged.builds.len());
+        assert_eq!("0000000", merged.builds[1].commit);
+        assert_eq!(2, merged.rows.len());
+        for (i, row) in merged.rows.iter().enumerate() {
+            assert_eq!("vdf", row.example);
+            assert_eq!(report.trace_length, row.trace_length);
+            assert_eq!(i, row.build);
+            assert_eq!(report.proof_size as f64, row.proof_size);
+        }
+        assert_eq!(2, merged.rows[0].num_reports);
+        assert_eq!(15.0, merged.rows[0].prover_time_ms);
+        assert_eq!(45.0, merged.rows[1].prover_time_ms);
+        assert!(merged.to_string().contains("3.00x"));
+
+        let csv = merged.to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(3, lines.len());
+        assert!(lines[0].starts_with("example,trace_length,commit,"));
+        assert!(lines[2].starts_with(&format!("vdf,{},0000000,", report.trace_length)));
+
+        // files which are not reports are rejected with their names
+        let err = merge_reports(&[dir.join("index.jsonl")]).unwrap_err();
+        assert!(matches!(err, ExampleError::Config(_)), "{}", err);
+        assert!(err.to_string().contains("index.jsonl"), "{}", err);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn report_diff_flags_regressions() {
+        use std::{env, fs, process};
+
+        let dir = env::temp_dir().join(format!("stark-examples-diff-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let report = example::run(&vdf_example(), vdf_options());
+        let mut json = serde_json::to_value(&report).unwrap();
+        json["prover_time_ms"] = 100.0.into();
+        json["verifier_time_ms"] = 2.0.into();
+        let before = dir.join("before.json");
+        fs::write(&before, serde_json::to_vec(&json).unwrap()).unwrap();
+
+        // a slower prover within the threshold and a faster verifier are not regressions
+        json["prover_time_ms"] = 104.0.into();
+        json["verifier_time_ms"] = 1.0.into();
+        let after = dir.join("after.json");
+        fs::write(&after, serde_json::to_vec(&json).unwrap()).unwrap();
+        let diff = diff_reports(&before, &after, 5.0).unwrap();
+        assert!(diff.regressions().is_empty(), "{}", diff);
+        let prover_time = diff
+            .deltas
+            .iter()
+            .find(|delta| delta.metric == Metric::ProverTime)
+            .unwrap();
+        assert_eq!(Some(4.0), prover_time.percent().map(f64::round));
+        assert!(diff.to_string().contains("+4.0%"));
+
+        // beyond the threshold the prover regresses, and a security level regresses by any bit
+        json["prover_time_ms"] = 110.0.into();
+        json["proven_security"] = (report.proven_security - 1).into();
+        fs::write(&after, serde_json::to_vec(&json).unwrap()).unwrap();
+        let diff = diff_reports(&before, &after, 5.0).unwrap();
+        let regressed = diff
+            .regressions()
+            .iter()
+            .map(|delta| delta.metric)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Metric::ProverTime, Metric::ProvenSecurity], regressed);
+        assert!(diff.to_string().contains("<- regression"));
+        let lenient = diff_reports(&before, &after, 20.0).unwrap();
+        assert_eq!(1, lenient.regressions().len());
+
+        // the examples are those the reports name, whatever the files are called
+        assert_eq!(
+            [Some("vdf".to_string()), Some("vdf".to_string())],
+            diff.examples
+        );
+        assert!(!diff.to_string().contains("different examples"));
+        json["example"] = "rescue".into();
+        fs::write(&after, serde_json::to_vec(&json).unwrap()).unwrap();
+        let diff = diff_reports(&before, &after, 5.0).unwrap();
+        assert!(diff
+            .to_string()
+            .contains("different examples (vdf and rescue)"));
+        json.as_object_mut().unwrap().remove("example");
+        fs::write(&after, serde_json::to_vec(&json).unwrap()).unwrap();
+        let diff = diff_reports(&before, &after, 5.0).unwrap();
+        assert_eq!(None, diff.examples[1]);
+        assert!(!diff.to_string().contains("different examples"));
+
+        let err = diff_reports(&before, &dir.join("missing.json"), 5.0).unwrap_err();
+        assert!(matches!(err, ExampleError::Io { .. }), "{}", err);
+        let notes = dir.join("notes.txt");
+        fs::write(&notes, "not a report").unwrap();
+        let err = diff_reports(&before, &notes, 5.0).unwrap_err();
+        assert!(matches!(err, ExampleError::Config(_)), "{}", err);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn trace_length_sweep_doubles_the_trace_length() {
+        let config = RunConfig {
+            example: "fib".to_string(),
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            fib: FibConfig {
+                trace_length: 64,
+                ..FibConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let points =
+            trace_length_sweep(&config, &["fib".to_string()], 2, Repetitions::SINGLE).unwrap();
+        let lengths = points
+            .iter()
+            .map(|point| point.trace_length)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![64, 128, 256], lengths);
+        assert!(points[0].proof_size < points[2].proof_size);
+
+        // the size of the exponentiation is fixed by its exponent
+        assert!(trace_length_sweep(&config, &["exp".to_string()], 2, Repetitions::SINGLE).is_err());
+    }
+
+    #[test]
+    fn fib_and_rescue_are_proven_in_every_swept_field() {
+        let config = RunConfig {
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            fib: FibConfig {
+                trace_length: 64,
+                ..FibConfig::default()
+            },
+            rescue: RescueConfig {
+                chain_length: 8,
+                ..RescueConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let examples = ["fib".to_string(), "rescue".to_string()];
+        let points = field_sweep(
+            &config,
+            &examples,
+            &[FieldType::F128, FieldType::F62],
+            Repetitions::SINGLE,
+        )
+        .unwrap();
+        let swept = points
+            .iter()
+            .map(|point| (point.example.as_str(), point.field))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("fib", FieldType::F128),
+                ("fib", FieldType::F62),
+                ("rescue", FieldType::F128),
+                ("rescue", FieldType::F62),
+            ],
+            swept
+        );
+        // elements of the 62-bit field take half the bytes
+        assert!(points[1].proof_size < points[0].proof_size);
+        assert_eq!(points[0].trace_length, points[1].trace_length);
+    }
+
+    #[test]
+    fn bench_times_are_means_of_the_measured_runs() {
+        let config = RunConfig {
+            fib: FibConfig {
+                trace_length: 64,
+                ..FibConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let repetitions = Repetitions {
+            warmup: 1,
+            samples: 2,
+        };
+        let points = trace_length_sweep(&config, &["fib".to_string()], 1, repetitions).unwrap();
+        assert_eq!(2, points.len());
+
+        let millis = Duration::from_millis;
+        let steady = Timing::of(&[millis(100), millis(104), millis(96)]);
+        assert_eq!(millis(100), steady.mean);
+        assert!(!steady.is_noisy());
+        let noisy = Timing::of(&[millis(100), millis(150)]);
+        assert_eq!(millis(125), noisy.mean);
+        assert!(noisy.is_noisy());
+        assert_eq!(0f64, Timing::of(&[millis(10)]).variation);
+
+        assert!(pin_to_cores(&[usize::MAX]).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::{and_word, xor_word},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in a word.
+pub const WORD_BITS: usize = 32;
+
+/// Trace column layout. The columns from `A_BITS` and `B_BITS` on hold the bits of the two
+/// words of a row, least significant bit first; `A_WORD` and `B_WORD` hold the words themselves,
+/// `XOR` and `AND` the results of the row, and `XOR_SUM` and `AND_SUM` the sums of the results
+/// of all previous rows.
+pub const A_BITS: usize = 0;
+pub const B_BITS: usize = A_BITS + WORD_BITS;
+pub const A_WORD: usize = B_BITS + WORD_BITS;
+pub const B_WORD: usize = A_WORD + 1;
+pub const XOR: usize = B_WORD + 1;
+pub const AND: usize = XOR + 1;
+pub const XOR_SUM: usize = AND + 1;
+pub const AND_SUM: usize = XOR_SUM + 1;
+pub const TRACE_WIDTH: usize = AND_SUM + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct BitwiseInputs<B: ExampleField> {
+    pub xor_sum: B,
+    pub and_sum: B,
+}
+
+impl<B: ExampleField> Serializable for BitwiseInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.xor_sum);
+        target.write(self.and_sum);
+    }
+}
+
+impl<B: ExampleField> Deserializable for BitwiseInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(BitwiseInputs {
+            xor_sum: B::read_from(source)?,
+            and_sum: B::read_from(source)?,
+        })
+    }
+}
+
+// BITWISE AIR
+// ================================================================================================
+
+/// AIR of a sequence of pairs of 32-bit words, whose XORs and ANDs are summed up.
+///
+/// A prime field has no bitwise operations, so each word is decomposed into bit columns, and
+/// the operations are computed bit by bit with polynomials which agree with them on zero and
+/// one. The constraints of a row are:
+///
+/// * `a_i * a_i = a_i` and `b_i * b_i = b_i` for every bit, so that each bit is binary;
+/// * `a = sum(2^i * a_i)` and `b = sum(2^i * b_i)`, so that the bits are those of the words;
+/// * `xor = sum(2^i * (a_i + b_i - 2 * a_i * b_i))`, since `a_i + b_i - 2 * a_i * b_i` is one
+///   exactly when one of the bits is one;
+/// * `and = sum(2^i * a_i * b_i)`, since `a_i * b_i` is one exactly when both bits are one.
+///
+/// The per-bit polynomials compute bitwise operations only on binary values, so the binary
+/// constraints are what make the results meaningful: without them, `a = 2` and `b = 1` could be
+/// decomposed as `a_0 = 2` and `b_0 = 1`, whose XOR polynomial `2 + 1 - 4` is not 3. Two more
+/// constraints add the results to the running sums, whose final values are public.
+pub struct BitwiseAir<B: ExampleField> {
+    context: AirContext<B>,
+    pub_inputs: BitwiseInputs<B>,
+}
+
+impl<B: ExampleField> Air for BitwiseAir<B> {
+    type BaseField = B;
+    type PublicInputs = BitwiseInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: BitwiseInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![2; 2 * WORD_BITS];
+        degrees.extend([1, 1, 2, 2, 1, 1]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let a_bits = &current[A_BITS..A_BITS + WORD_BITS];
+        let b_bits = &current[B_BITS..B_BITS + WORD_BITS];
+
+        // every bit is binary, and the bits add up to the words
+        result.enforce_binary(A_BITS, a_bits);
+        result.enforce_binary(B_BITS, b_bits);
+        result[A_WORD] = are_equal(current[A_WORD], combine_bits(a_bits));
+        result[B_WORD] = are_equal(current[B_WORD], combine_bits(b_bits));
+
+        // the results are computed bit by bit and aggregated back into words, most significant
+        // bit first
+        result[XOR] = are_equal(current[XOR], xor_word(a_bits, b_bits));
+        result[AND] = are_equal(current[AND], and_word(a_bits, b_bits));
+
+        // the results are added to the running sums
+        result[XOR_SUM] = are_equal(next[XOR_SUM], current[XOR_SUM] + current[XOR]);
+        result[AND_SUM] = are_equal(next[AND_SUM], current[AND_SUM] + current[AND]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sums start at zero and end with the public results
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(XOR_SUM, B::ZERO),
+            first_row_equals(AND_SUM, B::ZERO),
+            last_row_equals(XOR_SUM, trace_length, self.pub_inputs.xor_sum),
+            last_row_equals(AND_SUM, trace_length, self.pub_inputs.and_sum),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
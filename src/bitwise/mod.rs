@@ -0,0 +1,149 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::BitwiseConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{BitwiseAir, BitwiseInputs, WORD_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::BitwiseProver;
+
+#[cfg(test)]
+mod tests;
+
+// BITWISE EXAMPLE
+// ================================================================================================
+
+/// Proves that the results are the sums of `a XOR b` and of `a AND b` over a sequence of
+/// private pairs of 32-bit words, one pair per step.
+///
+/// The words are decomposed into bit columns on which the operations are computed bit by bit;
+/// see [BitwiseAir] for the constraints.
+///
+/// The pairs are private and bound to nothing the verifier knows; they are drawn from the seed,
+/// but the seed is not part of the statement. A proof therefore exists for any sums which some
+/// pairs of 32-bit words add up to, and shows only that the operations in the trace were
+/// computed correctly, not which pairs they were computed on.
+#[cfg(feature = "std")]
+pub struct BitwiseExample<B: ExampleField> {
+    seed: u64,
+    pairs: Vec<(u32, u32)>,
+    xor_sum: B,
+    and_sum: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> BitwiseExample<B> {
+    pub fn new(config: &BitwiseConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // the last row is not covered by transition constraints, so no pair is combined there
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let pairs = (0..config.trace_length - 1)
+            .map(|_| (rng.gen(), rng.gen()))
+            .collect::<Vec<_>>();
+
+        // compute results
+        let now = Instant::now();
+        let (xor_sum, and_sum) = pairs
+            .iter()
+            .fold((B::ZERO, B::ZERO), |(xor, and), &(a, b)| {
+                (xor + B::from(a ^ b), and + B::from(a & b))
+            });
+        info!("Computed results in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            pairs,
+            xor_sum,
+            and_sum,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &BitwiseConfig) -> Result<(), String> {
+        validate::check_trace_length("bitwise.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for BitwiseExample<B> {
+    type Air = BitwiseAir<B>;
+    type Prover = BitwiseProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        BitwiseProver::build_trace(&self.pairs)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = BitwiseConfig::default();
+        ExampleDescriptor::new(
+            "bitwise",
+            "the sums of the XORs and ANDs of private pairs of 32-bit words",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("A_BITS", WORD_BITS),
+                ("B_BITS", WORD_BITS),
+                ("A_WORD", 1),
+                ("B_WORD", 1),
+                ("XOR", 1),
+                ("AND", 1),
+                ("XOR_SUM", 1),
+                ("AND_SUM", 1),
+            ],
+            constraints: vec![
+                ("binary bits", 2 * WORD_BITS),
+                ("word from bits", 2),
+                ("XOR of bits", 1),
+                ("AND of bits", 1),
+                ("sum accumulation", 2),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> BitwiseProver<B> {
+        BitwiseProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> BitwiseInputs<B> {
+        BitwiseInputs {
+            xor_sum: self.xor_sum,
+            and_sum: self.and_sum,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> BitwiseInputs<B> {
+        BitwiseInputs {
+            and_sum: self.and_sum + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::{
+    air::{AND, AND_SUM, A_BITS, A_WORD, B_BITS, B_WORD, TRACE_WIDTH, WORD_BITS, XOR, XOR_SUM},
+    BitwiseAir, BitwiseInputs,
+};
+use crate::{field::ExampleField, gadgets::to_bits};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct BitwiseProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> BitwiseProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which combines one pair of words per row, and sums up the results; the
+    /// last row, which follows the last pair, repeats the first pair so that its bits stay
+    /// consistent with its words.
+    pub fn build_trace(pairs: &[(u32, u32)]) -> TraceTable<B> {
+        let trace_length = pairs.len() + 1;
+        let mut columns = vec![vec![B::ZERO; trace_length]; TRACE_WIDTH];
+        let (mut xor_sum, mut and_sum) = (B::ZERO, B::ZERO);
+        for (step, &(a, b)) in pairs.iter().chain(pairs.first()).enumerate() {
+            for (i, bit) in to_bits(a as u64, WORD_BITS).enumerate() {
+                columns[A_BITS + i][step] = bit;
+            }
+            for (i, bit) in to_bits(b as u64, WORD_BITS).enumerate() {
+                columns[B_BITS + i][step] = bit;
+            }
+            columns[A_WORD][step] = B::from(a);
+            columns[B_WORD][step] = B::from(b);
+            columns[XOR][step] = B::from(a ^ b);
+            columns[AND][step] = B::from(a & b);
+            columns[XOR_SUM][step] = xor_sum;
+            columns[AND_SUM][step] = and_sum;
+            xor_sum += B::from(a ^ b);
+            and_sum += B::from(a & b);
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for BitwiseProver<B> {
+    type BaseField = B;
+    type Air = BitwiseAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BitwiseInputs<B> {
+        let last_step = trace.length() - 1;
+        BitwiseInputs {
+            xor_sum: trace.get(XOR_SUM, last_step),
+            and_sum: trace.get(AND_SUM, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
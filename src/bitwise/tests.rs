@@ -0,0 +1,131 @@
+use super::{
+    air::{AND, AND_SUM, A_BITS, A_WORD, B_BITS, B_WORD, WORD_BITS, XOR, XOR_SUM},
+    BitwiseExample, BitwiseInputs, BitwiseProver,
+};
+use crate::{
+    config::{BitwiseConfig, ProofOptionsConfig, RunConfig},
+    example::Example,
+    run_example,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn bitwise_trace_decomposes_words_and_sums_results() {
+    let pairs = [
+        (0b1100, 0b1010),
+        (u32::MAX, 0x0f0f_0f0f),
+        (7, 0),
+        (0, 0),
+        (1 << 31, 1 << 31),
+        (0xdead_beef, u32::MAX),
+        (5, 6),
+    ];
+    let trace = BitwiseProver::<BaseElement>::build_trace(&pairs);
+    let xors = [0b0110u64, 0xf0f0_f0f0, 7, 0, 0, 0x2152_4110, 3];
+    let ands = [0b1000u64, 0x0f0f_0f0f, 0, 0, 1 << 31, 0xdead_beef, 4];
+    let (mut xor_sum, mut and_sum) = (0, 0);
+    for (step, &(a, b)) in pairs.iter().enumerate() {
+        for i in 0..WORD_BITS {
+            assert_eq!(BaseElement::from((a >> i) & 1), trace.get(A_BITS + i, step));
+            assert_eq!(BaseElement::from((b >> i) & 1), trace.get(B_BITS + i, step));
+        }
+        assert_eq!(BaseElement::from(a), trace.get(A_WORD, step));
+        assert_eq!(BaseElement::from(b), trace.get(B_WORD, step));
+        assert_eq!(BaseElement::from(xors[step]), trace.get(XOR, step));
+        assert_eq!(BaseElement::from(ands[step]), trace.get(AND, step));
+        assert_eq!(BaseElement::from(xor_sum), trace.get(XOR_SUM, step));
+        assert_eq!(BaseElement::from(and_sum), trace.get(AND_SUM, step));
+        xor_sum += xors[step];
+        and_sum += ands[step];
+    }
+    assert_eq!(BaseElement::from(xor_sum), trace.get(XOR_SUM, pairs.len()));
+    assert_eq!(BaseElement::from(and_sum), trace.get(AND_SUM, pairs.len()));
+}
+
+#[test]
+fn bitwise_decomposition_into_non_binary_bits_is_rejected() {
+    // decompose a = 1 as a_0 = -1 and a_1 = 1, which still adds up to the word; with b = 1, the
+    // per-bit polynomials then yield 4 for the XOR rather than 0, and -1 for the AND rather
+    // than 1, and only the binary constraints catch the forgery
+    let pairs = (0..TRACE_LENGTH as u32 - 1)
+        .map(|i| (i.wrapping_mul(0x9e37_79b9), i))
+        .collect::<Vec<_>>();
+    let mut trace = BitwiseProver::<BaseElement>::build_trace(&pairs);
+    let step = 4;
+    for i in 0..WORD_BITS {
+        trace.set(A_BITS + i, step, BaseElement::ZERO);
+        trace.set(B_BITS + i, step, BaseElement::ZERO);
+    }
+    trace.set(A_BITS, step, -BaseElement::ONE);
+    trace.set(A_BITS + 1, step, BaseElement::ONE);
+    trace.set(B_BITS, step, BaseElement::ONE);
+    trace.set(A_WORD, step, BaseElement::ONE);
+    trace.set(B_WORD, step, BaseElement::ONE);
+    let forged_xor = BaseElement::from(4u8) - trace.get(XOR, step);
+    let forged_and = -BaseElement::ONE - trace.get(AND, step);
+    trace.set(XOR, step, BaseElement::from(4u8));
+    trace.set(AND, step, -BaseElement::ONE);
+    for later in step + 1..TRACE_LENGTH {
+        trace.set(XOR_SUM, later, trace.get(XOR_SUM, later) + forged_xor);
+        trace.set(AND_SUM, later, trace.get(AND_SUM, later) + forged_and);
+    }
+    let pub_inputs = BitwiseInputs {
+        xor_sum: trace.get(XOR_SUM, TRACE_LENGTH - 1),
+        and_sum: trace.get(AND_SUM, TRACE_LENGTH - 1),
+    };
+
+    let proof = BitwiseProver::new(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<BitwiseExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn bitwise_seed_reproduces_inputs() {
+    let mut config = RunConfig {
+        example: "bitwise".to_string(),
+        bitwise: BitwiseConfig {
+            trace_length: TRACE_LENGTH,
+            ..BitwiseConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    config.set_seed(5).unwrap();
+    let first = run_example(&config).unwrap();
+    assert_eq!(Some(5), first.seed);
+    assert_eq!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+
+    config.set_seed(6).unwrap();
+    assert_ne!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
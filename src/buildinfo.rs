@@ -0,0 +1,168 @@
+//! Facts about the build and the machine which make benchmark numbers comparable.
+//!
+//! Prover times depend as much on how the binary was built and which CPU it runs on as on the
+//! proof options: a debug build is an order of magnitude slower than a release build, and the
+//! field arithmetic of winterfell speeds up with wider vector instructions. The build script
+//! records the profile, the target, the rustc version, the winterfell version, and the git commit
+//! the crate was built with; the CPU features are detected when the program runs.
+
+use serde::Serialize;
+use std::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// CPU features which affect the speed of the prover, in the order they are reported.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const CPU_FEATURES: [&str; 6] = ["sse4.2", "avx", "avx2", "avx512f", "bmi2", "adx"];
+#[cfg(target_arch = "aarch64")]
+const CPU_FEATURES: [&str; 4] = ["neon", "aes", "sha3", "sve"];
+
+// BUILD INFO
+// ================================================================================================
+
+/// How the crate was built and which CPU features the machine it runs on supports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Cargo profile of the build, such as `release` or `debug`.
+    pub profile: &'static str,
+    /// Target triple the crate was compiled for.
+    pub target: &'static str,
+    /// Version of the compiler, as printed by `rustc --version`.
+    pub rustc_version: &'static str,
+    /// Version of winterfell the crate was linked against.
+    pub winterfell_version: &'static str,
+    /// Abbreviated hash of the git commit the crate was built from.
+    pub commit: &'static str,
+    /// CPU features affecting the speed of the prover which the compiler was allowed to use,
+    /// such as with `-C target-cpu=native`.
+    pub target_features: Vec<&'static str>,
+    /// CPU features affecting the speed of the prover which the CPU running the program
+    /// supports.
+    pub cpu_features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    /// Returns the build info of the running program.
+    pub fn current() -> Self {
+        let enabled = env!("STARK_EXAMPLES_TARGET_FEATURES")
+            .split(',')
+            .collect::<Vec<_>>();
+        Self {
+            profile: or_unknown(env!("STARK_EXAMPLES_PROFILE")),
+            target: or_unknown(env!("STARK_EXAMPLES_TARGET")),
+            rustc_version: or_unknown(env!("STARK_EXAMPLES_RUSTC_VERSION")),
+            winterfell_version: or_unknown(env!("STARK_EXAMPLES_WINTERFELL_VERSION")),
+            commit: or_unknown(env!("STARK_EXAMPLES_COMMIT")),
+            target_features: cpu_features()
+                .iter()
+                .copied()
+                .filter(|feature| enabled.contains(feature))
+                .collect(),
+            cpu_features: cpu_features()
+                .iter()
+                .copied()
+                .filter(|feature| is_detected(feature))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let features = |features: &[&str]| match features {
+            [] => "none".to_string(),
+            features => features.join(", "),
+        };
+        write!(
+            f,
+            "Build: {} profile for {}, {}, winterfell {}, commit {}; target features: {}; CPU \
+             features: {}",
+            self.profile,
+            self.target,
+            self.rustc_version,
+            self.winterfell_version,
+            self.commit,
+            features(&self.target_features),
+            features(&self.cpu_features)
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn or_unknown(value: &'static str) -> &'static str {
+    if value.is_empty() {
+        "unknown"
+    } else {
+        value
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+fn cpu_features() -> &'static [&'static str] {
+    &CPU_FEATURES
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn cpu_features() -> &'static [&'static str] {
+    &[]
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn is_detected(feature: &str) -> bool {
+    match feature {
+        "sse4.2" => std::is_x86_feature_detected!("sse4.2"),
+        "avx" => std::is_x86_feature_detected!("avx"),
+        "avx2" => std::is_x86_feature_detected!("avx2"),
+        "avx512f" => std::is_x86_feature_detected!("avx512f"),
+        "bmi2" => std::is_x86_feature_detected!("bmi2"),
+        "adx" => std::is_x86_feature_detected!("adx"),
+        _ => false,
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn is_detected(feature: &str) -> bool {
+    match feature {
+        "neon" => std::arch::is_aarch64_feature_detected!("neon"),
+        "aes" => std::arch::is_aarch64_feature_detected!("aes"),
+        "sha3" => std::arch::is_aarch64_feature_detected!("sha3"),
+        "sve" => std::arch::is_aarch64_feature_detected!("sve"),
+        _ => false,
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn is_detected(_feature: &str) -> bool {
+    false
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn report_records_build_info() {
+        let report = example::run(&vdf_example(), vdf_options());
+        let build = &report.build;
+        assert_eq!("0.3.0", build.winterfell_version);
+        assert!(build.rustc_version.starts_with("rustc "));
+        assert_ne!("unknown", build.profile);
+        // the program could not run on a CPU which lacks a feature the compiler relied on
+        for feature in build.target_features.iter() {
+            assert!(build.cpu_features.contains(feature), "{}", feature);
+        }
+        assert!(report.to_string().contains("winterfell 0.3.0"));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!("0.3.0", json["build"]["winterfell_version"]);
+    }
+}
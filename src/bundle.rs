@@ -0,0 +1,335 @@
+use crate::{
+    config::RunConfig,
+    error::ExampleError,
+    run_example,
+    verify::{verify_proof, VerifyError},
+};
+use log::info;
+use std::{
+    fmt, fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+use winter_utils::SliceReader;
+use winterfell::{ByteReader, ByteWriter};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes at the start of every bundle file.
+const BUNDLE_MAGIC: &[u8; 8] = b"STXBUNDL";
+
+/// Version of the bundle format written by [ProofBundle::to_bytes()].
+const BUNDLE_VERSION: u8 = 1;
+
+// PROOF BUNDLE
+// ================================================================================================
+
+/// A proof of one of the examples together with the public inputs it is verified against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleEntry {
+    /// Name of the example the proof is for.
+    pub example: String,
+    /// The serialized proof.
+    pub proof_bytes: Vec<u8>,
+    /// The serialized public inputs.
+    pub pub_inputs_bytes: Vec<u8>,
+}
+
+/// A collection of proofs which are stored in a single file and verified together.
+///
+/// A bundle is serialized as the magic bytes `STXBUNDL`, a format version byte, and the number
+/// of entries as a `u32`, followed by each entry: the example name prefixed by its length as a
+/// `u8`, and the proof and the public inputs, each prefixed by its length as a `u32`. All
+/// integers are little-endian.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofBundle {
+    pub entries: Vec<BundleEntry>,
+}
+
+impl ProofBundle {
+    /// Returns the serialized bundle.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.write_u8_slice(BUNDLE_MAGIC);
+        bytes.write_u8(BUNDLE_VERSION);
+        bytes.write_u32(self.entries.len() as u32);
+        for entry in self.entries.iter() {
+            bytes.write_u8(entry.example.len() as u8);
+            bytes.write_u8_slice(entry.example.as_bytes());
+            bytes.write_u32(entry.proof_bytes.len() as u32);
+            bytes.write_u8_slice(&entry.proof_bytes);
+            bytes.write_u32(entry.pub_inputs_bytes.len() as u32);
+            bytes.write_u8_slice(&entry.pub_inputs_bytes);
+        }
+        bytes
+    }
+
+    /// Parses a bundle from bytes written by [ProofBundle::to_bytes()].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = SliceReader::new(bytes);
+        let magic = reader.read_u8_array::<8>().map_err(|err| err.to_string())?;
+        if &magic != BUNDLE_MAGIC {
+            return Err("not a proof bundle".to_string());
+        }
+        let version = reader.read_u8().map_err(|err| err.to_string())?;
+        if version != BUNDLE_VERSION {
+            return Err(format!("unsupported bundle version {}", version));
+        }
+
+        let num_entries = reader.read_u32().map_err(|err| err.to_string())?;
+        let mut entries = Vec::new();
+        for index in 0..num_entries {
+            let entry = read_entry(&mut reader)
+                .map_err(|err| format!("failed to read entry {}: {}", index, err))?;
+            entries.push(entry);
+        }
+        if reader.has_more_bytes() {
+            return Err("unexpected trailing bytes".to_string());
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Writes the serialized bundle to the specified file.
+    pub fn write(&self, path: &Path) -> Result<(), ExampleError> {
+        fs::write(path, self.to_bytes()).map_err(|err| ExampleError::write("bundle", path, err))
+    }
+
+    /// Reads a bundle from the specified file.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the file cannot be read, and a
+    /// [Verification](ExampleError::Verification) error if it does not hold a bundle.
+    pub fn read(path: &Path) -> Result<Self, ExampleError> {
+        let bytes = fs::read(path).map_err(|err| ExampleError::read("bundle", path, err))?;
+        Self::from_bytes(&bytes).map_err(|err| {
+            VerifyError::MalformedProof(format!("bundle {}: {}", path.display(), err)).into()
+        })
+    }
+}
+
+// PROVING
+// ================================================================================================
+
+/// Proves `count` statements of the example selected in the config and collects the proofs
+/// into a bundle; statement `i` is the one selected by [RunConfig::statement()].
+///
+/// Returns an error if the config names an unknown example, or a
+/// [Verification](ExampleError::Verification) error if any proof is rejected by the verifier
+/// right after it is generated.
+pub fn prove_bundle(config: &RunConfig, count: usize) -> Result<ProofBundle, ExampleError> {
+    let mut bundle = ProofBundle::default();
+    for index in 0..count {
+        let now = Instant::now();
+        let report = run_example(&config.statement(index as u64))?;
+        if let Some(err) = report.verification_error {
+            return Err(
+                VerifyError::Rejected(format!("proof of statement {}: {}", index, err)).into(),
+            );
+        }
+        info!(
+            "Proved statement {} of {} in {} ms",
+            index + 1,
+            count,
+            now.elapsed().as_millis()
+        );
+        bundle.entries.push(BundleEntry {
+            example: config.example.clone(),
+            proof_bytes: report.proof_bytes,
+            pub_inputs_bytes: report.pub_inputs_bytes,
+        });
+    }
+    Ok(bundle)
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// A bundle entry which was not accepted by the verifier.
+#[derive(Debug, Clone)]
+pub struct BundleFailure {
+    /// Position of the entry in the bundle.
+    pub index: usize,
+    /// Name of the example the entry claims to be a proof of.
+    pub example: String,
+    /// Reason the entry was rejected.
+    pub reason: String,
+}
+
+/// Aggregate results of verifying every proof in a bundle.
+#[derive(Debug, Clone)]
+pub struct BundleReport {
+    /// Number of proofs in the bundle.
+    pub num_proofs: usize,
+    /// Total size of the serialized proofs in bytes.
+    pub proof_bytes: usize,
+    /// Time it took to verify all proofs.
+    pub total_time: Duration,
+    /// Longest time it took to verify a single proof.
+    pub max_time: Duration,
+    /// Entries which were rejected, in bundle order.
+    pub failures: Vec<BundleFailure>,
+}
+
+impl BundleReport {
+    /// Returns true if every proof in the bundle was verified successfully.
+    pub fn is_verified(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for BundleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in self.failures.iter() {
+            writeln!(
+                f,
+                "Proof {} ({}) was rejected: {}",
+                failure.index, failure.example, failure.reason
+            )?;
+        }
+        let mean_time = self.total_time.as_micros() as f64 / self.num_proofs.max(1) as f64;
+        writeln!(
+            f,
+            "Verified {} of {} proofs ({:.1} KB) in {:.1} ms",
+            self.num_proofs - self.failures.len(),
+            self.num_proofs,
+            self.proof_bytes as f64 / 1024f64,
+            self.total_time.as_micros() as f64 / 1000f64
+        )?;
+        write!(
+            f,
+            "Verification time per proof: {:.1} ms mean, {:.1} ms max",
+            mean_time / 1000f64,
+            self.max_time.as_micros() as f64 / 1000f64
+        )
+    }
+}
+
+/// Verifies every proof in the bundle and reports the failures together with aggregate
+/// statistics; a rejected proof does not stop the remaining ones from being verified.
+pub fn verify_bundle(bundle: &ProofBundle) -> BundleReport {
+    let mut report = BundleReport {
+        num_proofs: bundle.entries.len(),
+        proof_bytes: 0,
+        total_time: Duration::ZERO,
+        max_time: Duration::ZERO,
+        failures: Vec::new(),
+    };
+
+    for (index, entry) in bundle.entries.iter().enumerate() {
+        let now = Instant::now();
+        // winterfell panics rather than returning an error for some malformed proofs
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            verify_proof(&entry.example, &entry.proof_bytes, &entry.pub_inputs_bytes)
+        }));
+        let time = now.elapsed();
+
+        report.proof_bytes += entry.proof_bytes.len();
+        report.total_time += time;
+        report.max_time = report.max_time.max(time);
+        let reason = match result {
+            Ok(Ok(())) => continue,
+            Ok(Err(err)) => err.to_string(),
+            Err(_) => "verification panicked".to_string(),
+        };
+        report.failures.push(BundleFailure {
+            index,
+            example: entry.example.clone(),
+            reason,
+        });
+    }
+
+    report
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn read_entry(reader: &mut SliceReader) -> Result<BundleEntry, String> {
+    let name_len = reader.read_u8().map_err(|err| err.to_string())?;
+    let name = reader
+        .read_u8_vec(name_len as usize)
+        .map_err(|err| err.to_string())?;
+    let example = String::from_utf8(name).map_err(|_| "example name is not valid UTF-8")?;
+    let proof_len = reader.read_u32().map_err(|err| err.to_string())?;
+    let proof_bytes = reader
+        .read_u8_vec(proof_len as usize)
+        .map_err(|err| err.to_string())?;
+    let pub_inputs_len = reader.read_u32().map_err(|err| err.to_string())?;
+    let pub_inputs_bytes = reader
+        .read_u8_vec(pub_inputs_len as usize)
+        .map_err(|err| err.to_string())?;
+
+    Ok(BundleEntry {
+        example,
+        proof_bytes,
+        pub_inputs_bytes,
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{prove_bundle, verify_bundle, BundleEntry, ProofBundle};
+    use crate::{
+        config::{FibConfig, ProofOptionsConfig, RunConfig},
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn bundle_verifies_and_reports_failures() {
+        let config = RunConfig {
+            example: "fib".to_string(),
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            fib: FibConfig {
+                trace_length: 64,
+                ..FibConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let bundle = prove_bundle(&config, 3).unwrap();
+        assert_eq!(3, bundle.entries.len());
+        assert_ne!(bundle.entries[0].proof_bytes, bundle.entries[1].proof_bytes);
+
+        let bytes = bundle.to_bytes();
+        assert_eq!(bundle, ProofBundle::from_bytes(&bytes).unwrap());
+        assert!(ProofBundle::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(verify_bundle(&bundle).is_verified());
+
+        // a rejected proof is reported without stopping verification of the others
+        let mut tampered = bundle.clone();
+        tampered.entries[1].pub_inputs_bytes[0] ^= 1;
+        let report = verify_bundle(&tampered);
+        assert_eq!(3, report.num_proofs);
+        assert_eq!(1, report.failures.len());
+        assert_eq!(1, report.failures[0].index);
+    }
+
+    #[test]
+    fn mutated_bundles_are_rejected_without_panicking() {
+        // the same entry point as the bundle fuzz target, over truncated and oversized inputs
+        let report = example::run(&vdf_example(), vdf_options());
+        let bundle = ProofBundle {
+            entries: vec![BundleEntry {
+                example: "vdf".to_string(),
+                proof_bytes: report.proof_bytes.clone(),
+                pub_inputs_bytes: report.pub_inputs_bytes.clone(),
+            }],
+        }
+        .to_bytes();
+        for len in (0..bundle.len()).step_by(61) {
+            assert!(ProofBundle::from_bytes(&bundle[..len]).is_err());
+        }
+        // an entry count far beyond the size of the file
+        let mut mutated = bundle.clone();
+        mutated[9..13].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ProofBundle::from_bytes(&mutated).is_err());
+    }
+}
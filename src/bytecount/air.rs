@@ -0,0 +1,137 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::is_zero,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits of a byte of the text.
+pub const BYTE_BITS: usize = 8;
+
+/// Trace column layout. The columns from `BITS` on hold the bits of the byte of a row, least
+/// significant bit first; `INVERSE` holds the inverse of the difference between the byte and the
+/// counted byte (or zero where they are equal), `IS_MATCH` is one exactly where they are equal,
+/// and `COUNT` holds the number of matches in all previous rows.
+pub const BITS: usize = 0;
+pub const INVERSE: usize = BITS + BYTE_BITS;
+pub const IS_MATCH: usize = INVERSE + 1;
+pub const COUNT: usize = IS_MATCH + 1;
+pub const TRACE_WIDTH: usize = COUNT + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct ByteCountInputs<B: ExampleField> {
+    /// The byte whose occurrences are counted; never zero, since the text is padded with zeros.
+    pub byte: u8,
+    pub count: B,
+}
+
+impl<B: ExampleField> Serializable for ByteCountInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.byte);
+        target.write(self.count);
+    }
+}
+
+impl<B: ExampleField> Deserializable for ByteCountInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let byte = source.read_u8()?;
+        if byte == 0 {
+            return Err(DeserializationError::InvalidValue(
+                "the padding byte 0 cannot be counted".into(),
+            ));
+        }
+        Ok(ByteCountInputs {
+            byte,
+            count: B::read_from(source)?,
+        })
+    }
+}
+
+// BYTE COUNT AIR
+// ================================================================================================
+
+/// AIR of a private text, one byte per row, in which the occurrences of a public byte are
+/// counted.
+///
+/// The constraints of a row are:
+///
+/// * `b_i * b_i = b_i` for every bit of the byte, which range-checks the byte to `[0, 256)`;
+/// * the two constraints of the is-zero gadget of [crate::gadgets] on the difference between
+///   the byte and the counted byte, which make `IS_MATCH` one exactly where they are equal;
+/// * `count' = count + match`, which adds the flag to the running count.
+///
+/// Without the range check, a row could hold any field element, and the count would be over a
+/// sequence of field elements rather than a text. The rows after the text hold zero bytes,
+/// which never match, since the counted byte is not zero. The count starts at zero and ends
+/// with the public value.
+pub struct ByteCountAir<B: ExampleField> {
+    context: AirContext<B>,
+    byte: u8,
+    count: B,
+}
+
+impl<B: ExampleField> Air for ByteCountAir<B> {
+    type BaseField = B;
+    type PublicInputs = ByteCountInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: ByteCountInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![2; BYTE_BITS + 2];
+        degrees.push(1);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            byte: pub_inputs.byte,
+            count: pub_inputs.count,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let bits = &current[BITS..BITS + BYTE_BITS];
+        let is_match = current[IS_MATCH];
+
+        // every bit is binary
+        result.enforce_binary(BITS, bits);
+
+        // the flag is one exactly where the byte is the counted one
+        let difference = combine_bits(bits) - E::from(self.byte);
+        let [inverse, flag] = is_zero(difference, current[INVERSE], is_match);
+        result[INVERSE] = inverse;
+        result[IS_MATCH] = flag;
+
+        // the flag is added to the running count
+        result[COUNT] = are_equal(next[COUNT], current[COUNT] + is_match);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the count starts at zero and ends with the public value
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(COUNT, B::ZERO),
+            last_row_equals(COUNT, trace_length, self.count),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
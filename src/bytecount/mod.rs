@@ -0,0 +1,141 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::ByteCountConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate::MIN_TRACE_LENGTH,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{ByteCountAir, ByteCountInputs, BYTE_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::ByteCountProver;
+
+#[cfg(test)]
+mod tests;
+
+// BYTE COUNT EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the number of occurrences of a public character in a private text.
+///
+/// The text is held one byte per row, range-checked by a bit decomposition, and an is-equal
+/// flag built from the is-zero gadget of [crate::gadgets] marks the rows which hold the counted
+/// byte; see [ByteCountAir] for the constraints. Neither the text nor its length is revealed,
+/// beyond the bound which the length of the trace places on it.
+#[cfg(feature = "std")]
+pub struct ByteCountExample<B: ExampleField> {
+    text: Vec<u8>,
+    byte: u8,
+    count: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> ByteCountExample<B> {
+    pub fn new(config: &ByteCountConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let text = config.text.as_bytes().to_vec();
+        let byte = config.character as u8;
+
+        // compute result
+        let now = Instant::now();
+        let count = text.iter().filter(|&&value| value == byte).count();
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            text,
+            byte,
+            count: B::from(count as u64),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &ByteCountConfig) -> Result<(), String> {
+        // an ASCII character is a single byte of UTF-8 which is never part of another
+        // character, and the text is padded with zero bytes
+        if !config.character.is_ascii() || config.character == '\0' {
+            return Err(format!(
+                "counted character must be ASCII and not NUL, but bytecount.character is {:?}",
+                config.character
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for ByteCountExample<B> {
+    type Air = ByteCountAir<B>;
+    type Prover = ByteCountProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        ByteCountProver::build_trace(&self.text, self.byte, trace_length(self.text.len()))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = ByteCountConfig::default();
+        ExampleDescriptor::new(
+            "bytecount",
+            "the number of occurrences of a public character in a private text",
+        )
+        .with_default("text", config.text)
+        .with_default("character", config.character)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("BITS", BYTE_BITS),
+                ("INVERSE", 1),
+                ("IS_MATCH", 1),
+                ("COUNT", 1),
+            ],
+            constraints: vec![
+                ("binary bits", BYTE_BITS),
+                ("is-equal flag", 2),
+                ("count accumulation", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> ByteCountProver<B> {
+        ByteCountProver::new(options, self.byte)
+    }
+
+    fn pub_inputs(&self) -> ByteCountInputs<B> {
+        ByteCountInputs {
+            byte: self.byte,
+            count: self.count,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> ByteCountInputs<B> {
+        ByteCountInputs {
+            byte: self.byte,
+            count: self.count + B::ONE,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the length of a trace which holds a text of `text_len` bytes followed by at least
+/// one zero byte, since transition constraints do not cover the last row.
+#[cfg(feature = "std")]
+pub(crate) fn trace_length(text_len: usize) -> usize {
+    (text_len + 1).next_power_of_two().max(MIN_TRACE_LENGTH)
+}
@@ -0,0 +1,69 @@
+use super::{
+    air::{BITS, BYTE_BITS, COUNT, INVERSE, IS_MATCH, TRACE_WIDTH},
+    ByteCountAir, ByteCountInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{is_zero_witness, to_bits},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct ByteCountProver<B: ExampleField> {
+    options: ProofOptions,
+    byte: u8,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> ByteCountProver<B> {
+    pub fn new(options: ProofOptions, byte: u8) -> Self {
+        Self {
+            options,
+            byte,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which holds one byte of the text per row, followed by zero bytes up to
+    /// the specified length, and counts the rows which hold the specified byte.
+    pub fn build_trace(text: &[u8], byte: u8, trace_length: usize) -> TraceTable<B> {
+        assert!(
+            text.len() < trace_length,
+            "the last row of the trace must follow the text"
+        );
+        let mut columns = vec![vec![B::ZERO; trace_length]; TRACE_WIDTH];
+        let mut count = B::ZERO;
+        let padding = [0].iter().cycle().take(trace_length - text.len());
+        for (step, &value) in text.iter().chain(padding).enumerate() {
+            for (i, bit) in to_bits(value as u64, BYTE_BITS).enumerate() {
+                columns[BITS + i][step] = bit;
+            }
+            let (inverse, is_match) = is_zero_witness(B::from(value) - B::from(byte));
+            columns[INVERSE][step] = inverse;
+            columns[IS_MATCH][step] = is_match;
+            columns[COUNT][step] = count;
+            count += is_match;
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for ByteCountProver<B> {
+    type BaseField = B;
+    type Air = ByteCountAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> ByteCountInputs<B> {
+        ByteCountInputs {
+            byte: self.byte,
+            count: trace.get(COUNT, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
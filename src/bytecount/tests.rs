@@ -0,0 +1,120 @@
+use super::{
+    air::{BITS, BYTE_BITS, COUNT, IS_MATCH},
+    trace_length, ByteCountExample, ByteCountInputs, ByteCountProver,
+};
+use crate::{
+    config::ByteCountConfig,
+    example::{self, Example},
+    verify::verify_proof,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn bytecount_trace_decomposes_bytes_and_counts_matches() {
+    let text = b"abracadabra";
+    let trace = ByteCountProver::<BaseElement>::build_trace(text, b'a', 16);
+    assert_eq!(16, trace.length());
+    let mut count = 0;
+    for step in 0..16 {
+        let value = text.get(step).copied().unwrap_or(0);
+        for i in 0..BYTE_BITS {
+            assert_eq!(
+                BaseElement::from(((value >> i) & 1) as u64),
+                trace.get(BITS + i, step)
+            );
+        }
+        let is_match = u64::from(value == b'a');
+        assert_eq!(BaseElement::from(is_match), trace.get(IS_MATCH, step));
+        assert_eq!(BaseElement::from(count), trace.get(COUNT, step));
+        count += is_match;
+    }
+    assert_eq!(5, count);
+    assert_eq!(BaseElement::from(5u64), build_count("abracadabra", 'a'));
+    assert_eq!(BaseElement::ZERO, build_count("abracadabra", 'z'));
+}
+
+#[test]
+fn bytecount_wrong_flag_is_rejected() {
+    // claim that a row which holds the counted byte does not: the is-zero gadget then requires
+    // the inverse of a zero difference
+    let text = b"she sells sea shells";
+    let mut trace = ByteCountProver::<BaseElement>::build_trace(text, b's', 32);
+    let step = 4;
+    assert_eq!(BaseElement::ONE, trace.get(IS_MATCH, step));
+    trace.set(IS_MATCH, step, BaseElement::ZERO);
+    for later in step + 1..32 {
+        trace.set(COUNT, later, trace.get(COUNT, later) - BaseElement::ONE);
+    }
+    let pub_inputs = ByteCountInputs {
+        byte: b's',
+        count: trace.get(COUNT, 31),
+    };
+
+    let proof = ByteCountProver::new(build_options(), b's')
+        .prove(trace)
+        .unwrap();
+    assert!(
+        winterfell::verify::<<ByteCountExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn bytecount_trace_length_leaves_a_row_after_the_text() {
+    assert_eq!(8, trace_length(0));
+    assert_eq!(16, trace_length(15));
+    assert_eq!(32, trace_length(16));
+}
+
+#[test]
+fn bytecount_invalid_character_is_rejected() {
+    for character in ['\0', 'é', '→'] {
+        let config = ByteCountConfig {
+            character,
+            ..ByteCountConfig::default()
+        };
+        let err = ByteCountExample::<BaseElement>::validate(&config).unwrap_err();
+        assert!(err.contains("bytecount.character"), "{}", err);
+    }
+}
+
+#[test]
+fn bytecount_padding_byte_cannot_be_counted() {
+    let report = example::run(&build_example(), build_options());
+    let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+    pub_inputs_bytes[0] = 0;
+    assert!(verify_proof("bytecount", &report.proof_bytes, &pub_inputs_bytes).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> ByteCountExample<BaseElement> {
+    ByteCountExample::new(&ByteCountConfig::default()).unwrap()
+}
+
+fn build_count(text: &str, character: char) -> BaseElement {
+    ByteCountExample::<BaseElement>::new(&ByteCountConfig {
+        text: text.to_string(),
+        character,
+        ..ByteCountConfig::default()
+    })
+    .unwrap()
+    .pub_inputs()
+    .count
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
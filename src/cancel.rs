@@ -0,0 +1,215 @@
+//! Orderly interruption of long runs with Ctrl-C.
+//!
+//! Neither trace generation nor proving can be stopped from the outside: the trace is built in
+//! tight loops and the proof inside winterfell, possibly on many threads at once. Instead of
+//! leaving the terminal silent after a killed run, [install()] turns the first Ctrl-C into an
+//! orderly exit: a watcher thread reports the [Stage] the run was in, calls the hook set with
+//! [on_interrupt()] so that partial results are written out, and exits with status 130. Another
+//! Ctrl-C while this is under way exits at once.
+
+use crate::phases::{self, ProofPhase};
+use std::{
+    fmt,
+    io::{self, Write},
+    process,
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Exit status of a process which was interrupted by Ctrl-C, as set by shells.
+pub const INTERRUPTED_STATUS: i32 = 130;
+
+/// How often the watcher thread checks whether Ctrl-C was pressed.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Set once Ctrl-C is pressed after [install()].
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Set once the watcher thread has started to report an interruption.
+static REPORTING: AtomicBool = AtomicBool::new(false);
+
+/// The [Stage] of the current run.
+static STAGE: AtomicU8 = AtomicU8::new(Stage::Idle as u8);
+
+/// Called by the watcher thread before it exits; see [on_interrupt()].
+#[allow(clippy::type_complexity)]
+static HOOK: Mutex<Option<Box<dyn FnOnce() + Send>>> = Mutex::new(None);
+
+// STAGES
+// ================================================================================================
+
+/// The part of an example run which is in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// No trace is being built or proven, such as while the inputs of an example are prepared
+    /// or between the runs of a benchmark.
+    Idle = 0,
+    /// The execution trace is being built.
+    BuildingTrace = 1,
+    /// The proof is being generated.
+    Proving = 2,
+    /// The proof is being verified.
+    Verifying = 3,
+}
+
+impl Stage {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Stage::BuildingTrace,
+            2 => Stage::Proving,
+            3 => Stage::Verifying,
+            _ => Stage::Idle,
+        }
+    }
+}
+
+/// Records that the current run has moved on to the specified stage.
+pub fn enter(stage: Stage) {
+    STAGE.store(stage as u8, Ordering::Relaxed);
+}
+
+// INTERRUPTION
+// ================================================================================================
+
+/// Where a run was when it was interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interruption {
+    /// Stage the run was in.
+    pub stage: Stage,
+    /// Phase of the prover which reported progress last; `None` outside of proving, if no phase
+    /// was complete yet, or if prover phases are not recorded (see [phases::record()]).
+    pub last_phase: Option<ProofPhase>,
+}
+
+impl Interruption {
+    /// Returns where the current run is.
+    pub fn current() -> Self {
+        let stage = Stage::from_u8(STAGE.load(Ordering::Relaxed));
+        let last_phase = match stage {
+            Stage::Proving => phases::last_completed(),
+            _ => None,
+        };
+        Self { stage, last_phase }
+    }
+}
+
+impl fmt::Display for Interruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.stage, self.last_phase) {
+            (Stage::Idle, _) => write!(f, "Interrupted before the execution trace was built"),
+            (Stage::BuildingTrace, _) => {
+                write!(f, "Interrupted while building the execution trace")
+            }
+            (Stage::Proving, None) => write!(f, "Interrupted while proving"),
+            (Stage::Proving, Some(phase)) => write!(
+                f,
+                "Interrupted while proving, after the prover completed {}",
+                phase
+            ),
+            (Stage::Verifying, _) => write!(f, "Interrupted while verifying the proof"),
+        }
+    }
+}
+
+/// Sets a function which is called when the run is interrupted, before the process exits; it
+/// replaces any function set before.
+///
+/// The hook runs on the watcher thread while the rest of the program is still running, so any
+/// results it writes out must be shared with it behind a lock.
+pub fn on_interrupt(hook: impl FnOnce() + Send + 'static) {
+    *HOOK.lock().unwrap_or_else(|err| err.into_inner()) = Some(Box::new(hook));
+}
+
+/// Removes the function set with [on_interrupt()].
+pub fn clear_hook() {
+    HOOK.lock().unwrap_or_else(|err| err.into_inner()).take();
+}
+
+/// Handles Ctrl-C for the rest of the process: the first one reports the [Interruption], calls
+/// the hook set with [on_interrupt()], and exits with [INTERRUPTED_STATUS]; one which is pressed
+/// while that is under way exits immediately.
+///
+/// Returns an error if the signal handler cannot be installed on this platform.
+pub fn install() -> Result<(), String> {
+    install_handler()?;
+    thread::Builder::new()
+        .name("interrupt watcher".to_string())
+        .spawn(watch)
+        .map(|_| ())
+        .map_err(|err| format!("failed to start the interrupt watcher: {}", err))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn watch() {
+    while !INTERRUPTED.load(Ordering::SeqCst) {
+        thread::sleep(POLL_INTERVAL);
+    }
+    REPORTING.store(true, Ordering::SeqCst);
+    // a closed terminal must not keep the partial results from being written
+    let _ = io::stdout().flush();
+    let _ = writeln!(io::stderr(), "{}", Interruption::current());
+    let hook = HOOK.lock().unwrap_or_else(|err| err.into_inner()).take();
+    if let Some(hook) = hook {
+        hook();
+    }
+    let _ = io::stdout().flush();
+    process::exit(INTERRUPTED_STATUS);
+}
+
+#[cfg(any(unix, windows))]
+fn install_handler() -> Result<(), String> {
+    extern "C" fn handle_interrupt(_signal: libc::c_int) {
+        // only async-signal-safe functions may be called here; a signal which is delivered
+        // twice in quick succession, as `timeout` does, is counted once
+        if REPORTING.load(Ordering::SeqCst) {
+            unsafe { libc::_exit(INTERRUPTED_STATUS) };
+        }
+        INTERRUPTED.store(true, Ordering::SeqCst);
+    }
+
+    let handler = handle_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t;
+    if unsafe { libc::signal(libc::SIGINT, handler) } == libc::SIG_ERR {
+        return Err("failed to install the Ctrl-C handler".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn install_handler() -> Result<(), String> {
+    Err("Ctrl-C cannot be handled on this platform".to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Interruption, Stage};
+    use crate::phases::ProofPhase;
+
+    #[test]
+    fn interruption_names_stage_and_phase() {
+        let interruption = |stage, last_phase| Interruption { stage, last_phase }.to_string();
+        assert_eq!(
+            "Interrupted while building the execution trace",
+            interruption(Stage::BuildingTrace, None)
+        );
+        assert_eq!(
+            "Interrupted while proving",
+            interruption(Stage::Proving, None)
+        );
+        assert_eq!(
+            "Interrupted while proving, after the prover completed trace LDE",
+            interruption(Stage::Proving, Some(ProofPhase::TraceLde))
+        );
+    }
+}
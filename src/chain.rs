@@ -0,0 +1,241 @@
+//! Proofs of a long Fibonacci computation split into chunks which are linked through their
+//! public inputs.
+//!
+//! Each chunk of the `fibchunk` example is proven on its own, starting from the state the
+//! previous chunk ends in, and the proofs are stored in a [ProofBundle] in chain order. A
+//! verifier accepts the whole computation if every proof verifies and every chunk starts where
+//! the one before it ends. This is the manual counterpart of recursive proof composition: the
+//! prover never holds more than one chunk in memory, but the verifier checks, and receives,
+//! one proof per chunk.
+
+use crate::{
+    bundle::{self, BundleEntry, BundleReport, ProofBundle},
+    compose::read_inputs,
+    config::RunConfig,
+    error::ExampleError,
+    example::{self, Example},
+    fibchunk::FibChunkExample,
+    field::{ExampleField, FieldType},
+    verify::VerifyError,
+};
+use log::info;
+use std::{fmt, time::Instant};
+use winterfell::{
+    math::fields::{f128, f62, f64},
+    StarkProof,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Name of the example every proof in a chain is a proof of.
+const CHUNK_EXAMPLE: &str = "fibchunk";
+
+// CHAIN REPORT
+// ================================================================================================
+
+/// The computation which a chain of chunk proofs proves as a whole.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainSpan {
+    /// Base field the proofs are over.
+    pub field: FieldType,
+    /// Number of Fibonacci steps over all chunks.
+    pub num_steps: usize,
+    /// State the first chunk starts from.
+    pub start: [String; 2],
+    /// State the last chunk ends in.
+    pub end: [String; 2],
+}
+
+/// Outcome of verifying the proofs of a chain together with the links between them.
+#[derive(Debug, Clone)]
+pub struct ChainReport {
+    /// Outcome of verifying every proof of the chain on its own.
+    pub proofs: BundleReport,
+    /// The computation the chain proves; `None` if the chunks are not linked.
+    pub span: Option<ChainSpan>,
+    /// Reason the chunks are not linked into a single computation; `None` if every chunk starts
+    /// where the one before it ends.
+    pub link_error: Option<String>,
+}
+
+impl ChainReport {
+    /// Returns true if every proof was verified successfully and the chunks are linked.
+    pub fn is_verified(&self) -> bool {
+        self.proofs.is_verified() && self.link_error.is_none()
+    }
+}
+
+impl fmt::Display for ChainReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.proofs)?;
+        match (&self.span, &self.link_error) {
+            (Some(span), None) => write!(
+                f,
+                "Chain linked: {} chunks prove {} steps in {} from ({}, {}) to ({}, {})",
+                self.proofs.num_proofs,
+                span.num_steps,
+                span.field,
+                span.start[0],
+                span.start[1],
+                span.end[0],
+                span.end[1]
+            ),
+            (_, Some(err)) => write!(f, "Chain rejected: {}", err),
+            (None, None) => unreachable!("a chain is either linked or rejected"),
+        }
+    }
+}
+
+// PROVING
+// ================================================================================================
+
+/// Proves `num_chunks` chunks of the Fibonacci sequence set up in the `fibchunk` section of the
+/// config, each one starting from the state the previous one ends in, and collects the proofs
+/// into a bundle in chain order.
+///
+/// The `example` setting is ignored. Returns a [Config](ExampleError::Config) error if the
+/// chain has no chunks or the `fibchunk` section cannot be proven, and a
+/// [Verification](ExampleError::Verification) error if any proof is rejected by the verifier
+/// right after it is generated.
+pub fn prove_chain(config: &RunConfig, num_chunks: usize) -> Result<ProofBundle, ExampleError> {
+    if num_chunks == 0 {
+        return Err(ExampleError::Config(
+            "a chain needs at least one chunk".to_string(),
+        ));
+    }
+    match config.field {
+        FieldType::F128 => prove_chain_in_field::<f128::BaseElement>(config, num_chunks),
+        FieldType::F64 => prove_chain_in_field::<f64::BaseElement>(config, num_chunks),
+        FieldType::F62 => prove_chain_in_field::<f62::BaseElement>(config, num_chunks),
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies every proof in a bundle written by [prove_chain()], and checks that all of them are
+/// proofs of chunks over the same base field and that every chunk starts in the state the
+/// previous one ends in.
+pub fn verify_chain(bundle: &ProofBundle) -> ChainReport {
+    let proofs = bundle::verify_bundle(bundle);
+    let (span, link_error) = match check_links(bundle) {
+        Ok(span) => (Some(span), None),
+        Err(err) => (None, Some(err)),
+    };
+    ChainReport {
+        proofs,
+        span,
+        link_error,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove_chain_in_field<B: ExampleField>(
+    config: &RunConfig,
+    num_chunks: usize,
+) -> Result<ProofBundle, ExampleError> {
+    let options = config
+        .proof_options_for(&config.fibchunk.proof_options)
+        .to_proof_options();
+    let trace_length = config.fibchunk.trace_length;
+
+    let mut bundle = ProofBundle::default();
+    let mut chunk = FibChunkExample::<B>::new(&config.fibchunk)?;
+    for index in 0..num_chunks {
+        let now = Instant::now();
+        let report = example::run(&chunk, options.clone());
+        if let Some(err) = report.verification_error {
+            return Err(VerifyError::Rejected(format!("proof of chunk {}: {}", index, err)).into());
+        }
+        info!(
+            "Proved chunk {} of {} in {} ms",
+            index + 1,
+            num_chunks,
+            now.elapsed().as_millis()
+        );
+        bundle.entries.push(BundleEntry {
+            example: CHUNK_EXAMPLE.to_string(),
+            proof_bytes: report.proof_bytes,
+            pub_inputs_bytes: report.pub_inputs_bytes,
+        });
+
+        // the public output of each chunk becomes the public input of the next one
+        chunk = FibChunkExample::with_start(chunk.pub_inputs().end, trace_length);
+    }
+    Ok(bundle)
+}
+
+fn check_links(bundle: &ProofBundle) -> Result<ChainSpan, String> {
+    if bundle.entries.is_empty() {
+        return Err("the chain has no chunks".to_string());
+    }
+
+    let mut field = None;
+    let mut num_steps = 0;
+    for (index, entry) in bundle.entries.iter().enumerate() {
+        if entry.example != CHUNK_EXAMPLE {
+            return Err(format!(
+                "chunk {} is a proof of example '{}' rather than '{}'",
+                index, entry.example, CHUNK_EXAMPLE
+            ));
+        }
+        let proof = StarkProof::from_bytes(&entry.proof_bytes)
+            .map_err(|err| format!("failed to parse proof of chunk {}: {}", index, err))?;
+        let chunk_field = FieldType::from_modulus_bytes(proof.context.field_modulus_bytes())
+            .ok_or_else(|| format!("chunk {} is over an unsupported base field", index))?;
+        if *field.get_or_insert(chunk_field) != chunk_field {
+            return Err(format!(
+                "chunk {} is over a different base field than chunk 0",
+                index
+            ));
+        }
+        num_steps += proof.context.trace_length() - 1;
+    }
+
+    let field = field.expect("the chain has chunks");
+    let (start, end) = match field {
+        FieldType::F128 => check_links_in_field::<f128::BaseElement>(bundle)?,
+        FieldType::F64 => check_links_in_field::<f64::BaseElement>(bundle)?,
+        FieldType::F62 => check_links_in_field::<f62::BaseElement>(bundle)?,
+    };
+    Ok(ChainSpan {
+        field,
+        num_steps,
+        start,
+        end,
+    })
+}
+
+/// Returns the state the first chunk starts from and the state the last chunk ends in.
+fn check_links_in_field<B: ExampleField>(
+    bundle: &ProofBundle,
+) -> Result<([String; 2], [String; 2]), String> {
+    let inputs = bundle
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            read_inputs::<FibChunkExample<B>>(&entry.pub_inputs_bytes)
+                .map_err(|err| format!("chunk {}: {}", index, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (index, pair) in inputs.windows(2).enumerate() {
+        if pair[0].end != pair[1].start {
+            return Err(format!(
+                "chunk {} does not start where chunk {} ends",
+                index + 1,
+                index
+            ));
+        }
+    }
+
+    let first = inputs.first().expect("the chain has chunks");
+    let last = inputs.last().expect("the chain has chunks");
+    Ok((
+        first.start.map(|value| value.to_string()),
+        last.end.map(|value| value.to_string()),
+    ))
+}
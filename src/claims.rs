@@ -0,0 +1,248 @@
+//! Boundary assertions supplied by the user rather than fixed by an AIR.
+//!
+//! A claim states that a register of an example holds a value at a step of the execution trace.
+//! The `vm` and `fixedexp` examples accept a list of claims in their config section, or from a
+//! JSON file passed with `--claims <file>`. The claims travel with the public inputs as
+//! [ClaimedValue]s, and the AIR turns each of them into a single assertion, so that the same AIR
+//! proves different statements about a computation without being changed. A claim which does
+//! not hold makes the proof fail to verify.
+
+use crate::field::ExampleField;
+use winter_utils::collections::Vec;
+use winterfell::{Assertion, ByteReader, ByteWriter, DeserializationError, Serializable};
+
+#[cfg(feature = "std")]
+use crate::config::RunConfig;
+#[cfg(feature = "std")]
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Names of the examples which accept claims.
+pub const CLAIM_EXAMPLES: [&str; 2] = ["vm", "fixedexp"];
+
+// CLAIMED VALUES
+// ================================================================================================
+
+/// The value which a claim asserts for a column of the execution trace at a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimedValue<B: ExampleField> {
+    pub column: usize,
+    pub step: usize,
+    pub value: B,
+}
+
+impl<B: ExampleField> ClaimedValue<B> {
+    fn is_at(&self, column: usize, step: usize) -> bool {
+        self.column == column && self.step == step
+    }
+}
+
+impl<B: ExampleField> Serializable for ClaimedValue<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.column as u8);
+        target.write_u32(self.step as u32);
+        target.write(self.value);
+    }
+}
+
+/// Writes the number of claimed values followed by the values.
+pub fn write_claimed_values<B: ExampleField, W: ByteWriter>(
+    claims: &[ClaimedValue<B>],
+    target: &mut W,
+) {
+    target.write_u32(claims.len() as u32);
+    target.write(claims);
+}
+
+/// Reads values written by [write_claimed_values()].
+///
+/// Returns an error if a value is claimed for a column which is not in `columns`, or if a cell
+/// is claimed twice, since winterfell rejects overlapping assertions.
+pub fn read_claimed_values<B: ExampleField, R: ByteReader>(
+    source: &mut R,
+    columns: &[usize],
+) -> Result<Vec<ClaimedValue<B>>, DeserializationError> {
+    let num_claims = source.read_u32()? as usize;
+    let mut claims = Vec::<ClaimedValue<B>>::new();
+    for _ in 0..num_claims {
+        let claim = ClaimedValue {
+            column: source.read_u8()? as usize,
+            step: source.read_u32()? as usize,
+            value: B::read_from(source)?,
+        };
+        if !columns.contains(&claim.column) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "column {} cannot be claimed",
+                claim.column
+            )));
+        }
+        if claims
+            .iter()
+            .any(|other| other.is_at(claim.column, claim.step))
+        {
+            return Err(DeserializationError::InvalidValue(format!(
+                "column {} is claimed twice at step {}",
+                claim.column, claim.step
+            )));
+        }
+        claims.push(claim);
+    }
+    Ok(claims)
+}
+
+/// Returns a single assertion for every claimed value.
+///
+/// # Panics
+/// Panics if a value is claimed for a step outside of a trace of `trace_length` steps.
+pub fn claim_assertions<B: ExampleField>(
+    claims: &[ClaimedValue<B>],
+    trace_length: usize,
+) -> Vec<Assertion<B>> {
+    claims
+        .iter()
+        .map(|claim| {
+            assert!(
+                claim.step < trace_length,
+                "step {} of claim is outside of a trace of {} steps",
+                claim.step,
+                trace_length
+            );
+            Assertion::single(claim.column, claim.step, claim.value)
+        })
+        .collect()
+}
+
+// CLAIMS
+// ================================================================================================
+
+/// A claim as written in a config section or a claims file.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Claim {
+    /// Name of the register, such as `r0` for the `vm` example.
+    pub register: String,
+    /// Step of the execution trace at which the register holds the value.
+    pub step: usize,
+    pub value: u64,
+}
+
+/// Checks that claims on the named registers of an example can be turned into assertions.
+///
+/// `registers` names the columns which can be claimed, and `is_asserted` tells which cells the
+/// AIR of the example asserts already; `key` names the claims in error messages, such as
+/// `vm.claims`.
+///
+/// Returns an error if a claim names an unknown register, lies outside of a trace of
+/// `trace_length` steps, or claims a cell which is asserted already or claimed before.
+#[cfg(feature = "std")]
+pub fn check_claims(
+    key: &str,
+    claims: &[Claim],
+    registers: &[(&str, usize)],
+    trace_length: usize,
+    is_asserted: impl Fn(usize, usize) -> bool,
+) -> Result<(), String> {
+    let mut cells = Vec::with_capacity(claims.len());
+    for (i, claim) in claims.iter().enumerate() {
+        let column = find_register(claim, registers).ok_or_else(|| {
+            let names = registers.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            format!(
+                "{}[{}] names register '{}', but only {} can be claimed",
+                key,
+                i,
+                claim.register,
+                names.join(", ")
+            )
+        })?;
+        if claim.step >= trace_length {
+            return Err(format!(
+                "{}[{}] is at step {}, outside of a trace of {} steps",
+                key, i, claim.step, trace_length
+            ));
+        }
+        if is_asserted(column, claim.step) {
+            return Err(format!(
+                "{}[{}] claims {} at step {}, which the AIR asserts already",
+                key, i, claim.register, claim.step
+            ));
+        }
+        if cells.contains(&(column, claim.step)) {
+            return Err(format!(
+                "{}[{}] claims {} at step {} a second time",
+                key, i, claim.register, claim.step
+            ));
+        }
+        cells.push((column, claim.step));
+    }
+    Ok(())
+}
+
+/// Returns the values asserted by claims on the named registers of an example.
+///
+/// # Panics
+/// Panics if a claim names a register which is not in `registers`; see [check_claims()].
+#[cfg(feature = "std")]
+pub fn to_claimed_values<B: ExampleField>(
+    claims: &[Claim],
+    registers: &[(&str, usize)],
+) -> Vec<ClaimedValue<B>> {
+    claims
+        .iter()
+        .map(|claim| ClaimedValue {
+            column: find_register(claim, registers)
+                .unwrap_or_else(|| panic!("unknown register '{}'", claim.register)),
+            step: claim.step,
+            value: B::from(claim.value),
+        })
+        .collect()
+}
+
+// LOADING
+// ================================================================================================
+
+/// Reads a JSON array of claims from the specified file, and replaces the claims of the example
+/// selected in the config with them.
+///
+/// Returns an error if the file cannot be read or parsed, or if the selected example does not
+/// accept claims.
+#[cfg(feature = "std")]
+pub fn apply_claims(config: &mut RunConfig, path: &Path) -> Result<(), String> {
+    let source = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read claims {}: {}", path.display(), err))?;
+    apply_claims_source(config, &source, &path.display().to_string())
+}
+
+/// Same as [apply_claims()], but takes the contents of the claims file; `origin` names the
+/// file in error messages.
+#[cfg(feature = "std")]
+pub fn apply_claims_source(
+    config: &mut RunConfig,
+    source: &str,
+    origin: &str,
+) -> Result<(), String> {
+    let claims = serde_json::from_str::<Vec<Claim>>(source)
+        .map_err(|err| format!("failed to parse claims {}: {}", origin, err))?;
+    match config.example.as_str() {
+        "vm" => config.vm.claims = claims,
+        "fixedexp" => config.fixedexp.claims = claims,
+        example => return Err(format!("example '{}' does not accept claims", example)),
+    }
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the column of the register named by the claim.
+#[cfg(feature = "std")]
+fn find_register(claim: &Claim, registers: &[(&str, usize)]) -> Option<usize> {
+    registers
+        .iter()
+        .find(|(name, _)| *name == claim.register)
+        .map(|&(_, column)| column)
+}
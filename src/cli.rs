@@ -0,0 +1,1530 @@
+//! The command line interface of the `stark-examples` binary.
+//!
+//! The binary only installs a [TrackingAllocator](crate::memory::TrackingAllocator) and calls
+//! [main()]; a crate which registers examples of its own (see [plugins](crate::plugins)) gets
+//! the same commands for them by doing the same.
+
+use clap::{Args, Parser, Subcommand};
+use log::{warn, LevelFilter};
+
+#[cfg(feature = "profiling")]
+use crate::profiling;
+#[cfg(feature = "progress")]
+use crate::progress;
+use crate::{
+    artifacts::{ArtifactKind, ArtifactStore},
+    bench,
+    bundle::{self, ProofBundle},
+    cancel, chain, claims, compose,
+    config::{ProofOptionsConfig, RunConfig},
+    constraints, degrees,
+    describe::{self, AirDescription},
+    emulator,
+    encoding::Encoding,
+    error::ExampleError,
+    estimate::{self, TraceShape},
+    example::{self, Example, RunReport},
+    exercises, explain,
+    field::FieldType,
+    html, inspect,
+    phases::PhaseLogger,
+    plugins::DynExample,
+    presets::Preset,
+    proof_file::ProofFile,
+    pub_input_digest::PubInputDigest,
+    registry::{self, ProofRegistry},
+    security::SecurityBound,
+    soak::{self, SoakCase, SoakOutcome},
+    stream, tamper,
+    trace::{self, ChunkedTraceBuilder, RowRange},
+    tune::{self, TuneTarget},
+    verification_key::VerificationKey,
+    verify::{verify_proof, VerifyError},
+    with_example, witness, ExampleCommand,
+};
+#[cfg(feature = "progress")]
+use std::io::IsTerminal;
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use winterfell::ProofOptions;
+
+// COMMAND LINE INTERFACE
+// ================================================================================================
+
+#[derive(Parser)]
+#[command(
+    name = "stark-examples",
+    about = "Toy STARK examples built with winterfell"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Report the progress of each proving phase; `RUST_LOG` can be used for finer filtering
+    #[arg(long, short, global = true)]
+    verbose: bool,
+    /// Number of threads the prover uses; sets `RAYON_NUM_THREADS`, and defaults to one thread
+    /// per core
+    #[cfg(feature = "concurrent")]
+    #[arg(long, global = true)]
+    threads: Option<usize>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate and verify a proof for an example
+    Run(RunArgs),
+    /// Generate a proof for an example and write it to standard output as base64
+    Prove(ProveArgs),
+    /// Measure how proof generation responds to changes in proof options
+    Bench(BenchArgs),
+    /// Compare run reports written as JSON
+    Report {
+        #[command(subcommand)]
+        command: ReportCommand,
+    },
+    /// Recommend proof options which reach a target security level at the lowest prover cost
+    Tune(TuneArgs),
+    /// Tune proof options for several security levels and compare them side by side
+    CompareSecurity(CompareSecurityArgs),
+    /// Prove an example with each hash function and compare proof sizes and security levels,
+    /// marking the levels which the collision resistance of the hash caps
+    CompareHash {
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Verify a proof against its public inputs, with the parameters fixed by a verification key
+    /// or taken from the proof
+    Verify(VerifyArgs),
+    /// Export or show verification keys
+    Vk {
+        #[command(subcommand)]
+        command: VkCommand,
+    },
+    /// Prove many statements of an example and store the proofs in a single bundle file
+    ProveBundle(ProveBundleArgs),
+    /// Verify every proof in a bundle file and report aggregate statistics
+    VerifyBundle {
+        /// File containing a bundle written by `prove-bundle`
+        bundle: PathBuf,
+    },
+    /// Prove a long Fibonacci computation as a chain of chunks, each starting where the previous
+    /// one ends, and store the proofs in a single bundle file
+    ProveChain(ProveChainArgs),
+    /// Verify every proof in a chain file and check that each chunk starts where the previous
+    /// one ends
+    VerifyChain {
+        /// File containing a chain written by `prove-chain`
+        chain: PathBuf,
+    },
+    /// Prove a Rescue hash chain and a Poseidon hash chain seeded with its digest, and verify
+    /// both proofs together with the link between their public inputs
+    Compose {
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Prove an example, then verify the proof as verifiers would which assume other proof
+    /// options or another trace shape than the proof was generated with, and show why each of
+    /// them rejects it
+    DemoBinding {
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Commit to a set of proof files with a Merkle tree over their hashes and write the root
+    /// together with the path which opens every proof
+    CommitProofs {
+        /// Proof files to commit to
+        #[arg(required = true)]
+        proofs: Vec<PathBuf>,
+        /// File to write the registry to
+        #[arg(long, default_value = "registry.json")]
+        output: PathBuf,
+    },
+    /// Check that a proof file is committed to by a registry root, using the path recorded for
+    /// it by `commit-proofs`
+    VerifyRegistered {
+        /// Proof file to check
+        proof: PathBuf,
+        /// Root of the registry as a hex string, as printed by `commit-proofs`
+        #[arg(long)]
+        root: String,
+        /// File containing the registry written by `commit-proofs`
+        #[arg(long, default_value = "registry.json")]
+        registry: PathBuf,
+    },
+    /// Prove and verify randomly drawn examples, fields, sizes, inputs, and proof options in a
+    /// loop, and report every case which fails
+    Soak(SoakArgs),
+    /// Check that the declared degree of every transition constraint matches its actual degree
+    CheckDegrees(CheckDegreesArgs),
+    /// Run the hidden tests of an exercise against the AIR written for it and report the first
+    /// constraint or assertion which is wrong
+    CheckExercise {
+        /// Name of the exercise (fib, counter, or hashchain)
+        name: String,
+    },
+    /// List the examples with their descriptions, trace widths, numbers of constraints, and
+    /// default settings
+    List,
+    /// Describe the columns, transition constraints, and boundary assertions of an example
+    Describe(DescribeArgs),
+    /// Predict the size of a proof from the shape of its trace without building the trace
+    Estimate(EstimateArgs),
+    /// List the files which `run --artifacts` wrote into the artifacts directory
+    Artifacts {
+        /// Directory of the artifacts; defaults to the cache directory of the platform
+        #[arg(long)]
+        artifacts_dir: Option<PathBuf>,
+    },
+    /// Print a breakdown of the components of a serialized proof
+    Inspect {
+        /// File containing a proof written by `run --save-proof`
+        proof: PathBuf,
+    },
+    /// Serve HTTP endpoints which prove and verify the examples
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: std::net::SocketAddr,
+        /// Number of seconds after which a request fails
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Serve a gRPC service which proves and verifies the examples
+    #[cfg(feature = "grpc")]
+    ServeGrpc {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:50051")]
+        addr: std::net::SocketAddr,
+        /// Number of seconds after which a request fails
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum VkCommand {
+    /// Write the example, field, trace shape, and proof options of a config into a verification
+    /// key file
+    Export(VkExportArgs),
+    /// Print the parameters fixed by a verification key file
+    Show {
+        /// File containing a key written by `vk export`
+        key: PathBuf,
+    },
+}
+
+/// Arguments which select an example, its inputs, and the field it is instantiated in.
+#[derive(Args, Default)]
+struct ConfigArgs {
+    /// TOML file with example settings and proof options
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Base field of the computation; overrides the field set in the config file
+    #[arg(long, value_enum)]
+    field: Option<FieldType>,
+    /// JSON file with the inputs of the example; overrides the inputs set in the config file
+    #[arg(long)]
+    witness: Option<PathBuf>,
+    /// JSON file with values which registers of the example hold at given steps, asserted in
+    /// addition to those the AIR asserts; overrides the claims set in the config file
+    #[arg(long)]
+    claims: Option<PathBuf>,
+    /// Seed of the random number generator which draws the inputs of the example; overrides
+    /// the seed set in the config file
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Named set of proof options on which the options set in the config file and on the
+    /// command line are applied; overrides the preset set in the config file
+    #[arg(long, value_enum)]
+    preset: Option<Preset>,
+    /// Number of queries; overrides the number of queries set anywhere in the config file
+    #[arg(long)]
+    num_queries: Option<usize>,
+    /// Blowup factor of the trace extension; overrides the blowup factor set anywhere in the
+    /// config file
+    #[arg(long)]
+    blowup_factor: Option<usize>,
+    /// Number of bits of proof-of-work; overrides the grinding factor set anywhere in the config
+    /// file
+    #[arg(long)]
+    grinding_factor: Option<u32>,
+    /// Factor by which each FRI layer reduces the degree (4, 8, or 16); overrides the FRI
+    /// folding factor set anywhere in the config file
+    #[arg(long)]
+    fri_folding_factor: Option<usize>,
+    /// Largest FRI remainder (a power of two from 32 to 1024); overrides the FRI max remainder
+    /// size set anywhere in the config file
+    #[arg(long)]
+    fri_max_remainder_size: Option<usize>,
+}
+
+#[derive(Args, Default)]
+#[command(group(clap::ArgGroup::new("saved").multiple(true).args(["save_proof", "save_inputs"])))]
+struct RunArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// File into which the serialized proof is written
+    #[arg(long)]
+    save_proof: Option<PathBuf>,
+    /// File into which the serialized public inputs are written
+    #[arg(long)]
+    save_inputs: Option<PathBuf>,
+    /// File into which the BLAKE3-256 digest of the serialized public inputs is written as hex,
+    /// for `verify --digest`
+    #[arg(long)]
+    save_digest: Option<PathBuf>,
+    /// Write the files of `--save-proof` and `--save-inputs`, at least one of which is required,
+    /// as text in this encoding instead of binary; a saved proof is read back and verified again
+    #[arg(long, requires = "saved")]
+    encode: Option<Encoding>,
+    /// Write the proof, the public inputs, and the report as JSON into the artifacts directory,
+    /// under names with the time of the run
+    #[arg(long)]
+    artifacts: bool,
+    /// Directory of the artifacts; defaults to the cache directory of the platform
+    #[arg(long, requires = "artifacts")]
+    artifacts_dir: Option<PathBuf>,
+    /// Security bound which is checked against `--min-security`
+    #[arg(long, value_enum, default_value_t = SecurityBound::Conjectured)]
+    security: SecurityBound,
+    /// Fail the run if the proof provides fewer bits of security than this
+    #[arg(long)]
+    min_security: Option<u32>,
+    /// Print the run report as JSON instead of text; no progress bars are drawn
+    #[arg(long)]
+    json: bool,
+    /// Check that tampered proofs, traces, and public inputs are rejected instead of running
+    /// normally
+    #[arg(long)]
+    tamper: bool,
+    /// Compare every row of the execution trace against an emulator of the computation and
+    /// report the first divergence instead of running normally
+    #[arg(long, conflicts_with = "tamper")]
+    check_trace: bool,
+    /// Evaluate every transition constraint and boundary assertion over the execution trace and
+    /// report the first ones which fail instead of running normally
+    #[arg(long, conflicts_with_all = ["tamper", "check_trace"])]
+    check_constraints: bool,
+    /// Directory into which completed chunks of the execution trace are written; a run which
+    /// was interrupted before proving resumes trace generation from the chunks found there
+    #[arg(long)]
+    checkpoint_dir: Option<PathBuf>,
+    /// Number of trace rows in each checkpointed chunk; must be a power of two
+    #[arg(long, default_value_t = trace::DEFAULT_CHUNK_LENGTH, requires = "checkpoint_dir")]
+    chunk_length: usize,
+    /// File into which the execution trace is written as CSV before proving, with a column per
+    /// register and a row per step
+    #[arg(long)]
+    dump_trace: Option<PathBuf>,
+    /// Rows of the trace to write with `--dump-trace`, as START..END (end exclusive) or START..
+    #[arg(long, default_value = "0..", requires = "dump_trace")]
+    dump_rows: RowRange,
+    /// Verify the proof this many more times and report the mean, median, and 99th percentile
+    /// of the verification time
+    #[arg(long)]
+    verify_iters: Option<usize>,
+    /// Deserialize the proof in every iteration of `--verify-iters` instead of only once
+    #[arg(long, requires = "verify_iters")]
+    verify_deserialize: bool,
+    /// File into which a sampling profile of proving and verifying is written: a flamegraph if
+    /// the file name ends in `.svg`, and folded stacks otherwise
+    #[cfg(feature = "profiling")]
+    #[arg(long)]
+    profile_out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+#[command(group(clap::ArgGroup::new("verifier").required(true).args(["vk", "example"])))]
+struct VerifyArgs {
+    /// File containing a proof written by `run --save-proof`
+    proof: PathBuf,
+    /// File containing the public inputs written by `run --save-inputs`
+    inputs: PathBuf,
+    /// Verification key written by `vk export`; proofs whose field, trace shape, or proof
+    /// options differ from the key are rejected
+    #[arg(long)]
+    vk: Option<PathBuf>,
+    /// Name of the example the proof is for, if no key is given; the proof is then accepted
+    /// under whatever options it was generated with
+    #[arg(long)]
+    example: Option<String>,
+    /// Digest written by `run --save-digest`; the public inputs are rejected unless they hash
+    /// to it
+    #[arg(long)]
+    digest: Option<String>,
+    /// Verify the proof stage by stage, from the commitments to the FRI queries, and print what
+    /// each stage checked
+    #[arg(long, conflicts_with = "vk")]
+    explain: bool,
+    /// Read the proof and the public inputs as text in this encoding, as written by `run
+    /// --encode`
+    #[arg(long)]
+    encode: Option<Encoding>,
+}
+
+#[derive(Args)]
+struct VkExportArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// File to write the key to
+    #[arg(long, default_value = "example.vk")]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct ProveArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Read one JSON request per line from standard input, each naming an example under
+    /// `example` next to its settings, and write one base64 proof per line
+    #[arg(long, conflicts_with = "config")]
+    stdin: bool,
+}
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true)]
+struct BenchArgs {
+    #[command(subcommand)]
+    command: Option<BenchCommand>,
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Comma-separated list of grinding factors to prove the example with
+    #[arg(long, value_delimiter = ',', default_values_t = bench::DEFAULT_GRINDING_FACTORS)]
+    grinding: Vec<u32>,
+    /// Compare proof sizes under gzip and zstd compression instead of sweeping grinding factors
+    #[cfg(feature = "compression")]
+    #[arg(long)]
+    compression: bool,
+    /// Prove the example with every number of threads from 1 to this one and report the speedup
+    /// over a single thread, instead of sweeping grinding factors
+    #[cfg(feature = "concurrent")]
+    #[arg(long)]
+    max_threads: Option<usize>,
+    /// Sweep trace lengths instead of grinding factors, and write an HTML report of prover time
+    /// and proof size vs. trace length into this file
+    #[arg(long, group = "sweep")]
+    html: Option<PathBuf>,
+    /// Comma-separated list of base fields to prove the example in and compare prover time
+    /// across, instead of sweeping grinding factors
+    #[arg(long, value_enum, value_delimiter = ',', group = "sweep")]
+    fields: Vec<FieldType>,
+    /// Comma-separated list of examples to sweep; defaults to the example selected in the config
+    #[arg(long, value_delimiter = ',', requires = "sweep")]
+    examples: Vec<String>,
+    /// Number of times the trace length is doubled, starting from the size set in the config
+    #[arg(long, default_value_t = bench::DEFAULT_SWEEP_STEPS, requires = "html")]
+    steps: u32,
+    /// Number of runs before the measured ones of every configuration, whose times are discarded
+    #[arg(long, default_value_t = bench::DEFAULT_WARMUP)]
+    warmup: usize,
+    /// Number of measured runs of every configuration, whose times are averaged
+    #[arg(long, default_value_t = bench::DEFAULT_SAMPLES)]
+    samples: usize,
+    /// Comma-separated list of CPU cores to pin the benchmark to; only supported on Linux
+    #[arg(long, value_delimiter = ',')]
+    pin_cores: Vec<usize>,
+}
+
+impl BenchArgs {
+    fn repetitions(&self) -> bench::Repetitions {
+        bench::Repetitions {
+            warmup: self.warmup,
+            samples: self.samples,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum BenchCommand {
+    /// Merge run reports written as JSON, such as by `run --artifacts`, into a table of the mean
+    /// measurements of every example, trace length, and build
+    Merge {
+        /// JSON files with run reports
+        #[arg(required = true)]
+        reports: Vec<PathBuf>,
+        /// File into which the table is also written as CSV
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReportCommand {
+    /// Print how the measurements of a run report changed from those of an earlier one, and exit
+    /// with an error if any of them regressed
+    Diff {
+        /// JSON file with the earlier report, such as one written by `run --artifacts`
+        before: PathBuf,
+        /// JSON file with the later report
+        after: PathBuf,
+        /// Percentage by which a time, proof size, or peak memory may grow before it is flagged
+        /// as a regression; any drop of a security level is flagged
+        #[arg(long, default_value_t = bench::DEFAULT_REGRESSION_THRESHOLD)]
+        threshold: f64,
+    },
+}
+
+#[derive(Args)]
+#[command(group(clap::ArgGroup::new("length").required(true).args(["hours", "case"])))]
+struct SoakArgs {
+    /// Number of hours to run cases for; may be fractional
+    #[arg(long)]
+    hours: Option<f64>,
+    /// Seed from which the seeds of the cases are drawn; defaults to the current time
+    #[arg(long, requires = "hours")]
+    seed: Option<u64>,
+    /// Run only the case with this seed, as printed with a failure
+    #[arg(long)]
+    case: Option<u64>,
+}
+
+#[derive(Args)]
+struct CheckDegreesArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Seed of the random trace over which the constraints are evaluated
+    #[arg(long, default_value_t = 0)]
+    trace_seed: u64,
+}
+
+#[derive(Args)]
+struct DescribeArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Print the description as a graphviz graph instead of text
+    #[arg(long)]
+    dot: bool,
+    /// Write the description into this file instead of printing it
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct EstimateArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Number of columns in the execution trace
+    #[arg(long)]
+    trace_width: usize,
+    /// Number of rows in the execution trace; must be a power of two
+    #[arg(long)]
+    trace_length: usize,
+    /// Highest degree of a transition constraint, counting each periodic column it uses as one
+    #[arg(long, default_value_t = 2)]
+    constraint_degree: usize,
+}
+
+#[derive(Args)]
+struct TuneArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Security level in bits which the recommended options must reach
+    #[arg(long, default_value_t = tune::DEFAULT_TARGET_SECURITY)]
+    target_security: u32,
+    /// Security bound under which the target is checked
+    #[arg(long, value_enum, default_value_t = SecurityBound::Conjectured)]
+    security: SecurityBound,
+    /// Largest acceptable proof size in KB
+    #[arg(long)]
+    max_proof_size: Option<usize>,
+    /// Comma-separated list of grinding factors to consider
+    #[arg(long, value_delimiter = ',', default_values_t = tune::DEFAULT_GRINDING_FACTORS)]
+    grinding: Vec<u32>,
+}
+
+#[derive(Args)]
+struct CompareSecurityArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Comma-separated list of security levels in bits to compare
+    #[arg(long, value_delimiter = ',', default_values_t = tune::DEFAULT_SECURITY_LEVELS)]
+    levels: Vec<u32>,
+    /// Security bound under which the levels are checked
+    #[arg(long, value_enum, default_value_t = SecurityBound::Conjectured)]
+    security: SecurityBound,
+    /// Comma-separated list of grinding factors to consider
+    #[arg(long, value_delimiter = ',', default_values_t = tune::DEFAULT_GRINDING_FACTORS)]
+    grinding: Vec<u32>,
+}
+
+#[derive(Args)]
+struct ProveBundleArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Number of statements to prove; each one uses a different seed
+    #[arg(long, default_value_t = 100)]
+    count: usize,
+    /// File into which the bundle is written
+    #[arg(long)]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct ProveChainArgs {
+    #[command(flatten)]
+    config: ConfigArgs,
+    /// Number of chunks the computation is split into; each one is as long as the trace length
+    /// set in the `fibchunk` section of the config
+    #[arg(long, default_value_t = 4)]
+    chunks: usize,
+    /// File into which the chain is written
+    #[arg(long)]
+    output: PathBuf,
+}
+
+// MAIN FUNCTION
+// ================================================================================================
+
+/// Runs the command given by the arguments of the process, and exits the process if it fails.
+pub fn main() {
+    let cli = Cli::parse();
+    // `prove` writes proofs to standard output, so that they can be piped, and `list` its
+    // listing; both log elsewhere
+    let log_target = match cli.command {
+        Some(Command::Prove(_)) | Some(Command::List) => env_logger::Target::Stderr,
+        _ => env_logger::Target::Stdout,
+    };
+    init_logger(cli.verbose, log_target);
+    if let Err(err) = cancel::install() {
+        warn!("{}; Ctrl-C exits without a report", err);
+    }
+    #[cfg(feature = "concurrent")]
+    if let Some(threads) = cli.threads {
+        if threads == 0 {
+            fail(ExampleError::Config(
+                "number of threads must be positive".to_string(),
+            ));
+        }
+        // the global thread pool reads this when the prover first uses it
+        std::env::set_var("RAYON_NUM_THREADS", threads.to_string());
+    }
+
+    match cli.command {
+        None => {
+            let config = RunConfig::default();
+            let command = RunCommand {
+                args: &RunArgs::default(),
+                example: &config.example,
+            };
+            execute(&config, command);
+        }
+        Some(Command::Run(args)) => {
+            let config = load_config(&args.config);
+            let command = RunCommand {
+                args: &args,
+                example: &config.example,
+            };
+            execute(&config, command);
+        }
+        Some(Command::Prove(args)) => {
+            let result = if args.stdin {
+                stream::prove_lines(io::stdin().lock(), io::stdout().lock()).map(|_| ())
+            } else {
+                stream::prove_config(&load_config(&args.config))
+                    .map(|report| println!("{}", stream::encode_proof(&report)))
+            };
+            if let Err(err) = result {
+                fail(err);
+            }
+        }
+        Some(Command::Bench(BenchArgs {
+            command: Some(BenchCommand::Merge { reports, csv }),
+            ..
+        })) => match bench::merge_reports(&reports) {
+            Ok(merged) => {
+                println!("{}", merged);
+                if let Some(path) = csv {
+                    save("merged reports", &path, merged.to_csv().as_bytes());
+                }
+            }
+            Err(err) => fail(err),
+        },
+        Some(Command::Report {
+            command:
+                ReportCommand::Diff {
+                    before,
+                    after,
+                    threshold,
+                },
+        }) => match bench::diff_reports(&before, &after, threshold) {
+            Ok(diff) => {
+                println!("{}", diff);
+                if !diff.regressions().is_empty() {
+                    process::exit(1);
+                }
+            }
+            Err(err) => fail(err),
+        },
+        Some(Command::Bench(args)) => {
+            if args.samples == 0 {
+                fail(ExampleError::Config(
+                    "number of samples must be positive".to_string(),
+                ));
+            }
+            // the affinity must be set before the first proof starts the thread pool
+            if !args.pin_cores.is_empty() {
+                if let Err(err) = bench::pin_to_cores(&args.pin_cores) {
+                    fail(ExampleError::Config(err));
+                }
+            }
+            let config = load_config(&args.config);
+            let examples = if args.examples.is_empty() {
+                vec![config.example.clone()]
+            } else {
+                args.examples.clone()
+            };
+            if let Some(path) = &args.html {
+                // an interrupted sweep still writes a report of the sizes it has measured
+                let points = Arc::new(Mutex::new(Vec::new()));
+                let partial = (points.clone(), path.clone());
+                cancel::on_interrupt(move || {
+                    let (points, path) = partial;
+                    let points = points.lock().unwrap_or_else(|err| err.into_inner());
+                    if !points.is_empty() {
+                        let report = html::sweep_report(&points);
+                        save("partial sweep report", &path, report.as_bytes());
+                    }
+                });
+                if let Err(err) = bench::trace_length_sweep_into(
+                    &config,
+                    &examples,
+                    args.steps,
+                    args.repetitions(),
+                    &points,
+                ) {
+                    fail(err);
+                }
+                cancel::clear_hook();
+                let points = points.lock().expect("sweep is complete");
+                save("sweep report", path, html::sweep_report(&points).as_bytes());
+            } else if !args.fields.is_empty() {
+                if let Err(err) =
+                    bench::field_sweep(&config, &examples, &args.fields, args.repetitions())
+                {
+                    fail(err);
+                }
+            } else {
+                execute(&config, &args);
+            }
+        }
+        Some(Command::Tune(args)) => execute(&load_config(&args.config), &args),
+        Some(Command::CompareSecurity(args)) => execute(&load_config(&args.config), &args),
+        Some(Command::CompareHash { config }) => execute(&load_config(&config), CompareHashCommand),
+        Some(Command::Verify(args)) => match verify(&args) {
+            Ok(()) => println!("Proof verified"),
+            Err(err) => fail(err),
+        },
+        Some(Command::Vk { command }) => match command {
+            VkCommand::Export(args) => {
+                let config = load_config(&args.config);
+                let command = VkExportCommand {
+                    output: &args.output,
+                    example: &config.example,
+                };
+                execute(&config, command);
+            }
+            VkCommand::Show { key } => match VerificationKey::read(&key) {
+                Ok(key) => println!("{}", key),
+                Err(err) => fail(err),
+            },
+        },
+        Some(Command::ProveBundle(args)) => {
+            let config = load_config(&args.config);
+            let bundle = bundle::prove_bundle(&config, args.count).unwrap_or_else(|err| fail(err));
+            if let Err(err) = bundle.write(&args.output) {
+                fail(err);
+            }
+            println!(
+                "Wrote {} proofs to {}",
+                bundle.entries.len(),
+                args.output.display()
+            );
+        }
+        Some(Command::VerifyBundle { bundle }) => {
+            let bundle = ProofBundle::read(&bundle).unwrap_or_else(|err| fail(err));
+            let report = bundle::verify_bundle(&bundle);
+            println!("{}", report);
+            if !report.is_verified() {
+                process::exit(1);
+            }
+        }
+        Some(Command::ProveChain(args)) => {
+            let config = load_config(&args.config);
+            let bundle = chain::prove_chain(&config, args.chunks).unwrap_or_else(|err| fail(err));
+            if let Err(err) = bundle.write(&args.output) {
+                fail(err);
+            }
+            println!(
+                "Wrote a chain of {} chunks to {}",
+                bundle.entries.len(),
+                args.output.display()
+            );
+        }
+        Some(Command::VerifyChain { chain }) => {
+            let bundle = ProofBundle::read(&chain).unwrap_or_else(|err| fail(err));
+            let report = chain::verify_chain(&bundle);
+            println!("{}", report);
+            if !report.is_verified() {
+                process::exit(1);
+            }
+        }
+        Some(Command::CommitProofs { proofs, output }) => {
+            let registry = registry::commit_files(&proofs).unwrap_or_else(|err| fail(err));
+            if let Err(err) = registry.write(&output) {
+                fail(err);
+            }
+            println!("{}", registry);
+            println!("Wrote registry to {}", output.display());
+        }
+        Some(Command::VerifyRegistered {
+            proof,
+            root,
+            registry,
+        }) => {
+            let result = read_file("proof", &proof).and_then(|bytes| {
+                let registry = ProofRegistry::read(&registry)?;
+                let entry = registry.find(&bytes).ok_or_else(|| {
+                    VerifyError::Rejected(format!("{} is not in the registry", proof.display()))
+                })?;
+                registry::verify_membership(&root, &bytes, entry).map_err(VerifyError::Rejected)?;
+                Ok(entry.index)
+            });
+            match result {
+                Ok(index) => println!(
+                    "{} is leaf {} of the registry with root {}",
+                    proof.display(),
+                    index,
+                    root
+                ),
+                Err(err) => fail(err),
+            }
+        }
+        Some(Command::Compose { config }) => {
+            let report = compose::compose(&load_config(&config)).unwrap_or_else(|err| fail(err));
+            println!("{}", report);
+            if !report.is_verified() {
+                process::exit(1);
+            }
+        }
+        Some(Command::Soak(args)) => {
+            if let Some(seed) = args.case {
+                let case = SoakCase::from_seed(seed);
+                println!("{}", case);
+                match case.run() {
+                    SoakOutcome::Verified => println!("Proof verified"),
+                    SoakOutcome::Skipped(err) => println!("Skipped: {}", err),
+                    SoakOutcome::Failed(err) => {
+                        eprintln!("Failed: {}", err);
+                        process::exit(1);
+                    }
+                }
+                return;
+            }
+            let hours = args.hours.expect("clap requires hours or a case");
+            if hours.is_nan() || hours <= 0f64 {
+                fail(ExampleError::Config(
+                    "number of hours must be positive".to_string(),
+                ));
+            }
+            let seed = args.seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_nanos() as u64)
+            });
+            println!("Drawing cases from seed {}", seed);
+            let duration = Duration::from_secs_f64(hours * 3600f64);
+            let summary = soak::soak(duration, seed, |case, outcome| match outcome {
+                SoakOutcome::Verified => println!("verified {}", case),
+                SoakOutcome::Skipped(err) => println!("skipped  {}: {}", case, err),
+                SoakOutcome::Failed(err) => eprintln!(
+                    "FAILED   {}: {}\n         reproduce with `soak --case {}`",
+                    case, err, case.seed
+                ),
+            });
+            println!("{}", summary);
+            if !summary.is_passed() {
+                process::exit(1);
+            }
+        }
+        Some(Command::DemoBinding { config }) => execute(&load_config(&config), DemoBindingCommand),
+        Some(Command::CheckDegrees(args)) => execute(&load_config(&args.config), &args),
+        Some(Command::CheckExercise { name }) => {
+            let report = exercises::check_exercise(&name)
+                .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+            println!("{}", report);
+            if !report.is_passed() {
+                process::exit(1);
+            }
+        }
+        Some(Command::List) => match describe::list_examples() {
+            Ok(summaries) => {
+                let summaries = summaries.iter().map(|summary| summary.to_string());
+                println!("{}", summaries.collect::<Vec<_>>().join("\n\n"));
+            }
+            Err(err) => fail(err),
+        },
+        Some(Command::Describe(args)) => {
+            let config = load_config(&args.config);
+            let command = DescribeCommand {
+                args: &args,
+                example: &config.example,
+            };
+            execute(&config, command);
+        }
+        Some(Command::Estimate(args)) => {
+            let config = load_config(&args.config);
+            let shape = TraceShape {
+                width: args.trace_width,
+                length: args.trace_length,
+                constraint_degree: args.constraint_degree,
+                field: config.field,
+            };
+            let options = config
+                .proof_options_for(&ProofOptionsConfig::default())
+                .to_proof_options();
+            match estimate::estimate(&shape, &options) {
+                Ok(estimate) => println!("{}", estimate),
+                Err(err) => fail(ExampleError::Config(err)),
+            }
+        }
+        Some(Command::Artifacts { artifacts_dir }) => {
+            let store = open_artifacts(artifacts_dir.as_deref());
+            let entries = store.entries().unwrap_or_else(|err| fail(err));
+            println!("Artifacts in {}:", store.dir().display());
+            for entry in entries.iter() {
+                println!("{}", entry);
+            }
+        }
+        Some(Command::Inspect { proof }) => {
+            if let Err(err) = inspect::inspect(&proof) {
+                fail(err);
+            }
+        }
+        #[cfg(feature = "serve")]
+        Some(Command::Serve { addr, timeout }) => {
+            let timeout = std::time::Duration::from_secs(timeout);
+            if let Err(err) = crate::serve::serve(addr, timeout) {
+                fail(err);
+            }
+        }
+        #[cfg(feature = "grpc")]
+        Some(Command::ServeGrpc { addr, timeout }) => {
+            let timeout = std::time::Duration::from_secs(timeout);
+            if let Err(err) = crate::grpc::serve(addr, timeout) {
+                fail(err);
+            }
+        }
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies the proof named in the arguments of the `verify` command.
+fn verify(args: &VerifyArgs) -> Result<(), ExampleError> {
+    let proof_bytes = read_encoded("proof", &args.proof, args.encode)?;
+    let pub_inputs_bytes = read_encoded("public inputs", &args.inputs, args.encode)?;
+    if let Some(digest) = &args.digest {
+        PubInputDigest::from_hex(digest)
+            .map_err(ExampleError::Config)?
+            .check(&pub_inputs_bytes)
+            .map_err(VerifyError::Rejected)?;
+    }
+    if args.explain {
+        let example = args.example.as_ref().expect("clap requires an example");
+        // the AIRs assert on public inputs which do not fit the proof, as in verify_proof()
+        let explanation =
+            tamper::catch_panic(|| explain::explain(example, &proof_bytes, &pub_inputs_bytes))
+                .map_err(VerifyError::Rejected)??;
+        print!("{}", explanation);
+        return match explanation.failed_stage() {
+            Some(stage) => Err(VerifyError::Rejected(format!("{} failed", stage.name)).into()),
+            None => Ok(()),
+        };
+    }
+    let verdict = match (&args.vk, &args.example) {
+        (Some(key), _) => VerificationKey::read(key)?.verify(&proof_bytes, &pub_inputs_bytes),
+        (None, Some(example)) => verify_proof(example, &proof_bytes, &pub_inputs_bytes),
+        (None, None) => unreachable!("clap requires a key or an example"),
+    };
+    Ok(verdict?)
+}
+
+// EXAMPLE COMMANDS
+// ================================================================================================
+
+/// A run of the named example with the arguments of the `run` command.
+struct RunCommand<'a> {
+    args: &'a RunArgs,
+    example: &'a str,
+}
+
+impl ExampleCommand for RunCommand<'_> {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        if self.args.verify_iters == Some(0) {
+            fail(ExampleError::Config(
+                "number of verifier iterations must be positive".to_string(),
+            ));
+        }
+        let options = options.to_proof_options();
+        // the store is opened up front, so that a run does not end with nowhere to write
+        let store = self
+            .args
+            .artifacts
+            .then(|| open_artifacts(self.args.artifacts_dir.as_deref()));
+        if self.args.tamper {
+            let num_accepted = tamper::run(example, options);
+            if num_accepted > 0 {
+                eprintln!("{} tampered statements were accepted", num_accepted);
+                process::exit(1);
+            }
+            return;
+        }
+        if self.args.check_trace {
+            let check = emulator::check_trace(example)
+                .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+            println!("{}", check);
+            if !check.is_match() {
+                process::exit(1);
+            }
+            return;
+        }
+        if self.args.check_constraints {
+            let check = constraints::check_constraints(example, options)
+                .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+            println!("{}", check);
+            if !check.is_satisfied() {
+                process::exit(1);
+            }
+            return;
+        }
+
+        if let Some(path) = &self.args.dump_trace {
+            let rows = trace::dump_csv(&example.build_trace(), self.args.dump_rows, path)
+                .unwrap_or_else(|err| fail(err));
+            println!(
+                "Wrote execution trace rows {}..{} to {}",
+                rows.start,
+                rows.end,
+                path.display()
+            );
+        }
+
+        #[cfg(feature = "profiling")]
+        let profiler = self
+            .args
+            .profile_out
+            .as_ref()
+            .map(|_| profiling::Profiler::start().unwrap_or_else(|err| fail(err)));
+        let report = match &self.args.checkpoint_dir {
+            Some(dir) => {
+                if !self.args.chunk_length.is_power_of_two() {
+                    fail(ExampleError::Config(
+                        "chunk length must be a power of two".to_string(),
+                    ));
+                }
+                let builder = ChunkedTraceBuilder::new(dir, self.args.chunk_length);
+                example::run_with_checkpoints(example, options, &builder)
+            }
+            None => self.prove(example, options),
+        }
+        .unwrap_or_else(|err| fail(err));
+        #[cfg(feature = "profiling")]
+        if let (Some(profiler), Some(path)) = (profiler, &self.args.profile_out) {
+            match profiler.write(path) {
+                Ok(num_samples) => println!(
+                    "Wrote profile of {} samples to {}",
+                    num_samples,
+                    path.display()
+                ),
+                Err(err) => fail(err),
+            }
+        }
+        self.print_report(&report);
+        if let Some(iterations) = self.args.verify_iters {
+            match bench::verifier_latency(
+                example,
+                &report.proof_bytes,
+                iterations,
+                self.args.verify_deserialize,
+            ) {
+                Ok(latency) => println!("{}", latency),
+                Err(err) => fail(err),
+            }
+        }
+        self.save_outputs(&report, store);
+        self.check_verified(&report);
+    }
+
+    fn execute_registered(
+        self,
+        example: &dyn DynExample,
+        options: ProofOptionsConfig,
+    ) -> Result<(), ExampleError> {
+        let unsupported = [
+            (self.args.checkpoint_dir.is_some(), "--checkpoint-dir"),
+            (self.args.dump_trace.is_some(), "--dump-trace"),
+            (self.args.verify_iters.is_some(), "--verify-iters"),
+            #[cfg(feature = "profiling")]
+            (self.args.profile_out.is_some(), "--profile-out"),
+        ];
+        if let Some((_, flag)) = unsupported.iter().find(|(is_set, _)| *is_set) {
+            return Err(ExampleError::Config(format!(
+                "{} needs an example of this crate, but '{}' is registered by another crate",
+                flag, self.example
+            )));
+        }
+        let options = options.to_proof_options();
+        let store = self
+            .args
+            .artifacts
+            .then(|| open_artifacts(self.args.artifacts_dir.as_deref()));
+        if self.args.tamper {
+            let num_accepted = example.tamper(options);
+            if num_accepted > 0 {
+                eprintln!("{} tampered statements were accepted", num_accepted);
+                process::exit(1);
+            }
+            return Ok(());
+        }
+        if self.args.check_trace || self.args.check_constraints {
+            let check = if self.args.check_trace {
+                example.check_trace()
+            } else {
+                example.check_constraints(options)
+            };
+            let check = check.map_err(ExampleError::Config)?;
+            println!("{}", check.summary);
+            if !check.passed {
+                process::exit(1);
+            }
+            return Ok(());
+        }
+
+        let report = example.run(options);
+        self.print_report(&report);
+        self.save_outputs(&report, store);
+        self.check_verified(&report);
+        Ok(())
+    }
+}
+
+impl RunCommand<'_> {
+    /// Proves and verifies the example, with progress bars if the report goes to a terminal.
+    fn prove<E: Example>(
+        &self,
+        example: &E,
+        options: ProofOptions,
+    ) -> Result<RunReport, ExampleError> {
+        #[cfg(feature = "progress")]
+        if !self.args.json && io::stdout().is_terminal() {
+            return progress::run_with_progress(example, options);
+        }
+        example::try_run(example, options)
+    }
+
+    /// Exits if the verifier rejected the proof of the run; its outputs are written first, so
+    /// that the rejected proof can be inspected.
+    fn check_verified(&self, report: &RunReport) {
+        if let Some(err) = &report.verification_error {
+            fail(VerifyError::Rejected(err.clone()).into());
+        }
+    }
+
+    /// Prints the report of a run, and exits if the proof is below the minimum security level.
+    fn print_report(&self, report: &RunReport) {
+        if self.args.json {
+            let json = serde_json::to_string_pretty(report).expect("report is serializable");
+            println!("{}", json);
+        } else {
+            println!("{}", report);
+        }
+        if let Some(min_security) = self.args.min_security {
+            let security = report.security_level(self.args.security);
+            if security < min_security {
+                eprintln!(
+                    "Proof provides {} bits of {} security, which is below the minimum of {} bits",
+                    security, self.args.security, min_security
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Writes the proof, public inputs, digest, and artifacts of a run which were asked for.
+    fn save_outputs(&self, report: &RunReport, store: Option<ArtifactStore>) {
+        if let Some(path) = &self.args.save_proof {
+            let proof_file = ProofFile::new(self.example, report.proof_bytes.clone())
+                .unwrap_or_else(|err| fail(ExampleError::Proving(err)));
+            save_encoded("proof", path, &proof_file.to_bytes(), self.args.encode);
+            if let Some(encoding) = self.args.encode {
+                // the text is what gets pasted elsewhere, so it is checked rather than the bytes
+                let verdict = encoding.read_file("proof", path).and_then(|bytes| {
+                    Ok(verify_proof(
+                        self.example,
+                        &bytes,
+                        &report.pub_inputs_bytes,
+                    )?)
+                });
+                match verdict {
+                    Ok(_) => println!("Verified the proof read back from {}", path.display()),
+                    Err(err) => fail(err),
+                }
+            }
+        }
+        if let Some(path) = &self.args.save_inputs {
+            save_encoded(
+                "public inputs",
+                path,
+                &report.pub_inputs_bytes,
+                self.args.encode,
+            );
+        }
+        if let Some(path) = &self.args.save_digest {
+            let digest = PubInputDigest::of(&report.pub_inputs_bytes);
+            save(
+                "public input digest",
+                path,
+                format!("{}\n", digest).as_bytes(),
+            );
+        }
+        if let Some(store) = store {
+            let proof_file = ProofFile::new(self.example, report.proof_bytes.clone())
+                .unwrap_or_else(|err| fail(ExampleError::Proving(err)));
+            let report_json = serde_json::to_vec_pretty(&report).expect("report is serializable");
+            for (kind, bytes) in [
+                (ArtifactKind::Proof, proof_file.to_bytes()),
+                (ArtifactKind::PublicInputs, report.pub_inputs_bytes.clone()),
+                (ArtifactKind::Report, report_json),
+            ] {
+                match store.write(self.example, kind, &bytes) {
+                    Ok(path) => println!("Wrote {} to {}", kind, path.display()),
+                    Err(err) => fail(err),
+                }
+            }
+        }
+    }
+}
+
+impl ExampleCommand for &BenchArgs {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        #[cfg(feature = "compression")]
+        if self.compression {
+            bench::compression_comparison(example, &options);
+            return;
+        }
+        #[cfg(feature = "concurrent")]
+        if let Some(max_threads) = self.max_threads {
+            if max_threads == 0 {
+                fail(ExampleError::Config(
+                    "number of threads must be positive".to_string(),
+                ));
+            }
+            if let Err(err) =
+                bench::thread_sweep(example, &options, max_threads, self.repetitions())
+            {
+                fail(err);
+            }
+            return;
+        }
+        bench::grinding_sweep(example, &options, &self.grinding, self.repetitions());
+    }
+
+    fn execute_registered(
+        self,
+        example: &dyn DynExample,
+        options: ProofOptionsConfig,
+    ) -> Result<(), ExampleError> {
+        #[cfg(feature = "compression")]
+        if self.compression {
+            example.compression_comparison(&options);
+            return Ok(());
+        }
+        #[cfg(feature = "concurrent")]
+        if let Some(max_threads) = self.max_threads {
+            if max_threads == 0 {
+                return Err(ExampleError::Config(
+                    "number of threads must be positive".to_string(),
+                ));
+            }
+            return example.thread_sweep(&options, max_threads, self.repetitions());
+        }
+        example.grinding_sweep(&options, &self.grinding, self.repetitions());
+        Ok(())
+    }
+}
+
+impl ExampleCommand for &CheckDegreesArgs {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        let report = degrees::check_degrees(example, options.to_proof_options(), self.trace_seed);
+        println!("{}", report);
+        if !report.is_consistent() {
+            process::exit(1);
+        }
+    }
+}
+
+/// Verification of a proof of the example under changed proof contexts.
+struct DemoBindingCommand;
+
+impl ExampleCommand for DemoBindingCommand {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        let num_unexpected = tamper::demo_binding(example, options.to_proof_options());
+        if num_unexpected > 0 {
+            eprintln!(
+                "{} verdicts did not match the context they were reached under",
+                num_unexpected
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Export of the verification key of the named example into a file.
+struct VkExportCommand<'a> {
+    output: &'a Path,
+    example: &'a str,
+}
+
+impl ExampleCommand for VkExportCommand<'_> {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        let key = VerificationKey::new(self.example, example, options.to_proof_options());
+        if let Err(err) = key.write(self.output) {
+            fail(err);
+        }
+        println!("{}", key);
+        println!("Wrote verification key to {}", self.output.display());
+    }
+}
+
+/// A description of the named example with the arguments of the `describe` command.
+struct DescribeCommand<'a> {
+    args: &'a DescribeArgs,
+    example: &'a str,
+}
+
+impl ExampleCommand for DescribeCommand<'_> {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        let description = describe::describe(self.example, example, options.to_proof_options())
+            .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+        self.print(&description);
+    }
+
+    fn execute_registered(
+        self,
+        example: &dyn DynExample,
+        options: ProofOptionsConfig,
+    ) -> Result<(), ExampleError> {
+        let description = example
+            .describe(options.to_proof_options())
+            .map_err(ExampleError::Config)?;
+        self.print(&description);
+        Ok(())
+    }
+}
+
+impl DescribeCommand<'_> {
+    /// Prints the description, or writes it into the output file of the arguments.
+    fn print(&self, description: &AirDescription) {
+        let text = if self.args.dot {
+            description.to_dot()
+        } else {
+            format!("{}\n", description)
+        };
+        match &self.args.output {
+            Some(path) => save("description", path, text.as_bytes()),
+            None => print!("{}", text),
+        }
+    }
+}
+
+impl ExampleCommand for &TuneArgs {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        let target = TuneTarget {
+            security: self.target_security,
+            bound: self.security,
+            max_proof_size: self.max_proof_size.map(|size| size * 1024),
+        };
+        let candidate = match tune::tune(example, &options, &target, &self.grinding) {
+            Some(candidate) => candidate,
+            None => {
+                eprintln!("No proof options meet the target");
+                process::exit(1);
+            }
+        };
+
+        let options = &candidate.options;
+        println!();
+        println!(
+            "Recommended options ({} bits of {} security, {:.1} KB proof, {} ms to prove):",
+            candidate.security,
+            self.security,
+            candidate.proof_size as f64 / 1024f64,
+            candidate.prover_time.as_millis()
+        );
+        println!("[proof_options]");
+        println!("num_queries = {}", options.num_queries());
+        println!("blowup_factor = {}", options.blowup_factor());
+        println!("grinding_factor = {}", options.grinding_factor());
+        let fri_options = options.to_fri_options();
+        println!("fri_folding_factor = {}", fri_options.folding_factor());
+        println!(
+            "fri_max_remainder_size = {}",
+            fri_options.max_remainder_size()
+        );
+    }
+}
+
+impl ExampleCommand for &CompareSecurityArgs {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        tune::compare_security(
+            example,
+            &options,
+            &self.levels,
+            self.security,
+            &self.grinding,
+        );
+    }
+}
+
+struct CompareHashCommand;
+
+impl ExampleCommand for CompareHashCommand {
+    type Output = ();
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+        tune::compare_hash(example, &options);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prints log messages without decoration; messages at debug level, which include the phases
+/// of the winterfell prover, are shown only in verbose mode unless `RUST_LOG` says otherwise.
+/// The phases are timed for the run report either way.
+fn init_logger(verbose: bool, target: env_logger::Target) {
+    let level = if verbose {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
+    };
+    let logger = env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .target(target)
+        .format(|buf, record| writeln!(buf, "{}", record.args()))
+        .build();
+    let level = logger.filter();
+    PhaseLogger::install(Box::new(logger), level).expect("logger is installed only once");
+}
+
+/// Prints the error and exits the process with its [exit code](ExampleError::exit_code).
+fn fail(err: ExampleError) -> ! {
+    eprintln!("error: {}", err);
+    process::exit(err.exit_code())
+}
+
+fn load_config(args: &ConfigArgs) -> RunConfig {
+    let mut config = match &args.config {
+        Some(path) => RunConfig::load(path).unwrap_or_else(|err| fail(err)),
+        None => RunConfig::default(),
+    };
+    if let Some(field) = args.field {
+        config.field = field;
+    }
+    if let Some(preset) = args.preset {
+        config.preset = preset;
+    }
+    config.override_proof_options(&ProofOptionsConfig {
+        num_queries: args.num_queries,
+        blowup_factor: args.blowup_factor,
+        grinding_factor: args.grinding_factor,
+        fri_folding_factor: args.fri_folding_factor,
+        fri_max_remainder_size: args.fri_max_remainder_size,
+        ..ProofOptionsConfig::default()
+    });
+    if let Some(seed) = args.seed {
+        config
+            .set_seed(seed)
+            .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+    }
+    if let Some(path) = &args.witness {
+        witness::apply_witness(&mut config, path)
+            .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+    }
+    if let Some(path) = &args.claims {
+        claims::apply_claims(&mut config, path)
+            .unwrap_or_else(|err| fail(ExampleError::Config(err)));
+    }
+    config
+}
+
+/// Opens the artifact store in the specified directory, or in the cache directory of the platform.
+fn open_artifacts(dir: Option<&Path>) -> ArtifactStore {
+    dir.map_or_else(ArtifactStore::open_default, ArtifactStore::open)
+        .unwrap_or_else(|err| fail(err))
+}
+
+fn read_file(what: &str, path: &Path) -> Result<Vec<u8>, ExampleError> {
+    fs::read(path).map_err(|err| ExampleError::read(what, path, err))
+}
+
+/// Reads a file holding `what`, which is in the encoding if one is given and binary otherwise.
+fn read_encoded(
+    what: &str,
+    path: &Path,
+    encoding: Option<Encoding>,
+) -> Result<Vec<u8>, ExampleError> {
+    match encoding {
+        Some(encoding) => encoding.read_file(what, path),
+        None => read_file(what, path),
+    }
+}
+
+/// Writes a file holding `what`, in the encoding if one is given and binary otherwise.
+fn save_encoded(what: &str, path: &Path, bytes: &[u8], encoding: Option<Encoding>) {
+    match encoding {
+        Some(encoding) => match encoding.write_file(what, path, bytes) {
+            Ok(_) => println!("Wrote {} to {} as {}", what, path.display(), encoding),
+            Err(err) => fail(err),
+        },
+        None => save(what, path, bytes),
+    }
+}
+
+fn save(what: &str, path: &Path, bytes: &[u8]) {
+    match fs::write(path, bytes) {
+        Ok(_) => println!("Wrote {} to {}", what, path.display()),
+        Err(err) => fail(ExampleError::write(what, path, err)),
+    }
+}
+
+fn execute<C: ExampleCommand<Output = ()>>(config: &RunConfig, command: C) {
+    if let Err(err) = with_example(config, command) {
+        fail(err);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::less_than_difference,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits of the compared values.
+pub const VALUE_BITS: usize = 32;
+
+/// Trace column layout. The columns from `A_BITS`, `B_BITS`, and `DIFF_BITS` on hold the bits of
+/// the two values of a row and of their difference, least significant bit first; `LESS` is one
+/// exactly when `a < b`, and `COUNT` holds the number of such pairs in all previous rows.
+pub const A_BITS: usize = 0;
+pub const B_BITS: usize = A_BITS + VALUE_BITS;
+pub const DIFF_BITS: usize = B_BITS + VALUE_BITS;
+pub const LESS: usize = DIFF_BITS + VALUE_BITS;
+pub const COUNT: usize = LESS + 1;
+pub const TRACE_WIDTH: usize = COUNT + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The count is the only public input; the pairs it counts are chosen freely by the prover.
+#[derive(Clone)]
+pub struct CompareInputs<B: ExampleField> {
+    pub count: B,
+}
+
+impl<B: ExampleField> Serializable for CompareInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.count);
+    }
+}
+
+impl<B: ExampleField> Deserializable for CompareInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(CompareInputs {
+            count: B::read_from(source)?,
+        })
+    }
+}
+
+// COMPARE AIR
+// ================================================================================================
+
+/// AIR of a sequence of pairs of 32-bit values, of which those with `a < b` are counted.
+///
+/// A prime field has no order, so the comparison is made on a difference which is range-checked
+/// by a bit decomposition, as described in [less_than_difference]. The constraints of a row are:
+///
+/// * `x_i * x_i = x_i` for every bit of `a`, `b`, and the difference, so that each is binary;
+/// * `less * less = less`, so that the flag is binary;
+/// * `b - a - 1 + 2^32 * (1 - less) = sum(2^i * d_i)`, so that the difference lies in
+///   `[0, 2^32)`, which it only does for the right flag.
+///
+/// The values are the combinations of their own bits, which range-checks them as the gadget
+/// requires. One more constraint adds the flag to the running count, whose final value is
+/// public.
+pub struct CompareAir<B: ExampleField> {
+    context: AirContext<B>,
+    count: B,
+}
+
+impl<B: ExampleField> Air for CompareAir<B> {
+    type BaseField = B;
+    type PublicInputs = CompareInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: CompareInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![2; 3 * VALUE_BITS + 1];
+        degrees.extend([1, 1]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            count: pub_inputs.count,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let a_bits = &current[A_BITS..A_BITS + VALUE_BITS];
+        let b_bits = &current[B_BITS..B_BITS + VALUE_BITS];
+        let diff_bits = &current[DIFF_BITS..DIFF_BITS + VALUE_BITS];
+        let less = current[LESS];
+
+        // every bit and the flag are binary
+        result.enforce_binary(A_BITS, a_bits);
+        result.enforce_binary(B_BITS, b_bits);
+        result.enforce_binary(DIFF_BITS, diff_bits);
+        result[LESS] = is_binary(less);
+
+        // the difference fits into its bits only for the right flag
+        let difference =
+            less_than_difference(combine_bits(a_bits), combine_bits(b_bits), less, VALUE_BITS);
+        result[LESS + 1] = are_equal(difference, combine_bits(diff_bits));
+
+        // the flag is added to the running count
+        result[LESS + 2] = are_equal(next[COUNT], current[COUNT] + less);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the count starts at zero and ends with the public value
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(COUNT, B::ZERO),
+            last_row_equals(COUNT, trace_length, self.count),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::CompareConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{CompareAir, CompareInputs, VALUE_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::CompareProver;
+
+#[cfg(test)]
+mod tests;
+
+// COMPARE EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the number of pairs with `a < b` in a sequence of private pairs of
+/// 32-bit values, one pair per step.
+///
+/// Each comparison is made by the less-than gadget of [crate::gadgets], on a difference which is
+/// range-checked by a bit decomposition; see [CompareAir] for the constraints.
+///
+/// The pairs are private and bound to nothing the verifier knows; they are drawn from the seed,
+/// but the seed is not part of the statement. A proof therefore exists for every count from zero
+/// to the number of pairs, and shows only that the comparisons in the trace were made correctly,
+/// not which pairs were compared.
+#[cfg(feature = "std")]
+pub struct CompareExample<B: ExampleField> {
+    seed: u64,
+    pairs: Vec<(u32, u32)>,
+    count: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> CompareExample<B> {
+    pub fn new(config: &CompareConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // the last row is not covered by transition constraints, so no pair is compared there
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let pairs = (0..config.trace_length - 1)
+            .map(|_| (rng.gen(), rng.gen()))
+            .collect::<Vec<(u32, u32)>>();
+
+        // compute result
+        let now = Instant::now();
+        let count = pairs.iter().filter(|(a, b)| a < b).count();
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            pairs,
+            count: B::from(count as u64),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &CompareConfig) -> Result<(), String> {
+        validate::check_trace_length("compare.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for CompareExample<B> {
+    type Air = CompareAir<B>;
+    type Prover = CompareProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        CompareProver::build_trace(&self.pairs)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = CompareConfig::default();
+        ExampleDescriptor::new(
+            "compare",
+            "the number of private pairs of 32-bit values with a < b",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("A_BITS", VALUE_BITS),
+                ("B_BITS", VALUE_BITS),
+                ("DIFF_BITS", VALUE_BITS),
+                ("LESS", 1),
+                ("COUNT", 1),
+            ],
+            constraints: vec![
+                ("binary bits", 3 * VALUE_BITS),
+                ("binary flag", 1),
+                ("range-checked difference", 1),
+                ("count accumulation", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> CompareProver<B> {
+        CompareProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> CompareInputs<B> {
+        CompareInputs { count: self.count }
+    }
+
+    fn wrong_pub_inputs(&self) -> CompareInputs<B> {
+        CompareInputs {
+            count: self.count + B::ONE,
+        }
+    }
+}
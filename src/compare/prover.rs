@@ -0,0 +1,68 @@
+use super::{
+    air::{A_BITS, B_BITS, COUNT, DIFF_BITS, LESS, TRACE_WIDTH, VALUE_BITS},
+    CompareAir, CompareInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{less_than_witness, to_bits},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct CompareProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> CompareProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which compares one pair of values per row, and counts the pairs with
+    /// `a < b`; the last row, which follows the last pair, repeats the first pair so that its
+    /// bits stay consistent with its flag.
+    pub fn build_trace(pairs: &[(u32, u32)]) -> TraceTable<B> {
+        let trace_length = pairs.len() + 1;
+        let mut columns = vec![vec![B::ZERO; trace_length]; TRACE_WIDTH];
+        let mut count = B::ZERO;
+        for (step, &(a, b)) in pairs.iter().chain(pairs.first()).enumerate() {
+            let (less, difference) = less_than_witness(a as u64, b as u64, VALUE_BITS);
+            for (column, value) in [
+                (A_BITS, a as u64),
+                (B_BITS, b as u64),
+                (DIFF_BITS, difference),
+            ] {
+                for (i, bit) in to_bits(value, VALUE_BITS).enumerate() {
+                    columns[column + i][step] = bit;
+                }
+            }
+            columns[LESS][step] = B::from(less as u8);
+            columns[COUNT][step] = count;
+            count += columns[LESS][step];
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for CompareProver<B> {
+    type BaseField = B;
+    type Air = CompareAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> CompareInputs<B> {
+        CompareInputs {
+            count: trace.get(COUNT, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,124 @@
+use super::{
+    air::{A_BITS, B_BITS, COUNT, DIFF_BITS, LESS, VALUE_BITS},
+    CompareExample, CompareInputs, CompareProver,
+};
+use crate::{
+    config::{CompareConfig, ProofOptionsConfig, RunConfig},
+    example::Example,
+    run_example,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn compare_trace_decomposes_differences_and_counts_pairs() {
+    let pairs = [
+        (3, 5),
+        (5, 3),
+        (7, 7),
+        (0, u32::MAX),
+        (u32::MAX, 0),
+        (u32::MAX - 1, u32::MAX),
+        (0, 0),
+    ];
+    let trace = CompareProver::<BaseElement>::build_trace(&pairs);
+    let less = [1u64, 0, 0, 1, 0, 1, 0];
+    let differences = [
+        1u64,
+        0xffff_fffd,
+        0xffff_ffff,
+        0xffff_fffe,
+        0,
+        0,
+        0xffff_ffff,
+    ];
+    let mut count = 0;
+    for (step, &(a, b)) in pairs.iter().enumerate() {
+        for i in 0..VALUE_BITS {
+            assert_eq!(BaseElement::from((a >> i) & 1), trace.get(A_BITS + i, step));
+            assert_eq!(BaseElement::from((b >> i) & 1), trace.get(B_BITS + i, step));
+            assert_eq!(
+                BaseElement::from((differences[step] >> i) & 1),
+                trace.get(DIFF_BITS + i, step)
+            );
+        }
+        assert_eq!(BaseElement::from(less[step]), trace.get(LESS, step));
+        assert_eq!(BaseElement::from(count), trace.get(COUNT, step));
+        count += less[step];
+    }
+    assert_eq!(BaseElement::from(count), trace.get(COUNT, pairs.len()));
+}
+
+#[test]
+fn compare_wrong_flag_is_rejected() {
+    // claim that a pair with a < b is not less: the difference b - a - 1 + 2^32 then needs a 33rd
+    // bit, so the best a forger can do is decompose its low 32 bits, which breaks the
+    // decomposition constraint
+    let pairs = (0..TRACE_LENGTH as u32 - 1)
+        .map(|i| (i, i.wrapping_mul(0x9e37_79b9)))
+        .collect::<Vec<_>>();
+    let mut trace = CompareProver::<BaseElement>::build_trace(&pairs);
+    let step = 4;
+    assert_eq!(BaseElement::ONE, trace.get(LESS, step));
+    trace.set(LESS, step, BaseElement::ZERO);
+    for later in step + 1..TRACE_LENGTH {
+        trace.set(COUNT, later, trace.get(COUNT, later) - BaseElement::ONE);
+    }
+    let pub_inputs = CompareInputs {
+        count: trace.get(COUNT, TRACE_LENGTH - 1),
+    };
+
+    let proof = CompareProver::new(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<CompareExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn compare_seed_reproduces_inputs() {
+    let mut config = RunConfig {
+        example: "compare".to_string(),
+        compare: CompareConfig {
+            trace_length: TRACE_LENGTH,
+            ..CompareConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    config.set_seed(5).unwrap();
+    let first = run_example(&config).unwrap();
+    assert_eq!(Some(5), first.seed);
+    assert_eq!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+
+    config.set_seed(6).unwrap();
+    assert_ne!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,180 @@
+//! Manual composition of two proofs through shared public inputs.
+//!
+//! The first proof shows that a digest was obtained by a Rescue hash chain, and the second that
+//! a Poseidon hash chain seeded with that digest ends in a certain result. Neither proof knows
+//! about the other; the statement "the Poseidon chain continues the Rescue chain" holds because
+//! the verifier checks both proofs and then checks that the public output of the first is the
+//! public input of the second. This composes the statements without recursion, at the cost of
+//! the verifier having to check, and receive, every proof.
+
+use crate::{
+    config::RunConfig,
+    error::ExampleError,
+    example::{self, Example, PublicInputs, RunReport},
+    field::{ExampleField, FieldType},
+    poseidon::PoseidonExample,
+    rescue::RescueExample,
+    verify::verify_proof,
+};
+use std::fmt;
+use winter_utils::SliceReader;
+use winterfell::{
+    math::fields::{f128, f62, f64},
+    Deserializable, StarkProof,
+};
+
+// COMPOSITION REPORT
+// ================================================================================================
+
+/// Reports of the two proofs of a composed statement, and the outcome of checking them
+/// together.
+#[derive(Debug, Clone)]
+pub struct ComposeReport {
+    /// Run of the Rescue hash chain which produces the digest.
+    pub hash_chain: RunReport,
+    /// Run of the Poseidon hash chain which starts from the digest.
+    pub continuation: RunReport,
+    /// Reason the composed statement was rejected by [verify_composition()]; `None` if both
+    /// proofs verified and their public inputs are linked.
+    pub composition_error: Option<String>,
+}
+
+impl ComposeReport {
+    /// Returns true if the composed statement was verified successfully.
+    pub fn is_verified(&self) -> bool {
+        self.composition_error.is_none()
+    }
+}
+
+impl fmt::Display for ComposeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Stage 1: Rescue hash chain")?;
+        writeln!(f, "{}", self.hash_chain)?;
+        writeln!(
+            f,
+            "Stage 2: Poseidon hash chain seeded with the Rescue digest"
+        )?;
+        writeln!(f, "{}", self.continuation)?;
+        match &self.composition_error {
+            None => write!(
+                f,
+                "Composed statement verified: the digest proven in stage 1 seeds stage 2"
+            ),
+            Some(err) => write!(f, "Composed statement rejected: {}", err),
+        }
+    }
+}
+
+// COMPOSITION
+// ================================================================================================
+
+/// Proves the Rescue hash chain set up in the `rescue` section of the config, proves a Poseidon
+/// hash chain of the length set in the `poseidon` section which starts from the resulting
+/// digest, and verifies the composed statement from the serialized proofs and public inputs.
+///
+/// The `example` setting and the seed of the Poseidon section are ignored.
+///
+/// Returns a [Config](ExampleError::Config) error if either section does not describe a chain
+/// which can be proven.
+pub fn compose(config: &RunConfig) -> Result<ComposeReport, ExampleError> {
+    match config.field {
+        FieldType::F128 => compose_in_field::<f128::BaseElement>(config),
+        FieldType::F64 => compose_in_field::<f64::BaseElement>(config),
+        FieldType::F62 => compose_in_field::<f62::BaseElement>(config),
+    }
+}
+
+/// Verifies both proofs of a composed statement against their serialized public inputs, and
+/// checks that the digest proven by the Rescue chain is the seed of the Poseidon chain.
+///
+/// Returns an error if either proof is rejected, if the proofs are over different base fields,
+/// or if the public inputs of the proofs are not linked.
+pub fn verify_composition(
+    hash_chain_proof: &[u8],
+    hash_chain_inputs: &[u8],
+    continuation_proof: &[u8],
+    continuation_inputs: &[u8],
+) -> Result<(), String> {
+    verify_proof("rescue", hash_chain_proof, hash_chain_inputs)
+        .map_err(|err| format!("proof of the Rescue hash chain: {}", err))?;
+    verify_proof("poseidon", continuation_proof, continuation_inputs)
+        .map_err(|err| format!("proof of the Poseidon hash chain: {}", err))?;
+
+    let field = proof_field(hash_chain_proof)?;
+    if proof_field(continuation_proof)? != field {
+        return Err("the proofs are over different base fields".to_string());
+    }
+    match field {
+        FieldType::F128 => check_link::<f128::BaseElement>(hash_chain_inputs, continuation_inputs),
+        FieldType::F64 => check_link::<f64::BaseElement>(hash_chain_inputs, continuation_inputs),
+        FieldType::F62 => check_link::<f62::BaseElement>(hash_chain_inputs, continuation_inputs),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compose_in_field<B: ExampleField>(config: &RunConfig) -> Result<ComposeReport, ExampleError> {
+    PoseidonExample::<B>::validate(&config.poseidon).map_err(ExampleError::Config)?;
+    let hash_chain = RescueExample::<B>::new(&config.rescue)?;
+    let hash_chain_report = example::run(
+        &hash_chain,
+        config
+            .proof_options_for(&config.rescue.proof_options)
+            .to_proof_options(),
+    );
+
+    // the public output of the first proof becomes the public input of the second
+    let digest = hash_chain.pub_inputs().result;
+    let continuation = PoseidonExample::<B>::with_seed(digest, config.poseidon.chain_length);
+    let continuation_report = example::run(
+        &continuation,
+        config
+            .proof_options_for(&config.poseidon.proof_options)
+            .to_proof_options(),
+    );
+
+    let composition_error = verify_composition(
+        &hash_chain_report.proof_bytes,
+        &hash_chain_report.pub_inputs_bytes,
+        &continuation_report.proof_bytes,
+        &continuation_report.pub_inputs_bytes,
+    )
+    .err();
+
+    Ok(ComposeReport {
+        hash_chain: hash_chain_report,
+        continuation: continuation_report,
+        composition_error,
+    })
+}
+
+fn check_link<B: ExampleField>(
+    hash_chain_inputs: &[u8],
+    continuation_inputs: &[u8],
+) -> Result<(), String> {
+    let digest = read_inputs::<RescueExample<B>>(hash_chain_inputs)?.result;
+    let seed = read_inputs::<PoseidonExample<B>>(continuation_inputs)?.seed;
+    if digest != seed {
+        return Err(
+            "the seed of the Poseidon hash chain is not the digest of the Rescue hash chain"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+pub(crate) fn read_inputs<E: Example>(bytes: &[u8]) -> Result<PublicInputs<E>, String>
+where
+    PublicInputs<E>: Deserializable,
+{
+    PublicInputs::<E>::read_from(&mut SliceReader::new(bytes))
+        .map_err(|err| format!("failed to parse public inputs: {}", err))
+}
+
+fn proof_field(proof_bytes: &[u8]) -> Result<FieldType, String> {
+    let proof = StarkProof::from_bytes(proof_bytes)
+        .map_err(|err| format!("failed to parse proof: {}", err))?;
+    FieldType::from_modulus_bytes(proof.context.field_modulus_bytes())
+        .ok_or_else(|| "proof is for an unsupported base field".to_string())
+}
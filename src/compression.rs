@@ -0,0 +1,80 @@
+use flate2::{write::GzEncoder, Compression};
+use std::{fmt, io::Write};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Compression level used for gzip; the highest level supported by the format.
+const GZIP_LEVEL: u32 = 9;
+
+/// Compression level used for zstd; the highest level which does not need the large windows of
+/// the "ultra" levels.
+const ZSTD_LEVEL: i32 = 19;
+
+// ENCODING
+// ================================================================================================
+
+/// An encoding into which a serialized proof can be wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Uncompressed,
+    Gzip,
+    Zstd,
+}
+
+impl Encoding {
+    /// All supported encodings, starting with the uncompressed one.
+    pub const ALL: [Encoding; 3] = [Encoding::Uncompressed, Encoding::Gzip, Encoding::Zstd];
+
+    /// Encodes the provided bytes.
+    pub fn encode(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Encoding::Uncompressed => bytes.to_vec(),
+            Encoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::new(GZIP_LEVEL));
+                encoder.write_all(bytes).unwrap();
+                encoder.finish().unwrap()
+            }
+            Encoding::Zstd => zstd::encode_all(bytes, ZSTD_LEVEL).unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Encoding::Uncompressed => write!(f, "uncompressed"),
+            Encoding::Gzip => write!(f, "gzip"),
+            Encoding::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn compressed_proof_decodes_to_original() {
+        use std::io::Read;
+
+        let report = example::run(&vdf_example(), vdf_options());
+        let proof_bytes = &report.proof_bytes;
+
+        let mut gzip_decoded = Vec::new();
+        flate2::read::GzDecoder::new(&Encoding::Gzip.encode(proof_bytes)[..])
+            .read_to_end(&mut gzip_decoded)
+            .unwrap();
+        assert_eq!(proof_bytes, &gzip_decoded);
+
+        let zstd_decoded = zstd::decode_all(&Encoding::Zstd.encode(proof_bytes)[..]).unwrap();
+        assert_eq!(proof_bytes, &zstd_decoded);
+    }
+}
@@ -0,0 +1,1317 @@
+use crate::{
+    claims::Claim, error::ExampleError, field::FieldType, presets::Preset, vm::Instruction,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{fs, path::Path};
+use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+// DEFAULTS
+// ================================================================================================
+
+const DEFAULT_EXAMPLE: &str = "vdf";
+
+const DEFAULT_VDF_TRACE_LENGTH: usize = 1024 * 1024;
+const DEFAULT_VDF_SEED: u64 = 5;
+
+const DEFAULT_RESCUE_CHAIN_LENGTH: usize = 1024;
+const DEFAULT_RESCUE_SEED: [u64; 2] = [42, 43];
+
+const DEFAULT_POSEIDON_CHAIN_LENGTH: usize = 256;
+const DEFAULT_POSEIDON_SEED: [u64; 2] = [42, 43];
+
+const DEFAULT_COUNTER_NUM_BLOCKS: usize = 1024;
+const DEFAULT_COUNTER_SEED: u64 = 3;
+
+const DEFAULT_EXP_BASE: u64 = 3;
+const DEFAULT_EXP_EXPONENT: u64 = 0x5851_f42d_4c95_7f2d;
+
+const DEFAULT_SUM_LENGTH: usize = 1 << 14;
+const DEFAULT_SUM_SEED: u64 = 11;
+
+const DEFAULT_LAMPORT_NUM_SIGNATURES: usize = 256;
+const DEFAULT_LAMPORT_SEED: u64 = 7;
+
+const DEFAULT_FIB_TRACE_LENGTH: usize = 1 << 12;
+const DEFAULT_FIB_SEED: u64 = 13;
+
+const DEFAULT_GCD_A: u16 = 46368;
+const DEFAULT_GCD_B: u16 = 28657;
+
+const DEFAULT_QUAD_TRACE_LENGTH: usize = 1 << 14;
+const DEFAULT_QUAD_START: u64 = 3;
+const DEFAULT_QUAD_CONSTANT: u64 = 1;
+
+const DEFAULT_ISZERO_TRACE_LENGTH: usize = 1024;
+const DEFAULT_ISZERO_START: u64 = 700;
+
+const DEFAULT_ROM_TRACE_LENGTH: usize = 1024;
+const DEFAULT_ROM_TABLE: [u64; 8] = [5, 11, 2, 7, 3, 13, 8, 1];
+const DEFAULT_ROM_SEED: u64 = 17;
+
+const DEFAULT_SQRT_TRACE_LENGTH: usize = 64;
+const DEFAULT_SQRT_VALUE: f64 = 2.0;
+
+const DEFAULT_MERKLE_NUM_LEAVES: usize = 16;
+const DEFAULT_MERKLE_NUM_STEPS: usize = 64;
+const DEFAULT_MERKLE_SEED: u64 = 3;
+
+const DEFAULT_BITWISE_TRACE_LENGTH: usize = 1024;
+const DEFAULT_BITWISE_SEED: u64 = 19;
+
+const DEFAULT_COMPARE_TRACE_LENGTH: usize = 1024;
+const DEFAULT_COMPARE_SEED: u64 = 23;
+
+const DEFAULT_FIBMOD_TRACE_LENGTH: usize = 1024;
+const DEFAULT_FIBMOD_MODULUS: u64 = (1 << 31) - 1;
+
+const DEFAULT_SORT_TRACE_LENGTH: usize = 32;
+const DEFAULT_SORT_SEED: u64 = 29;
+
+const DEFAULT_FIBCHUNK_TRACE_LENGTH: usize = 1024;
+const DEFAULT_FIBCHUNK_START: [u64; 2] = [0, 1];
+
+const DEFAULT_FIXEDEXP_GENERATOR: u64 = 7;
+const DEFAULT_FIXEDEXP_NUM_EXPONENTS: usize = 16;
+const DEFAULT_FIXEDEXP_SEED: u64 = 31;
+
+const DEFAULT_MODEXP_EXPONENT: u64 = 65537;
+const DEFAULT_MODEXP_NUM_MESSAGES: usize = 16;
+const DEFAULT_MODEXP_SEED: u64 = 37;
+
+const DEFAULT_BYTECOUNT_TEXT: &str = "she sells sea shells by the sea shore";
+const DEFAULT_BYTECOUNT_CHARACTER: char = 's';
+
+const DEFAULT_RUNSUM_TRACE_LENGTH: usize = 1024;
+const DEFAULT_RUNSUM_SEED: u64 = 41;
+
+const DEFAULT_HORNER_TRACE_LENGTH: usize = 1024;
+const DEFAULT_HORNER_SEED: u64 = 43;
+const DEFAULT_HORNER_POINT: u64 = 7;
+
+const DEFAULT_WRAP_TRACE_LENGTH: usize = 1024;
+const DEFAULT_WRAP_START: u8 = 250;
+const DEFAULT_WRAP_INCREMENT: u8 = 3;
+
+const DEFAULT_WINDOW_TRACE_LENGTH: usize = 1024;
+const DEFAULT_WINDOW_WIDTH: usize = 3;
+const DEFAULT_WINDOW_START: u64 = 1;
+
+const DEFAULT_FIBDIV_TRACE_LENGTH: usize = 1024;
+const DEFAULT_FIBDIV_DIVISOR: u64 = 3;
+
+const DEFAULT_VM_TRACE_LENGTH: usize = 1024;
+const DEFAULT_VM_REGISTERS: [u64; 2] = [12, 13];
+
+// RUN CONFIGURATION
+// ================================================================================================
+
+/// Parameters of a single example run.
+///
+/// A configuration is usually read from a TOML file which looks like this:
+///
+/// ```toml
+/// example = "vdf"
+/// field = "f128"
+///
+/// [proof_options]
+/// num_queries = 40
+/// blowup_factor = 4
+///
+/// [vdf]
+/// trace_length = 1048576
+/// seed = 5
+///
+/// [vdf.proof_options]
+/// grinding_factor = 16
+/// ```
+///
+/// Top-level `proof_options` apply to all examples, while the `proof_options` table in an
+/// example section overrides them for that example only. Any option which is not specified
+/// falls back to its default value.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunConfig {
+    pub example: String,
+    pub field: FieldType,
+    /// Proof options used for every value which is set neither in `proof_options` nor in the
+    /// proof options of the example.
+    pub preset: Preset,
+    pub proof_options: ProofOptionsConfig,
+    pub vdf: VdfConfig,
+    pub rescue: RescueConfig,
+    pub poseidon: PoseidonConfig,
+    pub counter: CounterConfig,
+    pub lamport: LamportConfig,
+    pub exp: ExpConfig,
+    pub sum: SumConfig,
+    pub vm: VmConfig,
+    pub fib: FibConfig,
+    pub gcd: GcdConfig,
+    pub quad: QuadConfig,
+    pub iszero: IsZeroConfig,
+    pub rom: RomConfig,
+    pub sqrt: SqrtConfig,
+    pub merkle: MerkleConfig,
+    pub bitwise: BitwiseConfig,
+    pub compare: CompareConfig,
+    pub fibmod: FibModConfig,
+    pub sort: SortConfig,
+    pub fibchunk: FibChunkConfig,
+    pub fixedexp: FixedExpConfig,
+    pub modexp: ModExpConfig,
+    pub bytecount: ByteCountConfig,
+    pub runsum: RunSumConfig,
+    pub horner: HornerConfig,
+    pub wrap: WrapConfig,
+    pub window: WindowConfig,
+    pub fibdiv: FibDivConfig,
+}
+
+impl RunConfig {
+    /// Reads a run configuration from the TOML file at the specified path.
+    pub fn load(path: &Path) -> Result<Self, ExampleError> {
+        let source =
+            fs::read_to_string(path).map_err(|err| ExampleError::read("config file", path, err))?;
+        toml::from_str(&source).map_err(|err| {
+            ExampleError::Config(format!(
+                "failed to parse config file {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+
+    /// Returns the proof options of the preset with the shared proof options and then the
+    /// specified example-specific overrides applied.
+    pub fn proof_options_for(&self, overrides: &ProofOptionsConfig) -> ProofOptionsConfig {
+        self.preset
+            .proof_options(self.field)
+            .merge(&self.proof_options)
+            .merge(overrides)
+    }
+
+    /// Applies the values set in `overrides` to the shared proof options and to the proof
+    /// options of every example, so that they take precedence over all options in the config.
+    pub fn override_proof_options(&mut self, overrides: &ProofOptionsConfig) {
+        let sections = [
+            &mut self.proof_options,
+            &mut self.vdf.proof_options,
+            &mut self.rescue.proof_options,
+            &mut self.poseidon.proof_options,
+            &mut self.counter.proof_options,
+            &mut self.lamport.proof_options,
+            &mut self.exp.proof_options,
+            &mut self.sum.proof_options,
+            &mut self.vm.proof_options,
+            &mut self.fib.proof_options,
+            &mut self.gcd.proof_options,
+            &mut self.quad.proof_options,
+            &mut self.iszero.proof_options,
+            &mut self.rom.proof_options,
+            &mut self.sqrt.proof_options,
+            &mut self.merkle.proof_options,
+            &mut self.bitwise.proof_options,
+            &mut self.compare.proof_options,
+            &mut self.fibmod.proof_options,
+            &mut self.sort.proof_options,
+            &mut self.fibchunk.proof_options,
+            &mut self.fixedexp.proof_options,
+            &mut self.modexp.proof_options,
+            &mut self.bytecount.proof_options,
+            &mut self.runsum.proof_options,
+            &mut self.horner.proof_options,
+            &mut self.wrap.proof_options,
+            &mut self.window.proof_options,
+            &mut self.fibdiv.proof_options,
+        ];
+        for options in sections {
+            *options = options.merge(overrides);
+        }
+    }
+
+    /// Sets the seed of the random number generator which draws the inputs of the selected
+    /// example.
+    ///
+    /// Returns an error if the inputs of the example are not random: only `fib`, `lamport`,
+    /// `rom`, `sum`, `bitwise`, `compare`, `sort`, `fixedexp`, `modexp`, `runsum`, and `horner`
+    /// draw their inputs from a seed, and `sum` only while its vector or its weights are not set
+    /// explicitly.
+    pub fn set_seed(&mut self, seed: u64) -> Result<(), String> {
+        match self.example.as_str() {
+            "fib" => self.fib.seed = seed,
+            "lamport" => self.lamport.seed = seed,
+            "rom" => self.rom.seed = seed,
+            "sum" if self.sum.draws_random_inputs() => self.sum.seed = seed,
+            "bitwise" => self.bitwise.seed = seed,
+            "compare" => self.compare.seed = seed,
+            "sort" => self.sort.seed = seed,
+            "fixedexp" => self.fixedexp.seed = seed,
+            "modexp" => self.modexp.seed = seed,
+            "runsum" => self.runsum.seed = seed,
+            "horner" => self.horner.seed = seed,
+            example => {
+                return Err(format!(
+                    "the inputs of example '{}' are not drawn from a seed",
+                    example
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of this config in which the selected example proves a different statement
+    /// for every `index`; index zero leaves the config unchanged.
+    ///
+    /// The index is added to the seed of the example; `exp` has no seed and offsets its exponent
+    /// instead, `sum` offsets its first element when the vector is set explicitly, `vm` offsets
+    /// the initial value of `r0`, `gcd` its first input, `quad` and `iszero` their starting
+    /// points, `sqrt` the number whose root it approximates, `fibchunk` the second term of its
+    /// starting state, `wrap` the start of its counter, `window` the first term of its sequence,
+    /// `fibdiv` raises its divisor, `fibmod` lowers its modulus, and `bytecount` appends the
+    /// index to its text.
+    pub fn statement(&self, index: u64) -> RunConfig {
+        let mut config = self.clone();
+        match config.example.as_str() {
+            "vdf" => config.vdf.seed = config.vdf.seed.wrapping_add(index),
+            "rescue" => config.rescue.seed[0] = config.rescue.seed[0].wrapping_add(index),
+            "poseidon" => config.poseidon.seed[0] = config.poseidon.seed[0].wrapping_add(index),
+            "counter" => config.counter.seed = config.counter.seed.wrapping_add(index),
+            "lamport" => config.lamport.seed = config.lamport.seed.wrapping_add(index),
+            "exp" => config.exp.exponent = config.exp.exponent.wrapping_add(index),
+            "sum" => match config.sum.values.as_mut() {
+                Some(values) => values[0] = values[0].wrapping_add(index),
+                None => config.sum.seed = config.sum.seed.wrapping_add(index),
+            },
+            "vm" => config.vm.registers[0] = config.vm.registers[0].wrapping_add(index),
+            "fib" => config.fib.seed = config.fib.seed.wrapping_add(index),
+            "gcd" => config.gcd.a = config.gcd.a.wrapping_add(index as u16),
+            "quad" => config.quad.start = config.quad.start.wrapping_add(index),
+            "iszero" => config.iszero.start = config.iszero.start.wrapping_add(index),
+            "rom" => config.rom.seed = config.rom.seed.wrapping_add(index),
+            "sqrt" => config.sqrt.value += index as f64,
+            "merkle" => config.merkle.seed = config.merkle.seed.wrapping_add(index),
+            "bitwise" => config.bitwise.seed = config.bitwise.seed.wrapping_add(index),
+            "compare" => config.compare.seed = config.compare.seed.wrapping_add(index),
+            "fibmod" => config.fibmod.modulus = config.fibmod.modulus.wrapping_sub(index),
+            "sort" => config.sort.seed = config.sort.seed.wrapping_add(index),
+            "fibchunk" => config.fibchunk.start[1] = config.fibchunk.start[1].wrapping_add(index),
+            "fixedexp" => config.fixedexp.seed = config.fixedexp.seed.wrapping_add(index),
+            "modexp" => config.modexp.seed = config.modexp.seed.wrapping_add(index),
+            "bytecount" if index > 0 => config.bytecount.text.push_str(&index.to_string()),
+            "runsum" => config.runsum.seed = config.runsum.seed.wrapping_add(index),
+            "horner" => config.horner.seed = config.horner.seed.wrapping_add(index),
+            "wrap" => config.wrap.start = config.wrap.start.wrapping_add(index as u8),
+            "window" => config.window.start = config.window.start.wrapping_add(index),
+            "fibdiv" => config.fibdiv.divisor = config.fibdiv.divisor.wrapping_add(index),
+            _ => {}
+        }
+        config
+    }
+
+    /// Returns a copy of this config in which the computation of the selected example is
+    /// `2^doublings` times as long, which grows its execution trace by the same factor.
+    ///
+    /// Returns an error if the size of the computation is not a setting of the example: the
+    /// trace length of `exp` is fixed by the width of the exponent, that of `gcd` depends on its
+    /// inputs, that of `sum` cannot grow when its vector is set explicitly, that of `sort` is
+    /// limited by its column per list entry, and that of `bytecount` by the length of its text.
+    pub fn scaled(&self, doublings: u32) -> Result<RunConfig, String> {
+        let mut config = self.clone();
+        let size = match config.example.as_str() {
+            "vdf" => &mut config.vdf.trace_length,
+            "rescue" => &mut config.rescue.chain_length,
+            "poseidon" => &mut config.poseidon.chain_length,
+            "counter" => &mut config.counter.num_blocks,
+            "lamport" => &mut config.lamport.num_signatures,
+            "sum" if config.sum.values.is_none() => &mut config.sum.length,
+            "vm" => &mut config.vm.trace_length,
+            "fib" => &mut config.fib.trace_length,
+            "quad" => &mut config.quad.trace_length,
+            "iszero" => &mut config.iszero.trace_length,
+            "rom" => &mut config.rom.trace_length,
+            "sqrt" => &mut config.sqrt.trace_length,
+            "merkle" => &mut config.merkle.num_steps,
+            "bitwise" => &mut config.bitwise.trace_length,
+            "compare" => &mut config.compare.trace_length,
+            "fibmod" => &mut config.fibmod.trace_length,
+            "fibchunk" => &mut config.fibchunk.trace_length,
+            "fixedexp" => &mut config.fixedexp.num_exponents,
+            "modexp" => &mut config.modexp.num_messages,
+            "runsum" => &mut config.runsum.trace_length,
+            "horner" => &mut config.horner.trace_length,
+            "wrap" => &mut config.wrap.trace_length,
+            "window" => &mut config.window.trace_length,
+            "fibdiv" => &mut config.fibdiv.trace_length,
+            example => {
+                return Err(format!(
+                    "the trace length of example '{}' cannot be scaled",
+                    example
+                ))
+            }
+        };
+        *size <<= doublings;
+        if let Some(num_terms) = &mut config.fib.num_terms {
+            *num_terms <<= doublings;
+        }
+        Ok(config)
+    }
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            example: DEFAULT_EXAMPLE.to_string(),
+            field: FieldType::default(),
+            preset: Preset::default(),
+            proof_options: ProofOptionsConfig::default(),
+            vdf: VdfConfig::default(),
+            rescue: RescueConfig::default(),
+            poseidon: PoseidonConfig::default(),
+            counter: CounterConfig::default(),
+            lamport: LamportConfig::default(),
+            exp: ExpConfig::default(),
+            sum: SumConfig::default(),
+            vm: VmConfig::default(),
+            fib: FibConfig::default(),
+            gcd: GcdConfig::default(),
+            quad: QuadConfig::default(),
+            iszero: IsZeroConfig::default(),
+            rom: RomConfig::default(),
+            sqrt: SqrtConfig::default(),
+            merkle: MerkleConfig::default(),
+            bitwise: BitwiseConfig::default(),
+            compare: CompareConfig::default(),
+            fibmod: FibModConfig::default(),
+            sort: SortConfig::default(),
+            fibchunk: FibChunkConfig::default(),
+            fixedexp: FixedExpConfig::default(),
+            modexp: ModExpConfig::default(),
+            bytecount: ByteCountConfig::default(),
+            runsum: RunSumConfig::default(),
+            horner: HornerConfig::default(),
+            wrap: WrapConfig::default(),
+            window: WindowConfig::default(),
+            fibdiv: FibDivConfig::default(),
+        }
+    }
+}
+
+// EXAMPLE SECTIONS
+// ================================================================================================
+
+/// Settings of the VDF example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VdfConfig {
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// Starting value of the VDF computation.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for VdfConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_VDF_TRACE_LENGTH,
+            seed: DEFAULT_VDF_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Rescue hash chain example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RescueConfig {
+    /// Number of hash invocations in the chain; must be a power of two.
+    pub chain_length: usize,
+    /// Two field elements which are fed into the first hash invocation.
+    pub seed: [u64; 2],
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for RescueConfig {
+    fn default() -> Self {
+        Self {
+            chain_length: DEFAULT_RESCUE_CHAIN_LENGTH,
+            seed: DEFAULT_RESCUE_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Poseidon hash chain example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PoseidonConfig {
+    /// Number of hash invocations in the chain; must be a power of two.
+    pub chain_length: usize,
+    /// Two field elements which are fed into the first hash invocation.
+    pub seed: [u64; 2],
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for PoseidonConfig {
+    fn default() -> Self {
+        Self {
+            chain_length: DEFAULT_POSEIDON_CHAIN_LENGTH,
+            seed: DEFAULT_POSEIDON_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the counter example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CounterConfig {
+    /// Number of 8-step blocks in the execution trace; must be a power of two.
+    pub num_blocks: usize,
+    /// Value absorbed by the first block; each following block absorbs the next integer.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for CounterConfig {
+    fn default() -> Self {
+        Self {
+            num_blocks: DEFAULT_COUNTER_NUM_BLOCKS,
+            seed: DEFAULT_COUNTER_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Lamport signature aggregation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LamportConfig {
+    /// Number of signed messages; must be a power of two.
+    pub num_signatures: usize,
+    /// Seed of the random number generator which draws keys and messages.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for LamportConfig {
+    fn default() -> Self {
+        Self {
+            num_signatures: DEFAULT_LAMPORT_NUM_SIGNATURES,
+            seed: DEFAULT_LAMPORT_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the exponentiation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExpConfig {
+    /// Public base of the exponentiation.
+    pub base: u64,
+    /// Secret exponent; the proof shows knowledge of it without including it in the statement.
+    /// TOML integers are signed, so exponents of 2^63 and above cannot be set in a config file.
+    pub exponent: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for ExpConfig {
+    fn default() -> Self {
+        Self {
+            base: DEFAULT_EXP_BASE,
+            exponent: DEFAULT_EXP_EXPONENT,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the sum and inner product example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SumConfig {
+    /// Number of elements in the public vector; must be a power of two.
+    pub length: usize,
+    /// Seed of the random number generator which draws the vector and its weights.
+    pub seed: u64,
+    /// Prove the inner product of the vector with a public vector of random weights instead of
+    /// its plain sum.
+    pub dot_product: bool,
+    /// Elements of the vector; when set, `length` and `seed` are ignored and the number of
+    /// elements must be a power of two.
+    pub values: Option<Vec<u64>>,
+    /// Weights of the inner product; when set, they replace the random weights, and there must
+    /// be as many of them as there are elements in the vector.
+    pub weights: Option<Vec<u64>>,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for SumConfig {
+    fn default() -> Self {
+        Self {
+            length: DEFAULT_SUM_LENGTH,
+            seed: DEFAULT_SUM_SEED,
+            dot_product: false,
+            values: None,
+            weights: None,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+impl SumConfig {
+    /// Returns true if the vector or its weights are drawn from `seed`.
+    pub fn draws_random_inputs(&self) -> bool {
+        self.values.is_none() || (self.dot_product && self.weights.is_none())
+    }
+}
+
+/// Settings of the register machine example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VmConfig {
+    /// Instructions of the program, such as `"LOAD 5"` or `"JZ 3"`.
+    pub program: Vec<Instruction>,
+    /// Initial values of registers `r0` and `r1`.
+    pub registers: [u64; 2],
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// Values which registers `pc`, `r0`, and `r1` hold at steps other than the first and the
+    /// last, such as `{ register = "r0", step = 2, value = 156 }`.
+    pub claims: Vec<Claim>,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for VmConfig {
+    fn default() -> Self {
+        Self {
+            program: default_vm_program(),
+            registers: DEFAULT_VM_REGISTERS,
+            trace_length: DEFAULT_VM_TRACE_LENGTH,
+            claims: Vec::new(),
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Multiplies the registers and counts the product down to zero.
+fn default_vm_program() -> Vec<Instruction> {
+    vec![
+        Instruction::Mul,      // r0 = r0 * r1
+        Instruction::Load(0),  // r1 = r0, r0 = 0
+        Instruction::Add,      // loop: r0 = r1
+        Instruction::Load(-1), // r1 = r0, r0 = -1
+        Instruction::Add,      // r0 = r1 - 1
+        Instruction::Jz(5),    // once r0 is zero, loop here forever
+        Instruction::Load(0),  // r1 = r0, r0 = 0
+        Instruction::Jz(2),    // jump back to the start of the loop
+    ]
+}
+
+/// Settings of the Fibonacci example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FibConfig {
+    /// Number of steps in the execution trace, including the padding row; must be a power of
+    /// two.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the padding row.
+    pub seed: u64,
+    /// Number of terms up to the result; must be even and at most `2 * (trace_length - 2)`.
+    /// When set, the trace gains a step counter and a selector which mark the row of the
+    /// result; otherwise the result is the last term before the padding row.
+    pub num_terms: Option<usize>,
+    /// Indexes of further terms of the sequence which the proof asserts, each from 3 to
+    /// `2 * (trace_length - 1)`; without `num_terms`, the last of these is the result already.
+    pub terms: Vec<usize>,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for FibConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_FIB_TRACE_LENGTH,
+            seed: DEFAULT_FIB_SEED,
+            num_terms: None,
+            terms: Vec::new(),
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the greatest common divisor example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GcdConfig {
+    /// First input; the inputs are 16-bit integers.
+    pub a: u16,
+    /// Second input; must not be zero.
+    pub b: u16,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for GcdConfig {
+    fn default() -> Self {
+        Self {
+            a: DEFAULT_GCD_A,
+            b: DEFAULT_GCD_B,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the quadratic map example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuadConfig {
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// First term of the sequence.
+    pub start: u64,
+    /// Constant `c` which is added to the square of each term.
+    pub constant: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for QuadConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_QUAD_TRACE_LENGTH,
+            start: DEFAULT_QUAD_START,
+            constant: DEFAULT_QUAD_CONSTANT,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the is-zero gadget example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IsZeroConfig {
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// Value the counter starts at; must be positive and smaller than the trace length.
+    pub start: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for IsZeroConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_ISZERO_TRACE_LENGTH,
+            start: DEFAULT_ISZERO_START,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the read-only memory example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RomConfig {
+    /// Number of steps in the execution trace; must be a power of two. The trace makes one read
+    /// per step except the last.
+    pub trace_length: usize,
+    /// Values of the public table; must have between 2 and 64 entries.
+    pub table: Vec<u64>,
+    /// Seed of the random number generator which draws the addresses of the reads.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for RomConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_ROM_TRACE_LENGTH,
+            table: DEFAULT_ROM_TABLE.to_vec(),
+            seed: DEFAULT_ROM_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the square root example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SqrtConfig {
+    /// Number of steps in the execution trace; must be a power of two. Every step but the last
+    /// is an iteration of the Babylonian method.
+    pub trace_length: usize,
+    /// Number whose square root is approximated; it is rounded to a multiple of 2^-16, and must
+    /// be positive and below 65536.
+    pub value: f64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for SqrtConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_SQRT_TRACE_LENGTH,
+            value: DEFAULT_SQRT_VALUE,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Merkle root example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MerkleConfig {
+    /// Number of outputs, each computed by its own VDF; must be a power of two between 2 and 64.
+    pub num_leaves: usize,
+    /// Number of steps of every VDF computation; must be at least 2.
+    pub num_steps: usize,
+    /// Starting value of the first VDF computation; the others start from the following values.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for MerkleConfig {
+    fn default() -> Self {
+        Self {
+            num_leaves: DEFAULT_MERKLE_NUM_LEAVES,
+            num_steps: DEFAULT_MERKLE_NUM_STEPS,
+            seed: DEFAULT_MERKLE_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the bitwise operations example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BitwiseConfig {
+    /// Number of steps in the execution trace; must be a power of two. The trace combines one
+    /// pair of words per step except the last.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the pairs of words.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for BitwiseConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_BITWISE_TRACE_LENGTH,
+            seed: DEFAULT_BITWISE_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the less-than comparison example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompareConfig {
+    /// Number of steps in the execution trace; must be a power of two. The trace compares one
+    /// pair of values per step except the last.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the pairs of values.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for CompareConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_COMPARE_TRACE_LENGTH,
+            seed: DEFAULT_COMPARE_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Fibonacci modulo a small modulus example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FibModConfig {
+    /// Number of steps in the execution trace; must be a power of two. The last step holds the
+    /// Fibonacci number `F(trace_length)` reduced modulo `modulus`.
+    pub trace_length: usize,
+    /// Modulus of the sequence; must be between 2 and 2^32. It is typically a small prime such
+    /// as 2^31 - 1, but the reduction works for any modulus.
+    pub modulus: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for FibModConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_FIBMOD_TRACE_LENGTH,
+            modulus: DEFAULT_FIBMOD_MODULUS,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the sorting example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SortConfig {
+    /// Number of steps in the execution trace; must be a power of two from 8 to 64. The list
+    /// holds one value per step except the last.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the values of the list.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for SortConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_SORT_TRACE_LENGTH,
+            seed: DEFAULT_SORT_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the chunked Fibonacci example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FibChunkConfig {
+    /// Number of steps in the execution trace of a single chunk; must be a power of two.
+    pub trace_length: usize,
+    /// Two consecutive Fibonacci terms the first chunk starts from; `prove-chain` starts each
+    /// further chunk from the state the previous one ends in. They must not both be zero.
+    pub start: [u64; 2],
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for FibChunkConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_FIBCHUNK_TRACE_LENGTH,
+            start: DEFAULT_FIBCHUNK_START,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the fixed-base exponentiation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FixedExpConfig {
+    /// Generator whose powers the exponents lead to; must be neither zero nor one.
+    pub generator: u64,
+    /// Number of secret exponents, each of which takes 64 steps of the execution trace; must be
+    /// a power of two.
+    pub num_exponents: usize,
+    /// Seed of the random number generator which draws the exponents.
+    pub seed: u64,
+    /// Values which registers `bit` and `accumulator` hold at steps where the AIR does not assert
+    /// the accumulator, such as `{ register = "bit", step = 0, value = 1 }`.
+    pub claims: Vec<Claim>,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for FixedExpConfig {
+    fn default() -> Self {
+        Self {
+            generator: DEFAULT_FIXEDEXP_GENERATOR,
+            num_exponents: DEFAULT_FIXEDEXP_NUM_EXPONENTS,
+            seed: DEFAULT_FIXEDEXP_SEED,
+            claims: Vec::new(),
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the modular exponentiation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModExpConfig {
+    /// Public exponent of the encryption; must be below 2^31 and coprime to the order of the
+    /// group of units modulo the fixed modulus.
+    pub exponent: u64,
+    /// Number of secret messages, each of which takes 32 steps of the execution trace; must be a
+    /// power of two.
+    pub num_messages: usize,
+    /// Seed of the random number generator which draws the messages.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for ModExpConfig {
+    fn default() -> Self {
+        Self {
+            exponent: DEFAULT_MODEXP_EXPONENT,
+            num_messages: DEFAULT_MODEXP_NUM_MESSAGES,
+            seed: DEFAULT_MODEXP_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the character count example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ByteCountConfig {
+    /// Private text, held one byte of its UTF-8 encoding per step of the execution trace.
+    pub text: String,
+    /// Character whose occurrences are counted; must be ASCII and not NUL.
+    pub character: char,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for ByteCountConfig {
+    fn default() -> Self {
+        Self {
+            text: DEFAULT_BYTECOUNT_TEXT.to_string(),
+            character: DEFAULT_BYTECOUNT_CHARACTER,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the running sum example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunSumConfig {
+    /// Number of steps in the execution trace; must be a power of two. The trace holds one
+    /// value per step.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the values.
+    pub seed: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for RunSumConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_RUNSUM_TRACE_LENGTH,
+            seed: DEFAULT_RUNSUM_SEED,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the polynomial evaluation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HornerConfig {
+    /// Number of steps in the execution trace; must be a power of two. The trace holds one
+    /// coefficient per step, so the polynomial has degree `trace_length - 1`.
+    pub trace_length: usize,
+    /// Seed of the random number generator which draws the coefficients.
+    pub seed: u64,
+    /// Point at which the polynomial is evaluated.
+    pub point: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for HornerConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_HORNER_TRACE_LENGTH,
+            seed: DEFAULT_HORNER_SEED,
+            point: DEFAULT_HORNER_POINT,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the wrapping counter example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WrapConfig {
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// Value of the 8-bit counter on the first step.
+    pub start: u8,
+    /// Amount added to the counter on every step, modulo 256.
+    pub increment: u8,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for WrapConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_WRAP_TRACE_LENGTH,
+            start: DEFAULT_WRAP_START,
+            increment: DEFAULT_WRAP_INCREMENT,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the sliding window sum example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WindowConfig {
+    /// Number of steps in the execution trace; must be a power of two.
+    pub trace_length: usize,
+    /// Number of terms each term is the sum of, which is also the width of the trace; between 2
+    /// and 16.
+    pub width: usize,
+    /// First term of the sequence; the terms before it are zero.
+    pub start: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_WINDOW_TRACE_LENGTH,
+            width: DEFAULT_WINDOW_WIDTH,
+            start: DEFAULT_WINDOW_START,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+/// Settings of the Fibonacci divisibility example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FibDivConfig {
+    /// Number of steps in the execution trace; must be a power of two. The example proves
+    /// whether the Fibonacci number `F(trace_length)` is divisible by `divisor`.
+    pub trace_length: usize,
+    /// Divisor of the test; must be between 2 and 2^16. A divisor of 2 tests whether the term is
+    /// even.
+    pub divisor: u64,
+    pub proof_options: ProofOptionsConfig,
+}
+
+impl Default for FibDivConfig {
+    fn default() -> Self {
+        Self {
+            trace_length: DEFAULT_FIBDIV_TRACE_LENGTH,
+            divisor: DEFAULT_FIBDIV_DIVISOR,
+            proof_options: ProofOptionsConfig::default(),
+        }
+    }
+}
+
+// PROVE REQUESTS
+// ================================================================================================
+
+/// Settings of one example together with the field and proof options to prove it with, as
+/// carried by the requests of the HTTP service and by the lines read by `prove --stdin`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProveRequest {
+    /// Base field; the default field if not set.
+    pub field: Option<FieldType>,
+    /// Preset from which unset proof options are taken; the default preset if not set.
+    pub preset: Option<Preset>,
+    /// Proof options which take precedence over the preset.
+    #[serde(default)]
+    pub options: ProofOptionsConfig,
+    /// Settings of the config section of the example.
+    #[serde(flatten)]
+    pub settings: Map<String, Value>,
+}
+
+impl ProveRequest {
+    /// Returns the run config which selects the named example with the settings of this
+    /// request.
+    ///
+    /// Returns an error if the settings do not form a valid config section of the example.
+    pub fn into_config(self, example: &str) -> Result<RunConfig, String> {
+        let mut config = Map::new();
+        config.insert("example".to_string(), Value::from(example));
+        config.insert(example.to_string(), Value::Object(self.settings));
+        let mut config = serde_json::from_value::<RunConfig>(Value::Object(config))
+            .map_err(|err| format!("invalid settings of example '{}': {}", example, err))?;
+        if let Some(field) = self.field {
+            config.field = field;
+        }
+        if let Some(preset) = self.preset {
+            config.preset = preset;
+        }
+        config.proof_options = self.options;
+        Ok(config)
+    }
+}
+
+// PROOF OPTIONS
+// ================================================================================================
+
+/// A partially specified set of [ProofOptions]; missing values are filled in with defaults.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProofOptionsConfig {
+    pub num_queries: Option<usize>,
+    pub blowup_factor: Option<usize>,
+    pub grinding_factor: Option<u32>,
+    pub hash_fn: Option<HashFn>,
+    pub field_extension: Option<FieldExt>,
+    pub fri_folding_factor: Option<usize>,
+    pub fri_max_remainder_size: Option<usize>,
+}
+
+impl ProofOptionsConfig {
+    /// Returns a copy of these options with all values set in `overrides` taking precedence.
+    pub fn merge(&self, overrides: &ProofOptionsConfig) -> ProofOptionsConfig {
+        ProofOptionsConfig {
+            num_queries: overrides.num_queries.or(self.num_queries),
+            blowup_factor: overrides.blowup_factor.or(self.blowup_factor),
+            grinding_factor: overrides.grinding_factor.or(self.grinding_factor),
+            hash_fn: overrides.hash_fn.or(self.hash_fn),
+            field_extension: overrides.field_extension.or(self.field_extension),
+            fri_folding_factor: overrides.fri_folding_factor.or(self.fri_folding_factor),
+            fri_max_remainder_size: overrides
+                .fri_max_remainder_size
+                .or(self.fri_max_remainder_size),
+        }
+    }
+
+    /// Builds [ProofOptions] from these values, taking the unspecified ones from the default
+    /// [Preset], which does not depend on the field.
+    ///
+    /// # Panics
+    /// Panics if any of the values is outside of the range accepted by [ProofOptions::new()], or
+    /// if the FRI max remainder size is smaller than the square of the folding factor.
+    pub fn to_proof_options(&self) -> ProofOptions {
+        let options = Preset::default()
+            .proof_options(FieldType::default())
+            .merge(self);
+        fn value<T>(value: Option<T>) -> T {
+            value.expect("presets set every proof option")
+        }
+        let folding_factor = value(options.fri_folding_factor);
+        let max_remainder_size = value(options.fri_max_remainder_size);
+
+        // the remainder is folded once more when it is committed to, and can end up with as few
+        // as max_remainder_size / folding_factor elements, which must fill at least two leaves
+        assert!(
+            max_remainder_size >= folding_factor * folding_factor,
+            "FRI max remainder size {} is too small for folding factor {}; it must be at least {}",
+            max_remainder_size,
+            folding_factor,
+            folding_factor * folding_factor
+        );
+        ProofOptions::new(
+            value(options.num_queries),
+            value(options.blowup_factor),
+            value(options.grinding_factor),
+            value(options.hash_fn).into(),
+            value(options.field_extension).into(),
+            folding_factor,
+            max_remainder_size,
+        )
+    }
+}
+
+/// Hash function names as they appear in config files.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum HashFn {
+    #[serde(rename = "blake3_192")]
+    Blake3_192,
+    #[serde(rename = "blake3_256")]
+    Blake3_256,
+    #[serde(rename = "sha3_256")]
+    Sha3_256,
+}
+
+impl From<HashFn> for HashFunction {
+    fn from(hash_fn: HashFn) -> Self {
+        match hash_fn {
+            HashFn::Blake3_192 => HashFunction::Blake3_192,
+            HashFn::Blake3_256 => HashFunction::Blake3_256,
+            HashFn::Sha3_256 => HashFunction::Sha3_256,
+        }
+    }
+}
+
+/// Field extension names as they appear in config files.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldExt {
+    None,
+    Quadratic,
+    Cubic,
+}
+
+impl From<FieldExt> for FieldExtension {
+    fn from(extension: FieldExt) -> Self {
+        match extension {
+            FieldExt::None => FieldExtension::None,
+            FieldExt::Quadratic => FieldExtension::Quadratic,
+            FieldExt::Cubic => FieldExtension::Cubic,
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProofOptionsConfig, RunConfig, VdfConfig};
+    use crate::{field::FieldType, presets::Preset, run_example};
+    use winterfell::FieldExtension;
+
+    #[test]
+    fn fri_overrides_take_precedence_and_are_reported() {
+        let mut config = RunConfig {
+            vdf: VdfConfig {
+                trace_length: 1024,
+                proof_options: ProofOptionsConfig {
+                    fri_folding_factor: Some(16),
+                    fri_max_remainder_size: Some(256),
+                    ..ProofOptionsConfig::default()
+                },
+                ..VdfConfig::default()
+            },
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let coarse = run_example(&config).unwrap();
+        assert_eq!(16, coarse.fri_folding_factor);
+
+        config.override_proof_options(&ProofOptionsConfig {
+            fri_folding_factor: Some(4),
+            fri_max_remainder_size: Some(32),
+            ..ProofOptionsConfig::default()
+        });
+        let fine = run_example(&config).unwrap();
+        assert!(fine.is_verified());
+        assert_eq!(4, fine.fri_folding_factor);
+        assert_eq!(32, fine.fri_max_remainder_size);
+        assert!(fine.fri_layers > coarse.fri_layers);
+    }
+
+    #[test]
+    fn preset_fills_in_options_which_are_not_set() {
+        let config = RunConfig {
+            field: FieldType::F64,
+            preset: Preset::Secure,
+            vdf: VdfConfig {
+                proof_options: ProofOptionsConfig {
+                    num_queries: Some(30),
+                    ..ProofOptionsConfig::default()
+                },
+                ..VdfConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let options = config
+            .proof_options_for(&config.vdf.proof_options)
+            .to_proof_options();
+        assert_eq!(30, options.num_queries());
+        assert_eq!(8, options.blowup_factor());
+        assert_eq!(FieldExtension::Cubic, options.field_extension());
+        assert_eq!(
+            Preset::Balanced
+                .proof_options(FieldType::F128)
+                .to_proof_options(),
+            ProofOptionsConfig::default().to_proof_options()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "too small for folding factor 16")]
+    fn fri_remainder_smaller_than_folding_factor_squared_is_not_accepted() {
+        ProofOptionsConfig {
+            fri_folding_factor: Some(16),
+            fri_max_remainder_size: Some(128),
+            ..ProofOptionsConfig::default()
+        }
+        .to_proof_options();
+    }
+}
@@ -0,0 +1,332 @@
+//! Evaluation of the constraints of an AIR over the actual execution trace of an example.
+//!
+//! The prover does not check a trace against the AIR it proves, so a trace which breaks a
+//! constraint only shows up as a proof which fails to verify. [check_constraints()] evaluates
+//! every transition constraint on every pair of consecutive rows and every boundary assertion,
+//! and names the first constraint and assertion which fail by the labels of the
+//! [AirMetadata](crate::describe::AirMetadata) of the example.
+//!
+//! The same evaluation backs helpers for unit tests of any AIR and trace:
+//! [assert_constraints_hold()], [assert_constraints_fail()], and [assert_constraints_fail_at()]
+//! check an AIR without generating a proof, which takes a fraction of the time of a prove and
+//! verify cycle.
+
+use crate::example::{BaseField, Example};
+use std::fmt;
+use winterfell::{
+    math::{FieldElement, StarkField},
+    Air, EvaluationFrame, ProofOptions, Trace, TraceTable,
+};
+
+// CONSTRAINT CHECK
+// ================================================================================================
+
+/// The first transition constraint which does not evaluate to zero on the trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionFailure<B: StarkField> {
+    /// Label of the constraint.
+    pub constraint: String,
+    /// Row of the trace on which the constraint was evaluated, together with the row after it.
+    pub step: usize,
+    /// Value the constraint evaluates to.
+    pub value: B,
+}
+
+/// The first boundary assertion which does not hold on the trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionFailure<B: StarkField> {
+    /// Label of the column which the assertion is placed on.
+    pub column: String,
+    /// Row of the trace in which the assertion does not hold.
+    pub step: usize,
+    /// Value which the assertion expects.
+    pub expected: B,
+    /// Value found in the trace.
+    pub actual: B,
+}
+
+/// Outcome of evaluating the constraints of an AIR over an execution trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintCheck<B: StarkField> {
+    /// Number of rows in the trace.
+    pub trace_length: usize,
+    /// Number of transition constraints which were evaluated on every pair of rows.
+    pub num_constraints: usize,
+    /// Number of boundary assertions which were checked.
+    pub num_assertions: usize,
+    /// The first transition constraint which fails, searching row by row; `None` if all of them
+    /// hold.
+    pub transition_failure: Option<TransitionFailure<B>>,
+    /// The first boundary assertion which fails, in the order of the assertions of the AIR;
+    /// `None` if all of them hold.
+    pub assertion_failure: Option<AssertionFailure<B>>,
+}
+
+impl<B: StarkField> ConstraintCheck<B> {
+    /// Returns true if the trace satisfies every transition constraint and boundary assertion.
+    pub fn is_satisfied(&self) -> bool {
+        self.transition_failure.is_none() && self.assertion_failure.is_none()
+    }
+}
+
+impl<B: StarkField> fmt::Display for ConstraintCheck<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_satisfied() {
+            return write!(
+                f,
+                "All {} transition constraints and {} assertions hold on the {} rows of the trace",
+                self.num_constraints, self.num_assertions, self.trace_length
+            );
+        }
+        let mut failures = Vec::new();
+        if let Some(failure) = &self.transition_failure {
+            failures.push(format!(
+                "constraint '{}' failed at row {}: evaluates to {}",
+                failure.constraint, failure.step, failure.value
+            ));
+        }
+        if let Some(failure) = &self.assertion_failure {
+            failures.push(format!(
+                "assertion on column '{}' failed at row {}: expected {}, found {}",
+                failure.column, failure.step, failure.expected, failure.actual
+            ));
+        }
+        write!(f, "{}", failures.join("\n"))
+    }
+}
+
+/// Builds the execution trace of the example and evaluates the constraints of its AIR over it.
+///
+/// Returns an error if the metadata of the example does not label every column and constraint
+/// of its AIR.
+pub fn check_constraints<E: Example>(
+    example: &E,
+    options: ProofOptions,
+) -> Result<ConstraintCheck<BaseField<E>>, String> {
+    check_trace_constraints(example, &example.build_trace(), options)
+}
+
+/// Same as [check_constraints()], but for a trace which has already been built, which need not
+/// be the trace of the example.
+pub fn check_trace_constraints<E: Example>(
+    example: &E,
+    trace: &TraceTable<BaseField<E>>,
+    options: ProofOptions,
+) -> Result<ConstraintCheck<BaseField<E>>, String> {
+    let air = E::Air::new(trace.get_info(), example.pub_inputs(), options);
+    let metadata = example.metadata();
+    let columns = metadata.column_labels();
+    let constraints = metadata.constraint_labels();
+    if columns.len() != air.trace_width() {
+        return Err(format!(
+            "metadata labels {} columns, but the trace has {}",
+            columns.len(),
+            air.trace_width()
+        ));
+    }
+    let num_constraints = air.transition_constraint_degrees().len();
+    if constraints.len() != num_constraints {
+        return Err(format!(
+            "metadata labels {} constraints, but the AIR has {}",
+            constraints.len(),
+            num_constraints
+        ));
+    }
+    Ok(check_air_constraints(&air, trace, &columns, &constraints))
+}
+
+/// Same as [check_trace_constraints()], but for an AIR which has already been instantiated;
+/// `columns` and `constraints` must label every column and transition constraint of the AIR.
+pub fn check_air_constraints<A: Air>(
+    air: &A,
+    trace: &TraceTable<A::BaseField>,
+    columns: &[String],
+    constraints: &[String],
+) -> ConstraintCheck<A::BaseField> {
+    let num_constraints = air.transition_constraint_degrees().len();
+
+    // transition constraints apply to every row and the row after it, except the last row
+    let trace_length = trace.length();
+    let mut evaluator = TransitionEvaluator::new(air);
+    let mut transition_failure = None;
+    for step in 0..trace_length - 1 {
+        let result = evaluator.evaluate(trace, step);
+        if let Some(index) = result.iter().position(|&value| value != A::BaseField::ZERO) {
+            transition_failure = Some(TransitionFailure {
+                constraint: constraints[index].clone(),
+                step,
+                value: result[index],
+            });
+            break;
+        }
+    }
+
+    let assertions = air.get_assertions();
+    let mut assertion_failure = None;
+    for assertion in assertions.iter() {
+        let column = assertion.register();
+        assertion.apply(trace_length, |step, expected| {
+            let actual = trace.get(column, step);
+            if assertion_failure.is_none() && actual != expected {
+                assertion_failure = Some(AssertionFailure {
+                    column: columns[column].clone(),
+                    step,
+                    expected,
+                    actual,
+                });
+            }
+        });
+    }
+
+    ConstraintCheck {
+        trace_length,
+        num_constraints,
+        num_assertions: assertions.len(),
+        transition_failure,
+        assertion_failure,
+    }
+}
+
+/// Evaluates every transition constraint of the AIR on the specified row of the trace and the
+/// row after it, and returns the evaluations in the order in which the AIR writes them.
+pub fn evaluate_transition_at<A: Air>(
+    air: &A,
+    trace: &TraceTable<A::BaseField>,
+    step: usize,
+) -> Vec<A::BaseField> {
+    TransitionEvaluator::new(air).evaluate(trace, step).to_vec()
+}
+
+// TEST HELPERS
+// ================================================================================================
+
+/// Panics unless every transition constraint and boundary assertion of the AIR holds on the
+/// trace.
+///
+/// Unlike proving and verifying the trace, this evaluates the AIR once per row, so it is the
+/// quickest way to check an AIR in a unit test. Failures are reported by the index of the
+/// constraint and of the column.
+#[track_caller]
+pub fn assert_constraints_hold<A: Air>(air: &A, trace: &TraceTable<A::BaseField>) {
+    let check = check_indexed_constraints(air, trace);
+    assert!(check.is_satisfied(), "{}", check);
+}
+
+/// Panics if every transition constraint and boundary assertion of the AIR holds on the trace.
+#[track_caller]
+pub fn assert_constraints_fail<A: Air>(air: &A, trace: &TraceTable<A::BaseField>) {
+    let check = check_indexed_constraints(air, trace);
+    assert!(!check.is_satisfied(), "{}", check);
+}
+
+/// Panics unless transition constraint `index` of the AIR fails on the specified row of the
+/// trace and the row after it.
+#[track_caller]
+pub fn assert_constraints_fail_at<A: Air>(
+    air: &A,
+    trace: &TraceTable<A::BaseField>,
+    step: usize,
+    index: usize,
+) {
+    let result = evaluate_transition_at(air, trace, step);
+    assert!(
+        result[index] != A::BaseField::ZERO,
+        "constraint {} holds at row {}",
+        index,
+        step
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Same as [check_air_constraints()], with columns and constraints labelled by their index.
+fn check_indexed_constraints<A: Air>(
+    air: &A,
+    trace: &TraceTable<A::BaseField>,
+) -> ConstraintCheck<A::BaseField> {
+    let columns = index_labels(air.trace_width());
+    let constraints = index_labels(air.transition_constraint_degrees().len());
+    check_air_constraints(air, trace, &columns, &constraints)
+}
+
+/// Returns the labels `0`, `1`, ..., up to `count - 1`.
+pub(crate) fn index_labels(count: usize) -> Vec<String> {
+    (0..count).map(|index| index.to_string()).collect()
+}
+
+/// Evaluates the transition constraints of an AIR on one row of a trace after another, reusing
+/// the periodic columns and buffers of the AIR.
+struct TransitionEvaluator<'a, A: Air> {
+    air: &'a A,
+    periodic_columns: Vec<Vec<A::BaseField>>,
+    frame: EvaluationFrame<A::BaseField>,
+    periodic_values: Vec<A::BaseField>,
+    result: Vec<A::BaseField>,
+}
+
+impl<'a, A: Air> TransitionEvaluator<'a, A> {
+    fn new(air: &'a A) -> Self {
+        let periodic_columns = air.get_periodic_column_values();
+        let num_constraints = air.transition_constraint_degrees().len();
+        Self {
+            air,
+            frame: EvaluationFrame::new(air.trace_width()),
+            periodic_values: vec![A::BaseField::ZERO; periodic_columns.len()],
+            periodic_columns,
+            result: vec![A::BaseField::ZERO; num_constraints],
+        }
+    }
+
+    fn evaluate(&mut self, trace: &TraceTable<A::BaseField>, step: usize) -> &[A::BaseField] {
+        for column in 0..self.air.trace_width() {
+            self.frame.current_mut()[column] = trace.get(column, step);
+            self.frame.next_mut()[column] = trace.get(column, step + 1);
+        }
+        for (value, values) in self
+            .periodic_values
+            .iter_mut()
+            .zip(self.periodic_columns.iter())
+        {
+            *value = values[step % values.len()];
+        }
+        // constraints may be accumulated into the results, as in winterfell's own evaluator
+        self.result.fill(A::BaseField::ZERO);
+        self.air
+            .evaluate_transition(&self.frame, &self.periodic_values, &mut self.result);
+        &self.result
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::check_constraints;
+    use crate::{
+        config::ProofOptionsConfig,
+        example::Example,
+        runner::{self, ExampleCommand},
+        test_utils::example_config,
+        EXAMPLES,
+    };
+
+    #[test]
+    fn trace_of_every_example_satisfies_constraints() {
+        struct CheckConstraints;
+
+        impl ExampleCommand for CheckConstraints {
+            type Output = ();
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+                let check = check_constraints(example, options.to_proof_options()).unwrap();
+                assert!(check.is_satisfied(), "{}: {}", E::descriptor().name, check);
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            runner::with_example(&example_config(name), CheckConstraints).unwrap();
+        }
+    }
+}
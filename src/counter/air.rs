@@ -0,0 +1,127 @@
+use super::BLOCK_LENGTH;
+use crate::{
+    air_utils::{
+        are_equal, cyclic_degrees, first_row_equals, last_row_equals, periodic_reset,
+        transition_degrees, EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Specifies steps on which the counter is incremented and the block input is carried over to
+/// the next step; on the last step of each block both are set by boundary assertions instead.
+const BLOCK_MASK: [u8; BLOCK_LENGTH] = [1, 1, 1, 1, 1, 1, 1, 0];
+
+/// Trace column layout.
+const COUNTER: usize = 0;
+const VALUE: usize = 1;
+const SUM: usize = 2;
+pub const TRACE_WIDTH: usize = 3;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct CounterInputs<B: ExampleField> {
+    pub inputs: Vec<B>,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for CounterInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.inputs.len() as u32);
+        target.write(&self.inputs[..]);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for CounterInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_inputs = source.read_u32()? as usize;
+        Ok(CounterInputs {
+            inputs: B::read_batch_from(source, num_inputs)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// COUNTER AIR
+// ================================================================================================
+
+pub struct CounterAir<B: ExampleField> {
+    context: AirContext<B>,
+    inputs: Vec<B>,
+    result: B,
+}
+
+impl<B: ExampleField> Air for CounterAir<B> {
+    type BaseField = B;
+    type PublicInputs = CounterInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: CounterInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        assert_eq!(
+            trace_info.length(),
+            pub_inputs.inputs.len() * BLOCK_LENGTH,
+            "expected one input per block of the trace"
+        );
+        let mut degrees = cyclic_degrees(&[1, 1], BLOCK_LENGTH);
+        degrees.append(&mut transition_degrees(&[1]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            inputs: pub_inputs.inputs,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let block_flag = periodic_values[0];
+
+        // within a block, the counter is incremented by the block input, and the block input
+        // stays the same
+        result.agg_constraint(
+            COUNTER,
+            block_flag,
+            are_equal(next[COUNTER], current[COUNTER] + current[VALUE]),
+        );
+        result.agg_constraint(VALUE, block_flag, are_equal(next[VALUE], current[VALUE]));
+
+        // on every step, including the first step of a block where the counter is zero, the
+        // counter is added to the sum
+        result[SUM] = are_equal(next[SUM], current[SUM] + next[COUNTER]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the counter is zero at the start of every block, the block inputs appear on the first
+        // step of their blocks, and the sum goes from zero to the result
+        let trace_length = self.trace_length();
+        vec![
+            periodic_reset(COUNTER, BLOCK_LENGTH, B::ZERO),
+            Assertion::sequence(VALUE, 0, BLOCK_LENGTH, self.inputs.clone()),
+            first_row_equals(SUM, B::ZERO),
+            last_row_equals(SUM, trace_length, self.result),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![BLOCK_MASK.iter().map(|&flag| B::from(flag)).collect()]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
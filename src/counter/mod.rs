@@ -0,0 +1,139 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::CounterConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{CounterAir, CounterInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::CounterProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of steps after which the counter resets to zero.
+pub(crate) const BLOCK_LENGTH: usize = 8;
+
+// COUNTER EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the sum of a counter over all steps of the computation, where the
+/// counter resets to zero at the start of every block of [BLOCK_LENGTH] steps and is incremented
+/// by the public input of the current block on every other step.
+///
+/// The AIR of this example makes use of all three kinds of boundary assertions: counter resets
+/// are enforced with a periodic assertion, block inputs are placed into the trace with a
+/// sequence assertion, and the initial and final values of the sum are enforced with single
+/// assertions.
+#[cfg(feature = "std")]
+pub struct CounterExample<B: ExampleField> {
+    inputs: Vec<B>,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> CounterExample<B> {
+    pub fn new(config: &CounterConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let inputs = (0..config.num_blocks as u64)
+            .map(|i| B::from(config.seed + i))
+            .collect::<Vec<_>>();
+
+        // compute result
+        let now = Instant::now();
+        let result = weighted_sum(&inputs);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self { inputs, result })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &CounterConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "number of blocks",
+            "counter.num_blocks",
+            config.num_blocks,
+            1,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for CounterExample<B> {
+    type Air = CounterAir<B>;
+    type Prover = CounterProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        CounterProver::build_trace(&self.inputs)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = CounterConfig::default();
+        ExampleDescriptor::new(
+            "counter",
+            "a running sum of a counter which resets every 8 steps",
+        )
+        .with_default("num_blocks", config.num_blocks)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("COUNTER", 1), ("VALUE", 1), ("SUM", 1)],
+            constraints: vec![
+                ("counter increment", 1),
+                ("block input carried over", 1),
+                ("sum accumulation", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> CounterProver<B> {
+        CounterProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> CounterInputs<B> {
+        CounterInputs {
+            inputs: self.inputs.clone(),
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> CounterInputs<B> {
+        CounterInputs {
+            inputs: self.inputs.clone(),
+            result: self.result + B::ONE,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// The counter takes values `0, value, 2 * value, ..., 7 * value` within a block, so every block
+/// adds `(0 + 1 + ... + 7) * value` to the sum.
+fn weighted_sum<B: ExampleField>(inputs: &[B]) -> B {
+    let counter_sum = B::from((BLOCK_LENGTH * (BLOCK_LENGTH - 1) / 2) as u64);
+    inputs
+        .iter()
+        .fold(B::ZERO, |sum, &value| sum + counter_sum * value)
+}
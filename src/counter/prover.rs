@@ -0,0 +1,69 @@
+use super::{air::TRACE_WIDTH, CounterAir, CounterInputs, BLOCK_LENGTH};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct CounterProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> CounterProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn build_trace(inputs: &[B]) -> TraceTable<B> {
+        let trace_length = inputs.len() * BLOCK_LENGTH;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                state[0] = B::ZERO;
+                state[1] = inputs[0];
+                state[2] = B::ZERO;
+            },
+            |step, state| {
+                // at the end of a block, reset the counter and load the input of the next block;
+                // otherwise, increment the counter by the block input
+                let next_step = step + 1;
+                if next_step % BLOCK_LENGTH == 0 {
+                    state[0] = B::ZERO;
+                    state[1] = inputs[next_step / BLOCK_LENGTH];
+                } else {
+                    state[0] += state[1];
+                }
+                state[2] += state[0];
+            },
+        );
+
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for CounterProver<B> {
+    type BaseField = B;
+    type Air = CounterAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> CounterInputs<B> {
+        let last_step = trace.length() - 1;
+        CounterInputs {
+            inputs: (0..trace.length())
+                .step_by(BLOCK_LENGTH)
+                .map(|step| trace.get(1, step))
+                .collect(),
+            result: trace.get(2, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,67 @@
+use super::CounterExample;
+use crate::{
+    bench,
+    config::CounterConfig,
+    example::{self, Example},
+    verify::{verify_proof, VerifyError},
+};
+use winterfell::{
+    math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions, Serializable,
+};
+
+#[test]
+fn counter_inputs_of_another_length_are_rejected() {
+    // the AIR asserts that there is one input per block, so the verifier panics on these inputs
+    let report = example::run(&build_example(), build_options());
+    let example = CounterExample::<BaseElement>::new(&CounterConfig {
+        num_blocks: 8,
+        ..CounterConfig::default()
+    })
+    .unwrap();
+    let pub_inputs_bytes = example.pub_inputs().to_bytes();
+    let err = verify_proof("counter", &report.proof_bytes, &pub_inputs_bytes).unwrap_err();
+    assert!(matches!(err, VerifyError::Rejected(_)), "{}", err);
+    assert!(err.to_string().contains("one input per block"), "{}", err);
+}
+
+#[test]
+fn counter_verifier_latency_is_measured() {
+    let example = build_example();
+    let report = example::run(&example, build_options());
+    for deserialize in [false, true] {
+        let latency =
+            bench::verifier_latency(&example, &report.proof_bytes, 20, deserialize).unwrap();
+        assert_eq!(20, latency.iterations);
+        assert!(latency.median <= latency.p99);
+        assert!(latency.mean <= latency.p99);
+        assert!(latency.to_string().starts_with("Verified proof 20 times"));
+    }
+
+    let mut proof_bytes = report.proof_bytes.clone();
+    proof_bytes.push(0);
+    assert!(bench::verifier_latency(&example, &proof_bytes, 20, false).is_err());
+    assert!(bench::verifier_latency(&example, &proof_bytes[..100], 20, true).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> CounterExample<BaseElement> {
+    CounterExample::new(&CounterConfig {
+        num_blocks: 16,
+        ..CounterConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,368 @@
+use crate::{
+    config::ProofOptionsConfig,
+    example::{BaseField, Example},
+    validate::{MAX_BLOWUP_FACTOR, MIN_BLOWUP_FACTOR},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt;
+use winterfell::{
+    math::{fft, log2, polynom, FieldElement, StarkField},
+    Air, EvaluationFrame, ProofOptions, Trace, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Factor by which the evaluation domain of the check is larger than the constraint evaluation
+/// domain implied by the declared degrees, so that constraints whose degree is under-declared
+/// still fit into the domain.
+const DOMAIN_HEADROOM: usize = 4;
+
+// DEGREE REPORT
+// ================================================================================================
+
+/// Declared and measured degree of a single transition constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstraintDegree {
+    /// Position of the constraint in the results of `evaluate_transition()`.
+    pub index: usize,
+    /// Degree of the constraint polynomial implied by its declared
+    /// [TransitionConstraintDegree](winterfell::TransitionConstraintDegree).
+    pub declared: usize,
+    /// Degree of the constraint polynomial evaluated over a random trace.
+    pub actual: usize,
+}
+
+impl ConstraintDegree {
+    /// Returns true if the measured degree equals the declared one.
+    pub fn is_match(&self) -> bool {
+        self.declared == self.actual
+    }
+}
+
+/// Declared and measured degrees of all transition constraints of an AIR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DegreeReport {
+    /// Number of steps in the trace over which the constraints were evaluated.
+    pub trace_length: usize,
+    /// Size of the domain over which the constraints were evaluated; a measured degree of one
+    /// less than this may be a degree which does not fit into the domain at all.
+    pub domain_size: usize,
+    /// Degrees of the constraints, in the order in which the AIR evaluates them.
+    pub constraints: Vec<ConstraintDegree>,
+}
+
+impl DegreeReport {
+    /// Returns the constraints whose measured degree differs from the declared one.
+    pub fn mismatches(&self) -> Vec<ConstraintDegree> {
+        self.constraints
+            .iter()
+            .copied()
+            .filter(|constraint| !constraint.is_match())
+            .collect()
+    }
+
+    /// Returns true if every constraint has the degree it declares.
+    pub fn is_consistent(&self) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| constraint.is_match())
+    }
+}
+
+impl fmt::Display for DegreeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Transition constraint degrees over a random trace of {} steps:",
+            self.trace_length
+        )?;
+        let trace_degree = (self.trace_length - 1) as f64;
+        for constraint in self.constraints.iter() {
+            if constraint.is_match() {
+                writeln!(
+                    f,
+                    "  constraint {:>3}: degree {} as declared",
+                    constraint.index, constraint.actual
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "  constraint {:>3}: MISMATCH, declared degree {} ({:.2}x the trace degree) \
+                    but measured degree {} ({:.2}x the trace degree)",
+                    constraint.index,
+                    constraint.declared,
+                    constraint.declared as f64 / trace_degree,
+                    constraint.actual,
+                    constraint.actual as f64 / trace_degree
+                )?;
+            }
+        }
+        match self.mismatches().len() {
+            0 => write!(f, "All {} constraints match", self.constraints.len()),
+            n => write!(
+                f,
+                "{} of {} constraints do not match their declared degree",
+                n,
+                self.constraints.len()
+            ),
+        }
+    }
+}
+
+// DEGREE CHECK
+// ================================================================================================
+
+/// Measures the degree of every transition constraint of the example and compares it to the
+/// degree declared in its AIR.
+///
+/// The constraints are evaluated over a low-degree extension of a trace of random values drawn
+/// from `seed`, which has the same shape as the trace of the example; the periodic columns are
+/// those of the AIR. Interpolating the evaluations of each constraint yields its degree as a
+/// polynomial in the trace columns, which is what the declared degree describes. Unlike the
+/// check in debug builds of the winterfell prover, constraints are not evaluated over the
+/// actual trace, so a constraint whose degree drops on valid traces is still measured at its
+/// full degree.
+pub fn check_degrees<E: Example>(example: &E, options: ProofOptions, seed: u64) -> DegreeReport {
+    let trace_info = example.build_trace().get_info();
+    let air = E::Air::new(trace_info, example.pub_inputs(), options);
+    check_air_degrees(&air, seed)
+}
+
+/// Same as [check_degrees()], but for an AIR which has already been instantiated.
+pub fn check_air_degrees<A: Air>(air: &A, seed: u64) -> DegreeReport {
+    let trace_length = air.trace_length();
+    let blowup_factor = air.ce_blowup_factor() * DOMAIN_HEADROOM;
+    let domain_size = trace_length * blowup_factor;
+    let offset = A::BaseField::GENERATOR;
+
+    // extend random trace columns and the periodic columns over the evaluation domain
+    let mut rng = StdRng::seed_from_u64(seed);
+    let trace_twiddles = fft::get_inv_twiddles::<A::BaseField>(trace_length);
+    let twiddles = fft::get_twiddles::<A::BaseField>(trace_length);
+    let extend = |mut column: Vec<A::BaseField>| {
+        fft::interpolate_poly(&mut column, &trace_twiddles);
+        fft::evaluate_poly_with_offset(&column, &twiddles, offset, blowup_factor)
+    };
+    let trace = (0..air.trace_width())
+        .map(|_| {
+            let column = (0..trace_length)
+                .map(|_| A::BaseField::from(rng.gen::<u64>()))
+                .collect::<Vec<_>>();
+            extend(column)
+        })
+        .collect::<Vec<_>>();
+    let periodic = air
+        .get_periodic_column_values()
+        .into_iter()
+        .map(|values| {
+            let column = (0..trace_length)
+                .map(|step| values[step % values.len()])
+                .collect::<Vec<_>>();
+            extend(column)
+        })
+        .collect::<Vec<_>>();
+
+    // evaluate the transition constraints at every point of the domain; the next row of a
+    // point is the point shifted by the trace domain generator
+    let num_constraints = air.transition_constraint_degrees().len();
+    let mut evaluations = (0..num_constraints)
+        .map(|_| Vec::with_capacity(domain_size))
+        .collect::<Vec<_>>();
+    let mut frame = EvaluationFrame::new(air.trace_width());
+    let mut periodic_values = vec![A::BaseField::ZERO; periodic.len()];
+    let mut result = vec![A::BaseField::ZERO; num_constraints];
+    for step in 0..domain_size {
+        let next_step = (step + blowup_factor) % domain_size;
+        for (column, values) in trace.iter().enumerate() {
+            frame.current_mut()[column] = values[step];
+            frame.next_mut()[column] = values[next_step];
+        }
+        for (value, values) in periodic_values.iter_mut().zip(periodic.iter()) {
+            *value = values[step];
+        }
+        // constraints may be accumulated into the results, as in winterfell's own evaluator
+        result.fill(A::BaseField::ZERO);
+        air.evaluate_transition(&frame, &periodic_values, &mut result);
+        for (evaluations, &value) in evaluations.iter_mut().zip(result.iter()) {
+            evaluations.push(value);
+        }
+    }
+
+    // interpolate the evaluations of each constraint to find its degree
+    let domain_twiddles = fft::get_inv_twiddles::<A::BaseField>(domain_size);
+    let constraints = evaluations
+        .into_iter()
+        .zip(air.transition_constraint_degrees())
+        .enumerate()
+        .map(|(index, (mut evaluations, degree))| {
+            fft::interpolate_poly(&mut evaluations, &domain_twiddles);
+            ConstraintDegree {
+                index,
+                declared: degree.get_evaluation_degree(trace_length),
+                actual: polynom::degree_of(&evaluations),
+            }
+        })
+        .collect();
+
+    DegreeReport {
+        trace_length,
+        domain_size,
+        constraints,
+    }
+}
+
+// DEGREE BOUNDS
+// ================================================================================================
+
+/// Degree of the transition constraints of an AIR, and the blowup factor they require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegreeBounds {
+    /// Highest degree of a transition constraint as a multiple of the degree of the trace
+    /// polynomials, rounded up; a periodic column adds at most one to it.
+    pub max_degree: usize,
+    /// Smallest blowup factor under which winterfell can evaluate the constraints: the degree
+    /// of the constraint of the highest degree, counting each periodic column it reads as one,
+    /// rounded up to a power of two, and at least 2.
+    pub min_blowup_factor: usize,
+}
+
+impl DegreeBounds {
+    /// Returns the bounds of the transition constraints of an instantiated AIR.
+    pub fn of<A: Air>(air: &A) -> Self {
+        let trace_degree = air.trace_length() - 1;
+        let max_degree = air
+            .transition_constraint_degrees()
+            .iter()
+            .map(|degree| {
+                let degree = degree.get_evaluation_degree(air.trace_length());
+                degree.div_ceil(trace_degree)
+            })
+            .max()
+            .unwrap_or_default();
+        DegreeBounds {
+            max_degree,
+            min_blowup_factor: air.ce_blowup_factor(),
+        }
+    }
+}
+
+/// Returns the bounds of the transition constraints of the example for a trace of the specified
+/// shape; the trace does not need to be built.
+///
+/// winterfell refuses to instantiate an AIR under a blowup factor which is too small for its
+/// constraints, so the AIR is instantiated under the largest blowup factor the base field has
+/// room for at this trace length.
+pub fn degree_bounds<E: Example>(example: &E, trace_info: TraceInfo) -> DegreeBounds {
+    let headroom = BaseField::<E>::TWO_ADICITY.saturating_sub(log2(trace_info.length()));
+    let log_blowup = headroom.min(MAX_BLOWUP_FACTOR.trailing_zeros());
+    let options = ProofOptionsConfig {
+        blowup_factor: Some(MIN_BLOWUP_FACTOR.max(1 << log_blowup)),
+        ..ProofOptionsConfig::default()
+    }
+    .to_proof_options();
+    DegreeBounds::of(&E::Air::new(trace_info, example.pub_inputs(), options))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::RunConfig,
+        runner::{self, ExampleCommand},
+        EXAMPLES,
+    };
+    use winterfell::{
+        math::fields::f128::BaseElement, AirContext, Assertion, FieldExtension, HashFunction,
+        TransitionConstraintDegree,
+    };
+
+    #[test]
+    fn degrees_of_every_example_match_declared() {
+        struct CheckDegrees;
+
+        impl ExampleCommand for CheckDegrees {
+            type Output = DegreeReport;
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) -> Self::Output {
+                check_degrees(example, options.to_proof_options(), 0)
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            // the constraints of vm need a blowup factor of 8 under its default settings
+            let config = RunConfig {
+                example: name.to_string(),
+                proof_options: ProofOptionsConfig {
+                    blowup_factor: Some(8),
+                    ..ProofOptionsConfig::default()
+                },
+                ..RunConfig::default()
+            };
+            let report = runner::with_example(&config, CheckDegrees).unwrap();
+            assert!(report.is_consistent(), "{}: {}", name, report);
+        }
+    }
+
+    #[test]
+    fn misdeclared_constraint_degree_is_flagged() {
+        // declaring a linear constraint as quadratic is flagged
+        let trace_length = 64;
+        let air = CounterAir::new(TraceInfo::new(1, trace_length), BaseElement::ONE, options());
+        let report = check_air_degrees(&air, 0);
+        assert_eq!(1, report.mismatches().len());
+        let mismatch = report.mismatches()[0];
+        assert_eq!(0, mismatch.index);
+        assert_eq!(2 * (trace_length - 1), mismatch.declared);
+        assert_eq!(trace_length - 1, mismatch.actual);
+    }
+
+    /// A column which counts up from zero, with its linear constraint declared as quadratic.
+    struct CounterAir {
+        context: AirContext<BaseElement>,
+    }
+
+    impl Air for CounterAir {
+        type BaseField = BaseElement;
+        type PublicInputs = BaseElement;
+
+        fn new(trace_info: TraceInfo, _pub_inputs: BaseElement, options: ProofOptions) -> Self {
+            let degrees = vec![TransitionConstraintDegree::new(2)];
+            Self {
+                context: AirContext::new(trace_info, degrees, options),
+            }
+        }
+
+        fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            _periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+        }
+
+        fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+            vec![Assertion::single(0, 0, BaseElement::ZERO)]
+        }
+
+        fn context(&self) -> &AirContext<Self::BaseField> {
+            &self.context
+        }
+    }
+
+    fn options() -> ProofOptions {
+        ProofOptions::new(
+            28,
+            8,
+            0,
+            HashFunction::Blake3_256,
+            FieldExtension::None,
+            4,
+            32,
+        )
+    }
+}
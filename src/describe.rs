@@ -0,0 +1,650 @@
+//! Descriptions of the AIRs of the examples: their columns, their transition constraints with
+//! the degrees they declare, and their boundary assertions.
+//!
+//! Every example names the groups of its columns and constraints in an [AirMetadata]; everything
+//! else is read from the AIR itself. Which columns a constraint reads is found by evaluating it
+//! over a random frame and changing one cell at a time, so the description stays in step with the
+//! constraints without being maintained by hand. [AirDescription] prints as text, and
+//! [AirDescription::to_dot()] renders it as a graphviz graph. [list_examples()] summarizes every
+//! registered example from its [ExampleDescriptor] and metadata.
+
+use crate::{
+    config::{ProofOptionsConfig, RunConfig},
+    degrees::{degree_bounds, DegreeBounds},
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    plugins::{self, DynExample},
+    runner::{self, ExampleCommand},
+    validate::trace_info,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{fmt, fmt::Write, ops::Range};
+use winterfell::{
+    math::{FieldElement, StarkField},
+    Air, Assertion, EvaluationFrame, ProofOptions, Trace,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Seed of the random frames over which the columns read by each constraint are found.
+const FRAME_SEED: u64 = 0;
+
+/// Number of assertions listed for a column group before the rest are summarized.
+const MAX_LISTED_ASSERTIONS: usize = 3;
+
+// AIR METADATA
+// ================================================================================================
+
+/// Names of the columns and the transition constraints of an AIR.
+///
+/// Both are listed in groups of consecutive columns or constraints which share a name, with the
+/// number of columns or constraints in each group, in the order of the trace and of the results of
+/// `evaluate_transition()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirMetadata {
+    pub columns: Vec<(&'static str, usize)>,
+    pub constraints: Vec<(&'static str, usize)>,
+}
+
+impl AirMetadata {
+    /// Returns a label for every column: the name of its group, followed by the position of the
+    /// column within the group if the group has more than one column.
+    pub fn column_labels(&self) -> Vec<String> {
+        labels(&self.columns)
+    }
+
+    /// Returns a label for every transition constraint, in the order of the results of
+    /// `evaluate_transition()`; labels are formed the same way as those of the columns.
+    pub fn constraint_labels(&self) -> Vec<String> {
+        labels(&self.constraints)
+    }
+}
+
+// AIR DESCRIPTION
+// ================================================================================================
+
+/// A named group of consecutive columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroup {
+    pub name: &'static str,
+    pub columns: Range<usize>,
+}
+
+/// A named group of consecutive transition constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintGroup {
+    pub name: &'static str,
+    pub constraints: Range<usize>,
+    /// Distinct declared degrees of the constraints in the group, in ascending order. A degree is
+    /// that of the constraint polynomial divided by the degree of the trace polynomials, rounded
+    /// up, so periodic columns add to it.
+    pub degrees: Vec<usize>,
+    /// Indices of the column groups which the constraints read in the current row.
+    pub reads_current: Vec<usize>,
+    /// Indices of the column groups which the constraints read in the next row.
+    pub reads_next: Vec<usize>,
+}
+
+/// Boundary assertions on the columns of one group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionGroup {
+    /// Index of the column group.
+    pub group: usize,
+    /// Description of each assertion.
+    pub assertions: Vec<String>,
+}
+
+/// Columns, transition constraints, and boundary assertions of the AIR of an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirDescription {
+    pub example: String,
+    pub trace_length: usize,
+    pub columns: Vec<ColumnGroup>,
+    pub constraints: Vec<ConstraintGroup>,
+    pub assertions: Vec<AssertionGroup>,
+}
+
+impl AirDescription {
+    /// Renders the description as a graphviz graph, with a node per column group, constraint
+    /// group, and group of assertions. Constraints point to the columns they read, with a solid
+    /// edge for the current row and a dashed one for the next row; assertions point to the
+    /// columns they pin down.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph \"{}\" {{", self.example).unwrap();
+        writeln!(dot, "  rankdir=LR;").unwrap();
+        writeln!(dot, "  node [fontname=\"monospace\"];").unwrap();
+        for (index, group) in self.columns.iter().enumerate() {
+            writeln!(
+                dot,
+                "  col{} [shape=box, label=\"{}\"];",
+                index,
+                escape(&describe_columns(group))
+            )
+            .unwrap();
+        }
+        for (index, group) in self.constraints.iter().enumerate() {
+            writeln!(
+                dot,
+                "  con{} [shape=ellipse, label=\"{}\\n{}\"];",
+                index,
+                escape(&describe_constraints(group)),
+                describe_degrees(&group.degrees)
+            )
+            .unwrap();
+            for column in group.reads_current.iter() {
+                writeln!(dot, "  con{} -> col{};", index, column).unwrap();
+            }
+            for column in group.reads_next.iter() {
+                writeln!(dot, "  con{} -> col{} [style=dashed];", index, column).unwrap();
+            }
+        }
+        for (index, group) in self.assertions.iter().enumerate() {
+            writeln!(
+                dot,
+                "  asrt{} [shape=note, label=\"{}\"];",
+                index,
+                escape(&summarize_assertions(&group.assertions).join("\n"))
+            )
+            .unwrap();
+            writeln!(dot, "  asrt{} -> col{};", index, group.group).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl fmt::Display for AirDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "AIR of {} over a trace of {} steps",
+            self.example, self.trace_length
+        )?;
+        writeln!(f, "Columns:")?;
+        for group in self.columns.iter() {
+            writeln!(f, "  {}", describe_columns(group))?;
+        }
+        writeln!(f, "Transition constraints:")?;
+        let names = |groups: &[usize]| {
+            groups
+                .iter()
+                .map(|&group| self.columns[group].name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        for group in self.constraints.iter() {
+            write!(
+                f,
+                "  {}: {}, reads {}",
+                describe_constraints(group),
+                describe_degrees(&group.degrees),
+                names(&group.reads_current)
+            )?;
+            if !group.reads_next.is_empty() {
+                write!(f, " and next {}", names(&group.reads_next))?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "Boundary assertions:")?;
+        for group in self.assertions.iter() {
+            for assertion in summarize_assertions(&group.assertions) {
+                write!(f, "\n  {}: {}", self.columns[group.group].name, assertion)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// DESCRIBE
+// ================================================================================================
+
+/// Describes the AIR of the example, instantiated for the trace of the example.
+///
+/// Returns an error if the metadata of the example does not name every column and constraint of
+/// its AIR.
+pub fn describe<E: Example>(
+    name: &str,
+    example: &E,
+    options: ProofOptions,
+) -> Result<AirDescription, String> {
+    let trace_info = example.build_trace().get_info();
+    let air = E::Air::new(trace_info, example.pub_inputs(), options);
+    let metadata = example.metadata();
+
+    let columns = to_ranges(&metadata.columns);
+    let constraints = to_ranges(&metadata.constraints);
+    let num_columns = columns.last().map_or(0, |(_, range)| range.end);
+    let num_constraints = constraints.last().map_or(0, |(_, range)| range.end);
+    if num_columns != air.trace_width() {
+        return Err(format!(
+            "metadata of example '{}' names {} columns, but its trace has {}",
+            name,
+            num_columns,
+            air.trace_width()
+        ));
+    }
+    let degrees = air.transition_constraint_degrees();
+    if num_constraints != degrees.len() {
+        return Err(format!(
+            "metadata of example '{}' names {} constraints, but its AIR has {}",
+            name,
+            num_constraints,
+            degrees.len()
+        ));
+    }
+    let group_of = |column: usize| {
+        columns
+            .iter()
+            .position(|(_, range)| range.contains(&column))
+            .expect("every column is in a group")
+    };
+
+    let trace_length = air.trace_length();
+    let (reads_current, reads_next) = find_reads(&air);
+    let constraints = constraints
+        .into_iter()
+        .map(|(name, range)| {
+            let mut group_degrees = degrees[range.clone()]
+                .iter()
+                .map(|degree| {
+                    let degree = degree.get_evaluation_degree(trace_length);
+                    (degree + trace_length - 2) / (trace_length - 1)
+                })
+                .collect::<Vec<_>>();
+            group_degrees.sort_unstable();
+            group_degrees.dedup();
+            let groups = |reads: &[Vec<usize>]| {
+                let mut groups = reads[range.clone()]
+                    .iter()
+                    .flatten()
+                    .map(|&column| group_of(column))
+                    .collect::<Vec<_>>();
+                groups.sort_unstable();
+                groups.dedup();
+                groups
+            };
+            ConstraintGroup {
+                name,
+                degrees: group_degrees,
+                reads_current: groups(&reads_current),
+                reads_next: groups(&reads_next),
+                constraints: range,
+            }
+        })
+        .collect();
+
+    let mut assertions = Vec::<AssertionGroup>::new();
+    for assertion in air.get_assertions() {
+        let group = group_of(assertion.register());
+        let description = describe_assertion(&assertion, &columns[group].1);
+        match assertions.iter_mut().find(|entry| entry.group == group) {
+            Some(entry) => entry.assertions.push(description),
+            None => assertions.push(AssertionGroup {
+                group,
+                assertions: vec![description],
+            }),
+        }
+    }
+    assertions.sort_by_key(|entry| entry.group);
+
+    Ok(AirDescription {
+        example: name.to_string(),
+        trace_length,
+        columns: columns
+            .into_iter()
+            .map(|(name, columns)| ColumnGroup { name, columns })
+            .collect(),
+        constraints,
+        assertions,
+    })
+}
+
+// EXAMPLE LIST
+// ================================================================================================
+
+/// Summary of an example instantiated with its default settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleSummary {
+    pub descriptor: ExampleDescriptor,
+    /// Number of columns of the execution trace.
+    pub trace_width: usize,
+    /// Number of transition constraints of the AIR.
+    pub num_constraints: usize,
+    /// Highest degree of the transition constraints and the smallest blowup factor which they
+    /// accept.
+    pub degrees: DegreeBounds,
+}
+
+impl fmt::Display for ExampleSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} - {}",
+            self.descriptor.name, self.descriptor.description
+        )?;
+        write!(
+            f,
+            "  {}, {} of degree at most {}, blowup factor at least {}",
+            count(self.trace_width, "column"),
+            count(self.num_constraints, "transition constraint"),
+            self.degrees.max_degree,
+            self.degrees.min_blowup_factor
+        )?;
+        for (setting, value) in self.descriptor.defaults.iter() {
+            write!(f, "\n  {} = {}", setting, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes every example of [EXAMPLES](crate::EXAMPLES), in order, each instantiated with its
+/// default settings in the default field, followed by the examples registered by other crates
+/// (see [plugins](crate::plugins)).
+///
+/// Returns an error if an example rejects its default settings.
+pub fn list_examples() -> Result<Vec<ExampleSummary>, ExampleError> {
+    plugins::example_names()
+        .into_iter()
+        .map(|name| {
+            let config = RunConfig {
+                example: name.to_string(),
+                ..RunConfig::default()
+            };
+            runner::with_example(&config, Summarize { config: &config })
+        })
+        .collect()
+}
+
+/// Summarizes the example it is executed against, which the config selects.
+struct Summarize<'a> {
+    config: &'a RunConfig,
+}
+
+impl<'a> ExampleCommand for Summarize<'a> {
+    type Output = ExampleSummary;
+
+    // the summary reports the blowup factor which the example needs
+    const NEEDS_PROVABLE_OPTIONS: bool = false;
+
+    fn execute<E: Example>(self, example: &E, _options: ProofOptionsConfig) -> ExampleSummary {
+        let degrees = degree_bounds(example, trace_info(self.config));
+        summarize(E::descriptor(), &example.metadata(), degrees)
+    }
+
+    fn execute_registered(
+        self,
+        example: &dyn DynExample,
+        _options: ProofOptionsConfig,
+    ) -> Result<ExampleSummary, ExampleError> {
+        Ok(summarize(
+            example.descriptor(),
+            &example.metadata(),
+            example.degree_bounds(),
+        ))
+    }
+}
+
+fn summarize(
+    descriptor: ExampleDescriptor,
+    metadata: &AirMetadata,
+    degrees: DegreeBounds,
+) -> ExampleSummary {
+    let total = |groups: &[(&'static str, usize)]| groups.iter().map(|(_, n)| n).sum();
+    ExampleSummary {
+        descriptor,
+        trace_width: total(&metadata.columns),
+        num_constraints: total(&metadata.constraints),
+        degrees,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a number of things with the noun in singular or plural.
+fn count(n: usize, noun: &str) -> String {
+    match n {
+        1 => format!("1 {}", noun),
+        _ => format!("{} {}s", n, noun),
+    }
+}
+
+/// Turns groups with sizes into groups with ranges of consecutive indices.
+fn to_ranges(groups: &[(&'static str, usize)]) -> Vec<(&'static str, Range<usize>)> {
+    let mut start = 0;
+    groups
+        .iter()
+        .map(|&(name, size)| {
+            start += size;
+            (name, start - size..start)
+        })
+        .collect()
+}
+
+fn labels(groups: &[(&'static str, usize)]) -> Vec<String> {
+    groups
+        .iter()
+        .flat_map(|&(name, size)| {
+            (0..size).map(move |i| match size {
+                1 => name.to_string(),
+                _ => format!("{}[{}]", name, i),
+            })
+        })
+        .collect()
+}
+
+/// Returns, for every constraint, the columns it reads in the current and in the next row.
+///
+/// The constraints are evaluated over a random frame and random periodic values, and again with
+/// each cell of the frame replaced by another random value; a constraint reads a cell if its
+/// evaluation changes. A constraint which only reads a cell through a factor that happens to be
+/// zero in the random frame would be missed, which for random values is vanishingly unlikely.
+fn find_reads<A: Air>(air: &A) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let width = air.trace_width();
+    let num_constraints = air.transition_constraint_degrees().len();
+    let mut rng = StdRng::seed_from_u64(FRAME_SEED);
+    let mut random = || A::BaseField::from(rng.gen::<u64>());
+
+    let mut frame = EvaluationFrame::new(width);
+    for column in 0..width {
+        frame.current_mut()[column] = random();
+        frame.next_mut()[column] = random();
+    }
+    let periodic_values = air
+        .get_periodic_column_values()
+        .iter()
+        .map(|_| random())
+        .collect::<Vec<_>>();
+    let evaluate = |frame: &EvaluationFrame<A::BaseField>| {
+        // constraints may be accumulated into the results, as in winterfell's own evaluator
+        let mut result = vec![A::BaseField::ZERO; num_constraints];
+        air.evaluate_transition(frame, &periodic_values, &mut result);
+        result
+    };
+    let baseline = evaluate(&frame);
+
+    let mut reads = [
+        vec![Vec::new(); num_constraints],
+        vec![Vec::new(); num_constraints],
+    ];
+    for column in 0..width {
+        for (row, reads) in reads.iter_mut().enumerate() {
+            let original = row_mut(&mut frame, row)[column];
+            row_mut(&mut frame, row)[column] = original + A::BaseField::ONE + random();
+            for (constraint, (&value, &base)) in
+                evaluate(&frame).iter().zip(baseline.iter()).enumerate()
+            {
+                if value != base {
+                    reads[constraint].push(column);
+                }
+            }
+            row_mut(&mut frame, row)[column] = original;
+        }
+    }
+    let [current, next] = reads;
+    (current, next)
+}
+
+/// Returns the current row of the frame if `row` is zero, and the next row otherwise.
+fn row_mut<B: FieldElement>(frame: &mut EvaluationFrame<B>, row: usize) -> &mut [B] {
+    if row == 0 {
+        frame.current_mut()
+    } else {
+        frame.next_mut()
+    }
+}
+
+fn describe_assertion<B: StarkField>(assertion: &Assertion<B>, columns: &Range<usize>) -> String {
+    let column = if columns.len() > 1 {
+        format!("[{}] ", assertion.register() - columns.start)
+    } else {
+        String::new()
+    };
+    let value = assertion.values()[0];
+    if assertion.is_single() {
+        format!("{}= {} at step {}", column, value, assertion.first_step())
+    } else if assertion.is_periodic() {
+        format!(
+            "{}= {} every {} steps from step {}",
+            column,
+            value,
+            assertion.stride(),
+            assertion.first_step()
+        )
+    } else {
+        format!(
+            "{}= sequence of {} values every {} steps from step {}",
+            column,
+            assertion.values().len(),
+            assertion.stride(),
+            assertion.first_step()
+        )
+    }
+}
+
+fn summarize_assertions(assertions: &[String]) -> Vec<String> {
+    let mut lines = assertions
+        .iter()
+        .take(MAX_LISTED_ASSERTIONS)
+        .cloned()
+        .collect::<Vec<_>>();
+    if assertions.len() > MAX_LISTED_ASSERTIONS {
+        lines.push(format!(
+            "... and {} more",
+            assertions.len() - MAX_LISTED_ASSERTIONS
+        ));
+    }
+    lines
+}
+
+fn describe_columns(group: &ColumnGroup) -> String {
+    match group.columns.len() {
+        1 => format!("{} (column {})", group.name, group.columns.start),
+        n => format!(
+            "{} ({} columns from {})",
+            group.name, n, group.columns.start
+        ),
+    }
+}
+
+fn describe_constraints(group: &ConstraintGroup) -> String {
+    match group.constraints.len() {
+        1 => group.name.to_string(),
+        n => format!("{} (x{})", group.name, n),
+    }
+}
+
+fn describe_degrees(degrees: &[usize]) -> String {
+    let degrees = degrees
+        .iter()
+        .map(|degree| degree.to_string())
+        .collect::<Vec<_>>();
+    match degrees.len() {
+        1 => format!("degree {}", degrees[0]),
+        _ => format!("degrees {}", degrees.join(", ")),
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLES;
+
+    #[test]
+    fn metadata_of_every_example_names_every_column_and_constraint() {
+        struct Describe<'a>(&'a str);
+
+        impl ExampleCommand for Describe<'_> {
+            type Output = Result<AirDescription, String>;
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) -> Self::Output {
+                describe(self.0, example, options.to_proof_options())
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            // the constraints of vm need a blowup factor of 8 under its default settings
+            let config = RunConfig {
+                example: name.to_string(),
+                proof_options: ProofOptionsConfig {
+                    blowup_factor: Some(8),
+                    ..ProofOptionsConfig::default()
+                },
+                ..RunConfig::default()
+            };
+            let description = runner::with_example(&config, Describe(name)).unwrap();
+            description.unwrap_or_else(|err| panic!("{}: {}", name, err));
+        }
+    }
+
+    #[test]
+    fn list_summarizes_every_example_with_its_defaults() {
+        // examples registered by other tests follow those of this crate
+        let summaries = list_examples().unwrap();
+        let summaries = &summaries[..EXAMPLES.len()];
+        let names = summaries.iter().map(|summary| summary.descriptor.name);
+        assert_eq!(EXAMPLES.to_vec(), names.collect::<Vec<_>>());
+
+        let fib = &summaries[8];
+        assert_eq!((2, 2), (fib.trace_width, fib.num_constraints));
+        let bounds = DegreeBounds {
+            max_degree: 2,
+            min_blowup_factor: 2,
+        };
+        assert_eq!(bounds, fib.degrees);
+        // the fib constraints read a periodic column, and the address range check of vm has the
+        // degree of its program, which has 8 instructions
+        assert_eq!(8, summaries[7].degrees.min_blowup_factor);
+        assert!(
+            fib.to_string().contains("\n  trace_length = 4096\n"),
+            "{}",
+            fib
+        );
+
+        // the defaults of every example read back as its config section; configs are compared
+        // by their debug output, as they do not implement PartialEq
+        for summary in summaries.iter() {
+            let descriptor = &summary.descriptor;
+            let mut source = format!("example = \"{}\"\n[{}]\n", descriptor.name, descriptor.name);
+            for (setting, value) in descriptor.defaults.iter() {
+                source.push_str(&format!("{} = {}\n", setting, value));
+            }
+            let config = toml::from_str::<RunConfig>(&source)
+                .unwrap_or_else(|err| panic!("{}: {}", descriptor.name, err));
+            let expected = RunConfig {
+                example: descriptor.name.to_string(),
+                ..RunConfig::default()
+            };
+            assert_eq!(format!("{:?}", expected), format!("{:?}", config));
+        }
+    }
+}
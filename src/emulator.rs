@@ -0,0 +1,157 @@
+//! Comparison of execution traces against independent emulators of their computations.
+//!
+//! A bug in trace generation usually shows up as a proof which fails to verify, or as a panic
+//! in the prover, neither of which says where the trace went wrong. An [Emulator] runs the
+//! computation of an example on its own, without building a trace, and exposes its state after
+//! every step as the values which a few of the trace columns are expected to hold;
+//! [check_trace()] compares the trace against it row by row and reports the first divergence.
+
+use crate::example::{BaseField, Example};
+use std::fmt;
+use winterfell::{math::StarkField, Trace, TraceTable};
+
+// EMULATOR
+// ================================================================================================
+
+/// A trace column which an [Emulator] predicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmulatedColumn {
+    /// Name of the register held by the column.
+    pub name: &'static str,
+    /// Index of the column in the execution trace.
+    pub index: usize,
+}
+
+/// Runs the computation of an example step by step, independently of its trace builder.
+pub trait Emulator<B: StarkField> {
+    /// Columns of the trace which the emulator predicts, in the order in which [state()]
+    /// returns their values.
+    ///
+    /// [state()]: Emulator::state
+    fn columns(&self) -> Vec<EmulatedColumn>;
+
+    /// Returns the values which the predicted columns hold in the row of the current step.
+    fn state(&self) -> Vec<B>;
+
+    /// Advances the computation by one step.
+    fn step(&mut self);
+}
+
+// TRACE CHECK
+// ================================================================================================
+
+/// The first cell of a trace which differs from the state of the emulator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence<B: StarkField> {
+    /// Row of the trace in which the cell is located.
+    pub step: usize,
+    /// Column of the trace in which the cell is located.
+    pub column: EmulatedColumn,
+    /// Value computed by the emulator.
+    pub expected: B,
+    /// Value found in the trace.
+    pub actual: B,
+}
+
+/// Outcome of comparing an execution trace against an emulator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceCheck<B: StarkField> {
+    /// Number of rows in the trace.
+    pub trace_length: usize,
+    /// Columns which were compared.
+    pub columns: Vec<EmulatedColumn>,
+    /// The first cell in which the trace differs from the emulator, searching row by row;
+    /// `None` if every compared cell matches.
+    pub divergence: Option<Divergence<B>>,
+}
+
+impl<B: StarkField> TraceCheck<B> {
+    /// Returns true if the trace matches the emulator in every compared cell.
+    pub fn is_match(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+impl<B: StarkField> fmt::Display for TraceCheck<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self
+            .columns
+            .iter()
+            .map(|column| column.name)
+            .collect::<Vec<_>>();
+        match &self.divergence {
+            None => write!(
+                f,
+                "All {} rows of the trace match the emulator in columns {}",
+                self.trace_length,
+                names.join(", ")
+            ),
+            Some(divergence) => write!(
+                f,
+                "Trace diverges from the emulator at step {} in column {} ({}): expected {}, \
+                found {}",
+                divergence.step,
+                divergence.column.index,
+                divergence.column.name,
+                divergence.expected,
+                divergence.actual
+            ),
+        }
+    }
+}
+
+/// Builds the execution trace of the example and compares it against the emulator of the
+/// example.
+///
+/// Returns an error if the example does not provide an emulator.
+pub fn check_trace<E: Example>(example: &E) -> Result<TraceCheck<BaseField<E>>, String> {
+    let mut emulator = example
+        .emulator()
+        .ok_or_else(|| "example does not provide an emulator to check its trace".to_string())?;
+    Ok(compare(&example.build_trace(), &mut *emulator))
+}
+
+/// Compares every row of the trace against the state of the emulator at the same step.
+///
+/// # Panics
+/// Panics if the emulator predicts a column which the trace does not have.
+pub fn compare<B: StarkField>(
+    trace: &TraceTable<B>,
+    emulator: &mut dyn Emulator<B>,
+) -> TraceCheck<B> {
+    let columns = emulator.columns();
+    for column in columns.iter() {
+        assert!(
+            column.index < trace.width(),
+            "emulated column {} ({}) is not in a trace of {} columns",
+            column.index,
+            column.name,
+            trace.width()
+        );
+    }
+
+    let mut divergence = None;
+    'rows: for step in 0..trace.length() {
+        if step > 0 {
+            emulator.step();
+        }
+        for (&column, expected) in columns.iter().zip(emulator.state()) {
+            let actual = trace.get(column.index, step);
+            if actual != expected {
+                divergence = Some(Divergence {
+                    step,
+                    column,
+                    expected,
+                    actual,
+                });
+                break 'rows;
+            }
+        }
+    }
+
+    TraceCheck {
+        trace_length: trace.length(),
+        columns,
+        divergence,
+    }
+}
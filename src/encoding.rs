@@ -0,0 +1,261 @@
+//! Text encodings of proof files and public inputs, so that they can be pasted into JSON
+//! configs, chat messages, and web demos, which have no room for binary files.
+//!
+//! `run --encode base64|hex` writes the files of `--save-proof` and `--save-inputs` as text,
+//! and reads the proof back to check that it still verifies after the round trip; `verify
+//! --encode` reads files written that way. Whitespace in encoded text, such as the line breaks a
+//! chat client inserts into a pasted proof, is skipped when it is decoded.
+//!
+//! Proofs of large traces run to megabytes, so text is encoded straight into the writer and
+//! decoded straight from the reader through buffers of [CHUNK_BYTES]; the bytes of a proof are
+//! never held next to the text which encodes them.
+
+use crate::error::ExampleError;
+use base64::{engine::general_purpose::STANDARD, read::DecoderReader, write::EncoderWriter};
+use clap::ValueEnum;
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bytes encoded or decoded at a time.
+pub const CHUNK_BYTES: usize = 4096;
+
+/// Digits of the hex encoding.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+// ENCODING
+// ================================================================================================
+
+/// Text encoding of a binary file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    /// Standard base64 with padding; four characters for every three bytes.
+    Base64,
+    /// Lowercase hex; two characters for every byte. Uppercase digits are accepted when
+    /// decoding.
+    Hex,
+}
+
+impl Encoding {
+    /// Writes the bytes into the writer in this encoding, followed by a newline.
+    pub fn encode_to<W: Write>(self, bytes: &[u8], mut writer: W) -> io::Result<()> {
+        match self {
+            Encoding::Base64 => {
+                let mut encoder = EncoderWriter::new(&mut writer, &STANDARD);
+                for chunk in bytes.chunks(CHUNK_BYTES) {
+                    encoder.write_all(chunk)?;
+                }
+                encoder.finish()?;
+            }
+            Encoding::Hex => {
+                let mut text = [0; 2 * CHUNK_BYTES];
+                for chunk in bytes.chunks(CHUNK_BYTES) {
+                    for (byte, digits) in chunk.iter().zip(text.chunks_mut(2)) {
+                        digits[0] = HEX_DIGITS[(byte >> 4) as usize];
+                        digits[1] = HEX_DIGITS[(byte & 0xf) as usize];
+                    }
+                    writer.write_all(&text[..2 * chunk.len()])?;
+                }
+            }
+        }
+        writer.write_all(b"\n")?;
+        writer.flush()
+    }
+
+    /// Reads text in this encoding from the reader and returns the bytes it encodes.
+    ///
+    /// Returns an error of kind [InvalidData](io::ErrorKind::InvalidData) if the text, with
+    /// whitespace skipped, is not valid in this encoding.
+    pub fn decode_from<R: Read>(self, reader: R) -> io::Result<Vec<u8>> {
+        let mut reader = SkipWhitespace(reader);
+        let mut bytes = Vec::new();
+        match self {
+            Encoding::Base64 => {
+                DecoderReader::new(reader, &STANDARD).read_to_end(&mut bytes)?;
+            }
+            Encoding::Hex => {
+                let mut text = [0; 2 * CHUNK_BYTES];
+                let mut len = 0;
+                loop {
+                    let n = reader.read(&mut text[len..])?;
+                    len += n;
+                    let num_pairs = len / 2;
+                    for digits in text[..2 * num_pairs].chunks(2) {
+                        bytes.push(hex_value(digits[0])? << 4 | hex_value(digits[1])?);
+                    }
+                    text.copy_within(2 * num_pairs..len, 0);
+                    len -= 2 * num_pairs;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                if len > 0 {
+                    return Err(invalid_data("hex text has an odd number of digits"));
+                }
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the bytes encoded as a line of text, without the trailing newline.
+    pub fn encode(self, bytes: &[u8]) -> String {
+        let mut text = Vec::new();
+        self.encode_to(bytes, &mut text)
+            .expect("writing into a vector does not fail");
+        text.pop();
+        String::from_utf8(text).expect("encoded text is ASCII")
+    }
+
+    /// Returns the bytes which the text encodes; see [decode_from()](Self::decode_from).
+    pub fn decode(self, text: &str) -> Result<Vec<u8>, String> {
+        self.decode_from(text.as_bytes())
+            .map_err(|err| format!("invalid {} text: {}", self, err))
+    }
+
+    /// Writes a file holding `what` with the bytes in this encoding.
+    pub fn write_file(self, what: &str, path: &Path, bytes: &[u8]) -> Result<(), ExampleError> {
+        File::create(path)
+            .and_then(|file| self.encode_to(bytes, BufWriter::new(file)))
+            .map_err(|err| ExampleError::write(what, path, err))
+    }
+
+    /// Reads a file holding `what` in this encoding and returns the bytes it encodes.
+    pub fn read_file(self, what: &str, path: &Path) -> Result<Vec<u8>, ExampleError> {
+        File::open(path)
+            .and_then(|file| self.decode_from(BufReader::new(file)))
+            .map_err(|err| ExampleError::read(&format!("{} {}", self, what), path, err))
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Encoding::Base64 => write!(f, "base64"),
+            Encoding::Hex => write!(f, "hex"),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// A reader which leaves out the ASCII whitespace of the reader it wraps.
+struct SkipWhitespace<R>(R);
+
+impl<R: Read> Read for SkipWhitespace<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let n = self.0.read(buf)?;
+            if n == 0 {
+                return Ok(0);
+            }
+            let mut len = 0;
+            for i in 0..n {
+                if !buf[i].is_ascii_whitespace() {
+                    buf[len] = buf[i];
+                    len += 1;
+                }
+            }
+            if len > 0 {
+                return Ok(len);
+            }
+        }
+    }
+}
+
+fn hex_value(digit: u8) -> io::Result<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(invalid_data(&format!(
+            "'{}' is not a hex digit",
+            digit.escape_ascii()
+        ))),
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoding, CHUNK_BYTES};
+    use crate::{
+        example,
+        proof_file::ProofFile,
+        test_utils::{vdf_example, vdf_options},
+        verify::verify_proof,
+    };
+    use std::fs;
+
+    #[test]
+    fn encoded_proof_files_verify_after_round_trip() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use std::{env, process};
+
+        let report = example::run(&vdf_example(), vdf_options());
+        let file_bytes = ProofFile::new("vdf", report.proof_bytes.clone())
+            .unwrap()
+            .to_bytes();
+        // the proof spans several chunks, so the streaming encoders carry text across them
+        assert!(file_bytes.len() > 2 * CHUNK_BYTES);
+
+        let dir = env::temp_dir().join(format!("stark-examples-encode-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for encoding in [Encoding::Base64, Encoding::Hex] {
+            let path = dir.join(format!("vdf.proof.{}", encoding));
+            encoding.write_file("proof", &path, &file_bytes).unwrap();
+            let text = fs::read_to_string(&path).unwrap();
+            assert_eq!(format!("{}\n", encoding.encode(&file_bytes)), text);
+
+            let decoded = encoding.read_file("proof", &path).unwrap();
+            assert_eq!(file_bytes, decoded);
+            assert_eq!(
+                Ok(()),
+                verify_proof("vdf", &decoded, &report.pub_inputs_bytes)
+            );
+
+            // line breaks inserted when the text is pasted are skipped
+            let wrapped = text
+                .as_bytes()
+                .chunks(76)
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect::<Vec<_>>()
+                .join("\r\n");
+            assert_eq!(file_bytes, encoding.decode(&wrapped).unwrap());
+
+            // a character which is not part of the encoding is reported
+            let err = encoding
+                .decode(&text.replacen(&text[..1], "!", 1))
+                .unwrap_err();
+            assert!(
+                err.starts_with(&format!("invalid {} text", encoding)),
+                "{}",
+                err
+            );
+        }
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            STANDARD.encode(&file_bytes),
+            Encoding::Base64.encode(&file_bytes)
+        );
+        assert_eq!("00abff", Encoding::Hex.encode(&[0x00, 0xab, 0xff]));
+        assert_eq!(
+            vec![0x00, 0xab, 0xff],
+            Encoding::Hex.decode("00ABff").unwrap()
+        );
+        assert!(Encoding::Hex.decode("abc").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! Errors of the library API and the exit codes the command line reports them with.
+//!
+//! Functions which select, prove, or verify an example as a whole, such as
+//! [run_example()](crate::run_example) and [RunConfig::load()](crate::config::RunConfig::load),
+//! return an [ExampleError], whose variant tells a caller which stage failed without parsing the
+//! message. So do helpers whose failures span stages, such as reading and parsing a file. Helpers
+//! whose failures all belong to one stage, such as the validation of a config section, report
+//! them as strings, which their callers wrap into the variant of that stage; an [ExampleError]
+//! converts back into a string for code which only passes messages on.
+
+use crate::verify_core::VerifyError;
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+// EXAMPLE ERROR
+// ================================================================================================
+
+/// Reason an example could not be run or its proof was not accepted.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ExampleError {
+    /// The config or the command line selects an unknown example, or settings which it cannot
+    /// prove; the message names the setting.
+    #[error("{0}")]
+    Config(String),
+    /// The execution trace could not be built, such as when checkpointed chunks cannot be
+    /// written or read.
+    #[error("failed to build the execution trace: {0}")]
+    Trace(String),
+    /// The prover failed to generate a proof of the execution trace.
+    #[error("failed to generate a proof: {0}")]
+    Proving(String),
+    /// The proof was not accepted by the verifier.
+    #[error("proof rejected: {0}")]
+    Verification(#[from] VerifyError),
+    /// A file could not be read or written.
+    #[error("failed to {action} {what} {}: {source}", path.display())]
+    Io {
+        /// Either "read" or "write".
+        action: &'static str,
+        /// What the file holds, such as "config file" or "proof".
+        what: String,
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ExampleError {
+    /// Returns an error for a file holding `what` which could not be read.
+    pub fn read(what: &str, path: impl Into<PathBuf>, source: io::Error) -> Self {
+        ExampleError::Io {
+            action: "read",
+            what: what.to_string(),
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Returns an error for a file holding `what` which could not be written.
+    pub fn write(what: &str, path: impl Into<PathBuf>, source: io::Error) -> Self {
+        ExampleError::Io {
+            action: "write",
+            what: what.to_string(),
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Returns the exit code with which the command line reports the error.
+    ///
+    /// Codes are distinct per variant, so that scripts can tell a rejected proof from a typo in
+    /// a config; code 1 is left for failures outside of these stages, such as a check which
+    /// finds a tampered proof accepted, and code 2 is shared with usage errors of the arguments.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ExampleError::Config(_) => 2,
+            ExampleError::Trace(_) => 3,
+            ExampleError::Proving(_) => 4,
+            ExampleError::Verification(_) => 5,
+            ExampleError::Io { .. } => 6,
+        }
+    }
+}
+
+impl From<ExampleError> for String {
+    fn from(err: ExampleError) -> Self {
+        err.to_string()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::ExampleError;
+    use crate::{config::RunConfig, run_example, verify::verify_proof};
+
+    #[test]
+    fn errors_name_the_stage_which_failed() {
+        let config = RunConfig {
+            example: "fibonacci".to_string(),
+            ..RunConfig::default()
+        };
+        let err = run_example(&config).unwrap_err();
+        assert!(matches!(err, ExampleError::Config(_)), "{}", err);
+        assert_eq!(2, err.exit_code());
+
+        let path = std::env::temp_dir().join("stark-examples-missing-config.toml");
+        let err = RunConfig::load(&path).unwrap_err();
+        assert!(
+            matches!(err, ExampleError::Io { action: "read", .. }),
+            "{}",
+            err
+        );
+        assert!(
+            err.to_string().starts_with("failed to read config file"),
+            "{}",
+            err
+        );
+        assert_eq!(6, err.exit_code());
+
+        let err = ExampleError::from(verify_proof("vdf", &[], &[]).unwrap_err());
+        assert!(err.to_string().starts_with("proof rejected: "), "{}", err);
+        assert_eq!(5, err.exit_code());
+
+        // the error converts back into its message for code which passes strings on
+        let message = err.to_string();
+        assert_eq!(message, String::from(err));
+    }
+}
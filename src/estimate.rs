@@ -0,0 +1,348 @@
+//! Prediction of proof sizes, and of the memory of the prover, from the shape of a trace and
+//! the proof options.
+//!
+//! Proving a large trace can take minutes, which makes planning a parameter sweep by trial and
+//! error slow. The layout of a winterfell proof is fully determined by the trace shape and the
+//! proof options, except for the Merkle authentication paths, whose size depends on how many
+//! of the randomly drawn query positions share a path; [estimate()] uses the expected value of
+//! the latter, so estimates are usually within a few percent of the size of an actual proof.
+
+use crate::{
+    field::FieldType,
+    inspect::digest_size,
+    security::{security_level, SecurityBound},
+};
+use std::fmt;
+use winterfell::{math::log2, ProofOptions};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bytes in the serialized proof options.
+const OPTIONS_BYTES: usize = 7;
+
+/// Number of bytes in the proof-of-work nonce.
+const NONCE_BYTES: usize = 8;
+
+// TRACE SHAPE
+// ================================================================================================
+
+/// Everything about a computation which determines the size of its proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceShape {
+    /// Number of columns in the execution trace.
+    pub width: usize,
+    /// Number of rows in the execution trace; must be a power of two.
+    pub length: usize,
+    /// Highest degree of a transition constraint, counting each periodic column it uses as one.
+    pub constraint_degree: usize,
+    /// Base field of the computation.
+    pub field: FieldType,
+}
+
+// PROOF ESTIMATE
+// ================================================================================================
+
+/// Predicted layout of a proof.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofEstimate {
+    /// Number of elements in the low-degree extension of the trace.
+    pub lde_domain_size: usize,
+    /// Number of distinct positions at which the verifier queries the trace and the constraint
+    /// evaluations.
+    pub num_queries: usize,
+    /// Number of columns into which the constraint composition polynomial is split.
+    pub composition_columns: usize,
+    /// Expected number of distinct positions at which the verifier queries each FRI layer.
+    pub fri_layer_queries: Vec<f64>,
+    /// Number of elements in the FRI remainder.
+    pub fri_remainder_size: usize,
+    /// Predicted size of each section of the proof in bytes, in the order in which the sections
+    /// are serialized.
+    pub sections: Vec<(&'static str, usize)>,
+    /// Conjectured security level of the proof in bits.
+    pub conjectured_security: u32,
+    /// Proven security level of the proof in bits.
+    pub proven_security: u32,
+}
+
+impl ProofEstimate {
+    /// Returns the predicted size of the serialized proof in bytes.
+    pub fn proof_size(&self) -> usize {
+        self.sections.iter().map(|(_, size)| size).sum()
+    }
+}
+
+impl fmt::Display for ProofEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Estimated proof size: {:.1} KB ({} bytes)",
+            self.proof_size() as f64 / 1024f64,
+            self.proof_size()
+        )?;
+        for (name, size) in self.sections.iter() {
+            writeln!(f, "  {:<20} {:>8} bytes", name, size)?;
+        }
+        writeln!(
+            f,
+            "Verifier queries {} positions in an LDE domain of 2^{} elements, with {} \
+            constraint composition columns",
+            self.num_queries,
+            log2(self.lde_domain_size),
+            self.composition_columns
+        )?;
+        let layer_queries = self
+            .fri_layer_queries
+            .iter()
+            .map(|queries| format!("{:.1}", queries))
+            .collect::<Vec<_>>();
+        writeln!(
+            f,
+            "FRI: {} layers queried at {} positions on average, and a remainder of {} elements",
+            self.fri_layer_queries.len(),
+            layer_queries.join(", "),
+            self.fri_remainder_size
+        )?;
+        write!(
+            f,
+            "Proof security: {} bits conjectured, {} bits proven",
+            self.conjectured_security, self.proven_security
+        )
+    }
+}
+
+/// Predicts the layout of a proof of a trace of the specified shape under the specified
+/// options, without building the trace.
+///
+/// Returns an error if the trace length is not a power of two of at least 8, if the width is
+/// zero, or if the blowup factor is too small for the constraint degree.
+pub fn estimate(shape: &TraceShape, options: &ProofOptions) -> Result<ProofEstimate, String> {
+    if !shape.length.is_power_of_two() || shape.length < 8 {
+        return Err(format!(
+            "trace length must be a power of two of at least 8, but was {}",
+            shape.length
+        ));
+    }
+    if shape.width == 0 {
+        return Err("trace width must be positive".to_string());
+    }
+
+    // winterfell splits the composition polynomial into as many columns as its degree is a
+    // multiple of the trace length, which is the constraint degree rounded up to a power of two
+    let composition_columns = shape.constraint_degree.next_power_of_two().max(2);
+    if options.blowup_factor() < composition_columns {
+        return Err(format!(
+            "blowup factor {} is too small for constraints of degree {}; it must be at least {}",
+            options.blowup_factor(),
+            shape.constraint_degree,
+            composition_columns
+        ));
+    }
+
+    let lde_domain_size = shape.length * options.blowup_factor();
+    let num_queries = options.num_queries();
+    let base_bytes = shape.field.element_bytes();
+    let element_bytes = base_bytes * options.field_extension().degree() as usize;
+    let digest_bytes = digest_size(options.hash_fn());
+    let queries = Queries {
+        domain_size: lde_domain_size,
+        num_queries,
+        digest_bytes,
+    };
+
+    // FRI folds the domain until it fits into the remainder; the remainder is committed to,
+    // but not queried
+    let fri_options = options.to_fri_options();
+    let folding_factor = fri_options.folding_factor();
+    let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+    let fri_remainder_size = fri_options.fri_remainder_size(lde_domain_size);
+    let mut fri_layer_queries = Vec::with_capacity(num_fri_layers);
+    let mut fri_bytes = 1 + 2 + fri_remainder_size * element_bytes + 1;
+    let mut group_size = 1;
+    for _ in 0..num_fri_layers {
+        group_size *= folding_factor;
+        let layer_queries = queries.occupied(group_size);
+        fri_layer_queries.push(layer_queries);
+        fri_bytes += serialized_queries(
+            layer_queries * (folding_factor * element_bytes) as f64,
+            queries.path_bytes(group_size),
+        );
+    }
+
+    let sections = vec![
+        (
+            "context",
+            1 + 1 + 2 + 1 + shape.field.modulus_bytes() + OPTIONS_BYTES,
+        ),
+        ("commitments", 2 + (2 + num_fri_layers + 1) * digest_bytes),
+        (
+            "trace queries",
+            serialized_queries(
+                (num_queries * shape.width * base_bytes) as f64,
+                queries.path_bytes(1),
+            ),
+        ),
+        (
+            "constraint queries",
+            serialized_queries(
+                (num_queries * composition_columns * element_bytes) as f64,
+                queries.path_bytes(1),
+            ),
+        ),
+        (
+            "OOD frame",
+            2 + 2 * shape.width * element_bytes + 2 + composition_columns * element_bytes,
+        ),
+        ("FRI proof", fri_bytes),
+        ("proof-of-work nonce", NONCE_BYTES),
+    ];
+
+    let modulus_bits = shape.field.modulus_bits();
+    Ok(ProofEstimate {
+        lde_domain_size,
+        num_queries,
+        composition_columns,
+        fri_layer_queries,
+        fri_remainder_size,
+        sections,
+        conjectured_security: security_level(
+            options,
+            modulus_bits,
+            shape.length,
+            SecurityBound::Conjectured,
+        ),
+        proven_security: security_level(options, modulus_bits, shape.length, SecurityBound::Proven),
+    })
+}
+
+// PROVER MEMORY
+// ================================================================================================
+
+/// Returns a lower bound on the heap memory in bytes which the prover needs for a trace of the
+/// specified width and length under the specified options.
+///
+/// The bound counts the buffers which the prover holds at the same time once the trace is
+/// committed to: the trace, its polynomials, and their low-degree extension, the Merkle tree
+/// over the extension, and one column of constraint evaluations and of the DEEP composition
+/// polynomial over the extended domain. Constraints of higher degree and the FRI layers add to
+/// this, so actual runs need more.
+pub fn prover_memory(
+    width: usize,
+    length: usize,
+    field: FieldType,
+    options: &ProofOptions,
+) -> usize {
+    let lde_domain_size = length.saturating_mul(options.blowup_factor());
+    let base_bytes = field.element_bytes();
+    let element_bytes = base_bytes * options.field_extension().degree() as usize;
+    let digest_bytes = digest_size(options.hash_fn());
+
+    let trace = width.saturating_mul(length).saturating_mul(2 * base_bytes);
+    let trace_lde = width
+        .saturating_mul(lde_domain_size)
+        .saturating_mul(base_bytes);
+    let trace_tree = lde_domain_size.saturating_mul(2 * digest_bytes);
+    let composition = lde_domain_size.saturating_mul(2 * element_bytes);
+    trace
+        .saturating_add(trace_lde)
+        .saturating_add(trace_tree)
+        .saturating_add(composition)
+}
+
+// QUERY STATISTICS
+// ================================================================================================
+
+/// Distinct query positions drawn uniformly at random from a domain, as the verifier does.
+struct Queries {
+    domain_size: usize,
+    num_queries: usize,
+    digest_bytes: usize,
+}
+
+impl Queries {
+    /// Returns the expected number of groups of `group_size` consecutive positions in the
+    /// domain (taken modulo `domain_size / group_size`, as FRI folds them) which contain at
+    /// least one query position.
+    fn occupied(&self, group_size: usize) -> f64 {
+        let num_groups = (self.domain_size / group_size) as f64;
+        // probability that none of the positions falls into a given group, drawing them without
+        // replacement
+        let empty = (0..self.num_queries)
+            .map(|i| {
+                let remaining = (self.domain_size - i) as f64;
+                ((remaining - group_size as f64) / remaining).max(0.0)
+            })
+            .product::<f64>();
+        num_groups * (1.0 - empty)
+    }
+
+    /// Returns the expected number of bytes in a batch Merkle proof for a tree whose leaves
+    /// each hold `group_size` positions of the domain.
+    ///
+    /// The proof holds the sibling of every node on the paths from the queried leaves to the
+    /// root which is not itself on one of these paths, and a byte with the number of siblings
+    /// per pair of leaves.
+    fn path_bytes(&self, group_size: usize) -> f64 {
+        let depth = log2(self.domain_size / group_size) as usize;
+        // nodes on the paths at each level above the leaves, up to the root
+        let nodes = (0..=depth)
+            .map(|level| self.occupied(group_size << level))
+            .collect::<Vec<_>>();
+        let siblings = (0..depth)
+            .map(|level| 2.0 * nodes[level + 1] - nodes[level])
+            .sum::<f64>();
+        1.0 + nodes[1] + siblings * self.digest_bytes as f64
+    }
+}
+
+/// Returns the number of bytes in serialized queries with the specified numbers of value bytes
+/// and Merkle path bytes, each prefixed with its length.
+fn serialized_queries(value_bytes: f64, path_bytes: f64) -> usize {
+    4 + value_bytes.round() as usize + 4 + path_bytes.round() as usize
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::TraceShape;
+    use crate::{
+        example,
+        field::FieldType,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn estimated_proof_size_is_close_to_actual() {
+        let example = vdf_example();
+        let options = vdf_options();
+        let shape = TraceShape {
+            width: 1,
+            length: 128,
+            constraint_degree: 3,
+            field: FieldType::F128,
+        };
+        let estimate = super::estimate(&shape, &options).unwrap();
+        let report = example::run(&example, options);
+
+        // only the Merkle paths depend on the positions which happen to be queried
+        let deviation = estimate.proof_size() as f64 / report.proof_size as f64 - 1.0;
+        assert!(deviation.abs() < 0.05, "{}", estimate);
+        assert_eq!(report.fri_layers, estimate.fri_layer_queries.len());
+        assert_eq!(4, estimate.composition_columns);
+    }
+
+    #[test]
+    fn estimate_rejects_blowup_factor_below_constraint_degree() {
+        let shape = TraceShape {
+            width: 1,
+            length: 128,
+            constraint_degree: 9,
+            field: FieldType::F128,
+        };
+        let err = super::estimate(&shape, &vdf_options()).unwrap_err();
+        assert!(err.contains("must be at least 16"), "{}", err);
+    }
+}
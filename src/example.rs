@@ -0,0 +1,491 @@
+use crate::{
+    buildinfo::BuildInfo,
+    cancel::{self, Stage},
+    describe::AirMetadata,
+    emulator::Emulator,
+    error::ExampleError,
+    memory,
+    phases::{self, PhaseTimings},
+    security::{self, SecurityBound},
+    trace::{self, ChunkedTraceBuilder, TraceSteps},
+};
+use log::debug;
+use serde::{Serialize, Serializer};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+use winterfell::{Air, ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable};
+
+// EXAMPLE TRAIT
+// ================================================================================================
+
+/// Base field of the computation described by an example.
+pub type BaseField<E> = <<E as Example>::Air as Air>::BaseField;
+
+/// Public inputs of the computation described by an example.
+pub type PublicInputs<E> = <<E as Example>::Air as Air>::PublicInputs;
+
+/// A computation which can be proven and verified with a STARK.
+///
+/// An example knows the inputs of its computation; proof options are supplied separately so
+/// that the same statement can be proven under different parameters.
+pub trait Example {
+    /// AIR describing the computation.
+    type Air: Air;
+
+    /// Prover producing proofs for the computation; it is `Send` so that proofs can be generated
+    /// in a thread pool of a chosen size.
+    type Prover: Prover<Air = Self::Air, Trace = TraceTable<BaseField<Self>>> + Send;
+
+    /// Executes the computation and records its execution trace.
+    fn build_trace(&self) -> TraceTable<BaseField<Self>>;
+
+    /// Returns a row-by-row description of the execution trace, which lets the trace be built
+    /// in checkpointed chunks; `None` for examples which only build their trace in one piece.
+    fn trace_steps(&self) -> Option<Box<dyn TraceSteps<BaseField<Self>> + '_>> {
+        None
+    }
+
+    /// Returns an emulator which runs the computation without building a trace, against which
+    /// the trace can be checked (see [check_trace()](crate::emulator::check_trace)); `None` for
+    /// examples without one.
+    fn emulator(&self) -> Option<Box<dyn Emulator<BaseField<Self>> + '_>> {
+        None
+    }
+
+    /// Returns the seed of the random number generator which drew the inputs of the computation;
+    /// `None` for examples whose inputs are not random.
+    fn seed(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the name and description of the example, and the default settings of its config
+    /// section, which the `list` command prints.
+    fn descriptor() -> ExampleDescriptor;
+
+    /// Returns the names of the columns and transition constraints of the AIR, from which the
+    /// AIR can be described (see [describe()](crate::describe::describe)).
+    fn metadata(&self) -> AirMetadata;
+
+    /// Returns a prover for the computation instantiated with the specified options.
+    fn prover(&self, options: ProofOptions) -> Self::Prover;
+
+    /// Returns public inputs against which proofs of the computation are verified.
+    fn pub_inputs(&self) -> PublicInputs<Self>;
+
+    /// Returns public inputs which differ from the correct ones; a proof of the computation must
+    /// not verify against them.
+    fn wrong_pub_inputs(&self) -> PublicInputs<Self>;
+}
+
+// EXAMPLE DESCRIPTOR
+// ================================================================================================
+
+/// Documentation of an example: what it proves, and the settings it is built from by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleDescriptor {
+    /// Name under which the example is selected in a config.
+    pub name: &'static str,
+    /// One-line description of the statement the example proves.
+    pub description: &'static str,
+    /// Default values of the settings of the config section of the example, formatted as TOML
+    /// values; settings which are unset by default are left out.
+    pub defaults: Vec<(&'static str, String)>,
+}
+
+impl ExampleDescriptor {
+    /// Returns a descriptor of an example without default settings.
+    pub fn new(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            defaults: Vec::new(),
+        }
+    }
+
+    /// Adds the default value of a setting.
+    ///
+    /// # Panics
+    /// Panics if the value cannot be written as a TOML value.
+    pub fn with_default<T: Serialize>(mut self, setting: &'static str, value: T) -> Self {
+        let value = toml::Value::try_from(value)
+            .unwrap_or_else(|err| panic!("invalid default of {}.{}: {}", self.name, setting, err));
+        self.defaults.push((setting, value.to_string()));
+        self
+    }
+}
+
+// RUNNER
+// ================================================================================================
+
+/// Builds the execution trace of the example, proves it, and verifies the resulting proof,
+/// recording timings and proof parameters along the way.
+///
+/// # Panics
+/// Panics if the prover fails to generate a proof; see [try_run()].
+pub fn run<E: Example>(example: &E, options: ProofOptions) -> RunReport {
+    try_run(example, options).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Same as [run()], but returns a [Proving](ExampleError::Proving) error if the prover fails to
+/// generate a proof, such as when the trace does not satisfy the constraints in a debug build.
+pub fn try_run<E: Example>(example: &E, options: ProofOptions) -> Result<RunReport, ExampleError> {
+    // build execution trace
+    debug!("Building execution trace");
+    cancel::enter(Stage::BuildingTrace);
+    let now = Instant::now();
+    let trace = example.build_trace();
+    let trace_time = now.elapsed();
+
+    prove_and_verify(example, options, trace, trace_time)
+}
+
+/// Same as [run()], but reports the progress of building the execution trace: `on_rows` is
+/// called with the number of rows built so far and the length of the trace.
+///
+/// Examples which describe their trace row by row (see [Example::trace_steps()]) are built from
+/// that description, and report every [ROWS_PER_UPDATE](crate::trace::ROWS_PER_UPDATE) rows;
+/// the others report once, when the trace is complete.
+pub fn run_observed<E: Example>(
+    example: &E,
+    options: ProofOptions,
+    on_rows: &dyn Fn(usize, usize),
+) -> Result<RunReport, ExampleError> {
+    // build execution trace
+    debug!("Building execution trace");
+    cancel::enter(Stage::BuildingTrace);
+    let now = Instant::now();
+    let trace = match example.trace_steps() {
+        Some(steps) => {
+            let length = steps.length();
+            trace::build_trace_observed(&*steps, &|num_rows| on_rows(num_rows, length))
+        }
+        None => {
+            let trace = example.build_trace();
+            on_rows(trace.length(), trace.length());
+            trace
+        }
+    };
+    let trace_time = now.elapsed();
+
+    prove_and_verify(example, options, trace, trace_time)
+}
+
+/// Same as [run()], but builds the execution trace in chunks which are checkpointed to disk, so
+/// that an interrupted run resumes trace generation from the last completed chunk.
+///
+/// Returns a [Trace](ExampleError::Trace) error if the example does not describe its trace row
+/// by row, or if the checkpointed chunks cannot be written or read.
+pub fn run_with_checkpoints<E: Example>(
+    example: &E,
+    options: ProofOptions,
+    builder: &ChunkedTraceBuilder,
+) -> Result<RunReport, ExampleError> {
+    let steps = example.trace_steps().ok_or_else(|| {
+        ExampleError::Trace("example does not support checkpointed trace generation".to_string())
+    })?;
+
+    // build execution trace
+    debug!(
+        "Building execution trace with checkpoints in {}",
+        builder.dir().display()
+    );
+    cancel::enter(Stage::BuildingTrace);
+    let now = Instant::now();
+    let trace = builder.build(&*steps).map_err(ExampleError::Trace)?;
+    let trace_time = now.elapsed();
+
+    prove_and_verify(example, options, trace, trace_time)
+}
+
+fn prove_and_verify<E: Example>(
+    example: &E,
+    options: ProofOptions,
+    trace: TraceTable<BaseField<E>>,
+    trace_time: Duration,
+) -> Result<RunReport, ExampleError> {
+    // instantiate the prover
+    let trace_length = trace.length();
+    let fri_options = options.to_fri_options();
+    let prover = example.prover(options);
+
+    // generate the proof
+    debug!("Generating proof");
+    cancel::enter(Stage::Proving);
+    let now = Instant::now();
+    let ((proof, prover_phases), peak_memory) =
+        memory::measure(|| phases::record(|| prover.prove(trace)));
+    let proof = proof.map_err(|err| ExampleError::Proving(err.to_string()))?;
+    let prover_time = now.elapsed();
+
+    // serialize proof and check security level
+    let proof_bytes = proof.to_bytes();
+    let fri_layers = proof.fri_proof.num_layers();
+    let conjectured_security = security::proof_security(&proof, SecurityBound::Conjectured);
+    let proven_security = security::proof_security(&proof, SecurityBound::Proven);
+
+    // deserialize proof
+    let parsed_proof = StarkProof::from_bytes(&proof_bytes).map_err(|err| {
+        ExampleError::Proving(format!("serialized proof cannot be parsed: {}", err))
+    })?;
+    if proof != parsed_proof {
+        return Err(ExampleError::Proving(
+            "serialized proof parses into a different proof".to_string(),
+        ));
+    }
+
+    // verify the proof
+    let pub_inputs = example.pub_inputs();
+    let pub_inputs_bytes = pub_inputs.to_bytes();
+    debug!("Verifying proof");
+    cancel::enter(Stage::Verifying);
+    let now = Instant::now();
+    let verification = winterfell::verify::<E::Air>(proof, pub_inputs);
+    let verifier_time = now.elapsed();
+    cancel::enter(Stage::Idle);
+
+    Ok(RunReport {
+        example: E::descriptor().name.to_string(),
+        seed: example.seed(),
+        trace_length,
+        trace_time,
+        prover_time,
+        prover_phases,
+        peak_memory,
+        verifier_time,
+        proof_size: proof_bytes.len(),
+        proof_bytes,
+        pub_inputs_bytes,
+        fri_folding_factor: fri_options.folding_factor(),
+        fri_max_remainder_size: fri_options.max_remainder_size(),
+        fri_layers,
+        conjectured_security,
+        proven_security,
+        verification_error: verification.err().map(|err| err.to_string()),
+        build: BuildInfo::current(),
+    })
+}
+
+// RUN REPORT
+// ================================================================================================
+
+/// Timings and proof parameters of a single example run.
+///
+/// When serialized, durations are written as fractional milliseconds and the proof itself is
+/// omitted.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct RunReport {
+    /// Name of the example which was run, as it is selected in a config.
+    pub example: String,
+    /// Seed of the random number generator which drew the inputs of the example; `None` if the
+    /// inputs are not random.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Number of steps in the execution trace.
+    pub trace_length: usize,
+    /// Time it took to build the execution trace.
+    #[serde(rename = "trace_time_ms", serialize_with = "serialize_ms")]
+    pub trace_time: Duration,
+    /// Time it took to generate the proof.
+    #[serde(rename = "prover_time_ms", serialize_with = "serialize_ms")]
+    pub prover_time: Duration,
+    /// Time the prover spent in each of its phases; `None` unless a
+    /// [PhaseLogger](crate::phases::PhaseLogger) is installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prover_phases: Option<PhaseTimings>,
+    /// Largest amount of heap memory in bytes which was allocated at once while the proof was
+    /// generated, including the execution trace; `None` unless a
+    /// [TrackingAllocator](crate::memory::TrackingAllocator) is installed.
+    #[serde(rename = "peak_memory_bytes", skip_serializing_if = "Option::is_none")]
+    pub peak_memory: Option<usize>,
+    /// Time it took to verify the proof.
+    #[serde(rename = "verifier_time_ms", serialize_with = "serialize_ms")]
+    pub verifier_time: Duration,
+    /// The serialized proof.
+    #[serde(skip)]
+    pub proof_bytes: Vec<u8>,
+    /// The serialized public inputs which the proof was verified against.
+    #[serde(skip)]
+    pub pub_inputs_bytes: Vec<u8>,
+    /// Size of the serialized proof in bytes.
+    pub proof_size: usize,
+    /// Factor by which the degree of the polynomial is reduced in each FRI layer.
+    pub fri_folding_factor: usize,
+    /// Largest number of elements in the FRI remainder, below which no more layers are folded.
+    pub fri_max_remainder_size: usize,
+    /// Number of FRI layers in the proof, not counting the remainder.
+    pub fri_layers: usize,
+    /// Security level of the proof in bits, assuming conjectures about the soundness of FRI and
+    /// of Reed-Solomon proximity testing hold.
+    pub conjectured_security: u32,
+    /// Security level of the proof in bits which is supported by known soundness proofs.
+    pub proven_security: u32,
+    /// Reason the proof was rejected; `None` if the proof was verified successfully.
+    pub verification_error: Option<String>,
+    /// How the crate was built and which CPU features the machine supports.
+    pub build: BuildInfo,
+}
+
+impl RunReport {
+    /// Returns true if the proof was verified successfully.
+    pub fn is_verified(&self) -> bool {
+        self.verification_error.is_none()
+    }
+
+    /// Returns the security level of the proof in bits under the specified bound.
+    pub fn security_level(&self, bound: SecurityBound) -> u32 {
+        match bound {
+            SecurityBound::Conjectured => self.conjectured_security,
+            SecurityBound::Proven => self.proven_security,
+        }
+    }
+}
+
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(seed) = self.seed {
+            writeln!(f, "Drew inputs from seed {}", seed)?;
+        }
+        writeln!(
+            f,
+            "Built execution trace of {} steps in {} ms",
+            self.trace_length,
+            self.trace_time.as_millis()
+        )?;
+        writeln!(f, "Generated proof in {} ms", self.prover_time.as_millis())?;
+        if let Some(phases) = &self.prover_phases {
+            writeln!(f, "{}", phases)?;
+        }
+        if let Some(peak_memory) = self.peak_memory {
+            writeln!(
+                f,
+                "Peak memory: {:.1} MB",
+                peak_memory as f64 / (1024f64 * 1024f64)
+            )?;
+        }
+        writeln!(f, "Proof size: {:.1} KB", self.proof_size as f64 / 1024f64)?;
+        writeln!(
+            f,
+            "FRI: {} layers with folding factor {} and max remainder size {}",
+            self.fri_layers, self.fri_folding_factor, self.fri_max_remainder_size
+        )?;
+        writeln!(
+            f,
+            "Proof security: {} bits conjectured, {} bits proven",
+            self.conjectured_security, self.proven_security
+        )?;
+        writeln!(f, "{}", self.build)?;
+        match &self.verification_error {
+            None => write!(
+                f,
+                "Proof verified in {:.1} ms",
+                self.verifier_time.as_micros() as f64 / 1000f64
+            ),
+            Some(err) => write!(f, "Something went wrong! {}", err),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn serialize_ms<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_micros() as f64 / 1000f64)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::Example;
+    use crate::{
+        config::{ProofOptionsConfig, VdfConfig},
+        runner::{self, ExampleCommand},
+        security::SecurityBound,
+        test_utils::{example_config, vdf_example, vdf_options},
+        trace::{self, ROWS_PER_UPDATE},
+        vdf::VdfExample,
+        EXAMPLES,
+    };
+    use std::{cell::Cell, sync::Mutex};
+    use winterfell::{math::fields::f128::BaseElement, Prover, StarkProof, Trace};
+
+    #[test]
+    fn run_report_describes_proof() {
+        let report = super::run(&vdf_example(), vdf_options());
+        assert!(report.is_verified(), "{}", report);
+        assert_eq!(report.proof_size, report.proof_bytes.len());
+        assert!(report.proven_security <= report.conjectured_security);
+        assert_eq!(
+            report.proven_security,
+            report.security_level(SecurityBound::Proven)
+        );
+
+        let proof = StarkProof::from_bytes(&report.proof_bytes).unwrap();
+        assert_eq!(proof.security_level(true), report.conjectured_security);
+
+        let serialized = toml::to_string(&report).unwrap();
+        assert!(serialized.contains("prover_time_ms"));
+        assert!(!serialized.contains("proof_bytes"));
+    }
+
+    #[test]
+    fn observed_run_reports_built_trace_rows() {
+        let example = VdfExample::<BaseElement>::new(&VdfConfig {
+            trace_length: 2 * ROWS_PER_UPDATE,
+            ..VdfConfig::default()
+        })
+        .unwrap();
+        let updates = Mutex::new(Vec::new());
+        let report = super::run_observed(&example, vdf_options(), &|num_rows, length| {
+            updates.lock().unwrap().push((num_rows, length))
+        })
+        .unwrap();
+        assert!(report.verification_error.is_none());
+        let length = 2 * ROWS_PER_UPDATE;
+        assert_eq!(
+            vec![(ROWS_PER_UPDATE, length), (length, length)],
+            updates.into_inner().unwrap()
+        );
+
+        // the trace built from its steps is the trace of the example, and a length which is not a
+        // multiple of the update interval is reported once it is complete
+        let example = vdf_example();
+        let steps = example.trace_steps().unwrap();
+        let num_updates = Cell::new(0);
+        let trace = trace::build_trace_observed(&*steps, &|num_rows| {
+            assert_eq!(128, num_rows);
+            num_updates.set(num_updates.get() + 1);
+        });
+        assert_eq!(1, num_updates.get());
+        assert_eq!(
+            vdf_example().build_trace().into_columns(),
+            trace.into_columns()
+        );
+    }
+
+    #[test]
+    fn proof_of_every_example_verifies() {
+        struct ProveAndVerify;
+
+        impl ExampleCommand for ProveAndVerify {
+            type Output = ();
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+                let proof = example
+                    .prover(options.to_proof_options())
+                    .prove(example.build_trace())
+                    .unwrap();
+                let result = winterfell::verify::<E::Air>(proof, example.pub_inputs());
+                assert!(result.is_ok(), "{}: {:?}", E::descriptor().name, result);
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            runner::with_example(&example_config(name), ProveAndVerify).unwrap();
+        }
+    }
+}
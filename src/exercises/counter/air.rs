@@ -0,0 +1,69 @@
+//! AIR of the counter exercise; fill in the `todo!()`s and run `check-exercise counter`.
+
+// until the holes are filled in, the AIR leaves its imports, arguments, and fields unused
+#![allow(dead_code, unused_imports, unused_variables)]
+
+use super::{CounterInputs, COUNTER, SUM, TRACE_WIDTH};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// COUNTER AIR
+// ================================================================================================
+
+/// AIR of a counter which starts at zero and goes up by one in every row, next to the running
+/// sum of the counter: row `i` of the trace holds `i` in column `COUNTER` and `0 + 1 + ... + i`
+/// in column `SUM`.
+pub struct CounterAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for CounterAir {
+    type BaseField = BaseElement;
+    type PublicInputs = CounterInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: CounterInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(), options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // TODO: write expressions into `result` which are zero exactly when the next row holds
+        // the counter after the current one and the sum up to it
+        todo!("enforce the transition from one row to the next")
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // TODO: assert where the counter and the sum start, and that the last row of column SUM
+        // holds the result
+        todo!("assert the first row and the result")
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degrees of the transition constraints of the AIR.
+fn transition_degrees() -> Vec<TransitionConstraintDegree> {
+    // TODO: declare the degree of every transition constraint, in the order in which
+    // evaluate_transition() writes them into its result
+    todo!("declare the degrees of the transition constraints")
+}
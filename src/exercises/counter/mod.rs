@@ -0,0 +1,131 @@
+//! Counter exercise: write the AIR of a counter which goes up by one in every row and of the
+//! running sum of its values in `air.rs`.
+
+use super::oracle::{Case, Exercise};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    ByteWriter, Serializable, Trace, TraceTable,
+};
+
+// the `solutions` feature builds the complete AIR in place of the skeleton; both share the
+// public inputs and the trace builder of this module
+#[cfg_attr(feature = "solutions", path = "solution.rs")]
+mod air;
+pub use air::CounterAir;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; row `i` holds the counter `i` and the sum of the counters up to it.
+pub const COUNTER: usize = 0;
+pub const SUM: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The sum which the last row of the trace holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterInputs {
+    pub result: BaseElement,
+}
+
+impl Serializable for CounterInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.result);
+    }
+}
+
+// TRACE BUILDER
+// ================================================================================================
+
+/// Builds a trace of `length` rows in which the counter and the sum both start at zero.
+pub fn build_trace(length: usize) -> TraceTable<BaseElement> {
+    build_trace_with(length, [BaseElement::ZERO; 2], |_, [counter, sum]| {
+        let counter = counter + BaseElement::ONE;
+        [counter, sum + counter]
+    })
+}
+
+/// Builds a trace of `length` rows which starts from `start` and computes every row from the
+/// step and the row before it.
+fn build_trace_with<F>(length: usize, start: [BaseElement; 2], next: F) -> TraceTable<BaseElement>
+where
+    F: Fn(usize, [BaseElement; 2]) -> [BaseElement; 2],
+{
+    let mut trace = TraceTable::new(TRACE_WIDTH, length);
+    trace.fill(
+        |state| state.copy_from_slice(&start),
+        |step, state| {
+            let row = next(step, [state[COUNTER], state[SUM]]);
+            state.copy_from_slice(&row);
+        },
+    );
+    trace
+}
+
+// HIDDEN TESTS
+// ================================================================================================
+
+pub struct CounterExercise;
+
+impl Exercise for CounterExercise {
+    type Air = CounterAir;
+
+    const COLUMNS: &'static [&'static str] = &["COUNTER", "SUM"];
+
+    fn valid_cases() -> Vec<Case<CounterAir>> {
+        [8, 64]
+            .iter()
+            .map(|&length| honest_case(format!("a trace of {} steps", length), build_trace(length)))
+            .collect()
+    }
+
+    fn forged_cases() -> Vec<Case<CounterAir>> {
+        let one = BaseElement::ONE;
+        let valid = build_trace(8);
+        let result = valid.get(SUM, valid.length() - 1);
+        let count = |_, [counter, sum]: [BaseElement; 2]| [counter + one, sum + counter + one];
+        vec![
+            honest_case(
+                "a trace whose counter starts at one".to_string(),
+                build_trace_with(8, [one, BaseElement::ZERO], count),
+            ),
+            honest_case(
+                "a trace whose sum starts at one".to_string(),
+                build_trace_with(8, [BaseElement::ZERO, one], count),
+            ),
+            honest_case(
+                "a trace in which the counter goes up by two at step 3".to_string(),
+                build_trace_with(8, [BaseElement::ZERO; 2], |step, [counter, sum]| {
+                    let counter = counter + if step == 3 { one + one } else { one };
+                    [counter, sum + counter]
+                }),
+            ),
+            honest_case(
+                "a trace in which the sum does not add the counter at step 3".to_string(),
+                build_trace_with(8, [BaseElement::ZERO; 2], |step, [counter, sum]| {
+                    let counter = counter + one;
+                    [counter, if step == 3 { sum } else { sum + counter }]
+                }),
+            ),
+            Case {
+                description: "a trace of 8 steps against a result it does not end in".to_string(),
+                trace: valid,
+                pub_inputs: CounterInputs {
+                    result: result + one,
+                },
+            },
+        ]
+    }
+}
+
+/// Returns a case in which the result is the sum the trace ends in.
+fn honest_case(description: String, trace: TraceTable<BaseElement>) -> Case<CounterAir> {
+    let result = trace.get(SUM, trace.length() - 1);
+    Case {
+        description,
+        trace,
+        pub_inputs: CounterInputs { result },
+    }
+}
@@ -0,0 +1,60 @@
+//! Solution of the counter exercise, built in place of `air.rs` with the `solutions` feature.
+
+use super::{CounterInputs, COUNTER, SUM, TRACE_WIDTH};
+use crate::air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+};
+
+// COUNTER AIR
+// ================================================================================================
+
+/// AIR of a counter which starts at zero and goes up by one in every row, next to the running
+/// sum of the counter: row `i` of the trace holds `i` in column `COUNTER` and `0 + 1 + ... + i`
+/// in column `SUM`.
+pub struct CounterAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for CounterAir {
+    type BaseField = BaseElement;
+    type PublicInputs = CounterInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: CounterInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&[1, 1]), options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // the sum adds the counter of the next row, so that it includes the counter of every row
+        // up to its own
+        result[COUNTER] = are_equal(next[COUNTER], current[COUNTER] + E::ONE);
+        result[SUM] = are_equal(next[SUM], current[SUM] + next[COUNTER]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(COUNTER, BaseElement::ZERO),
+            first_row_equals(SUM, BaseElement::ZERO),
+            last_row_equals(SUM, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
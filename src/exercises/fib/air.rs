@@ -0,0 +1,68 @@
+//! AIR of the Fibonacci exercise; fill in the `todo!()`s and run `check-exercise fib`.
+
+// until the holes are filled in, the AIR leaves its imports, arguments, and fields unused
+#![allow(dead_code, unused_imports, unused_variables)]
+
+use super::{FibInputs, A, B, TRACE_WIDTH};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// FIBONACCI AIR
+// ================================================================================================
+
+/// AIR of the Fibonacci sequence `1, 1, 2, 3, 5, ...`, in which row `i` of the trace holds
+/// terms `i` and `i + 1` in columns `A` and `B`.
+pub struct FibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for FibAir {
+    type BaseField = BaseElement;
+    type PublicInputs = FibInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(), options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // TODO: write expressions into `result` which are zero exactly when the next row holds
+        // the two terms which follow the terms of the current row
+        todo!("enforce the transition from one row to the next")
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // TODO: assert the first two terms of the sequence, and that the last row of column B
+        // holds the result
+        todo!("assert the first row and the result")
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degrees of the transition constraints of the AIR.
+fn transition_degrees() -> Vec<TransitionConstraintDegree> {
+    // TODO: declare the degree of every transition constraint, in the order in which
+    // evaluate_transition() writes them into its result
+    todo!("declare the degrees of the transition constraints")
+}
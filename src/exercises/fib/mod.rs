@@ -0,0 +1,123 @@
+//! Fibonacci exercise: write the AIR of the sequence `1, 1, 2, 3, 5, ...` in `air.rs`,
+//! whose trace holds two consecutive terms in every row.
+
+use super::oracle::{Case, Exercise};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    ByteWriter, Serializable, Trace, TraceTable,
+};
+
+// the `solutions` feature builds the complete AIR in place of the skeleton; both share the
+// public inputs and the trace builder of this module
+#[cfg_attr(feature = "solutions", path = "solution.rs")]
+mod air;
+pub use air::FibAir;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; row `i` holds terms `i` and `i + 1` of the sequence.
+pub const A: usize = 0;
+pub const B: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The term of the sequence which column `B` holds in the last row of the trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FibInputs {
+    pub result: BaseElement,
+}
+
+impl Serializable for FibInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.result);
+    }
+}
+
+// TRACE BUILDER
+// ================================================================================================
+
+/// Builds a trace of `length` rows which starts from the terms one and one.
+pub fn build_trace(length: usize) -> TraceTable<BaseElement> {
+    build_trace_with(length, [BaseElement::ONE; 2], |_, [a, b]| [b, a + b])
+}
+
+/// Builds a trace of `length` rows which starts from `start` and computes every row from the
+/// step and the row before it.
+fn build_trace_with<F>(length: usize, start: [BaseElement; 2], next: F) -> TraceTable<BaseElement>
+where
+    F: Fn(usize, [BaseElement; 2]) -> [BaseElement; 2],
+{
+    let mut trace = TraceTable::new(TRACE_WIDTH, length);
+    trace.fill(
+        |state| state.copy_from_slice(&start),
+        |step, state| {
+            let row = next(step, [state[A], state[B]]);
+            state.copy_from_slice(&row);
+        },
+    );
+    trace
+}
+
+// HIDDEN TESTS
+// ================================================================================================
+
+pub struct FibExercise;
+
+impl Exercise for FibExercise {
+    type Air = FibAir;
+
+    const COLUMNS: &'static [&'static str] = &["A", "B"];
+
+    fn valid_cases() -> Vec<Case<FibAir>> {
+        [8, 64]
+            .iter()
+            .map(|&length| honest_case(format!("a trace of {} steps", length), build_trace(length)))
+            .collect()
+    }
+
+    fn forged_cases() -> Vec<Case<FibAir>> {
+        let two = BaseElement::from(2u8);
+        let valid = build_trace(8);
+        let result = valid.get(B, valid.length() - 1);
+        vec![
+            honest_case(
+                "a trace which starts from the terms 2 and 3".to_string(),
+                build_trace_with(8, [two, two + BaseElement::ONE], |_, [a, b]| [b, a + b]),
+            ),
+            honest_case(
+                "a trace in which column A does not copy column B at step 3".to_string(),
+                build_trace_with(8, [BaseElement::ONE; 2], |step, [a, b]| match step {
+                    3 => [b + BaseElement::ONE, a + b],
+                    _ => [b, a + b],
+                }),
+            ),
+            honest_case(
+                "a trace in which column B is not the sum of the row before at step 3".to_string(),
+                build_trace_with(8, [BaseElement::ONE; 2], |step, [a, b]| match step {
+                    3 => [b, a + b + BaseElement::ONE],
+                    _ => [b, a + b],
+                }),
+            ),
+            Case {
+                description: "a trace of 8 steps against a result it does not end in".to_string(),
+                trace: valid,
+                pub_inputs: FibInputs {
+                    result: result + BaseElement::ONE,
+                },
+            },
+        ]
+    }
+}
+
+/// Returns a case in which the result is the term the trace ends in.
+fn honest_case(description: String, trace: TraceTable<BaseElement>) -> Case<FibAir> {
+    let result = trace.get(B, trace.length() - 1);
+    Case {
+        description,
+        trace,
+        pub_inputs: FibInputs { result },
+    }
+}
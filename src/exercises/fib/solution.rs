@@ -0,0 +1,60 @@
+//! Solution of the Fibonacci exercise, built in place of `air.rs` with the `solutions` feature.
+
+use super::{FibInputs, A, B, TRACE_WIDTH};
+use crate::air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+};
+
+// FIBONACCI AIR
+// ================================================================================================
+
+/// AIR of the Fibonacci sequence `1, 1, 2, 3, 5, ...`, in which row `i` of the trace holds
+/// terms `i` and `i + 1` in columns `A` and `B`.
+pub struct FibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for FibAir {
+    type BaseField = BaseElement;
+    type PublicInputs = FibInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&[1, 1]), options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // the sequence moves on by one term: column A takes over the term in column B, and
+        // column B holds the sum of both
+        result[0] = are_equal(next[A], current[B]);
+        result[1] = are_equal(next[B], current[A] + current[B]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // without the first row, the transition constraints hold for any two starting terms
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(A, BaseElement::ONE),
+            first_row_equals(B, BaseElement::ONE),
+            last_row_equals(B, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,78 @@
+//! AIR of the hash chain exercise; fill in the `todo!()`s and run `check-exercise hashchain`.
+
+// until the holes are filled in, the AIR leaves its imports, arguments, and fields unused
+#![allow(dead_code, unused_imports, unused_variables)]
+
+use super::{HashChainInputs, ROUND_CONSTANTS, TRACE_WIDTH, VALUE};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// HASH CHAIN AIR
+// ================================================================================================
+
+/// AIR of a hash chain which starts from a public seed and applies `x -> x^3 + k` once per row,
+/// where the round constant `k` of step `i` is `ROUND_CONSTANTS[i % 8]`.
+///
+/// The round constants are supplied by a periodic column, which is already written: the value
+/// of the column in a row is the first entry of `periodic_values` in evaluate_transition().
+pub struct HashChainAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    digest: BaseElement,
+}
+
+impl Air for HashChainAir {
+    type BaseField = BaseElement;
+    type PublicInputs = HashChainInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: HashChainInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(), options),
+            seed: pub_inputs.seed,
+            digest: pub_inputs.digest,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constant = periodic_values[0];
+
+        // TODO: write an expression into `result` which is zero exactly when the next row holds
+        // the hash of the current one
+        todo!("enforce one application of the hash")
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // TODO: assert that the chain starts from the seed and ends in the digest
+        todo!("assert the seed and the digest")
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![ROUND_CONSTANTS.to_vec()]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degrees of the transition constraints of the AIR.
+fn transition_degrees() -> Vec<TransitionConstraintDegree> {
+    // TODO: declare the degree of the transition constraint; a periodic value counts toward
+    // the degree only if it multiplies trace values, in which case the degree names the length
+    // of its cycle
+    todo!("declare the degrees of the transition constraints")
+}
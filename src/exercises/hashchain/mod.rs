@@ -0,0 +1,155 @@
+//! Hash chain exercise: write the AIR of a chain of applications of the toy hash function
+//! `x -> x^3 + k` in `air.rs`, whose round constant `k` is supplied by a periodic column.
+
+use super::oracle::{Case, Exercise};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    ByteWriter, Serializable, Trace, TraceTable,
+};
+
+// the `solutions` feature builds the complete AIR in place of the skeleton; both share the
+// public inputs and the trace builder of this module
+#[cfg_attr(feature = "solutions", path = "solution.rs")]
+mod air;
+pub use air::HashChainAir;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; row `i` holds the value of the chain after `i` applications of the hash.
+pub const VALUE: usize = 0;
+pub const TRACE_WIDTH: usize = 1;
+
+/// Round constants of the hash; step `i` adds constant `i mod 8`.
+pub const ROUND_CONSTANTS: [BaseElement; 8] = [
+    BaseElement::new(42),
+    BaseElement::new(17),
+    BaseElement::new(99),
+    BaseElement::new(3),
+    BaseElement::new(1024),
+    BaseElement::new(7),
+    BaseElement::new(65537),
+    BaseElement::new(12345),
+];
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The value the chain starts from and the digest which the last row of the trace holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashChainInputs {
+    pub seed: BaseElement,
+    pub digest: BaseElement,
+}
+
+impl Serializable for HashChainInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.digest);
+    }
+}
+
+// TRACE BUILDER
+// ================================================================================================
+
+/// Builds a trace of `length` rows which starts from `seed`.
+pub fn build_trace(seed: BaseElement, length: usize) -> TraceTable<BaseElement> {
+    build_trace_with(seed, length, |step, value| {
+        value.cube() + ROUND_CONSTANTS[step % ROUND_CONSTANTS.len()]
+    })
+}
+
+/// Builds a trace of `length` rows which starts from `seed` and computes every row from the
+/// step and the row before it.
+fn build_trace_with<F>(seed: BaseElement, length: usize, next: F) -> TraceTable<BaseElement>
+where
+    F: Fn(usize, BaseElement) -> BaseElement,
+{
+    let mut trace = TraceTable::new(TRACE_WIDTH, length);
+    trace.fill(
+        |state| state[VALUE] = seed,
+        |step, state| state[VALUE] = next(step, state[VALUE]),
+    );
+    trace
+}
+
+// HIDDEN TESTS
+// ================================================================================================
+
+pub struct HashChainExercise;
+
+impl Exercise for HashChainExercise {
+    type Air = HashChainAir;
+
+    const COLUMNS: &'static [&'static str] = &["VALUE"];
+
+    fn valid_cases() -> Vec<Case<HashChainAir>> {
+        [8, 64]
+            .iter()
+            .map(|&length| {
+                honest_case(
+                    format!("a chain of {} steps", length),
+                    seed(),
+                    build_trace(seed(), length),
+                )
+            })
+            .collect()
+    }
+
+    fn forged_cases() -> Vec<Case<HashChainAir>> {
+        let valid = build_trace(seed(), 8);
+        let digest = valid.get(VALUE, valid.length() - 1);
+        let round = |step: usize, value: BaseElement| {
+            value.cube() + ROUND_CONSTANTS[step % ROUND_CONSTANTS.len()]
+        };
+        vec![
+            honest_case(
+                "a chain which starts from another value than the seed".to_string(),
+                seed(),
+                build_trace(seed() + BaseElement::ONE, 8),
+            ),
+            honest_case(
+                "a chain which adds the round constant of step 4 at step 3".to_string(),
+                seed(),
+                build_trace_with(seed(), 8, |step, value| match step {
+                    3 => round(4, value),
+                    _ => round(step, value),
+                }),
+            ),
+            honest_case(
+                "a chain which squares the value instead of cubing it at step 3".to_string(),
+                seed(),
+                build_trace_with(seed(), 8, |step, value| match step {
+                    3 => value.square() + ROUND_CONSTANTS[3],
+                    _ => round(step, value),
+                }),
+            ),
+            Case {
+                description: "a chain of 8 steps against a digest it does not end in".to_string(),
+                trace: valid,
+                pub_inputs: HashChainInputs {
+                    seed: seed(),
+                    digest: digest + BaseElement::ONE,
+                },
+            },
+        ]
+    }
+}
+
+fn seed() -> BaseElement {
+    BaseElement::new(3)
+}
+
+/// Returns a case in which the digest is the value the trace ends in.
+fn honest_case(
+    description: String,
+    seed: BaseElement,
+    trace: TraceTable<BaseElement>,
+) -> Case<HashChainAir> {
+    let digest = trace.get(VALUE, trace.length() - 1);
+    Case {
+        description,
+        trace,
+        pub_inputs: HashChainInputs { seed, digest },
+    }
+}
@@ -0,0 +1,64 @@
+//! Solution of the hash chain exercise, built in place of `air.rs` with the `solutions` feature.
+
+use super::{HashChainInputs, ROUND_CONSTANTS, TRACE_WIDTH, VALUE};
+use crate::air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+};
+
+// HASH CHAIN AIR
+// ================================================================================================
+
+/// AIR of a hash chain which starts from a public seed and applies `x -> x^3 + k` once per row,
+/// where the round constant `k` of step `i` is `ROUND_CONSTANTS[i % 8]`.
+pub struct HashChainAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    digest: BaseElement,
+}
+
+impl Air for HashChainAir {
+    type BaseField = BaseElement;
+    type PublicInputs = HashChainInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: HashChainInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        // the round constant is only added to the cube, so unlike a periodic value which
+        // multiplies trace values it does not raise the degree of the constraint
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&[3]), options),
+            seed: pub_inputs.seed,
+            digest: pub_inputs.digest,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constant = periodic_values[0];
+
+        result[0] = are_equal(next[VALUE], current[VALUE].cube() + round_constant);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(VALUE, self.seed),
+            last_row_equals(VALUE, trace_length, self.digest),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![ROUND_CONSTANTS.to_vec()]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
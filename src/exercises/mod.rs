@@ -0,0 +1,48 @@
+//! Exercises in writing AIRs.
+//!
+//! Each exercise comes with everything needed to prove its computation except the constraints:
+//! the public inputs, the trace builder, and an AIR whose transition constraints, degrees, and
+//! boundary assertions are left as `todo!()` in the `air.rs` file of the exercise. Filling in
+//! the holes and running `check-exercise <name>` runs the hidden tests of the [oracle] against
+//! the AIR, which report the first constraint or assertion that is wrong.
+//!
+//! The oracle knows nothing about how an exercise should be solved: it checks the AIR against
+//! valid traces, which it must accept, and against forged traces, which it must reject.
+//!
+//! With the `solutions` feature, every exercise is built with the reference solution in its
+//! `solution.rs` instead of the skeleton, and passes its hidden tests.
+
+pub mod counter;
+pub mod fib;
+pub mod hashchain;
+pub mod oracle;
+
+#[cfg(test)]
+mod tests;
+
+use oracle::ExerciseReport;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Names of the exercises which can be checked.
+pub const EXERCISES: [&str; 3] = ["fib", "counter", "hashchain"];
+
+// EXERCISE CHECKER
+// ================================================================================================
+
+/// Runs the hidden tests of the named exercise against its AIR.
+///
+/// Returns an error if there is no exercise of that name.
+pub fn check_exercise(name: &str) -> Result<ExerciseReport, String> {
+    match name {
+        "fib" => Ok(oracle::check::<fib::FibExercise>(name)),
+        "counter" => Ok(oracle::check::<counter::CounterExercise>(name)),
+        "hashchain" => Ok(oracle::check::<hashchain::HashChainExercise>(name)),
+        _ => Err(format!(
+            "unknown exercise '{}'; available exercises: {}",
+            name,
+            EXERCISES.join(", ")
+        )),
+    }
+}
@@ -0,0 +1,312 @@
+//! Hidden tests which the AIR of an exercise is checked with.
+//!
+//! The tests are independent of any solution to the exercise: an exercise supplies valid traces
+//! of its computation, which the AIR must accept, and forged traces, which the AIR must reject,
+//! and the tests evaluate the AIR over them with [check_air_constraints()]. The description of
+//! the first trace which is handled wrongly points to the constraint or assertion at fault.
+
+use crate::{
+    constraints::{check_air_constraints, index_labels, ConstraintCheck},
+    degrees::check_air_degrees,
+    tamper::catch_panic,
+};
+use std::{fmt, panic};
+use winterfell::{Air, FieldExtension, HashFunction, ProofOptions, Prover, Trace, TraceTable};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Seed of the random trace over which the degrees of the constraints are measured.
+const DEGREE_SEED: u64 = 0;
+
+// EXERCISE TRAIT
+// ================================================================================================
+
+/// A computation whose AIR is written by the reader, together with the traces which the AIR is
+/// checked against.
+pub trait Exercise {
+    /// AIR written for the exercise.
+    type Air: Air;
+
+    /// Labels of the columns of the trace, by which failed assertions are reported.
+    const COLUMNS: &'static [&'static str];
+
+    /// Returns traces of the computation together with the public inputs they are proven
+    /// against; the AIR must accept every one of them.
+    fn valid_cases() -> Vec<Case<Self::Air>>;
+
+    /// Returns traces which do not follow the computation or do not match their public inputs;
+    /// the AIR must reject every one of them.
+    fn forged_cases() -> Vec<Case<Self::Air>>;
+}
+
+/// A trace and the public inputs it is checked against.
+pub struct Case<A: Air> {
+    /// What the trace shows; for a forged trace, how it departs from the computation.
+    pub description: String,
+    pub trace: TraceTable<A::BaseField>,
+    pub pub_inputs: A::PublicInputs,
+}
+
+// EXERCISE REPORT
+// ================================================================================================
+
+/// Outcome of a single hidden test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestResult {
+    Passed,
+    /// The test failed for the specified reason.
+    Failed(String),
+    /// The test was not run because an earlier test failed.
+    Skipped,
+}
+
+/// Outcomes of the hidden tests of an exercise, in the order in which they were run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExerciseReport {
+    /// Name of the exercise.
+    pub name: String,
+    /// Description and outcome of every test.
+    pub tests: Vec<(&'static str, TestResult)>,
+}
+
+impl ExerciseReport {
+    /// Returns true if every test passed.
+    pub fn is_passed(&self) -> bool {
+        self.tests
+            .iter()
+            .all(|(_, result)| *result == TestResult::Passed)
+    }
+
+    /// Returns the reason for which the first failed test failed, if any.
+    pub fn failure(&self) -> Option<&str> {
+        self.tests.iter().find_map(|(_, result)| match result {
+            TestResult::Failed(reason) => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for ExerciseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Checked exercise {}:", self.name)?;
+        for (description, result) in self.tests.iter() {
+            match result {
+                TestResult::Passed => writeln!(f, "  ok    {}", description)?,
+                TestResult::Failed(reason) => {
+                    writeln!(f, "  FAIL  {}", description)?;
+                    writeln!(f, "        {}", reason)?;
+                }
+                TestResult::Skipped => writeln!(f, "  skip  {}", description)?,
+            }
+        }
+        let num_passed = self
+            .tests
+            .iter()
+            .filter(|(_, result)| *result == TestResult::Passed)
+            .count();
+        if self.is_passed() {
+            write!(f, "All {} tests passed", num_passed)
+        } else {
+            write!(f, "{} of {} tests passed", num_passed, self.tests.len())
+        }
+    }
+}
+
+// HIDDEN TESTS
+// ================================================================================================
+
+/// Description of a hidden test and the test itself.
+type HiddenTest<'a> = (&'static str, &'a dyn Fn() -> Result<(), String>);
+
+/// Runs the hidden tests of the exercise against its AIR.
+///
+/// The tests build on each other, so once one fails the ones after it are skipped. Panics
+/// raised by the AIR, such as those of `todo!()`, fail the test which raised them.
+pub fn check<X: Exercise>(name: &str) -> ExerciseReport
+where
+    <X::Air as Air>::PublicInputs: Clone,
+{
+    let valid = X::valid_cases();
+    let forged = X::forged_cases();
+    let tests: [HiddenTest; 5] = [
+        ("the AIR can be instantiated for every valid trace", &|| {
+            valid
+                .iter()
+                .try_for_each(|case| instantiate::<X::Air>(case).map(|_| ()))
+        }),
+        (
+            "every transition constraint has its declared degree",
+            &|| valid.iter().try_for_each(check_degrees::<X::Air>),
+        ),
+        (
+            "valid traces satisfy every constraint and assertion",
+            &|| {
+                valid.iter().try_for_each(|case| {
+                    let check = evaluate::<X::Air>(case, X::COLUMNS)?;
+                    if check.is_satisfied() {
+                        Ok(())
+                    } else {
+                        Err(format!("on {}, {}", case.description, check))
+                    }
+                })
+            },
+        ),
+        ("forged traces break a constraint or an assertion", &|| {
+            forged.iter().try_for_each(|case| {
+                if evaluate::<X::Air>(case, X::COLUMNS)?.is_satisfied() {
+                    Err(format!("accepted {}", case.description))
+                } else {
+                    Ok(())
+                }
+            })
+        }),
+        ("proofs of valid traces verify", &|| {
+            // proving consumes the traces, so they are built once more
+            X::valid_cases()
+                .into_iter()
+                .try_for_each(prove_and_verify::<X::Air>)
+        }),
+    ];
+
+    // the messages of panics raised by the AIR are captured in the report, so the default hook
+    // is silenced while the tests run
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut results = Vec::with_capacity(tests.len());
+    let mut failed = false;
+    for (description, test) in tests {
+        let result = if failed {
+            TestResult::Skipped
+        } else {
+            match test() {
+                Ok(()) => TestResult::Passed,
+                Err(reason) => {
+                    failed = true;
+                    TestResult::Failed(reason)
+                }
+            }
+        };
+        results.push((description, result));
+    }
+    panic::set_hook(hook);
+
+    ExerciseReport {
+        name: name.to_string(),
+        tests: results,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Options with which the AIR of an exercise is instantiated; the blowup factor leaves room for
+/// constraints of degree up to eight.
+fn exercise_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        8,
+        64,
+    )
+}
+
+fn instantiate<A: Air>(case: &Case<A>) -> Result<A, String>
+where
+    A::PublicInputs: Clone,
+{
+    catch_panic(|| {
+        A::new(
+            case.trace.get_info(),
+            case.pub_inputs.clone(),
+            exercise_options(),
+        )
+    })
+    .map_err(|reason| format!("on {}, Air::new() {}", case.description, reason))
+}
+
+fn check_degrees<A: Air>(case: &Case<A>) -> Result<(), String>
+where
+    A::PublicInputs: Clone,
+{
+    let air = instantiate(case)?;
+    let report = catch_panic(|| check_air_degrees(&air, DEGREE_SEED))
+        .map_err(|reason| format!("on {}, evaluate_transition() {}", case.description, reason))?;
+    let trace_degree = (report.trace_length - 1) as f64;
+    match report.mismatches().first() {
+        None => Ok(()),
+        Some(constraint) => Err(format!(
+            "on {}, constraint {} is declared with degree {:.2}x the trace degree, but has \
+            degree {:.2}x the trace degree",
+            case.description,
+            constraint.index,
+            constraint.declared as f64 / trace_degree,
+            constraint.actual as f64 / trace_degree
+        )),
+    }
+}
+
+fn evaluate<A: Air>(
+    case: &Case<A>,
+    columns: &[&str],
+) -> Result<ConstraintCheck<A::BaseField>, String>
+where
+    A::PublicInputs: Clone,
+{
+    let air = instantiate(case)?;
+    let columns = columns
+        .iter()
+        .map(|label| label.to_string())
+        .collect::<Vec<_>>();
+    let constraints = index_labels(air.transition_constraint_degrees().len());
+    catch_panic(|| check_air_constraints(&air, &case.trace, &columns, &constraints))
+        .map_err(|reason| format!("on {}, evaluating the AIR {}", case.description, reason))
+}
+
+fn prove_and_verify<A: Air>(case: Case<A>) -> Result<(), String>
+where
+    A::PublicInputs: Clone,
+{
+    let Case {
+        description,
+        trace,
+        pub_inputs,
+    } = case;
+    let prover = ExerciseProver::<A> {
+        options: exercise_options(),
+        pub_inputs: pub_inputs.clone(),
+    };
+    let proof = catch_panic(|| prover.prove(trace))
+        .and_then(|proof| proof.map_err(|err| err.to_string()))
+        .map_err(|reason| format!("on {}, proving failed: {}", description, reason))?;
+    catch_panic(|| winterfell::verify::<A>(proof, pub_inputs))
+        .and_then(|verdict| verdict.map_err(|err| err.to_string()))
+        .map_err(|reason| format!("on {}, the proof was rejected: {}", description, reason))
+}
+
+/// Prover for the AIR of any exercise, which proves a trace against the public inputs it was
+/// created with.
+struct ExerciseProver<A: Air> {
+    options: ProofOptions,
+    pub_inputs: A::PublicInputs,
+}
+
+impl<A: Air> Prover for ExerciseProver<A>
+where
+    A::PublicInputs: Clone,
+{
+    type BaseField = A::BaseField;
+    type Air = A;
+    type Trace = TraceTable<A::BaseField>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> A::PublicInputs {
+        self.pub_inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,153 @@
+use super::{
+    check_exercise,
+    fib::{FibAir, FibExercise, FibInputs, A, B},
+    oracle::{self, Case, Exercise, TestResult},
+    EXERCISES,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+#[cfg(not(feature = "solutions"))]
+#[test]
+fn exercise_skeletons_fail_on_their_first_hole() {
+    for name in EXERCISES {
+        let report = check_exercise(name).unwrap();
+        assert!(!report.is_passed());
+        assert!(
+            report.failure().unwrap().contains("not yet implemented"),
+            "{}",
+            report
+        );
+        assert!(report.tests[1..]
+            .iter()
+            .all(|(_, result)| *result == TestResult::Skipped));
+    }
+}
+
+#[cfg(feature = "solutions")]
+#[test]
+fn exercise_solutions_pass_every_test() {
+    for name in EXERCISES {
+        let report = check_exercise(name).unwrap();
+        assert!(report.is_passed(), "{}", report);
+    }
+}
+
+#[test]
+fn unknown_exercise_is_rejected() {
+    let err = check_exercise("vdf").unwrap_err();
+    assert!(err.starts_with("unknown exercise 'vdf'"), "{}", err);
+}
+
+#[test]
+fn oracle_passes_a_complete_air() {
+    let report = oracle::check::<TestFib<true, 1>>("fib");
+    assert!(report.is_passed(), "{}", report);
+}
+
+#[test]
+fn oracle_reports_a_forged_trace_which_is_accepted() {
+    let report = oracle::check::<TestFib<false, 1>>("fib");
+    assert_eq!(TestResult::Passed, report.tests[2].1);
+    assert_eq!(
+        Some("accepted a trace which starts from the terms 2 and 3"),
+        report.failure()
+    );
+}
+
+#[test]
+fn oracle_reports_a_wrong_degree() {
+    let report = oracle::check::<TestFib<true, 2>>("fib");
+    assert_eq!(TestResult::Passed, report.tests[0].1);
+    let failure = report.failure().unwrap();
+    assert!(
+        failure.contains("constraint 0 is declared with degree 2.00x"),
+        "{}",
+        failure
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// The Fibonacci exercise checked against [TestFibAir].
+struct TestFib<const ASSERT_START: bool, const DEGREE: usize>;
+
+impl<const ASSERT_START: bool, const DEGREE: usize> Exercise for TestFib<ASSERT_START, DEGREE> {
+    type Air = TestFibAir<ASSERT_START, DEGREE>;
+
+    const COLUMNS: &'static [&'static str] = FibExercise::COLUMNS;
+
+    fn valid_cases() -> Vec<Case<Self::Air>> {
+        FibExercise::valid_cases()
+            .into_iter()
+            .map(convert)
+            .collect()
+    }
+
+    fn forged_cases() -> Vec<Case<Self::Air>> {
+        FibExercise::forged_cases()
+            .into_iter()
+            .map(convert)
+            .collect()
+    }
+}
+
+fn convert<T: Air<BaseField = BaseElement, PublicInputs = FibInputs>>(
+    case: Case<FibAir>,
+) -> Case<T> {
+    Case {
+        description: case.description,
+        trace: case.trace,
+        pub_inputs: case.pub_inputs,
+    }
+}
+
+/// An AIR of the Fibonacci exercise which asserts the first row only if `ASSERT_START` is set,
+/// and declares its constraints with degree `DEGREE`.
+struct TestFibAir<const ASSERT_START: bool, const DEGREE: usize> {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl<const ASSERT_START: bool, const DEGREE: usize> Air for TestFibAir<ASSERT_START, DEGREE> {
+    type BaseField = BaseElement;
+    type PublicInputs = FibInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(DEGREE); 2];
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = next[A] - current[B];
+        result[1] = next[B] - (current[A] + current[B]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        let mut assertions = vec![Assertion::single(B, last_step, self.result)];
+        if ASSERT_START {
+            assertions.push(Assertion::single(A, 0, BaseElement::ONE));
+            assertions.push(Assertion::single(B, 0, BaseElement::ONE));
+        }
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, is_binary, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout.
+const BIT: usize = 0;
+const ACCUMULATOR: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct ExpInputs<B: ExampleField> {
+    pub base: B,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for ExpInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.base);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for ExpInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(ExpInputs {
+            base: B::read_from(source)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// EXPONENTIATION AIR
+// ================================================================================================
+
+pub struct ExpAir<B: ExampleField> {
+    context: AirContext<B>,
+    base: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for ExpAir<B> {
+    type BaseField = B;
+    type PublicInputs = ExpInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: ExpInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let degrees = transition_degrees(&[2, 3]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            base: pub_inputs.base,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let bit = current[BIT];
+
+        // every bit of the exponent must be either zero or one
+        result[0] = is_binary(bit);
+
+        // the accumulator is squared, and multiplied by the base when the bit is one; the
+        // multiplier is 1 + bit * (base - 1)
+        let multiplier = E::ONE + bit * (E::from(self.base) - E::ONE);
+        result[1] = are_equal(
+            next[ACCUMULATOR],
+            current[ACCUMULATOR].square() * multiplier,
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the accumulator starts at one and ends with the result; the bits are not asserted
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(ACCUMULATOR, B::ONE),
+            last_row_equals(ACCUMULATOR, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
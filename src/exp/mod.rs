@@ -0,0 +1,133 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::ExpConfig,
+    describe::AirMetadata,
+    example::{Example, ExampleDescriptor},
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+use winter_utils::collections::Vec;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{ExpAir, ExpInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::ExpProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in the secret exponent.
+const EXPONENT_BITS: usize = u64::BITS as usize;
+
+/// Number of steps in the execution trace: one step per bit of the exponent, preceded by steps
+/// for leading zero bits which pad the trace to a power of two.
+pub(crate) const TRACE_LENGTH: usize = EXPONENT_BITS * 2;
+
+// EXPONENTIATION EXAMPLE
+// ================================================================================================
+
+/// Proves knowledge of an exponent `x` such that `base^x` equals the public result, without
+/// making `x` a part of the statement.
+///
+/// The trace computes the power with the square-and-multiply method: every step squares the
+/// accumulator and multiplies it by `base` if the current bit of the exponent is one. The bits
+/// are kept in a trace column, starting with the most significant one, where the AIR constrains
+/// them to be binary. Winterfell proofs are not zero-knowledge, so the trace values opened at
+/// query positions do reveal some of the bits.
+#[cfg(feature = "std")]
+pub struct ExpExample<B: ExampleField> {
+    base: B,
+    exponent: u64,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> ExpExample<B> {
+    pub fn new(config: &ExpConfig) -> Self {
+        let base = B::from(config.base);
+        let exponent = config.exponent;
+
+        // compute result
+        let now = Instant::now();
+        let result = base.exp(exponent.into());
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Self {
+            base,
+            exponent,
+            result,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for ExpExample<B> {
+    type Air = ExpAir<B>;
+    type Prover = ExpProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = ExpProver::build_trace(self.base, &exponent_bits(self.exponent));
+        assert_eq!(self.result, trace.get(1, TRACE_LENGTH - 1));
+        trace
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = ExpConfig::default();
+        ExampleDescriptor::new(
+            "exp",
+            "knowledge of a secret exponent x such that base^x equals a public result",
+        )
+        .with_default("base", config.base)
+        .with_default("exponent", config.exponent)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("BIT", 1), ("ACCUMULATOR", 1)],
+            constraints: vec![("binary bit", 1), ("square and multiply", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> ExpProver<B> {
+        ExpProver::new(self.base, options)
+    }
+
+    fn pub_inputs(&self) -> ExpInputs<B> {
+        ExpInputs {
+            base: self.base,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> ExpInputs<B> {
+        ExpInputs {
+            base: self.base,
+            result: self.result * self.base,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the bits of the exponent as field elements, most significant bit first, preceded by
+/// enough zeros to fill all but the last step of the trace.
+fn exponent_bits<B: ExampleField>(exponent: u64) -> Vec<B> {
+    let padding = TRACE_LENGTH - 1 - EXPONENT_BITS;
+    let bits = (0..EXPONENT_BITS)
+        .rev()
+        .map(|i| B::from((exponent >> i) & 1));
+    core::iter::repeat_n(B::ZERO, padding).chain(bits).collect()
+}
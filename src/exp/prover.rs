@@ -0,0 +1,56 @@
+use super::{air::TRACE_WIDTH, ExpAir, ExpInputs};
+use crate::field::ExampleField;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct ExpProver<B: ExampleField> {
+    base: B,
+    options: ProofOptions,
+}
+
+impl<B: ExampleField> ExpProver<B> {
+    pub fn new(base: B, options: ProofOptions) -> Self {
+        Self { base, options }
+    }
+
+    /// Builds a trace which consumes one bit of the exponent per step, starting with the most
+    /// significant bit; the bit in the last row of the trace is not used and is set to zero.
+    pub fn build_trace(base: B, bits: &[B]) -> TraceTable<B> {
+        let trace_length = bits.len() + 1;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                state[0] = bits[0];
+                state[1] = B::ONE;
+            },
+            |step, state| {
+                let multiplier = B::ONE + state[0] * (base - B::ONE);
+                state[1] = state[1].square() * multiplier;
+                state[0] = bits.get(step + 1).copied().unwrap_or(B::ZERO);
+            },
+        );
+
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for ExpProver<B> {
+    type BaseField = B;
+    type Air = ExpAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> ExpInputs<B> {
+        let last_step = trace.length() - 1;
+        ExpInputs {
+            base: self.base,
+            result: trace.get(1, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,41 @@
+use super::{exponent_bits, ExpExample, ExpInputs, ExpProver, TRACE_LENGTH};
+use crate::example::Example;
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn exp_non_binary_bits_are_rejected() {
+    // a bit of two multiplies the accumulator by 2 * base - 1; the trace is otherwise
+    // consistent, so only the booleanity constraint can catch it
+    let base = BaseElement::from(3u8);
+    let mut bits = exponent_bits::<BaseElement>(12345);
+    bits[TRACE_LENGTH / 2] = BaseElement::from(2u8);
+    let trace = ExpProver::build_trace(base, &bits);
+    let pub_inputs = ExpInputs {
+        base,
+        result: trace.get(1, TRACE_LENGTH - 1),
+    };
+    assert_ne!(base.exp(12345), pub_inputs.result);
+
+    let proof = ExpProver::new(base, build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<ExpExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_err()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,714 @@
+//! Verification of a proof stage by stage, for `verify --explain`.
+//!
+//! [verify_proof()](crate::verify::verify_proof) hands a proof to `winterfell::verify`, which
+//! says whether the proof is valid but not what was checked. [explain()] takes the same steps as
+//! the winterfell verifier, assembled from the lower-level components which winterfell exposes:
+//! it replays the Fiat-Shamir transcript with a [RandomCoin], evaluates the constraints of the
+//! AIR at the out-of-domain point, checks the Merkle openings of the queried trace rows and
+//! constraint evaluations, and runs the FRI verifier over the DEEP composition of the queried
+//! values. Every stage is reported with what it checked and whether it passed, and the walk
+//! stops at the first stage which fails.
+//!
+//! The stages follow the verifier of winterfell 0.3 step by step, so a proof passes all of them
+//! exactly when `winterfell::verify` accepts it. Winterfell does not expose the folding of a
+//! single FRI layer, so the FRI queries are checked by [FriVerifier] in one stage; a failure
+//! names the layer at which the folding was inconsistent.
+
+use crate::{
+    proof_file::ProofFile,
+    verify::VerifyError,
+    verify_core::{visit_air, AirVisitor},
+};
+use std::{fmt, mem};
+use winter_fri::{FriVerifier, VerifierChannel as FriVerifierChannel};
+use winterfell::{
+    crypto::{
+        hashers::{Blake3_192, Blake3_256, Sha3_256},
+        BatchMerkleProof, Digest, ElementHasher, MerkleTree, RandomCoin,
+    },
+    math::{
+        fields::{CubeExtension, QuadExtension},
+        polynom, FieldElement, StarkField,
+    },
+    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients, Deserializable,
+    EvaluationFrame, FieldExtension, HashFunction, Serializable, StarkProof,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const PROOF_PARSING: &str = "proof parsing";
+const TRACE_COMMITMENT: &str = "trace commitment";
+const CONSTRAINT_COMMITMENT: &str = "constraint commitment";
+const OOD_CONSISTENCY: &str = "OOD consistency";
+const FRI_COMMITMENTS: &str = "FRI commitments";
+const PROOF_OF_WORK: &str = "proof of work";
+const TRACE_QUERIES: &str = "trace queries";
+const CONSTRAINT_QUERIES: &str = "constraint queries";
+const FRI_QUERIES: &str = "FRI queries";
+
+/// Names of the stages of verification, in the order they are taken.
+pub const STAGES: [&str; 9] = [
+    PROOF_PARSING,
+    TRACE_COMMITMENT,
+    CONSTRAINT_COMMITMENT,
+    OOD_CONSISTENCY,
+    FRI_COMMITMENTS,
+    PROOF_OF_WORK,
+    TRACE_QUERIES,
+    CONSTRAINT_QUERIES,
+    FRI_QUERIES,
+];
+
+// EXPLANATION
+// ================================================================================================
+
+/// One stage of verification and its outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    /// One of [STAGES].
+    pub name: &'static str,
+    pub passed: bool,
+    /// What the stage checked, or why it failed.
+    pub detail: String,
+}
+
+/// The stages of verification which were taken for a proof; every stage up to the first one
+/// which failed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Explanation {
+    pub stages: Vec<Stage>,
+}
+
+impl Explanation {
+    /// Returns true if every stage was taken and passed.
+    pub fn is_verified(&self) -> bool {
+        self.stages.len() == STAGES.len() && self.stages.iter().all(|stage| stage.passed)
+    }
+
+    /// Returns the stage which failed, if any.
+    pub fn failed_stage(&self) -> Option<&Stage> {
+        self.stages.iter().find(|stage| !stage.passed)
+    }
+
+    fn pass(&mut self, name: &'static str, detail: String) {
+        self.stages.push(Stage {
+            name,
+            passed: true,
+            detail,
+        });
+    }
+
+    fn fail(mut self, name: &'static str, detail: String) -> Self {
+        self.stages.push(Stage {
+            name,
+            passed: false,
+            detail,
+        });
+        self
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, stage) in self.stages.iter().enumerate() {
+            let outcome = if stage.passed { "passed" } else { "FAILED" };
+            writeln!(f, "{}. {}: {}", i + 1, stage.name, outcome)?;
+            writeln!(f, "   {}", stage.detail)?;
+        }
+        Ok(())
+    }
+}
+
+// VERIFICATION WALK-THROUGH
+// ================================================================================================
+
+/// Verifies a serialized proof of the named example against serialized public inputs stage by
+/// stage, and returns the stages which were taken.
+///
+/// The proof may be a bare serialized proof or a proof file, as for
+/// [verify_proof()](crate::verify::verify_proof). Returns an error if the proof or the public
+/// inputs cannot be parsed at all; a proof which parses but is rejected yields an explanation
+/// whose last stage failed.
+pub fn explain(
+    example: &str,
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+) -> Result<Explanation, VerifyError> {
+    let proof_file;
+    let proof_bytes = if ProofFile::is_proof_file(proof_bytes) {
+        proof_file = ProofFile::from_bytes(proof_bytes).map_err(VerifyError::MalformedProof)?;
+        if proof_file.header.example != example {
+            return Err(VerifyError::Rejected(format!(
+                "proof file holds a proof of example '{}'",
+                proof_file.header.example
+            )));
+        }
+        &proof_file.proof_bytes[..]
+    } else {
+        proof_bytes
+    };
+    visit_air(example, proof_bytes, pub_inputs_bytes, Explainer)
+}
+
+struct Explainer;
+
+impl AirVisitor for Explainer {
+    type Output = Explanation;
+
+    fn visit<A: Air>(self, proof: StarkProof, pub_inputs: A::PublicInputs) -> Explanation
+    where
+        A::PublicInputs: Deserializable,
+    {
+        // the transcript starts from the public inputs and the context of the proof
+        let mut coin_seed = Vec::new();
+        pub_inputs.write_into(&mut coin_seed);
+        proof.context.write_into(&mut coin_seed);
+        let air = A::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+
+        match air.options().field_extension() {
+            FieldExtension::None => explain_with_hash::<A, A::BaseField>(air, proof, &coin_seed),
+            FieldExtension::Quadratic => {
+                if !<QuadExtension<A::BaseField>>::is_supported() {
+                    return unsupported_extension(2);
+                }
+                explain_with_hash::<A, QuadExtension<A::BaseField>>(air, proof, &coin_seed)
+            }
+            FieldExtension::Cubic => {
+                if !<CubeExtension<A::BaseField>>::is_supported() {
+                    return unsupported_extension(3);
+                }
+                explain_with_hash::<A, CubeExtension<A::BaseField>>(air, proof, &coin_seed)
+            }
+        }
+    }
+}
+
+fn explain_with_hash<A, E>(air: A, proof: StarkProof, coin_seed: &[u8]) -> Explanation
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+{
+    match air.options().hash_fn() {
+        HashFunction::Blake3_256 => walk::<A, E, Blake3_256<A::BaseField>>(air, proof, coin_seed),
+        HashFunction::Blake3_192 => walk::<A, E, Blake3_192<A::BaseField>>(air, proof, coin_seed),
+        HashFunction::Sha3_256 => walk::<A, E, Sha3_256<A::BaseField>>(air, proof, coin_seed),
+    }
+}
+
+/// Takes the stages of verification in the order of the winterfell verifier.
+fn walk<A, E, H>(air: A, proof: StarkProof, coin_seed: &[u8]) -> Explanation
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+    H: ElementHasher<BaseField = A::BaseField>,
+{
+    let mut explanation = Explanation::default();
+    let mut public_coin = RandomCoin::<A::BaseField, H>::new(coin_seed);
+    let options = air.options();
+    let fri_options = options.to_fri_options();
+    let num_fri_layers = fri_options.num_fri_layers(air.lde_domain_size());
+
+    let parsed = match ParsedProof::<A::BaseField, E, H>::new(&air, proof) {
+        Ok(parsed) => parsed,
+        Err(err) => return explanation.fail(PROOF_PARSING, err),
+    };
+    let ParsedProof {
+        trace_root,
+        trace_proof,
+        trace_states,
+        constraint_root,
+        constraint_proof,
+        constraint_evaluations,
+        ood_frame,
+        ood_evaluations,
+        pow_nonce,
+        mut fri_channel,
+    } = parsed;
+    explanation.pass(
+        PROOF_PARSING,
+        format!(
+            "trace of {} columns and {} steps, extended to a domain of {} points; {} queries \
+             and {} FRI layers over a field extension of degree {}",
+            air.trace_width(),
+            air.trace_length(),
+            air.lde_domain_size(),
+            options.num_queries(),
+            num_fri_layers,
+            options.field_extension().degree()
+        ),
+    );
+
+    // the verifier draws the coefficients which combine the constraints after the prover has
+    // committed to the trace
+    public_coin.reseed(trace_root);
+    let constraint_coeffs =
+        match air.get_constraint_composition_coefficients::<E, H>(&mut public_coin) {
+            Ok(coefficients) => coefficients,
+            Err(err) => return explanation.fail(TRACE_COMMITMENT, err.to_string()),
+        };
+    explanation.pass(
+        TRACE_COMMITMENT,
+        format!(
+            "trace root {}; drew the coefficients of {} transition and {} boundary constraints",
+            to_hex(trace_root),
+            constraint_coeffs.transition.len(),
+            constraint_coeffs.boundary.len()
+        ),
+    );
+
+    // and the out-of-domain point after it has committed to the constraint evaluations
+    public_coin.reseed(constraint_root);
+    let z = match public_coin.draw::<E>() {
+        Ok(z) => z,
+        Err(err) => return explanation.fail(CONSTRAINT_COMMITMENT, err.to_string()),
+    };
+    explanation.pass(
+        CONSTRAINT_COMMITMENT,
+        format!(
+            "constraint root {}; drew the out-of-domain point z = {}",
+            to_hex(constraint_root),
+            z
+        ),
+    );
+
+    // the constraints evaluated over the trace at z and z * g must match the composition
+    // polynomial which the prover evaluated at z
+    let expected = evaluate_constraints(&air, constraint_coeffs, &ood_frame, z);
+    public_coin.reseed(H::hash_elements(ood_frame.current()));
+    public_coin.reseed(H::hash_elements(ood_frame.next()));
+    let actual = ood_evaluations
+        .iter()
+        .enumerate()
+        .fold(E::ZERO, |result, (i, &value)| {
+            result + z.exp((i as u32).into()) * value
+        });
+    public_coin.reseed(H::hash_elements(&ood_evaluations));
+    if expected != actual {
+        return explanation.fail(
+            OOD_CONSISTENCY,
+            format!(
+                "the constraints evaluate to {} over the trace at z, but the composition \
+                 polynomial evaluates to {}",
+                expected, actual
+            ),
+        );
+    }
+    explanation.pass(
+        OOD_CONSISTENCY,
+        format!(
+            "the constraints evaluated over the trace at z and z * g match the {} columns of \
+             the composition polynomial at z: {}",
+            ood_evaluations.len(),
+            actual
+        ),
+    );
+
+    let deep_coeffs = match air.get_deep_composition_coefficients::<E, H>(&mut public_coin) {
+        Ok(coefficients) => coefficients,
+        Err(err) => return explanation.fail(FRI_COMMITMENTS, err.to_string()),
+    };
+    let fri_verifier = match FriVerifier::new(
+        &mut fri_channel,
+        &mut public_coin,
+        fri_options.clone(),
+        air.trace_poly_degree(),
+    ) {
+        Ok(fri_verifier) => fri_verifier,
+        Err(err) => return explanation.fail(FRI_COMMITMENTS, err.to_string()),
+    };
+    explanation.pass(
+        FRI_COMMITMENTS,
+        format!(
+            "drew the DEEP composition coefficients, then a folding challenge from each of {} \
+             layer commitments with folding factor {}",
+            num_fri_layers + 1,
+            fri_options.folding_factor()
+        ),
+    );
+
+    public_coin.reseed_with_int(pow_nonce);
+    let leading_zeros = public_coin.leading_zeros();
+    if leading_zeros < options.grinding_factor() {
+        return explanation.fail(
+            PROOF_OF_WORK,
+            format!(
+                "nonce {} gives a query seed with {} leading zeros, but the grinding factor is {}",
+                pow_nonce,
+                leading_zeros,
+                options.grinding_factor()
+            ),
+        );
+    }
+    explanation.pass(
+        PROOF_OF_WORK,
+        format!(
+            "nonce {} gives a query seed with {} leading zeros, at least the grinding factor {}",
+            pow_nonce,
+            leading_zeros,
+            options.grinding_factor()
+        ),
+    );
+
+    let positions = match public_coin.draw_integers(options.num_queries(), air.lde_domain_size()) {
+        Ok(positions) => positions,
+        Err(err) => return explanation.fail(TRACE_QUERIES, err.to_string()),
+    };
+    if MerkleTree::verify_batch(&trace_root, &positions, &trace_proof).is_err() {
+        return explanation.fail(
+            TRACE_QUERIES,
+            "the queried trace rows do not match the trace root".to_string(),
+        );
+    }
+    explanation.pass(
+        TRACE_QUERIES,
+        format!(
+            "{} trace rows at positions drawn from the query seed open against the trace root",
+            positions.len()
+        ),
+    );
+
+    if MerkleTree::verify_batch(&constraint_root, &positions, &constraint_proof).is_err() {
+        return explanation.fail(
+            CONSTRAINT_QUERIES,
+            "the queried constraint evaluations do not match the constraint root".to_string(),
+        );
+    }
+    explanation.pass(
+        CONSTRAINT_QUERIES,
+        format!(
+            "{} rows of constraint evaluations open against the constraint root",
+            positions.len()
+        ),
+    );
+
+    let ood = OodValues {
+        z,
+        frame: ood_frame,
+        evaluations: ood_evaluations,
+    };
+    let deep_evaluations = compose_deep(
+        &air,
+        &positions,
+        &deep_coeffs,
+        &ood,
+        &trace_states,
+        &constraint_evaluations,
+    );
+    if let Err(err) = fri_verifier.verify(&mut fri_channel, &deep_evaluations, &positions) {
+        return explanation.fail(FRI_QUERIES, err.to_string());
+    }
+    explanation.pass(
+        FRI_QUERIES,
+        format!(
+            "the DEEP composition at the {} queried positions folds consistently through {} \
+             layers into a remainder of low degree",
+            positions.len(),
+            num_fri_layers
+        ),
+    );
+    explanation
+}
+
+// PARSED PROOF
+// ================================================================================================
+
+/// The parts of a proof, parsed for the shape of the trace and the options of the proof.
+struct ParsedProof<B, E, H>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+    H: ElementHasher<BaseField = B>,
+{
+    trace_root: H::Digest,
+    trace_proof: BatchMerkleProof<H>,
+    trace_states: Vec<Vec<B>>,
+    constraint_root: H::Digest,
+    constraint_proof: BatchMerkleProof<H>,
+    constraint_evaluations: Vec<Vec<E>>,
+    ood_frame: EvaluationFrame<E>,
+    ood_evaluations: Vec<E>,
+    pow_nonce: u64,
+    fri_channel: FriChannel<E, H>,
+}
+
+impl<B, E, H> ParsedProof<B, E, H>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+    H: ElementHasher<BaseField = B>,
+{
+    fn new<A: Air<BaseField = B>>(air: &A, proof: StarkProof) -> Result<Self, String> {
+        let lde_domain_size = air.lde_domain_size();
+        let num_queries = air.options().num_queries();
+        let fri_options = air.options().to_fri_options();
+
+        let (trace_root, constraint_root, layer_roots) = proof
+            .commitments
+            .parse::<H>(fri_options.num_fri_layers(lde_domain_size))
+            .map_err(|err| format!("failed to parse commitments: {}", err))?;
+        let (trace_proof, trace_states) = proof
+            .trace_queries
+            .parse::<H, B>(lde_domain_size, num_queries, air.trace_width())
+            .map_err(|err| format!("failed to parse trace queries: {}", err))?;
+        let (constraint_proof, constraint_evaluations) = proof
+            .constraint_queries
+            .parse::<H, E>(lde_domain_size, num_queries, air.ce_blowup_factor())
+            .map_err(|err| format!("failed to parse constraint queries: {}", err))?;
+        let (ood_frame, ood_evaluations) = proof
+            .ood_frame
+            .parse(air.trace_width(), air.ce_blowup_factor())
+            .map_err(|err| format!("failed to parse out-of-domain frame: {}", err))?;
+        let remainder = proof
+            .fri_proof
+            .parse_remainder()
+            .map_err(|err| format!("failed to parse FRI remainder: {}", err))?;
+        let num_partitions = proof.fri_proof.num_partitions();
+        let (layer_queries, layer_proofs) = proof
+            .fri_proof
+            .parse_layers::<H, E>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| format!("failed to parse FRI layers: {}", err))?;
+
+        Ok(Self {
+            trace_root,
+            trace_proof,
+            trace_states,
+            constraint_root,
+            constraint_proof,
+            constraint_evaluations,
+            ood_frame,
+            ood_evaluations,
+            pow_nonce: proof.pow_nonce,
+            fri_channel: FriChannel {
+                num_partitions,
+                layer_roots,
+                layer_proofs,
+                layer_queries,
+                remainder,
+            },
+        })
+    }
+}
+
+/// The FRI part of a proof, read by [FriVerifier] one layer at a time.
+struct FriChannel<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> {
+    num_partitions: usize,
+    layer_roots: Vec<H::Digest>,
+    layer_proofs: Vec<BatchMerkleProof<H>>,
+    layer_queries: Vec<Vec<E>>,
+    remainder: Vec<E>,
+}
+
+impl<E, H> FriVerifierChannel<E> for FriChannel<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    type Hasher = H;
+
+    fn read_fri_num_partitions(&self) -> usize {
+        self.num_partitions
+    }
+
+    fn read_fri_layer_commitments(&mut self) -> Vec<H::Digest> {
+        mem::take(&mut self.layer_roots)
+    }
+
+    fn take_next_fri_layer_proof(&mut self) -> BatchMerkleProof<H> {
+        self.layer_proofs.remove(0)
+    }
+
+    fn take_next_fri_layer_queries(&mut self) -> Vec<E> {
+        self.layer_queries.remove(0)
+    }
+
+    fn take_fri_remainder(&mut self) -> Vec<E> {
+        mem::take(&mut self.remainder)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Values of the trace and the composition polynomial at the out-of-domain point.
+struct OodValues<E: FieldElement> {
+    z: E,
+    /// Trace at z and z * g.
+    frame: EvaluationFrame<E>,
+    /// Columns of the composition polynomial at z^m, where m is the number of columns.
+    evaluations: Vec<E>,
+}
+
+/// Evaluates the constraint composition polynomial at `x` over the specified frame: the random
+/// linear combination of the transition constraints divided by their divisor, plus the random
+/// linear combinations of the boundary constraints divided by theirs.
+fn evaluate_constraints<A, E>(
+    air: &A,
+    coefficients: ConstraintCompositionCoefficients<E>,
+    frame: &EvaluationFrame<E>,
+    x: E,
+) -> E
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+{
+    let periodic_values = air
+        .get_periodic_column_polys()
+        .iter()
+        .map(|poly| {
+            let num_cycles = air.trace_length() / poly.len();
+            polynom::eval(poly, x.exp((num_cycles as u32).into()))
+        })
+        .collect::<Vec<_>>();
+    let mut evaluations = E::zeroed_vector(air.num_transition_constraints());
+    air.evaluate_transition(frame, &periodic_values, &mut evaluations);
+
+    let transition = air
+        .get_transition_constraints(&coefficients.transition)
+        .iter()
+        .fold(E::ZERO, |result, group| {
+            result + group.merge_evaluations(&evaluations, x)
+        });
+    let mut result = transition / air.transition_constraint_divisor().evaluate_at(x);
+    for group in air.get_boundary_constraints(&coefficients.boundary).iter() {
+        let xp = x.exp(group.degree_adjustment().into());
+        result += group.evaluate_at(frame.current(), x, xp);
+    }
+    result
+}
+
+/// Evaluates the DEEP composition polynomial at the queried positions of the extended domain
+/// from the queried trace rows and constraint evaluations.
+///
+/// For every trace column T and composition column H, the quotients (T(x) - T(z)) / (x - z),
+/// (T(x) - T(z * g)) / (x - z * g), and (H(x) - H(z^m)) / (x - z^m) are combined with random
+/// coefficients; in an extension field T is also opened at the conjugate of z, which shows that
+/// the trace is defined over the base field. The result is multiplied by a random linear
+/// function of x to restore the degree which the divisions took away.
+fn compose_deep<A, E>(
+    air: &A,
+    positions: &[usize],
+    coefficients: &DeepCompositionCoefficients<E>,
+    ood: &OodValues<E>,
+    trace_states: &[Vec<A::BaseField>],
+    constraint_evaluations: &[Vec<E>],
+) -> Vec<E>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+{
+    let z = ood.z;
+    let next_z = z * E::from(air.trace_domain_generator());
+    let z_m = z.exp((ood.evaluations.len() as u32).into());
+    let conjugates = if air.options().field_extension().is_none() {
+        None
+    } else {
+        let trace_at_z = ood.frame.current().iter().map(|value| value.conjugate());
+        Some((z.conjugate(), trace_at_z.collect::<Vec<_>>()))
+    };
+
+    let g_lde = air.lde_domain_generator();
+    positions
+        .iter()
+        .zip(trace_states)
+        .zip(constraint_evaluations)
+        .map(|((&position, row), evaluations)| {
+            let x = E::from(g_lde.exp((position as u64).into()) * air.domain_offset());
+            let mut composition = E::ZERO;
+            for (i, &value) in row.iter().enumerate() {
+                let value = E::from(value);
+                let (c1, c2, c3) = coefficients.trace[i];
+                composition += (value - ood.frame.current()[i]) / (x - z) * c1;
+                composition += (value - ood.frame.next()[i]) / (x - next_z) * c2;
+                if let Some((z_conjugate, trace_at_z_conjugate)) = &conjugates {
+                    composition += (value - trace_at_z_conjugate[i]) / (x - *z_conjugate) * c3;
+                }
+            }
+            for (i, &evaluation) in evaluations.iter().enumerate() {
+                composition +=
+                    (evaluation - ood.evaluations[i]) / (x - z_m) * coefficients.constraints[i];
+            }
+            composition * (coefficients.degree.0 + x * coefficients.degree.1)
+        })
+        .collect()
+}
+
+fn unsupported_extension(degree: u32) -> Explanation {
+    Explanation::default().fail(
+        PROOF_PARSING,
+        format!("the base field has no field extension of degree {}", degree),
+    )
+}
+
+fn to_hex<D: Digest>(digest: D) -> String {
+    digest
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::STAGES;
+    use crate::{
+        config::VdfConfig,
+        example::{self, Example},
+        test_utils::{vdf_example, vdf_options},
+        vdf::VdfExample,
+        verify::verify_proof,
+    };
+    use winterfell::{math::fields::f64, FieldExtension, HashFunction, ProofOptions, Serializable};
+
+    #[test]
+    fn explained_verification_agrees_with_verifier() {
+        let option_sets = [
+            (HashFunction::Blake3_256, FieldExtension::None),
+            (HashFunction::Blake3_192, FieldExtension::Quadratic),
+            (HashFunction::Sha3_256, FieldExtension::Cubic),
+        ];
+        for (hash_fn, field_extension) in option_sets.iter().copied() {
+            let options = ProofOptions::new(28, 8, 0, hash_fn, field_extension, 4, 32);
+            let example = VdfExample::<f64::BaseElement>::new(&VdfConfig {
+                trace_length: 128,
+                ..VdfConfig::default()
+            })
+            .unwrap();
+            let report = example::run(&example, options);
+            let explanation =
+                super::explain("vdf", &report.proof_bytes, &report.pub_inputs_bytes).unwrap();
+            assert!(explanation.is_verified(), "{}", explanation);
+            let names = explanation.stages.iter().map(|stage| stage.name);
+            assert_eq!(STAGES.to_vec(), names.collect::<Vec<_>>());
+
+            // the constraints at the out-of-domain point do not hold for another result
+            let wrong_inputs_bytes = example.wrong_pub_inputs().to_bytes();
+            let explanation =
+                super::explain("vdf", &report.proof_bytes, &wrong_inputs_bytes).unwrap();
+            assert_eq!(
+                Some("OOD consistency"),
+                explanation.failed_stage().map(|stage| stage.name)
+            );
+        }
+
+        let report = example::run(&vdf_example(), vdf_options());
+        for position in (0..report.proof_bytes.len()).step_by(97) {
+            let mut proof_bytes = report.proof_bytes.clone();
+            proof_bytes[position] ^= 1;
+            let verdict = verify_proof("vdf", &proof_bytes, &report.pub_inputs_bytes);
+            let explained = super::explain("vdf", &proof_bytes, &report.pub_inputs_bytes)
+                .map(|explanation| explanation.is_verified());
+            assert_eq!(
+                verdict.is_ok(),
+                explained == Ok(true),
+                "byte {}: {:?}, {:?}",
+                position,
+                verdict,
+                explained
+            );
+        }
+    }
+}
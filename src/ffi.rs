@@ -0,0 +1,588 @@
+//! C ABI for verifying proofs of the examples from other languages.
+//!
+//! The library is built as a `cdylib`, so these functions can be loaded from C, Python
+//! (`ctypes`), Go (`cgo`), and similar; `include/stark_examples.h` declares them. Proofs and
+//! public inputs are passed in the same encodings which `run --save-proof <file> --save-inputs
+//! <file>` writes. Every function returns [VERIFY_OK] if the proof is accepted, and one of the
+//! other `VERIFY_*` codes otherwise.
+
+use crate::verify::{verify_proof, VerifyError};
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int},
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+// RESULT CODES
+// ================================================================================================
+
+/// The proof was verified successfully.
+pub const VERIFY_OK: c_int = 0;
+
+/// A pointer argument was null, or the example name was not valid UTF-8.
+pub const VERIFY_INVALID_ARGUMENT: c_int = 1;
+
+/// No example has the specified name.
+pub const VERIFY_UNKNOWN_EXAMPLE: c_int = 2;
+
+/// The proof could not be deserialized.
+pub const VERIFY_MALFORMED_PROOF: c_int = 3;
+
+/// The proof is for a base field which none of the examples can be instantiated in.
+pub const VERIFY_UNSUPPORTED_FIELD: c_int = 4;
+
+/// The public inputs could not be deserialized.
+pub const VERIFY_MALFORMED_PUBLIC_INPUTS: c_int = 5;
+
+/// The proof was parsed, but the verifier rejected it.
+pub const VERIFY_REJECTED: c_int = 6;
+
+/// Verification panicked outside of the verifier, whose panics on malformed proofs are reported
+/// as [VERIFY_REJECTED] by [verify_proof()]; panics must not unwind into the caller.
+pub const VERIFY_PANICKED: c_int = 7;
+
+impl VerifyError {
+    /// Returns the `VERIFY_*` code which stands for this error in the C ABI.
+    pub fn code(&self) -> c_int {
+        match self {
+            VerifyError::UnknownExample(_) => VERIFY_UNKNOWN_EXAMPLE,
+            VerifyError::MalformedProof(_) => VERIFY_MALFORMED_PROOF,
+            VerifyError::UnsupportedField => VERIFY_UNSUPPORTED_FIELD,
+            VerifyError::MalformedPublicInputs(_) => VERIFY_MALFORMED_PUBLIC_INPUTS,
+            VerifyError::Rejected(_) => VERIFY_REJECTED,
+        }
+    }
+}
+
+// VERIFIERS
+// ================================================================================================
+
+/// Verifies a proof of the example with the specified NUL-terminated name.
+///
+/// # Safety
+/// `example` must point to a NUL-terminated string, and `proof` and `pub_inputs` must point to
+/// at least `proof_len` and `pub_inputs_len` readable bytes respectively.
+#[no_mangle]
+pub unsafe extern "C" fn stark_examples_verify(
+    example: *const c_char,
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    if example.is_null() {
+        return VERIFY_INVALID_ARGUMENT;
+    }
+    match CStr::from_ptr(example).to_str() {
+        Ok(example) => verify_raw(example, proof, proof_len, pub_inputs, pub_inputs_len),
+        Err(_) => VERIFY_INVALID_ARGUMENT,
+    }
+}
+
+/// Verifies a proof of the VDF example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_vdf_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("vdf", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Rescue hash chain example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_rescue_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("rescue", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Poseidon hash chain example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_poseidon_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("poseidon", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the counter example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_counter_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("counter", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Lamport signature aggregation example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_lamport_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("lamport", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the exponentiation example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_exp_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("exp", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the sum and inner product example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_sum_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("sum", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the register machine example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_vm_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("vm", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Fibonacci example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_fib_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("fib", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the greatest common divisor example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_gcd_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("gcd", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the quadratic map example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_quad_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("quad", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the is-zero gadget example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_iszero_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("iszero", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the read-only memory example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_rom_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("rom", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the square root example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_sqrt_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("sqrt", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Merkle root example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_merkle_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("merkle", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the bitwise operations example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_bitwise_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("bitwise", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the less-than comparison example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_compare_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("compare", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Fibonacci modulo a small modulus example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_fibmod_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("fibmod", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the sorting example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_sort_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("sort", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of a single chunk of the chunked Fibonacci example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_fibchunk_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("fibchunk", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the fixed-base exponentiation example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_fixedexp_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("fixedexp", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the modular exponentiation example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_modexp_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("modexp", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the character count example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_bytecount_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("bytecount", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the running sum example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_runsum_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("runsum", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the polynomial evaluation example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_horner_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("horner", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the wrapping counter example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_wrap_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("wrap", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the sliding window sum example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_window_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("window", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+/// Verifies a proof of the Fibonacci divisibility example.
+///
+/// # Safety
+/// See [stark_examples_verify()].
+#[no_mangle]
+pub unsafe extern "C" fn verify_fibdiv_proof(
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    verify_raw("fibdiv", proof, proof_len, pub_inputs, pub_inputs_len)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+unsafe fn verify_raw(
+    example: &str,
+    proof: *const u8,
+    proof_len: usize,
+    pub_inputs: *const u8,
+    pub_inputs_len: usize,
+) -> c_int {
+    let (proof, pub_inputs) = match (
+        as_slice(proof, proof_len),
+        as_slice(pub_inputs, pub_inputs_len),
+    ) {
+        (Some(proof), Some(pub_inputs)) => (proof, pub_inputs),
+        _ => return VERIFY_INVALID_ARGUMENT,
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        verify_proof(example, proof, pub_inputs)
+    })) {
+        Ok(Ok(())) => VERIFY_OK,
+        Ok(Err(err)) => err.code(),
+        Err(_) => VERIFY_PANICKED,
+    }
+}
+
+/// Returns the byte slice at `ptr`, or `None` if `ptr` is null; an empty slice may be passed as
+/// a null pointer with zero length.
+unsafe fn as_slice<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() {
+        (len == 0).then_some(&[])
+    } else {
+        Some(slice::from_raw_parts(ptr, len))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        stark_examples_verify, verify_vdf_proof, VERIFY_INVALID_ARGUMENT, VERIFY_MALFORMED_PROOF,
+        VERIFY_MALFORMED_PUBLIC_INPUTS, VERIFY_OK, VERIFY_REJECTED, VERIFY_UNKNOWN_EXAMPLE,
+    };
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn ffi_verifier_returns_error_codes() {
+        use std::ptr;
+
+        let report = example::run(&vdf_example(), vdf_options());
+        let (proof, inputs) = (&report.proof_bytes, &report.pub_inputs_bytes);
+        unsafe {
+            assert_eq!(
+                VERIFY_OK,
+                verify_vdf_proof(proof.as_ptr(), proof.len(), inputs.as_ptr(), inputs.len())
+            );
+            assert_eq!(
+                VERIFY_OK,
+                stark_examples_verify(
+                    b"vdf\0".as_ptr().cast(),
+                    proof.as_ptr(),
+                    proof.len(),
+                    inputs.as_ptr(),
+                    inputs.len()
+                )
+            );
+            assert_eq!(
+                VERIFY_UNKNOWN_EXAMPLE,
+                stark_examples_verify(
+                    b"fibonacci\0".as_ptr().cast(),
+                    proof.as_ptr(),
+                    proof.len(),
+                    inputs.as_ptr(),
+                    inputs.len()
+                )
+            );
+            assert_eq!(
+                VERIFY_INVALID_ARGUMENT,
+                verify_vdf_proof(ptr::null(), 10, inputs.as_ptr(), inputs.len())
+            );
+            assert_eq!(
+                VERIFY_MALFORMED_PUBLIC_INPUTS,
+                verify_vdf_proof(
+                    proof.as_ptr(),
+                    proof.len(),
+                    inputs.as_ptr(),
+                    inputs.len() - 1
+                )
+            );
+            assert_eq!(
+                VERIFY_MALFORMED_PROOF,
+                verify_vdf_proof(proof.as_ptr(), 16, inputs.as_ptr(), inputs.len())
+            );
+        }
+
+        let mut wrong_inputs = inputs.clone();
+        wrong_inputs[0] ^= 1;
+        let code = unsafe {
+            verify_vdf_proof(
+                proof.as_ptr(),
+                proof.len(),
+                wrong_inputs.as_ptr(),
+                wrong_inputs.len(),
+            )
+        };
+        assert_eq!(VERIFY_REJECTED, code);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    air_utils::{are_equal, cyclic_degrees, first_row_equals, is_binary, not},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; each row holds two consecutive terms of the sequence. The step counter
+/// and the selector are present only when the statement sets the number of terms.
+const ODD: usize = 0;
+const EVEN: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+pub const COUNTER: usize = 2;
+pub const SELECTOR: usize = 3;
+pub const COUNTED_TRACE_WIDTH: usize = 4;
+
+/// Number of rows at the end of the trace which hold padding rather than terms of the sequence.
+pub const NUM_PADDING_ROWS: usize = 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct FibInputs<B: ExampleField> {
+    pub result: B,
+    /// Number of terms up to the result; when not set, the result is the last term before the
+    /// padding.
+    pub num_terms: Option<usize>,
+    /// Further terms of the sequence, each as its index and its value, such as `(10, 55)` for
+    /// `F(10)`; sorted by index.
+    pub terms: Vec<(usize, B)>,
+}
+
+impl<B: ExampleField> FibInputs<B> {
+    /// Returns the number of columns in the trace of this statement.
+    pub fn trace_width(&self) -> usize {
+        if self.num_terms.is_some() {
+            COUNTED_TRACE_WIDTH
+        } else {
+            TRACE_WIDTH
+        }
+    }
+
+    /// Returns the step of the row whose even term is the result in a trace of the specified
+    /// length.
+    pub fn result_step(&self, trace_length: usize) -> usize {
+        match self.num_terms {
+            Some(num_terms) => num_terms / 2 - 1,
+            None => trace_length - 1 - NUM_PADDING_ROWS,
+        }
+    }
+}
+
+/// Returns the column and the step of the cell which holds the term of the sequence with the
+/// specified index; terms with odd indexes are in the odd column.
+pub fn term_cell(index: usize) -> (usize, usize) {
+    let column = if index % 2 == 1 { ODD } else { EVEN };
+    (column, (index - 1) / 2)
+}
+
+impl<B: ExampleField> Serializable for FibInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.result);
+        match self.num_terms {
+            Some(num_terms) => {
+                target.write_u8(1);
+                target.write_u32(num_terms as u32);
+            }
+            None => target.write_u8(0),
+        }
+        target.write_u32(self.terms.len() as u32);
+        for &(index, value) in self.terms.iter() {
+            target.write_u32(index as u32);
+            target.write(value);
+        }
+    }
+}
+
+impl<B: ExampleField> Deserializable for FibInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let result = B::read_from(source)?;
+        let num_terms = match source.read_u8()? {
+            0 => None,
+            1 => match source.read_u32()? as usize {
+                // each row holds two terms, so only even numbers of terms end on a row
+                num_terms if num_terms >= 2 && num_terms % 2 == 0 => Some(num_terms),
+                num_terms => {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "invalid number of terms {}",
+                        num_terms
+                    )))
+                }
+            },
+            flag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid number of terms flag {}",
+                    flag
+                )))
+            }
+        };
+        let num_asserted_terms = source.read_u32()? as usize;
+        let mut terms = Vec::<(usize, B)>::new();
+        for _ in 0..num_asserted_terms {
+            let index = source.read_u32()? as usize;
+            // the first two terms are asserted already, and an index which is not above the one
+            // before it would assert a cell twice
+            if index < 3 || terms.last().is_some_and(|&(last, _)| index <= last) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid term index {}",
+                    index
+                )));
+            }
+            terms.push((index, B::read_from(source)?));
+        }
+        Ok(FibInputs {
+            result,
+            num_terms,
+            terms,
+        })
+    }
+}
+
+// FIBONACCI AIR
+// ================================================================================================
+
+/// AIR of a Fibonacci sequence whose trace ends in a row of random padding.
+///
+/// Transition constraints are enforced between every pair of consecutive rows, and winterfell
+/// exempts only the transition from the last row back to the first one. Here the last two rows
+/// must be exempted, since the sequence does not continue into the padding row either. Later
+/// winterfell versions set this with `AirContext::set_num_transition_exemptions(2)`; version
+/// 0.3 always exempts exactly one row, so the constraints are instead multiplied by a periodic
+/// column which is zero at the second-to-last step. The column repeats only once per trace,
+/// which raises the constraint degree by the trace length, and the verifier interpolates it
+/// over the entire trace domain.
+///
+/// When the statement sets the number of terms, the result need not be in the last row. Two
+/// more columns then locate it: a binary selector which is one on the rows up to the result and
+/// zero after them, and a step counter which adds up the selector, so that its value in the row
+/// before the padding is the number of rows with terms of the statement. The selector drops
+/// from one to zero exactly once, and the result is checked in the row where it does.
+///
+/// Further terms which the statement lists are each checked by a single assertion on the cell
+/// which holds them, so that one proof shows several points of the sequence; the verifier pays
+/// for every extra assertion only with one more term of the boundary constraints.
+pub struct FibAir<B: ExampleField> {
+    context: AirContext<B>,
+    pub_inputs: FibInputs<B>,
+}
+
+impl<B: ExampleField> Air for FibAir<B> {
+    type BaseField = B;
+    type PublicInputs = FibInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.trace_width(), trace_info.width());
+        let trace_length = trace_info.length();
+        let last_step = trace_length - 1 - NUM_PADDING_ROWS;
+        for &(index, _) in pub_inputs.terms.iter() {
+            let (column, step) = term_cell(index);
+            assert!(
+                step <= last_step,
+                "term {} is outside of a trace of {} steps",
+                index,
+                trace_length
+            );
+            assert!(
+                pub_inputs.num_terms.is_some() || (column, step) != (EVEN, last_step),
+                "term {} is the result",
+                index
+            );
+        }
+        let degrees = if pub_inputs.num_terms.is_some() {
+            cyclic_degrees(&[1, 1, 2, 2, 1, 2], trace_length)
+        } else {
+            cyclic_degrees(&[1, 1], trace_length)
+        };
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let mask = periodic_values[0];
+
+        // each row computes the next two terms from the two terms before them; without the
+        // mask, the constraints would reject the transition into the padding row of every
+        // valid trace
+        let odd = current[ODD] + current[EVEN];
+        result[0] = mask * are_equal(next[ODD], odd);
+        result[1] = mask * are_equal(next[EVEN], odd + current[EVEN]);
+
+        if self.pub_inputs.num_terms.is_some() {
+            // the selector is binary and never returns to one once it is zero, the counter adds
+            // it up, and where it drops to zero the even term is the result
+            let selector = current[SELECTOR];
+            let drop = selector - next[SELECTOR];
+            let expected = E::from(self.pub_inputs.result);
+            result[2] = mask * is_binary(selector);
+            result[3] = mask * next[SELECTOR] * not(selector);
+            result[4] = mask * are_equal(next[COUNTER], current[COUNTER] + selector);
+            result[5] = mask * drop * are_equal(current[EVEN], expected);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence starts with two ones, and the last term before the padding is the
+        // result, unless the counter shows that the selector has dropped to zero on the row
+        // after the result
+        let last_step = self.trace_length() - 1 - NUM_PADDING_ROWS;
+        let mut assertions = vec![
+            first_row_equals(ODD, B::ONE),
+            first_row_equals(EVEN, B::ONE),
+        ];
+        match self.pub_inputs.num_terms {
+            Some(num_terms) => {
+                let num_rows = B::from((num_terms / 2) as u64);
+                assertions.push(first_row_equals(COUNTER, B::ZERO));
+                assertions.push(Assertion::single(COUNTER, last_step, num_rows));
+                assertions.push(Assertion::single(SELECTOR, last_step, B::ZERO));
+            }
+            None => assertions.push(Assertion::single(EVEN, last_step, self.pub_inputs.result)),
+        }
+        for &(index, value) in self.pub_inputs.terms.iter() {
+            let (column, step) = term_cell(index);
+            assertions.push(Assertion::single(column, step, value));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![get_transition_mask(self.trace_length())]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a column which is zero at the steps whose transition into the next row is exempted
+/// from the transition constraints, other than the last step which winterfell exempts already,
+/// and one everywhere else.
+fn get_transition_mask<B: ExampleField>(trace_length: usize) -> Vec<B> {
+    let mut mask = vec![B::ONE; trace_length];
+    mask[trace_length - 1 - NUM_PADDING_ROWS..trace_length - 1].fill(B::ZERO);
+    mask
+}
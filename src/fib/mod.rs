@@ -0,0 +1,218 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::FibConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{term_cell, FibAir, FibInputs, NUM_PADDING_ROWS, TRACE_WIDTH};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::FibProver;
+
+#[cfg(test)]
+mod tests;
+
+// FIBONACCI EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the Fibonacci number `F(2 * (trace_length - 1))`, where
+/// `F(1) = F(2) = 1`, or `F(num_terms)` when the number of terms is set.
+///
+/// Each row of the trace holds two consecutive terms of the sequence, and the trace ends with a
+/// row of random values in the way traces are padded with randomness to hide their contents.
+/// The padding row does not follow from the row before it, so the AIR exempts the last two rows
+/// of the trace from its transition constraints rather than only the last one.
+///
+/// A public number of terms lets traces of one length prove any even number of terms up to
+/// `2 * (trace_length - 2)`: a step counter and a selector column then mark the row which holds
+/// the result, instead of the result always being in the row before the padding.
+///
+/// The statement can also list further terms along the sequence, each of which the proof
+/// asserts in the row that holds it, so that a single proof shows several of them at once.
+#[cfg(feature = "std")]
+pub struct FibExample<B: ExampleField> {
+    seed: u64,
+    trace_length: usize,
+    num_terms: Option<usize>,
+    terms: Vec<(usize, B)>,
+    padding: Vec<[B; TRACE_WIDTH]>,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> FibExample<B> {
+    pub fn new(config: &FibConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let padding = (0..NUM_PADDING_ROWS)
+            .map(|_| [B::from(rng.gen::<u64>()), B::from(rng.gen::<u64>())])
+            .collect();
+
+        // compute result
+        let now = Instant::now();
+        let num_terms = config
+            .num_terms
+            .unwrap_or(2 * (config.trace_length - NUM_PADDING_ROWS));
+        let result = compute_fib_term(num_terms);
+        let mut terms = config
+            .terms
+            .iter()
+            .map(|&index| (index, compute_fib_term(index)))
+            .collect::<Vec<_>>();
+        terms.sort_by_key(|&(index, _)| index);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            trace_length: config.trace_length,
+            num_terms: config.num_terms,
+            terms,
+            padding,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &FibConfig) -> Result<(), String> {
+        validate::check_trace_length("fib.trace_length", config.trace_length)?;
+        match config.num_terms {
+            Some(num_terms) if num_terms < 2 || num_terms % 2 != 0 => Err(format!(
+                "number of terms must be even and at least 2, but fib.num_terms is {}",
+                num_terms
+            )),
+            // the row before the padding must hold no terms, so that the selector drops
+            Some(num_terms) if num_terms > 2 * (config.trace_length - 1 - NUM_PADDING_ROWS) => {
+                Err(format!(
+                    "number of terms must be at most {} for a trace of {} steps, but \
+                    fib.num_terms is {}",
+                    2 * (config.trace_length - 1 - NUM_PADDING_ROWS),
+                    config.trace_length,
+                    num_terms
+                ))
+            }
+            _ => Ok(()),
+        }?;
+
+        let max_index = 2 * (config.trace_length - NUM_PADDING_ROWS);
+        for (i, &index) in config.terms.iter().enumerate() {
+            if !(3..=max_index).contains(&index) {
+                return Err(format!(
+                    "term indexes must be from 3 to {} for a trace of {} steps, but fib.terms[{}] \
+                    is {}",
+                    max_index, config.trace_length, i, index
+                ));
+            }
+            if config.num_terms.is_none() && index == max_index {
+                return Err(format!(
+                    "fib.terms[{}] is {}, which is the result already",
+                    i, index
+                ));
+            }
+            if config.terms[..i].contains(&index) {
+                return Err(format!("fib.terms[{}] repeats term {}", i, index));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for FibExample<B> {
+    type Air = FibAir<B>;
+    type Prover = FibProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = FibProver::build_trace(self.trace_length, &self.padding, self.num_terms);
+        let result_step = self.pub_inputs().result_step(trace.length());
+        assert_eq!(self.result, trace.get(1, result_step));
+        for &(index, value) in self.terms.iter() {
+            let (column, step) = term_cell(index);
+            assert_eq!(value, trace.get(column, step));
+        }
+        trace
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = FibConfig::default();
+        ExampleDescriptor::new(
+            "fib",
+            "a Fibonacci number computed by a trace ending in random padding",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        let mut metadata = AirMetadata {
+            columns: vec![("ODD", 1), ("EVEN", 1)],
+            constraints: vec![("masked Fibonacci step", 2)],
+        };
+        if self.num_terms.is_some() {
+            metadata.columns.extend([("COUNTER", 1), ("SELECTOR", 1)]);
+            metadata.constraints.extend([
+                ("binary selector", 1),
+                ("selector stays zero", 1),
+                ("step counter", 1),
+                ("result at selector drop", 1),
+            ]);
+        }
+        metadata
+    }
+
+    fn prover(&self, options: ProofOptions) -> FibProver<B> {
+        let terms = self.terms.iter().map(|&(index, _)| index).collect();
+        FibProver::new(options, self.num_terms, terms)
+    }
+
+    fn pub_inputs(&self) -> FibInputs<B> {
+        FibInputs {
+            result: self.result,
+            num_terms: self.num_terms,
+            terms: self.terms.clone(),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> FibInputs<B> {
+        FibInputs {
+            result: self.result + B::ONE,
+            num_terms: self.num_terms,
+            terms: self.terms.clone(),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the n-th term of the Fibonacci sequence which starts with `F(1) = F(2) = 1`.
+pub fn compute_fib_term<B: ExampleField>(n: usize) -> B {
+    let mut t0 = B::ZERO;
+    let mut t1 = B::ONE;
+    for _ in 1..n {
+        let t = t0 + t1;
+        t0 = t1;
+        t1 = t;
+    }
+    t1
+}
@@ -0,0 +1,102 @@
+use super::{
+    air::{COUNTED_TRACE_WIDTH, COUNTER, NUM_PADDING_ROWS, SELECTOR, TRACE_WIDTH},
+    term_cell, FibAir, FibInputs,
+};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct FibProver<B: ExampleField> {
+    options: ProofOptions,
+    num_terms: Option<usize>,
+    terms: Vec<usize>,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> FibProver<B> {
+    /// Returns a prover of the result and of the terms with the specified indexes, which must be
+    /// sorted.
+    pub fn new(options: ProofOptions, num_terms: Option<usize>, terms: Vec<usize>) -> Self {
+        Self {
+            options,
+            num_terms,
+            terms,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which computes two terms of the sequence per row and ends with the
+    /// specified padding rows; when the number of terms is set, the trace also counts the rows
+    /// up to the one which holds the last of them.
+    pub fn build_trace(
+        trace_length: usize,
+        padding: &[[B; TRACE_WIDTH]],
+        num_terms: Option<usize>,
+    ) -> TraceTable<B> {
+        assert_eq!(NUM_PADDING_ROWS, padding.len());
+        let last_step = trace_length - 1 - NUM_PADDING_ROWS;
+        let num_rows = num_terms.map(|num_terms| num_terms / 2);
+        let width = match num_rows {
+            Some(_) => COUNTED_TRACE_WIDTH,
+            None => TRACE_WIDTH,
+        };
+        let mut trace = TraceTable::new(width, trace_length);
+
+        trace.fill(
+            |state| {
+                state[0] = B::ONE;
+                state[1] = B::ONE;
+                if num_rows.is_some() {
+                    state[COUNTER] = B::ZERO;
+                    state[SELECTOR] = B::ONE;
+                }
+            },
+            |step, state| {
+                if step < last_step {
+                    state[0] += state[1];
+                    state[1] += state[0];
+                } else {
+                    state[..TRACE_WIDTH].copy_from_slice(&padding[step - last_step]);
+                }
+                // the counter keeps running through the padding, where it is not constrained
+                if let Some(num_rows) = num_rows {
+                    state[COUNTER] += state[SELECTOR];
+                    state[SELECTOR] = if step + 1 < num_rows { B::ONE } else { B::ZERO };
+                }
+            },
+        );
+
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for FibProver<B> {
+    type BaseField = B;
+    type Air = FibAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibInputs<B> {
+        let mut pub_inputs = FibInputs {
+            result: B::ZERO,
+            num_terms: self.num_terms,
+            terms: Vec::new(),
+        };
+        pub_inputs.result = trace.get(1, pub_inputs.result_step(trace.length()));
+        pub_inputs.terms = self
+            .terms
+            .iter()
+            .map(|&index| {
+                let (column, step) = term_cell(index);
+                (index, trace.get(column, step))
+            })
+            .collect();
+        pub_inputs
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
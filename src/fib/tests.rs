@@ -0,0 +1,429 @@
+use super::{compute_fib_term, FibExample, FibInputs, FibProver};
+use crate::{
+    air_utils::are_equal,
+    config::FibConfig,
+    constraints, degrees, describe,
+    example::{self, Example},
+    field::FieldType,
+    tamper,
+    verify::{parse_pub_inputs, verify_proof},
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
+    Prover, Serializable, Trace, TraceInfo, TraceTable, TransitionConstraintDegree,
+};
+
+#[test]
+fn fib_trace_computes_fibonacci_numbers() {
+    let padding = [[BaseElement::from(7u8), BaseElement::from(9u8)]];
+    let trace = FibProver::build_trace(8, &padding, None);
+
+    // rows hold (F(1), F(2)), (F(3), F(4)), ..., (F(13), F(14)), followed by the padding
+    for step in 0..7 {
+        assert_eq!(
+            compute_fib_term::<BaseElement>(2 * step + 1),
+            trace.get(0, step)
+        );
+        assert_eq!(
+            compute_fib_term::<BaseElement>(2 * step + 2),
+            trace.get(1, step)
+        );
+    }
+    assert_eq!(BaseElement::from(377u16), trace.get(1, 6));
+    assert_eq!(padding[0], [trace.get(0, 7), trace.get(1, 7)]);
+}
+
+#[test]
+fn fib_padding_row_is_not_constrained() {
+    let example = build_example();
+    let padding = [[BaseElement::from(1u8), BaseElement::from(2u8)]];
+    let proof = example
+        .prover(build_options())
+        .prove(FibProver::build_trace(TRACE_LENGTH, &padding, None))
+        .unwrap();
+    assert!(
+        winterfell::verify::<<FibExample<BaseElement> as Example>::Air>(
+            proof,
+            example.pub_inputs()
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn fib_valid_trace_is_rejected_without_exemption() {
+    // the same AIR with winterfell's default exemption of the last row only: the transition
+    // into the padding row is checked and fails, so even an honestly generated proof is
+    // rejected
+    let example = build_example();
+    let prover = UnexemptedFibProver(build_options());
+    let proof = prover.prove(example.build_trace()).unwrap();
+    assert!(winterfell::verify::<UnexemptedFibAir>(proof, example.pub_inputs()).is_err());
+}
+
+#[test]
+fn fib_constraint_check_names_failing_constraint() {
+    let example = build_example();
+    let mut trace = example.build_trace();
+    trace.set(1, 5, trace.get(1, 5) + BaseElement::ONE);
+    let check = constraints::check_trace_constraints(&example, &trace, build_options()).unwrap();
+    let failure = check.transition_failure.as_ref().unwrap();
+    assert_eq!("masked Fibonacci step[1]", failure.constraint);
+    assert_eq!(4, failure.step);
+    assert_eq!(None, check.assertion_failure);
+    assert_eq!(
+        "constraint 'masked Fibonacci step[1]' failed at row 4: evaluates to 1",
+        check.to_string()
+    );
+}
+
+#[test]
+fn fib_counted_proof_verifies_any_number_of_terms() {
+    for num_terms in [2, 10, 100, 2 * (TRACE_LENGTH - 2)] {
+        let example = build_counted_example(num_terms);
+        assert_eq!(
+            compute_fib_term::<BaseElement>(num_terms),
+            example.pub_inputs().result
+        );
+        let report = example::run(&example, build_options());
+        assert_eq!(
+            Ok(()),
+            verify_proof("fib", &report.proof_bytes, &report.pub_inputs_bytes),
+            "{} terms",
+            num_terms
+        );
+    }
+}
+
+#[test]
+fn fib_counted_trace_marks_result_row() {
+    let padding = [[BaseElement::from(7u8), BaseElement::from(9u8)]];
+    let trace = FibProver::build_trace(8, &padding, Some(6));
+
+    // the selector is one on the three rows up to F(6) = 8, and the counter adds it up
+    assert_eq!(BaseElement::from(8u8), trace.get(1, 2));
+    let counter = (0..8).map(|step| trace.get(2, step)).collect::<Vec<_>>();
+    let selector = (0..8).map(|step| trace.get(3, step)).collect::<Vec<_>>();
+    assert_eq!(
+        [0u8, 1, 2, 3, 3, 3, 3, 3].map(BaseElement::from).to_vec(),
+        counter
+    );
+    assert_eq!(
+        [1u8, 1, 1, 0, 0, 0, 0, 0].map(BaseElement::from).to_vec(),
+        selector
+    );
+}
+
+#[test]
+fn fib_counted_wrong_inputs_are_rejected() {
+    let example = build_counted_example(100);
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    let verdict = tamper::use_wrong_inputs(&example, proof.clone());
+    assert!(verdict.is_rejected(), "{}", verdict);
+
+    // the proof of 100 terms does not prove the same result for another number of terms
+    let pub_inputs = FibInputs {
+        num_terms: Some(102),
+        ..example.pub_inputs()
+    };
+    assert!(
+        winterfell::verify::<<FibExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_err()
+    );
+
+    // moving the drop of the selector away from the result is caught
+    for column in [2, 3] {
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, 50);
+        assert!(verdict.is_rejected(), "column {}: {}", column, verdict);
+    }
+}
+
+#[test]
+fn fib_counted_trace_satisfies_constraints() {
+    let example = build_counted_example(100);
+    let check = constraints::check_constraints(&example, build_options()).unwrap();
+    assert!(check.is_satisfied(), "{}", check);
+    let report = degrees::check_degrees(&example, build_options(), 0);
+    assert!(report.is_consistent(), "{}", report);
+    describe::describe("fib", &example, build_options()).unwrap();
+}
+
+#[test]
+fn fib_counted_selector_must_drop_after_result_row() {
+    // checked on the AIR directly, without proving
+    let example = build_counted_example(100);
+    let mut trace = example.build_trace();
+    let air = <FibExample<BaseElement> as Example>::Air::new(
+        trace.get_info(),
+        example.pub_inputs(),
+        build_options(),
+    );
+    constraints::assert_constraints_hold(&air, &trace);
+
+    // dropping the selector a row early marks the term before the result, and leaves the
+    // counter one short
+    trace.set(3, 49, BaseElement::ZERO);
+    constraints::assert_constraints_fail(&air, &trace);
+    constraints::assert_constraints_fail_at(&air, &trace, 48, 5);
+    constraints::assert_constraints_fail_at(&air, &trace, 49, 4);
+}
+
+#[test]
+#[should_panic(expected = "constraint 5 holds at row 47")]
+fn fib_counted_constraint_holds_away_from_result_row() {
+    let example = build_counted_example(100);
+    let trace = example.build_trace();
+    let air = <FibExample<BaseElement> as Example>::Air::new(
+        trace.get_info(),
+        example.pub_inputs(),
+        build_options(),
+    );
+    constraints::assert_constraints_fail_at(&air, &trace, 47, 5);
+}
+
+#[test]
+fn fib_invalid_number_of_terms_is_rejected() {
+    for (num_terms, message) in [
+        (7, "must be even and at least 2, but fib.num_terms is 7"),
+        (0, "must be even and at least 2, but fib.num_terms is 0"),
+        (
+            2 * (TRACE_LENGTH - 1),
+            "must be at most 508 for a trace of 256 steps",
+        ),
+    ] {
+        let config = FibConfig {
+            trace_length: TRACE_LENGTH,
+            num_terms: Some(num_terms),
+            ..FibConfig::default()
+        };
+        let err = FibExample::<BaseElement>::validate(&config).unwrap_err();
+        assert!(err.contains(message), "{}", err);
+    }
+
+    // the number of terms is followed by the number of further terms
+    let mut pub_inputs_bytes = build_counted_example(100).pub_inputs().to_bytes();
+    let len = pub_inputs_bytes.len();
+    pub_inputs_bytes[len - 8] = 101;
+    assert!(parse_pub_inputs("fib", FieldType::F128, &pub_inputs_bytes).is_err());
+}
+
+#[test]
+fn fib_terms_are_asserted_along_the_sequence() {
+    let example = build_example_with_terms(None, vec![77, 10, 3, 509]);
+    let pub_inputs = example.pub_inputs();
+    assert_eq!(
+        vec![3, 10, 77, 509],
+        pub_inputs
+            .terms
+            .iter()
+            .map(|&(index, _)| index)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!((10, BaseElement::from(55u8)), pub_inputs.terms[1]);
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    assert!(
+        winterfell::verify::<<FibExample<BaseElement> as Example>::Air>(
+            proof.clone(),
+            pub_inputs.clone()
+        )
+        .is_ok()
+    );
+
+    // every term is checked, whichever column and row it is in
+    for i in 0..pub_inputs.terms.len() {
+        let mut wrong_inputs = pub_inputs.clone();
+        wrong_inputs.terms[i].1 += BaseElement::ONE;
+        assert!(
+            winterfell::verify::<<FibExample<BaseElement> as Example>::Air>(
+                proof.clone(),
+                wrong_inputs
+            )
+            .is_err(),
+            "term {}",
+            pub_inputs.terms[i].0
+        );
+    }
+
+    // terms past the result of a counted statement are asserted too
+    let example = build_example_with_terms(Some(100), vec![150, 510]);
+    let report = example::run(&example, build_options());
+    assert!(report.is_verified());
+    assert_eq!(
+        Ok(()),
+        verify_proof("fib", &report.proof_bytes, &report.pub_inputs_bytes)
+    );
+}
+
+#[test]
+fn fib_invalid_terms_are_rejected() {
+    for (num_terms, terms, message) in [
+        (
+            None,
+            vec![2],
+            "must be from 3 to 510 for a trace of 256 steps",
+        ),
+        (Some(100), vec![511], "but fib.terms[0] is 511"),
+        (
+            None,
+            vec![5, 510],
+            "fib.terms[1] is 510, which is the result already",
+        ),
+        (None, vec![5, 9, 5], "fib.terms[2] repeats term 5"),
+    ] {
+        let config = FibConfig {
+            trace_length: TRACE_LENGTH,
+            num_terms,
+            terms,
+            ..FibConfig::default()
+        };
+        let err = FibExample::<BaseElement>::validate(&config).unwrap_err();
+        assert!(err.contains(message), "{}", err);
+    }
+
+    // terms are read only in increasing order and from the third on
+    let pub_inputs = build_example_with_terms(None, vec![3, 10]).pub_inputs();
+    for terms in [vec![(10, 55u8), (3, 2)], vec![(3, 2), (3, 2)], vec![(2, 1)]] {
+        let pub_inputs = FibInputs {
+            terms: terms
+                .into_iter()
+                .map(|(index, value)| (index, BaseElement::from(value)))
+                .collect(),
+            ..pub_inputs.clone()
+        };
+        let bytes = pub_inputs.to_bytes();
+        assert!(parse_pub_inputs("fib", FieldType::F128, &bytes).is_err());
+    }
+    assert!(parse_pub_inputs("fib", FieldType::F128, &pub_inputs.to_bytes()).is_ok());
+}
+
+// AIR WITHOUT EXEMPTION
+// ================================================================================================
+
+struct UnexemptedFibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for UnexemptedFibAir {
+    type BaseField = BaseElement;
+    type PublicInputs = FibInputs<BaseElement>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+        ];
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let odd = current[0] + current[1];
+        result[0] = are_equal(next[0], odd);
+        result[1] = are_equal(next[1], odd + current[1]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 2;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+struct UnexemptedFibProver(ProofOptions);
+
+impl Prover for UnexemptedFibProver {
+    type BaseField = BaseElement;
+    type Air = UnexemptedFibAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibInputs<BaseElement> {
+        FibInputs {
+            result: trace.get(1, trace.length() - 2),
+            num_terms: None,
+            terms: Vec::new(),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.0
+    }
+}
+
+#[test]
+fn fib_corrupted_row_before_padding_is_rejected() {
+    // the second-to-last row is exempted from transition constraints into the padding row,
+    // but not from those which lead into it
+    let example = build_example();
+    let verdict = tamper::corrupt_trace_cell(&example, build_options(), 0, TRACE_LENGTH - 2);
+    assert!(verdict.is_rejected(), "{}", verdict);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 256;
+
+fn build_example() -> FibExample<BaseElement> {
+    FibExample::new(&FibConfig {
+        trace_length: TRACE_LENGTH,
+        ..FibConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_counted_example(num_terms: usize) -> FibExample<BaseElement> {
+    FibExample::new(&FibConfig {
+        trace_length: TRACE_LENGTH,
+        num_terms: Some(num_terms),
+        ..FibConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_example_with_terms(
+    num_terms: Option<usize>,
+    terms: Vec<usize>,
+) -> FibExample<BaseElement> {
+    FibExample::new(&FibConfig {
+        trace_length: TRACE_LENGTH,
+        num_terms,
+        terms,
+        ..FibConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,104 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; each row holds two consecutive terms of the sequence, `CURRENT` the
+/// earlier and `NEXT` the later one.
+pub const CURRENT: usize = 0;
+pub const NEXT: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The states a chunk of the sequence starts and ends in; each state is a pair of consecutive
+/// terms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FibChunkInputs<B: ExampleField> {
+    pub start: [B; TRACE_WIDTH],
+    pub end: [B; TRACE_WIDTH],
+}
+
+impl<B: ExampleField> Serializable for FibChunkInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.start[CURRENT]);
+        target.write(self.start[NEXT]);
+        target.write(self.end[CURRENT]);
+        target.write(self.end[NEXT]);
+    }
+}
+
+impl<B: ExampleField> Deserializable for FibChunkInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(FibChunkInputs {
+            start: [B::read_from(source)?, B::read_from(source)?],
+            end: [B::read_from(source)?, B::read_from(source)?],
+        })
+    }
+}
+
+// CHUNKED FIBONACCI AIR
+// ================================================================================================
+
+/// AIR of a chunk of the Fibonacci sequence which starts and ends in public states.
+///
+/// Each row advances the sequence by one term: the later term of a row becomes the earlier term
+/// of the next row, and the later term of the next row is the sum of both terms of the row.
+/// Unlike the Fibonacci example, the first row is not fixed to `(1, 1)`, so the proof of one
+/// chunk can pick up where the proof of another one left off.
+pub struct FibChunkAir<B: ExampleField> {
+    context: AirContext<B>,
+    start: [B; TRACE_WIDTH],
+    end: [B; TRACE_WIDTH],
+}
+
+impl<B: ExampleField> Air for FibChunkAir<B> {
+    type BaseField = B;
+    type PublicInputs = FibChunkInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibChunkInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let degrees = transition_degrees(&[1, 1]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            start: pub_inputs.start,
+            end: pub_inputs.end,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        result[0] = are_equal(next[CURRENT], current[NEXT]);
+        result[1] = are_equal(next[NEXT], current[CURRENT] + current[NEXT]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the chunk starts in the public starting state and ends in the public end state
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(CURRENT, self.start[CURRENT]),
+            first_row_equals(NEXT, self.start[NEXT]),
+            last_row_equals(CURRENT, trace_length, self.end[CURRENT]),
+            last_row_equals(NEXT, trace_length, self.end[NEXT]),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::FibChunkConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{FibChunkAir, FibChunkInputs, CURRENT, NEXT, TRACE_WIDTH};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::FibChunkProver;
+
+#[cfg(test)]
+mod tests;
+
+// CHUNKED FIBONACCI EXAMPLE
+// ================================================================================================
+
+/// Proves that the Fibonacci sequence, started from a public state of two consecutive terms,
+/// reaches a public end state after `trace_length - 1` steps.
+///
+/// A long computation can be split into chunks which are proven independently, each starting
+/// from the state the previous one ends in; `prove-chain` proves such a chain of chunks, and
+/// `verify-chain` checks every proof together with the links between them. This bounds the
+/// memory of the prover by the length of a chunk, at the cost of the verifier receiving and
+/// checking one proof per chunk, which recursive proofs would avoid.
+#[cfg(feature = "std")]
+pub struct FibChunkExample<B: ExampleField> {
+    trace_length: usize,
+    start: [B; TRACE_WIDTH],
+    end: [B; TRACE_WIDTH],
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> FibChunkExample<B> {
+    pub fn new(config: &FibChunkConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let start = [B::from(config.start[0]), B::from(config.start[1])];
+        Ok(Self::with_start(start, config.trace_length))
+    }
+
+    /// Returns an example of a chunk which starts from an arbitrary state, such as the end state
+    /// proven by the previous chunk.
+    pub fn with_start(start: [B; TRACE_WIDTH], trace_length: usize) -> Self {
+        assert!(
+            trace_length.is_power_of_two(),
+            "trace length must be a power of two"
+        );
+        // the trace of the zero state is constant, which winterfell cannot prove
+        assert!(
+            start != [B::ZERO; TRACE_WIDTH],
+            "starting state must not be zero"
+        );
+
+        // compute end state
+        let now = Instant::now();
+        let end = compute_state(start, trace_length - 1);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Self {
+            trace_length,
+            start,
+            end,
+        }
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &FibChunkConfig) -> Result<(), String> {
+        validate::check_trace_length("fibchunk.trace_length", config.trace_length)?;
+        // the trace of the zero state is constant, which winterfell cannot prove
+        if config.start.iter().all(|&term| B::from(term) == B::ZERO) {
+            return Err(
+                "starting state must not be zero, but both terms of fibchunk.start are".to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for FibChunkExample<B> {
+    type Air = FibChunkAir<B>;
+    type Prover = FibChunkProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = FibChunkProver::build_trace(self.start, self.trace_length);
+        let last_step = trace.length() - 1;
+        assert_eq!(
+            self.end,
+            [trace.get(CURRENT, last_step), trace.get(NEXT, last_step)]
+        );
+        trace
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = FibChunkConfig::default();
+        ExampleDescriptor::new(
+            "fibchunk",
+            "one chunk of a long Fibonacci computation between public states",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("start", config.start)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("CURRENT", 1), ("NEXT", 1)],
+            constraints: vec![("term shift", 1), ("Fibonacci sum", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> FibChunkProver<B> {
+        FibChunkProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> FibChunkInputs<B> {
+        FibChunkInputs {
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> FibChunkInputs<B> {
+        FibChunkInputs {
+            start: self.start,
+            end: [self.end[CURRENT], self.end[NEXT] + B::ONE],
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the state of the Fibonacci sequence `steps` terms after the specified state.
+#[cfg(feature = "std")]
+fn compute_state<B: ExampleField>(start: [B; TRACE_WIDTH], steps: usize) -> [B; TRACE_WIDTH] {
+    let [mut current, mut next] = start;
+    for _ in 0..steps {
+        (current, next) = (next, current + next);
+    }
+    [current, next]
+}
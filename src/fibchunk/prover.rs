@@ -0,0 +1,59 @@
+use super::{
+    air::{CURRENT, NEXT, TRACE_WIDTH},
+    FibChunkAir, FibChunkInputs,
+};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct FibChunkProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> FibChunkProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which starts in the specified state and advances the sequence by one term
+    /// per row.
+    pub fn build_trace(start: [B; TRACE_WIDTH], trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        trace.fill(
+            |state| {
+                state.copy_from_slice(&start);
+            },
+            |_, state| {
+                let sum = state[CURRENT] + state[NEXT];
+                state[CURRENT] = state[NEXT];
+                state[NEXT] = sum;
+            },
+        );
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for FibChunkProver<B> {
+    type BaseField = B;
+    type Air = FibChunkAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibChunkInputs<B> {
+        let last_step = trace.length() - 1;
+        FibChunkInputs {
+            start: [trace.get(CURRENT, 0), trace.get(NEXT, 0)],
+            end: [trace.get(CURRENT, last_step), trace.get(NEXT, last_step)],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,114 @@
+use super::{FibChunkExample, FibChunkProver};
+use crate::{
+    bundle::ProofBundle,
+    chain,
+    config::{FibChunkConfig, ProofOptionsConfig, RunConfig},
+    error::ExampleError,
+    example::Example,
+};
+use winterfell::{math::fields::f128::BaseElement, Trace};
+
+#[test]
+fn fibchunk_trace_follows_sequence() {
+    let start = [BaseElement::from(0u8), BaseElement::from(1u8)];
+    let trace = FibChunkProver::build_trace(start, 8);
+    let terms = [0u64, 1, 1, 2, 3, 5, 8, 13, 21];
+    for step in 0..8 {
+        assert_eq!(BaseElement::from(terms[step]), trace.get(0, step));
+        assert_eq!(BaseElement::from(terms[step + 1]), trace.get(1, step));
+    }
+}
+
+#[test]
+fn fibchunk_chunks_continue_the_sequence() {
+    // two chunks of 8 rows take 14 steps, as many as a single chunk of 15 rows would
+    let first = FibChunkExample::<BaseElement>::new(&FibChunkConfig {
+        trace_length: 8,
+        ..FibChunkConfig::default()
+    })
+    .unwrap();
+    let second = FibChunkExample::with_start(first.pub_inputs().end, 8);
+    let end = second.pub_inputs().end;
+    assert_eq!([BaseElement::from(377u16), BaseElement::from(610u16)], end);
+}
+
+#[test]
+fn fibchunk_chain_verifies() {
+    let bundle = chain::prove_chain(&build_config(), 3).unwrap();
+    assert_eq!(3, bundle.entries.len());
+    let bundle = ProofBundle::from_bytes(&bundle.to_bytes()).unwrap();
+
+    let report = chain::verify_chain(&bundle);
+    assert!(report.is_verified(), "{}", report);
+    let span = report.span.unwrap();
+    assert_eq!(3 * (TRACE_LENGTH - 1), span.num_steps);
+    assert_eq!(["0".to_string(), "1".to_string()], span.start);
+}
+
+#[test]
+fn fibchunk_reordered_chain_is_rejected() {
+    let mut bundle = chain::prove_chain(&build_config(), 3).unwrap();
+    bundle.entries.swap(1, 2);
+
+    // every proof is still valid on its own
+    let report = chain::verify_chain(&bundle);
+    assert!(report.proofs.is_verified(), "{}", report);
+    assert!(!report.is_verified());
+    assert_eq!(
+        Some("chunk 1 does not start where chunk 0 ends".to_string()),
+        report.link_error
+    );
+}
+
+#[test]
+fn fibchunk_chain_with_foreign_chunk_is_rejected() {
+    let mut bundle = chain::prove_chain(&build_config(), 2).unwrap();
+    let mut config = build_config();
+    config.fibchunk.start = [1, 1];
+    let foreign = chain::prove_chain(&config, 1).unwrap();
+    bundle.entries[1] = foreign.entries[0].clone();
+
+    let report = chain::verify_chain(&bundle);
+    assert!(report.proofs.is_verified(), "{}", report);
+    assert!(!report.is_verified());
+
+    let mut bundle = chain::prove_chain(&build_config(), 2).unwrap();
+    bundle.entries[1].proof_bytes[100] ^= 1;
+    let report = chain::verify_chain(&bundle);
+    assert!(!report.proofs.is_verified());
+    assert!(!report.is_verified());
+
+    assert!(chain::prove_chain(&build_config(), 0).is_err());
+    assert!(!chain::verify_chain(&ProofBundle::default()).is_verified());
+}
+
+#[test]
+fn fibchunk_zero_start_is_not_accepted() {
+    let err = FibChunkExample::<BaseElement>::new(&FibChunkConfig {
+        start: [0, 0],
+        ..FibChunkConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(err.to_string().contains("must not be zero"), "{}", err);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 128;
+
+fn build_config() -> RunConfig {
+    RunConfig {
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        fibchunk: FibChunkConfig {
+            trace_length: TRACE_LENGTH,
+            ..FibChunkConfig::default()
+        },
+        ..RunConfig::default()
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::{is_zero, less_than_difference, reduction},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in the decompositions of the next remainder and of its slack below the
+/// divisor.
+pub const DIVISOR_BITS: usize = 16;
+
+/// Largest supported divisor. Two remainders below it add up to less than
+/// `2^(DIVISOR_BITS + 1)`, which is far below the modulus of every supported field, so the
+/// reduction constraint holds over the integers.
+pub const MAX_DIVISOR: u64 = 1 << DIVISOR_BITS;
+
+/// Trace column layout. `TERM` holds the remainder of the current term of the sequence, the
+/// columns from `NEXT_BITS` on the bits of the remainder of the next term, least significant bit
+/// first, and the columns from `SLACK_BITS` on those of `divisor - 1 - next`; `QUOTIENT` holds
+/// the quotient by the divisor of the sum of the two remainders, `INVERSE` and `IS_ZERO` the
+/// witness and the flag of the is-zero gadget applied to `TERM`, and `STEP` the index of the row.
+pub const TERM: usize = 0;
+pub const NEXT_BITS: usize = TERM + 1;
+pub const SLACK_BITS: usize = NEXT_BITS + DIVISOR_BITS;
+pub const QUOTIENT: usize = SLACK_BITS + DIVISOR_BITS;
+pub const INVERSE: usize = QUOTIENT + 1;
+pub const IS_ZERO: usize = INVERSE + 1;
+pub const STEP: usize = IS_ZERO + 1;
+pub const TRACE_WIDTH: usize = STEP + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of the statement that `F(trace_length)` is, or is not, divisible by the
+/// divisor; the term itself and its remainder are not among them.
+#[derive(Clone)]
+pub struct FibDivInputs {
+    pub divisor: u64,
+    pub divisible: bool,
+}
+
+impl Serializable for FibDivInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.divisor);
+        target.write_u8(self.divisible as u8);
+    }
+}
+
+impl Deserializable for FibDivInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let divisor = source.read_u64()?;
+        if !(2..=MAX_DIVISOR).contains(&divisor) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid divisor {}",
+                divisor
+            )));
+        }
+        let divisible = match source.read_u8()? {
+            0 => false,
+            1 => true,
+            value => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid divisibility flag {}",
+                    value
+                )))
+            }
+        };
+        Ok(FibDivInputs { divisor, divisible })
+    }
+}
+
+// FIBONACCI DIVISIBILITY AIR
+// ================================================================================================
+
+/// AIR of the statement that a Fibonacci number is divisible by a small divisor, in which only
+/// the outcome of the test is public.
+///
+/// The sequence is computed modulo the divisor in the same way as by
+/// [FibModAir](crate::fibmod::air::FibModAir): the sum of the two remainders of a row is split
+/// as `q * divisor + next`, with the quotient binary and `next` range-checked below the divisor
+/// by the bits of `next` and of `divisor - 1 - next`. The term is divisible exactly when its
+/// remainder is zero, which the is-zero gadget of [crate::gadgets] turns into a flag:
+///
+/// * `term * inv = 1 - flag` and `flag * term = 0`, evaluated on the next row so that the last
+///   row is covered, force `flag` to be one exactly when the remainder is zero.
+///
+/// The remainders repeat with the Pisano period of the divisor, which for divisors such as 3
+/// (with a period of 8) divides the trace length. Every column would then repeat as well, and
+/// its polynomial would have a lower degree than the constraints declare, which winterfell 0.3
+/// rejects when it builds the composition polynomial. A `STEP` column, which counts the rows with
+/// `step' = step + 1`, never repeats and keeps the trace from being periodic.
+///
+/// Boundary assertions fix the first two remainders to `F(1) = F(2) = 1`, the first step to zero,
+/// and the flag on the last row to the public outcome. Unlike in `fibmod`, no assertion touches the
+/// remainder on the last row, so the public inputs carry one bit about it rather than its value.
+/// The constraints alone do not hide the remainder, though: the term is determined by the public
+/// trace length, and winterfell 0.3 proofs are not zero-knowledge, since the queried rows of the
+/// extended trace are sent in the clear. The example shows how such a statement is structured, with
+/// a private value tied to a public predicate, rather than how to keep the value secret.
+pub struct FibDivAir<B: ExampleField> {
+    context: AirContext<B>,
+    divisor: B,
+    divisible: bool,
+}
+
+impl<B: ExampleField> Air for FibDivAir<B> {
+    type BaseField = B;
+    type PublicInputs = FibDivInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibDivInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![1];
+        degrees.extend([2; 2 * DIVISOR_BITS + 1]);
+        degrees.extend([1, 1, 2, 2, 1]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            divisor: B::from(pub_inputs.divisor),
+            divisible: pub_inputs.divisible,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let divisor = E::from(self.divisor);
+        let next_bits = &current[NEXT_BITS..NEXT_BITS + DIVISOR_BITS];
+        let slack_bits = &current[SLACK_BITS..SLACK_BITS + DIVISOR_BITS];
+        let term = current[TERM];
+        let next_term = combine_bits(next_bits);
+
+        // the next remainder moves into the term column
+        result[TERM] = are_equal(next[TERM], next_term);
+
+        // every bit and the quotient are binary
+        result.enforce_binary(NEXT_BITS, next_bits);
+        result.enforce_binary(SLACK_BITS, slack_bits);
+        result[QUOTIENT] = is_binary(current[QUOTIENT]);
+
+        // the next remainder is below the divisor
+        let slack = less_than_difference(next_term, divisor, E::ONE, DIVISOR_BITS);
+        result[QUOTIENT + 1] = are_equal(slack, combine_bits(slack_bits));
+
+        // the next remainder of the next row is the reduced sum of the two remainders of this row
+        let sum = term + next_term;
+        let remainder = combine_bits(&next[NEXT_BITS..NEXT_BITS + DIVISOR_BITS]);
+        result[QUOTIENT + 2] = reduction(sum, current[QUOTIENT], remainder, divisor);
+
+        // the flag of the next row tells whether its remainder is zero
+        let [inverse, flag] = is_zero(next[TERM], next[INVERSE], next[IS_ZERO]);
+        result[QUOTIENT + 3] = inverse;
+        result[QUOTIENT + 4] = flag;
+
+        // the step counter keeps the trace from repeating with the remainders
+        result[QUOTIENT + 5] = are_equal(next[STEP], current[STEP] + E::ONE);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence starts with F(1) = F(2) = 1, and the flag of the last term is public
+        let trace_length = self.trace_length();
+        let divisible = if self.divisible { B::ONE } else { B::ZERO };
+        vec![
+            first_row_equals(TERM, B::ONE),
+            Assertion::single(TERM, 1, B::ONE),
+            first_row_equals(STEP, B::ZERO),
+            last_row_equals(IS_ZERO, trace_length, divisible),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::FibDivConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::{marker::PhantomData, time::Instant};
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{FibDivAir, FibDivInputs, DIVISOR_BITS, MAX_DIVISOR};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::FibDivProver;
+
+#[cfg(test)]
+mod tests;
+
+// FIBONACCI DIVISIBILITY EXAMPLE
+// ================================================================================================
+
+/// Proves whether the Fibonacci number `F(trace_length)`, where `F(1) = F(2) = 1`, is divisible
+/// by a small divisor, with the outcome as the only public result.
+///
+/// The sequence is computed modulo the divisor as in [crate::fibmod], and the is-zero gadget of
+/// [crate::gadgets] turns the last remainder into the public flag; see [FibDivAir] for the
+/// constraints and for what the proof does and does not reveal about the remainder.
+#[cfg(feature = "std")]
+pub struct FibDivExample<B: ExampleField> {
+    trace_length: usize,
+    divisor: u64,
+    divisible: bool,
+    _field: PhantomData<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> FibDivExample<B> {
+    pub fn new(config: &FibDivConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // compute result
+        let now = Instant::now();
+        let divisible = remainder(config.divisor, config.trace_length) == 0;
+        info!(
+            "Computed whether F({}) is divisible by {} in {} ms",
+            config.trace_length,
+            config.divisor,
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            divisor: config.divisor,
+            divisible,
+            _field: PhantomData,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &FibDivConfig) -> Result<(), String> {
+        validate::check_trace_length("fibdiv.trace_length", config.trace_length)?;
+        if !(2..=MAX_DIVISOR).contains(&config.divisor) {
+            return Err(format!(
+                "divisor must be between 2 and {}, but fibdiv.divisor is {}",
+                MAX_DIVISOR, config.divisor
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns true if `F(trace_length)` is divisible by the divisor.
+    pub fn is_divisible(&self) -> bool {
+        self.divisible
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for FibDivExample<B> {
+    type Air = FibDivAir<B>;
+    type Prover = FibDivProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        FibDivProver::build_trace(self.divisor, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = FibDivConfig::default();
+        ExampleDescriptor::new(
+            "fibdiv",
+            "whether a Fibonacci number is divisible by a small divisor, with the term private",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("divisor", config.divisor)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("TERM", 1),
+                ("NEXT_BITS", DIVISOR_BITS),
+                ("SLACK_BITS", DIVISOR_BITS),
+                ("QUOTIENT", 1),
+                ("INVERSE", 1),
+                ("IS_ZERO", 1),
+                ("STEP", 1),
+            ],
+            constraints: vec![
+                ("next remainder from bits", 1),
+                ("binary bits", 2 * DIVISOR_BITS),
+                ("binary quotient", 1),
+                ("remainder below divisor", 1),
+                ("modular reduction", 1),
+                ("is-zero inverse", 1),
+                ("is-zero flag", 1),
+                ("step counter", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> FibDivProver<B> {
+        FibDivProver::new(options, self.divisor)
+    }
+
+    fn pub_inputs(&self) -> FibDivInputs {
+        FibDivInputs {
+            divisor: self.divisor,
+            divisible: self.divisible,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> FibDivInputs {
+        FibDivInputs {
+            divisible: !self.divisible,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// SEQUENCE FUNCTIONS
+// ================================================================================================
+
+/// Returns `F(n) mod divisor`, where `F(1) = F(2) = 1`.
+#[cfg(feature = "std")]
+pub fn remainder(divisor: u64, n: usize) -> u64 {
+    let (term, _) = (1..n).fold((1, 1), |(term, next), _| (next, (term + next) % divisor));
+    term
+}
@@ -0,0 +1,75 @@
+use super::{
+    air::{
+        DIVISOR_BITS, INVERSE, IS_ZERO, NEXT_BITS, QUOTIENT, SLACK_BITS, STEP, TERM, TRACE_WIDTH,
+    },
+    FibDivAir, FibDivInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{is_zero_witness, less_than_witness, reduction_witness, to_bits},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct FibDivProver<B: ExampleField> {
+    options: ProofOptions,
+    divisor: u64,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> FibDivProver<B> {
+    pub fn new(options: ProofOptions, divisor: u64) -> Self {
+        Self {
+            options,
+            divisor,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace of `trace_length` rows, whose row `i` holds `F(i + 1) mod divisor`, the
+    /// bits of `F(i + 2) mod divisor` and of its slack below the divisor, the quotient of the
+    /// sum of the two remainders by the divisor, whether `F(i + 1)` is divisible, and `i`.
+    pub fn build_trace(divisor: u64, trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        let (mut term, mut next) = (1, 1);
+        for step in 0..trace_length {
+            let (_, slack) = less_than_witness(next, divisor, DIVISOR_BITS);
+            let (quotient, sum) = reduction_witness(term + next, divisor);
+            let (inverse, is_zero) = is_zero_witness(B::from(term));
+            trace.set(TERM, step, B::from(term));
+            for (i, bit) in to_bits(next, DIVISOR_BITS).enumerate() {
+                trace.set(NEXT_BITS + i, step, bit);
+            }
+            for (i, bit) in to_bits(slack, DIVISOR_BITS).enumerate() {
+                trace.set(SLACK_BITS + i, step, bit);
+            }
+            trace.set(QUOTIENT, step, B::from(quotient));
+            trace.set(INVERSE, step, inverse);
+            trace.set(IS_ZERO, step, is_zero);
+            trace.set(STEP, step, B::from(step as u64));
+            term = next;
+            next = sum;
+        }
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for FibDivProver<B> {
+    type BaseField = B;
+    type Air = FibDivAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibDivInputs {
+        FibDivInputs {
+            divisor: self.divisor,
+            divisible: trace.get(IS_ZERO, trace.length() - 1) == B::ONE,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
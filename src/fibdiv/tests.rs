@@ -0,0 +1,111 @@
+use super::{
+    air::{INVERSE, IS_ZERO, TERM},
+    remainder, FibDivExample, FibDivInputs, FibDivProver,
+};
+use crate::{
+    config::FibDivConfig,
+    example::{self, Example},
+    verify::verify_proof,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn fibdiv_unsupported_inputs_are_rejected() {
+    let report = example::run(&build_example(), build_options());
+    let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+    pub_inputs_bytes[..8].copy_from_slice(&(1u64 << 17).to_le_bytes());
+    assert!(verify_proof("fibdiv", &report.proof_bytes, &pub_inputs_bytes).is_err());
+
+    let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+    pub_inputs_bytes[8] = 2;
+    assert!(verify_proof("fibdiv", &report.proof_bytes, &pub_inputs_bytes).is_err());
+}
+
+#[test]
+fn fibdiv_flags_every_divisible_term() {
+    // F(1..=8) = 1, 1, 2, 3, 5, 8, 13, 21, of which F(3) and F(6) are even
+    let trace = FibDivProver::<BaseElement>::build_trace(2, 8);
+    let flags = [0u64, 0, 1, 0, 0, 1, 0, 0];
+    for (step, &flag) in flags.iter().enumerate() {
+        assert_eq!(BaseElement::from(flag), trace.get(IS_ZERO, step));
+    }
+
+    // F(n) is divisible by 2 exactly when n is divisible by 3, and by 3 when n is divisible by 4
+    for n in 1..=24 {
+        assert_eq!(n % 3 == 0, remainder(2, n) == 0, "F({})", n);
+        assert_eq!(n % 4 == 0, remainder(3, n) == 0, "F({})", n);
+    }
+    let odd = FibDivExample::<BaseElement>::new(&FibDivConfig {
+        trace_length: TRACE_LENGTH,
+        divisor: 2,
+        ..FibDivConfig::default()
+    })
+    .unwrap();
+    assert!(build_example().is_divisible());
+    assert!(!odd.is_divisible());
+    let proof = odd
+        .prover(build_options())
+        .prove(odd.build_trace())
+        .unwrap();
+    assert!(
+        winterfell::verify::<<FibDivExample<BaseElement> as Example>::Air>(proof, odd.pub_inputs())
+            .is_ok()
+    );
+}
+
+#[test]
+fn fibdiv_forged_flag_is_rejected() {
+    // claim that F(64) is not divisible by 3 by clearing its flag; the remainder is zero, so no
+    // inverse can make up for the flag, and the prover is left with a trace which breaks the
+    // is-zero constraints on the last row
+    let mut trace = FibDivProver::<BaseElement>::build_trace(3, TRACE_LENGTH);
+    let last = TRACE_LENGTH - 1;
+    assert_eq!(BaseElement::ZERO, trace.get(TERM, last));
+    trace.set(IS_ZERO, last, BaseElement::ZERO);
+    trace.set(INVERSE, last, BaseElement::ONE);
+    let pub_inputs = FibDivInputs {
+        divisor: 3,
+        divisible: false,
+    };
+
+    let proof = FibDivProver::new(build_options(), 3).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<FibDivExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn fibdiv_public_inputs_hold_divisor_and_outcome() {
+    // the public inputs are the divisor and the outcome, and nothing about the remainder
+    let report = example::run(&build_example(), build_options());
+    assert_eq!(9, report.pub_inputs_bytes.len());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_example() -> FibDivExample<BaseElement> {
+    FibDivExample::new(&FibDivConfig {
+        trace_length: TRACE_LENGTH,
+        ..FibDivConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,155 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::{less_than_difference, reduction},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in the decompositions of the next term and of its slack below the modulus.
+pub const MODULUS_BITS: usize = 32;
+
+/// Largest supported modulus. Two terms below it add up to less than `2^(MODULUS_BITS + 1)`,
+/// which is far below the modulus of every supported field, so the reduction constraint holds
+/// over the integers.
+pub const MAX_MODULUS: u64 = 1 << MODULUS_BITS;
+
+/// Trace column layout. `TERM` holds the current term of the sequence, the columns from
+/// `NEXT_BITS` on the bits of the next term, least significant bit first, and the columns from
+/// `SLACK_BITS` on those of `modulus - 1 - next`; `QUOTIENT` holds the quotient by the modulus of
+/// the sum of the two terms.
+pub const TERM: usize = 0;
+pub const NEXT_BITS: usize = TERM + 1;
+pub const SLACK_BITS: usize = NEXT_BITS + MODULUS_BITS;
+pub const QUOTIENT: usize = SLACK_BITS + MODULUS_BITS;
+pub const TRACE_WIDTH: usize = QUOTIENT + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct FibModInputs<B: ExampleField> {
+    pub modulus: u64,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for FibModInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.modulus);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for FibModInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let modulus = source.read_u64()?;
+        if !(2..=MAX_MODULUS).contains(&modulus) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid modulus {}",
+                modulus
+            )));
+        }
+        Ok(FibModInputs {
+            modulus,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// FIBONACCI MODULO AIR
+// ================================================================================================
+
+/// AIR of the Fibonacci sequence modulo a small modulus, computed inside a much larger field.
+///
+/// The field has no reduction modulo another number, so every addition is followed by an
+/// explicit one: the sum `a + b` of the two terms of a row is split as `q * modulus + next`, and
+/// range checks pin the quotient and the remainder down. The constraints of a row are:
+///
+/// * `n_i * n_i = n_i` and `s_i * s_i = s_i` for every bit of the next term and of its slack;
+/// * `q * q = q`, so that the quotient is binary, which is the range of the quotient of a sum of
+///   two reduced terms;
+/// * `modulus - 1 - next = sum(2^i * s_i)`, so that the next term is below the modulus, given
+///   that its bits keep it non-negative;
+/// * `a + b = q * modulus + next`, where `b = sum(2^i * n_i)` and `next` is the `b` of the next
+///   row;
+/// * the term of the next row is `b`.
+///
+/// Without the range checks, the sum could be split with `q = 0` into an unreduced remainder,
+/// and the trace would then compute the sequence modulo nothing at all.
+pub struct FibModAir<B: ExampleField> {
+    context: AirContext<B>,
+    modulus: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for FibModAir<B> {
+    type BaseField = B;
+    type PublicInputs = FibModInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FibModInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![1];
+        degrees.extend([2; 2 * MODULUS_BITS + 1]);
+        degrees.extend([1, 1]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            modulus: B::from(pub_inputs.modulus),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let modulus = E::from(self.modulus);
+        let next_bits = &current[NEXT_BITS..NEXT_BITS + MODULUS_BITS];
+        let slack_bits = &current[SLACK_BITS..SLACK_BITS + MODULUS_BITS];
+        let term = current[TERM];
+        let next_term = combine_bits(next_bits);
+
+        // the next term moves into the term column
+        result[TERM] = are_equal(next[TERM], next_term);
+
+        // every bit and the quotient are binary
+        result.enforce_binary(NEXT_BITS, next_bits);
+        result.enforce_binary(SLACK_BITS, slack_bits);
+        result[QUOTIENT] = is_binary(current[QUOTIENT]);
+
+        // the next term is below the modulus
+        let slack = less_than_difference(next_term, modulus, E::ONE, MODULUS_BITS);
+        result[QUOTIENT + 1] = are_equal(slack, combine_bits(slack_bits));
+
+        // the next term of the next row is the reduced sum of the two terms of this row
+        let sum = term + next_term;
+        let remainder = combine_bits(&next[NEXT_BITS..NEXT_BITS + MODULUS_BITS]);
+        result[QUOTIENT + 2] = reduction(sum, current[QUOTIENT], remainder, modulus);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence starts with F(1) = F(2) = 1 and ends with the public result
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(TERM, B::ONE),
+            Assertion::single(TERM, 1, B::ONE),
+            last_row_equals(TERM, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::FibModConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::{marker::PhantomData, time::Instant};
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{FibModAir, FibModInputs, MAX_MODULUS, MODULUS_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::FibModProver;
+
+#[cfg(test)]
+mod tests;
+
+// FIBONACCI MODULO EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the Fibonacci number `F(trace_length) mod modulus`, where
+/// `F(1) = F(2) = 1`, for a modulus far smaller than that of the field.
+///
+/// The field is not the one the sequence is computed in, so every addition is reduced with the
+/// modular reduction gadget of [crate::gadgets]; see [FibModAir] for the constraints.
+#[cfg(feature = "std")]
+pub struct FibModExample<B: ExampleField> {
+    trace_length: usize,
+    modulus: u64,
+    result: u64,
+    _field: PhantomData<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> FibModExample<B> {
+    pub fn new(config: &FibModConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // compute result
+        let now = Instant::now();
+        let (result, _) = (1..config.trace_length).fold((1, 1), |(term, next), _| {
+            (next, (term + next) % config.modulus)
+        });
+        info!(
+            "Computed Fibonacci number {} modulo {} in {} ms",
+            result,
+            config.modulus,
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            modulus: config.modulus,
+            result,
+            _field: PhantomData,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &FibModConfig) -> Result<(), String> {
+        validate::check_trace_length("fibmod.trace_length", config.trace_length)?;
+        if !(2..=MAX_MODULUS).contains(&config.modulus) {
+            return Err(format!(
+                "modulus must be between 2 and {}, but fibmod.modulus is {}",
+                MAX_MODULUS, config.modulus
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for FibModExample<B> {
+    type Air = FibModAir<B>;
+    type Prover = FibModProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        FibModProver::build_trace(self.modulus, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = FibModConfig::default();
+        ExampleDescriptor::new("fibmod", "a Fibonacci number modulo a small prime")
+            .with_default("trace_length", config.trace_length)
+            .with_default("modulus", config.modulus)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("TERM", 1),
+                ("NEXT_BITS", MODULUS_BITS),
+                ("SLACK_BITS", MODULUS_BITS),
+                ("QUOTIENT", 1),
+            ],
+            constraints: vec![
+                ("next term from bits", 1),
+                ("binary bits", 2 * MODULUS_BITS),
+                ("binary quotient", 1),
+                ("remainder below modulus", 1),
+                ("modular reduction", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> FibModProver<B> {
+        FibModProver::new(options, self.modulus)
+    }
+
+    fn pub_inputs(&self) -> FibModInputs<B> {
+        FibModInputs {
+            modulus: self.modulus,
+            result: B::from(self.result),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> FibModInputs<B> {
+        FibModInputs {
+            result: B::from((self.result + 1) % self.modulus),
+            ..self.pub_inputs()
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::{
+    air::{MODULUS_BITS, NEXT_BITS, QUOTIENT, SLACK_BITS, TERM, TRACE_WIDTH},
+    FibModAir, FibModInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{less_than_witness, reduction_witness, to_bits},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct FibModProver<B: ExampleField> {
+    options: ProofOptions,
+    modulus: u64,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> FibModProver<B> {
+    pub fn new(options: ProofOptions, modulus: u64) -> Self {
+        Self {
+            options,
+            modulus,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace of `trace_length` rows, whose row `i` holds `F(i + 1) mod modulus`, the
+    /// bits of `F(i + 2) mod modulus` and of its slack below the modulus, and the quotient of
+    /// the sum of the two terms by the modulus.
+    pub fn build_trace(modulus: u64, trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        let (mut term, mut next) = (1, 1);
+        for step in 0..trace_length {
+            let (_, slack) = less_than_witness(next, modulus, MODULUS_BITS);
+            let (quotient, sum) = reduction_witness(term + next, modulus);
+            trace.set(TERM, step, B::from(term));
+            for (i, bit) in to_bits(next, MODULUS_BITS).enumerate() {
+                trace.set(NEXT_BITS + i, step, bit);
+            }
+            for (i, bit) in to_bits(slack, MODULUS_BITS).enumerate() {
+                trace.set(SLACK_BITS + i, step, bit);
+            }
+            trace.set(QUOTIENT, step, B::from(quotient));
+            term = next;
+            next = sum;
+        }
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for FibModProver<B> {
+    type BaseField = B;
+    type Air = FibModAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibModInputs<B> {
+        FibModInputs {
+            modulus: self.modulus,
+            result: trace.get(TERM, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,128 @@
+use super::{
+    air::{MODULUS_BITS, NEXT_BITS, QUOTIENT, SLACK_BITS, TERM},
+    FibModExample, FibModInputs, FibModProver,
+};
+use crate::{
+    config::FibModConfig,
+    example::{self, Example},
+    verify::verify_proof,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement, StarkField},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace, TraceTable,
+};
+
+#[test]
+fn fibmod_unsupported_modulus_is_rejected() {
+    let report = example::run(&build_example(), build_options());
+    let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+    pub_inputs_bytes[..8].copy_from_slice(&(1u64 << 33).to_le_bytes());
+    assert!(verify_proof("fibmod", &report.proof_bytes, &pub_inputs_bytes).is_err());
+}
+
+#[test]
+fn fibmod_trace_reduces_every_sum() {
+    // F(1..=9) = 1, 1, 2, 3, 5, 8, 13, 21, 34, which is 1, 1, 2, 3, 5, 1, 6, 0, 6 modulo 7
+    let trace = FibModProver::<BaseElement>::build_trace(7, 8);
+    let terms = [1u64, 1, 2, 3, 5, 1, 6, 0];
+    let nexts = [1u64, 2, 3, 5, 1, 6, 0, 6];
+    let quotients = [0u64, 0, 0, 1, 0, 1, 0, 0];
+    for step in 0..8 {
+        assert_eq!(BaseElement::from(terms[step]), trace.get(TERM, step));
+        assert_eq!(nexts[step], combine(&trace, NEXT_BITS, step));
+        assert_eq!(6 - nexts[step], combine(&trace, SLACK_BITS, step));
+        assert_eq!(
+            BaseElement::from(quotients[step]),
+            trace.get(QUOTIENT, step)
+        );
+    }
+
+    let example = FibModExample::<BaseElement>::new(&FibModConfig {
+        trace_length: 8,
+        modulus: 7,
+        ..FibModConfig::default()
+    })
+    .unwrap();
+    assert_eq!(BaseElement::ZERO, example.pub_inputs().result);
+}
+
+#[test]
+fn fibmod_unreduced_remainder_is_rejected() {
+    // skip a reduction by taking a quotient of zero, so that the remainder is r + modulus, and
+    // make up for it in the two following reductions, which then have a quotient of one more;
+    // every constraint but the range check of the remainder below the modulus still holds
+    let modulus = FibModConfig::default().modulus;
+    let mut trace = FibModProver::<BaseElement>::build_trace(modulus, TRACE_LENGTH);
+    let quotient = |trace: &TraceTable<BaseElement>, step| trace.get(QUOTIENT, step).as_int();
+    let step = (0..TRACE_LENGTH - 3)
+        .find(|&step| {
+            quotient(&trace, step) == 1
+                && quotient(&trace, step + 1) == 0
+                && quotient(&trace, step + 2) == 0
+        })
+        .expect("no reduction is followed by two sums without one");
+    let unreduced = combine(&trace, NEXT_BITS, step + 1) + modulus;
+    assert!(unreduced < 1 << MODULUS_BITS);
+
+    trace.set(QUOTIENT, step, BaseElement::ZERO);
+    let slack = (modulus - 1).wrapping_sub(unreduced);
+    for i in 0..MODULUS_BITS {
+        trace.set(
+            NEXT_BITS + i,
+            step + 1,
+            BaseElement::from((unreduced >> i) & 1),
+        );
+        trace.set(
+            SLACK_BITS + i,
+            step + 1,
+            BaseElement::from((slack >> i) & 1),
+        );
+    }
+    trace.set(QUOTIENT, step + 1, BaseElement::ONE);
+    trace.set(TERM, step + 2, BaseElement::from(unreduced));
+    trace.set(QUOTIENT, step + 2, BaseElement::ONE);
+    let pub_inputs = FibModInputs {
+        modulus,
+        result: trace.get(TERM, TRACE_LENGTH - 1),
+    };
+
+    let proof = FibModProver::new(build_options(), modulus)
+        .prove(trace)
+        .unwrap();
+    assert!(
+        winterfell::verify::<<FibModExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_example() -> FibModExample<BaseElement> {
+    FibModExample::new(&FibModConfig {
+        trace_length: TRACE_LENGTH,
+        ..FibModConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+/// Returns the integer whose bits are held in the columns from `column` on in row `step`.
+fn combine(trace: &TraceTable<BaseElement>, column: usize, step: usize) -> u64 {
+    (0..MODULUS_BITS).rev().fold(0, |value, i| {
+        (value << 1) | trace.get(column + i, step).as_int() as u64
+    })
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+use winterfell::math::{
+    fields::{f128, f62, f64},
+    ExtensibleField, FieldElement, StarkField,
+};
+
+// EXAMPLE FIELD
+// ================================================================================================
+
+/// A base field over which the examples can be instantiated.
+///
+/// Besides the requirements placed on a base field by winterfell, the examples need to know how
+/// to build a power map which is a permutation of the field; both the VDF and Rescue rely on
+/// inverting such a map.
+pub trait ExampleField: StarkField + ExtensibleField<2> + ExtensibleField<3> {
+    /// Smallest exponent `alpha` for which `x -> x^alpha` is a permutation of the field; that is,
+    /// the smallest prime which does not divide `MODULUS - 1`.
+    const ALPHA: u32;
+
+    /// Inverse of [ExampleField::ALPHA] modulo `MODULUS - 1`; raising `x^alpha` to this power
+    /// yields `x`.
+    const INV_ALPHA: Self::PositiveInteger;
+}
+
+impl ExampleField for f128::BaseElement {
+    const ALPHA: u32 = 3;
+    const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
+}
+
+impl ExampleField for f64::BaseElement {
+    // MODULUS - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537, so the smallest usable exponent is 7
+    const ALPHA: u32 = 7;
+    const INV_ALPHA: u64 = 10540996611094048183;
+}
+
+impl ExampleField for f62::BaseElement {
+    const ALPHA: u32 = 3;
+    const INV_ALPHA: u64 = 3074416663688030891;
+}
+
+// FIELD SELECTION
+// ================================================================================================
+
+/// Base fields which can be selected on the command line or in a config file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, clap::ValueEnum))]
+#[cfg_attr(feature = "std", serde(rename_all = "lowercase"))]
+pub enum FieldType {
+    /// 128-bit field with modulus 2^128 - 45 * 2^40 + 1
+    #[default]
+    F128,
+    /// 64-bit field with modulus 2^64 - 2^32 + 1
+    F64,
+    /// 62-bit field with modulus 2^62 - 111 * 2^39 + 1
+    F62,
+}
+
+impl FieldType {
+    /// Returns the field with the specified modulus, encoded as little-endian bytes, or `None`
+    /// if none of the supported fields has this modulus.
+    pub fn from_modulus_bytes(modulus_bytes: &[u8]) -> Option<Self> {
+        if modulus_bytes == f128::BaseElement::get_modulus_le_bytes().as_slice() {
+            Some(FieldType::F128)
+        } else if modulus_bytes == f64::BaseElement::get_modulus_le_bytes().as_slice() {
+            Some(FieldType::F64)
+        } else if modulus_bytes == f62::BaseElement::get_modulus_le_bytes().as_slice() {
+            Some(FieldType::F62)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of bits in the modulus of the field.
+    pub fn modulus_bits(&self) -> u32 {
+        match self {
+            FieldType::F128 => f128::BaseElement::MODULUS_BITS,
+            FieldType::F64 => f64::BaseElement::MODULUS_BITS,
+            FieldType::F62 => f62::BaseElement::MODULUS_BITS,
+        }
+    }
+
+    /// Returns the number of bytes in the modulus of the field, encoded as it is in the context
+    /// of a proof.
+    pub fn modulus_bytes(&self) -> usize {
+        match self {
+            FieldType::F128 => f128::BaseElement::get_modulus_le_bytes().len(),
+            FieldType::F64 => f64::BaseElement::get_modulus_le_bytes().len(),
+            FieldType::F62 => f62::BaseElement::get_modulus_le_bytes().len(),
+        }
+    }
+
+    /// Returns the number of bytes in a serialized element of the field.
+    pub fn element_bytes(&self) -> usize {
+        match self {
+            FieldType::F128 => f128::BaseElement::ELEMENT_BYTES,
+            FieldType::F64 => f64::BaseElement::ELEMENT_BYTES,
+            FieldType::F62 => f62::BaseElement::ELEMENT_BYTES,
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldType::F128 => write!(f, "f128"),
+            FieldType::F64 => write!(f, "f64"),
+            FieldType::F62 => write!(f, "f62"),
+        }
+    }
+}
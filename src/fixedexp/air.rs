@@ -0,0 +1,170 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, is_binary, not},
+    claims::{claim_assertions, read_claimed_values, write_claimed_values, ClaimedValue},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; each row holds one bit of an exponent and the power accumulated from the
+/// bits before it.
+pub const BIT: usize = 0;
+pub const ACCUMULATOR: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+/// Columns which claims can name.
+pub const REGISTERS: [(&str, usize); 2] = [("bit", BIT), ("accumulator", ACCUMULATOR)];
+
+/// Number of rows per exponent; the last row of every cycle holds the result and no bit.
+pub const CYCLE_LENGTH: usize = 64;
+
+/// Number of bits in an exponent.
+pub const EXPONENT_BITS: usize = CYCLE_LENGTH - 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The generator, the powers of it which the secret exponents lead to, and the values claimed
+/// for the trace in between.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedExpInputs<B: ExampleField> {
+    pub generator: B,
+    pub results: Vec<B>,
+    pub claims: Vec<ClaimedValue<B>>,
+}
+
+impl<B: ExampleField> Serializable for FixedExpInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.generator);
+        target.write_u32(self.results.len() as u32);
+        target.write(&self.results[..]);
+        write_claimed_values(&self.claims, target);
+    }
+}
+
+impl<B: ExampleField> Deserializable for FixedExpInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let generator = B::read_from(source)?;
+        let num_results = source.read_u32()? as usize;
+        Ok(FixedExpInputs {
+            generator,
+            results: B::read_batch_from(source, num_results)?,
+            claims: read_claimed_values(source, &REGISTERS.map(|(_, column)| column))?,
+        })
+    }
+}
+
+// FIXED-BASE EXPONENTIATION AIR
+// ================================================================================================
+
+/// AIR of a batch of exponentiations of a fixed generator, one per cycle of 64 rows.
+///
+/// Row `i` of a cycle multiplies the accumulator by `g^(2^i)` if bit `i` of the exponent is
+/// one. The powers `g^(2^i)` are public parameters which both the prover and the verifier derive
+/// from the generator, so instead of squaring them in a trace column, which would need its own
+/// constraint, the AIR supplies them as a periodic column that repeats every cycle. A second
+/// periodic column is zero in the last row of every cycle, where the accumulator is reset to one
+/// for the next exponent instead of being multiplied.
+pub struct FixedExpAir<B: ExampleField> {
+    context: AirContext<B>,
+    generator: B,
+    results: Vec<B>,
+    claims: Vec<ClaimedValue<B>>,
+}
+
+impl<B: ExampleField> Air for FixedExpAir<B> {
+    type BaseField = B;
+    type PublicInputs = FixedExpInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: FixedExpInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        assert_eq!(
+            trace_info.length(),
+            pub_inputs.results.len() * CYCLE_LENGTH,
+            "expected one result per cycle"
+        );
+
+        // the multiplication uses both periodic columns
+        let degrees = vec![
+            TransitionConstraintDegree::new(2),
+            TransitionConstraintDegree::with_cycles(2, vec![CYCLE_LENGTH, CYCLE_LENGTH]),
+        ];
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            generator: pub_inputs.generator,
+            results: pub_inputs.results,
+            claims: pub_inputs.claims,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let within_cycle = periodic_values[0];
+        let power = periodic_values[1];
+
+        // the accumulator is multiplied by the power of the row if its bit is one, and starts
+        // over at one in the first row of the next cycle
+        let bit = current[BIT];
+        let factor = bit * (power - E::ONE) + E::ONE;
+        result[0] = is_binary(bit);
+        result[1] = are_equal(
+            next[ACCUMULATOR],
+            within_cycle * current[ACCUMULATOR] * factor + not(within_cycle),
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the first accumulator is one, the last row of every cycle holds its result, and the
+        // claimed values are where they are claimed to be
+        let mut assertions = vec![
+            first_row_equals(ACCUMULATOR, B::ONE),
+            Assertion::sequence(
+                ACCUMULATOR,
+                CYCLE_LENGTH - 1,
+                CYCLE_LENGTH,
+                self.results.clone(),
+            ),
+        ];
+        assertions.append(&mut claim_assertions(&self.claims, self.trace_length()));
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut within_cycle = vec![B::ONE; CYCLE_LENGTH];
+        within_cycle[CYCLE_LENGTH - 1] = B::ZERO;
+        vec![within_cycle, get_power_table(self.generator)]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `g^(2^i)` for every row `i` of a cycle which holds a bit, followed by one for the
+/// last row.
+pub fn get_power_table<B: ExampleField>(generator: B) -> Vec<B> {
+    let mut table = Vec::with_capacity(CYCLE_LENGTH);
+    let mut power = generator;
+    for _ in 0..EXPONENT_BITS {
+        table.push(power);
+        power = power.square();
+    }
+    table.push(B::ONE);
+    table
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "std")]
+use crate::{
+    claims::{self, ClaimedValue},
+    config::FixedExpConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{FixedExpAir, FixedExpInputs, ACCUMULATOR, CYCLE_LENGTH, EXPONENT_BITS, REGISTERS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::FixedExpProver;
+
+#[cfg(test)]
+mod tests;
+
+// FIXED-BASE EXPONENTIATION EXAMPLE
+// ================================================================================================
+
+/// Proves knowledge of a batch of 63-bit exponents `x` such that `g^x` equals each of the public
+/// results, for a fixed public generator `g`.
+///
+/// Unlike the `exp` example, which squares the base in the trace, every power `g^(2^i)` the
+/// computation needs is fixed in advance by the generator, so the AIR supplies them as a
+/// periodic column: a table of public parameters which the verifier interpolates itself instead
+/// of the prover committing to it. One periodic column takes the place of a trace column of
+/// constants, which would need constraints or assertions of its own to pin its values. Each
+/// exponent takes one cycle of 64 rows, in which the table repeats.
+#[cfg(feature = "std")]
+pub struct FixedExpExample<B: ExampleField> {
+    seed: u64,
+    generator: B,
+    exponents: Vec<u64>,
+    results: Vec<B>,
+    claims: Vec<ClaimedValue<B>>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> FixedExpExample<B> {
+    pub fn new(config: &FixedExpConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let generator = B::from(config.generator);
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let exponents = (0..config.num_exponents)
+            .map(|_| rng.gen::<u64>() >> (u64::BITS as usize - EXPONENT_BITS))
+            .collect::<Vec<_>>();
+
+        // compute results
+        let now = Instant::now();
+        let results = exponents
+            .iter()
+            .map(|&exponent| generator.exp(exponent.into()))
+            .collect();
+        info!("Computed results in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            generator,
+            exponents,
+            results,
+            claims: claims::to_claimed_values(&config.claims, &REGISTERS),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &FixedExpConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "number of exponents",
+            "fixedexp.num_exponents",
+            config.num_exponents,
+            1,
+        )?;
+        // every exponent leads to the same power of zero or one
+        if B::from(config.generator) == B::ZERO || B::from(config.generator) == B::ONE {
+            return Err(format!(
+                "generator must be neither zero nor one, but fixedexp.generator is {}",
+                config.generator
+            ));
+        }
+        // the accumulator is asserted in the first row and in the last row of every cycle
+        claims::check_claims(
+            "fixedexp.claims",
+            &config.claims,
+            &REGISTERS,
+            config.num_exponents * CYCLE_LENGTH,
+            |column, step| {
+                column == ACCUMULATOR && (step == 0 || step % CYCLE_LENGTH == CYCLE_LENGTH - 1)
+            },
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for FixedExpExample<B> {
+    type Air = FixedExpAir<B>;
+    type Prover = FixedExpProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = FixedExpProver::build_trace(self.generator, &self.exponents);
+        for (i, &result) in self.results.iter().enumerate() {
+            assert_eq!(result, trace.get(ACCUMULATOR, (i + 1) * CYCLE_LENGTH - 1));
+        }
+        trace
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = FixedExpConfig::default();
+        ExampleDescriptor::new(
+            "fixedexp",
+            "powers of a fixed generator for a batch of secret exponents",
+        )
+        .with_default("generator", config.generator)
+        .with_default("num_exponents", config.num_exponents)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("BIT", 1), ("ACCUMULATOR", 1)],
+            constraints: vec![("binary bit", 1), ("multiply by table power", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> FixedExpProver<B> {
+        FixedExpProver::new(self.generator, self.claims.clone(), options)
+    }
+
+    fn pub_inputs(&self) -> FixedExpInputs<B> {
+        FixedExpInputs {
+            generator: self.generator,
+            results: self.results.clone(),
+            claims: self.claims.clone(),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> FixedExpInputs<B> {
+        let mut results = self.results.clone();
+        results[0] *= self.generator;
+        FixedExpInputs {
+            results,
+            ..self.pub_inputs()
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::{
+    air::{get_power_table, ACCUMULATOR, CYCLE_LENGTH, EXPONENT_BITS, TRACE_WIDTH},
+    FixedExpAir, FixedExpInputs,
+};
+use crate::{claims::ClaimedValue, field::ExampleField};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct FixedExpProver<B: ExampleField> {
+    options: ProofOptions,
+    generator: B,
+    claims: Vec<ClaimedValue<B>>,
+}
+
+impl<B: ExampleField> FixedExpProver<B> {
+    pub fn new(generator: B, claims: Vec<ClaimedValue<B>>, options: ProofOptions) -> Self {
+        Self {
+            options,
+            generator,
+            claims,
+        }
+    }
+
+    /// Builds a trace with one cycle per exponent, in which every row holds one bit of the
+    /// exponent, least significant bit first, and the power of the generator for the bits
+    /// before it.
+    pub fn build_trace(generator: B, exponents: &[u64]) -> TraceTable<B> {
+        let table = get_power_table(generator);
+        let trace_length = exponents.len() * CYCLE_LENGTH;
+        let mut bits = Vec::with_capacity(trace_length);
+        let mut accumulators = Vec::with_capacity(trace_length);
+        for &exponent in exponents {
+            let mut accumulator = B::ONE;
+            for (i, &power) in table.iter().enumerate() {
+                let bit = if i < EXPONENT_BITS {
+                    (exponent >> i) & 1
+                } else {
+                    0
+                };
+                bits.push(B::from(bit));
+                accumulators.push(accumulator);
+                if bit == 1 {
+                    accumulator *= power;
+                }
+            }
+        }
+        TraceTable::init(vec![bits, accumulators])
+    }
+}
+
+impl<B: ExampleField> Prover for FixedExpProver<B> {
+    type BaseField = B;
+    type Air = FixedExpAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FixedExpInputs<B> {
+        assert_eq!(TRACE_WIDTH, trace.width());
+        let results = (CYCLE_LENGTH - 1..trace.length())
+            .step_by(CYCLE_LENGTH)
+            .map(|step| trace.get(ACCUMULATOR, step))
+            .collect();
+        FixedExpInputs {
+            generator: self.generator,
+            results,
+            claims: self.claims.clone(),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,147 @@
+use super::{
+    air::{get_power_table, BIT},
+    FixedExpExample, FixedExpInputs, FixedExpProver,
+};
+use crate::{
+    claims::Claim,
+    config::FixedExpConfig,
+    example::{self, Example},
+    verify::verify_proof,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement, StarkField},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn fixedexp_trace_multiplies_table_powers() {
+    let generator = BaseElement::from(3u8);
+    let table = get_power_table(generator);
+    assert_eq!(BaseElement::from(81u8), table[2]);
+    assert_eq!(BaseElement::ONE, table[63]);
+
+    // 6 = 0b110 multiplies in 3^2 and 3^4, and the next cycle starts over at one
+    let trace = FixedExpProver::build_trace(generator, &[6, 1]);
+    let accumulators = (0..4).map(|step| trace.get(1, step)).collect::<Vec<_>>();
+    assert_eq!(
+        [1u16, 1, 9, 9 * 81].map(BaseElement::from).to_vec(),
+        accumulators
+    );
+    assert_eq!(BaseElement::from(729u16), trace.get(1, 63));
+    assert_eq!(BaseElement::ONE, trace.get(1, 64));
+    assert_eq!(generator, trace.get(1, 127));
+}
+
+#[test]
+fn fixedexp_invalid_settings_are_rejected() {
+    let config = FixedExpConfig {
+        num_exponents: 3,
+        ..FixedExpConfig::default()
+    };
+    let err = FixedExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(
+        err.starts_with("number of exponents must be a power of two, but fixedexp.num_exponents"),
+        "{}",
+        err
+    );
+
+    let config = FixedExpConfig {
+        generator: 1,
+        ..FixedExpConfig::default()
+    };
+    let err = FixedExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(err.contains("fixedexp.generator is 1"), "{}", err);
+}
+
+#[test]
+fn fixedexp_claimed_values_are_asserted() {
+    // a claim on a bit reveals it, so the statement becomes the results together with the
+    // lowest bit of the first exponent; the accumulator starts the second cycle at one
+    let bit = build_example().build_trace().get(BIT, 0).as_int() as u64;
+    let claims = vec![claim("bit", 0, bit), claim("accumulator", 64, 1)];
+    let example = build_claimed_example(claims.clone());
+    let report = example::run(&example, build_options());
+    assert!(report.is_verified());
+    assert_eq!(
+        Ok(()),
+        verify_proof("fixedexp", &report.proof_bytes, &report.pub_inputs_bytes)
+    );
+
+    let false_claims = vec![claim("bit", 0, 1 - bit), claims[1].clone()];
+    let report = example::run(&build_claimed_example(false_claims), build_options());
+    assert!(!report.is_verified());
+}
+
+#[test]
+fn fixedexp_claims_on_results_are_rejected() {
+    // the accumulator is asserted where a cycle ends, but the bit is not
+    let config = FixedExpConfig {
+        num_exponents: 4,
+        claims: vec![claim("bit", 63, 0), claim("accumulator", 127, 1)],
+        ..FixedExpConfig::default()
+    };
+    let err = FixedExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert_eq!(
+        "fixedexp.claims[1] claims accumulator at step 127, which the AIR asserts already",
+        err
+    );
+}
+
+#[test]
+fn fixedexp_proof_under_another_generator_is_rejected() {
+    // the verifier derives the table from the generator, so another generator changes every
+    // power the trace is checked against
+    let example = build_example();
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    let pub_inputs = FixedExpInputs {
+        generator: BaseElement::from(5u8),
+        ..example.pub_inputs()
+    };
+    assert!(
+        winterfell::verify::<<FixedExpExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> FixedExpExample<BaseElement> {
+    FixedExpExample::new(&FixedExpConfig {
+        num_exponents: 4,
+        ..FixedExpConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_claimed_example(claims: Vec<Claim>) -> FixedExpExample<BaseElement> {
+    FixedExpExample::new(&FixedExpConfig {
+        num_exponents: 4,
+        claims,
+        ..FixedExpConfig::default()
+    })
+    .unwrap()
+}
+
+fn claim(register: &str, step: usize, value: u64) -> Claim {
+    Claim {
+        register: register.to_string(),
+        step,
+        value,
+    }
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,268 @@
+//! Gadgets which give a prime field the operations it lacks: is-zero tests, bitwise operations,
+//! comparisons, and arithmetic modulo other numbers; and running sums and Horner evaluations,
+//! which accumulate a column over the whole trace.
+//!
+//! A gadget is a group of witness columns together with the constraints which tie them to the
+//! values they describe. The constraint functions return evaluations which are zero exactly on
+//! valid witnesses, and the witness functions compute what the prover puts in the columns.
+
+use crate::air_utils::and;
+use winter_utils::collections::Vec;
+use winterfell::math::{FieldElement, StarkField};
+
+// IS-ZERO
+// ================================================================================================
+
+/// Returns the two constraints which force `flag` to be one when `x` is zero and zero otherwise,
+/// given a witness `inv`: `x * inv = 1 - flag` and `flag * x = 0`.
+///
+/// If `x` is not zero, the first constraint can only hold with `flag = 0` (and `inv = 1 / x`),
+/// since the second one rules out `flag = 1`; if `x` is zero, it forces `flag = 1`. The flag is
+/// thus binary without a constraint of its own. Both constraints have degree two.
+pub fn is_zero<E: FieldElement>(x: E, inv: E, flag: E) -> [E; 2] {
+    [x * inv - (E::ONE - flag), flag * x]
+}
+
+/// Returns the witness `inv` and the flag of [is_zero()] for `x`.
+pub fn is_zero_witness<B: StarkField>(x: B) -> (B, B) {
+    if x == B::ZERO {
+        (B::ZERO, B::ONE)
+    } else {
+        (x.inv(), B::ZERO)
+    }
+}
+
+// BITWISE OPERATIONS
+// ================================================================================================
+
+/// Returns one when exactly one of the binary values `a` and `b` is one, and zero otherwise.
+pub fn xor<E: FieldElement>(a: E, b: E) -> E {
+    a + b - (a * b).double()
+}
+
+/// Returns the XOR of two words given by their bits, least significant bit first.
+///
+/// The result is only the XOR of the words if every bit is constrained to be binary: on other
+/// values, the polynomial of [xor()] computes something else.
+pub fn xor_word<E: FieldElement>(a_bits: &[E], b_bits: &[E]) -> E {
+    a_bits
+        .iter()
+        .zip(b_bits)
+        .rev()
+        .fold(E::ZERO, |word, (&a, &b)| word.double() + xor(a, b))
+}
+
+/// Returns the AND of two words given by their bits, least significant bit first.
+///
+/// As for [xor_word()], every bit must be constrained to be binary.
+pub fn and_word<E: FieldElement>(a_bits: &[E], b_bits: &[E]) -> E {
+    a_bits
+        .iter()
+        .zip(b_bits)
+        .rev()
+        .fold(E::ZERO, |word, (&a, &b)| word.double() + and(a, b))
+}
+
+/// Returns the `num_bits` least significant bits of `value`, least significant bit first.
+pub fn to_bits<B: StarkField>(value: u64, num_bits: usize) -> impl Iterator<Item = B> {
+    (0..num_bits).map(move |i| B::from((value >> i) & 1))
+}
+
+// LESS-THAN
+// ================================================================================================
+
+/// Returns the difference which must lie in `[0, 2^num_bits)` for `flag` to be one exactly when
+/// `a < b`, where `a` and `b` are known to lie in `[0, 2^num_bits)`: `b - a - 1 + 2^num_bits *
+/// (1 - flag)`.
+///
+/// If `a < b`, then `b - a - 1` lies in the range and `b - a - 1 + 2^num_bits` does not; if
+/// `a >= b`, then `b - a - 1` is negative, which in the field wraps around to a value far above
+/// the range, and adding `2^num_bits` brings it into the range. The difference is range-checked
+/// by decomposing it into `num_bits` binary columns, so that a binary `flag` can only take the
+/// one value for which the difference fits. This holds as long as `2^(num_bits + 1)` is far
+/// below the modulus, so that the difference cannot wrap around into the range. The constraint
+/// is linear, but `a` and `b` must be range-checked as well, typically by their own
+/// decompositions.
+pub fn less_than_difference<E: FieldElement>(a: E, b: E, flag: E, num_bits: usize) -> E {
+    let range = E::from(1u64 << num_bits);
+    b - a - E::ONE + range * (E::ONE - flag)
+}
+
+/// Returns whether `a < b`, and the difference of [less_than_difference()] for `a` and `b`.
+pub fn less_than_witness(a: u64, b: u64, num_bits: usize) -> (bool, u64) {
+    let less = a < b;
+    let range = 1u64 << num_bits;
+    (less, (b + if less { 0 } else { range }) - a - 1)
+}
+
+// MODULAR REDUCTION
+// ================================================================================================
+
+/// Returns the constraint which makes `remainder` the remainder of `value` modulo `modulus`, with
+/// a witness `quotient`: `value = quotient * modulus + remainder`.
+///
+/// The constraint only defines the remainder if the quotient and the remainder are range-checked
+/// as well: the remainder to `[0, modulus)`, which [less_than_difference()] with a flag of one
+/// does, and the quotient to a range small enough that `quotient * modulus + remainder` cannot
+/// wrap around the modulus of the field. The equation then holds over the integers, which
+/// emulates arithmetic modulo `modulus` inside the field. The constraint is linear.
+pub fn reduction<E: FieldElement>(value: E, quotient: E, remainder: E, modulus: E) -> E {
+    value - (quotient * modulus + remainder)
+}
+
+/// Returns the quotient and the remainder of [reduction()] for `value` and `modulus`.
+pub fn reduction_witness(value: u64, modulus: u64) -> (u64, u64) {
+    (value / modulus, value % modulus)
+}
+
+// WRAPPING ADDITION
+// ================================================================================================
+
+/// Returns the constraint which makes `next_value` the sum of `value` and `increment` modulo
+/// `2^num_bits`, with an overflow flag `flag`: `next_value = value + increment - 2^num_bits *
+/// flag`.
+///
+/// This is [reduction()] of `value + increment` modulo `2^num_bits` with the quotient in place
+/// of the flag: a sum of two numbers below `2^num_bits` is below `2^(num_bits + 1)`, so the
+/// quotient is a single bit, which is one exactly when the addition wraps around. The constraint
+/// only defines the flag if `flag` is constrained to be binary and `next_value` is range-checked
+/// to `[0, 2^num_bits)`, typically by its bits; of the two values of the flag, only one then
+/// leaves `next_value` in the range. `value` and `increment` must lie in the range as well,
+/// which the constraint on the previous step does for a counter which starts in it. The
+/// constraint is linear.
+pub fn wrapping_add<E: FieldElement>(
+    value: E,
+    increment: E,
+    next_value: E,
+    flag: E,
+    num_bits: usize,
+) -> E {
+    reduction(
+        value + increment,
+        flag,
+        next_value,
+        E::from(1u64 << num_bits),
+    )
+}
+
+/// Returns the sum of [wrapping_add()] for `value` and `increment`, and whether it overflowed.
+pub fn wrapping_add_witness(value: u64, increment: u64, num_bits: usize) -> (u64, bool) {
+    let (flag, sum) = reduction_witness(value + increment, 1 << num_bits);
+    (sum, flag == 1)
+}
+
+// LIMB MULTIPLICATION
+// ================================================================================================
+
+/// Returns the two constraints which make `r` the remainder of `a * b` modulo `n`, for numbers
+/// of two limbs of `limb_bits` bits, least significant limb first, with witnesses `q`, the
+/// quotient in limbs as well, and `carry`, the signed carry out of the low limb.
+///
+/// A product of numbers about half as wide as the field wraps around its modulus, so unlike
+/// [reduction()], the equation `a * b = q * n + r` is split by powers of `w = 2^limb_bits` into
+/// `d_0 = a_0 b_0 - q_0 n_0 - r_0`, `d_1 = a_0 b_1 + a_1 b_0 - q_0 n_1 - q_1 n_0 - r_1`, and
+/// `d_2 = a_1 b_1 - q_1 n_1`, none of which comes near the modulus of the field. The equation
+/// holds over the integers exactly when `d_0 + d_1 w + d_2 w^2 = 0`, that is when
+/// `d_0 = carry * w` and `d_1 + carry + d_2 w = 0` for an integer `carry`, which lies in
+/// `[-w, w)`. The constraints are checked in the field, so they only imply the equation if every
+/// limb is range-checked to `[0, w)` and `carry + w` to `[0, 2w)`, typically by bit
+/// decompositions, and if `w^3` is far below the modulus of the field; the evaluations are then
+/// too small to wrap around. The remainder is only the canonical one if it is also checked to be
+/// below `n`, which [less_than_difference()] does. Both constraints have degree two.
+pub fn limb_product<E: FieldElement>(
+    a: [E; 2],
+    b: [E; 2],
+    q: [E; 2],
+    r: [E; 2],
+    n: [E; 2],
+    carry: E,
+    limb_bits: usize,
+) -> [E; 2] {
+    let w = E::from(1u64 << limb_bits);
+    let low = a[0] * b[0] - q[0] * n[0] - r[0];
+    let middle = a[0] * b[1] + a[1] * b[0] - q[0] * n[1] - q[1] * n[0] - r[1];
+    let high = a[1] * b[1] - q[1] * n[1];
+    [low - carry * w, middle + carry + high * w]
+}
+
+/// Returns the quotient and the remainder of [limb_product()] for `a * b` modulo `n`, and the
+/// carry out of the low limb; `a`, `b`, and `n` must have no more than `2 * limb_bits` bits.
+pub fn limb_product_witness(a: u64, b: u64, n: u64, limb_bits: usize) -> (u64, u64, i64) {
+    let (quotient, remainder) = reduction_witness(a * b, n);
+    let [a, b, q, r, n] = [a, b, quotient, remainder, n].map(|x| to_limbs(x, limb_bits)[0] as i64);
+    let low = a * b - q * n - r;
+    (quotient, remainder, low >> limb_bits)
+}
+
+/// Returns the two limbs of `limb_bits` bits of `value`, least significant limb first.
+pub fn to_limbs(value: u64, limb_bits: usize) -> [u64; 2] {
+    [value & ((1 << limb_bits) - 1), value >> limb_bits]
+}
+
+// RUNNING SUMS
+// ================================================================================================
+
+/// Returns the two constraints which make `sum` the running sum of a column of terms, given the
+/// value `is_first` of a periodic column which is one on the first row and zero elsewhere:
+/// `is_first * (sum - term) = 0` and `next_sum = sum + next_term`.
+///
+/// The second constraint adds every term but the first to the sum, since the terms it reads are
+/// those of the next row; the first term is only tied to the sum by the first constraint, which
+/// the flag switches off on every other row. A boundary assertion cannot take its place when the
+/// first term is private, since assertions fix cells to public values. Both constraints have
+/// degree one in the trace, the first in addition to the degree of the periodic column.
+pub fn running_sum<E: FieldElement>(
+    sum: E,
+    term: E,
+    next_sum: E,
+    next_term: E,
+    is_first: E,
+) -> [E; 2] {
+    horner(sum, term, next_sum, next_term, E::ONE, is_first)
+}
+
+/// Returns the running sums of the terms for [running_sum()]; the last one is the sum of all
+/// terms.
+pub fn running_sum_witness<B: StarkField>(terms: &[B]) -> Vec<B> {
+    horner_witness(terms, B::ONE)
+}
+
+/// Returns the two constraints which make `acc` evaluate a column of coefficients, leading
+/// coefficient first, at the point `x` by Horner's rule: `is_first * (acc - coeff) = 0` and
+/// `next_acc = acc * x + next_coeff`.
+///
+/// This is the running sum of [running_sum()] with the sum multiplied by `x` before every term
+/// is added, so that after `n` rows `acc` holds the value at `x` of the polynomial with the
+/// first `n` coefficients. The first row is tied to its coefficient in the same way. With a
+/// public point both constraints have degree one in the trace; a point held in a column of the
+/// trace would make the second one quadratic.
+pub fn horner<E: FieldElement>(
+    acc: E,
+    coeff: E,
+    next_acc: E,
+    next_coeff: E,
+    x: E,
+    is_first: E,
+) -> [E; 2] {
+    [is_first * (acc - coeff), next_acc - (acc * x + next_coeff)]
+}
+
+/// Returns the accumulators of [horner()] for the coefficients at the point `x`; the last one is
+/// the value of the whole polynomial at `x`.
+pub fn horner_witness<B: StarkField>(coeffs: &[B], x: B) -> Vec<B> {
+    coeffs
+        .iter()
+        .scan(B::ZERO, |acc, &coeff| {
+            *acc = *acc * x + coeff;
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// Returns the values of the periodic column of [running_sum()] and [horner()] for a trace of
+/// `trace_length` rows: one on the first row and zero on the others.
+pub fn first_row_flags<B: StarkField>(trace_length: usize) -> Vec<B> {
+    let mut flags = vec![B::ZERO; trace_length];
+    flags[0] = B::ONE;
+    flags
+}
@@ -0,0 +1,160 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, not,
+        transition_degrees, EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in the inputs; the product of two such numbers plus a third is far below
+/// the modulus of every supported field, so the division constraint holds over the integers.
+pub const NUM_BITS: usize = 16;
+
+/// Trace column layout. `DIVIDEND` and `DIVISOR` hold the pair `(a, b)` the algorithm works
+/// on, `QUOTIENT` the quotient `q` of the current division, and `DONE` is one once `b` has
+/// reached zero. The bit columns hold the binary decompositions of `b`, of `q`, and of the
+/// slack `b - 1 - r` between the divisor and the remainder `r`, which becomes `b` of the next
+/// row.
+pub const DIVIDEND: usize = 0;
+pub const DIVISOR: usize = 1;
+pub const QUOTIENT: usize = 2;
+pub const DONE: usize = 3;
+pub const DIVISOR_BITS: usize = 4;
+pub const QUOTIENT_BITS: usize = DIVISOR_BITS + NUM_BITS;
+pub const SLACK_BITS: usize = QUOTIENT_BITS + NUM_BITS;
+pub const TRACE_WIDTH: usize = SLACK_BITS + NUM_BITS;
+
+/// Number of transition constraints other than the ones keeping the bit columns binary.
+const NUM_STEP_CONSTRAINTS: usize = 9;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct GcdInputs<B: ExampleField> {
+    pub a: B,
+    pub b: B,
+    pub gcd: B,
+}
+
+impl<B: ExampleField> Serializable for GcdInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.a);
+        target.write(self.b);
+        target.write(self.gcd);
+    }
+}
+
+impl<B: ExampleField> Deserializable for GcdInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(GcdInputs {
+            a: B::read_from(source)?,
+            b: B::read_from(source)?,
+            gcd: B::read_from(source)?,
+        })
+    }
+}
+
+// GCD AIR
+// ================================================================================================
+
+/// AIR of the Euclidean algorithm, which replaces `(a, b)` with `(b, a mod b)` until `b` is
+/// zero.
+///
+/// The number of iterations depends on the inputs, while the trace length must be a power of
+/// two, so the trace is padded with copies of the final state. The `DONE` column marks the
+/// rows in which `b` is zero: division constraints are enforced only while it is zero, and
+/// constraints which freeze the state are enforced once it is one. Besides the boundary
+/// assertion that `b` is zero in the last row, nothing ties the flag to completion directly;
+/// the flag cannot be raised early because it forces `b` to zero, and it cannot stay zero once
+/// `b` is zero because dividing by zero would leave a remainder of `-1 - slack`, which is not a
+/// 16-bit number.
+pub struct GcdAir<B: ExampleField> {
+    context: AirContext<B>,
+    a: B,
+    b: B,
+    gcd: B,
+}
+
+impl<B: ExampleField> Air for GcdAir<B> {
+    type BaseField = B;
+    type PublicInputs = GcdInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: GcdInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = transition_degrees(&[2, 2, 2, 3, 2, 2, 1, 2, 2]);
+        degrees.append(&mut transition_degrees(&[2; 3 * NUM_BITS]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            a: pub_inputs.a,
+            b: pub_inputs.b,
+            gcd: pub_inputs.gcd,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let done = current[DONE];
+        let active = not(done);
+
+        // the flag is binary, and can only be set when b is zero
+        result[0] = is_binary(done);
+        result[1] = done * current[DIVISOR];
+
+        // while the algorithm runs, a = q * b + r with 0 <= r < b, and the next pair is (b, r);
+        // the bounds on r follow from the decompositions of r, which is b of the next row, and
+        // of the slack b - 1 - r
+        result[2] = active * are_equal(next[DIVIDEND], current[DIVISOR]);
+        result[3] = active
+            * are_equal(
+                current[DIVIDEND],
+                current[QUOTIENT] * current[DIVISOR] + next[DIVISOR],
+            );
+        let slack = combine_bits(&current[SLACK_BITS..SLACK_BITS + NUM_BITS]);
+        result[4] = active * are_equal(current[DIVISOR] - E::ONE - next[DIVISOR], slack);
+        let quotient = combine_bits(&current[QUOTIENT_BITS..QUOTIENT_BITS + NUM_BITS]);
+        result[5] = active * are_equal(current[QUOTIENT], quotient);
+        result[6] = are_equal(
+            current[DIVISOR],
+            combine_bits(&current[DIVISOR_BITS..DIVISOR_BITS + NUM_BITS]),
+        );
+
+        // once the algorithm is done, the state is copied into every remaining row
+        result[7] = done * are_equal(next[DIVIDEND], current[DIVIDEND]);
+        result[8] = done * next[DIVISOR];
+
+        result.enforce_binary(
+            NUM_STEP_CONSTRAINTS,
+            &current[DIVISOR_BITS..DIVISOR_BITS + 3 * NUM_BITS],
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the algorithm starts with the inputs and must be done by the last step
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(DIVIDEND, self.a),
+            first_row_equals(DIVISOR, self.b),
+            last_row_equals(DIVIDEND, trace_length, self.gcd),
+            last_row_equals(DIVISOR, trace_length, B::ZERO),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::GcdConfig,
+    describe::AirMetadata,
+    emulator::{EmulatedColumn, Emulator},
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{GcdAir, GcdInputs, DIVIDEND, DIVISOR, DONE, NUM_BITS, QUOTIENT};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::GcdProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Smallest trace length supported by winterfell.
+const MIN_TRACE_LENGTH: usize = 8;
+
+// GCD EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the greatest common divisor of two public 16-bit integers.
+///
+/// The trace runs the Euclidean algorithm, whose number of iterations depends on the inputs;
+/// it is padded to the next power of two with copies of the final state, and a flag column
+/// tells the AIR which rows belong to the computation and which ones are padding. The trace
+/// length, and thus the size of the proof, reveals roughly how many iterations were needed.
+#[cfg(feature = "std")]
+pub struct GcdExample<B: ExampleField> {
+    a: u16,
+    b: u16,
+    gcd: B,
+    trace_length: usize,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> GcdExample<B> {
+    pub fn new(config: &GcdConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // compute result
+        let now = Instant::now();
+        let (gcd, num_iterations) = euclid(config.a, config.b);
+        info!(
+            "Computed result in {} ms after {} iterations",
+            now.elapsed().as_millis(),
+            num_iterations
+        );
+
+        Ok(Self {
+            a: config.a,
+            b: config.b,
+            gcd: B::from(gcd),
+            trace_length: get_trace_length(num_iterations),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &GcdConfig) -> Result<(), String> {
+        // with b = 0 every row of the trace would be the same, and winterfell cannot prove
+        // traces whose constraint composition polynomial is zero
+        if config.b == 0 {
+            return Err(
+                "second input must not be zero, but gcd.b is 0; swap the inputs or \
+                 set gcd.b to a positive integer"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for GcdExample<B> {
+    type Air = GcdAir<B>;
+    type Prover = GcdProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        GcdProver::build_trace(self.a, self.b, self.trace_length)
+    }
+
+    fn emulator(&self) -> Option<Box<dyn Emulator<B> + '_>> {
+        Some(Box::new(GcdEmulator {
+            a: self.a,
+            b: self.b,
+        }))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = GcdConfig::default();
+        ExampleDescriptor::new("gcd", "the greatest common divisor of two 16-bit integers")
+            .with_default("a", config.a)
+            .with_default("b", config.b)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("DIVIDEND", 1),
+                ("DIVISOR", 1),
+                ("QUOTIENT", 1),
+                ("DONE", 1),
+                ("DIVISOR_BITS", NUM_BITS),
+                ("QUOTIENT_BITS", NUM_BITS),
+                ("SLACK_BITS", NUM_BITS),
+            ],
+            constraints: vec![
+                ("binary done flag", 1),
+                ("done only at zero", 1),
+                ("divisor shift", 1),
+                ("division", 1),
+                ("remainder below divisor", 1),
+                ("quotient bits", 1),
+                ("divisor bits", 1),
+                ("frozen state", 2),
+                ("binary bits", 3 * NUM_BITS),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> GcdProver<B> {
+        GcdProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> GcdInputs<B> {
+        GcdInputs {
+            a: B::from(self.a),
+            b: B::from(self.b),
+            gcd: self.gcd,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> GcdInputs<B> {
+        GcdInputs {
+            gcd: self.gcd + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// EMULATOR
+// ================================================================================================
+
+/// Runs the Euclidean algorithm on integers and predicts the division performed in every row
+/// of the trace; once the algorithm is done, its state no longer changes.
+#[cfg(feature = "std")]
+struct GcdEmulator {
+    a: u16,
+    b: u16,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Emulator<B> for GcdEmulator {
+    fn columns(&self) -> Vec<EmulatedColumn> {
+        vec![
+            EmulatedColumn {
+                name: "dividend",
+                index: DIVIDEND,
+            },
+            EmulatedColumn {
+                name: "divisor",
+                index: DIVISOR,
+            },
+            EmulatedColumn {
+                name: "quotient",
+                index: QUOTIENT,
+            },
+            EmulatedColumn {
+                name: "done",
+                index: DONE,
+            },
+        ]
+    }
+
+    fn state(&self) -> Vec<B> {
+        let quotient = self.a.checked_div(self.b).unwrap_or(0);
+        vec![
+            B::from(self.a),
+            B::from(self.b),
+            B::from(quotient),
+            B::from((self.b == 0) as u8),
+        ]
+    }
+
+    fn step(&mut self) {
+        if self.b != 0 {
+            (self.a, self.b) = (self.b, self.a % self.b);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the greatest common divisor of `a` and `b`, and the number of iterations of the
+/// Euclidean algorithm it took to compute it.
+fn euclid(mut a: u16, mut b: u16) -> (u16, usize) {
+    let mut num_iterations = 0;
+    while b != 0 {
+        (a, b) = (b, a % b);
+        num_iterations += 1;
+    }
+    (a, num_iterations)
+}
+
+/// Returns the length of the trace of the computation of `gcd(a, b)`.
+pub(crate) fn trace_length(a: u16, b: u16) -> usize {
+    get_trace_length(euclid(a, b).1)
+}
+
+/// Returns the length of a trace which holds a row for every iteration and a row for the
+/// final state.
+fn get_trace_length(num_iterations: usize) -> usize {
+    (num_iterations + 1)
+        .next_power_of_two()
+        .max(MIN_TRACE_LENGTH)
+}
@@ -0,0 +1,96 @@
+use super::{
+    air::{
+        DIVIDEND, DIVISOR, DIVISOR_BITS, DONE, NUM_BITS, QUOTIENT, QUOTIENT_BITS, SLACK_BITS,
+        TRACE_WIDTH,
+    },
+    GcdAir, GcdInputs,
+};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct GcdProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> GcdProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace with a row per iteration of the Euclidean algorithm, followed by the
+    /// final state of the algorithm repeated up to `trace_length` rows.
+    ///
+    /// # Panics
+    /// Panics if the algorithm takes more than `trace_length - 1` iterations.
+    pub fn build_trace(a: u16, b: u16, trace_length: usize) -> TraceTable<B> {
+        let mut columns = (0..TRACE_WIDTH)
+            .map(|_| Vec::with_capacity(trace_length))
+            .collect::<Vec<_>>();
+        let (mut a, mut b) = (a, b);
+        for _ in 0..trace_length {
+            let (q, slack) = match a.checked_div(b) {
+                Some(q) => (q, b - 1 - a % b),
+                None => (0, 0),
+            };
+            let mut row = [B::ZERO; TRACE_WIDTH];
+            row[DIVIDEND] = B::from(a);
+            row[DIVISOR] = B::from(b);
+            row[QUOTIENT] = B::from(q);
+            row[DONE] = B::from((b == 0) as u8);
+            write_bits(&mut row[DIVISOR_BITS..DIVISOR_BITS + NUM_BITS], b);
+            write_bits(&mut row[QUOTIENT_BITS..QUOTIENT_BITS + NUM_BITS], q);
+            write_bits(&mut row[SLACK_BITS..SLACK_BITS + NUM_BITS], slack);
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+
+            if b != 0 {
+                (a, b) = (b, a % b);
+            }
+        }
+        assert_eq!(
+            B::ZERO,
+            columns[DIVISOR][trace_length - 1],
+            "the algorithm does not complete within the trace"
+        );
+
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for GcdProver<B> {
+    type BaseField = B;
+    type Air = GcdAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> GcdInputs<B> {
+        let last_step = trace.length() - 1;
+        GcdInputs {
+            a: trace.get(DIVIDEND, 0),
+            b: trace.get(DIVISOR, 0),
+            gcd: trace.get(DIVIDEND, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the binary decomposition of `value` into `bits`, least significant bit first.
+fn write_bits<B: ExampleField>(bits: &mut [B], value: u16) {
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = B::from((value >> i) & 1);
+    }
+}
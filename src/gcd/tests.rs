@@ -0,0 +1,108 @@
+use super::{
+    air::{DIVIDEND, DIVISOR, DONE},
+    euclid, get_trace_length, GcdExample, GcdProver,
+};
+use crate::{config::GcdConfig, emulator, example::Example, tamper};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn gcd_trace_length_depends_on_inputs() {
+    // consecutive Fibonacci numbers take the most iterations
+    assert_eq!((1, 22), euclid(46368, 28657));
+    assert_eq!((4, 2), euclid(12, 8));
+    assert_eq!(32, get_trace_length(22));
+    assert_eq!(8, get_trace_length(2));
+
+    for (a, b) in [(12, 8), (8, 12), (0, 7), (65535, 65534)] {
+        let example = GcdExample::<BaseElement>::new(&GcdConfig {
+            a,
+            b,
+            ..GcdConfig::default()
+        })
+        .unwrap();
+        let proof = example
+            .prover(build_options())
+            .prove(example.build_trace())
+            .unwrap();
+        assert!(
+            winterfell::verify::<<GcdExample<BaseElement> as Example>::Air>(
+                proof,
+                example.pub_inputs()
+            )
+            .is_ok(),
+            "gcd({}, {})",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn gcd_trace_freezes_after_completion() {
+    let trace = GcdProver::<BaseElement>::build_trace(46368, 28657, 32);
+    for step in 0..22 {
+        assert_eq!(BaseElement::ZERO, trace.get(DONE, step));
+    }
+    for step in 22..32 {
+        assert_eq!(BaseElement::ONE, trace.get(DONE, step));
+        assert_eq!(BaseElement::ONE, trace.get(DIVIDEND, step));
+        assert_eq!(BaseElement::ZERO, trace.get(DIVISOR, step));
+    }
+}
+
+#[test]
+fn gcd_early_completion_is_rejected() {
+    // raising the flag before b reaches zero would let the prover freeze an intermediate pair
+    // and claim its first element as the result
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let step = 20;
+    let (a, b) = (trace.get(DIVIDEND, step), trace.get(DIVISOR, step));
+    for step in step..trace.length() {
+        trace.set(DIVIDEND, step, a);
+        trace.set(DIVISOR, step, b);
+        trace.set(DONE, step, BaseElement::ONE);
+    }
+    let mut pub_inputs = example.pub_inputs();
+    pub_inputs.gcd = a;
+    let proof = example.prover(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<GcdExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_err()
+    );
+}
+
+#[test]
+fn gcd_trace_matches_emulator() {
+    let check = emulator::check_trace(&build_example()).unwrap();
+    assert!(check.is_match(), "{}", check);
+    assert_eq!(4, check.columns.len());
+}
+
+#[test]
+fn gcd_corrupted_padding_row_is_rejected() {
+    let example = build_example();
+    let verdict = tamper::corrupt_trace_cell(&example, build_options(), DIVIDEND, 28);
+    assert!(verdict.is_rejected(), "{}", verdict);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> GcdExample<BaseElement> {
+    GcdExample::new(&GcdConfig::default()).unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
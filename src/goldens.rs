@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, env, fs, path::PathBuf, sync::Mutex};
+use winterfell::crypto::{hashers::Blake3_256, Digest, Hasher};
+use winterfell::math::fields::f128::BaseElement;
+
+// CONSTANTS
+// ================================================================================================
+
+/// File with the golden values, relative to the root of the crate.
+const GOLDENS_FILE: &str = "goldens.toml";
+
+/// Environment variable which makes the tests record proofs instead of checking them.
+const UPDATE_VARIABLE: &str = "UPDATE_GOLDENS";
+
+/// Comment written at the top of the goldens file when it is regenerated.
+const HEADER: &str = "\
+# Sizes and Blake3 digests of the proofs generated by the golden tests. Changing the execution
+# trace, the constraints, the proof options of a test, or the winterfell version changes them.
+# Regenerate with `UPDATE_GOLDENS=1 cargo test`.
+
+";
+
+/// Serializes updates of the goldens file by tests running in parallel.
+static GOLDENS_LOCK: Mutex<()> = Mutex::new(());
+
+// GOLDEN PROOFS
+// ================================================================================================
+
+/// Size and digest of a proof with fixed inputs and proof options.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Golden {
+    proof_size: usize,
+    proof_digest: String,
+}
+
+/// Checks that the proof matches the golden proof recorded under the specified name.
+///
+/// When the `UPDATE_GOLDENS` environment variable is set, the proof is recorded as the new
+/// golden proof instead.
+pub fn check_golden(name: &str, proof_bytes: &[u8]) {
+    let actual = Golden {
+        proof_size: proof_bytes.len(),
+        proof_digest: hex(&Blake3_256::<BaseElement>::hash(proof_bytes).as_bytes()),
+    };
+
+    let _lock = GOLDENS_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let mut goldens = read_goldens();
+    if env::var_os(UPDATE_VARIABLE).is_some() {
+        goldens.insert(name.to_string(), actual);
+        let source = toml::to_string(&goldens).unwrap();
+        fs::write(goldens_path(), format!("{}{}", HEADER, source)).unwrap();
+        return;
+    }
+
+    match goldens.get(name) {
+        Some(expected) => assert_eq!(
+            expected, &actual,
+            "proof '{}' differs from its golden value; if the change is intended, rerun the \
+            tests with {}=1",
+            name, UPDATE_VARIABLE
+        ),
+        None => panic!(
+            "no golden value for proof '{}'; run the tests with {}=1 to record it",
+            name, UPDATE_VARIABLE
+        ),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn goldens_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(GOLDENS_FILE)
+}
+
+fn read_goldens() -> BTreeMap<String, Golden> {
+    match fs::read_to_string(goldens_path()) {
+        Ok(source) => toml::from_str(&source).unwrap(),
+        Err(_) => BTreeMap::new(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// TESTS
+// ================================================================================================
+
+mod tests {
+    use super::check_golden;
+    use crate::{run_example, test_utils::example_config, EXAMPLES};
+
+    #[test]
+    fn proof_of_every_example_matches_golden() {
+        for &name in EXAMPLES.iter() {
+            let report = run_example(&example_config(name)).unwrap();
+            check_golden(name, &report.proof_bytes);
+        }
+    }
+}
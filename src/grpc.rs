@@ -0,0 +1,469 @@
+//! gRPC service which proves and verifies the examples, so that they can be driven from other
+//! languages.
+//!
+//! The service is defined in `proto/stark_examples.proto`, with a settings message per example;
+//! requests are handled like the requests of the HTTP service in [serve](crate::serve), except
+//! that proofs and public inputs are carried as bytes rather than hex strings.
+
+use crate::{
+    config::{FieldExt, HashFn, ProofOptionsConfig},
+    error::ExampleError,
+    example::RunReport,
+    field::FieldType,
+    presets::Preset,
+    serve::{self, ProveRequest},
+};
+use log::info;
+use serde_json::{Map, Value};
+use std::{net::SocketAddr, time::Duration};
+use tonic::{Request, Response, Status};
+
+/// Messages and service generated from `proto/stark_examples.proto`.
+pub mod proto {
+    tonic::include_proto!("stark_examples");
+}
+
+use proto::{
+    prove_request::Example as Settings,
+    stark_examples_server::{StarkExamples, StarkExamplesServer},
+};
+
+// SERVICE
+// ================================================================================================
+
+/// Serves the gRPC service at `addr` until the process is stopped.
+///
+/// Returns an [Io](ExampleError::Io) error if the address cannot be bound.
+pub fn serve(addr: SocketAddr, timeout: Duration) -> Result<(), ExampleError> {
+    let runtime =
+        tokio::runtime::Runtime::new().map_err(|err| serve::server_error("start", addr, err))?;
+    runtime.block_on(async {
+        info!("Listening on {}", addr);
+        tonic::transport::Server::builder()
+            .add_service(StarkExamplesServer::new(ExampleService::new(timeout)))
+            .serve(addr)
+            .await
+            .map_err(|err| serve::server_error("run", addr, err))
+    })
+}
+
+/// Implementation of the `StarkExamples` service; requests which take longer than the timeout
+/// fail with `DEADLINE_EXCEEDED`.
+#[derive(Debug, Clone)]
+pub struct ExampleService {
+    timeout: Duration,
+}
+
+impl ExampleService {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+}
+
+#[tonic::async_trait]
+impl StarkExamples for ExampleService {
+    async fn prove(
+        &self,
+        request: Request<proto::ProveRequest>,
+    ) -> Result<Response<proto::ProveResponse>, Status> {
+        let (example, request) =
+            prove_request(request.into_inner()).map_err(Status::invalid_argument)?;
+        let report = with_timeout(self.timeout, move || serve::prove_report(example, request))
+            .await?
+            .map_err(Status::invalid_argument)?;
+        Ok(Response::new(proto::ProveResponse {
+            report: Some(report_message(&report)),
+            proof: report.proof_bytes,
+            pub_inputs: report.pub_inputs_bytes,
+        }))
+    }
+
+    async fn verify(
+        &self,
+        request: Request<proto::VerifyRequest>,
+    ) -> Result<Response<proto::VerifyResponse>, Status> {
+        let request = request.into_inner();
+        let response = with_timeout(self.timeout, move || {
+            serve::verify_bytes(&request.example, &request.proof, &request.pub_inputs)
+        })
+        .await?;
+        Ok(Response::new(proto::VerifyResponse {
+            verified: response.verified,
+            error: response.error.unwrap_or_default(),
+        }))
+    }
+}
+
+/// Runs `f` on a blocking thread, and fails if it does not return within `timeout`.
+async fn with_timeout<T: Send + 'static, F: FnOnce() -> T + Send + 'static>(
+    timeout: Duration,
+    f: F,
+) -> Result<T, Status> {
+    match tokio::time::timeout(timeout, tokio::task::spawn_blocking(f)).await {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(err)) => Err(Status::internal(err.to_string())),
+        Err(_) => Err(Status::deadline_exceeded(format!(
+            "request did not finish within {} s",
+            timeout.as_secs_f64()
+        ))),
+    }
+}
+
+// MESSAGE CONVERSIONS
+// ================================================================================================
+
+/// Returns the name of the example in a prove request, and the request in the form taken by the
+/// HTTP service.
+fn prove_request(request: proto::ProveRequest) -> Result<(&'static str, ProveRequest), String> {
+    let field = match request.field() {
+        proto::Field::Unspecified => None,
+        proto::Field::F128 => Some(FieldType::F128),
+        proto::Field::F64 => Some(FieldType::F64),
+        proto::Field::F62 => Some(FieldType::F62),
+    };
+    let preset = match request.preset() {
+        proto::Preset::Unspecified => None,
+        proto::Preset::Fast => Some(Preset::Fast),
+        proto::Preset::Balanced => Some(Preset::Balanced),
+        proto::Preset::Secure => Some(Preset::Secure),
+    };
+    let options = request.options.map(proof_options).unwrap_or_default();
+    let example = request.example.ok_or("request does not name an example")?;
+    let (example, settings) = example_settings(example);
+    Ok((
+        example,
+        ProveRequest {
+            field,
+            preset,
+            options,
+            settings: settings.0,
+        },
+    ))
+}
+
+fn proof_options(options: proto::ProofOptions) -> ProofOptionsConfig {
+    let hash_fn = match options.hash_fn() {
+        proto::HashFunction::Unspecified => None,
+        proto::HashFunction::Blake3192 => Some(HashFn::Blake3_192),
+        proto::HashFunction::Blake3256 => Some(HashFn::Blake3_256),
+        proto::HashFunction::Sha3256 => Some(HashFn::Sha3_256),
+    };
+    let field_extension = match options.field_extension() {
+        proto::FieldExtension::Unspecified => None,
+        proto::FieldExtension::None => Some(FieldExt::None),
+        proto::FieldExtension::Quadratic => Some(FieldExt::Quadratic),
+        proto::FieldExtension::Cubic => Some(FieldExt::Cubic),
+    };
+    ProofOptionsConfig {
+        num_queries: options.num_queries.map(|value| value as usize),
+        blowup_factor: options.blowup_factor.map(|value| value as usize),
+        grinding_factor: options.grinding_factor,
+        hash_fn,
+        field_extension,
+        fri_folding_factor: options.fri_folding_factor.map(|value| value as usize),
+        fri_max_remainder_size: options.fri_max_remainder_size.map(|value| value as usize),
+    }
+}
+
+/// Returns the name of the example and the settings which are set in its message, keyed as in
+/// config files.
+fn example_settings(example: Settings) -> (&'static str, SettingsMap) {
+    let settings = SettingsMap(Map::new());
+    match example {
+        Settings::Vdf(s) => (
+            "vdf",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed),
+        ),
+        Settings::Rescue(s) => (
+            "rescue",
+            settings
+                .optional("chain_length", s.chain_length)
+                .repeated("seed", s.seed),
+        ),
+        Settings::Poseidon(s) => (
+            "poseidon",
+            settings
+                .optional("chain_length", s.chain_length)
+                .repeated("seed", s.seed),
+        ),
+        Settings::Counter(s) => (
+            "counter",
+            settings
+                .optional("num_blocks", s.num_blocks)
+                .optional("seed", s.seed),
+        ),
+        Settings::Lamport(s) => (
+            "lamport",
+            settings
+                .optional("num_signatures", s.num_signatures)
+                .optional("seed", s.seed),
+        ),
+        Settings::Exp(s) => (
+            "exp",
+            settings
+                .optional("base", s.base)
+                .optional("exponent", s.exponent),
+        ),
+        Settings::Sum(s) => (
+            "sum",
+            settings
+                .optional("length", s.length)
+                .optional("seed", s.seed)
+                .optional("dot_product", s.dot_product)
+                .repeated("values", s.values)
+                .repeated("weights", s.weights),
+        ),
+        Settings::Vm(s) => (
+            "vm",
+            settings
+                .repeated("program", s.program)
+                .repeated("registers", s.registers)
+                .optional("trace_length", s.trace_length),
+        ),
+        Settings::Fib(s) => (
+            "fib",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed)
+                .optional("num_terms", s.num_terms),
+        ),
+        Settings::Gcd(s) => ("gcd", settings.optional("a", s.a).optional("b", s.b)),
+        Settings::Quad(s) => (
+            "quad",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("start", s.start)
+                .optional("constant", s.constant),
+        ),
+        Settings::Iszero(s) => (
+            "iszero",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("start", s.start),
+        ),
+        Settings::Rom(s) => (
+            "rom",
+            settings
+                .optional("trace_length", s.trace_length)
+                .repeated("table", s.table)
+                .optional("seed", s.seed),
+        ),
+        Settings::Sqrt(s) => (
+            "sqrt",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("value", s.value),
+        ),
+        Settings::Merkle(s) => (
+            "merkle",
+            settings
+                .optional("num_leaves", s.num_leaves)
+                .optional("num_steps", s.num_steps)
+                .optional("seed", s.seed),
+        ),
+        Settings::Bitwise(s) => (
+            "bitwise",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed),
+        ),
+        Settings::Compare(s) => (
+            "compare",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed),
+        ),
+        Settings::Fibmod(s) => (
+            "fibmod",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("modulus", s.modulus),
+        ),
+        Settings::Sort(s) => (
+            "sort",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed),
+        ),
+        Settings::Fibchunk(s) => (
+            "fibchunk",
+            settings
+                .optional("trace_length", s.trace_length)
+                .repeated("start", s.start),
+        ),
+        Settings::Fixedexp(s) => (
+            "fixedexp",
+            settings
+                .optional("generator", s.generator)
+                .optional("num_exponents", s.num_exponents)
+                .optional("seed", s.seed),
+        ),
+        Settings::Modexp(s) => (
+            "modexp",
+            settings
+                .optional("exponent", s.exponent)
+                .optional("num_messages", s.num_messages)
+                .optional("seed", s.seed),
+        ),
+        Settings::Bytecount(s) => (
+            "bytecount",
+            settings
+                .optional("text", s.text)
+                .optional("character", s.character),
+        ),
+        Settings::Runsum(s) => (
+            "runsum",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed),
+        ),
+        Settings::Horner(s) => (
+            "horner",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("seed", s.seed)
+                .optional("point", s.point),
+        ),
+        Settings::Wrap(s) => (
+            "wrap",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("start", s.start)
+                .optional("increment", s.increment),
+        ),
+        Settings::Window(s) => (
+            "window",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("width", s.width)
+                .optional("start", s.start),
+        ),
+        Settings::Fibdiv(s) => (
+            "fibdiv",
+            settings
+                .optional("trace_length", s.trace_length)
+                .optional("divisor", s.divisor),
+        ),
+    }
+}
+
+fn report_message(report: &RunReport) -> proto::Report {
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+    proto::Report {
+        seed: report.seed,
+        trace_length: report.trace_length as u64,
+        trace_time_ms: ms(report.trace_time),
+        prover_time_ms: ms(report.prover_time),
+        verifier_time_ms: ms(report.verifier_time),
+        proof_size: report.proof_size as u64,
+        fri_layers: report.fri_layers as u32,
+        conjectured_security: report.conjectured_security,
+        proven_security: report.proven_security,
+        build: Some(proto::BuildInfo {
+            profile: report.build.profile.to_string(),
+            target: report.build.target.to_string(),
+            rustc_version: report.build.rustc_version.to_string(),
+            winterfell_version: report.build.winterfell_version.to_string(),
+            target_features: strings(&report.build.target_features),
+            cpu_features: strings(&report.build.cpu_features),
+            commit: report.build.commit.to_string(),
+        }),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Settings of an example as the JSON object of its config section; fields which are not set
+/// in the message are left out, so that they take their defaults.
+struct SettingsMap(Map<String, Value>);
+
+impl SettingsMap {
+    fn optional<T: Into<Value>>(mut self, key: &str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.0.insert(key.to_string(), value.into());
+        }
+        self
+    }
+
+    fn repeated<T: Into<Value>>(mut self, key: &str, values: Vec<T>) -> Self {
+        if !values.is_empty() {
+            self.0.insert(key.to_string(), values.into());
+        }
+        self
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        proto::{self, prove_request::Example, stark_examples_server::StarkExamples},
+        ExampleService,
+    };
+    use std::time::Duration;
+    use tonic::{Code, Request};
+
+    #[test]
+    fn grpc_proof_verifies() {
+        let service = ExampleService::new(Duration::from_secs(60));
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let prove = |request| {
+            runtime
+                .block_on(service.prove(Request::new(request)))
+                .map_err(|status| status.code())
+        };
+        let verify = |request| {
+            runtime
+                .block_on(service.verify(Request::new(request)))
+                .unwrap()
+                .into_inner()
+                .verified
+        };
+
+        let request = proto::ProveRequest {
+            options: Some(proto::ProofOptions {
+                num_queries: Some(28),
+                blowup_factor: Some(8),
+                ..Default::default()
+            }),
+            example: Some(Example::Fib(proto::FibSettings {
+                trace_length: Some(64),
+                seed: None,
+                num_terms: None,
+            })),
+            ..Default::default()
+        };
+        let response = prove(request.clone()).unwrap().into_inner();
+        assert_eq!(64, response.report.unwrap().trace_length);
+        let request = proto::VerifyRequest {
+            example: "fib".to_string(),
+            proof: response.proof,
+            pub_inputs: response.pub_inputs,
+        };
+        assert!(verify(request.clone()));
+        let request = proto::VerifyRequest {
+            example: "vdf".to_string(),
+            ..request
+        };
+        assert!(!verify(request));
+
+        // settings which the example rejects, and requests without an example, are invalid
+        let request = proto::ProveRequest {
+            example: Some(Example::Fib(proto::FibSettings {
+                trace_length: Some(63),
+                seed: None,
+                num_terms: None,
+            })),
+            ..Default::default()
+        };
+        assert_eq!(Code::InvalidArgument, prove(request).unwrap_err());
+        let request = proto::ProveRequest::default();
+        assert_eq!(Code::InvalidArgument, prove(request).unwrap_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    air_utils::{cyclic_degrees, last_row_equals, transition_degrees},
+    field::ExampleField,
+    gadgets::{first_row_flags, horner},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout. `COEFF` holds the private coefficients of the polynomial, leading
+/// coefficient first, and `ACC` the value at the public point of the polynomial with the
+/// coefficients of this row and all previous ones.
+pub const COEFF: usize = 0;
+pub const ACC: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The point and the value are the only public inputs; the coefficients are chosen freely by
+/// the prover.
+#[derive(Clone)]
+pub struct HornerInputs<B: ExampleField> {
+    /// Point at which the polynomial is evaluated.
+    pub point: B,
+    /// Value of the polynomial at the point.
+    pub value: B,
+}
+
+impl<B: ExampleField> Serializable for HornerInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.point);
+        target.write(self.value);
+    }
+}
+
+impl<B: ExampleField> Deserializable for HornerInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(HornerInputs {
+            point: B::read_from(source)?,
+            value: B::read_from(source)?,
+        })
+    }
+}
+
+// HORNER AIR
+// ================================================================================================
+
+/// AIR of a private column of polynomial coefficients, one per row and leading coefficient
+/// first, and a column which evaluates the polynomial at a public point by Horner's rule.
+///
+/// The constraints are those of the Horner gadget of [crate::gadgets]:
+///
+/// * `is_first * (acc - coeff) = 0`, where `is_first` is a periodic column which is one on the
+///   first row only, so that the accumulator starts with the leading coefficient;
+/// * `acc' = acc * point + coeff'`, which multiplies the accumulator by the point before adding
+///   the coefficient of every later row.
+///
+/// An assertion pins the accumulator of the last row to the public value. The point is a public
+/// input rather than a column, so both constraints have degree one. Nothing constrains the
+/// coefficients themselves, so the AIR accepts any polynomial which takes the value at the point.
+pub struct HornerAir<B: ExampleField> {
+    context: AirContext<B>,
+    point: B,
+    value: B,
+}
+
+impl<B: ExampleField> Air for HornerAir<B> {
+    type BaseField = B;
+    type PublicInputs = HornerInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: HornerInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        // only the first constraint reads the periodic column
+        let mut degrees = cyclic_degrees(&[1], trace_info.length());
+        degrees.append(&mut transition_degrees(&[1]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            point: pub_inputs.point,
+            value: pub_inputs.value,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let [first, rest] = horner(
+            current[ACC],
+            current[COEFF],
+            next[ACC],
+            next[COEFF],
+            E::from(self.point),
+            periodic_values[0],
+        );
+        result[0] = first;
+        result[1] = rest;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the evaluation ends with the public value
+        vec![last_row_equals(ACC, self.trace_length(), self.value)]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![first_row_flags(self.trace_length())]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
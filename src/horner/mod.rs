@@ -0,0 +1,131 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::HornerConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{math::polynom, ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{HornerAir, HornerInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::HornerProver;
+
+#[cfg(test)]
+mod tests;
+
+// HORNER EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the value at a public point of some private polynomial, whose
+/// coefficients are drawn from a seed.
+///
+/// The coefficients are held in the trace, one per step, and evaluated at the point by Horner's
+/// rule with the Horner gadget of [crate::gadgets]; see [HornerAir] for the constraints.
+///
+/// The coefficients are bound to nothing the verifier knows; they are drawn from the seed, but
+/// the seed is not part of the statement. A proof therefore exists for every value, and shows
+/// only that some polynomial of degree `trace_length - 1` takes that value at the point, not
+/// which polynomial it is.
+#[cfg(feature = "std")]
+pub struct HornerExample<B: ExampleField> {
+    seed: u64,
+    coeffs: Vec<B>,
+    point: B,
+    value: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> HornerExample<B> {
+    pub fn new(config: &HornerConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let coeffs = (0..config.trace_length)
+            .map(|_| B::from(rng.gen::<u64>()))
+            .collect::<Vec<_>>();
+        let point = B::from(config.point);
+
+        // compute result; polynom::eval() takes the constant coefficient first
+        let now = Instant::now();
+        let lowest_first = coeffs.iter().rev().copied().collect::<Vec<_>>();
+        let value = polynom::eval(&lowest_first, point);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            coeffs,
+            point,
+            value,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &HornerConfig) -> Result<(), String> {
+        validate::check_trace_length("horner.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for HornerExample<B> {
+    type Air = HornerAir<B>;
+    type Prover = HornerProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        HornerProver::build_trace(&self.coeffs, self.point)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = HornerConfig::default();
+        ExampleDescriptor::new(
+            "horner",
+            "the value of a private polynomial at a public point",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+        .with_default("point", config.point)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("COEFF", 1), ("ACC", 1)],
+            constraints: vec![("leading coefficient", 1), ("Horner step", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> HornerProver<B> {
+        HornerProver::new(options, self.point)
+    }
+
+    fn pub_inputs(&self) -> HornerInputs<B> {
+        HornerInputs {
+            point: self.point,
+            value: self.value,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> HornerInputs<B> {
+        HornerInputs {
+            point: self.point,
+            value: self.value + B::ONE,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::{
+    air::{ACC, COEFF, TRACE_WIDTH},
+    HornerAir, HornerInputs,
+};
+use crate::{field::ExampleField, gadgets::horner_witness};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct HornerProver<B: ExampleField> {
+    options: ProofOptions,
+    point: B,
+}
+
+impl<B: ExampleField> HornerProver<B> {
+    pub fn new(options: ProofOptions, point: B) -> Self {
+        Self { options, point }
+    }
+
+    /// Builds a trace which holds one coefficient per row, leading coefficient first, next to
+    /// the value at the point of the polynomial with the coefficients up to and including that
+    /// row.
+    pub fn build_trace(coeffs: &[B], point: B) -> TraceTable<B> {
+        let mut columns = vec![Vec::new(); TRACE_WIDTH];
+        columns[COEFF] = coeffs.to_vec();
+        columns[ACC] = horner_witness(coeffs, point);
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for HornerProver<B> {
+    type BaseField = B;
+    type Air = HornerAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> HornerInputs<B> {
+        HornerInputs {
+            point: self.point,
+            value: trace.get(ACC, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
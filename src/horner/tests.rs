@@ -0,0 +1,131 @@
+use super::{
+    air::{ACC, COEFF},
+    HornerExample, HornerInputs, HornerProver,
+};
+use crate::{
+    config::{HornerConfig, ProofOptionsConfig, RunConfig},
+    example::Example,
+    run_example, tamper,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn horner_trace_holds_partial_evaluations() {
+    // 2x^7 + 7x^6 + x^5 + 8x^4 + 2x^3 + 8x^2 + x + 8 at x = 3, one coefficient at a time
+    let coeffs = [2u64, 7, 1, 8, 2, 8, 1, 8].map(BaseElement::from);
+    let trace = HornerProver::build_trace(&coeffs, BaseElement::from(3u64));
+    let accs = [2u64, 13, 40, 128, 386, 1166, 3499, 10505];
+    for step in 0..coeffs.len() {
+        assert_eq!(coeffs[step], trace.get(COEFF, step));
+        assert_eq!(BaseElement::from(accs[step]), trace.get(ACC, step));
+    }
+}
+
+#[test]
+fn horner_proof_does_not_verify_at_another_point() {
+    let example = build_example();
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    let pub_inputs = HornerInputs {
+        point: example.pub_inputs().point + BaseElement::ONE,
+        ..example.pub_inputs()
+    };
+    assert!(
+        winterfell::verify::<<HornerExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn horner_scaled_accumulators_are_rejected() {
+    // adding `offset * point^i` to the accumulator of row `i` keeps every Horner step satisfied
+    // and shifts the value, so only the constraint on the first row rules it out
+    let example = build_example();
+    let point = example.pub_inputs().point;
+    let mut trace = example.build_trace();
+    let mut offset = BaseElement::ONE;
+    for step in 0..TRACE_LENGTH {
+        trace.set(ACC, step, trace.get(ACC, step) + offset);
+        offset *= point;
+    }
+    let pub_inputs = HornerInputs {
+        value: trace.get(ACC, TRACE_LENGTH - 1),
+        ..example.pub_inputs()
+    };
+
+    let proof = HornerProver::new(build_options(), point)
+        .prove(trace)
+        .unwrap();
+    assert!(
+        winterfell::verify::<<HornerExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn horner_seed_reproduces_inputs() {
+    let mut config = RunConfig {
+        example: "horner".to_string(),
+        horner: HornerConfig {
+            trace_length: TRACE_LENGTH,
+            ..HornerConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    config.set_seed(5).unwrap();
+    let first = run_example(&config).unwrap();
+    assert_eq!(Some(5), first.seed);
+    assert_eq!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+
+    config.set_seed(6).unwrap();
+    assert_ne!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+}
+
+#[test]
+fn horner_corrupted_first_row_is_rejected() {
+    let example = build_example();
+    for column in [COEFF, ACC] {
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, 0);
+        assert!(verdict.is_rejected(), "column {}: {}", column, verdict);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_example() -> HornerExample<BaseElement> {
+    HornerExample::new(&HornerConfig {
+        trace_length: TRACE_LENGTH,
+        ..HornerConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,283 @@
+//! Self-contained HTML reports of bench sweeps.
+//!
+//! A report is a single file without external stylesheets, scripts, or fonts, so that it can be
+//! opened offline or dropped into slides: the measurements are rendered as plain tables and as
+//! inline SVG line charts.
+
+use crate::bench::{format_memory, SweepPoint};
+use std::fmt::Write;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Colors of the lines of successive examples in a chart.
+const COLORS: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 360.0;
+
+/// Space between the edges of a chart and its plot area, for axis labels.
+const MARGIN_LEFT: f64 = 64.0;
+const MARGIN_RIGHT: f64 = 120.0;
+const MARGIN_TOP: f64 = 32.0;
+const MARGIN_BOTTOM: f64 = 48.0;
+
+/// Number of intervals into which the vertical axis is divided.
+const NUM_Y_TICKS: usize = 5;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #ccc; padding: 4px 12px; text-align: right; }
+th { background: #f4f4f4; }
+svg { display: block; margin-bottom: 2em; }
+svg text { font-size: 12px; }";
+
+// REPORT
+// ================================================================================================
+
+/// Renders the results of a trace length sweep as an HTML page, with a chart of prover time and
+/// a chart of proof size against trace length, followed by a table of measurements for each
+/// example.
+pub fn sweep_report(points: &[SweepPoint]) -> String {
+    let examples = group_by_example(points);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>STARK examples: trace length sweep</title>\n");
+    writeln!(html, "<style>\n{}\n</style>\n</head>\n<body>", STYLE).unwrap();
+    html.push_str("<h1>Prover time and proof size vs. trace length</h1>\n");
+
+    html.push_str("<h2>Prover time</h2>\n");
+    html.push_str(&line_chart(&examples, "prover time (ms)", |point| {
+        point.prover_time.as_micros() as f64 / 1000f64
+    }));
+    html.push_str("<h2>Proof size</h2>\n");
+    html.push_str(&line_chart(&examples, "proof size (KB)", |point| {
+        point.proof_size as f64 / 1024f64
+    }));
+
+    for (example, points) in examples.iter() {
+        writeln!(html, "<h2>{}</h2>", escape(example)).unwrap();
+        html.push_str("<table>\n<tr><th>trace length</th><th>prover time (ms)</th>");
+        html.push_str("<th>proof size (KB)</th><th>FRI layers</th><th>verifier time (ms)</th>");
+        html.push_str("<th>peak memory (MB)</th></tr>\n");
+        for point in points.iter() {
+            writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{:.1}</td><td>{}</td><td>{:.1}</td><td>{}</td></tr>",
+                point.trace_length,
+                point.prover_time.as_millis(),
+                point.proof_size as f64 / 1024f64,
+                point.fri_layers,
+                point.verifier_time.as_micros() as f64 / 1000f64,
+                format_memory(point.peak_memory)
+            )
+            .unwrap();
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+// CHARTS
+// ================================================================================================
+
+/// Renders an SVG chart with a line per example, plotting `value` against the trace length on
+/// a logarithmic axis.
+fn line_chart(
+    examples: &[(&str, Vec<&SweepPoint>)],
+    label: &str,
+    value: impl Fn(&SweepPoint) -> f64,
+) -> String {
+    let log_lengths = examples
+        .iter()
+        .flat_map(|(_, points)| points.iter())
+        .map(|point| (point.trace_length as f64).log2());
+    let min_x = log_lengths.clone().fold(f64::INFINITY, f64::min).floor();
+    let max_x = log_lengths.fold(f64::NEG_INFINITY, f64::max).ceil();
+    let max_x = if max_x > min_x { max_x } else { min_x + 1.0 };
+    let max_y = examples
+        .iter()
+        .flat_map(|(_, points)| points.iter())
+        .map(|point| value(point))
+        .fold(0.0, f64::max);
+    let max_y = if max_y > 0.0 { max_y * 1.1 } else { 1.0 };
+
+    let plot_width = CHART_WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = CHART_HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let x = |trace_length: usize| {
+        MARGIN_LEFT + ((trace_length as f64).log2() - min_x) / (max_x - min_x) * plot_width
+    };
+    let y = |value: f64| MARGIN_TOP + (1.0 - value / max_y) * plot_height;
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+        viewBox=\"0 0 {w} {h}\">",
+        w = CHART_WIDTH,
+        h = CHART_HEIGHT
+    )
+    .unwrap();
+
+    // axes, with a tick for every power of two of the trace length
+    let bottom = MARGIN_TOP + plot_height;
+    let right = MARGIN_LEFT + plot_width;
+    writeln!(
+        svg,
+        "<path d=\"M{l:.1},{t:.1} V{b:.1} H{r:.1}\" fill=\"none\" stroke=\"#222\"/>",
+        l = MARGIN_LEFT,
+        t = MARGIN_TOP,
+        b = bottom,
+        r = right
+    )
+    .unwrap();
+    for log_length in (min_x as u32)..=(max_x as u32) {
+        let tick = x(1 << log_length);
+        writeln!(
+            svg,
+            "<line x1=\"{x:.1}\" y1=\"{b:.1}\" x2=\"{x:.1}\" y2=\"{t:.1}\" stroke=\"#222\"/>\
+            <text x=\"{x:.1}\" y=\"{l:.1}\" text-anchor=\"middle\">2^{n}</text>",
+            x = tick,
+            b = bottom,
+            t = bottom + 4.0,
+            l = bottom + 18.0,
+            n = log_length
+        )
+        .unwrap();
+    }
+    for i in 0..=NUM_Y_TICKS {
+        let tick_value = max_y * i as f64 / NUM_Y_TICKS as f64;
+        writeln!(
+            svg,
+            "<line x1=\"{l:.1}\" y1=\"{y:.1}\" x2=\"{r:.1}\" y2=\"{y:.1}\" stroke=\"#eee\"/>\
+            <text x=\"{t:.1}\" y=\"{y:.1}\" text-anchor=\"end\" dy=\"4\">{v:.1}</text>",
+            l = MARGIN_LEFT + 1.0,
+            r = right,
+            y = y(tick_value),
+            t = MARGIN_LEFT - 6.0,
+            v = tick_value
+        )
+        .unwrap();
+    }
+    writeln!(
+        svg,
+        "<text x=\"{x:.1}\" y=\"{y:.1}\" text-anchor=\"middle\">trace length</text>\
+        <text x=\"{l:.1}\" y=\"{t:.1}\">{label}</text>",
+        x = MARGIN_LEFT + plot_width / 2.0,
+        y = CHART_HEIGHT - 8.0,
+        l = MARGIN_LEFT - 48.0,
+        t = MARGIN_TOP - 12.0,
+        label = escape(label)
+    )
+    .unwrap();
+
+    // a line and a legend entry per example
+    for (i, (example, points)) in examples.iter().enumerate() {
+        let color = COLORS[i % COLORS.len()];
+        let coordinates = points
+            .iter()
+            .map(|&point| (x(point.trace_length), y(value(point))))
+            .collect::<Vec<_>>();
+        let polyline = coordinates
+            .iter()
+            .map(|(cx, cy)| format!("{:.1},{:.1}", cx, cy))
+            .collect::<Vec<_>>();
+        writeln!(
+            svg,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>",
+            polyline.join(" "),
+            color
+        )
+        .unwrap();
+        for (cx, cy) in coordinates.iter() {
+            writeln!(
+                svg,
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"{}\"/>",
+                cx, cy, color
+            )
+            .unwrap();
+        }
+        let legend_y = MARGIN_TOP + 18.0 * i as f64;
+        writeln!(
+            svg,
+            "<rect x=\"{x:.1}\" y=\"{y:.1}\" width=\"12\" height=\"12\" fill=\"{c}\"/>\
+            <text x=\"{tx:.1}\" y=\"{ty:.1}\">{name}</text>",
+            x = right + 16.0,
+            y = legend_y,
+            c = color,
+            tx = right + 34.0,
+            ty = legend_y + 10.0,
+            name = escape(example)
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Groups the points by example, keeping examples and the points of each in sweep order.
+fn group_by_example(points: &[SweepPoint]) -> Vec<(&str, Vec<&SweepPoint>)> {
+    let mut examples: Vec<(&str, Vec<&SweepPoint>)> = Vec::new();
+    for point in points.iter() {
+        match examples
+            .iter_mut()
+            .find(|(example, _)| *example == point.example)
+        {
+            Some((_, group)) => group.push(point),
+            None => examples.push((&point.example, vec![point])),
+        }
+    }
+    examples
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::sweep_report;
+    use crate::{
+        bench::{self, Repetitions},
+        config::{FibConfig, ProofOptionsConfig, RunConfig},
+    };
+
+    #[test]
+    fn trace_length_sweep_is_reported_as_html() {
+        let config = RunConfig {
+            example: "fib".to_string(),
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            fib: FibConfig {
+                trace_length: 64,
+                ..FibConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let points =
+            bench::trace_length_sweep(&config, &["fib".to_string()], 2, Repetitions::SINGLE)
+                .unwrap();
+        let report = sweep_report(&points);
+        assert_eq!(2, report.matches("<svg").count());
+        assert_eq!(2, report.matches("<polyline").count());
+        assert!(report.contains("<td>128</td>"));
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    error::ExampleError,
+    field::FieldType,
+    proof_file::ProofFile,
+    security::{proof_security, SecurityBound},
+    verify::VerifyError,
+};
+use std::{fs, path::Path};
+use winterfell::{math::log2, HashFunction, Serializable, StarkProof};
+
+// PROOF INSPECTION
+// ================================================================================================
+
+/// Reads a serialized STARK proof from the specified file and prints a breakdown of its
+/// components: proof context, queries, out-of-domain frame, FRI layers, and the number of bytes
+/// taken up by each section.
+///
+/// Returns an [Io](ExampleError::Io) error if the file cannot be read, and a
+/// [Verification](ExampleError::Verification) error if it does not hold a proof.
+pub fn inspect(path: &Path) -> Result<(), ExampleError> {
+    let file_bytes = fs::read(path).map_err(|err| ExampleError::read("proof file", path, err))?;
+    let malformed = |err: &dyn std::fmt::Display| {
+        ExampleError::from(VerifyError::MalformedProof(format!(
+            "{}: {}",
+            path.display(),
+            err
+        )))
+    };
+    // bare proofs, as written before proof files had a header, are still accepted
+    let (header, proof_bytes) = if ProofFile::is_proof_file(&file_bytes) {
+        let file = ProofFile::from_bytes(&file_bytes).map_err(|err| malformed(&err))?;
+        (Some(file.header), file.proof_bytes)
+    } else {
+        (None, file_bytes)
+    };
+    let proof = StarkProof::from_bytes(&proof_bytes).map_err(|err| malformed(&err))?;
+
+    let options = proof.options();
+    let fri_options = options.to_fri_options();
+    let base_element_bytes = proof.context.field_modulus_bytes().len();
+    let element_bytes = base_element_bytes * options.field_extension().degree() as usize;
+    let digest_bytes = digest_size(options.hash_fn());
+
+    println!("Proof {} ({} bytes)", path.display(), proof_bytes.len());
+
+    // header
+    println!();
+    match header {
+        Some(header) => {
+            println!("Header");
+            println!("  format version:     {}", header.format_version);
+            println!(
+                "  written by:         stark-examples {}",
+                header.crate_version
+            );
+            println!("  winterfell version: {}", header.winterfell_version);
+            println!("  example:            {}", header.example);
+            println!("  field:              {}", header.field);
+            println!("  hash function:      {}", header.hash_fn);
+        }
+        None => println!("No header; the file holds a bare serialized proof"),
+    }
+
+    // context
+    println!();
+    println!("Context");
+    println!(
+        "  trace:              {} columns x {} rows ({} bytes of metadata)",
+        proof.trace_width(),
+        proof.trace_length(),
+        proof.context.get_trace_info().meta().len()
+    );
+    println!(
+        "  base field:         {} ({}-bit modulus)",
+        field_name(proof.context.field_modulus_bytes()),
+        proof.context.num_modulus_bits()
+    );
+    println!(
+        "  LDE domain:         2^{} elements",
+        log2(proof.lde_domain_size())
+    );
+    println!("  num queries:        {}", options.num_queries());
+    println!("  blowup factor:      {}", options.blowup_factor());
+    println!("  grinding factor:    {}", options.grinding_factor());
+    println!("  hash function:      {:?}", options.hash_fn());
+    println!("  field extension:    {:?}", options.field_extension());
+    println!("  FRI folding factor: {}", fri_options.folding_factor());
+    println!("  FRI max remainder:  {}", fri_options.max_remainder_size());
+    println!(
+        "  conjectured security: {} bits",
+        proof_security(&proof, SecurityBound::Conjectured)
+    );
+    println!(
+        "  proven security:      {} bits",
+        proof_security(&proof, SecurityBound::Proven)
+    );
+
+    // commitments
+    let commitments = proof.commitments.to_bytes();
+    println!();
+    println!("Commitments");
+    println!(
+        "  {} Merkle roots of {} bytes each (trace, constraints, and FRI layers)",
+        (commitments.len() - 2) / digest_bytes,
+        digest_bytes
+    );
+
+    // queries; positions which were drawn more than once are included in the proof only once
+    let trace_queries = proof.trace_queries.to_bytes();
+    let constraint_queries = proof.constraint_queries.to_bytes();
+    let (trace_values, trace_paths) =
+        read_queries(&trace_queries).map_err(|err| malformed(&err))?;
+    let (constraint_values, constraint_paths) =
+        read_queries(&constraint_queries).map_err(|err| malformed(&err))?;
+    let num_unique_queries = trace_values / (proof.trace_width() * base_element_bytes);
+    println!();
+    println!("Queries");
+    println!(
+        "  {} requested, {} unique positions",
+        options.num_queries(),
+        num_unique_queries
+    );
+    println!(
+        "  trace:       {} values per query; {} bytes of values, {} bytes of Merkle paths",
+        proof.trace_width(),
+        trace_values,
+        trace_paths
+    );
+    println!(
+        "  constraints: {} values per query; {} bytes of values, {} bytes of Merkle paths",
+        constraint_values / (num_unique_queries * element_bytes),
+        constraint_values,
+        constraint_paths
+    );
+
+    // out-of-domain frame
+    let ood_frame = proof.ood_frame.to_bytes();
+    let (trace_row_bytes, evaluation_bytes) =
+        read_ood_frame(&ood_frame).map_err(|err| malformed(&err))?;
+    println!();
+    println!("Out-of-domain frame");
+    println!(
+        "  trace rows at z and z * g: 2 x {} elements",
+        trace_row_bytes / element_bytes
+    );
+    println!(
+        "  constraint composition columns at z: {} elements",
+        evaluation_bytes / element_bytes
+    );
+
+    // FRI proof
+    let fri_proof = proof.fri_proof.to_bytes();
+    let (layers, remainder_bytes) = read_fri_proof(&fri_proof).map_err(|err| malformed(&err))?;
+    println!();
+    println!("FRI proof");
+    println!(
+        "  {} layers, {} partitions",
+        layers.len(),
+        proof.fri_proof.num_partitions()
+    );
+    let mut domain_size = proof.lde_domain_size();
+    for (i, (value_bytes, path_bytes)) in layers.iter().enumerate() {
+        println!(
+            "  layer {}: domain of 2^{} elements; {} values, {} bytes of Merkle paths",
+            i,
+            log2(domain_size),
+            value_bytes / element_bytes,
+            path_bytes
+        );
+        domain_size /= fri_options.folding_factor();
+    }
+    println!("  remainder: {} elements", remainder_bytes / element_bytes);
+
+    // section sizes
+    let sections = [
+        ("context", proof.context.to_bytes().len()),
+        ("commitments", commitments.len()),
+        ("trace queries", trace_queries.len()),
+        ("constraint queries", constraint_queries.len()),
+        ("OOD frame", ood_frame.len()),
+        ("FRI proof", fri_proof.len()),
+        ("proof-of-work nonce", 8),
+    ];
+    println!();
+    println!("Section sizes");
+    for (name, size) in sections.iter() {
+        println!(
+            "  {:<20} {:>8} bytes ({:>5.1}%)",
+            name,
+            size,
+            *size as f64 * 100.0 / proof_bytes.len() as f64
+        );
+    }
+    println!("  {:<20} {:>8} bytes", "total", proof_bytes.len());
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+pub(crate) fn digest_size(hash_fn: HashFunction) -> usize {
+    match hash_fn {
+        HashFunction::Blake3_192 => 24,
+        HashFunction::Blake3_256 | HashFunction::Sha3_256 => 32,
+    }
+}
+
+pub(crate) fn field_name(modulus_bytes: &[u8]) -> &'static str {
+    match FieldType::from_modulus_bytes(modulus_bytes) {
+        Some(FieldType::F128) => "f128",
+        Some(FieldType::F64) => "f64",
+        Some(FieldType::F62) => "f62",
+        None => "unknown field",
+    }
+}
+
+// SERIALIZED LAYOUT READERS
+// ------------------------------------------------------------------------------------------------
+// Winterfell does not expose the internals of query, OOD frame, and FRI proof structs, so their
+// sizes are recovered from the serialized form of each struct.
+
+/// Returns the number of value bytes and Merkle path bytes in serialized queries.
+fn read_queries(bytes: &[u8]) -> Result<(usize, usize), String> {
+    let mut reader = Reader::new(bytes);
+    let value_bytes = reader.skip_u32_prefixed()?;
+    let path_bytes = reader.skip_u32_prefixed()?;
+    Ok((value_bytes, path_bytes))
+}
+
+/// Returns the number of bytes in a single trace row and in the constraint evaluations of a
+/// serialized out-of-domain frame.
+fn read_ood_frame(bytes: &[u8]) -> Result<(usize, usize), String> {
+    let mut reader = Reader::new(bytes);
+    let trace_row_bytes = reader.read_u16()? as usize;
+    reader.skip(trace_row_bytes * 2)?;
+    let evaluation_bytes = reader.read_u16()? as usize;
+    Ok((trace_row_bytes, evaluation_bytes))
+}
+
+/// Returns value and path byte counts for each layer, and the number of remainder bytes of a
+/// serialized FRI proof.
+fn read_fri_proof(bytes: &[u8]) -> Result<(Vec<(usize, usize)>, usize), String> {
+    let mut reader = Reader::new(bytes);
+    let num_layers = reader.read_u8()? as usize;
+    let mut layers = Vec::with_capacity(num_layers);
+    for _ in 0..num_layers {
+        let value_bytes = reader.skip_u32_prefixed()?;
+        let path_bytes = reader.skip_u32_prefixed()?;
+        layers.push((value_bytes, path_bytes));
+    }
+    let remainder_bytes = reader.read_u16()? as usize;
+    Ok((layers, remainder_bytes))
+}
+
+/// Minimal little-endian reader over a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, num_bytes: usize) -> Result<&'a [u8], String> {
+        let end = self.pos + num_bytes;
+        if end > self.bytes.len() {
+            return Err("unexpected end of proof section".to_string());
+        }
+        let result = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn skip(&mut self, num_bytes: usize) -> Result<(), String> {
+        self.take(num_bytes).map(|_| ())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, String> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Skips over a byte vector prefixed with its u32 length and returns the vector's length.
+    fn skip_u32_prefixed(&mut self) -> Result<usize, String> {
+        let num_bytes = self.read_u32()? as usize;
+        self.skip(num_bytes)?;
+        Ok(num_bytes)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, last_row_equals, not, transition_degrees},
+    field::ExampleField,
+    gadgets,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout. `COUNTER` holds the value which is counted down, `INVERSE` the inverse
+/// of the counter (or zero once the counter is zero), and `IS_ZERO` is one exactly in the rows
+/// where the counter is zero.
+pub const COUNTER: usize = 0;
+pub const INVERSE: usize = 1;
+pub const IS_ZERO: usize = 2;
+pub const TRACE_WIDTH: usize = 3;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct IsZeroInputs<B: ExampleField> {
+    pub start: B,
+}
+
+impl<B: ExampleField> Serializable for IsZeroInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.start);
+    }
+}
+
+impl<B: ExampleField> Deserializable for IsZeroInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(IsZeroInputs {
+            start: B::read_from(source)?,
+        })
+    }
+}
+
+// IS-ZERO AIR
+// ================================================================================================
+
+/// AIR of a counter which is decremented in every row until it reaches zero, and stays at zero
+/// from then on.
+///
+/// Whether the counter is zero is not a polynomial function of the counter, so the prover
+/// supplies it as the `IS_ZERO` flag, together with an `INVERSE` witness column, and two
+/// constraints tie the flag to the counter `x`:
+///
+/// * `x * inv = 1 - flag`: if `x` is not zero, some `inv` satisfies this only with `flag = 0`
+///   (namely `inv = 1 / x`); if `x` is zero, the left side is zero and `flag` must be one.
+/// * `flag * x = 0`: the flag cannot be one while `x` is not zero, whatever `inv` is.
+///
+/// Together they force `flag` to be exactly one when `x` is zero and zero otherwise, so the flag
+/// is binary without a constraint of its own. Both constraints multiply two columns and have
+/// degree two. The flag then selects the transition `x' = x - (1 - flag)`.
+pub struct IsZeroAir<B: ExampleField> {
+    context: AirContext<B>,
+    start: B,
+}
+
+impl<B: ExampleField> Air for IsZeroAir<B> {
+    type BaseField = B;
+    type PublicInputs = IsZeroInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: IsZeroInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let degrees = transition_degrees(&[2, 2, 1]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            start: pub_inputs.start,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let counter = current[COUNTER];
+        let is_zero = current[IS_ZERO];
+
+        // the flag is one exactly when the counter is zero
+        result[..2].copy_from_slice(&gadgets::is_zero(counter, current[INVERSE], is_zero));
+
+        // the counter is decremented until it is zero
+        result[2] = are_equal(next[COUNTER], counter - not(is_zero));
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the counter starts at the public value and has reached zero by the last step
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(COUNTER, self.start),
+            last_row_equals(COUNTER, trace_length, B::ZERO),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::IsZeroConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{IsZeroAir, IsZeroInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::IsZeroProver;
+
+#[cfg(test)]
+mod tests;
+
+// IS-ZERO EXAMPLE
+// ================================================================================================
+
+/// Proves that a counter which starts at a public value and is decremented once per step
+/// reaches zero within the trace, after which it stays at zero.
+///
+/// When to stop decrementing depends on whether the counter is zero, which the AIR learns from
+/// an is-zero flag column backed by an inverse witness column; see [IsZeroAir] for how the two
+/// constraints of this gadget pin the flag down.
+#[cfg(feature = "std")]
+pub struct IsZeroExample<B: ExampleField> {
+    trace_length: usize,
+    start: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> IsZeroExample<B> {
+    pub fn new(config: &IsZeroConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            start: B::from(config.start),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &IsZeroConfig) -> Result<(), String> {
+        validate::check_trace_length("iszero.trace_length", config.trace_length)?;
+        // a counter which starts at zero gives a constant trace, which winterfell cannot prove
+        if config.start == 0 {
+            return Err("counter must not start at zero, but iszero.start is 0".to_string());
+        }
+        if config.start >= config.trace_length as u64 {
+            return Err(format!(
+                "counter must reach zero within the trace, but iszero.start is {} for a trace \
+                 of {} steps; lower it below the trace length or raise iszero.trace_length to {}",
+                config.start,
+                config.trace_length,
+                (config.start as usize + 1).next_power_of_two()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for IsZeroExample<B> {
+    type Air = IsZeroAir<B>;
+    type Prover = IsZeroProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        IsZeroProver::build_trace(self.start, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = IsZeroConfig::default();
+        ExampleDescriptor::new(
+            "iszero",
+            "a counter which counts down to zero and stops there",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("start", config.start)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("COUNTER", 1), ("INVERSE", 1), ("IS_ZERO", 1)],
+            constraints: vec![("is-zero flag", 2), ("countdown", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> IsZeroProver<B> {
+        IsZeroProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> IsZeroInputs<B> {
+        IsZeroInputs { start: self.start }
+    }
+
+    fn wrong_pub_inputs(&self) -> IsZeroInputs<B> {
+        IsZeroInputs {
+            start: self.start + B::ONE,
+        }
+    }
+}
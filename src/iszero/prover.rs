@@ -0,0 +1,68 @@
+use super::{
+    air::{COUNTER, INVERSE, IS_ZERO, TRACE_WIDTH},
+    IsZeroAir, IsZeroInputs,
+};
+use crate::{field::ExampleField, gadgets};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct IsZeroProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> IsZeroProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which counts down from `start` to zero and then stays at zero, with the
+    /// inverse and the is-zero flag of the counter in every row.
+    pub fn build_trace(start: B, trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        trace.fill(
+            |state| {
+                fill_witness(start, state);
+            },
+            |_, state| {
+                let next = state[COUNTER] - (B::ONE - state[IS_ZERO]);
+                fill_witness(next, state);
+            },
+        );
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for IsZeroProver<B> {
+    type BaseField = B;
+    type Air = IsZeroAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> IsZeroInputs<B> {
+        IsZeroInputs {
+            start: trace.get(COUNTER, 0),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the counter into the row together with its inverse and is-zero flag; the inverse of
+/// zero is taken to be zero.
+fn fill_witness<B: ExampleField>(counter: B, state: &mut [B]) {
+    state[COUNTER] = counter;
+    let (inverse, is_zero) = gadgets::is_zero_witness(counter);
+    state[INVERSE] = inverse;
+    state[IS_ZERO] = is_zero;
+}
@@ -0,0 +1,121 @@
+use super::{
+    air::{COUNTER, INVERSE, IS_ZERO, TRACE_WIDTH},
+    IsZeroExample, IsZeroInputs, IsZeroProver,
+};
+use crate::{config::IsZeroConfig, error::ExampleError, example::Example, tamper};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace, TraceTable,
+};
+
+#[test]
+fn iszero_inverse_of_zero_is_unconstrained() {
+    // once the counter is zero, x * inv = 1 - flag holds for any inverse, so the prover may put
+    // anything into the witness column without affecting the statement
+    let example = build_example();
+    let verdict =
+        tamper::corrupt_trace_cell(&example, build_options(), INVERSE, START as usize + 10);
+    assert!(!verdict.is_rejected(), "{}", verdict);
+}
+
+#[test]
+fn iszero_trace_flags_zero_counter() {
+    let trace = IsZeroProver::build_trace(BaseElement::from(5u8), 8);
+    let counters = [5u8, 4, 3, 2, 1, 0, 0, 0];
+    for (step, &counter) in counters.iter().enumerate() {
+        let counter = BaseElement::from(counter);
+        assert_eq!(counter, trace.get(COUNTER, step));
+        if counter == BaseElement::ZERO {
+            assert_eq!(BaseElement::ONE, trace.get(IS_ZERO, step));
+            assert_eq!(BaseElement::ZERO, trace.get(INVERSE, step));
+        } else {
+            assert_eq!(BaseElement::ZERO, trace.get(IS_ZERO, step));
+            assert_eq!(BaseElement::ONE, counter * trace.get(INVERSE, step));
+        }
+    }
+}
+
+#[test]
+fn iszero_flag_set_on_nonzero_counter_is_rejected() {
+    // pause the countdown at 3 by claiming that the counter is zero; with the inverse set to
+    // zero, x * inv = 1 - flag holds, so only flag * x = 0 catches the lie
+    let mut counters = vec![5u64, 4, 3, 3, 2, 1];
+    counters.resize(16, 0);
+    let mut trace = TraceTable::new(TRACE_WIDTH, counters.len());
+    trace.fill(|_| {}, |_, _| {});
+    for (step, &counter) in counters.iter().enumerate() {
+        let counter = BaseElement::from(counter);
+        let paused = step == 2;
+        let is_zero = paused || counter == BaseElement::ZERO;
+        trace.set(COUNTER, step, counter);
+        trace.set(
+            INVERSE,
+            step,
+            if is_zero {
+                BaseElement::ZERO
+            } else {
+                counter.inv()
+            },
+        );
+        trace.set(IS_ZERO, step, BaseElement::from(is_zero as u8));
+    }
+
+    let proof = IsZeroProver::new(build_options()).prove(trace).unwrap();
+    let pub_inputs = IsZeroInputs {
+        start: BaseElement::from(5u8),
+    };
+    assert!(
+        winterfell::verify::<<IsZeroExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn iszero_counter_longer_than_trace_is_not_accepted() {
+    let err = IsZeroExample::<BaseElement>::new(&IsZeroConfig {
+        trace_length: TRACE_LENGTH,
+        start: TRACE_LENGTH as u64,
+        ..IsZeroConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(err.to_string().contains("reach zero"), "{}", err);
+}
+
+#[test]
+fn iszero_corrupted_trace_after_zero_is_rejected() {
+    let example = build_example();
+    for column in [COUNTER, IS_ZERO] {
+        let step = START as usize + 10;
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, step);
+        assert!(verdict.is_rejected(), "column {}: {}", column, verdict);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 128;
+const START: u64 = 100;
+
+fn build_example() -> IsZeroExample<BaseElement> {
+    IsZeroExample::new(&IsZeroConfig {
+        trace_length: TRACE_LENGTH,
+        start: START,
+        ..IsZeroConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,141 @@
+use super::{
+    signature::{PublicKey, MESSAGE_BITS},
+    CYCLE_LENGTH,
+};
+use crate::{
+    air_utils::{cyclic_degrees, first_row_equals, not, EvaluationResult},
+    field::ExampleField,
+    rescue::rescue::{self, Rescue},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Specifies steps on which Rescue transition function is applied.
+const CYCLE_MASK: [u8; CYCLE_LENGTH] = [1, 1, 1, 1, 1, 1, 1, 0];
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct LamportInputs<B: ExampleField> {
+    pub messages: Vec<u8>,
+    pub public_keys: Vec<PublicKey<B>>,
+}
+
+impl<B: ExampleField> Serializable for LamportInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.messages.len() as u32);
+        target.write_u8_slice(&self.messages);
+        for public_key in self.public_keys.iter() {
+            public_key.write_into(target);
+        }
+    }
+}
+
+impl<B: ExampleField> Deserializable for LamportInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_signatures = source.read_u32()? as usize;
+        Ok(LamportInputs {
+            messages: source.read_u8_vec(num_signatures)?,
+            public_keys: PublicKey::read_batch_from(source, num_signatures)?,
+        })
+    }
+}
+
+// LAMPORT AIR
+// ================================================================================================
+
+pub struct LamportAir<B: ExampleField> {
+    context: AirContext<B>,
+    rescue: Rescue<B>,
+    digests: [Vec<B>; 2],
+}
+
+impl<B: ExampleField> Air for LamportAir<B> {
+    type BaseField = B;
+    type PublicInputs = LamportInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: LamportInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(rescue::STATE_WIDTH, trace_info.width());
+        assert_eq!(
+            pub_inputs.messages.len(),
+            pub_inputs.public_keys.len(),
+            "expected one public key per message"
+        );
+        assert_eq!(
+            trace_info.length(),
+            pub_inputs.messages.len() * MESSAGE_BITS * CYCLE_LENGTH,
+            "expected one cycle per message bit"
+        );
+
+        // the secret revealed for every message bit must hash to the digest which the public
+        // key assigns to the value of that bit
+        let mut digests = [Vec::new(), Vec::new()];
+        for (&message, public_key) in pub_inputs.messages.iter().zip(&pub_inputs.public_keys) {
+            for bit_index in 0..MESSAGE_BITS {
+                let digest = public_key.expected_digest(message, bit_index);
+                digests[0].push(digest[0]);
+                digests[1].push(digest[1]);
+            }
+        }
+
+        let degrees = cyclic_degrees(&[B::ALPHA as usize; rescue::STATE_WIDTH], CYCLE_LENGTH);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            rescue: Rescue::new(),
+            digests,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // split periodic values into hash_flag and Rescue round constants
+        let hash_flag = periodic_values[0];
+        let ark = &periodic_values[1..];
+
+        // when hash_flag = 1, constraints for Rescue round are enforced
+        self.rescue
+            .enforce_round(result, current, next, ark, hash_flag);
+
+        // when hash_flag = 0, the rate portion of the state is free to take on the next secret,
+        // and the capacity portion is reset to zero
+        let reset_flag = not(hash_flag);
+        result.agg_constraint(2, reset_flag, next[2]);
+        result.agg_constraint(3, reset_flag, next[3]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert that the first hash starts with zeroed capacity, and that every hash ends with
+        // the digest expected by the public key
+        let last_round = CYCLE_LENGTH - 1;
+        vec![
+            first_row_equals(2, B::ZERO),
+            first_row_equals(3, B::ZERO),
+            Assertion::sequence(0, last_round, CYCLE_LENGTH, self.digests[0].clone()),
+            Assertion::sequence(1, last_round, CYCLE_LENGTH, self.digests[1].clone()),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut result = vec![CYCLE_MASK.iter().map(|&flag| B::from(flag)).collect()];
+        result.append(&mut self.rescue.get_round_constants());
+        result
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::LamportConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{LamportAir, LamportInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::LamportProver;
+
+pub(crate) mod signature;
+#[cfg(feature = "std")]
+use signature::{PrivateKey, PublicKey, Signature};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const CYCLE_LENGTH: usize = crate::rescue::rescue::CYCLE_LENGTH;
+#[cfg(feature = "std")]
+const STATE_WIDTH: usize = crate::rescue::rescue::STATE_WIDTH;
+
+// LAMPORT SIGNATURE AGGREGATION EXAMPLE
+// ================================================================================================
+
+/// Proves that every message in a batch carries a valid Lamport one-time signature under its
+/// signer's public key, without revealing the signatures.
+///
+/// A Lamport public key holds two Rescue digests for every bit of the message, and a signature
+/// reveals the preimage of the digest selected by each bit. The execution trace hashes every
+/// revealed preimage in a cycle of [CYCLE_LENGTH] rows, and the AIR asserts that the output of
+/// each cycle is the digest which the public key selects for the corresponding message bit.
+#[cfg(feature = "std")]
+pub struct LamportExample<B: ExampleField> {
+    seed: u64,
+    messages: Vec<u8>,
+    public_keys: Vec<PublicKey<B>>,
+    signatures: Vec<Signature<B>>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> LamportExample<B> {
+    pub fn new(config: &LamportConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+
+        // generate a key pair for every signer and sign a random message with it
+        let now = Instant::now();
+        let mut messages = Vec::with_capacity(config.num_signatures);
+        let mut public_keys = Vec::with_capacity(config.num_signatures);
+        let mut signatures = Vec::with_capacity(config.num_signatures);
+        for _ in 0..config.num_signatures {
+            let private_key = PrivateKey::generate(&mut rng);
+            let message = rng.gen::<u8>();
+            messages.push(message);
+            public_keys.push(private_key.public_key());
+            signatures.push(private_key.sign(message));
+        }
+        info!(
+            "Signed {} messages in {} ms",
+            config.num_signatures,
+            now.elapsed().as_millis()
+        );
+
+        // verify the signatures
+        let now = Instant::now();
+        for (i, signature) in signatures.iter().enumerate() {
+            assert!(
+                public_keys[i].verify(messages[i], signature),
+                "signature {} is invalid",
+                i
+            );
+        }
+        info!(
+            "Verified {} signatures in {} ms",
+            config.num_signatures,
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            seed: config.seed,
+            messages,
+            public_keys,
+            signatures,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &LamportConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "number of signatures",
+            "lamport.num_signatures",
+            config.num_signatures,
+            1,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for LamportExample<B> {
+    type Air = LamportAir<B>;
+    type Prover = LamportProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        LamportProver::build_trace(&self.signatures)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = LamportConfig::default();
+        ExampleDescriptor::new(
+            "lamport",
+            "a batch of Lamport one-time signatures over Rescue digests",
+        )
+        .with_default("num_signatures", config.num_signatures)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("STATE", STATE_WIDTH)],
+            constraints: vec![("Rescue round or capacity reset", STATE_WIDTH)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> LamportProver<B> {
+        LamportProver::new(self.pub_inputs(), options)
+    }
+
+    fn pub_inputs(&self) -> LamportInputs<B> {
+        LamportInputs {
+            messages: self.messages.clone(),
+            public_keys: self.public_keys.clone(),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> LamportInputs<B> {
+        let mut messages = self.messages.clone();
+        messages[0] ^= 1;
+        LamportInputs {
+            messages,
+            public_keys: self.public_keys.clone(),
+        }
+    }
+}
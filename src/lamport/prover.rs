@@ -0,0 +1,77 @@
+use super::{
+    signature::{Signature, MESSAGE_BITS},
+    LamportAir, LamportInputs, CYCLE_LENGTH,
+};
+use crate::{
+    field::ExampleField,
+    rescue::rescue::{self, Rescue},
+};
+use winterfell::{ProofOptions, Prover, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct LamportProver<B: ExampleField> {
+    pub_inputs: LamportInputs<B>,
+    options: ProofOptions,
+}
+
+impl<B: ExampleField> LamportProver<B> {
+    pub fn new(pub_inputs: LamportInputs<B>, options: ProofOptions) -> Self {
+        Self {
+            pub_inputs,
+            options,
+        }
+    }
+
+    /// Builds a trace in which every cycle hashes the secret revealed for one message bit; the
+    /// cycles of the first signature come first, ordered by bit index.
+    pub fn build_trace(signatures: &[Signature<B>]) -> TraceTable<B> {
+        let rescue = Rescue::new();
+        let secrets = signatures
+            .iter()
+            .flat_map(|signature| (0..MESSAGE_BITS).map(move |i| signature.secret(i)))
+            .collect::<Vec<_>>();
+        let trace_length = secrets.len() * CYCLE_LENGTH;
+        let mut trace = TraceTable::new(rescue::STATE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                // initialize the state with the first secret and zeroed capacity
+                state[0] = secrets[0][0];
+                state[1] = secrets[0][1];
+                state[2] = B::ZERO;
+                state[3] = B::ZERO;
+            },
+            |step, state| {
+                // execute the transition function for all steps except the last step of each
+                // cycle; for the last step, load the next secret and reset the capacity
+                if (step % CYCLE_LENGTH) < CYCLE_LENGTH - 1 {
+                    rescue.apply_round(state, step);
+                } else {
+                    let secret = secrets[(step + 1) / CYCLE_LENGTH];
+                    state[0] = secret[0];
+                    state[1] = secret[1];
+                    state[2] = B::ZERO;
+                    state[3] = B::ZERO;
+                }
+            },
+        );
+
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for LamportProver<B> {
+    type BaseField = B;
+    type Air = LamportAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> LamportInputs<B> {
+        self.pub_inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{field::ExampleField, rescue::rescue::Rescue};
+#[cfg(feature = "std")]
+use rand::Rng;
+use winterfell::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of message bits covered by one signature.
+pub const MESSAGE_BITS: usize = 8;
+
+// KEYS AND SIGNATURES
+// ================================================================================================
+
+/// Pair of Rescue digests per message bit: the hashes of the secrets revealed when the bit is
+/// zero and when it is one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey<B: ExampleField> {
+    digests: [[[B; 2]; 2]; MESSAGE_BITS],
+}
+
+/// Pair of secrets per message bit; a Lamport key must be used to sign only one message.
+pub struct PrivateKey<B: ExampleField> {
+    secrets: [[[B; 2]; 2]; MESSAGE_BITS],
+}
+
+/// The secrets selected by the bits of the signed message.
+#[derive(Debug, Clone, Copy)]
+pub struct Signature<B: ExampleField> {
+    secrets: [[B; 2]; MESSAGE_BITS],
+}
+
+impl<B: ExampleField> PrivateKey<B> {
+    /// Draws the secrets of a new key from the provided random number generator.
+    #[cfg(feature = "std")]
+    pub fn generate<R: Rng>(rng: &mut R) -> Self {
+        let mut secrets = [[[B::ZERO; 2]; 2]; MESSAGE_BITS];
+        for secret in secrets.iter_mut().flatten().flatten() {
+            *secret = B::from(rng.gen::<u64>());
+        }
+        Self { secrets }
+    }
+
+    /// Returns the public key which verifies signatures made with this key.
+    pub fn public_key(&self) -> PublicKey<B> {
+        let rescue = Rescue::new();
+        let mut digests = [[[B::ZERO; 2]; 2]; MESSAGE_BITS];
+        for (digest_pair, secret_pair) in digests.iter_mut().zip(self.secrets.iter()) {
+            for (digest, &secret) in digest_pair.iter_mut().zip(secret_pair.iter()) {
+                rescue.hash(secret, digest);
+            }
+        }
+        PublicKey { digests }
+    }
+
+    /// Signs the message by revealing, for every bit of the message, the secret selected by the
+    /// value of that bit.
+    pub fn sign(&self, message: u8) -> Signature<B> {
+        let mut secrets = [[B::ZERO; 2]; MESSAGE_BITS];
+        for (i, secret) in secrets.iter_mut().enumerate() {
+            *secret = self.secrets[i][bit(message, i)];
+        }
+        Signature { secrets }
+    }
+}
+
+impl<B: ExampleField> PublicKey<B> {
+    /// Returns the digest which the secret revealed for the specified bit of the message must
+    /// hash to.
+    pub fn expected_digest(&self, message: u8, bit_index: usize) -> [B; 2] {
+        self.digests[bit_index][bit(message, bit_index)]
+    }
+
+    /// Returns true if the signature is valid for the message under this public key.
+    pub fn verify(&self, message: u8, signature: &Signature<B>) -> bool {
+        let rescue = Rescue::new();
+        let mut digest = [B::ZERO; 2];
+        signature.secrets.iter().enumerate().all(|(i, &secret)| {
+            rescue.hash(secret, &mut digest);
+            digest == self.expected_digest(message, i)
+        })
+    }
+}
+
+impl<B: ExampleField> Signature<B> {
+    /// Returns the secret revealed for the specified bit of the message.
+    pub fn secret(&self, bit_index: usize) -> [B; 2] {
+        self.secrets[bit_index]
+    }
+}
+
+impl<B: ExampleField> Serializable for PublicKey<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for digest in self.digests.iter().flatten() {
+            target.write(&digest[..]);
+        }
+    }
+}
+
+impl<B: ExampleField> Deserializable for PublicKey<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut digests = [[[B::ZERO; 2]; 2]; MESSAGE_BITS];
+        for element in digests.iter_mut().flatten().flatten() {
+            *element = B::read_from(source)?;
+        }
+        Ok(PublicKey { digests })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value of the bit of the message at the specified index.
+fn bit(message: u8, index: usize) -> usize {
+    ((message >> index) & 1) as usize
+}
@@ -0,0 +1,58 @@
+use super::{LamportExample, PrivateKey};
+use crate::{config::LamportConfig, example::Example};
+use rand::{rngs::StdRng, SeedableRng};
+use winterfell::{
+    math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions, Prover,
+};
+
+#[test]
+fn lamport_signature_of_another_signer_is_rejected() {
+    // replace the first signature with a valid signature of the second signer
+    let mut example = build_example();
+    example.signatures[0] = example.signatures[1];
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    assert!(
+        winterfell::verify::<<LamportExample<BaseElement> as Example>::Air>(
+            proof,
+            example.pub_inputs()
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn lamport_signature_verifies_only_for_signed_message() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let private_key = PrivateKey::<BaseElement>::generate(&mut rng);
+    let public_key = private_key.public_key();
+    let signature = private_key.sign(0b1010_0110);
+    assert!(public_key.verify(0b1010_0110, &signature));
+    assert!(!public_key.verify(0b1010_0111, &signature));
+    assert!(!public_key.verify(0b0010_0110, &signature));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> LamportExample<BaseElement> {
+    LamportExample::new(&LamportConfig {
+        num_signatures: 4,
+        ..LamportConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
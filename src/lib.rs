@@ -0,0 +1,198 @@
+//! Toy STARK examples built with winterfell.
+//!
+//! Every example implements the [Example](example::Example) trait, and can be selected by name
+//! through a [RunConfig](config::RunConfig). [run_example()] proves and verifies the selected
+//! example and returns a [RunReport](example::RunReport) with timings and proof parameters, or
+//! an [ExampleError] which tells the stage that failed.
+//!
+//! Without the default `std` feature the crate is `no_std`, and only the verifier side of the
+//! examples is built; see [verify_core].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// without `std` only the AIRs of the examples are used, so the helpers which the examples share
+// with their provers go unused
+#![cfg_attr(not(feature = "std"), allow(dead_code))]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod artifacts;
+#[cfg(feature = "std")]
+pub mod bench;
+pub mod bitwise;
+#[cfg(feature = "std")]
+pub mod buildinfo;
+#[cfg(feature = "std")]
+pub mod bundle;
+pub mod bytecount;
+#[cfg(feature = "std")]
+pub mod cancel;
+#[cfg(feature = "std")]
+pub mod chain;
+pub mod claims;
+#[cfg(feature = "std")]
+pub mod cli;
+pub mod compare;
+#[cfg(feature = "std")]
+pub mod compose;
+#[cfg(feature = "compression")]
+pub mod compression;
+#[cfg(feature = "std")]
+pub mod config;
+#[cfg(feature = "std")]
+pub mod constraints;
+pub mod counter;
+#[cfg(feature = "std")]
+pub mod degrees;
+#[cfg(feature = "std")]
+pub mod describe;
+#[cfg(feature = "std")]
+pub mod emulator;
+#[cfg(feature = "std")]
+pub mod encoding;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "std")]
+pub mod estimate;
+#[cfg(feature = "std")]
+pub mod example;
+#[cfg(feature = "std")]
+pub mod exercises;
+pub mod exp;
+#[cfg(feature = "std")]
+pub mod explain;
+#[cfg(feature = "std")]
+pub mod ffi;
+pub mod fib;
+pub mod fibchunk;
+pub mod fibdiv;
+pub mod fibmod;
+pub mod field;
+pub mod fixedexp;
+pub mod gadgets;
+pub mod gcd;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod horner;
+#[cfg(feature = "std")]
+pub mod html;
+#[cfg(feature = "std")]
+pub mod inspect;
+pub mod iszero;
+pub mod lamport;
+#[cfg(feature = "std")]
+pub mod memory;
+pub mod merkle;
+pub mod modexp;
+#[cfg(feature = "std")]
+pub mod phases;
+#[cfg(feature = "std")]
+pub mod plugins;
+pub mod poseidon;
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod presets;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+#[cfg(feature = "progress")]
+pub mod progress;
+#[cfg(feature = "std")]
+pub mod proof_file;
+#[cfg(feature = "std")]
+pub mod proof_sizes;
+#[cfg(feature = "std")]
+pub mod pub_input_digest;
+pub mod quad;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod rescue;
+pub mod rom;
+pub mod runsum;
+#[cfg(feature = "std")]
+pub mod security;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "std")]
+pub mod soak;
+pub mod sort;
+pub mod sqrt;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod sum;
+#[cfg(feature = "std")]
+pub mod tamper;
+#[cfg(feature = "std")]
+pub mod trace;
+#[cfg(feature = "std")]
+pub mod tune;
+#[cfg(feature = "std")]
+pub mod validate;
+pub mod vdf;
+#[cfg(feature = "std")]
+pub mod verification_key;
+#[cfg(feature = "std")]
+pub mod verify;
+pub mod verify_core;
+pub mod vm;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod window;
+#[cfg(feature = "std")]
+pub mod witness;
+pub mod wrap;
+
+mod air_utils;
+#[cfg(feature = "std")]
+mod runner;
+#[cfg(feature = "std")]
+pub use error::ExampleError;
+#[cfg(feature = "std")]
+pub use runner::{run_example, with_example, ExampleCommand};
+
+#[cfg(test)]
+mod goldens;
+#[cfg(test)]
+mod test_utils;
+
+// measure memory in tests of the crate the same way the binary does
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: memory::TrackingAllocator = memory::TrackingAllocator;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Names of the examples which can be selected in a config.
+pub const EXAMPLES: [&str; 28] = [
+    "vdf",
+    "rescue",
+    "poseidon",
+    "counter",
+    "lamport",
+    "exp",
+    "sum",
+    "vm",
+    "fib",
+    "gcd",
+    "quad",
+    "iszero",
+    "rom",
+    "sqrt",
+    "merkle",
+    "bitwise",
+    "compare",
+    "fibmod",
+    "sort",
+    "fibchunk",
+    "fixedexp",
+    "modexp",
+    "bytecount",
+    "runsum",
+    "horner",
+    "wrap",
+    "window",
+    "fibdiv",
+];
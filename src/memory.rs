@@ -0,0 +1,157 @@
+//! Heap memory measurement of prover runs.
+//!
+//! Memory rather than time is often what limits the size of the traces which can be proven.
+//! [TrackingAllocator] wraps the system allocator and keeps track of the number of bytes which
+//! are allocated on the heap, and of the largest number which was allocated at any one time.
+//! The allocator has to be installed as the global allocator by the binary, which the
+//! `stark-examples` binary does; without it, [measure()] reports nothing.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fs,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Set once memory has been allocated through a [TrackingAllocator].
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Number of bytes currently allocated through a [TrackingAllocator].
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// Largest number of bytes allocated at once since the start of the innermost [measure()].
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// TRACKING ALLOCATOR
+// ================================================================================================
+
+/// A global allocator which forwards to the system allocator and counts allocated bytes.
+///
+/// Install it in a binary with:
+///
+/// ```
+/// #[global_allocator]
+/// static ALLOCATOR: stark_examples::memory::TrackingAllocator =
+///     stark_examples::memory::TrackingAllocator;
+/// ```
+///
+/// Allocations of all threads are counted together, so the measurements include the worker
+/// threads of the concurrent prover.
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+// MEASUREMENT
+// ================================================================================================
+
+/// Returns true if a [TrackingAllocator] is installed as the global allocator.
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` and returns its result together with the largest number of bytes which were
+/// allocated on the heap at any one time while it ran, including memory which was already
+/// allocated when it started; `None` if no [TrackingAllocator] is installed.
+///
+/// Measurements may be nested: the peak of an outer measurement includes the peaks of the
+/// measurements made inside it.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    if !is_installed() {
+        return (f(), None);
+    }
+    let outer_peak = PEAK.swap(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+    (result, Some(peak))
+}
+
+/// Returns the size of the physical memory of the machine in bytes, or `None` where it cannot be
+/// determined; it is read from `/proc/meminfo`, which only Linux provides.
+pub fn total_memory() -> Option<usize> {
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|line| line.starts_with("MemTotal:"))?;
+    let kilobytes = line
+        .trim_start_matches("MemTotal:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    kilobytes.checked_mul(1024)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn record_alloc(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::measure;
+    use crate::{
+        example::{self, Example},
+        test_utils::{vdf_example, vdf_options},
+    };
+    use winterfell::{math::fields::f128::BaseElement, Trace};
+
+    #[test]
+    fn peak_memory_covers_extended_trace() {
+        let example = vdf_example();
+        let trace = example.build_trace();
+        let report = example::run(&example, vdf_options());
+        let peak_memory = report
+            .peak_memory
+            .expect("tests install the tracking allocator");
+
+        // the prover holds the trace extended over the LDE domain in memory
+        let lde_size = trace.width()
+            * trace.length()
+            * vdf_options().blowup_factor()
+            * std::mem::size_of::<BaseElement>();
+        assert!(peak_memory >= lde_size, "{} < {}", peak_memory, lde_size);
+
+        // a nested measurement contributes to the peak of the outer one
+        let (inner_peak, outer_peak) = measure(|| measure(|| vec![0u8; 1 << 20].len()).1.unwrap());
+        assert!(inner_peak >= 1 << 20);
+        assert!(outer_peak.unwrap() >= inner_peak);
+    }
+}
@@ -0,0 +1,236 @@
+use super::CYCLE_LENGTH;
+use crate::{
+    air_utils::{are_equal, cyclic_degrees, first_row_equals, not, EvaluationResult},
+    field::ExampleField,
+    rescue::rescue::{self, Rescue},
+    vdf::FORTY_TWO,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Specifies steps on which Rescue transition function is applied.
+const CYCLE_MASK: [u8; CYCLE_LENGTH] = [1, 1, 1, 1, 1, 1, 1, 0];
+
+/// Largest number of outputs committed to by the root; the trace has three columns per output.
+pub const MAX_LEAVES: usize = 64;
+
+/// Trace column layout. The columns from `LEAVES` on hold one VDF computation per output, and
+/// they are followed by one Rescue state of [rescue::STATE_WIDTH] columns, a hash lane, per
+/// pair of outputs.
+pub const LEAVES: usize = 0;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Seed of the first VDF computation, the shape of the computation, and the Merkle root of its
+/// outputs; the outputs themselves are not part of the statement.
+#[derive(Clone)]
+pub struct MerkleInputs<B: ExampleField> {
+    pub seed: B,
+    pub num_leaves: usize,
+    pub num_steps: usize,
+    pub root: B,
+}
+
+impl<B: ExampleField> MerkleInputs<B> {
+    /// Returns the number of columns in the trace of this statement.
+    pub fn trace_width(&self) -> usize {
+        self.num_leaves + self.num_lanes() * rescue::STATE_WIDTH
+    }
+
+    /// Returns the number of hash lanes, each of which hashes one node of every level of the
+    /// tree; lanes which have no node left to hash on a higher level keep hashing unused values.
+    pub fn num_lanes(&self) -> usize {
+        self.num_leaves / 2
+    }
+
+    /// Returns the step at which the outputs are loaded into the hash lanes: the first step at
+    /// the end of a hash cycle at which all VDF computations are done.
+    pub fn load_step(&self) -> usize {
+        (self.num_steps - 1) / CYCLE_LENGTH * CYCLE_LENGTH + CYCLE_LENGTH - 1
+    }
+
+    /// Returns the step at which the first hash lane holds the root, after one hash cycle per
+    /// level of the tree.
+    pub fn root_step(&self) -> usize {
+        self.load_step() + self.num_leaves.trailing_zeros() as usize * CYCLE_LENGTH
+    }
+
+    /// Returns the number of rows in the trace of this statement.
+    pub fn trace_length(&self) -> usize {
+        (self.root_step() + 1).next_power_of_two()
+    }
+}
+
+impl<B: ExampleField> Serializable for MerkleInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write_u32(self.num_leaves as u32);
+        target.write_u32(self.num_steps as u32);
+        target.write(self.root);
+    }
+}
+
+impl<B: ExampleField> Deserializable for MerkleInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = B::read_from(source)?;
+        let num_leaves = source.read_u32()? as usize;
+        if !num_leaves.is_power_of_two() || !(2..=MAX_LEAVES).contains(&num_leaves) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid number of leaves {}",
+                num_leaves
+            )));
+        }
+        let num_steps = source.read_u32()? as usize;
+        if num_steps < 2 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid number of steps {}",
+                num_steps
+            )));
+        }
+        Ok(MerkleInputs {
+            seed,
+            num_leaves,
+            num_steps,
+            root: B::read_from(source)?,
+        })
+    }
+}
+
+// MERKLE AIR
+// ================================================================================================
+
+/// AIR of a batch of VDF computations whose outputs are committed to by a Merkle root of Rescue
+/// hashes, so that the public inputs stay the same size however many outputs there are.
+///
+/// The VDF computations run side by side for the first `num_steps` rows, starting from
+/// consecutive seeds, and then hold their outputs. At the end of the first hash cycle after
+/// that, lane `j` loads outputs `2j` and `2j+1` into its rate, and at the end of every following
+/// cycle it loads the digests of lanes `2j` and `2j+1`, so that each cycle hashes one level of
+/// the tree in the same rows. Nodes are the first element of a Rescue digest. The root is
+/// asserted in the first lane after one cycle per level; since it is a boundary assertion on a
+/// trace cell, it ties the statement to every output without the outputs being public.
+pub struct MerkleAir<B: ExampleField> {
+    context: AirContext<B>,
+    rescue: Rescue<B>,
+    pub_inputs: MerkleInputs<B>,
+}
+
+impl<B: ExampleField> Air for MerkleAir<B> {
+    type BaseField = B;
+    type PublicInputs = MerkleInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: MerkleInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.trace_width(), trace_info.width());
+        assert_eq!(pub_inputs.trace_length(), trace_info.length());
+        let alpha = B::ALPHA as usize;
+        let num_lane_columns = pub_inputs.num_lanes() * rescue::STATE_WIDTH;
+        let mut degrees = cyclic_degrees(&vec![alpha; pub_inputs.num_leaves], trace_info.length());
+        degrees.append(&mut cyclic_degrees(
+            &vec![alpha; num_lane_columns],
+            CYCLE_LENGTH,
+        ));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            rescue: Rescue::new(),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let num_leaves = self.pub_inputs.num_leaves;
+        let num_lanes = self.pub_inputs.num_lanes();
+
+        // split periodic values into flags and Rescue round constants
+        let hash_flag = periodic_values[0];
+        let vdf_flag = periodic_values[1];
+        let load_flag = periodic_values[2];
+        let ark = &periodic_values[3..];
+
+        // while the VDF computations run, every state is the next one raised to the power of
+        // alpha plus 42; afterwards, the outputs stay the same
+        let forty_two = E::from(FORTY_TWO);
+        for i in 0..num_leaves {
+            let (state, next_state) = (current[LEAVES + i], next[LEAVES + i]);
+            let step = are_equal(state, next_state.exp(B::ALPHA.into()) + forty_two);
+            result.agg_constraint(i, vdf_flag, step);
+            result.agg_constraint(i, not(vdf_flag), are_equal(next_state, state));
+        }
+
+        // when hash_flag = 1, every lane applies a Rescue round; when hash_flag = 0, lane j
+        // loads either outputs 2j and 2j+1 or the digests of lanes 2j and 2j+1, and resets its
+        // capacity to zero; the constraints of a lane are at the indexes of its columns
+        let copy_flag = not(hash_flag);
+        let lane = |j: usize| num_leaves + j * rescue::STATE_WIDTH;
+        for j in 0..num_lanes {
+            let column = lane(j);
+            let state = &current[column..column + rescue::STATE_WIDTH];
+            let next_state = &next[column..column + rescue::STATE_WIDTH];
+            self.rescue.enforce_round(
+                &mut result[column..column + rescue::STATE_WIDTH],
+                state,
+                next_state,
+                ark,
+                hash_flag,
+            );
+
+            let children = [lane((2 * j) % num_lanes), lane((2 * j + 1) % num_lanes)];
+            for (k, &child) in children.iter().enumerate() {
+                let input =
+                    load_flag * current[LEAVES + 2 * j + k] + not(load_flag) * current[child];
+                result.agg_constraint(column + k, copy_flag, are_equal(next_state[k], input));
+            }
+            result.agg_constraint(column + 2, copy_flag, next_state[2]);
+            result.agg_constraint(column + 3, copy_flag, next_state[3]);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the VDF computations start from consecutive seeds, and the first lane ends up with the
+        // root of their outputs
+        let inputs = &self.pub_inputs;
+        let mut assertions = (0..inputs.num_leaves)
+            .map(|i| first_row_equals(LEAVES + i, inputs.seed + B::from(i as u64)))
+            .collect::<Vec<_>>();
+        assertions.push(Assertion::single(
+            inputs.num_leaves,
+            inputs.root_step(),
+            inputs.root,
+        ));
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let trace_length = self.trace_length();
+        let num_steps = self.pub_inputs.num_steps;
+        let load_step = self.pub_inputs.load_step();
+        let mut result = vec![
+            CYCLE_MASK.iter().map(|&flag| B::from(flag)).collect(),
+            (0..trace_length)
+                .map(|step| B::from((step + 1 < num_steps) as u8))
+                .collect(),
+            (0..trace_length)
+                .map(|step| B::from((step == load_step) as u8))
+                .collect(),
+        ];
+        result.append(&mut self.rescue.get_round_constants());
+        result
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::MerkleConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+};
+use crate::{
+    field::ExampleField,
+    rescue::rescue::{self, Rescue},
+    vdf::FORTY_TWO,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+use winter_utils::collections::Vec;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{MerkleAir, MerkleInputs, MAX_LEAVES};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::MerkleProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const CYCLE_LENGTH: usize = rescue::CYCLE_LENGTH;
+
+// MERKLE ROOT EXAMPLE
+// ================================================================================================
+
+/// Proves that the public root is the Merkle root of the outputs of a batch of VDF
+/// computations, which start from consecutive seeds.
+///
+/// The public inputs hold the root instead of the outputs, so they stay small however many
+/// outputs there are; any single output can then be revealed with [MerkleExample::open] and
+/// checked against the root with [verify_opening]. The trace computes the root from the
+/// outputs with Rescue, as described in [MerkleAir].
+#[cfg(feature = "std")]
+pub struct MerkleExample<B: ExampleField> {
+    pub_inputs: MerkleInputs<B>,
+    outputs: Vec<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> MerkleExample<B> {
+    pub fn new(config: &MerkleConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let seed = B::from(config.seed);
+
+        // compute outputs and their root
+        let now = Instant::now();
+        let outputs = (0..config.num_leaves)
+            .map(|i| {
+                (1..config.num_steps).fold(seed + B::from(i as u64), |state, _| vdf_step(state))
+            })
+            .collect::<Vec<_>>();
+        let root = merkle_root(&outputs);
+        info!(
+            "Computed {} outputs and their root in {} ms",
+            outputs.len(),
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            pub_inputs: MerkleInputs {
+                seed,
+                num_leaves: config.num_leaves,
+                num_steps: config.num_steps,
+                root,
+            },
+            outputs,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &MerkleConfig) -> Result<(), String> {
+        if !config.num_leaves.is_power_of_two() || !(2..=MAX_LEAVES).contains(&config.num_leaves) {
+            return Err(format!(
+                "number of leaves must be a power of two between 2 and {}, but \
+                 merkle.num_leaves is {}",
+                MAX_LEAVES, config.num_leaves
+            ));
+        }
+        // with a single step the VDF flag would be zero everywhere
+        if config.num_steps < 2 {
+            return Err(format!(
+                "number of steps must be at least 2, but merkle.num_steps is {}",
+                config.num_steps
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the output at `index` and its authentication path, the siblings of the nodes on
+    /// the path from the output to the root, starting with the sibling of the output.
+    pub fn open(&self, index: usize) -> (B, Vec<B>) {
+        let mut path = Vec::new();
+        let mut level = self.outputs.clone();
+        let mut position = index;
+        while level.len() > 1 {
+            path.push(level[position ^ 1]);
+            level = hash_level(&level);
+            position /= 2;
+        }
+        (self.outputs[index], path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for MerkleExample<B> {
+    type Air = MerkleAir<B>;
+    type Prover = MerkleProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        MerkleProver::build_trace(&self.pub_inputs)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = MerkleConfig::default();
+        ExampleDescriptor::new(
+            "merkle",
+            "the Merkle root of the outputs of a batch of VDF computations",
+        )
+        .with_default("num_leaves", config.num_leaves)
+        .with_default("num_steps", config.num_steps)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        let num_leaves = self.pub_inputs.num_leaves;
+        let num_lanes = self.pub_inputs.num_lanes() * rescue::STATE_WIDTH;
+        AirMetadata {
+            columns: vec![("LEAVES", num_leaves), ("LANES", num_lanes)],
+            constraints: vec![
+                ("VDF step or hold", num_leaves),
+                ("Rescue round or load", num_lanes),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> MerkleProver<B> {
+        MerkleProver::new(options, self.pub_inputs.clone())
+    }
+
+    fn pub_inputs(&self) -> MerkleInputs<B> {
+        self.pub_inputs.clone()
+    }
+
+    fn wrong_pub_inputs(&self) -> MerkleInputs<B> {
+        MerkleInputs {
+            root: self.pub_inputs.root + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// MERKLE TREE
+// ================================================================================================
+
+/// Returns true if `path`, as returned by [MerkleExample::open], authenticates `output` as the
+/// output at `index` under `root`.
+pub fn verify_opening<B: ExampleField>(root: B, index: usize, output: B, path: &[B]) -> bool {
+    let rescue = Rescue::new();
+    let node = path
+        .iter()
+        .enumerate()
+        .fold(output, |node, (level, &sibling)| {
+            if (index >> level) & 1 == 0 {
+                hash_pair(&rescue, node, sibling)
+            } else {
+                hash_pair(&rescue, sibling, node)
+            }
+        });
+    index >> path.len() == 0 && node == root
+}
+
+/// Returns the root of the Merkle tree whose leaves are `leaves`.
+fn merkle_root<B: ExampleField>(leaves: &[B]) -> B {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = hash_level(&level);
+    }
+    level[0]
+}
+
+/// Returns the parents of the nodes of a level of the tree.
+fn hash_level<B: ExampleField>(level: &[B]) -> Vec<B> {
+    let rescue = Rescue::new();
+    level
+        .chunks(2)
+        .map(|pair| hash_pair(&rescue, pair[0], pair[1]))
+        .collect()
+}
+
+/// Returns the parent of two nodes: the first element of the Rescue digest of the nodes.
+fn hash_pair<B: ExampleField>(rescue: &Rescue<B>, left: B, right: B) -> B {
+    let mut digest = [B::ZERO; rescue::RATE_WIDTH];
+    rescue.hash([left, right], &mut digest);
+    digest[0]
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the next state of a VDF computation, as in the `vdf` example.
+fn vdf_step<B: ExampleField>(state: B) -> B {
+    (state - B::from(FORTY_TWO)).exp(B::INV_ALPHA)
+}
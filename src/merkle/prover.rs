@@ -0,0 +1,102 @@
+use super::{
+    air::{MerkleInputs, LEAVES},
+    vdf_step, MerkleAir, CYCLE_LENGTH,
+};
+use crate::{
+    field::ExampleField,
+    rescue::rescue::{self, Rescue},
+};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct MerkleProver<B: ExampleField> {
+    options: ProofOptions,
+    pub_inputs: MerkleInputs<B>,
+}
+
+impl<B: ExampleField> MerkleProver<B> {
+    /// Returns a prover for the statement in `pub_inputs`, of which only the root is taken from
+    /// the trace.
+    pub fn new(options: ProofOptions, pub_inputs: MerkleInputs<B>) -> Self {
+        Self {
+            options,
+            pub_inputs,
+        }
+    }
+
+    /// Builds a trace which runs the VDF computations of the statement and then hashes their
+    /// outputs into a Merkle tree, one level per hash cycle. The hash lanes start from an empty
+    /// state and hash unused values until the outputs are loaded.
+    pub fn build_trace(pub_inputs: &MerkleInputs<B>) -> TraceTable<B> {
+        let rescue = Rescue::new();
+        let (num_leaves, num_lanes) = (pub_inputs.num_leaves, pub_inputs.num_lanes());
+        let lane = |j: usize| num_leaves + j * rescue::STATE_WIDTH;
+        let load_step = pub_inputs.load_step();
+
+        let mut trace = TraceTable::new(pub_inputs.trace_width(), pub_inputs.trace_length());
+        trace.fill(
+            |state| {
+                for i in 0..num_leaves {
+                    state[LEAVES + i] = pub_inputs.seed + B::from(i as u64);
+                }
+            },
+            |step, state| {
+                if step + 1 < pub_inputs.num_steps {
+                    for i in 0..num_leaves {
+                        state[LEAVES + i] = vdf_step(state[LEAVES + i]);
+                    }
+                }
+
+                if step % CYCLE_LENGTH < CYCLE_LENGTH - 1 {
+                    for j in 0..num_lanes {
+                        rescue
+                            .apply_round(&mut state[lane(j)..lane(j) + rescue::STATE_WIDTH], step);
+                    }
+                    return;
+                }
+
+                // inputs are read before any lane is overwritten
+                let inputs = (0..num_lanes)
+                    .map(|j| {
+                        if step == load_step {
+                            [state[LEAVES + 2 * j], state[LEAVES + 2 * j + 1]]
+                        } else {
+                            [
+                                state[lane((2 * j) % num_lanes)],
+                                state[lane((2 * j + 1) % num_lanes)],
+                            ]
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                for (j, [left, right]) in inputs.into_iter().enumerate() {
+                    state[lane(j)..lane(j) + rescue::STATE_WIDTH].copy_from_slice(&[
+                        left,
+                        right,
+                        B::ZERO,
+                        B::ZERO,
+                    ]);
+                }
+            },
+        );
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for MerkleProver<B> {
+    type BaseField = B;
+    type Air = MerkleAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> MerkleInputs<B> {
+        MerkleInputs {
+            root: trace.get(self.pub_inputs.num_leaves, self.pub_inputs.root_step()),
+            ..self.pub_inputs.clone()
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,152 @@
+use super::{
+    air::{MerkleInputs, LEAVES},
+    verify_opening, MerkleExample, MerkleProver,
+};
+use crate::{config::MerkleConfig, error::ExampleError, example::Example, tamper};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Serializable, Trace,
+};
+
+#[test]
+fn merkle_public_inputs_do_not_grow_with_outputs() {
+    let sizes = [2, 16, 64]
+        .iter()
+        .map(|&num_leaves| {
+            let example = MerkleExample::<BaseElement>::new(&MerkleConfig {
+                num_leaves,
+                ..MerkleConfig::default()
+            })
+            .unwrap();
+            example.pub_inputs().to_bytes().len()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![sizes[0]; 3], sizes);
+}
+
+#[test]
+fn merkle_trace_computes_root() {
+    // 64 steps end in the last row of the eighth hash cycle, and four levels take four more
+    let example = build_example();
+    let pub_inputs = example.pub_inputs();
+    assert_eq!((63, 95, 128), shape(&pub_inputs));
+    let trace = example.build_trace();
+    assert_eq!(
+        pub_inputs.root,
+        trace.get(pub_inputs.num_leaves, pub_inputs.root_step())
+    );
+
+    // the load waits for the first cycle boundary after the VDF computations are done
+    for (num_steps, expected) in [(2, (7, 39, 64)), (8, (7, 39, 64)), (9, (15, 47, 64))] {
+        let inputs = MerkleInputs {
+            num_steps,
+            ..pub_inputs.clone()
+        };
+        assert_eq!(expected, shape(&inputs), "{} steps", num_steps);
+        let trace = MerkleProver::build_trace(&inputs);
+        let example = MerkleExample::<BaseElement>::new(&MerkleConfig {
+            num_steps,
+            ..MerkleConfig::default()
+        })
+        .unwrap();
+        assert_eq!(
+            example.pub_inputs().root,
+            trace.get(inputs.num_leaves, inputs.root_step())
+        );
+    }
+}
+
+#[test]
+fn merkle_outputs_open_against_root() {
+    let example = build_example();
+    let root = example.pub_inputs().root;
+    for index in [0, 5, 15] {
+        let (output, path) = example.open(index);
+        assert_eq!(4, path.len());
+        assert!(verify_opening(root, index, output, &path));
+        assert!(!verify_opening(
+            root,
+            index,
+            output + BaseElement::ONE,
+            &path
+        ));
+        assert!(!verify_opening(root, index ^ 1, output, &path));
+        assert!(!verify_opening(root, index + 16, output, &path));
+    }
+}
+
+#[test]
+fn merkle_forged_outputs_are_rejected() {
+    // a trace which loads different outputs into the hash lanes than the VDF computations
+    // produced does not reach the root of the real outputs
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let load_step = example.pub_inputs().load_step();
+    for step in example.pub_inputs().num_steps - 1..=load_step {
+        let output = trace.get(LEAVES, step);
+        trace.set(LEAVES, step, output + BaseElement::ONE);
+    }
+    let proof = example.prover(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<MerkleExample<BaseElement> as Example>::Air>(
+            proof,
+            example.pub_inputs()
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn merkle_too_many_leaves_are_rejected() {
+    let err = MerkleExample::<BaseElement>::new(&MerkleConfig {
+        num_leaves: 128,
+        ..MerkleConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(
+        err.to_string()
+            .contains("number of leaves must be a power of two between 2 and 64"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn merkle_corrupted_trace_is_rejected_in_every_phase() {
+    // a VDF step, a held output, and a hash round after the outputs are loaded
+    let example = build_example();
+    let lane = example.pub_inputs().num_leaves;
+    for (column, step) in [(LEAVES + 3, 10), (LEAVES + 5, 70), (lane + 6, 66)] {
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, step);
+        assert!(verdict.is_rejected(), "({}, {}): {}", column, step, verdict);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> MerkleExample<BaseElement> {
+    MerkleExample::new(&MerkleConfig::default()).unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+fn shape(inputs: &MerkleInputs<BaseElement>) -> (usize, usize, usize) {
+    (
+        inputs.load_step(),
+        inputs.root_step(),
+        inputs.trace_length(),
+    )
+}
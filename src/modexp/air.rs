@@ -0,0 +1,307 @@
+use crate::{
+    air_utils::{are_equal, combine_bits, periodic_reset, EvaluationResult},
+    field::ExampleField,
+    gadgets::{less_than_difference, limb_product, to_limbs},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Prime factors of the modulus, the two largest primes below `2^16`.
+pub const FACTORS: [u64; 2] = [65519, 65521];
+
+/// Modulus of the emulated arithmetic, which just fits into two limbs.
+pub const MODULUS: u64 = FACTORS[0] * FACTORS[1];
+
+/// Number of bits in a limb; residues modulo [MODULUS] are held in two limbs.
+pub const LIMB_BITS: usize = 16;
+
+/// Number of bits in a residue.
+pub const RESIDUE_BITS: usize = 2 * LIMB_BITS;
+
+/// Number of bits in the decomposition of a carry, which is offset by `2^LIMB_BITS` to make it
+/// non-negative.
+pub const CARRY_BITS: usize = LIMB_BITS + 1;
+
+/// Number of rows per message; the last row of every cycle holds the ciphertext and applies no
+/// bit of the exponent.
+pub const CYCLE_LENGTH: usize = 32;
+
+/// Number of bits in the exponent.
+pub const EXPONENT_BITS: usize = CYCLE_LENGTH - 1;
+
+/// Largest supported exponent.
+pub const MAX_EXPONENT: u64 = (1 << EXPONENT_BITS) - 1;
+
+/// Trace column layout. `ACC` and `BASE` hold the two limbs of the accumulated power and of the
+/// current square of the message; the columns from `ACC_BITS` and `BASE_BITS` on hold their bits,
+/// least significant bit first, and those from `ACC_SLACK_BITS` and `BASE_SLACK_BITS` on the bits
+/// of their slack below the modulus. The columns from `ACC_QUOTIENT_BITS` and `ACC_CARRY_BITS` on
+/// hold the bits of the quotient and of the offset carry of the multiplication of the
+/// accumulator, and likewise for the squaring of the base. Every column from `ACC_BITS` on holds
+/// a bit.
+pub const ACC: usize = 0;
+pub const BASE: usize = ACC + 2;
+pub const ACC_BITS: usize = BASE + 2;
+pub const ACC_SLACK_BITS: usize = ACC_BITS + RESIDUE_BITS;
+pub const BASE_BITS: usize = ACC_SLACK_BITS + RESIDUE_BITS;
+pub const BASE_SLACK_BITS: usize = BASE_BITS + RESIDUE_BITS;
+pub const ACC_QUOTIENT_BITS: usize = BASE_SLACK_BITS + RESIDUE_BITS;
+pub const ACC_CARRY_BITS: usize = ACC_QUOTIENT_BITS + RESIDUE_BITS;
+pub const BASE_QUOTIENT_BITS: usize = ACC_CARRY_BITS + CARRY_BITS;
+pub const BASE_CARRY_BITS: usize = BASE_QUOTIENT_BITS + RESIDUE_BITS;
+pub const TRACE_WIDTH: usize = BASE_CARRY_BITS + CARRY_BITS;
+
+/// Index of the first constraint after those which tie limbs to their bits and keep every bit
+/// binary; the range checks below the modulus and the two multiplications follow it.
+const RESIDUE_CONSTRAINTS: usize = TRACE_WIDTH;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The public exponent and the ciphertexts which the secret messages encrypt to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModExpInputs {
+    pub exponent: u64,
+    pub ciphertexts: Vec<u64>,
+}
+
+impl Serializable for ModExpInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.exponent);
+        target.write_u32(self.ciphertexts.len() as u32);
+        for &ciphertext in self.ciphertexts.iter() {
+            target.write_u64(ciphertext);
+        }
+    }
+}
+
+impl Deserializable for ModExpInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let exponent = source.read_u64()?;
+        if exponent > MAX_EXPONENT {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid exponent {}",
+                exponent
+            )));
+        }
+        let num_ciphertexts = source.read_u32()? as usize;
+        let mut ciphertexts = Vec::with_capacity(num_ciphertexts);
+        for _ in 0..num_ciphertexts {
+            // the limbs of a ciphertext are asserted rather than range-checked
+            let ciphertext = source.read_u64()?;
+            if ciphertext >= MODULUS {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid ciphertext {}",
+                    ciphertext
+                )));
+            }
+            ciphertexts.push(ciphertext);
+        }
+        Ok(ModExpInputs {
+            exponent,
+            ciphertexts,
+        })
+    }
+}
+
+// MODULAR EXPONENTIATION AIR
+// ================================================================================================
+
+/// AIR of a batch of RSA-style encryptions `c = m^e mod n` under the fixed modulus
+/// `n = 65519 * 65521`, one per cycle of 32 rows.
+///
+/// The modulus is not the one of the field, and a product of two residues can exceed the
+/// modulus of the smaller fields, so residues are held in two limbs of 16 bits and every
+/// multiplication is emulated by the [limb_product] gadget. Row `i` of a cycle squares the base
+/// and multiplies the accumulator by the base if bit `i` of the public exponent, supplied by a
+/// periodic column, is one. The constraints of a row are:
+///
+/// * `x_i * x_i = x_i` for every bit, and each limb of the accumulator and of the base is the
+///   combination of its 16 bits, which range-checks the limbs;
+/// * `n - 1 - x = sum(2^i * s_i)` for the accumulator and for the base, so that both are
+///   canonical residues below the modulus;
+/// * the two limb product constraints of `acc * (bit ? base : 1) = q * n + acc'` and of
+///   `base * base = q' * n + base'`, where `acc'` and `base'` are the limbs of the next row and
+///   the quotients and the offset carries are combinations of their own bits.
+///
+/// A second periodic column is zero in the last row of every cycle, where the multiplications
+/// are switched off so that the next row can start over with an accumulator of one and the next
+/// message, which is secret and only constrained to be a residue.
+pub struct ModExpAir<B: ExampleField> {
+    context: AirContext<B>,
+    exponent_bits: Vec<B>,
+    ciphertexts: Vec<u64>,
+}
+
+impl<B: ExampleField> Air for ModExpAir<B> {
+    type BaseField = B;
+    type PublicInputs = ModExpInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: ModExpInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        assert_eq!(
+            trace_info.length(),
+            pub_inputs.ciphertexts.len() * CYCLE_LENGTH,
+            "expected one ciphertext per cycle"
+        );
+
+        // limbs from bits, binary bits, and range checks below the modulus are fixed in every
+        // row; the multiplication of the accumulator uses both periodic columns, and the
+        // squaring of the base only the one which switches it off
+        let exponent_bits = get_exponent_period(pub_inputs.exponent);
+        let mut degrees = vec![TransitionConstraintDegree::new(1); 4];
+        degrees.extend((ACC_BITS..TRACE_WIDTH).map(|_| TransitionConstraintDegree::new(2)));
+        degrees.extend([
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+        ]);
+        for _ in 0..2 {
+            degrees.push(TransitionConstraintDegree::with_cycles(
+                2,
+                vec![CYCLE_LENGTH, exponent_bits.len()],
+            ));
+        }
+        for _ in 0..2 {
+            degrees.push(TransitionConstraintDegree::with_cycles(
+                2,
+                vec![CYCLE_LENGTH],
+            ));
+        }
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            exponent_bits,
+            ciphertexts: pub_inputs.ciphertexts,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let within_cycle = periodic_values[0];
+        let exponent_bit = periodic_values[1];
+        let modulus = to_limbs(MODULUS, LIMB_BITS).map(E::from);
+
+        // every limb is the combination of its bits, and every bit is binary
+        let acc = [current[ACC], current[ACC + 1]];
+        let base = [current[BASE], current[BASE + 1]];
+        let acc_bits = &current[ACC_BITS..ACC_BITS + RESIDUE_BITS];
+        let base_bits = &current[BASE_BITS..BASE_BITS + RESIDUE_BITS];
+        for (i, (&limb, bits)) in acc.iter().zip(acc_bits.chunks(LIMB_BITS)).enumerate() {
+            result[ACC + i] = are_equal(limb, combine_bits(bits));
+        }
+        for (i, (&limb, bits)) in base.iter().zip(base_bits.chunks(LIMB_BITS)).enumerate() {
+            result[BASE + i] = are_equal(limb, combine_bits(bits));
+        }
+        result.enforce_binary(ACC_BITS, &current[ACC_BITS..TRACE_WIDTH]);
+
+        // the accumulator and the base are below the modulus
+        let n = E::from(MODULUS);
+        for (i, (bits, slack_bits)) in [(ACC_BITS, ACC_SLACK_BITS), (BASE_BITS, BASE_SLACK_BITS)]
+            .iter()
+            .enumerate()
+        {
+            let value = combine_bits(&current[*bits..*bits + RESIDUE_BITS]);
+            let slack = combine_bits(&current[*slack_bits..*slack_bits + RESIDUE_BITS]);
+            result[RESIDUE_CONSTRAINTS + i] =
+                are_equal(less_than_difference(value, n, E::ONE, RESIDUE_BITS), slack);
+        }
+
+        // the accumulator is multiplied by the base where the exponent has a one, and the base
+        // is squared, both modulo n; the results are the residues of the next row
+        let factor = [
+            exponent_bit * (base[0] - E::ONE) + E::ONE,
+            exponent_bit * base[1],
+        ];
+        let next_acc = [next[ACC], next[ACC + 1]];
+        let next_base = [next[BASE], next[BASE + 1]];
+        let products = [
+            (acc, factor, ACC_QUOTIENT_BITS, ACC_CARRY_BITS, next_acc),
+            (base, base, BASE_QUOTIENT_BITS, BASE_CARRY_BITS, next_base),
+        ];
+        for (i, &(a, b, quotient_bits, carry_bits, remainder)) in products.iter().enumerate() {
+            let quotient = get_limbs(&current[quotient_bits..quotient_bits + RESIDUE_BITS]);
+            let carry = combine_bits(&current[carry_bits..carry_bits + CARRY_BITS])
+                - E::from(1u64 << LIMB_BITS);
+            let constraints = limb_product(a, b, quotient, remainder, modulus, carry, LIMB_BITS);
+            for (j, constraint) in constraints.iter().enumerate() {
+                result[RESIDUE_CONSTRAINTS + 2 + 2 * i + j] = within_cycle * *constraint;
+            }
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // every cycle starts with an accumulator of one, and its last row holds the ciphertext
+        let limbs = |i: usize| {
+            self.ciphertexts
+                .iter()
+                .map(|&ciphertext| B::from(to_limbs(ciphertext, LIMB_BITS)[i]))
+                .collect::<Vec<_>>()
+        };
+        vec![
+            periodic_reset(ACC, CYCLE_LENGTH, B::ONE),
+            periodic_reset(ACC + 1, CYCLE_LENGTH, B::ZERO),
+            Assertion::sequence(ACC, CYCLE_LENGTH - 1, CYCLE_LENGTH, limbs(0)),
+            Assertion::sequence(ACC + 1, CYCLE_LENGTH - 1, CYCLE_LENGTH, limbs(1)),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut within_cycle = vec![B::ONE; CYCLE_LENGTH];
+        within_cycle[CYCLE_LENGTH - 1] = B::ZERO;
+        vec![within_cycle, self.exponent_bits.clone()]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns bit `i` of the exponent for every row `i` of a cycle which applies one, followed by
+/// zero for the last row.
+pub fn get_exponent_bits<B: ExampleField>(exponent: u64) -> Vec<B> {
+    (0..CYCLE_LENGTH)
+        .map(|i| {
+            if i < EXPONENT_BITS {
+                B::from((exponent >> i) & 1)
+            } else {
+                B::ZERO
+            }
+        })
+        .collect()
+}
+
+/// Returns the bits of [get_exponent_bits()] over their shortest period within the cycle.
+///
+/// The polynomial of a periodic column has the degree of the number of values it is given, so
+/// bits which repeat within the cycle, such as those of 65537, which repeat every 16 rows, are
+/// supplied by their period to keep the declared degrees of the constraints exact.
+fn get_exponent_period<B: ExampleField>(exponent: u64) -> Vec<B> {
+    let mut bits = get_exponent_bits(exponent);
+    while bits.len() > 2 && bits[..bits.len() / 2] == bits[bits.len() / 2..] {
+        bits.truncate(bits.len() / 2);
+    }
+    bits
+}
+
+/// Returns the two limbs of the residue whose bits are `bits`, least significant bit first.
+fn get_limbs<E: FieldElement>(bits: &[E]) -> [E; 2] {
+    [
+        combine_bits(&bits[..LIMB_BITS]),
+        combine_bits(&bits[LIMB_BITS..]),
+    ]
+}
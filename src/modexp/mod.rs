@@ -0,0 +1,209 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::ModExpConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::{marker::PhantomData, time::Instant};
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{
+    ModExpAir, ModExpInputs, CARRY_BITS, FACTORS, MAX_EXPONENT, MODULUS, RESIDUE_BITS, TRACE_WIDTH,
+};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::ModExpProver;
+
+#[cfg(test)]
+mod tests;
+
+// MODULAR EXPONENTIATION EXAMPLE
+// ================================================================================================
+
+/// Proves knowledge of a batch of messages `m` whose RSA-style encryptions `m^e mod n` are the
+/// public ciphertexts, for a public exponent `e` and the fixed modulus `n = 65519 * 65521`.
+///
+/// The modulus is small enough that the messages can be recovered by factoring it, so the
+/// example demonstrates the arithmetic rather than the security of RSA: the field knows nothing
+/// of the modulus, and residues are as wide as the smallest supported fields allow products to
+/// be, so every multiplication is emulated on limbs and reduced with range-checked quotients and
+/// carries; see [ModExpAir] for the constraints. Each message takes one cycle of 32 rows, which
+/// is the most gadget-heavy trace of the crate.
+#[cfg(feature = "std")]
+pub struct ModExpExample<B: ExampleField> {
+    seed: u64,
+    exponent: u64,
+    messages: Vec<u64>,
+    ciphertexts: Vec<u64>,
+    _field: PhantomData<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> ModExpExample<B> {
+    pub fn new(config: &ModExpConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let messages = (0..config.num_messages)
+            .map(|_| rng.gen_range(0..MODULUS))
+            .collect::<Vec<_>>();
+
+        // compute ciphertexts
+        let now = Instant::now();
+        let ciphertexts = messages
+            .iter()
+            .map(|&message| mod_pow(message, config.exponent, MODULUS))
+            .collect();
+        info!(
+            "Encrypted {} messages modulo {} in {} ms",
+            config.num_messages,
+            MODULUS,
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            seed: config.seed,
+            exponent: config.exponent,
+            messages,
+            ciphertexts,
+            _field: PhantomData,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &ModExpConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "number of messages",
+            "modexp.num_messages",
+            config.num_messages,
+            1,
+        )?;
+        if !(3..=MAX_EXPONENT).contains(&config.exponent) {
+            return Err(format!(
+                "exponent must be between 3 and {}, but modexp.exponent is {}",
+                MAX_EXPONENT, config.exponent
+            ));
+        }
+        // otherwise encryption is not a permutation, and messages cannot be decrypted
+        let totient = (FACTORS[0] - 1) * (FACTORS[1] - 1);
+        if gcd(config.exponent, totient) != 1 {
+            return Err(format!(
+                "exponent must be coprime to {}, the order of the group of units modulo {}, but \
+                modexp.exponent is {}",
+                totient, MODULUS, config.exponent
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for ModExpExample<B> {
+    type Air = ModExpAir<B>;
+    type Prover = ModExpProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        ModExpProver::build_trace(self.exponent, &self.messages)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = ModExpConfig::default();
+        ExampleDescriptor::new(
+            "modexp",
+            "RSA-style encryptions of secret messages under a fixed 32-bit modulus",
+        )
+        .with_default("exponent", config.exponent)
+        .with_default("num_messages", config.num_messages)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("ACC", 2),
+                ("BASE", 2),
+                ("ACC_BITS", RESIDUE_BITS),
+                ("ACC_SLACK_BITS", RESIDUE_BITS),
+                ("BASE_BITS", RESIDUE_BITS),
+                ("BASE_SLACK_BITS", RESIDUE_BITS),
+                ("ACC_QUOTIENT_BITS", RESIDUE_BITS),
+                ("ACC_CARRY_BITS", CARRY_BITS),
+                ("BASE_QUOTIENT_BITS", RESIDUE_BITS),
+                ("BASE_CARRY_BITS", CARRY_BITS),
+            ],
+            constraints: vec![
+                ("accumulator limbs from bits", 2),
+                ("base limbs from bits", 2),
+                ("binary bits", TRACE_WIDTH - 4),
+                ("accumulator below modulus", 1),
+                ("base below modulus", 1),
+                ("multiply accumulator", 2),
+                ("square base", 2),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> ModExpProver<B> {
+        ModExpProver::new(options, self.pub_inputs())
+    }
+
+    fn pub_inputs(&self) -> ModExpInputs {
+        ModExpInputs {
+            exponent: self.exponent,
+            ciphertexts: self.ciphertexts.clone(),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> ModExpInputs {
+        let mut ciphertexts = self.ciphertexts.clone();
+        ciphertexts[0] = (ciphertexts[0] + 1) % MODULUS;
+        ModExpInputs {
+            exponent: self.exponent,
+            ciphertexts,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `base^exponent mod modulus`.
+#[cfg(feature = "std")]
+fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    let mut power = base % modulus;
+    for i in 0..u64::BITS {
+        if (exponent >> i) & 1 == 1 {
+            result = result * power % modulus;
+        }
+        power = power * power % modulus;
+    }
+    result
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+#[cfg(feature = "std")]
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
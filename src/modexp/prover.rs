@@ -0,0 +1,142 @@
+use super::{
+    air::{
+        get_exponent_bits, ACC, ACC_BITS, ACC_CARRY_BITS, ACC_QUOTIENT_BITS, ACC_SLACK_BITS, BASE,
+        BASE_BITS, BASE_CARRY_BITS, BASE_QUOTIENT_BITS, BASE_SLACK_BITS, CARRY_BITS, CYCLE_LENGTH,
+        LIMB_BITS, MODULUS, RESIDUE_BITS, TRACE_WIDTH,
+    },
+    ModExpAir, ModExpInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{less_than_witness, limb_product_witness, to_bits, to_limbs},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct ModExpProver<B: ExampleField> {
+    options: ProofOptions,
+    pub_inputs: ModExpInputs,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> ModExpProver<B> {
+    pub fn new(options: ProofOptions, pub_inputs: ModExpInputs) -> Self {
+        Self {
+            options,
+            pub_inputs,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace with one cycle per message, in which row `i` holds the power of the
+    /// message for the bits of the exponent before bit `i`, the message squared `i` times, and
+    /// the witnesses of the modular multiplications which lead to the next row.
+    pub fn build_trace(exponent: u64, messages: &[u64]) -> TraceTable<B> {
+        let exponent_bits = get_exponent_bits::<B>(exponent);
+        let mut trace = TraceTable::new(TRACE_WIDTH, messages.len() * CYCLE_LENGTH);
+        let mut step = 0;
+        for &message in messages {
+            let (mut acc, mut base) = (1, message);
+            for &bit in exponent_bits.iter() {
+                let factor = if bit == B::ONE { base } else { 1 };
+                let (acc_quotient, next_acc, acc_carry) =
+                    limb_product_witness(acc, factor, MODULUS, LIMB_BITS);
+                let (base_quotient, next_base, base_carry) =
+                    limb_product_witness(base, base, MODULUS, LIMB_BITS);
+
+                set_residue(&mut trace, step, ACC, ACC_BITS, ACC_SLACK_BITS, acc);
+                set_residue(&mut trace, step, BASE, BASE_BITS, BASE_SLACK_BITS, base);
+                set_bits(
+                    &mut trace,
+                    step,
+                    ACC_QUOTIENT_BITS,
+                    acc_quotient,
+                    RESIDUE_BITS,
+                );
+                set_bits(
+                    &mut trace,
+                    step,
+                    ACC_CARRY_BITS,
+                    offset(acc_carry),
+                    CARRY_BITS,
+                );
+                set_bits(
+                    &mut trace,
+                    step,
+                    BASE_QUOTIENT_BITS,
+                    base_quotient,
+                    RESIDUE_BITS,
+                );
+                set_bits(
+                    &mut trace,
+                    step,
+                    BASE_CARRY_BITS,
+                    offset(base_carry),
+                    CARRY_BITS,
+                );
+
+                acc = next_acc;
+                base = next_base;
+                step += 1;
+            }
+        }
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for ModExpProver<B> {
+    type BaseField = B;
+    type Air = ModExpAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> ModExpInputs {
+        self.pub_inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the limbs of a residue into the two columns from `column` on, its bits into the
+/// columns from `bits` on, and the bits of its slack below the modulus into those from `slack`
+/// on.
+fn set_residue<B: ExampleField>(
+    trace: &mut TraceTable<B>,
+    step: usize,
+    column: usize,
+    bits: usize,
+    slack: usize,
+    value: u64,
+) {
+    for (i, &limb) in to_limbs(value, LIMB_BITS).iter().enumerate() {
+        trace.set(column + i, step, B::from(limb));
+    }
+    let (_, difference) = less_than_witness(value, MODULUS, RESIDUE_BITS);
+    set_bits(trace, step, bits, value, RESIDUE_BITS);
+    set_bits(trace, step, slack, difference, RESIDUE_BITS);
+}
+
+/// Writes the `num_bits` least significant bits of `value` into the columns from `column` on.
+fn set_bits<B: ExampleField>(
+    trace: &mut TraceTable<B>,
+    step: usize,
+    column: usize,
+    value: u64,
+    num_bits: usize,
+) {
+    for (i, bit) in to_bits(value, num_bits).enumerate() {
+        trace.set(column + i, step, bit);
+    }
+}
+
+/// Returns a carry of the limb product gadget offset into its non-negative range.
+fn offset(carry: i64) -> u64 {
+    (carry + (1 << LIMB_BITS)) as u64
+}
@@ -0,0 +1,201 @@
+use super::{
+    air::{
+        ACC, BASE, BASE_BITS, BASE_CARRY_BITS, BASE_QUOTIENT_BITS, CARRY_BITS, FACTORS, LIMB_BITS,
+        MODULUS, RESIDUE_BITS, TRACE_WIDTH,
+    },
+    mod_pow, ModExpExample, ModExpInputs, ModExpProver,
+};
+use crate::{
+    config::ModExpConfig,
+    constraints, degrees,
+    example::{self, Example},
+    verify::verify_proof,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, FieldExtension, HashFunction, ProofOptions, Prover, Trace, TraceTable,
+};
+
+#[test]
+fn modexp_unreduced_ciphertext_is_rejected() {
+    // the limbs of a ciphertext are only asserted, so one above the modulus is rejected when the
+    // public inputs are read
+    let report = example::run(&build_example(), build_options());
+    let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+    pub_inputs_bytes[12..20].copy_from_slice(&MODULUS.to_le_bytes());
+    assert!(verify_proof("modexp", &report.proof_bytes, &pub_inputs_bytes).is_err());
+}
+
+#[test]
+fn modexp_constraint_degrees_match_declared_for_irregular_exponent() {
+    // unlike those of 65537, the bits of 11 do not repeat within the cycle
+    let example = ModExpExample::<BaseElement>::new(&ModExpConfig {
+        exponent: 11,
+        num_messages: 2,
+        ..ModExpConfig::default()
+    })
+    .unwrap();
+    let report = degrees::check_degrees(&example, build_options(), 0);
+    assert!(report.is_consistent(), "{}", report);
+}
+
+#[test]
+fn modexp_ciphertexts_decrypt_with_private_exponent() {
+    // the private exponent inverts the public one modulo (p - 1) * (q - 1), as in RSA
+    let config = ModExpConfig::default();
+    let totient = (FACTORS[0] - 1) * (FACTORS[1] - 1);
+    let private_exponent = (1..totient)
+        .find(|&d| (d as u128 * config.exponent as u128) % totient as u128 == 1)
+        .unwrap();
+    let example = ModExpExample::<BaseElement>::new(&config).unwrap();
+    for (&message, &ciphertext) in example.messages.iter().zip(&example.ciphertexts) {
+        assert_eq!(message, mod_pow(ciphertext, private_exponent, MODULUS));
+    }
+}
+
+#[test]
+fn modexp_trace_applies_exponent_bits() {
+    // 5 = 0b101 multiplies in 3 and 3^4, and the next cycle starts over at one
+    let trace = ModExpProver::<BaseElement>::build_trace(5, &[3, 7]);
+    let residue = |column, step| {
+        trace.get(column, step) + trace.get(column + 1, step) * BaseElement::from(1u64 << 16)
+    };
+    let accumulators = (0..4).map(|step| residue(ACC, step)).collect::<Vec<_>>();
+    let bases = (0..4).map(|step| residue(BASE, step)).collect::<Vec<_>>();
+    assert_eq!(
+        [1u32, 3, 3, 243].map(BaseElement::from).to_vec(),
+        accumulators
+    );
+    assert_eq!([3u32, 9, 81, 6561].map(BaseElement::from).to_vec(), bases);
+    assert_eq!(BaseElement::from(243u8), residue(ACC, 31));
+    assert_eq!(BaseElement::ONE, residue(ACC, 32));
+    assert_eq!(BaseElement::from(16807u16), residue(ACC, 63));
+}
+
+#[test]
+fn modexp_unreduced_residue_is_rejected() {
+    // 65535^2 = 4294836225 is below 2^32, so the square can be left unreduced with a quotient
+    // of zero; the multiplication gadget alone accepts it, and only the range check of the base
+    // below the modulus rejects it
+    let messages = [65535, 7];
+    let mut trace = ModExpProver::<BaseElement>::build_trace(3, &messages);
+    let air = build_air(&trace, 3, &messages);
+    constraints::assert_constraints_hold(&air, &trace);
+
+    let unreduced = 65535 * 65535;
+    let carry = (65535 * 65535 - (unreduced & 0xffff)) >> LIMB_BITS;
+    set_bits(&mut trace, BASE_QUOTIENT_BITS, 0, 0, RESIDUE_BITS);
+    set_bits(
+        &mut trace,
+        BASE_CARRY_BITS,
+        0,
+        carry + (1 << 16),
+        CARRY_BITS,
+    );
+    trace.set(BASE, 1, BaseElement::from(unreduced & 0xffff));
+    trace.set(BASE + 1, 1, BaseElement::from(unreduced >> LIMB_BITS));
+    set_bits(&mut trace, BASE_BITS, 1, unreduced, RESIDUE_BITS);
+
+    let result = constraints::evaluate_transition_at(&air, &trace, 0);
+    assert!(result.iter().all(|&value| value == BaseElement::ZERO));
+    // the range checks below the modulus follow a constraint for every column
+    constraints::assert_constraints_fail_at(&air, &trace, 1, TRACE_WIDTH + 1);
+}
+
+#[test]
+fn modexp_invalid_settings_are_rejected() {
+    let config = ModExpConfig {
+        num_messages: 3,
+        ..ModExpConfig::default()
+    };
+    let err = ModExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(
+        err.starts_with("number of messages must be a power of two, but modexp.num_messages"),
+        "{}",
+        err
+    );
+
+    let config = ModExpConfig {
+        exponent: 1 << 31,
+        ..ModExpConfig::default()
+    };
+    let err = ModExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(err.contains("modexp.exponent is 2147483648"), "{}", err);
+
+    // 65521 - 1 = 2^4 * 3^2 * 5 * 7 * 13
+    let config = ModExpConfig {
+        exponent: 7,
+        ..ModExpConfig::default()
+    };
+    let err = ModExpExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(err.starts_with("exponent must be coprime"), "{}", err);
+}
+
+#[test]
+fn modexp_proof_under_another_exponent_is_rejected() {
+    // the verifier derives the bits of the exponent from the public inputs
+    let example = build_example();
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    let pub_inputs = ModExpInputs {
+        exponent: 3,
+        ..example.pub_inputs()
+    };
+    assert!(
+        winterfell::verify::<<ModExpExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> ModExpExample<BaseElement> {
+    ModExpExample::new(&ModExpConfig {
+        num_messages: 2,
+        ..ModExpConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+fn build_air(
+    trace: &TraceTable<BaseElement>,
+    exponent: u64,
+    messages: &[u64],
+) -> <ModExpExample<BaseElement> as Example>::Air {
+    let pub_inputs = ModExpInputs {
+        exponent,
+        ciphertexts: messages
+            .iter()
+            .map(|&message| mod_pow(message, exponent, MODULUS))
+            .collect(),
+    };
+    <ModExpExample<BaseElement> as Example>::Air::new(trace.get_info(), pub_inputs, build_options())
+}
+
+/// Writes the `num_bits` least significant bits of `value` into the columns from `column` on.
+fn set_bits(
+    trace: &mut TraceTable<BaseElement>,
+    column: usize,
+    step: usize,
+    value: u64,
+    num_bits: usize,
+) {
+    for i in 0..num_bits {
+        trace.set(column + i, step, BaseElement::from((value >> i) & 1));
+    }
+}
@@ -0,0 +1,342 @@
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Log target of the winterfell prover.
+const PROVER_TARGET: &str = "winter_prover";
+
+/// Set once a [PhaseLogger] has been installed as the global logger.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Index in [ProofPhase::ALL] of the phase the prover has completed last, plus one; zero while
+/// no proof is being recorded or before its first phase is complete.
+static LAST_COMPLETED: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+// PROOF PHASES
+// ================================================================================================
+
+/// A phase of proof generation in the winterfell prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofPhase {
+    /// Instantiating the AIR and building the evaluation domain; in debug builds this also
+    /// includes checking the trace against the AIR.
+    Setup,
+    /// Interpolating the trace columns and evaluating them over the LDE domain.
+    TraceLde,
+    /// Building a Merkle tree over the extended trace.
+    TraceCommitment,
+    /// Evaluating the transition and boundary constraints over the constraint domain.
+    ConstraintEvaluation,
+    /// Building, extending, and committing to the constraint composition polynomial.
+    Composition,
+    /// Building and evaluating the DEEP composition polynomial.
+    DeepComposition,
+    /// Computing and committing to the FRI layers.
+    Fri,
+    /// Grinding the query seed, drawing query positions, and assembling the proof.
+    Queries,
+}
+
+impl ProofPhase {
+    /// All phases in the order in which the prover goes through them.
+    pub const ALL: [ProofPhase; 8] = [
+        ProofPhase::Setup,
+        ProofPhase::TraceLde,
+        ProofPhase::TraceCommitment,
+        ProofPhase::ConstraintEvaluation,
+        ProofPhase::Composition,
+        ProofPhase::DeepComposition,
+        ProofPhase::Fri,
+        ProofPhase::Queries,
+    ];
+
+    /// Returns a short description of the phase.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProofPhase::Setup => "setup",
+            ProofPhase::TraceLde => "trace LDE",
+            ProofPhase::TraceCommitment => "trace commitment",
+            ProofPhase::ConstraintEvaluation => "constraint evaluation",
+            ProofPhase::Composition => "composition polynomial",
+            ProofPhase::DeepComposition => "DEEP composition",
+            ProofPhase::Fri => "FRI",
+            ProofPhase::Queries => "grinding and queries",
+        }
+    }
+
+    /// Returns the name under which the phase is serialized.
+    fn key(&self) -> &'static str {
+        match self {
+            ProofPhase::Setup => "setup_ms",
+            ProofPhase::TraceLde => "trace_lde_ms",
+            ProofPhase::TraceCommitment => "trace_commitment_ms",
+            ProofPhase::ConstraintEvaluation => "constraint_evaluation_ms",
+            ProofPhase::Composition => "composition_ms",
+            ProofPhase::DeepComposition => "deep_composition_ms",
+            ProofPhase::Fri => "fri_ms",
+            ProofPhase::Queries => "queries_ms",
+        }
+    }
+
+    /// Returns the phase which the prover has just completed when it logs the message.
+    fn completed_by(message: &str) -> Option<Self> {
+        const PREFIXES: [(&str, ProofPhase); 12] = [
+            ("Built domain", ProofPhase::Setup),
+            ("Extended execution trace", ProofPhase::TraceLde),
+            (
+                "Committed to extended execution trace",
+                ProofPhase::TraceCommitment,
+            ),
+            ("Evaluated constraints", ProofPhase::ConstraintEvaluation),
+            ("Converted constraint evaluations", ProofPhase::Composition),
+            ("Evaluated composition polynomial", ProofPhase::Composition),
+            ("Committed to composed evaluations", ProofPhase::Composition),
+            (
+                "Built DEEP composition polynomial",
+                ProofPhase::DeepComposition,
+            ),
+            (
+                "Evaluated DEEP composition polynomial",
+                ProofPhase::DeepComposition,
+            ),
+            ("Computed", ProofPhase::Fri),
+            ("Determined", ProofPhase::Queries),
+            ("Built proof object", ProofPhase::Queries),
+        ];
+        PREFIXES
+            .iter()
+            .find(|(prefix, _)| message.starts_with(prefix))
+            .map(|&(_, phase)| phase)
+    }
+}
+
+impl fmt::Display for ProofPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// PHASE TIMINGS
+// ================================================================================================
+
+/// Time the prover spent in each phase of generating a single proof.
+///
+/// When serialized, durations are written as fractional milliseconds keyed by phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseTimings {
+    durations: [Duration; ProofPhase::ALL.len()],
+}
+
+impl PhaseTimings {
+    /// Returns the time spent in the specified phase.
+    pub fn get(&self, phase: ProofPhase) -> Duration {
+        self.durations[phase as usize]
+    }
+
+    /// Returns the time spent in all phases together.
+    pub fn total(&self) -> Duration {
+        self.durations.iter().sum()
+    }
+
+    /// Returns the phase in which the prover spent the most time.
+    pub fn dominant(&self) -> ProofPhase {
+        ProofPhase::ALL
+            .iter()
+            .copied()
+            .max_by_key(|&phase| self.get(phase))
+            .unwrap()
+    }
+}
+
+impl fmt::Display for PhaseTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.total().as_secs_f64().max(f64::MIN_POSITIVE);
+        for (i, &phase) in ProofPhase::ALL.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let duration = self.get(phase);
+            write!(
+                f,
+                "  {:<24}{:>10.1} ms {:>5.1}%",
+                format!("{}:", phase),
+                duration.as_micros() as f64 / 1000f64,
+                100f64 * duration.as_secs_f64() / total
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for PhaseTimings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(ProofPhase::ALL.len()))?;
+        for phase in ProofPhase::ALL {
+            map.serialize_entry(phase.key(), &(self.get(phase).as_micros() as f64 / 1000f64))?;
+        }
+        map.end()
+    }
+}
+
+// RECORDING
+// ================================================================================================
+
+/// Calls `f` and returns its result together with the time the winterfell prover spent in each
+/// phase of the proofs generated by `f` on the current thread.
+///
+/// The prover reports a phase by logging a message once the phase is complete, so the phase is
+/// timed from the previous message, or from the call to `f` for the first phase. Timings are
+/// only available if a [PhaseLogger] is installed; otherwise, `None` is returned.
+pub fn record<R>(f: impl FnOnce() -> R) -> (R, Option<PhaseTimings>) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    RECORDING.with(|recording| {
+        *recording.borrow_mut() = Some(Recording {
+            last_message: Instant::now(),
+            timings: PhaseTimings::default(),
+        })
+    });
+    set_last_completed(None);
+    let result = f();
+    set_last_completed(None);
+    let timings = RECORDING.with(|recording| recording.borrow_mut().take());
+
+    (result, timings.map(|recording| recording.timings))
+}
+
+/// Returns the phase which the prover has completed last while [record()] runs on any thread;
+/// `None` if no proof is being recorded or none of its phases is complete yet.
+pub fn last_completed() -> Option<ProofPhase> {
+    match LAST_COMPLETED.load(Ordering::Relaxed) {
+        0 => None,
+        index => Some(ProofPhase::ALL[index - 1]),
+    }
+}
+
+fn set_last_completed(phase: Option<ProofPhase>) {
+    LAST_COMPLETED.store(
+        phase.map_or(0, |phase| phase as usize + 1),
+        Ordering::Relaxed,
+    );
+}
+
+struct Recording {
+    last_message: Instant,
+    timings: PhaseTimings,
+}
+
+// PHASE LOGGER
+// ================================================================================================
+
+/// A logger which times the phases of the winterfell prover while [record()] is running, and
+/// passes all records on to another logger.
+pub struct PhaseLogger {
+    inner: Box<dyn Log>,
+}
+
+impl PhaseLogger {
+    /// Installs a phase logger wrapping `inner` as the global logger; `level` is the most
+    /// verbose level which `inner` logs at.
+    ///
+    /// The global maximum level is raised to debug, at which the prover reports its phases, but
+    /// records which `inner` does not accept are dropped as before.
+    pub fn install(inner: Box<dyn Log>, level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_boxed_logger(Box::new(PhaseLogger { inner }))?;
+        log::set_max_level(level.max(LevelFilter::Debug));
+        INSTALLED.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl Log for PhaseLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with(PROVER_TARGET) || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target().starts_with(PROVER_TARGET) {
+            RECORDING.with(|recording| {
+                if let Some(recording) = recording.borrow_mut().as_mut() {
+                    if let Some(phase) = ProofPhase::completed_by(&record.args().to_string()) {
+                        let now = Instant::now();
+                        recording.timings.durations[phase as usize] += now - recording.last_message;
+                        recording.last_message = now;
+                        set_last_completed(Some(phase));
+                    }
+                }
+            });
+        }
+        if self.inner.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{PhaseLogger, ProofPhase};
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn report_breaks_down_prover_time_by_phase() {
+        use log::{LevelFilter, Log, Metadata, Record};
+        use std::time::Duration;
+
+        struct SilentLogger;
+
+        impl Log for SilentLogger {
+            fn enabled(&self, _metadata: &Metadata) -> bool {
+                false
+            }
+            fn log(&self, _record: &Record) {}
+            fn flush(&self) {}
+        }
+
+        // a global logger can only be installed once per process
+        let _ = PhaseLogger::install(Box::new(SilentLogger), LevelFilter::Off);
+
+        let report = example::run(&vdf_example(), vdf_options());
+        let phases = report
+            .prover_phases
+            .as_ref()
+            .expect("prover phases were not recorded");
+        assert!(phases.total() <= report.prover_time);
+        for phase in [
+            ProofPhase::TraceLde,
+            ProofPhase::ConstraintEvaluation,
+            ProofPhase::Composition,
+            ProofPhase::Fri,
+        ] {
+            assert!(
+                phases.get(phase) > Duration::ZERO,
+                "{} was not timed",
+                phase
+            );
+        }
+        assert!(report.to_string().contains("constraint evaluation:"));
+    }
+}
@@ -0,0 +1,353 @@
+//! Examples defined by other crates.
+//!
+//! The tooling of this crate is generic over the [Example] trait, but the examples it runs are
+//! selected by name, and the names are matched against the examples of this crate (see
+//! [with_example()](crate::with_example)). A crate which defines an AIR and a prover of its own
+//! wraps them in an [Example], registers an instance with [register_example()], and then calls
+//! [cli::main()](crate::cli::main) as the binary of this crate does; the example can then be
+//! selected by the name in its [ExampleDescriptor] wherever a config names an example:
+//!
+//! ```ignore
+//! fn main() {
+//!     stark_examples::plugins::register_example(Box::new(MyExample::new()))
+//!         .expect("example names are unique");
+//!     stark_examples::cli::main()
+//! }
+//! ```
+//!
+//! [Example] has associated types and generic methods, so a registered example is held as a
+//! [DynExample], an object-safe view of it which every [Example] implements. Through it,
+//! registered examples can be run, proven into proof files, verified, listed, described,
+//! benchmarked, and checked with `run --tamper`, `--check-constraints`, and `--check-trace`.
+//! Settings which are read from the config section of an example, such as its size or seed, apply
+//! to the examples of this crate only; a registered example is an instance whose inputs and base
+//! field are fixed when it is registered, so it cannot be swept over trace lengths or fields.
+
+#[cfg(feature = "concurrent")]
+use crate::error::ExampleError;
+use crate::{
+    bench::{self, Repetitions},
+    config::ProofOptionsConfig,
+    constraints,
+    degrees::{self, DegreeBounds},
+    describe::{self, AirDescription, AirMetadata},
+    emulator,
+    example::{self, Example, ExampleDescriptor, PublicInputs, RunReport},
+    tamper,
+    verify::VerifyError,
+    verify_core, EXAMPLES,
+};
+use std::sync::{Arc, Mutex};
+use winterfell::{Deserializable, ProofOptions, Trace};
+
+// DYNAMIC EXAMPLE
+// ================================================================================================
+
+/// An object-safe view of an [Example], with the operations of the tooling which do not need
+/// its concrete type.
+pub trait DynExample: Send + Sync {
+    /// Returns the descriptor of the example, whose name selects it in a config.
+    fn descriptor(&self) -> ExampleDescriptor;
+
+    /// Returns the names of the columns and transition constraints of the AIR.
+    fn metadata(&self) -> AirMetadata;
+
+    /// Returns the degree bounds of the transition constraints; see
+    /// [degrees::degree_bounds()]. The execution trace is built to find its shape.
+    fn degree_bounds(&self) -> DegreeBounds;
+
+    /// Proves and verifies the example; see [example::run()].
+    fn run(&self, options: ProofOptions) -> RunReport;
+
+    /// Verifies a bare serialized proof of the example against serialized public inputs.
+    fn verify(&self, proof_bytes: &[u8], pub_inputs_bytes: &[u8]) -> Result<(), VerifyError>;
+
+    /// Checks that tampered proofs, traces, and public inputs are rejected, and returns the
+    /// number which were accepted; see [tamper::run()].
+    fn tamper(&self, options: ProofOptions) -> usize;
+
+    /// Evaluates the constraints of the AIR over the execution trace; see
+    /// [constraints::check_constraints()].
+    fn check_constraints(&self, options: ProofOptions) -> Result<CheckOutcome, String>;
+
+    /// Compares the execution trace against the emulator of the example; see
+    /// [emulator::check_trace()].
+    fn check_trace(&self) -> Result<CheckOutcome, String>;
+
+    /// Describes the columns, transition constraints, and boundary assertions of the AIR; see
+    /// [describe::describe()].
+    fn describe(&self, options: ProofOptions) -> Result<AirDescription, String>;
+
+    /// Proves the example for every grinding factor and prints the measurements; see
+    /// [bench::grinding_sweep()].
+    fn grinding_sweep(
+        &self,
+        options: &ProofOptionsConfig,
+        grinding_factors: &[u32],
+        repetitions: Repetitions,
+    );
+
+    /// Proves the example with up to `max_threads` threads and prints the measurements; see
+    /// [bench::thread_sweep()].
+    #[cfg(feature = "concurrent")]
+    fn thread_sweep(
+        &self,
+        options: &ProofOptionsConfig,
+        max_threads: usize,
+        repetitions: Repetitions,
+    ) -> Result<(), ExampleError>;
+
+    /// Proves the example once and prints the size of the proof in every encoding; see
+    /// [bench::compression_comparison()].
+    #[cfg(feature = "compression")]
+    fn compression_comparison(&self, options: &ProofOptionsConfig);
+}
+
+impl<E> DynExample for E
+where
+    E: Example + Send + Sync,
+    PublicInputs<E>: Deserializable,
+{
+    fn descriptor(&self) -> ExampleDescriptor {
+        E::descriptor()
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        Example::metadata(self)
+    }
+
+    fn degree_bounds(&self) -> DegreeBounds {
+        degrees::degree_bounds(self, self.build_trace().get_info())
+    }
+
+    fn run(&self, options: ProofOptions) -> RunReport {
+        example::run(self, options)
+    }
+
+    fn verify(&self, proof_bytes: &[u8], pub_inputs_bytes: &[u8]) -> Result<(), VerifyError> {
+        verify_core::verify_air::<E::Air>(proof_bytes, pub_inputs_bytes)
+    }
+
+    fn tamper(&self, options: ProofOptions) -> usize {
+        tamper::run(self, options)
+    }
+
+    fn check_constraints(&self, options: ProofOptions) -> Result<CheckOutcome, String> {
+        let check = constraints::check_constraints(self, options)?;
+        Ok(CheckOutcome {
+            passed: check.is_satisfied(),
+            summary: check.to_string(),
+        })
+    }
+
+    fn check_trace(&self) -> Result<CheckOutcome, String> {
+        let check = emulator::check_trace(self)?;
+        Ok(CheckOutcome {
+            passed: check.is_match(),
+            summary: check.to_string(),
+        })
+    }
+
+    fn describe(&self, options: ProofOptions) -> Result<AirDescription, String> {
+        describe::describe(E::descriptor().name, self, options)
+    }
+
+    fn grinding_sweep(
+        &self,
+        options: &ProofOptionsConfig,
+        grinding_factors: &[u32],
+        repetitions: Repetitions,
+    ) {
+        bench::grinding_sweep(self, options, grinding_factors, repetitions)
+    }
+
+    #[cfg(feature = "concurrent")]
+    fn thread_sweep(
+        &self,
+        options: &ProofOptionsConfig,
+        max_threads: usize,
+        repetitions: Repetitions,
+    ) -> Result<(), ExampleError> {
+        bench::thread_sweep(self, options, max_threads, repetitions)
+    }
+
+    #[cfg(feature = "compression")]
+    fn compression_comparison(&self, options: &ProofOptionsConfig) {
+        bench::compression_comparison(self, options)
+    }
+}
+
+/// Outcome of a check of a registered example, whose result types depend on the base field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckOutcome {
+    pub passed: bool,
+    /// The result of the check as it is printed.
+    pub summary: String,
+}
+
+// REGISTRY
+// ================================================================================================
+
+static REGISTERED: Mutex<Vec<Arc<dyn DynExample>>> = Mutex::new(Vec::new());
+
+/// Registers an example under the name in its descriptor.
+///
+/// Returns an error if an example of this crate or another registered example has the same
+/// name.
+pub fn register_example(example: Box<dyn DynExample>) -> Result<(), String> {
+    let name = example.descriptor().name;
+    if EXAMPLES.contains(&name) {
+        return Err(format!(
+            "'{}' is the name of an example of this crate",
+            name
+        ));
+    }
+    let mut registered = lock_registered();
+    if registered
+        .iter()
+        .any(|other| other.descriptor().name == name)
+    {
+        return Err(format!("an example named '{}' is already registered", name));
+    }
+    registered.push(Arc::from(example));
+    Ok(())
+}
+
+/// Returns the registered example with the specified name, if any.
+pub fn registered_example(name: &str) -> Option<Arc<dyn DynExample>> {
+    lock_registered()
+        .iter()
+        .find(|example| example.descriptor().name == name)
+        .cloned()
+}
+
+/// Returns the names of the registered examples, in the order they were registered.
+pub fn registered_names() -> Vec<&'static str> {
+    lock_registered()
+        .iter()
+        .map(|example| example.descriptor().name)
+        .collect()
+}
+
+/// Returns the names of the examples of this crate followed by those of the registered ones.
+pub fn example_names() -> Vec<&'static str> {
+    EXAMPLES.iter().copied().chain(registered_names()).collect()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn lock_registered() -> std::sync::MutexGuard<'static, Vec<Arc<dyn DynExample>>> {
+    // registration cannot panic halfway, so the list is consistent even if a lock was poisoned
+    REGISTERED.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bench::{self, Repetitions},
+        config::RunConfig,
+        describe::{self, AirMetadata},
+        error::ExampleError,
+        example::{Example, ExampleDescriptor, PublicInputs},
+        field::FieldType,
+        proof_file::ProofFile,
+        run_example,
+        test_utils::{vdf_example, vdf_options},
+        vdf::VdfExample,
+        verify::verify_proof,
+    };
+    use winterfell::{math::fields::f128::BaseElement, ProofOptions, TraceTable};
+
+    #[test]
+    fn registered_example_runs_and_verifies() {
+        super::register_example(Box::new(PluginVdf(vdf_example()))).unwrap();
+        let err = super::register_example(Box::new(PluginVdf(vdf_example()))).unwrap_err();
+        assert!(err.contains("already registered"), "{}", err);
+        let err = super::register_example(Box::new(vdf_example())).unwrap_err();
+        assert!(err.contains("example of this crate"), "{}", err);
+
+        let config = RunConfig {
+            example: "vdf-plugin".to_string(),
+            ..RunConfig::default()
+        };
+        let report = run_example(&config).unwrap();
+        assert!(report.verification_error.is_none());
+        verify_proof("vdf-plugin", &report.proof_bytes, &report.pub_inputs_bytes).unwrap();
+        let file = ProofFile::new("vdf-plugin", report.proof_bytes.clone()).unwrap();
+        verify_proof("vdf-plugin", &file.to_bytes(), &report.pub_inputs_bytes).unwrap();
+        // the proof is also a valid proof of the vdf example, but not under another name
+        assert!(verify_proof("vdf", &file.to_bytes(), &report.pub_inputs_bytes).is_err());
+
+        let registered = super::registered_example("vdf-plugin").unwrap();
+        assert_eq!(registered.tamper(vdf_options()), 0);
+        assert!(registered.check_constraints(vdf_options()).unwrap().passed);
+        // the vdf example has no emulator to check its trace against
+        assert!(registered.check_trace().is_err());
+        let description = registered.describe(vdf_options()).unwrap();
+        assert_eq!(description.example, "vdf-plugin");
+        assert_eq!(description.columns.len(), 1);
+        // the size and the field of a registered example are fixed
+        let err = bench::field_sweep(
+            &config,
+            &["vdf-plugin".to_string()],
+            &[FieldType::F64],
+            Repetitions::SINGLE,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExampleError::Config(_)), "{}", err);
+        assert!(
+            err.to_string().contains("registered by another crate"),
+            "{}",
+            err
+        );
+
+        let summaries = describe::list_examples().unwrap();
+        let summary = summaries.last().unwrap();
+        assert_eq!(summary.descriptor.name, "vdf-plugin");
+        assert_eq!((summary.trace_width, summary.num_constraints), (1, 1));
+        assert_eq!(
+            (3, 4),
+            (
+                summary.degrees.max_degree,
+                summary.degrees.min_blowup_factor
+            )
+        );
+    }
+
+    /// The vdf example under another name, as a crate defining an example of its own would
+    /// register it.
+    struct PluginVdf(VdfExample<BaseElement>);
+
+    impl Example for PluginVdf {
+        type Air = <VdfExample<BaseElement> as Example>::Air;
+        type Prover = <VdfExample<BaseElement> as Example>::Prover;
+
+        fn build_trace(&self) -> TraceTable<BaseElement> {
+            self.0.build_trace()
+        }
+
+        fn descriptor() -> ExampleDescriptor {
+            ExampleDescriptor::new("vdf-plugin", "the vdf example registered as a plugin")
+        }
+
+        fn metadata(&self) -> AirMetadata {
+            self.0.metadata()
+        }
+
+        fn prover(&self, options: ProofOptions) -> Self::Prover {
+            self.0.prover(options)
+        }
+
+        fn pub_inputs(&self) -> PublicInputs<Self> {
+            self.0.pub_inputs()
+        }
+
+        fn wrong_pub_inputs(&self) -> PublicInputs<Self> {
+            self.0.wrong_pub_inputs()
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::PoseidonConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    trace::TraceSteps,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{PoseidonAir, PoseidonInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::{PoseidonProver, PoseidonTrace};
+
+#[allow(clippy::module_inception)]
+pub(crate) mod poseidon;
+use poseidon::Poseidon;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const CYCLE_LENGTH: usize = poseidon::CYCLE_LENGTH;
+
+// POSEIDON HASH CHAIN EXAMPLE
+// ================================================================================================
+
+/// Proves that the result was obtained by applying the Poseidon hash function `chain_length`
+/// times starting from a public seed; every hash invocation occupies one cycle of
+/// [CYCLE_LENGTH] rows in the execution trace, and periodic columns tell the constraints which
+/// rows of the cycle hold full rounds and which hold partial rounds.
+#[cfg(feature = "std")]
+pub struct PoseidonExample<B: ExampleField> {
+    chain_length: usize,
+    seed: [B; 2],
+    result: [B; 2],
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> PoseidonExample<B> {
+    pub fn new(config: &PoseidonConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let seed = [B::from(config.seed[0]), B::from(config.seed[1])];
+        Ok(Self::with_seed(seed, config.chain_length))
+    }
+
+    /// Returns an example of a hash chain which starts from an arbitrary pair of field
+    /// elements, such as the digest proven by another example.
+    pub fn with_seed(seed: [B; 2], chain_length: usize) -> Self {
+        // compute result
+        let now = Instant::now();
+        let result = compute_hash_chain(seed, chain_length);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Self {
+            chain_length,
+            seed,
+            result,
+        }
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &PoseidonConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "chain length",
+            "poseidon.chain_length",
+            config.chain_length,
+            1,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for PoseidonExample<B> {
+    type Air = PoseidonAir<B>;
+    type Prover = PoseidonProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        PoseidonProver::build_trace(self.seed, self.chain_length)
+    }
+
+    fn trace_steps(&self) -> Option<Box<dyn TraceSteps<B> + '_>> {
+        Some(Box::new(PoseidonTrace::new(self.seed, self.chain_length)))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = PoseidonConfig::default();
+        ExampleDescriptor::new(
+            "poseidon",
+            "a hash chain which applies the Poseidon permutation to a public seed",
+        )
+        .with_default("chain_length", config.chain_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("STATE", poseidon::STATE_WIDTH)],
+            constraints: vec![("Poseidon round or copy", poseidon::STATE_WIDTH)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> PoseidonProver<B> {
+        PoseidonProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> PoseidonInputs<B> {
+        PoseidonInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> PoseidonInputs<B> {
+        PoseidonInputs {
+            seed: self.seed,
+            result: [self.result[0] + B::ONE, self.result[1]],
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compute_hash_chain<B: ExampleField>(seed: [B; 2], length: usize) -> [B; 2] {
+    let poseidon = Poseidon::new();
+    let mut values = seed;
+    let mut result = [B::ZERO; 2];
+    for _ in 0..length {
+        poseidon.hash(values, &mut result);
+        values.copy_from_slice(&result);
+    }
+    result
+}
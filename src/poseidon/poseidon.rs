@@ -0,0 +1,199 @@
+use crate::field::ExampleField;
+use winter_utils::collections::Vec;
+use winterfell::{
+    crypto::{hashers::Blake3_256, Digest, Hasher},
+    math::FieldElement,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of field elements in the Poseidon state.
+pub const STATE_WIDTH: usize = 3;
+
+/// Number of field elements absorbed and squeezed by a single hash invocation.
+pub const RATE_WIDTH: usize = 2;
+
+/// Number of full rounds; half of them are applied before the partial rounds, and half after.
+pub const NUM_FULL_ROUNDS: usize = 8;
+
+/// Number of partial rounds, in which the S-box is applied to the first state element only.
+pub const NUM_PARTIAL_ROUNDS: usize = 55;
+
+/// Number of rounds in the Poseidon permutation.
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+
+/// Number of trace rows consumed by one permutation: a row per round plus a row for resetting
+/// the capacity portion of the state before the next invocation.
+pub const CYCLE_LENGTH: usize = NUM_ROUNDS + 1;
+
+// POSEIDON PERMUTATION
+// ================================================================================================
+
+/// Poseidon permutation over a 3-element state instantiated in the base field `B`.
+///
+/// Each round applies the S-box `x -> x^alpha`, with alpha set to [ExampleField::ALPHA], to
+/// every state element in full rounds and to the first element only in partial rounds, then
+/// multiplies the state by the MDS matrix, and finally adds the round constants. Adding the
+/// constants at the end of a round rather than at its start keeps them out of the S-box, so
+/// that they enter the transition constraints linearly.
+pub struct Poseidon<B: ExampleField> {
+    mds: [[B; STATE_WIDTH]; STATE_WIDTH],
+    ark: [[B; STATE_WIDTH]; NUM_ROUNDS],
+}
+
+impl<B: ExampleField> Poseidon<B> {
+    pub fn new() -> Self {
+        // the Cauchy matrix 1 / (x_i + y_j) with x = [0, 1, 2] and y = [3, 4, 5]; all of its
+        // square sub-matrices are non-singular, and thus, it is MDS over each of the supported
+        // fields
+        let mut mds = [[B::ZERO; STATE_WIDTH]; STATE_WIDTH];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = B::from((i + STATE_WIDTH + j) as u64).inv();
+            }
+        }
+
+        // round constants are the first 16 bytes of BLAKE3("poseidon-ark-{round}-{element}")
+        // read as a little-endian integer and reduced modulo the field prime
+        let mut ark = [[B::ZERO; STATE_WIDTH]; NUM_ROUNDS];
+        for (round, constants) in ark.iter_mut().enumerate() {
+            for (i, constant) in constants.iter_mut().enumerate() {
+                let label = format!("poseidon-ark-{}-{}", round, i);
+                let digest = Blake3_256::<B>::hash(label.as_bytes()).as_bytes();
+                let mut bytes = [0u8; 16];
+                bytes.copy_from_slice(&digest[..16]);
+                *constant = B::from(u128::from_le_bytes(bytes));
+            }
+        }
+
+        Self { mds, ark }
+    }
+
+    // PARAMETERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the constants which are added to the state at the end of the specified round.
+    pub fn round_constants(&self, round: usize) -> &[B; STATE_WIDTH] {
+        &self.ark[round]
+    }
+
+    /// Returns true if the S-box is applied to the entire state in the specified round.
+    pub fn is_full_round(round: usize) -> bool {
+        !(NUM_FULL_ROUNDS / 2..NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS).contains(&round)
+    }
+
+    // HASH FUNCTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies all rounds of the permutation to the state.
+    pub fn permute(&self, state: &mut [B; STATE_WIDTH]) {
+        for round in 0..NUM_ROUNDS {
+            self.apply_round(state, round);
+        }
+    }
+
+    /// Hashes two field elements into two field elements.
+    pub fn hash(&self, value: [B; RATE_WIDTH], result: &mut [B]) {
+        let mut state = [B::ZERO; STATE_WIDTH];
+        state[..RATE_WIDTH].copy_from_slice(&value);
+        self.permute(&mut state);
+        result.copy_from_slice(&state[..RATE_WIDTH]);
+    }
+
+    /// Returns the periodic column which is one on the steps of a cycle at which a full round
+    /// is applied, and zero on steps with a partial round or no round at all.
+    pub fn get_full_round_flags(&self) -> Vec<B> {
+        (0..CYCLE_LENGTH)
+            .map(|step| B::from((step < NUM_ROUNDS && Self::is_full_round(step)) as u8))
+            .collect()
+    }
+
+    /// Returns round constants arranged as periodic columns, one column per state element.
+    /// Values for the last step of the cycle, where no round is applied, are set to zero.
+    pub fn get_round_constants(&self) -> Vec<Vec<B>> {
+        let mut constants = vec![vec![B::ZERO; CYCLE_LENGTH]; STATE_WIDTH];
+        for (round, ark) in self.ark.iter().enumerate() {
+            for (i, &constant) in ark.iter().enumerate() {
+                constants[i][round] = constant;
+            }
+        }
+
+        constants
+    }
+
+    // TRACE
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies a single Poseidon round to the state; `step` is the row of the execution trace at
+    /// which the round is applied.
+    pub fn apply_round(&self, state: &mut [B], step: usize) {
+        let round = step % CYCLE_LENGTH;
+        if Self::is_full_round(round) {
+            for element in state.iter_mut() {
+                *element = element.exp(B::ALPHA.into());
+            }
+        } else {
+            state[0] = state[0].exp(B::ALPHA.into());
+        }
+        apply_matrix(state, &self.mds);
+        for (element, &constant) in state.iter_mut().zip(self.round_constants(round)) {
+            *element += constant;
+        }
+    }
+
+    // CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// When `flag` = 1, enforces constraints for a single round of the Poseidon permutation;
+    /// `full_flag` selects between full and partial rounds, and `ark` holds the round constants,
+    /// which must be zero whenever `flag` is zero.
+    ///
+    /// Writing the S-box of an element as `x + s * (x^alpha - x)`, where `s` is one for the
+    /// elements which pass through the S-box in this round, lets the flags be distributed over
+    /// the terms of the round function so that no term is multiplied by more than one periodic
+    /// value; this keeps the constraint degree at alpha over a single cycle.
+    pub fn enforce_round<E: FieldElement<BaseField = B>>(
+        &self,
+        result: &mut [E],
+        current: &[E],
+        next: &[E],
+        ark: &[E],
+        flag: E,
+        full_flag: E,
+    ) {
+        // the first element passes through the S-box in every round, and the others only in
+        // full rounds
+        let mut sbox_terms = [E::ZERO; STATE_WIDTH];
+        for (i, term) in sbox_terms.iter_mut().enumerate() {
+            let sbox_flag = if i == 0 { flag } else { full_flag };
+            let x = current[i];
+            *term = sbox_flag * (x.exp(B::ALPHA.into()) - x);
+        }
+
+        let mut linear_terms = [E::ZERO; STATE_WIDTH];
+        linear_terms.copy_from_slice(current);
+        apply_matrix(&mut linear_terms, &self.mds);
+        apply_matrix(&mut sbox_terms, &self.mds);
+
+        for i in 0..STATE_WIDTH {
+            result[i] += flag * (next[i] - linear_terms[i]) - sbox_terms[i] - ark[i];
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn apply_matrix<B: ExampleField, E: FieldElement<BaseField = B>>(
+    state: &mut [E],
+    matrix: &[[B; STATE_WIDTH]; STATE_WIDTH],
+) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &coefficient) in row.iter().enumerate() {
+            result[i] += E::from(coefficient) * state[j];
+        }
+    }
+    state.copy_from_slice(&result);
+}
@@ -0,0 +1,229 @@
+use super::{
+    poseidon::{self, Poseidon, NUM_FULL_ROUNDS, NUM_PARTIAL_ROUNDS, STATE_WIDTH},
+    PoseidonExample, PoseidonInputs, PoseidonProver, CYCLE_LENGTH,
+};
+use crate::{
+    compose,
+    config::{PoseidonConfig, ProofOptionsConfig, RescueConfig, RunConfig},
+    example::{self, Example},
+    field::ExampleField,
+    tamper,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use winterfell::{
+    math::{
+        fields::{f128::BaseElement, f62, f64},
+        FieldElement,
+    },
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace, TraceTable,
+};
+
+#[test]
+fn poseidon_permutation_matches_reference() {
+    check_permutation::<BaseElement>();
+    check_permutation::<f64::BaseElement>();
+    check_permutation::<f62::BaseElement>();
+}
+
+#[test]
+fn poseidon_trace_matches_reference() {
+    let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+    let chain_length = 4;
+    let trace = PoseidonProver::build_trace(seed, chain_length);
+
+    let poseidon = Poseidon::new();
+    let mut state = [seed[0], seed[1], BaseElement::ZERO];
+    for i in 0..chain_length {
+        // the last row of each cycle holds the output of the permutation, and the capacity is
+        // reset to zero on the first row of the next cycle
+        state = reference_permutation(&poseidon, state);
+        let step = (i + 1) * CYCLE_LENGTH - 1;
+        for (column, &value) in state.iter().enumerate() {
+            assert_eq!(value, trace.get(column, step), "cycle {}", i);
+        }
+        state[2] = BaseElement::ZERO;
+    }
+}
+
+#[test]
+fn poseidon_full_round_in_place_of_partial_round_is_rejected() {
+    // apply the S-box to the entire state in one of the partial rounds; the rest of the trace
+    // follows from the altered state, so only the round selectors can catch it
+    let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+    let altered_step = NUM_FULL_ROUNDS / 2 + 10;
+    assert!(!Poseidon::<BaseElement>::is_full_round(altered_step));
+
+    let poseidon = Poseidon::<BaseElement>::new();
+    let mut trace = TraceTable::new(STATE_WIDTH, 16 * CYCLE_LENGTH);
+    trace.fill(
+        |state| {
+            state[0] = seed[0];
+            state[1] = seed[1];
+            state[2] = BaseElement::ZERO;
+        },
+        |step, state| {
+            if step == altered_step {
+                reference_round(&poseidon, state, step, STATE_WIDTH);
+            } else if (step % CYCLE_LENGTH) < CYCLE_LENGTH - 1 {
+                poseidon.apply_round(state, step);
+            } else {
+                state[2] = BaseElement::ZERO;
+            }
+        },
+    );
+    let last_step = trace.length() - 1;
+    let pub_inputs = PoseidonInputs {
+        seed,
+        result: [trace.get(0, last_step), trace.get(1, last_step)],
+    };
+
+    let proof = PoseidonProver::new(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<PoseidonExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+// REFERENCE IMPLEMENTATION
+// ================================================================================================
+
+/// Poseidon permutation written out as in the description of the construction: half of the
+/// full rounds, followed by the partial rounds, followed by the other half of the full rounds,
+/// with the MDS matrix computed independently of [Poseidon].
+fn reference_permutation<B: ExampleField>(
+    poseidon: &Poseidon<B>,
+    mut state: [B; STATE_WIDTH],
+) -> [B; STATE_WIDTH] {
+    let mut round = 0;
+    let round_groups = [
+        (NUM_FULL_ROUNDS / 2, STATE_WIDTH),
+        (NUM_PARTIAL_ROUNDS, 1),
+        (NUM_FULL_ROUNDS / 2, STATE_WIDTH),
+    ];
+    for &(num_rounds, num_sbox_elements) in round_groups.iter() {
+        for _ in 0..num_rounds {
+            reference_round(poseidon, &mut state, round, num_sbox_elements);
+            round += 1;
+        }
+    }
+    assert_eq!(poseidon::NUM_ROUNDS, round);
+    state
+}
+
+/// Applies the S-box to the first `num_sbox_elements` elements of the state, multiplies the
+/// state by the Cauchy matrix 1 / (i + j + 3), and adds the constants of the specified round.
+fn reference_round<B: ExampleField>(
+    poseidon: &Poseidon<B>,
+    state: &mut [B],
+    round: usize,
+    num_sbox_elements: usize,
+) {
+    for element in state.iter_mut().take(num_sbox_elements) {
+        *element = element.exp(B::ALPHA.into());
+    }
+
+    let mut mixed = [B::ZERO; STATE_WIDTH];
+    for (i, value) in mixed.iter_mut().enumerate() {
+        for (j, &element) in state.iter().enumerate() {
+            *value += element / B::from((i + j + STATE_WIDTH) as u64);
+        }
+    }
+
+    for ((element, &value), &constant) in state
+        .iter_mut()
+        .zip(mixed.iter())
+        .zip(poseidon.round_constants(round).iter())
+    {
+        *element = value + constant;
+    }
+}
+
+fn check_permutation<B: ExampleField>() {
+    let poseidon = Poseidon::<B>::new();
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..16 {
+        let mut state = [B::ZERO; STATE_WIDTH];
+        for element in state.iter_mut() {
+            *element = B::from(rng.gen::<u64>());
+        }
+        let expected = reference_permutation(&poseidon, state);
+        poseidon.permute(&mut state);
+        assert_eq!(expected, state);
+    }
+}
+
+#[test]
+fn poseidon_chain_composes_with_rescue_digest() {
+    let config = RunConfig {
+        rescue: RescueConfig {
+            chain_length: 16,
+            ..RescueConfig::default()
+        },
+        poseidon: PoseidonConfig {
+            chain_length: 16,
+            ..PoseidonConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            blowup_factor: Some(8),
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    let report = compose::compose(&config).unwrap();
+    assert!(report.is_verified(), "{}", report);
+
+    // a valid proof of a Poseidon chain which does not start from the digest breaks the link
+    let hash_chain = &report.hash_chain;
+    let unlinked = example::run(&build_example(), build_options());
+    assert!(unlinked.is_verified());
+    let err = compose::verify_composition(
+        &hash_chain.proof_bytes,
+        &hash_chain.pub_inputs_bytes,
+        &unlinked.proof_bytes,
+        &unlinked.pub_inputs_bytes,
+    )
+    .unwrap_err();
+    assert!(err.contains("not the digest"), "{}", err);
+
+    // claiming the digest as the seed of the unlinked chain is caught by its verifier
+    assert!(compose::verify_composition(
+        &hash_chain.proof_bytes,
+        &hash_chain.pub_inputs_bytes,
+        &unlinked.proof_bytes,
+        &report.continuation.pub_inputs_bytes,
+    )
+    .is_err());
+}
+
+#[test]
+fn poseidon_corrupted_partial_round_is_rejected() {
+    // step 30 holds the state after a partial round, where the second element does not pass
+    // through the S-box
+    let example = build_example();
+    let verdict = tamper::corrupt_trace_cell(&example, build_options(), 1, 30);
+    assert!(verdict.is_rejected(), "{}", verdict);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> PoseidonExample<BaseElement> {
+    PoseidonExample::new(&PoseidonConfig {
+        chain_length: 16,
+        ..PoseidonConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,123 @@
+//! The items most programs built on this crate need, for a single glob import.
+//!
+//! ```no_run
+//! use stark_examples::prelude::*;
+//! use winterfell::math::fields::f128::BaseElement;
+//!
+//! let config = RunConfig {
+//!     example: "fib".to_string(),
+//!     ..RunConfig::default()
+//! };
+//! let report: RunReport = run_example(&config)?;
+//! println!("{} bytes", report.proof_size);
+//!
+//! // an example can also be used through its types directly
+//! let example = FibExample::<BaseElement>::new(&config.fib).unwrap();
+//! let proof = example
+//!     .prover(config.proof_options.to_proof_options())
+//!     .prove(example.build_trace())
+//!     .expect("the trace is valid");
+//! winterfell::verify::<FibAir<BaseElement>>(proof, example.pub_inputs())
+//!     .expect("the proof is valid");
+//! # Ok::<(), ExampleError>(())
+//! ```
+//!
+//! Everything exported here is meant to stay: items are only removed or changed in
+//! incompatible ways together with a new major version. Errors and reports which are likely to
+//! grow are `#[non_exhaustive]`, so that a new variant or field is not a breaking change;
+//! matches on them need a wildcard arm. Modules which are not re-exported here, such as
+//! [bench](crate::bench), [tune](crate::tune), or [cli](crate::cli), follow the command line
+//! and may change with it.
+//!
+//! The AIR and public inputs of every example are exported with and without the `std`
+//! feature, so that a `no_std` verifier can name them; the examples themselves and their
+//! provers need `std`.
+
+pub use crate::{
+    field::ExampleField,
+    gadgets::{
+        and_word, first_row_flags, horner, horner_witness, is_zero, is_zero_witness,
+        less_than_difference, less_than_witness, limb_product, limb_product_witness, reduction,
+        reduction_witness, running_sum, running_sum_witness, to_bits, to_limbs, wrapping_add,
+        wrapping_add_witness, xor, xor_word,
+    },
+    verify_core::VerifyError,
+};
+pub use winterfell::{
+    math::{FieldElement, StarkField},
+    Air, ProofOptions, Prover,
+};
+
+#[cfg(feature = "std")]
+pub use crate::{
+    config::{ProofOptionsConfig, RunConfig},
+    example::{BaseField, Example, ExampleDescriptor, PublicInputs, RunReport},
+    field::FieldType,
+    run_example, with_example, ExampleCommand, ExampleError,
+};
+
+// EXAMPLES
+// ================================================================================================
+
+pub use crate::{
+    bitwise::air::{BitwiseAir, BitwiseInputs},
+    bytecount::air::{ByteCountAir, ByteCountInputs},
+    compare::air::{CompareAir, CompareInputs},
+    counter::air::{CounterAir, CounterInputs},
+    exp::air::{ExpAir, ExpInputs},
+    fib::air::{FibAir, FibInputs},
+    fibchunk::air::{FibChunkAir, FibChunkInputs},
+    fibdiv::air::{FibDivAir, FibDivInputs},
+    fibmod::air::{FibModAir, FibModInputs},
+    fixedexp::air::{FixedExpAir, FixedExpInputs},
+    gcd::air::{GcdAir, GcdInputs},
+    horner::air::{HornerAir, HornerInputs},
+    iszero::air::{IsZeroAir, IsZeroInputs},
+    lamport::air::{LamportAir, LamportInputs},
+    merkle::air::{MerkleAir, MerkleInputs},
+    modexp::air::{ModExpAir, ModExpInputs},
+    poseidon::air::{PoseidonAir, PoseidonInputs},
+    quad::air::{QuadAir, QuadInputs},
+    rescue::air::{RescueAir, RescueInputs},
+    rom::air::{RomAir, RomInputs},
+    runsum::air::{RunSumAir, RunSumInputs},
+    sort::air::{SortAir, SortInputs},
+    sqrt::air::{SqrtAir, SqrtInputs},
+    sum::air::{SumAir, SumInputs},
+    vdf::air::{VdfAir, VdfInputs},
+    vm::air::{VmAir, VmInputs},
+    window::air::{WindowAir, WindowInputs},
+    wrap::air::{WrapAir, WrapInputs},
+};
+
+#[cfg(feature = "std")]
+pub use crate::{
+    bitwise::{prover::BitwiseProver, BitwiseExample},
+    bytecount::{prover::ByteCountProver, ByteCountExample},
+    compare::{prover::CompareProver, CompareExample},
+    counter::{prover::CounterProver, CounterExample},
+    exp::{prover::ExpProver, ExpExample},
+    fib::{prover::FibProver, FibExample},
+    fibchunk::{prover::FibChunkProver, FibChunkExample},
+    fibdiv::{prover::FibDivProver, FibDivExample},
+    fibmod::{prover::FibModProver, FibModExample},
+    fixedexp::{prover::FixedExpProver, FixedExpExample},
+    gcd::{prover::GcdProver, GcdExample},
+    horner::{prover::HornerProver, HornerExample},
+    iszero::{prover::IsZeroProver, IsZeroExample},
+    lamport::{prover::LamportProver, LamportExample},
+    merkle::{prover::MerkleProver, MerkleExample},
+    modexp::{prover::ModExpProver, ModExpExample},
+    poseidon::{prover::PoseidonProver, PoseidonExample},
+    quad::{prover::QuadProver, QuadExample},
+    rescue::{prover::RescueProver, RescueExample},
+    rom::{prover::RomProver, RomExample},
+    runsum::{prover::RunSumProver, RunSumExample},
+    sort::{prover::SortProver, SortExample},
+    sqrt::{prover::SqrtProver, SqrtExample},
+    sum::{prover::SumProver, SumExample},
+    vdf::{prover::VdfProver, VdfExample},
+    vm::{prover::VmProver, VmExample},
+    window::{prover::WindowProver, WindowExample},
+    wrap::{prover::WrapProver, WrapExample},
+};
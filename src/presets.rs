@@ -0,0 +1,75 @@
+//! Named sets of proof options shared by all examples.
+//!
+//! Proof options trade prover time and proof size against security, and most of them only
+//! make sense in combination: grinding is worth its cost only once queries provide most of the
+//! security, and a field extension is needed before a small base field can reach a high
+//! security level. A preset fixes all of them at once; options set in a config file or on the
+//! command line take precedence over the preset, one by one.
+
+use crate::{
+    config::{FieldExt, HashFn, ProofOptionsConfig},
+    field::FieldType,
+};
+use clap::ValueEnum;
+use serde::Deserialize;
+
+// PRESETS
+// ================================================================================================
+
+/// A named set of proof options which can be selected on the command line or in a config file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    /// 20 queries at blowup factor 4 without grinding, for iterating on an example: proofs are
+    /// quick to generate and small, but provide only about 40 bits of conjectured security
+    Fast,
+    /// 40 queries at blowup factor 4 with 21 bits of grinding, for about 100 bits of conjectured
+    /// security in the 128-bit field; grinding dominates the prover time of small traces
+    #[default]
+    Balanced,
+    /// 40 queries at blowup factor 8 with 20 bits of grinding and a field extension, for 128
+    /// bits of conjectured security in every field at the cost of a twice larger trace extension
+    Secure,
+}
+
+impl Preset {
+    /// Returns the proof options of this preset for the specified base field, with every value
+    /// set.
+    ///
+    /// Only [Preset::Secure] depends on the field: it extends the 128-bit field quadratically,
+    /// which winterfell supports up to, and the 64-bit fields cubically.
+    pub fn proof_options(&self, field: FieldType) -> ProofOptionsConfig {
+        let options = ProofOptionsConfig {
+            hash_fn: Some(HashFn::Blake3_256),
+            field_extension: Some(FieldExt::None),
+            fri_folding_factor: Some(8),
+            fri_max_remainder_size: Some(64),
+            ..ProofOptionsConfig::default()
+        };
+        match self {
+            Preset::Fast => ProofOptionsConfig {
+                num_queries: Some(20),
+                blowup_factor: Some(4),
+                grinding_factor: Some(0),
+                hash_fn: Some(HashFn::Blake3_192),
+                ..options
+            },
+            Preset::Balanced => ProofOptionsConfig {
+                num_queries: Some(40),
+                blowup_factor: Some(4),
+                grinding_factor: Some(21),
+                ..options
+            },
+            Preset::Secure => ProofOptionsConfig {
+                num_queries: Some(40),
+                blowup_factor: Some(8),
+                grinding_factor: Some(20),
+                field_extension: Some(match field {
+                    FieldType::F128 => FieldExt::Quadratic,
+                    FieldType::F64 | FieldType::F62 => FieldExt::Cubic,
+                }),
+                ..options
+            },
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Sampling profiles of proving runs, for finding out which part of proof generation dominates
+//! without setting up external tools.
+//!
+//! With the `profiling` feature, `run --profile-out <file>` samples the stacks of all threads
+//! while the example is proven and verified, and writes them either as a flamegraph or as folded
+//! stacks, one line per distinct stack followed by its number of samples, which
+//! [inferno](https://github.com/jonhoo/inferno) and most other flamegraph tools read. Sampling
+//! relies on `SIGPROF`, so the feature is only available on Unix.
+
+use crate::error::ExampleError;
+use pprof::{ProfilerGuard, ProfilerGuardBuilder, Report};
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of stack samples taken per second; slightly off a round number, so that sampling does
+/// not fall into step with periodic work of the prover.
+const SAMPLING_FREQUENCY: i32 = 997;
+
+/// Libraries whose frames are not unwound, since unwinding through them from a signal handler
+/// can deadlock.
+const BLOCKLIST: [&str; 4] = ["libc", "libgcc", "pthread", "vdso"];
+
+// PROFILER
+// ================================================================================================
+
+/// A running sampling profiler; samples are taken from its creation until it is written out.
+pub struct Profiler {
+    guard: ProfilerGuard<'static>,
+}
+
+impl Profiler {
+    /// Starts sampling the stacks of all threads of the process.
+    ///
+    /// Returns a [Config](ExampleError::Config) error if the profiler cannot be started, such as
+    /// when another one is running.
+    pub fn start() -> Result<Self, ExampleError> {
+        let guard = ProfilerGuardBuilder::default()
+            .frequency(SAMPLING_FREQUENCY)
+            .blocklist(&BLOCKLIST)
+            .build()
+            .map_err(|err| ExampleError::Config(format!("failed to start profiler: {}", err)))?;
+        Ok(Self { guard })
+    }
+
+    /// Stops sampling and writes the profile into the specified file: as a flamegraph if the
+    /// file name ends in `.svg`, and as folded stacks otherwise. Returns the number of samples
+    /// taken.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the samples cannot be symbolized or the file
+    /// cannot be written.
+    pub fn write(self, path: &Path) -> Result<usize, ExampleError> {
+        let write_error = |err: &dyn std::fmt::Display| {
+            let source = io::Error::other(err.to_string());
+            ExampleError::write("profile", path, source)
+        };
+        let report = self
+            .guard
+            .report()
+            .build()
+            .map_err(|err| write_error(&err))?;
+        drop(self.guard);
+
+        let mut file = BufWriter::new(File::create(path).map_err(|err| write_error(&err))?);
+        if path.extension() == Some("svg".as_ref()) {
+            report
+                .flamegraph(&mut file)
+                .map_err(|err| write_error(&err))?;
+        } else {
+            for line in folded_stacks(&report) {
+                writeln!(file, "{}", line).map_err(|err| write_error(&err))?;
+            }
+        }
+        file.flush().map_err(|err| write_error(&err))?;
+        Ok(report.data.values().map(|&count| count as usize).sum())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a line per distinct stack of the report, with the thread first and the innermost
+/// frame last, separated by semicolons and followed by the number of samples; the lines are
+/// sorted, so that stacks which share their outer frames are next to each other.
+fn folded_stacks(report: &Report) -> Vec<String> {
+    let mut lines = report
+        .data
+        .iter()
+        .map(|(frames, count)| {
+            let mut line = frames.thread_name_or_id();
+            for symbol in frames
+                .frames
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter().rev())
+            {
+                write!(line, ";{}", symbol).unwrap();
+            }
+            write!(line, " {}", count).unwrap();
+            line
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+    lines
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+    };
+
+    #[test]
+    fn profile_is_written_as_folded_stacks() {
+        use std::{env, fs, process};
+
+        let path = env::temp_dir().join(format!("stark-examples-profile-{}.folded", process::id()));
+        let profiler = Profiler::start().unwrap();
+        let report = example::run(&vdf_example(), vdf_options());
+        let num_samples = profiler.write(&path).unwrap();
+        assert!(report.is_verified());
+
+        // every line is a stack followed by its number of samples, and the counts add up
+        let profile = fs::read_to_string(&path).unwrap();
+        let counts = profile.lines().map(|line| {
+            let (stack, count) = line.rsplit_once(' ').unwrap();
+            assert!(!stack.is_empty());
+            count.parse::<usize>().unwrap()
+        });
+        assert_eq!(num_samples, counts.sum::<usize>());
+        assert!(profile.contains("winter_prover"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+//! Progress bars for runs which take long enough to look frozen.
+//!
+//! Building and proving a trace of 2^22 rows takes about a minute, during which `run` would print
+//! nothing. [run_with_progress()] draws a bar of the rows of the execution trace built so far, fed
+//! by the row-count callback of [example::run_observed()], and then a bar of the phases of the
+//! prover. Winterfell reports its phases only by logging them, so a thread polls the phase
+//! completed last (see [last_completed()](crate::phases::last_completed)) along with the stage of
+//! the run; without a [PhaseLogger](crate::phases::PhaseLogger) installed, the bar of the prover
+//! only shows that it is busy. The bars are drawn on standard error and cleared once the run is
+//! complete, and they are hidden when standard error is not a terminal. `run` draws them only when
+//! standard output is a terminal and the report is not printed as JSON.
+
+use crate::{
+    cancel::{Interruption, Stage},
+    error::ExampleError,
+    example::{self, Example, RunReport},
+    phases::ProofPhase,
+};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
+use winterfell::ProofOptions;
+
+// CONSTANTS
+// ================================================================================================
+
+/// How often the bars are redrawn and the phase of the prover is polled.
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+// PROGRESS BARS
+// ================================================================================================
+
+/// Proves and verifies the example as [example::try_run()] does, while drawing progress bars
+/// for building the execution trace and for the phases of the prover.
+pub fn run_with_progress<E: Example>(
+    example: &E,
+    options: ProofOptions,
+) -> Result<RunReport, ExampleError> {
+    let bars = MultiProgress::new();
+    let trace_bar = bars.add(
+        ProgressBar::new(0)
+            .with_style(style(
+                "{spinner} {msg:<24} [{bar:40}] {pos}/{len} rows ({eta})",
+            ))
+            .with_message("building trace"),
+    );
+    trace_bar.enable_steady_tick(TICK_INTERVAL);
+    let proof_bar = ProgressBar::new(ProofPhase::ALL.len() as u64).with_style(style(
+        "{spinner} {msg:<24} [{bar:40}] {pos}/{len} phases ({elapsed})",
+    ));
+
+    let done = AtomicBool::new(false);
+    let report = thread::scope(|scope| {
+        scope.spawn(|| follow_prover(&done, &bars, &trace_bar, &proof_bar));
+        let report = example::run_observed(example, options, &|num_rows, length| {
+            trace_bar.set_length(length as u64);
+            trace_bar.set_position(num_rows as u64);
+        });
+        done.store(true, Ordering::Relaxed);
+        report
+    });
+
+    trace_bar.finish_and_clear();
+    proof_bar.finish_and_clear();
+    report
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Moves on from the bar of the trace to the bar of the prover once proving starts, and advances
+/// the latter with the phases of the prover, until `done` is set.
+fn follow_prover(
+    done: &AtomicBool,
+    bars: &MultiProgress,
+    trace_bar: &ProgressBar,
+    proof_bar: &ProgressBar,
+) {
+    while !done.load(Ordering::Relaxed) {
+        let current = Interruption::current();
+        if matches!(current.stage, Stage::Proving | Stage::Verifying) && !trace_bar.is_finished() {
+            trace_bar.finish();
+            bars.add(proof_bar.clone());
+            proof_bar.enable_steady_tick(TICK_INTERVAL);
+        }
+        match current.stage {
+            Stage::Proving => {
+                let num_completed = current.last_phase.map_or(0, |phase| phase as usize + 1);
+                proof_bar.set_position(num_completed as u64);
+                let next = ProofPhase::ALL
+                    .get(num_completed)
+                    .map_or("proving", |phase| phase.name());
+                proof_bar.set_message(next);
+            }
+            Stage::Verifying => {
+                proof_bar.set_position(ProofPhase::ALL.len() as u64);
+                proof_bar.set_message("verifying");
+            }
+            Stage::Idle | Stage::BuildingTrace => {}
+        }
+        thread::sleep(TICK_INTERVAL);
+    }
+}
+
+fn style(template: &str) -> ProgressStyle {
+    ProgressStyle::with_template(template)
+        .expect("progress templates are valid")
+        .progress_chars("=> ")
+}
@@ -0,0 +1,340 @@
+use crate::inspect::field_name;
+use std::{fs, path::Path};
+use winter_utils::SliceReader;
+use winterfell::{
+    crypto::{hashers::Blake3_256, Digest, Hasher},
+    math::fields::f128::BaseElement,
+    ByteReader, ByteWriter, HashFunction, StarkProof,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes at the start of every proof file.
+const PROOF_MAGIC: &[u8; 8] = b"STXPROOF";
+
+/// Version of the proof file format written by [ProofFile::to_bytes()].
+pub const PROOF_FORMAT_VERSION: u8 = 2;
+
+/// Oldest version of the proof file format which [ProofFile::from_bytes()] still reads.
+pub const MIN_PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Hash function of the checksum which follows the proof.
+type ChecksumHasher = Blake3_256<BaseElement>;
+
+/// Number of bytes in a checksum.
+const CHECKSUM_BYTES: usize = 32;
+
+/// Version of this crate, which is recorded in the proof files it writes.
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of winterfell which serializes the proofs; must be kept in sync with `Cargo.toml`.
+pub const WINTERFELL_VERSION: &str = "0.3";
+
+// PROOF FILE
+// ================================================================================================
+
+/// Metadata which is stored in front of a proof in a proof file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofHeader {
+    /// Version of the proof file format the file was read from; files are always written in
+    /// the current version.
+    pub format_version: u8,
+    /// Version of the crate which wrote the file.
+    pub crate_version: String,
+    /// Version of winterfell which serialized the proof.
+    pub winterfell_version: String,
+    /// Name of the example the proof is for.
+    pub example: String,
+    /// Base field of the proof, as named in config files.
+    pub field: String,
+    /// Hash function of the proof, as named in config files.
+    pub hash_fn: String,
+}
+
+/// A serialized proof together with a header describing how it was generated.
+///
+/// Winterfell proofs carry no version, so a proof serialized by one version of winterfell may
+/// fail to parse in another without saying why. Proof files record the versions they were
+/// written with, and [ProofFile::from_bytes()] rejects files from incompatible versions before
+/// the proof is parsed.
+///
+/// A proof file is serialized as the magic bytes `STXPROOF` and a format version byte, followed
+/// by the crate version, the winterfell version, the example name, the field name, and the hash
+/// function name, each prefixed by its length as a `u8`, then the proof, prefixed by its length
+/// as a `u32`, and finally a BLAKE3 checksum of the proof, which tells a file damaged on disk
+/// apart from a proof the verifier rejects. All integers are little-endian.
+///
+/// Files written in earlier format versions, down to [MIN_PROOF_FORMAT_VERSION], are translated
+/// into the current form when they are read, so that proofs saved by earlier releases keep
+/// verifying; a version 1 file is laid out in the same way but has no checksum. When the format
+/// changes, the reader of the previous version is kept, and a file of that version is added to
+/// the fixtures which the tests load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofFile {
+    pub header: ProofHeader,
+    /// The serialized proof.
+    pub proof_bytes: Vec<u8>,
+}
+
+impl ProofFile {
+    /// Returns a proof file for a serialized proof of the named example; the field and the hash
+    /// function are read from the proof.
+    ///
+    /// Returns an error if the proof cannot be parsed.
+    pub fn new(example: &str, proof_bytes: Vec<u8>) -> Result<Self, String> {
+        let proof = StarkProof::from_bytes(&proof_bytes)
+            .map_err(|err| format!("failed to parse proof: {}", err))?;
+        let header = ProofHeader {
+            format_version: PROOF_FORMAT_VERSION,
+            crate_version: CRATE_VERSION.to_string(),
+            winterfell_version: WINTERFELL_VERSION.to_string(),
+            example: example.to_string(),
+            field: field_name(proof.context.field_modulus_bytes()).to_string(),
+            hash_fn: hash_fn_name(proof.options().hash_fn()).to_string(),
+        };
+        Ok(Self {
+            header,
+            proof_bytes,
+        })
+    }
+
+    /// Returns true if `bytes` start like a proof file rather than a bare serialized proof.
+    pub fn is_proof_file(bytes: &[u8]) -> bool {
+        bytes.starts_with(PROOF_MAGIC)
+    }
+
+    /// Returns the proof file serialized in the current format version, whichever version it
+    /// was read from.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let header = &self.header;
+        let mut bytes = Vec::new();
+        bytes.write_u8_slice(PROOF_MAGIC);
+        bytes.write_u8(PROOF_FORMAT_VERSION);
+        for value in [
+            &header.crate_version,
+            &header.winterfell_version,
+            &header.example,
+            &header.field,
+            &header.hash_fn,
+        ] {
+            bytes.write_u8(value.len() as u8);
+            bytes.write_u8_slice(value.as_bytes());
+        }
+        bytes.write_u32(self.proof_bytes.len() as u32);
+        bytes.write_u8_slice(&self.proof_bytes);
+        bytes.write_u8_slice(&checksum(&self.proof_bytes));
+        bytes
+    }
+
+    /// Parses a proof file from bytes written by [ProofFile::to_bytes()] of this or an earlier
+    /// release.
+    ///
+    /// Returns an error if the bytes are not a proof file, if the file was written in a format
+    /// version this build does not read or by another version of winterfell, whose proofs this
+    /// build cannot parse, or if the proof does not match its checksum.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !Self::is_proof_file(bytes) {
+            return Err("not a proof file".to_string());
+        }
+        let mut reader = SliceReader::new(&bytes[PROOF_MAGIC.len()..]);
+        let format_version = reader.read_u8().map_err(|err| err.to_string())?;
+        if !(MIN_PROOF_FORMAT_VERSION..=PROOF_FORMAT_VERSION).contains(&format_version) {
+            return Err(format!(
+                "unsupported proof file format version {}; this build reads versions {} to {}",
+                format_version, MIN_PROOF_FORMAT_VERSION, PROOF_FORMAT_VERSION
+            ));
+        }
+
+        let crate_version = read_string(&mut reader)?;
+        let winterfell_version = read_string(&mut reader)?;
+        if winterfell_version != WINTERFELL_VERSION {
+            return Err(format!(
+                "proof was generated with incompatible winterfell version {} (by stark-examples \
+                 {}); this build uses winterfell {}",
+                winterfell_version, crate_version, WINTERFELL_VERSION
+            ));
+        }
+        let header = ProofHeader {
+            format_version,
+            crate_version,
+            winterfell_version,
+            example: read_string(&mut reader)?,
+            field: read_string(&mut reader)?,
+            hash_fn: read_string(&mut reader)?,
+        };
+
+        let proof_bytes = match format_version {
+            1 => read_proof_v1(&mut reader)?,
+            _ => read_proof(&mut reader)?,
+        };
+        if reader.has_more_bytes() {
+            return Err("unexpected trailing bytes".to_string());
+        }
+
+        Ok(Self {
+            header,
+            proof_bytes,
+        })
+    }
+
+    /// Writes the serialized proof file to the specified path.
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_bytes())
+            .map_err(|err| format!("failed to write proof file {}: {}", path.display(), err))
+    }
+
+    /// Reads a proof file from the specified path.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("failed to read proof file {}: {}", path.display(), err))?;
+        Self::from_bytes(&bytes)
+            .map_err(|err| format!("failed to parse proof file {}: {}", path.display(), err))
+    }
+}
+
+// PROOF READERS
+// ================================================================================================
+
+/// Reads the proof of a file in the current format version and checks it against its checksum.
+fn read_proof(reader: &mut SliceReader) -> Result<Vec<u8>, String> {
+    let proof_bytes = read_proof_v1(reader)?;
+    let expected = reader
+        .read_u8_vec(CHECKSUM_BYTES)
+        .map_err(|err| err.to_string())?;
+    if expected != checksum(&proof_bytes) {
+        return Err("proof does not match its checksum; the file is damaged".to_string());
+    }
+    Ok(proof_bytes)
+}
+
+/// Reads the proof of a version 1 file, which is not followed by a checksum.
+fn read_proof_v1(reader: &mut SliceReader) -> Result<Vec<u8>, String> {
+    let proof_len = reader.read_u32().map_err(|err| err.to_string())?;
+    reader
+        .read_u8_vec(proof_len as usize)
+        .map_err(|err| err.to_string())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn checksum(proof_bytes: &[u8]) -> Vec<u8> {
+    ChecksumHasher::hash(proof_bytes).as_bytes().to_vec()
+}
+
+fn read_string(reader: &mut SliceReader) -> Result<String, String> {
+    let len = reader.read_u8().map_err(|err| err.to_string())?;
+    let bytes = reader
+        .read_u8_vec(len as usize)
+        .map_err(|err| err.to_string())?;
+    String::from_utf8(bytes).map_err(|_| "header field is not valid UTF-8".to_string())
+}
+
+pub(crate) fn hash_fn_name(hash_fn: HashFunction) -> &'static str {
+    match hash_fn {
+        HashFunction::Blake3_192 => "blake3_192",
+        HashFunction::Blake3_256 => "blake3_256",
+        HashFunction::Sha3_256 => "sha3_256",
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProofFile, MIN_PROOF_FORMAT_VERSION, PROOF_FORMAT_VERSION};
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+        verify::{verify_proof, VerifyError},
+    };
+    use std::{fs, path::Path};
+
+    #[test]
+    fn proof_file_round_trips_and_verifies() {
+        let report = example::run(&vdf_example(), vdf_options());
+        let file = ProofFile::new("vdf", report.proof_bytes.clone()).unwrap();
+        assert_eq!(
+            ("f128", "blake3_256"),
+            (&*file.header.field, &*file.header.hash_fn)
+        );
+        let file_bytes = file.to_bytes();
+        assert_eq!(file, ProofFile::from_bytes(&file_bytes).unwrap());
+
+        // proof files are verified like bare proofs, but only as proofs of the example they name
+        assert_eq!(
+            Ok(()),
+            verify_proof("vdf", &file_bytes, &report.pub_inputs_bytes)
+        );
+        assert_eq!(
+            Err(VerifyError::Rejected(
+                "proof file holds a proof of example 'vdf'".to_string()
+            )),
+            verify_proof("fib", &file_bytes, &report.pub_inputs_bytes)
+        );
+
+        // a file damaged on disk is told apart from a proof the verifier rejects
+        let mut damaged = file_bytes;
+        let last = damaged.len() - 40;
+        damaged[last] ^= 1;
+        let err = ProofFile::from_bytes(&damaged).unwrap_err();
+        assert!(err.contains("checksum"), "{}", err);
+    }
+
+    #[test]
+    fn proofs_saved_in_earlier_formats_still_verify() {
+        let fixture = |name: &str| fs::read(Path::new(PROOF_FIXTURES).join(name)).unwrap();
+        let pub_inputs_bytes = fixture("vdf_inputs.bin");
+
+        // bare proofs were saved before proof files had a header
+        let bare = fixture("vdf_bare.proof");
+        assert_eq!(Ok(()), verify_proof("vdf", &bare, &pub_inputs_bytes));
+
+        for version in MIN_PROOF_FORMAT_VERSION..PROOF_FORMAT_VERSION {
+            let file_bytes = fixture(&format!("vdf_v{}.proof", version));
+            assert_eq!(Ok(()), verify_proof("vdf", &file_bytes, &pub_inputs_bytes));
+
+            // the file is translated into the current form and written in the current version
+            let file = ProofFile::from_bytes(&file_bytes).unwrap();
+            assert_eq!(
+                (version, &bare),
+                (file.header.format_version, &file.proof_bytes)
+            );
+            let upgraded = ProofFile::from_bytes(&file.to_bytes()).unwrap();
+            assert_eq!(PROOF_FORMAT_VERSION, upgraded.header.format_version);
+            assert_eq!(bare, upgraded.proof_bytes);
+        }
+    }
+
+    #[test]
+    fn proof_file_from_incompatible_version_is_rejected() {
+        let report = example::run(&vdf_example(), vdf_options());
+        let file = ProofFile::new("vdf", report.proof_bytes.clone()).unwrap();
+
+        let mut upgraded = file.clone();
+        upgraded.header.winterfell_version = "0.4".to_string();
+        let err = ProofFile::from_bytes(&upgraded.to_bytes()).unwrap_err();
+        assert!(
+            err.starts_with("proof was generated with incompatible winterfell version 0.4"),
+            "{}",
+            err
+        );
+        assert!(verify_proof("vdf", &upgraded.to_bytes(), &report.pub_inputs_bytes).is_err());
+
+        let mut reformatted = file.to_bytes();
+        reformatted[8] = PROOF_FORMAT_VERSION + 1;
+        let err = ProofFile::from_bytes(&reformatted).unwrap_err();
+        let version = format!("format version {}", PROOF_FORMAT_VERSION + 1);
+        assert!(err.contains(&version), "{}", err);
+
+        assert_eq!(
+            Err("not a proof file".to_string()),
+            ProofFile::from_bytes(&report.proof_bytes)
+        );
+    }
+
+    /// Directory of proofs saved by earlier releases, with the public inputs they were proven for.
+    const PROOF_FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/proof_files");
+}
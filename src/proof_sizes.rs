@@ -0,0 +1,142 @@
+//! Bands of proof sizes which guard against changes which make proofs larger.
+//!
+//! The golden tests pin every byte of the proofs they generate, so they fail on any change to an
+//! AIR or to winterfell, and the fix is to record the new proofs; whether a proof grew by a few
+//! bytes or doubled in size is easy to miss in the diff. A size band bounds the size of the
+//! proof of an example at fixed small parameters, those of the smallest soak case and the
+//! default proof options with a blowup factor of 8, which fits the constraints of every example,
+//! so that a change which balloons proof sizes fails with a message
+//! naming the example, while changes which leave the size within the band pass. The bands are
+//! some 10% either side of the measured sizes; a proof which shrinks below its band is flagged
+//! as well, since fewer queries or columns than expected can mean that a part of the proof was
+//! dropped.
+//!
+//! Grinding is turned off when measuring, as it only changes the nonce of a proof, whose size
+//! is fixed, and would make every check slow.
+
+use crate::{
+    config::{ProofOptionsConfig, RunConfig},
+    run_example, soak, EXAMPLES,
+};
+use std::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Blowup factor of the proofs whose sizes are checked; the default of 4 is too small for the
+/// degree of some AIRs.
+const BLOWUP_FACTOR: usize = 8;
+
+// SIZE BANDS
+// ================================================================================================
+
+/// Range of sizes, in bytes, within which the proof of an example must lie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeBand {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl SizeBand {
+    /// Returns true if `size` lies within the band, both ends included.
+    pub fn contains(&self, size: usize) -> bool {
+        (self.min..=self.max).contains(&size)
+    }
+}
+
+impl fmt::Display for SizeBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}] bytes", self.min, self.max)
+    }
+}
+
+/// Size band of the proof of every example at the parameters of [size_config()].
+pub const SIZE_BANDS: [(&str, SizeBand); 28] = [
+    ("vdf", band(14900, 18400)),
+    ("rescue", band(13600, 16700)),
+    ("poseidon", band(21300, 26200)),
+    ("counter", band(11700, 14400)),
+    ("lamport", band(16200, 19900)),
+    ("exp", band(20300, 25000)),
+    ("sum", band(14700, 18100)),
+    ("vm", band(22600, 27800)),
+    ("fib", band(15500, 19100)),
+    ("gcd", band(42800, 52400)),
+    ("quad", band(13800, 17000)),
+    ("iszero", band(14800, 18200)),
+    ("rom", band(20300, 24900)),
+    ("sqrt", band(60200, 73700)),
+    ("merkle", band(47500, 58200)),
+    ("bitwise", band(55500, 67900)),
+    ("compare", band(72900, 89200)),
+    ("fibmod", band(53600, 65600)),
+    ("sort", band(44100, 54000)),
+    ("fibchunk", band(14700, 18100)),
+    ("fixedexp", band(15700, 19400)),
+    ("modexp", band(153800, 188000)),
+    ("bytecount", band(20200, 24800)),
+    ("runsum", band(15100, 18500)),
+    ("horner", band(14300, 17600)),
+    ("wrap", band(20400, 25000)),
+    ("window", band(15400, 18800)),
+    ("fibdiv", band(35800, 43800)),
+];
+
+/// Returns the size band of the named example, if it has one.
+pub fn size_band(example: &str) -> Option<SizeBand> {
+    SIZE_BANDS
+        .iter()
+        .find(|(name, _)| *name == example)
+        .map(|(_, band)| *band)
+}
+
+/// Returns the config at which the proof of the named example is checked against its band.
+pub fn size_config(example: &str) -> RunConfig {
+    let mut config = soak::base_config(example);
+    config.proof_options = ProofOptionsConfig {
+        blowup_factor: Some(BLOWUP_FACTOR),
+        grinding_factor: Some(0),
+        ..ProofOptionsConfig::default()
+    };
+    config
+}
+
+// SIZE CHECKS
+// ================================================================================================
+
+/// Proves the named example at the parameters of [size_config()], and returns the size of the
+/// proof if it lies within the band of the example.
+///
+/// Returns an error if the example has no band, if it cannot be proven, or if the size of its
+/// proof is outside of the band.
+pub fn check_proof_size(example: &str) -> Result<usize, String> {
+    let band = size_band(example)
+        .ok_or_else(|| format!("example '{}' has no proof size band", example))?;
+    let report = run_example(&size_config(example))?;
+    let size = report.proof_bytes.len();
+    if !band.contains(size) {
+        let change = if size > band.max { "grew" } else { "shrank" };
+        return Err(format!(
+            "proof of example '{}' {} to {} bytes, outside of its band of {}; if the change is \
+             intended, update the band in SIZE_BANDS",
+            example, change, size, band
+        ));
+    }
+    Ok(size)
+}
+
+/// Checks the proof size of every example of [EXAMPLES], and returns the error of every
+/// example which failed.
+pub fn check_all_proof_sizes() -> Vec<String> {
+    EXAMPLES
+        .iter()
+        .filter_map(|example| check_proof_size(example).err())
+        .collect()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const fn band(min: usize, max: usize) -> SizeBand {
+    SizeBand { min, max }
+}
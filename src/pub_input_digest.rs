@@ -0,0 +1,106 @@
+//! Digests which stand in for large public inputs.
+//!
+//! The public inputs of some examples grow with the computation: the sum example makes both
+//! of its vectors public, and the sort example both the input and the sorted vector. A verifier
+//! needs the whole inputs to check the boundary assertions of these AIRs, and the transcript of
+//! every proof already commits to them, so a digest cannot replace them inside the proof.
+//! What a digest can replace is the inputs in transit: a prover publishes the proof together
+//! with the BLAKE3-256 digest of its serialized public inputs, and the inputs themselves are
+//! shipped separately, for example from a content-addressed store. [verify_with_digest()]
+//! recomputes the digest of the inputs it is given and rejects them unless it matches before
+//! verifying the proof, so a verifier who trusts the digest need not trust where the inputs
+//! came from.
+//!
+//! `run --save-digest` writes the digest of the public inputs of a run, and `verify --digest`
+//! checks the inputs against it.
+
+use crate::verify::{verify_proof, VerifyError};
+use std::fmt;
+use winterfell::{
+    crypto::{hashers::Blake3_256, Digest, Hasher},
+    math::fields::f128::BaseElement,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bytes in a digest.
+const DIGEST_BYTES: usize = 32;
+
+// PUBLIC INPUT DIGEST
+// ================================================================================================
+
+/// BLAKE3-256 digest of serialized public inputs; displayed as 64 hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PubInputDigest([u8; DIGEST_BYTES]);
+
+impl PubInputDigest {
+    /// Returns the digest of the specified serialized public inputs.
+    pub fn of(pub_inputs_bytes: &[u8]) -> Self {
+        Self(Blake3_256::<BaseElement>::hash(pub_inputs_bytes).as_bytes())
+    }
+
+    /// Parses a digest from the hex string written by its [Display](fmt::Display)
+    /// implementation; surrounding whitespace is ignored.
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        if hex.len() != 2 * DIGEST_BYTES || !hex.is_ascii() {
+            return Err(format!(
+                "expected {} hex digits, but got '{}'",
+                2 * DIGEST_BYTES,
+                hex
+            ));
+        }
+        let mut bytes = [0u8; DIGEST_BYTES];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+                .map_err(|_| format!("'{}' is not a hex string", hex))?;
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Returns the bytes of the digest.
+    pub fn as_bytes(&self) -> &[u8; DIGEST_BYTES] {
+        &self.0
+    }
+
+    /// Returns an error unless the specified serialized public inputs hash to this digest.
+    pub fn check(&self, pub_inputs_bytes: &[u8]) -> Result<(), String> {
+        let actual = Self::of(pub_inputs_bytes);
+        if actual != *self {
+            return Err(format!(
+                "public inputs hash to {}, but the digest is {}",
+                actual, self
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PubInputDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies a serialized proof of the named example, as [verify_proof()] does, after checking
+/// that the serialized public inputs hash to the specified digest.
+///
+/// Inputs which do not match the digest are rejected without looking at the proof.
+pub fn verify_with_digest(
+    example: &str,
+    proof_bytes: &[u8],
+    digest: &PubInputDigest,
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    digest
+        .check(pub_inputs_bytes)
+        .map_err(VerifyError::Rejected)?;
+    verify_proof(example, proof_bytes, pub_inputs_bytes)
+}
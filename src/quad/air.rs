@@ -0,0 +1,108 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; each row holds a single term of the sequence.
+pub const TRACE_WIDTH: usize = 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct QuadInputs<B: ExampleField> {
+    pub start: B,
+    pub constant: B,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for QuadInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.start);
+        target.write(self.constant);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for QuadInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(QuadInputs {
+            start: B::read_from(source)?,
+            constant: B::read_from(source)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// QUADRATIC MAP AIR
+// ================================================================================================
+
+/// AIR of the sequence `x_{i+1} = x_i^2 + c`.
+///
+/// The transition constraint `x_{i+1} - x_i^2 - c` multiplies the trace column by itself, so it
+/// is declared with degree two. Over a trace of `n` steps, the trace polynomial has degree
+/// `n - 1` and the constraint polynomial degree `2 * (n - 1)`; winterfell reads this from the
+/// declared degree and evaluates the constraints over a domain of `2 * n` points, the smallest
+/// power-of-two multiple of the trace length which is larger than that degree. The domain is a
+/// subset of the LDE domain, so the blowup factor must be at least two; a constraint of degree
+/// `d` needs a blowup factor of at least `d` rounded up to a power of two, and the prover
+/// spends proportionally more time extending and committing to the trace.
+///
+/// A degree which is declared too low makes the evaluation domain too small to hold the
+/// constraint polynomial, so the composition polynomial is not of low degree and proofs are
+/// rejected; one which is declared too high only wastes prover time. `check-degrees` measures
+/// the actual degrees of the constraints.
+pub struct QuadAir<B: ExampleField> {
+    context: AirContext<B>,
+    start: B,
+    constant: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for QuadAir<B> {
+    type BaseField = B;
+    type PublicInputs = QuadInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: QuadInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let degrees = transition_degrees(&[2]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            start: pub_inputs.start,
+            constant: pub_inputs.constant,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current()[0];
+        let next = frame.next()[0];
+        result[0] = are_equal(next, current.square() + E::from(self.constant));
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence starts with the public starting point and ends with the result
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(0, self.start),
+            last_row_equals(0, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,140 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::QuadConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{QuadAir, QuadInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::QuadProver;
+
+#[cfg(test)]
+mod tests;
+
+// QUADRATIC MAP EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the last term of the sequence `x_{i+1} = x_i^2 + c`, which starts
+/// at a public starting point and has one term per step of the trace.
+///
+/// Unlike the linear recurrences of the counter and Fibonacci examples, the next term is a
+/// quadratic function of the current one, so the transition constraint has degree two, which
+/// doubles the size of the domain the prover evaluates it over.
+#[cfg(feature = "std")]
+pub struct QuadExample<B: ExampleField> {
+    trace_length: usize,
+    start: B,
+    constant: B,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> QuadExample<B> {
+    pub fn new(config: &QuadConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let start = B::from(config.start);
+        let constant = B::from(config.constant);
+
+        // compute result
+        let now = Instant::now();
+        let result = compute_term(start, constant, config.trace_length - 1);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            start,
+            constant,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &QuadConfig) -> Result<(), String> {
+        validate::check_trace_length("quad.trace_length", config.trace_length)?;
+        // the trace of a fixed point is constant, which winterfell cannot prove
+        let start = B::from(config.start);
+        if start.square() + B::from(config.constant) == start {
+            return Err(format!(
+                "starting point must not be a fixed point of the sequence, but quad.start {} \
+                 is one for quad.constant {}; change either of them",
+                config.start, config.constant
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for QuadExample<B> {
+    type Air = QuadAir<B>;
+    type Prover = QuadProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = QuadProver::build_trace(self.start, self.constant, self.trace_length);
+        assert_eq!(self.result, trace.get(0, trace.length() - 1));
+        trace
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = QuadConfig::default();
+        ExampleDescriptor::new("quad", "a term of the non-linear sequence x -> x^2 + c")
+            .with_default("trace_length", config.trace_length)
+            .with_default("start", config.start)
+            .with_default("constant", config.constant)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("X", 1)],
+            constraints: vec![("quadratic step", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> QuadProver<B> {
+        QuadProver::new(options, self.constant)
+    }
+
+    fn pub_inputs(&self) -> QuadInputs<B> {
+        QuadInputs {
+            start: self.start,
+            constant: self.constant,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> QuadInputs<B> {
+        QuadInputs {
+            start: self.start,
+            constant: self.constant,
+            result: self.result + B::ONE,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the n-th term of the sequence `x_{i+1} = x_i^2 + constant` with `x_0 = start`.
+fn compute_term<B: ExampleField>(start: B, constant: B, n: usize) -> B {
+    let mut x = start;
+    for _ in 0..n {
+        x = x.square() + constant;
+    }
+    x
+}
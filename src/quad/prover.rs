@@ -0,0 +1,49 @@
+use super::{air::TRACE_WIDTH, QuadAir, QuadInputs};
+use crate::field::ExampleField;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct QuadProver<B: ExampleField> {
+    options: ProofOptions,
+    constant: B,
+}
+
+impl<B: ExampleField> QuadProver<B> {
+    pub fn new(options: ProofOptions, constant: B) -> Self {
+        Self { options, constant }
+    }
+
+    /// Builds a trace which applies `x -> x^2 + constant` to the starting point once per row.
+    pub fn build_trace(start: B, constant: B, trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        trace.fill(
+            |state| {
+                state[0] = start;
+            },
+            |_, state| {
+                state[0] = state[0].square() + constant;
+            },
+        );
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for QuadProver<B> {
+    type BaseField = B;
+    type Air = QuadAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> QuadInputs<B> {
+        QuadInputs {
+            start: trace.get(0, 0),
+            constant: self.constant,
+            result: trace.get(0, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,103 @@
+use super::{QuadExample, QuadProver};
+use crate::{
+    config::QuadConfig,
+    error::ExampleError,
+    example::Example,
+    trace::{self, RowRange},
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, FieldExtension, HashFunction, ProofOptions, Trace,
+};
+
+#[test]
+fn quad_trace_follows_recurrence() {
+    let trace = QuadProver::build_trace(BaseElement::from(1u8), BaseElement::from(1u8), 8);
+    let terms = [1u64, 2, 5, 26, 677, 458330, 210066388901];
+    for (step, &term) in terms.iter().enumerate() {
+        assert_eq!(BaseElement::from(term), trace.get(0, step));
+    }
+    assert_eq!(
+        BaseElement::from(210066388901u64).square() + BaseElement::from(1u8),
+        trace.get(0, 7)
+    );
+}
+
+#[test]
+fn quad_trace_is_exported_as_csv() {
+    let trace = QuadProver::build_trace(BaseElement::from(1u8), BaseElement::from(1u8), 8);
+
+    let mut csv = Vec::new();
+    trace::write_csv(&trace, 0..8, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(9, lines.len());
+    assert_eq!("step,col_0", lines[0]);
+    assert_eq!("3,26", lines[4]);
+
+    let rows = "2..5".parse::<RowRange>().unwrap().resolve(8).unwrap();
+    let mut csv = Vec::new();
+    trace::write_csv(&trace, rows, &mut csv).unwrap();
+    assert_eq!(
+        "step,col_0\n2,5\n3,26\n4,677\n",
+        String::from_utf8(csv).unwrap()
+    );
+
+    assert_eq!(Ok(5..8), "5..".parse::<RowRange>().unwrap().resolve(8));
+    assert!("4..9".parse::<RowRange>().unwrap().resolve(8).is_err());
+    assert!("4..4".parse::<RowRange>().unwrap().resolve(8).is_err());
+    assert!("4".parse::<RowRange>().is_err());
+}
+
+#[test]
+fn quad_constraint_degree_doubles_evaluation_domain() {
+    let example = build_example();
+    let trace = example.build_trace();
+    let air = <QuadExample<BaseElement> as Example>::Air::new(
+        trace.get_info(),
+        example.pub_inputs(),
+        build_options(),
+    );
+    assert_eq!(2, air.ce_blowup_factor());
+    assert_eq!(2 * TRACE_LENGTH, air.ce_domain_size());
+    assert_eq!(8 * TRACE_LENGTH, air.lde_domain_size());
+}
+
+#[test]
+fn quad_fixed_point_is_not_accepted() {
+    // 0 -> 0^2 + 0 = 0
+    let err = QuadExample::<BaseElement>::new(&QuadConfig {
+        start: 0,
+        constant: 0,
+        ..QuadConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(err.to_string().contains("fixed point"), "{}", err);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 128;
+
+fn build_example() -> QuadExample<BaseElement> {
+    QuadExample::new(&QuadConfig {
+        trace_length: TRACE_LENGTH,
+        ..QuadConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
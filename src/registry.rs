@@ -0,0 +1,274 @@
+//! Commitment to a set of proof files with a Merkle tree over their hashes.
+//!
+//! A registry lets a prover publish a single digest for many proofs and later show that one
+//! proof was among them without sending the others. [commit()] hashes every proof with BLAKE3,
+//! builds a Merkle tree over the digests, and records for every proof the path of sibling
+//! digests from its leaf to the root. Anyone who knows the root can then check a proof and its
+//! path with [verify_membership()]. The number of leaves is padded to a power of two with zero
+//! digests, which no proof hashes to.
+
+use crate::error::ExampleError;
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::Path};
+use winter_utils::SliceReader;
+use winterfell::{
+    crypto::{hashers::Blake3_256, Digest, Hasher, MerkleTree},
+    math::fields::f128::BaseElement,
+    Deserializable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Hash function which hashes the proofs and the nodes of the tree.
+type ProofHasher = Blake3_256<BaseElement>;
+
+/// Digest of a proof or of a node of the tree.
+type ProofDigest = <ProofHasher as Hasher>::Digest;
+
+/// Number of bytes in a digest of [ProofHasher].
+const DIGEST_BYTES: usize = 32;
+
+// PROOF REGISTRY
+// ================================================================================================
+
+/// The root of a Merkle tree over the digests of a set of proofs, together with the opening of
+/// every proof.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRegistry {
+    /// Root of the tree as a hex string.
+    pub root: String,
+    pub entries: Vec<RegistryEntry>,
+}
+
+/// A proof in a registry and the path which opens it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    /// Name of the file the proof was read from.
+    pub file: String,
+    /// Digest of the proof as a hex string.
+    pub digest: String,
+    /// Position of the proof among the leaves of the tree.
+    pub index: usize,
+    /// Digests of the siblings of the nodes on the path from the leaf to the root as hex
+    /// strings, starting with the sibling of the leaf.
+    pub path: Vec<String>,
+}
+
+impl ProofRegistry {
+    /// Returns the entry of the specified proof, or `None` if the proof is not in the registry.
+    pub fn find(&self, proof_bytes: &[u8]) -> Option<&RegistryEntry> {
+        let digest = to_hex(&ProofHasher::hash(proof_bytes).as_bytes());
+        self.entries.iter().find(|entry| entry.digest == digest)
+    }
+
+    /// Writes the registry to the specified file as JSON.
+    pub fn write(&self, path: &Path) -> Result<(), ExampleError> {
+        let json = serde_json::to_string_pretty(self).expect("registry is serializable");
+        fs::write(path, json).map_err(|err| ExampleError::write("registry", path, err))
+    }
+
+    /// Reads a registry from the specified file.
+    ///
+    /// Returns an [Io](ExampleError::Io) error if the file cannot be read, and a
+    /// [Config](ExampleError::Config) error if it does not hold a registry.
+    pub fn read(path: &Path) -> Result<Self, ExampleError> {
+        let json =
+            fs::read_to_string(path).map_err(|err| ExampleError::read("registry", path, err))?;
+        serde_json::from_str(&json).map_err(|err| {
+            ExampleError::Config(format!(
+                "failed to parse registry {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+}
+
+impl fmt::Display for ProofRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries.iter() {
+            writeln!(f, "{:>4}  {}  {}", entry.index, entry.digest, entry.file)?;
+        }
+        write!(
+            f,
+            "Committed {} proofs to root {}",
+            self.entries.len(),
+            self.root
+        )
+    }
+}
+
+// COMMITMENT
+// ================================================================================================
+
+/// Builds a registry of the specified proofs, each given by the name of its file and its bytes.
+///
+/// Returns an error if no proofs are given.
+pub fn commit(proofs: &[(String, Vec<u8>)]) -> Result<ProofRegistry, String> {
+    if proofs.is_empty() {
+        return Err("at least one proof is needed to build a registry".to_string());
+    }
+    let mut leaves = proofs
+        .iter()
+        .map(|(_, bytes)| ProofHasher::hash(bytes))
+        .collect::<Vec<_>>();
+    let num_leaves = leaves.len().next_power_of_two().max(2);
+    leaves.resize(num_leaves, ProofDigest::default());
+    let tree = MerkleTree::<ProofHasher>::new(leaves).map_err(|err| err.to_string())?;
+
+    let entries = proofs
+        .iter()
+        .enumerate()
+        .map(|(index, (file, _))| {
+            // the first node of a winterfell Merkle path is the leaf itself
+            let path = tree.prove(index).expect("index is a leaf of the tree");
+            RegistryEntry {
+                file: file.clone(),
+                digest: to_hex(&path[0].as_bytes()),
+                index,
+                path: path[1..]
+                    .iter()
+                    .map(|node| to_hex(&node.as_bytes()))
+                    .collect(),
+            }
+        })
+        .collect();
+    Ok(ProofRegistry {
+        root: to_hex(&tree.root().as_bytes()),
+        entries,
+    })
+}
+
+/// Builds a registry of the proofs in the specified files.
+///
+/// Returns an [Io](ExampleError::Io) error if a file cannot be read, and a
+/// [Config](ExampleError::Config) error if no files are given.
+pub fn commit_files<P: AsRef<Path>>(paths: &[P]) -> Result<ProofRegistry, ExampleError> {
+    let proofs = paths
+        .iter()
+        .map(|path| {
+            let path = path.as_ref();
+            fs::read(path)
+                .map(|bytes| (path.display().to_string(), bytes))
+                .map_err(|err| ExampleError::read("proof", path, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    commit(&proofs).map_err(ExampleError::Config)
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Checks that the specified proof is the leaf opened by `entry` in the tree with the specified
+/// root, which is given as a hex string.
+///
+/// Returns an error if the proof does not hash to the digest of the entry, or if the path of the
+/// entry does not lead to the root.
+pub fn verify_membership(
+    root: &str,
+    proof_bytes: &[u8],
+    entry: &RegistryEntry,
+) -> Result<(), String> {
+    let root = parse_digest(root).map_err(|err| format!("invalid root: {}", err))?;
+    let leaf = ProofHasher::hash(proof_bytes);
+    if to_hex(&leaf.as_bytes()) != entry.digest {
+        return Err(format!(
+            "proof does not match the digest of {} in the registry",
+            entry.file
+        ));
+    }
+    if entry.path.is_empty() || entry.index >> entry.path.len() != 0 {
+        return Err(format!(
+            "path of {} does not fit leaf {}",
+            entry.file, entry.index
+        ));
+    }
+
+    let mut path = vec![leaf];
+    for node in entry.path.iter() {
+        path.push(parse_digest(node).map_err(|err| format!("invalid path: {}", err))?);
+    }
+    MerkleTree::<ProofHasher>::verify(root, entry.index, &path)
+        .map_err(|_| format!("path of {} does not lead to the root", entry.file))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn parse_digest(hex: &str) -> Result<ProofDigest, String> {
+    if hex.len() != 2 * DIGEST_BYTES || !hex.is_ascii() {
+        return Err(format!(
+            "expected {} hex digits, but got '{}'",
+            2 * DIGEST_BYTES,
+            hex
+        ));
+    }
+    let mut bytes = [0u8; DIGEST_BYTES];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+            .map_err(|_| format!("'{}' is not a hex string", hex))?;
+    }
+    ProofDigest::read_from(&mut SliceReader::new(&bytes)).map_err(|err| err.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{example, test_utils::vdf_example};
+    use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+    #[test]
+    fn registry_opens_every_committed_proof() {
+        let proofs = [24, 26, 28]
+            .iter()
+            .map(|&num_queries| {
+                let options = ProofOptions::new(
+                    num_queries,
+                    8,
+                    0,
+                    HashFunction::Blake3_256,
+                    FieldExtension::None,
+                    4,
+                    32,
+                );
+                let report = example::run(&vdf_example(), options);
+                (format!("vdf-{}.proof", num_queries), report.proof_bytes)
+            })
+            .collect::<Vec<_>>();
+        let registry = super::commit(&proofs).unwrap();
+        assert_eq!(3, registry.entries.len());
+
+        for (file, bytes) in proofs.iter() {
+            let entry = registry.find(bytes).unwrap();
+            assert_eq!(file, &entry.file);
+            assert_eq!(2, entry.path.len());
+            assert_eq!(
+                Ok(()),
+                super::verify_membership(&registry.root, bytes, entry)
+            );
+        }
+
+        let (_, bytes) = &proofs[1];
+        let entry = registry.find(bytes).unwrap();
+        let mut tampered = bytes.clone();
+        tampered[0] ^= 1;
+        assert!(registry.find(&tampered).is_none());
+        assert!(super::verify_membership(&registry.root, &tampered, entry).is_err());
+
+        let other = super::commit(&proofs[..2]).unwrap();
+        let err = super::verify_membership(&other.root, bytes, entry).unwrap_err();
+        assert!(err.contains("does not lead to the root"), "{}", err);
+
+        let mut moved = entry.clone();
+        moved.index = 0;
+        assert!(super::verify_membership(&registry.root, bytes, &moved).is_err());
+        assert!(super::commit(&[]).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use super::{rescue, Rescue, CYCLE_LENGTH};
+use crate::{
+    air_utils::{
+        are_equal, cyclic_degrees, first_row_equals, last_row_equals, not, EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Specifies steps on which Rescue transition function is applied.
+const CYCLE_MASK: [u8; CYCLE_LENGTH] = [1, 1, 1, 1, 1, 1, 1, 0];
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct RescueInputs<B: ExampleField> {
+    pub seed: [B; 2],
+    pub result: [B; 2],
+}
+
+impl<B: ExampleField> Serializable for RescueInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(&self.seed[..]);
+        target.write(&self.result[..]);
+    }
+}
+
+impl<B: ExampleField> Deserializable for RescueInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(RescueInputs {
+            seed: [B::read_from(source)?, B::read_from(source)?],
+            result: [B::read_from(source)?, B::read_from(source)?],
+        })
+    }
+}
+
+// RESCUE AIR
+// ================================================================================================
+
+pub struct RescueAir<B: ExampleField> {
+    context: AirContext<B>,
+    rescue: Rescue<B>,
+    seed: [B; 2],
+    result: [B; 2],
+}
+
+impl<B: ExampleField> Air for RescueAir<B> {
+    type BaseField = B;
+    type PublicInputs = RescueInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: RescueInputs<B>, options: ProofOptions) -> Self {
+        let degrees = cyclic_degrees(&[B::ALPHA as usize; rescue::STATE_WIDTH], CYCLE_LENGTH);
+        assert_eq!(rescue::STATE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            rescue: Rescue::new(),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // split periodic values into hash_flag and Rescue round constants
+        let hash_flag = periodic_values[0];
+        let ark = &periodic_values[1..];
+
+        // when hash_flag = 1, constraints for Rescue round are enforced
+        self.rescue
+            .enforce_round(result, current, next, ark, hash_flag);
+
+        // when hash_flag = 0, the output of the previous hash becomes the input of the next one,
+        // and the capacity portion of the state is reset to zero
+        let copy_flag = not(hash_flag);
+        result.agg_constraint(0, copy_flag, are_equal(current[0], next[0]));
+        result.agg_constraint(1, copy_flag, are_equal(current[1], next[1]));
+        result.agg_constraint(2, copy_flag, next[2]);
+        result.agg_constraint(3, copy_flag, next[3]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert that the trace starts with the seed and zeroed capacity, and ends with the
+        // result of the last hash invocation
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(0, self.seed[0]),
+            first_row_equals(1, self.seed[1]),
+            first_row_equals(2, B::ZERO),
+            first_row_equals(3, B::ZERO),
+            last_row_equals(0, trace_length, self.result[0]),
+            last_row_equals(1, trace_length, self.result[1]),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut result = vec![CYCLE_MASK.iter().map(|&flag| B::from(flag)).collect()];
+        result.append(&mut self.rescue.get_round_constants());
+        result
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
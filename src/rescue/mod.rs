@@ -0,0 +1,145 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::RescueConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    trace::TraceSteps,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{RescueAir, RescueInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::{RescueProver, RescueTrace};
+
+#[allow(clippy::module_inception)]
+pub(crate) mod rescue;
+use rescue::Rescue;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const CYCLE_LENGTH: usize = rescue::CYCLE_LENGTH;
+
+// RESCUE HASH CHAIN EXAMPLE
+// ================================================================================================
+
+/// Proves that the result was obtained by applying the Rescue hash function `chain_length`
+/// times starting from a public seed; every hash invocation occupies one cycle of
+/// [CYCLE_LENGTH] rows in the execution trace.
+#[cfg(feature = "std")]
+pub struct RescueExample<B: ExampleField> {
+    chain_length: usize,
+    seed: [B; 2],
+    result: [B; 2],
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> RescueExample<B> {
+    pub fn new(config: &RescueConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let chain_length = config.chain_length;
+        let seed = [B::from(config.seed[0]), B::from(config.seed[1])];
+
+        // compute result
+        let now = Instant::now();
+        let result = compute_hash_chain(seed, chain_length);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            chain_length,
+            seed,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &RescueConfig) -> Result<(), String> {
+        validate::check_power_of_two(
+            "chain length",
+            "rescue.chain_length",
+            config.chain_length,
+            1,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for RescueExample<B> {
+    type Air = RescueAir<B>;
+    type Prover = RescueProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        RescueProver::build_trace(self.seed, self.chain_length)
+    }
+
+    fn trace_steps(&self) -> Option<Box<dyn TraceSteps<B> + '_>> {
+        Some(Box::new(RescueTrace::new(self.seed, self.chain_length)))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = RescueConfig::default();
+        ExampleDescriptor::new(
+            "rescue",
+            "a hash chain which applies the Rescue permutation to a public seed",
+        )
+        .with_default("chain_length", config.chain_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("STATE", rescue::STATE_WIDTH)],
+            constraints: vec![("Rescue round or copy", rescue::STATE_WIDTH)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> RescueProver<B> {
+        RescueProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> RescueInputs<B> {
+        RescueInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> RescueInputs<B> {
+        RescueInputs {
+            seed: self.seed,
+            result: [self.result[0] + B::ONE, self.result[1]],
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the digest at the end of a chain of `length` Rescue hashes starting from `seed`.
+pub fn compute_hash_chain<B: ExampleField>(seed: [B; 2], length: usize) -> [B; 2] {
+    let rescue = Rescue::new();
+    let mut values = seed;
+    let mut result = [B::ZERO; 2];
+    for _ in 0..length {
+        rescue.hash(values, &mut result);
+        values.copy_from_slice(&result);
+    }
+    result
+}
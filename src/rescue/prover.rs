@@ -0,0 +1,97 @@
+use super::{rescue, Rescue, RescueAir, RescueInputs, CYCLE_LENGTH};
+use crate::{
+    field::ExampleField,
+    trace::{self, TraceSteps},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct RescueProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> RescueProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn build_trace(seed: [B; 2], chain_length: usize) -> TraceTable<B> {
+        trace::build_trace(&RescueTrace::new(seed, chain_length))
+    }
+}
+
+impl<B: ExampleField> Prover for RescueProver<B> {
+    type BaseField = B;
+    type Air = RescueAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> RescueInputs<B> {
+        let last_step = trace.length() - 1;
+        RescueInputs {
+            seed: [trace.get(0, 0), trace.get(1, 0)],
+            result: [trace.get(0, last_step), trace.get(1, last_step)],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// TRACE
+// ================================================================================================
+
+/// Execution trace of a hash chain of `chain_length` invocations starting from `seed`,
+/// described row by row.
+pub struct RescueTrace<B: ExampleField> {
+    rescue: Rescue<B>,
+    seed: [B; 2],
+    chain_length: usize,
+}
+
+impl<B: ExampleField> RescueTrace<B> {
+    pub fn new(seed: [B; 2], chain_length: usize) -> Self {
+        Self {
+            rescue: Rescue::new(),
+            seed,
+            chain_length,
+        }
+    }
+}
+
+impl<B: ExampleField> TraceSteps<B> for RescueTrace<B> {
+    fn width(&self) -> usize {
+        rescue::STATE_WIDTH
+    }
+
+    fn length(&self) -> usize {
+        self.chain_length * CYCLE_LENGTH
+    }
+
+    fn init(&self, state: &mut [B]) {
+        // initialize the state with the seed and zeroed capacity
+        state[0] = self.seed[0];
+        state[1] = self.seed[1];
+        state[2] = B::ZERO;
+        state[3] = B::ZERO;
+    }
+
+    fn update(&self, step: usize, state: &mut [B]) {
+        // execute the transition function for all steps except the last step of each cycle; for
+        // the last step, reset the capacity so that the result of the hash becomes the input of
+        // the next invocation
+        if (step % CYCLE_LENGTH) < CYCLE_LENGTH - 1 {
+            self.rescue.apply_round(state, step);
+        } else {
+            state[2] = B::ZERO;
+            state[3] = B::ZERO;
+        }
+    }
+}
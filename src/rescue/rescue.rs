@@ -0,0 +1,316 @@
+use crate::field::ExampleField;
+use winter_utils::collections::Vec;
+use winterfell::math::FieldElement;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of field elements in the Rescue state.
+pub const STATE_WIDTH: usize = 4;
+
+/// Number of field elements absorbed and squeezed by a single hash invocation.
+pub const RATE_WIDTH: usize = 2;
+
+/// Number of rounds in the Rescue permutation.
+pub const NUM_ROUNDS: usize = 7;
+
+/// Number of trace rows consumed by one permutation: a row per round plus a row for resetting
+/// the capacity portion of the state before the next invocation.
+pub const CYCLE_LENGTH: usize = 8;
+
+// RESCUE PERMUTATION
+// ================================================================================================
+
+/// Rescue permutation over a 4-element state instantiated in the base field `B`.
+///
+/// The S-box raises state elements to the power of [ExampleField::ALPHA] in the first half of
+/// each round, and to the inverse of that power in the second half. The MDS matrix and round
+/// constants are the same integers for every field, reduced modulo the field prime.
+pub struct Rescue<B: ExampleField> {
+    mds: [[B; STATE_WIDTH]; STATE_WIDTH],
+    inv_mds: [[B; STATE_WIDTH]; STATE_WIDTH],
+    ark1: [[B; STATE_WIDTH]; NUM_ROUNDS],
+    ark2: [[B; STATE_WIDTH]; NUM_ROUNDS],
+}
+
+impl<B: ExampleField> Rescue<B> {
+    pub fn new() -> Self {
+        let mut mds = [[B::ZERO; STATE_WIDTH]; STATE_WIDTH];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = B::from(MDS_ROW[(STATE_WIDTH + j - i) % STATE_WIDTH]);
+            }
+        }
+
+        Self {
+            mds,
+            inv_mds: invert_matrix(mds),
+            ark1: ARK1.map(|row| row.map(B::from)),
+            ark2: ARK2.map(|row| row.map(B::from)),
+        }
+    }
+
+    // HASH FUNCTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Hashes two field elements into two field elements.
+    pub fn hash(&self, value: [B; RATE_WIDTH], result: &mut [B]) {
+        let mut state = [B::ZERO; STATE_WIDTH];
+        state[..RATE_WIDTH].copy_from_slice(&value);
+        for i in 0..NUM_ROUNDS {
+            self.apply_round(&mut state, i);
+        }
+        result.copy_from_slice(&state[..RATE_WIDTH]);
+    }
+
+    /// Returns round constants arranged as periodic columns: four columns for the first half of
+    /// each round followed by four columns for the second half. Values for the last step of the
+    /// cycle, where no round is applied, are set to zero.
+    pub fn get_round_constants(&self) -> Vec<Vec<B>> {
+        let mut constants = vec![vec![B::ZERO; CYCLE_LENGTH]; STATE_WIDTH * 2];
+        for (round, (ark1, ark2)) in self.ark1.iter().zip(self.ark2.iter()).enumerate() {
+            for (i, (&c1, &c2)) in ark1.iter().zip(ark2.iter()).enumerate() {
+                constants[i][round] = c1;
+                constants[i + STATE_WIDTH][round] = c2;
+            }
+        }
+
+        constants
+    }
+
+    // TRACE
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies a single Rescue round to the state; `step` is the row of the execution trace at
+    /// which the round is applied.
+    pub fn apply_round(&self, state: &mut [B], step: usize) {
+        // determine which round constants to use
+        let ark1 = &self.ark1[step % CYCLE_LENGTH];
+        let ark2 = &self.ark2[step % CYCLE_LENGTH];
+
+        // apply first half of Rescue round
+        apply_sbox(state);
+        apply_matrix(state, &self.mds);
+        for i in 0..STATE_WIDTH {
+            state[i] += ark1[i];
+        }
+
+        // apply second half of Rescue round
+        apply_inv_sbox(state);
+        apply_matrix(state, &self.mds);
+        for i in 0..STATE_WIDTH {
+            state[i] += ark2[i];
+        }
+    }
+
+    // CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// When `flag` = 1, enforces constraints for a single round of Rescue permutation. The
+    /// constraints are checked by applying the first half of the round to `current`, and the
+    /// inverse of the second half of the round to `next`, and making sure the two meet in the
+    /// middle.
+    pub fn enforce_round<E: FieldElement<BaseField = B>>(
+        &self,
+        result: &mut [E],
+        current: &[E],
+        next: &[E],
+        ark: &[E],
+        flag: E,
+    ) {
+        // compute the state that should result from applying the first half of Rescue round
+        // to the current state of the computation
+        let mut step1 = [E::ZERO; STATE_WIDTH];
+        step1.copy_from_slice(current);
+        apply_sbox(&mut step1);
+        apply_matrix(&mut step1, &self.mds);
+        for i in 0..STATE_WIDTH {
+            step1[i] += ark[i];
+        }
+
+        // compute the state that should result from applying the inverse of the second half of
+        // Rescue round to the next step of the computation
+        let mut step2 = [E::ZERO; STATE_WIDTH];
+        step2.copy_from_slice(next);
+        for i in 0..STATE_WIDTH {
+            step2[i] -= ark[STATE_WIDTH + i];
+        }
+        apply_matrix(&mut step2, &self.inv_mds);
+        apply_sbox(&mut step2);
+
+        // make sure that the results are equal
+        for i in 0..STATE_WIDTH {
+            result[i] += flag * (step2[i] - step1[i]);
+        }
+    }
+}
+
+// ROUND FUNCTION COMPONENTS
+// ================================================================================================
+
+fn apply_sbox<E: FieldElement>(state: &mut [E])
+where
+    E::BaseField: ExampleField,
+{
+    for element in state.iter_mut() {
+        *element = element.exp(<E::BaseField as ExampleField>::ALPHA.into());
+    }
+}
+
+fn apply_inv_sbox<B: ExampleField>(state: &mut [B]) {
+    for element in state.iter_mut() {
+        *element = element.exp(B::INV_ALPHA);
+    }
+}
+
+fn apply_matrix<B: ExampleField, E: FieldElement<BaseField = B>>(
+    state: &mut [E],
+    matrix: &[[B; STATE_WIDTH]; STATE_WIDTH],
+) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &coefficient) in row.iter().enumerate() {
+            result[i] += E::from(coefficient) * state[j];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+/// Inverts a non-singular matrix using Gauss-Jordan elimination.
+fn invert_matrix<B: ExampleField>(
+    matrix: [[B; STATE_WIDTH]; STATE_WIDTH],
+) -> [[B; STATE_WIDTH]; STATE_WIDTH] {
+    let mut m = matrix;
+    let mut inv = [[B::ZERO; STATE_WIDTH]; STATE_WIDTH];
+    for (i, row) in inv.iter_mut().enumerate() {
+        row[i] = B::ONE;
+    }
+
+    for col in 0..STATE_WIDTH {
+        let pivot = (col..STATE_WIDTH)
+            .find(|&row| m[row][col] != B::ZERO)
+            .expect("matrix is singular");
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+
+        let scale = m[col][col].inv();
+        for j in 0..STATE_WIDTH {
+            m[col][j] *= scale;
+            inv[col][j] *= scale;
+        }
+
+        for row in 0..STATE_WIDTH {
+            if row != col && m[row][col] != B::ZERO {
+                let factor = m[row][col];
+                for j in 0..STATE_WIDTH {
+                    let (m_col, inv_col) = (m[col][j], inv[col][j]);
+                    m[row][j] -= factor * m_col;
+                    inv[row][j] -= factor * inv_col;
+                }
+            }
+        }
+    }
+
+    inv
+}
+
+// MDS MATRIX AND ROUND CONSTANTS
+// ================================================================================================
+
+// The MDS matrix is the circulant matrix with the first row [2, 3, 1, 1]; all of its square
+// sub-matrices have small non-zero determinants, and thus, it is MDS over each of the supported
+// fields. Round constants were derived by taking the first 16 bytes of
+// SHA-256("rescue-f128-ark{1,2}-{round}-{element}") and reducing them modulo the prime of the
+// 128-bit field; for smaller fields they are further reduced modulo the field prime.
+
+const MDS_ROW: [u8; STATE_WIDTH] = [2, 3, 1, 1];
+
+const ARK1: [[u128; STATE_WIDTH]; NUM_ROUNDS] = [
+    [
+        259436560899601289082091527220713642680,
+        144115161941735627361707923697780558435,
+        216248393986985302183713274714371810755,
+        107011067633129360337988303767766031333,
+    ],
+    [
+        147712050029154240505196671128239091559,
+        190972856263937405492983144280684825604,
+        41126270870005770403852986718498195919,
+        229980935242985626962850192348334528597,
+    ],
+    [
+        39674041787869417074858509850307320599,
+        286783443501305874560925829727606311683,
+        67193655211026124723483379663559732396,
+        271114245453144708685223266788669675118,
+    ],
+    [
+        337184066522149513796458894806833846805,
+        308523544021672284444188734060898318521,
+        304388149911266426108714681919794387001,
+        267793760482707715770073234317121071843,
+    ],
+    [
+        257324086881228515453642351805088170198,
+        20030300272134258206602993563766723948,
+        221336769474970907419814122653419467180,
+        334292659614042403912696273674838933235,
+    ],
+    [
+        40675256128514316215268636933927920606,
+        298959264961528646671776379364150280126,
+        17742322420196800471274961419295265979,
+        10314689778139141880007796297765851158,
+    ],
+    [
+        246847383351626374767711244184395026784,
+        141927520448749149990149456153849619280,
+        55624677598944286205858861599294264503,
+        292304894827562462853928662747366098272,
+    ],
+];
+
+const ARK2: [[u128; STATE_WIDTH]; NUM_ROUNDS] = [
+    [
+        212666218864685667277792318125721093644,
+        75468875093797384743392893419007907161,
+        312441619289037338226659328975695436278,
+        302231564201867575775260333905108704805,
+    ],
+    [
+        200234413502991217571413924030107325815,
+        223731972946872013764437129190247539101,
+        288864083621752929333882147073998608604,
+        276161632149205766896563091178280962387,
+    ],
+    [
+        113602726447417551302192742250924194272,
+        174679790107744031120024095887841108616,
+        318543900612146681407527732704776976823,
+        87291622367323731045870595630066675193,
+    ],
+    [
+        100909481088964383739007587391218143864,
+        213566728852655264525971051603292140788,
+        121095212472947168384923847359503190757,
+        95279908396954767313234393149210370577,
+    ],
+    [
+        164774188958240537405398990047713213685,
+        269886653467420542295094102130695343859,
+        209485858174633939959404938625467911388,
+        160305691501127613770099262435140298540,
+    ],
+    [
+        186672682176911659851038156268798303976,
+        162101279352084305314773643822806109158,
+        121558437494192635766232729953098332766,
+        162015123366822880819868424249233837583,
+    ],
+    [
+        187722199546459890629680548913888421035,
+        242974339773685379284134520390408493595,
+        333313612615465401452711030984278141384,
+        156396979297321635261350505702616165527,
+    ],
+];
@@ -0,0 +1,119 @@
+use super::RescueExample;
+use crate::{
+    config::{CounterConfig, RescueConfig},
+    counter::CounterExample,
+    example::{self, Example},
+    trace::ChunkedTraceBuilder,
+};
+use std::{env, fs, path::PathBuf, process};
+use winterfell::{
+    math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions, Trace,
+};
+
+#[test]
+fn rescue_checkpointed_trace_matches_trace() {
+    let dir = checkpoint_dir("matches");
+    let example = build_example();
+    let builder = ChunkedTraceBuilder::new(&dir, 32);
+    let steps = example.trace_steps().unwrap();
+    let expected = example.build_trace();
+
+    // the first build writes all chunks, and the second one reads them back
+    for _ in 0..2 {
+        let trace = builder.build(&*steps).unwrap();
+        assert_eq!(4, fs::read_dir(&dir).unwrap().count());
+        for column in 0..expected.width() {
+            assert_eq!(expected.get_register(column), trace.get_register(column));
+        }
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rescue_checkpointed_trace_resumes_after_interruption() {
+    let dir = checkpoint_dir("resumes");
+    let example = build_example();
+    let builder = ChunkedTraceBuilder::new(&dir, 32);
+    let steps = example.trace_steps().unwrap();
+    builder.build(&*steps).unwrap();
+
+    // an interrupted build leaves the first chunks and possibly a partially written one behind
+    fs::remove_file(dir.join("chunk-000002.bin")).unwrap();
+    fs::remove_file(dir.join("chunk-000003.bin")).unwrap();
+    fs::write(dir.join("chunk-000002.part"), [1, 2, 3]).unwrap();
+
+    let trace = builder.build(&*steps).unwrap();
+    let expected = example.build_trace();
+    for column in 0..expected.width() {
+        assert_eq!(expected.get_register(column), trace.get_register(column));
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rescue_checkpoints_of_another_trace_are_rejected() {
+    let dir = checkpoint_dir("rejected");
+    let builder = ChunkedTraceBuilder::new(&dir, 32);
+    builder
+        .build(&*build_example().trace_steps().unwrap())
+        .unwrap();
+
+    let other = RescueExample::<BaseElement>::new(&RescueConfig {
+        chain_length: 16,
+        seed: [1, 2],
+        ..RescueConfig::default()
+    })
+    .unwrap();
+    match builder.build(&*other.trace_steps().unwrap()) {
+        Ok(_) => panic!("checkpoints of another trace were accepted"),
+        Err(err) => assert!(err.contains("different trace"), "{}", err),
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rescue_checkpointed_proof_verifies() {
+    let dir = checkpoint_dir("proof");
+    let builder = ChunkedTraceBuilder::new(&dir, 32);
+    let report = example::run_with_checkpoints(&build_example(), build_options(), &builder);
+    assert!(report.unwrap().is_verified());
+
+    // examples which build their trace in one piece cannot be checkpointed
+    let counter = CounterExample::<BaseElement>::new(&CounterConfig {
+        num_blocks: 16,
+        ..CounterConfig::default()
+    })
+    .unwrap();
+    assert!(example::run_with_checkpoints(&counter, build_options(), &builder).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an empty directory for the checkpoints of the named test.
+fn checkpoint_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("stark-examples-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn build_example() -> RescueExample<BaseElement> {
+    RescueExample::new(&RescueConfig {
+        chain_length: 16,
+        ..RescueConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,147 @@
+use crate::{
+    air_utils::{
+        are_equal, first_row_equals, last_row_equals, transition_degrees, EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout. `ADDRESS` and `VALUE` hold the read made in a row, `SUM` the sum of
+/// the values read in all previous rows, and the columns from `SELECTORS` on hold one selector
+/// per table entry, of which exactly the one of the address read is set.
+pub const ADDRESS: usize = 0;
+pub const VALUE: usize = 1;
+pub const SUM: usize = 2;
+pub const SELECTORS: usize = 3;
+
+/// Largest number of entries in the table; the trace has a column per entry.
+pub const MAX_TABLE_SIZE: usize = 64;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct RomInputs<B: ExampleField> {
+    pub table: Vec<B>,
+    pub result: B,
+}
+
+impl<B: ExampleField> RomInputs<B> {
+    /// Returns the number of columns in the trace of this statement.
+    pub fn trace_width(&self) -> usize {
+        SELECTORS + self.table.len()
+    }
+}
+
+impl<B: ExampleField> Serializable for RomInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.table.len() as u32);
+        target.write(&self.table[..]);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for RomInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let size = source.read_u32()? as usize;
+        if !(2..=MAX_TABLE_SIZE).contains(&size) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid table size {}",
+                size
+            )));
+        }
+        Ok(RomInputs {
+            table: B::read_batch_from(source, size)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// READ-ONLY MEMORY AIR
+// ================================================================================================
+
+/// AIR of a sequence of reads from a public table, whose values are summed up.
+///
+/// Each row carries one selector `s_i` per table entry `t_i`, and the read `(address, value)`
+/// of the row is tied to the table by:
+///
+/// * `s_i * s_i = s_i` for every entry, so that each selector is binary;
+/// * `sum(s_i) = 1`, so that exactly one selector is set;
+/// * `address = sum(i * s_i)` and `value = sum(t_i * s_i)`, so that the read is the entry the
+///   set selector picks.
+///
+/// The table is a public input, so the last two constraints are linear in the trace and only
+/// the binary constraints have degree two. This costs a column per entry and thus suits small
+/// tables only; large tables are looked up with a permutation argument over a sorted copy of
+/// the reads, which needs a verifier challenge that winterfell 0.3 cannot provide.
+pub struct RomAir<B: ExampleField> {
+    context: AirContext<B>,
+    pub_inputs: RomInputs<B>,
+}
+
+impl<B: ExampleField> Air for RomAir<B> {
+    type BaseField = B;
+    type PublicInputs = RomInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: RomInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.trace_width(), trace_info.width());
+        let mut degrees = transition_degrees(&vec![2; pub_inputs.table.len()]);
+        degrees.append(&mut transition_degrees(&[1; 4]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let table = &self.pub_inputs.table;
+        let selectors = &current[SELECTORS..];
+
+        // exactly one selector is set
+        result.enforce_binary(0, selectors);
+        let n = table.len();
+        let mut num_selected = E::ZERO;
+        let mut address = E::ZERO;
+        let mut value = E::ZERO;
+        for (i, (&selector, &entry)) in selectors.iter().zip(table).enumerate() {
+            num_selected += selector;
+            address += selector * E::from(i as u32);
+            value += selector * E::from(entry);
+        }
+        result[n] = are_equal(num_selected, E::ONE);
+
+        // the read is the table entry of the set selector
+        result[n + 1] = are_equal(current[ADDRESS], address);
+        result[n + 2] = are_equal(current[VALUE], value);
+
+        // the value read is added to the sum
+        result[n + 3] = are_equal(next[SUM], current[SUM] + current[VALUE]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sum goes from zero to the result
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(SUM, B::ZERO),
+            last_row_equals(SUM, trace_length, self.pub_inputs.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::RomConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{RomAir, RomInputs, MAX_TABLE_SIZE};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::RomProver;
+
+#[cfg(test)]
+mod tests;
+
+// READ-ONLY MEMORY EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the sum of values read from a small public table at private
+/// addresses, one read per step.
+///
+/// Every read is checked against the table by one-hot selector columns rather than by a
+/// sorted copy of the reads and a permutation argument, which winterfell 0.3 cannot express;
+/// see [RomAir] for the constraints.
+#[cfg(feature = "std")]
+pub struct RomExample<B: ExampleField> {
+    seed: u64,
+    table: Vec<B>,
+    addresses: Vec<usize>,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> RomExample<B> {
+    pub fn new(config: &RomConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let table = config
+            .table
+            .iter()
+            .map(|&value| B::from(value))
+            .collect::<Vec<_>>();
+
+        // the last row is not covered by transition constraints, so nothing is read there
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let addresses = (0..config.trace_length - 1)
+            .map(|_| rng.gen_range(0..table.len()))
+            .collect::<Vec<_>>();
+
+        // compute result
+        let now = Instant::now();
+        let result = addresses
+            .iter()
+            .fold(B::ZERO, |sum, &address| sum + table[address]);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            table,
+            addresses,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &RomConfig) -> Result<(), String> {
+        validate::check_trace_length("rom.trace_length", config.trace_length)?;
+        // with a single entry every read is the same, and the selector column is constant
+        if config.table.len() < 2 {
+            return Err(format!(
+                "table must have at least two entries, but rom.table has {}",
+                config.table.len()
+            ));
+        }
+        if config.table.len() > MAX_TABLE_SIZE {
+            return Err(format!(
+                "table must have at most {} entries, but rom.table has {}; each entry adds a \
+                 selector column to the trace",
+                MAX_TABLE_SIZE,
+                config.table.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for RomExample<B> {
+    type Air = RomAir<B>;
+    type Prover = RomProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        RomProver::build_trace(&self.table, &self.addresses)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = RomConfig::default();
+        ExampleDescriptor::new(
+            "rom",
+            "the sum of values read at private addresses from a public table",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("table", config.table)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        let n = self.table.len();
+        AirMetadata {
+            columns: vec![("ADDRESS", 1), ("VALUE", 1), ("SUM", 1), ("SELECTORS", n)],
+            constraints: vec![
+                ("binary selectors", n),
+                ("one selector set", 1),
+                ("address lookup", 1),
+                ("value lookup", 1),
+                ("sum accumulation", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> RomProver<B> {
+        RomProver::new(options, self.table.clone())
+    }
+
+    fn pub_inputs(&self) -> RomInputs<B> {
+        RomInputs {
+            table: self.table.clone(),
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> RomInputs<B> {
+        RomInputs {
+            result: self.result + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
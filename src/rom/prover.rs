@@ -0,0 +1,55 @@
+use super::{
+    air::{ADDRESS, SELECTORS, SUM, VALUE},
+    RomAir, RomInputs,
+};
+use crate::field::ExampleField;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct RomProver<B: ExampleField> {
+    options: ProofOptions,
+    table: Vec<B>,
+}
+
+impl<B: ExampleField> RomProver<B> {
+    pub fn new(options: ProofOptions, table: Vec<B>) -> Self {
+        Self { options, table }
+    }
+
+    /// Builds a trace which reads `table` at `addresses`, one address per row, and sums up the
+    /// values read; the last row, which follows the last read, repeats the first read so that
+    /// its selectors stay one-hot.
+    pub fn build_trace(table: &[B], addresses: &[usize]) -> TraceTable<B> {
+        let trace_length = addresses.len() + 1;
+        let mut columns = vec![vec![B::ZERO; trace_length]; SELECTORS + table.len()];
+        let mut sum = B::ZERO;
+        for (step, &address) in addresses.iter().chain(addresses.first()).enumerate() {
+            columns[ADDRESS][step] = B::from(address as u64);
+            columns[VALUE][step] = table[address];
+            columns[SUM][step] = sum;
+            columns[SELECTORS + address][step] = B::ONE;
+            sum += table[address];
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for RomProver<B> {
+    type BaseField = B;
+    type Air = RomAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> RomInputs<B> {
+        let last_step = trace.length() - 1;
+        RomInputs {
+            table: self.table.clone(),
+            result: trace.get(SUM, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,185 @@
+use super::{
+    air::{ADDRESS, SELECTORS, SUM, VALUE},
+    RomExample, RomInputs, RomProver,
+};
+use crate::{
+    config::{ProofOptionsConfig, RomConfig, RunConfig},
+    constraints, describe,
+    error::ExampleError,
+    example::Example,
+    run_example,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn rom_constraint_check_names_failing_assertion() {
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let last_step = TRACE_LENGTH - 1;
+    trace.set(SUM, last_step, trace.get(SUM, last_step) + BaseElement::ONE);
+    let check = constraints::check_trace_constraints(&example, &trace, build_options()).unwrap();
+    let transition = check.transition_failure.unwrap();
+    assert_eq!("sum accumulation", transition.constraint);
+    assert_eq!(last_step - 1, transition.step);
+    let assertion = check.assertion_failure.unwrap();
+    assert_eq!("SUM", assertion.column);
+    assert_eq!(last_step, assertion.step);
+    assert_eq!(assertion.expected + BaseElement::ONE, assertion.actual);
+}
+
+#[test]
+fn rom_description_finds_columns_read_by_constraints() {
+    let description = describe::describe("rom", &build_example(), build_options()).unwrap();
+    let names = |groups: &[usize]| {
+        groups
+            .iter()
+            .map(|&group| description.columns[group].name)
+            .collect::<Vec<_>>()
+    };
+    let lookup = &description.constraints[3];
+    assert_eq!("value lookup", lookup.name);
+    assert_eq!(vec!["VALUE", "SELECTORS"], names(&lookup.reads_current));
+    assert!(lookup.reads_next.is_empty());
+    let sum = &description.constraints[4];
+    assert_eq!(vec!["VALUE", "SUM"], names(&sum.reads_current));
+    assert_eq!(vec!["SUM"], names(&sum.reads_next));
+}
+
+#[test]
+fn rom_trace_reads_table() {
+    let table = to_elements(&TABLE);
+    let trace = RomProver::build_trace(&table, &[2, 0, 2, 1, 3, 0, 1]);
+    let reads = [2usize, 0, 2, 1, 3, 0, 1, 2];
+    let sums = [0u64, 5, 9, 14, 23, 24, 28, 37];
+    for (step, &address) in reads.iter().enumerate() {
+        assert_eq!(BaseElement::from(address as u64), trace.get(ADDRESS, step));
+        assert_eq!(table[address], trace.get(VALUE, step));
+        assert_eq!(BaseElement::from(sums[step]), trace.get(SUM, step));
+        for entry in 0..table.len() {
+            let selected = BaseElement::from((entry == address) as u8);
+            assert_eq!(selected, trace.get(SELECTORS + entry, step));
+        }
+    }
+}
+
+#[test]
+fn rom_value_of_other_entry_is_rejected() {
+    // read address 0 but take the value of entry 1, keeping the selectors and the running sum
+    // consistent with the forged value; only the value lookup catches the forgery
+    let table = to_elements(&TABLE);
+    let addresses = (0..TRACE_LENGTH - 1)
+        .map(|step| step % TABLE.len())
+        .collect::<Vec<_>>();
+    let mut trace = RomProver::build_trace(&table, &addresses);
+    let forged = table[1] - table[0];
+    trace.set(VALUE, 4, table[1]);
+    for step in 5..TRACE_LENGTH {
+        trace.set(SUM, step, trace.get(SUM, step) + forged);
+    }
+    let result = trace.get(SUM, TRACE_LENGTH - 1);
+
+    let proof = RomProver::new(build_options(), table.clone())
+        .prove(trace)
+        .unwrap();
+    let pub_inputs = RomInputs { table, result };
+    assert!(
+        winterfell::verify::<<RomExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_err()
+    );
+}
+
+#[test]
+fn rom_proof_against_other_table_is_rejected() {
+    let example = build_example();
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    let mut pub_inputs = example.pub_inputs();
+    pub_inputs.table[2] += BaseElement::ONE;
+    assert!(
+        winterfell::verify::<<RomExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_err()
+    );
+}
+
+#[test]
+fn rom_seed_reproduces_inputs_and_is_reported() {
+    let mut config = RunConfig {
+        example: "rom".to_string(),
+        rom: RomConfig {
+            trace_length: TRACE_LENGTH,
+            ..RomConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    config.set_seed(5).unwrap();
+    let first = run_example(&config).unwrap();
+    let second = run_example(&config).unwrap();
+    assert_eq!(Some(5), first.seed);
+    assert_eq!(first.pub_inputs_bytes, second.pub_inputs_bytes);
+    assert_eq!(first.proof_bytes, second.proof_bytes);
+    assert!(toml::to_string(&first).unwrap().contains("seed = 5"));
+
+    config.set_seed(6).unwrap();
+    assert_ne!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+
+    // the inputs of the VDF are not random, so it has no seed to set
+    config.example = "vdf".to_string();
+    assert!(config.set_seed(5).is_err());
+}
+
+#[test]
+fn rom_table_with_one_entry_is_not_accepted() {
+    let err = RomExample::<BaseElement>::new(&RomConfig {
+        trace_length: TRACE_LENGTH,
+        table: vec![7],
+        ..RomConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(err.to_string().contains("at least two entries"), "{}", err);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 128;
+const TABLE: [u64; 4] = [4, 9, 5, 1];
+
+fn build_example() -> RomExample<BaseElement> {
+    RomExample::new(&RomConfig {
+        trace_length: TRACE_LENGTH,
+        table: TABLE.to_vec(),
+        ..RomConfig::default()
+    })
+    .unwrap()
+}
+
+fn to_elements(values: &[u64]) -> Vec<BaseElement> {
+    values
+        .iter()
+        .map(|&value| BaseElement::from(value))
+        .collect()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,324 @@
+use crate::{
+    bitwise::BitwiseExample,
+    bytecount::ByteCountExample,
+    compare::CompareExample,
+    config::{ProofOptionsConfig, RunConfig},
+    counter::CounterExample,
+    degrees::degree_bounds,
+    error::ExampleError,
+    example::{self, Example, RunReport},
+    exp::ExpExample,
+    fib::FibExample,
+    fibchunk::FibChunkExample,
+    fibdiv::FibDivExample,
+    fibmod::FibModExample,
+    field::{ExampleField, FieldType},
+    fixedexp::FixedExpExample,
+    gcd::GcdExample,
+    horner::HornerExample,
+    iszero::IsZeroExample,
+    lamport::LamportExample,
+    merkle::MerkleExample,
+    modexp::ModExpExample,
+    plugins::{self, DynExample},
+    poseidon::PoseidonExample,
+    quad::QuadExample,
+    rescue::RescueExample,
+    rom::RomExample,
+    runsum::RunSumExample,
+    sort::SortExample,
+    sqrt::SqrtExample,
+    sum::SumExample,
+    validate::{check_blowup_factor, trace_info, validate},
+    vdf::VdfExample,
+    vm::VmExample,
+    window::WindowExample,
+    wrap::WrapExample,
+    EXAMPLES,
+};
+use winterfell::math::fields::{f128, f62, f64};
+
+// RUNNER
+// ================================================================================================
+
+/// Proves and verifies the example selected in the config.
+///
+/// Returns a [Config](ExampleError::Config) error if the config names an unknown example, or if
+/// it does not pass [validate()](crate::validate::validate), and a
+/// [Proving](ExampleError::Proving) error if the prover fails. A proof which the verifier
+/// rejects is not an error of the run; it is recorded in the report.
+pub fn run_example(config: &RunConfig) -> Result<RunReport, ExampleError> {
+    struct Run;
+
+    impl ExampleCommand for Run {
+        type Output = Result<RunReport, ExampleError>;
+
+        fn execute<E: Example>(
+            self,
+            example: &E,
+            options: ProofOptionsConfig,
+        ) -> Result<RunReport, ExampleError> {
+            example::try_run(example, options.to_proof_options())
+        }
+
+        fn execute_registered(
+            self,
+            example: &dyn DynExample,
+            options: ProofOptionsConfig,
+        ) -> Result<Self::Output, ExampleError> {
+            Ok(Ok(example.run(options.to_proof_options())))
+        }
+    }
+
+    with_example(config, Run)?
+}
+
+// EXAMPLE COMMANDS
+// ================================================================================================
+
+/// A command which operates on the example selected in a config.
+pub trait ExampleCommand {
+    type Output;
+
+    /// Whether the command proves the example, or otherwise needs proof options under which it
+    /// can be proven; if so, the blowup factor is checked against the constraints of the example
+    /// before the command is executed (see
+    /// [check_blowup_factor()](crate::validate::check_blowup_factor)).
+    const NEEDS_PROVABLE_OPTIONS: bool = true;
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) -> Self::Output;
+
+    /// Executes the command against an example registered by another crate, which is only known
+    /// through its [DynExample] view (see [plugins]); by default the command is not supported.
+    fn execute_registered(
+        self,
+        example: &dyn DynExample,
+        _options: ProofOptionsConfig,
+    ) -> Result<Self::Output, ExampleError>
+    where
+        Self: Sized,
+    {
+        Err(ExampleError::Config(format!(
+            "example '{}' is registered by another crate, and this command needs an example of \
+             this crate",
+            example.descriptor().name
+        )))
+    }
+}
+
+/// Instantiates the example selected in the config over the selected base field and executes
+/// the command against it.
+///
+/// An example registered by another crate is not instantiated from the config, since it was
+/// registered as an instance; the command is executed against that instance with the shared
+/// proof options of the config.
+///
+/// Returns a [Config](ExampleError::Config) error if the config names an unknown example, if it
+/// does not pass [validate()](crate::validate::validate), or if the command needs provable
+/// options and the blowup factor is too small for the constraints of the example.
+pub fn with_example<C: ExampleCommand>(
+    config: &RunConfig,
+    command: C,
+) -> Result<C::Output, ExampleError> {
+    if !EXAMPLES.contains(&config.example.as_str()) {
+        if let Some(example) = plugins::registered_example(&config.example) {
+            let options = config.proof_options_for(&ProofOptionsConfig::default());
+            if C::NEEDS_PROVABLE_OPTIONS {
+                check_blowup_factor(
+                    &config.example,
+                    example.degree_bounds(),
+                    &options.to_proof_options(),
+                )
+                .map_err(ExampleError::Config)?;
+            }
+            return command.execute_registered(&*example, options);
+        }
+    }
+    match config.field {
+        FieldType::F128 => with_example_in_field::<f128::BaseElement, C>(config, command),
+        FieldType::F64 => with_example_in_field::<f64::BaseElement, C>(config, command),
+        FieldType::F62 => with_example_in_field::<f62::BaseElement, C>(config, command),
+    }
+}
+
+fn with_example_in_field<B: ExampleField, C: ExampleCommand>(
+    config: &RunConfig,
+    command: C,
+) -> Result<C::Output, ExampleError> {
+    validate::<B>(config).map_err(ExampleError::Config)?;
+    let command = CheckBlowupFactor { command, config };
+    let output = match config.example.as_str() {
+        "vdf" => command.execute(
+            &VdfExample::<B>::new(&config.vdf)?,
+            config.proof_options_for(&config.vdf.proof_options),
+        ),
+        "rescue" => command.execute(
+            &RescueExample::<B>::new(&config.rescue)?,
+            config.proof_options_for(&config.rescue.proof_options),
+        ),
+        "poseidon" => command.execute(
+            &PoseidonExample::<B>::new(&config.poseidon)?,
+            config.proof_options_for(&config.poseidon.proof_options),
+        ),
+        "counter" => command.execute(
+            &CounterExample::<B>::new(&config.counter)?,
+            config.proof_options_for(&config.counter.proof_options),
+        ),
+        "lamport" => command.execute(
+            &LamportExample::<B>::new(&config.lamport)?,
+            config.proof_options_for(&config.lamport.proof_options),
+        ),
+        "exp" => command.execute(
+            &ExpExample::<B>::new(&config.exp),
+            config.proof_options_for(&config.exp.proof_options),
+        ),
+        "sum" => command.execute(
+            &SumExample::<B>::new(&config.sum)?,
+            config.proof_options_for(&config.sum.proof_options),
+        ),
+        "vm" => command.execute(
+            &VmExample::<B>::new(&config.vm)?,
+            config.proof_options_for(&config.vm.proof_options),
+        ),
+        "fib" => command.execute(
+            &FibExample::<B>::new(&config.fib)?,
+            config.proof_options_for(&config.fib.proof_options),
+        ),
+        "gcd" => command.execute(
+            &GcdExample::<B>::new(&config.gcd)?,
+            config.proof_options_for(&config.gcd.proof_options),
+        ),
+        "quad" => command.execute(
+            &QuadExample::<B>::new(&config.quad)?,
+            config.proof_options_for(&config.quad.proof_options),
+        ),
+        "iszero" => command.execute(
+            &IsZeroExample::<B>::new(&config.iszero)?,
+            config.proof_options_for(&config.iszero.proof_options),
+        ),
+        "rom" => command.execute(
+            &RomExample::<B>::new(&config.rom)?,
+            config.proof_options_for(&config.rom.proof_options),
+        ),
+        "sqrt" => command.execute(
+            &SqrtExample::<B>::new(&config.sqrt)?,
+            config.proof_options_for(&config.sqrt.proof_options),
+        ),
+        "merkle" => command.execute(
+            &MerkleExample::<B>::new(&config.merkle)?,
+            config.proof_options_for(&config.merkle.proof_options),
+        ),
+        "bitwise" => command.execute(
+            &BitwiseExample::<B>::new(&config.bitwise)?,
+            config.proof_options_for(&config.bitwise.proof_options),
+        ),
+        "compare" => command.execute(
+            &CompareExample::<B>::new(&config.compare)?,
+            config.proof_options_for(&config.compare.proof_options),
+        ),
+        "fibmod" => command.execute(
+            &FibModExample::<B>::new(&config.fibmod)?,
+            config.proof_options_for(&config.fibmod.proof_options),
+        ),
+        "sort" => command.execute(
+            &SortExample::<B>::new(&config.sort)?,
+            config.proof_options_for(&config.sort.proof_options),
+        ),
+        "fibchunk" => command.execute(
+            &FibChunkExample::<B>::new(&config.fibchunk)?,
+            config.proof_options_for(&config.fibchunk.proof_options),
+        ),
+        "fixedexp" => command.execute(
+            &FixedExpExample::<B>::new(&config.fixedexp)?,
+            config.proof_options_for(&config.fixedexp.proof_options),
+        ),
+        "modexp" => command.execute(
+            &ModExpExample::<B>::new(&config.modexp)?,
+            config.proof_options_for(&config.modexp.proof_options),
+        ),
+        "bytecount" => command.execute(
+            &ByteCountExample::<B>::new(&config.bytecount)?,
+            config.proof_options_for(&config.bytecount.proof_options),
+        ),
+        "runsum" => command.execute(
+            &RunSumExample::<B>::new(&config.runsum)?,
+            config.proof_options_for(&config.runsum.proof_options),
+        ),
+        "horner" => command.execute(
+            &HornerExample::<B>::new(&config.horner)?,
+            config.proof_options_for(&config.horner.proof_options),
+        ),
+        "wrap" => command.execute(
+            &WrapExample::<B>::new(&config.wrap)?,
+            config.proof_options_for(&config.wrap.proof_options),
+        ),
+        "window" => command.execute(
+            &WindowExample::<B>::new(&config.window)?,
+            config.proof_options_for(&config.window.proof_options),
+        ),
+        "fibdiv" => command.execute(
+            &FibDivExample::<B>::new(&config.fibdiv)?,
+            config.proof_options_for(&config.fibdiv.proof_options),
+        ),
+        name => {
+            return Err(ExampleError::Config(format!(
+                "unknown example '{}'; available examples: {}",
+                name,
+                plugins::example_names().join(", ")
+            )))
+        }
+    };
+    output
+}
+
+/// A command which is executed only if the blowup factor suits the constraints of the example
+/// (see [check_blowup_factor()]), so that a blowup factor which is too small is reported as a
+/// setting rather than by a panic of the prover. The degrees are read from an AIR over a trace
+/// of the shape the config selects, so the trace itself is not built.
+struct CheckBlowupFactor<'a, C> {
+    command: C,
+    config: &'a RunConfig,
+}
+
+impl<'a, C: ExampleCommand> ExampleCommand for CheckBlowupFactor<'a, C> {
+    type Output = Result<C::Output, ExampleError>;
+
+    fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) -> Self::Output {
+        if C::NEEDS_PROVABLE_OPTIONS {
+            let bounds = degree_bounds(example, trace_info(self.config));
+            check_blowup_factor(&self.config.example, bounds, &options.to_proof_options())
+                .map_err(ExampleError::Config)?;
+        }
+        Ok(self.command.execute(example, options))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::{ProofOptionsConfig, RunConfig, VdfConfig},
+        run_example,
+    };
+
+    #[test]
+    fn run_example_selects_example_by_name() {
+        let mut config = RunConfig {
+            vdf: VdfConfig {
+                trace_length: 128,
+                ..VdfConfig::default()
+            },
+            proof_options: ProofOptionsConfig {
+                grinding_factor: Some(0),
+                ..ProofOptionsConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        assert!(run_example(&config).unwrap().is_verified());
+
+        config.example = "fibonacci".to_string();
+        assert!(run_example(&config).is_err());
+    }
+}
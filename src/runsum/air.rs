@@ -0,0 +1,111 @@
+use crate::{
+    air_utils::{cyclic_degrees, last_row_equals, transition_degrees},
+    field::ExampleField,
+    gadgets::{first_row_flags, running_sum},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout. `VALUE` holds the private value of a row, and `SUM` the sum of the
+/// values of this row and all previous ones.
+pub const VALUE: usize = 0;
+pub const SUM: usize = 1;
+pub const TRACE_WIDTH: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// The total is the only public input; the values it sums are chosen freely by the prover.
+#[derive(Clone)]
+pub struct RunSumInputs<B: ExampleField> {
+    pub total: B,
+}
+
+impl<B: ExampleField> Serializable for RunSumInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.total);
+    }
+}
+
+impl<B: ExampleField> Deserializable for RunSumInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(RunSumInputs {
+            total: B::read_from(source)?,
+        })
+    }
+}
+
+// RUNNING SUM AIR
+// ================================================================================================
+
+/// AIR of a private column of values, one per row, and a column of their running sums.
+///
+/// The constraints are those of the running-sum gadget of [crate::gadgets]:
+///
+/// * `is_first * (sum - value) = 0`, where `is_first` is a periodic column which is one on the
+///   first row only, so that the sum starts with the first value;
+/// * `sum' = sum + value'`, which adds the value of every later row.
+///
+/// The first and the last row are treated differently. A transition constraint relates a row to
+/// the next one, so `sum' = sum + value'` reaches the value of the last row but never the one of
+/// the first row, which needs a constraint of its own. The last row is pinned by an assertion
+/// that the sum equals the public total; the first row cannot be, since the first value is
+/// private.
+pub struct RunSumAir<B: ExampleField> {
+    context: AirContext<B>,
+    total: B,
+}
+
+impl<B: ExampleField> Air for RunSumAir<B> {
+    type BaseField = B;
+    type PublicInputs = RunSumInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: RunSumInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        // only the first constraint reads the periodic column
+        let mut degrees = cyclic_degrees(&[1], trace_info.length());
+        degrees.append(&mut transition_degrees(&[1]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            total: pub_inputs.total,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let [first, rest] = running_sum(
+            current[SUM],
+            current[VALUE],
+            next[SUM],
+            next[VALUE],
+            periodic_values[0],
+        );
+        result[0] = first;
+        result[1] = rest;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sum ends with the public total
+        vec![last_row_equals(SUM, self.trace_length(), self.total)]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![first_row_flags(self.trace_length())]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
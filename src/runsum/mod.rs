@@ -0,0 +1,120 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::RunSumConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{RunSumAir, RunSumInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::RunSumProver;
+
+#[cfg(test)]
+mod tests;
+
+// RUNNING SUM EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the sum of some private column of values, one per step.
+///
+/// Unlike the `sum` example, whose vector is public and placed into the trace by assertions, the
+/// values here are only committed to in the trace, and a running sum accumulates them with the
+/// running-sum gadget of [crate::gadgets]; see [RunSumAir] for how the first and the last row
+/// are constrained.
+///
+/// The values are private and bound to nothing the verifier knows; they are drawn from the seed,
+/// but the seed is not part of the statement. A proof therefore exists for every total, and shows
+/// only that the running sums in the trace were accumulated correctly, not which values were
+/// summed.
+#[cfg(feature = "std")]
+pub struct RunSumExample<B: ExampleField> {
+    seed: u64,
+    values: Vec<B>,
+    total: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> RunSumExample<B> {
+    pub fn new(config: &RunSumConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let values = (0..config.trace_length)
+            .map(|_| B::from(rng.gen::<u64>()))
+            .collect::<Vec<_>>();
+
+        // compute result
+        let now = Instant::now();
+        let total = values.iter().fold(B::ZERO, |total, &value| total + value);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.seed,
+            values,
+            total,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &RunSumConfig) -> Result<(), String> {
+        validate::check_trace_length("runsum.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for RunSumExample<B> {
+    type Air = RunSumAir<B>;
+    type Prover = RunSumProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        RunSumProver::build_trace(&self.values)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = RunSumConfig::default();
+        ExampleDescriptor::new("runsum", "the sum of a private column of values")
+            .with_default("trace_length", config.trace_length)
+            .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("VALUE", 1), ("SUM", 1)],
+            constraints: vec![("first sum", 1), ("sum accumulation", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> RunSumProver<B> {
+        RunSumProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> RunSumInputs<B> {
+        RunSumInputs { total: self.total }
+    }
+
+    fn wrong_pub_inputs(&self) -> RunSumInputs<B> {
+        RunSumInputs {
+            total: self.total + B::ONE,
+        }
+    }
+}
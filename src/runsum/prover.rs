@@ -0,0 +1,49 @@
+use super::{
+    air::{SUM, TRACE_WIDTH, VALUE},
+    RunSumAir, RunSumInputs,
+};
+use crate::{field::ExampleField, gadgets::running_sum_witness};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct RunSumProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> RunSumProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which holds one value per row next to the sum of the values up to and
+    /// including that row.
+    pub fn build_trace(values: &[B]) -> TraceTable<B> {
+        let mut columns = vec![Vec::new(); TRACE_WIDTH];
+        columns[VALUE] = values.to_vec();
+        columns[SUM] = running_sum_witness(values);
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for RunSumProver<B> {
+    type BaseField = B;
+    type Air = RunSumAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> RunSumInputs<B> {
+        RunSumInputs {
+            total: trace.get(SUM, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,107 @@
+use super::{
+    air::{SUM, VALUE},
+    RunSumExample, RunSumInputs, RunSumProver,
+};
+use crate::{
+    config::{ProofOptionsConfig, RunConfig, RunSumConfig},
+    example::Example,
+    run_example, tamper,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn runsum_trace_holds_running_sums() {
+    let values = [3u64, 1, 4, 1, 5, 9, 2, 6].map(BaseElement::from);
+    let trace = RunSumProver::<BaseElement>::build_trace(&values);
+    let sums = [3u64, 4, 8, 9, 14, 23, 25, 31];
+    for step in 0..values.len() {
+        assert_eq!(values[step], trace.get(VALUE, step));
+        assert_eq!(BaseElement::from(sums[step]), trace.get(SUM, step));
+    }
+}
+
+#[test]
+fn runsum_offset_sums_are_rejected() {
+    // adding the same offset to every sum keeps `sum' = sum + value'` satisfied and shifts the
+    // last sum by the offset, so only the constraint on the first row rules it out
+    let example = build_example();
+    let mut trace = example.build_trace();
+    for step in 0..TRACE_LENGTH {
+        trace.set(SUM, step, trace.get(SUM, step) + BaseElement::ONE);
+    }
+    let pub_inputs = RunSumInputs {
+        total: example.pub_inputs().total + BaseElement::ONE,
+    };
+
+    let proof = RunSumProver::new(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<RunSumExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn runsum_seed_reproduces_inputs() {
+    let mut config = RunConfig {
+        example: "runsum".to_string(),
+        runsum: RunSumConfig {
+            trace_length: TRACE_LENGTH,
+            ..RunSumConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    config.set_seed(5).unwrap();
+    let first = run_example(&config).unwrap();
+    assert_eq!(Some(5), first.seed);
+    assert_eq!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+
+    config.set_seed(6).unwrap();
+    assert_ne!(
+        first.pub_inputs_bytes,
+        run_example(&config).unwrap().pub_inputs_bytes
+    );
+}
+
+#[test]
+fn runsum_corrupted_first_row_is_rejected() {
+    let example = build_example();
+    for column in [VALUE, SUM] {
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, 0);
+        assert!(verdict.is_rejected(), "column {}: {}", column, verdict);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_example() -> RunSumExample<BaseElement> {
+    RunSumExample::new(&RunSumConfig {
+        trace_length: TRACE_LENGTH,
+        ..RunSumConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,163 @@
+use clap::ValueEnum;
+use std::{cmp, fmt};
+use winterfell::{math::log2, ProofOptions, StarkProof};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Largest proximity parameter `m` tried when looking for the best proven bound; the optimum
+/// lies at small values of `m` because the commit-phase error of FRI grows as `m^7`.
+const MAX_PROXIMITY_PARAMETER: usize = 1000;
+
+/// Smallest proximity parameter `m` for which the bound holds.
+const MIN_PROXIMITY_PARAMETER: usize = 3;
+
+/// Number of points at which the trace is opened outside of the evaluation domain: `z` and
+/// `z * g`.
+const NUM_OPENINGS: f64 = 2.0;
+
+/// Query security (in bits) below which winterfell ignores grinding when estimating the
+/// conjectured security of a proof.
+pub const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+// SECURITY BOUND
+// ================================================================================================
+
+/// The way in which the security level of a proof is estimated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum SecurityBound {
+    /// Relies on conjectures about the soundness of FRI; gives the higher estimate.
+    #[default]
+    Conjectured,
+    /// Relies only on known soundness proofs.
+    Proven,
+}
+
+impl fmt::Display for SecurityBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecurityBound::Conjectured => write!(f, "conjectured"),
+            SecurityBound::Proven => write!(f, "proven"),
+        }
+    }
+}
+
+// SECURITY LEVEL
+// ================================================================================================
+
+/// Returns the security level of the proof in bits under the specified bound.
+pub fn proof_security(proof: &StarkProof, bound: SecurityBound) -> u32 {
+    security_level(
+        proof.options(),
+        proof.context.num_modulus_bits(),
+        proof.trace_length(),
+        bound,
+    )
+}
+
+/// Returns the security level in bits of a proof generated with the specified options for a
+/// trace of the specified length, without generating the proof.
+///
+/// The conjectured level is computed in the same way as by winterfell. Winterfell 0.3 does not
+/// estimate proven security, so that bound is computed following Theorem 8 of
+/// <https://eprint.iacr.org/2022/1216>, which analyzes FRI in the list-decoding regime. The
+/// bound depends on a proximity parameter `m`; the best value over all admissible `m` is used.
+/// Both levels are capped by the collision resistance of the hash function.
+pub fn security_level(
+    options: &ProofOptions,
+    base_field_bits: u32,
+    trace_length: usize,
+    bound: SecurityBound,
+) -> u32 {
+    let security = protocol_security(options, base_field_bits, trace_length, bound);
+    cmp::min(security, options.hash_fn().collision_resistance())
+}
+
+/// Returns the security level in bits which the field, the queries, and grinding provide on
+/// their own, that is, the level [security_level()] would return for a hash function of
+/// unbounded collision resistance.
+pub fn protocol_security(
+    options: &ProofOptions,
+    base_field_bits: u32,
+    trace_length: usize,
+    bound: SecurityBound,
+) -> u32 {
+    match bound {
+        SecurityBound::Conjectured => conjectured_security(options, base_field_bits, trace_length),
+        SecurityBound::Proven => (MIN_PROXIMITY_PARAMETER..max_proximity_parameter(trace_length))
+            .map(|m| proven_security(options, base_field_bits, trace_length, m))
+            .max()
+            .unwrap_or(0),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the conjectured security level, not taking the hash function into account.
+fn conjectured_security(options: &ProofOptions, base_field_bits: u32, trace_length: usize) -> u32 {
+    let lde_domain_size = trace_length * options.blowup_factor();
+    let field_security =
+        base_field_bits * options.field_extension().degree() - log2(lde_domain_size);
+
+    // grinding contributes only once queries alone provide adequate security
+    let mut query_security = log2(options.blowup_factor()) * options.num_queries() as u32;
+    if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+        query_security += options.grinding_factor();
+    }
+
+    cmp::min(field_security, query_security).saturating_sub(1)
+}
+
+/// Returns the proven security level for a fixed proximity parameter `m`, not taking the hash
+/// function into account.
+fn proven_security(
+    options: &ProofOptions,
+    base_field_bits: u32,
+    trace_length: usize,
+    m: usize,
+) -> u32 {
+    let field_bits = (base_field_bits * options.field_extension().degree()) as f64;
+    let num_queries = options.num_queries() as f64;
+    let m = m as f64;
+    let rho = 1.0 / options.blowup_factor() as f64;
+    let alpha = (1.0 + 0.5 / m) * rho.sqrt();
+    // the blowup factor bounds the degree of the constraint composition polynomial
+    let max_degree = options.blowup_factor() as f64 + 1.0;
+    let lde_domain_size = (trace_length * options.blowup_factor()) as f64;
+    let trace_length = trace_length as f64;
+
+    // the out-of-domain openings increase the rate of the code the DEEP quotients belong to, so
+    // a proximity parameter m_plus with the same agreement alpha is needed for that rate
+    let rho_plus = (trace_length + NUM_OPENINGS) / lde_domain_size;
+    let m_plus = (1.0 / (2.0 * (alpha / rho_plus.sqrt() - 1.0))).ceil();
+    let list_size = (2.0 * m_plus + 1.0) / (2.0 * rho_plus.sqrt());
+
+    // FRI commit phase; only the dominant term of the error is taken into account
+    let fri_commit_bits =
+        field_bits - (0.5 * (m + 0.5).powi(7) / rho.powf(1.5) * lde_domain_size.powi(2)).log2();
+
+    // FRI query phase
+    let fri_query_bits = options.grinding_factor() as f64 - num_queries * alpha.log2();
+
+    // constraint composition with linear batching, and the DEEP composition
+    let ali_bits = field_bits - list_size.log2();
+    let deep_bits = field_bits
+        - (list_size * (max_degree * (trace_length + NUM_OPENINGS - 1.0) + (trace_length - 1.0)))
+            .log2();
+
+    // each step of the protocol may fail independently, which costs one bit for FRI and one
+    // for the protocol as a whole
+    let fri_bits = fri_commit_bits.min(fri_query_bits) - 1.0;
+    let security = fri_bits.min(ali_bits).min(deep_bits) - 1.0;
+    security.max(0.0) as u32
+}
+
+/// Returns the largest proximity parameter for which the agreement of the DEEP quotients stays
+/// above the Johnson bound for a trace of the specified length.
+fn max_proximity_parameter(trace_length: usize) -> usize {
+    let trace_length = trace_length as f64;
+    let ratio = (trace_length + NUM_OPENINGS) / trace_length;
+    let max_m = (1.0 / (2.0 * (ratio.sqrt() - 1.0))).ceil();
+    cmp::min(max_m as usize, MAX_PROXIMITY_PARAMETER)
+}
@@ -0,0 +1,247 @@
+//! HTTP service which proves and verifies the examples, so that they can back a web demo.
+//!
+//! `POST /prove/<example>` takes a JSON object with the settings of the example's config
+//! section, and optionally `field`, `preset`, and `options` (proof options), and responds with
+//! the proof, the public inputs, and the run report. `POST /verify/<example>` takes a JSON
+//! object with `proof` and `pub_inputs` and responds with whether the proof was accepted. Proofs
+//! and public inputs are carried in their binary serializations, encoded as hex strings.
+//!
+//! Proving runs on a blocking thread, and a request fails once it takes longer than the
+//! request timeout; the thread itself cannot be interrupted and finishes the proof in the
+//! background.
+
+pub use crate::config::ProveRequest;
+use crate::{
+    error::ExampleError, example::RunReport, run_example, tamper::catch_panic,
+    verify::verify_proof, EXAMPLES,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::post,
+    Json, Router,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::{io, net::SocketAddr, time::Duration};
+
+// REQUESTS AND RESPONSES
+// ================================================================================================
+
+/// Body of the response to a request to prove an example.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProveResponse {
+    /// The serialized proof, hex-encoded.
+    pub proof: String,
+    /// The serialized public inputs, hex-encoded.
+    pub pub_inputs: String,
+    /// Report of the run which generated and verified the proof.
+    pub report: RunReport,
+}
+
+/// Body of a request to verify a proof of an example.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyRequest {
+    /// The serialized proof, hex-encoded.
+    pub proof: String,
+    /// The serialized public inputs, hex-encoded.
+    pub pub_inputs: String,
+}
+
+/// Body of the response to a request to verify a proof.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VerifyResponse {
+    /// True if the proof was accepted.
+    pub verified: bool,
+    /// Reason the proof was rejected; `None` if it was accepted.
+    pub error: Option<String>,
+}
+
+// SERVICE
+// ================================================================================================
+
+/// Serves the proving and verification endpoints at `addr` until the process is stopped.
+///
+/// Returns an [Io](ExampleError::Io) error if the address cannot be bound.
+pub fn serve(addr: SocketAddr, timeout: Duration) -> Result<(), ExampleError> {
+    let runtime = tokio::runtime::Runtime::new().map_err(|err| server_error("start", addr, err))?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .map_err(|err| server_error("bind", addr, err))?;
+        info!("Listening on {}", addr);
+        axum::serve(listener, router(timeout))
+            .await
+            .map_err(|err| server_error("run", addr, err))
+    })
+}
+
+/// Returns an error for a server at `addr` which failed to `action`, such as "bind".
+pub(crate) fn server_error(
+    action: &'static str,
+    addr: SocketAddr,
+    err: impl ToString,
+) -> ExampleError {
+    ExampleError::Io {
+        action,
+        what: "server at".to_string(),
+        path: addr.to_string().into(),
+        source: io::Error::other(err.to_string()),
+    }
+}
+
+/// Returns the router of the service; requests which take longer than `timeout` fail with
+/// `503 Service Unavailable`.
+pub fn router(timeout: Duration) -> Router {
+    Router::new()
+        .route("/prove/:example", post(handle_prove))
+        .route("/verify/:example", post(handle_verify))
+        .with_state(timeout)
+}
+
+async fn handle_prove(
+    State(timeout): State<Duration>,
+    Path(example): Path<String>,
+    Json(request): Json<ProveRequest>,
+) -> Result<Json<ProveResponse>, (StatusCode, String)> {
+    if !EXAMPLES.contains(&example.as_str()) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("unknown example '{}'", example),
+        ));
+    }
+    let response = with_timeout(timeout, move || prove(&example, request)).await?;
+    response
+        .map(Json)
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))
+}
+
+async fn handle_verify(
+    State(timeout): State<Duration>,
+    Path(example): Path<String>,
+    Json(request): Json<VerifyRequest>,
+) -> Result<Json<VerifyResponse>, (StatusCode, String)> {
+    let response = with_timeout(timeout, move || verify(&example, &request)).await?;
+    response
+        .map(Json)
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))
+}
+
+/// Runs `f` on a blocking thread, and fails if it does not return within `timeout`.
+async fn with_timeout<T: Send + 'static, F: FnOnce() -> T + Send + 'static>(
+    timeout: Duration,
+    f: F,
+) -> Result<T, (StatusCode, String)> {
+    match tokio::time::timeout(timeout, tokio::task::spawn_blocking(f)).await {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(err)) => Err((StatusCode::INTERNAL_SERVER_ERROR, err.to_string())),
+        Err(_) => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("request did not finish within {} s", timeout.as_secs_f64()),
+        )),
+    }
+}
+
+// PROVING AND VERIFICATION
+// ================================================================================================
+
+/// Proves the named example with the settings in the request.
+///
+/// Returns an error if the settings do not form a valid config section of the example, or if
+/// the example rejects them.
+pub fn prove(example: &str, request: ProveRequest) -> Result<ProveResponse, String> {
+    let report = prove_report(example, request)?;
+    Ok(ProveResponse {
+        proof: to_hex(&report.proof_bytes),
+        pub_inputs: to_hex(&report.pub_inputs_bytes),
+        report,
+    })
+}
+
+/// Verifies the proof in the request against the public inputs in the request.
+///
+/// Returns an error only if the request is not hex-encoded; proofs which are rejected are
+/// reported in the response.
+pub fn verify(example: &str, request: &VerifyRequest) -> Result<VerifyResponse, String> {
+    let proof = from_hex(&request.proof).map_err(|err| format!("invalid proof: {}", err))?;
+    let pub_inputs =
+        from_hex(&request.pub_inputs).map_err(|err| format!("invalid public inputs: {}", err))?;
+    Ok(verify_bytes(example, &proof, &pub_inputs))
+}
+
+/// Proves and verifies the named example with the settings in the request, and returns the
+/// report of the run.
+pub(crate) fn prove_report(example: &str, request: ProveRequest) -> Result<RunReport, String> {
+    let config = request.into_config(example)?;
+    Ok(catch_panic(|| run_example(&config))??)
+}
+
+/// Verifies a serialized proof of the named example against serialized public inputs.
+pub(crate) fn verify_bytes(example: &str, proof: &[u8], pub_inputs: &[u8]) -> VerifyResponse {
+    // winterfell panics rather than returning an error for some malformed proofs
+    let error = match catch_panic(|| verify_proof(example, proof, pub_inputs)) {
+        Ok(Ok(())) => None,
+        Ok(Err(err)) => Some(err.to_string()),
+        Err(err) => Some(err),
+    };
+    VerifyResponse {
+        verified: error.is_none(),
+        error,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    if !hex.len().is_multiple_of(2) {
+        return Err("odd number of hex digits".to_string());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| format!("invalid hex digits at position {}", i))
+        })
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{prove, verify, ProveRequest, VerifyRequest};
+
+    #[test]
+    fn served_proof_verifies() {
+        let request = serde_json::from_str::<ProveRequest>(
+            r#"{"trace_length": 64, "options": {"num_queries": 28, "blowup_factor": 8}}"#,
+        )
+        .unwrap();
+        let response = prove("fib", request).unwrap();
+        assert_eq!(64, response.report.trace_length);
+        let request = VerifyRequest {
+            proof: response.proof.clone(),
+            pub_inputs: response.pub_inputs.clone(),
+        };
+        assert!(verify("fib", &request).unwrap().verified);
+
+        // a proof of one example is rejected as a proof of another, and malformed hex is an error
+        assert!(!verify("vdf", &request).unwrap().verified);
+        let request = VerifyRequest {
+            proof: response.proof[1..].to_string(),
+            ..request
+        };
+        assert!(verify("fib", &request).is_err());
+
+        // settings which the example does not have are rejected
+        let request = serde_json::from_str::<ProveRequest>(r#"{"n": 64}"#).unwrap();
+        assert!(prove("fib", request).is_err());
+    }
+}
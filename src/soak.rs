@@ -0,0 +1,350 @@
+//! Randomized soak testing of all examples.
+//!
+//! `soak --hours <h>` proves and verifies randomly drawn cases until the time is up: each case
+//! picks an example, a field, a size, inputs, and proof options at random, within bounds which
+//! keep proving fast and the options valid. The tests of the crate cover each example with a
+//! handful of fixed parameters, so a soak run is a way of finding bugs which only show up for
+//! some combinations of parameters, such as an AIR whose constraints break in one field or a
+//! prover which panics at one trace length.
+//!
+//! Every case is drawn from a seed of its own, which is printed with any failure together with
+//! the parameters of the case; `soak --case <seed>` runs that case again.
+
+use crate::{
+    config::{FieldExt, HashFn, ProofOptionsConfig, RunConfig},
+    error::ExampleError,
+    field::FieldType,
+    proof_file::hash_fn_name,
+    run_example,
+    verify::verify_proof,
+    EXAMPLES,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const FIELDS: [FieldType; 3] = [FieldType::F128, FieldType::F64, FieldType::F62];
+const HASH_FNS: [HashFn; 3] = [HashFn::Blake3_192, HashFn::Blake3_256, HashFn::Sha3_256];
+const FIELD_EXTENSIONS: [FieldExt; 3] = [FieldExt::None, FieldExt::Quadratic, FieldExt::Cubic];
+
+/// Trace length of the smallest case of the examples whose trace length can be set directly.
+const BASE_TRACE_LENGTH: usize = 64;
+
+/// Largest number of times the computation of a case is doubled from its smallest size.
+const MAX_DOUBLINGS: u32 = 3;
+
+/// Largest statement index drawn for examples whose inputs are not drawn from a seed; small
+/// offsets keep their inputs valid.
+const MAX_STATEMENT: u64 = 1000;
+
+// SOAK CASE
+// ================================================================================================
+
+/// How the inputs of a case are varied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseInputs {
+    /// The inputs are drawn from this seed.
+    Seed(u64),
+    /// The inputs are those of [RunConfig::statement()] with this index.
+    Statement(u64),
+}
+
+/// A randomly drawn run of an example.
+#[derive(Debug, Clone)]
+pub struct SoakCase {
+    /// Seed from which every parameter of the case was drawn.
+    pub seed: u64,
+    /// Number of times the computation is doubled from its smallest size.
+    pub doublings: u32,
+    pub inputs: CaseInputs,
+    /// Config which runs the case.
+    pub config: RunConfig,
+}
+
+impl SoakCase {
+    /// Draws the case with the specified seed; the same seed always yields the same case.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let example = EXAMPLES[rng.gen_range(0..EXAMPLES.len())];
+        let field = FIELDS[rng.gen_range(0..FIELDS.len())];
+
+        let mut config = base_config(example);
+        config.field = field;
+        config.proof_options = random_options(&mut rng, field);
+
+        let mut doublings = rng.gen_range(0..=MAX_DOUBLINGS);
+        match config.scaled(doublings) {
+            Ok(scaled) => config = scaled,
+            Err(_) => doublings = 0,
+        }
+
+        let input_seed = rng.gen();
+        let inputs = match config.set_seed(input_seed) {
+            Ok(()) => CaseInputs::Seed(input_seed),
+            Err(_) => {
+                let index = rng.gen_range(0..=MAX_STATEMENT);
+                config = config.statement(index);
+                CaseInputs::Statement(index)
+            }
+        };
+
+        Self {
+            seed,
+            doublings,
+            inputs,
+            config,
+        }
+    }
+
+    /// Proves and verifies the case, and verifies the serialized proof as a separate verifier
+    /// would; panics of the prover are reported as failures.
+    pub fn run(&self) -> SoakOutcome {
+        let config = &self.config;
+        let report = match panic::catch_unwind(AssertUnwindSafe(|| run_example(config))) {
+            Ok(Ok(report)) => report,
+            // the parameters were drawn at random, so some combinations are not valid
+            Ok(Err(ExampleError::Config(err))) => return SoakOutcome::Skipped(err),
+            Ok(Err(err)) => return SoakOutcome::Failed(err.to_string()),
+            Err(err) => return SoakOutcome::Failed(format!("panicked: {}", panic_message(&*err))),
+        };
+        if let Some(err) = report.verification_error {
+            return SoakOutcome::Failed(format!("proof was rejected: {}", err));
+        }
+        match verify_proof(
+            &config.example,
+            &report.proof_bytes,
+            &report.pub_inputs_bytes,
+        ) {
+            Ok(()) => SoakOutcome::Verified,
+            Err(err) => SoakOutcome::Failed(format!("serialized proof was rejected: {}", err)),
+        }
+    }
+}
+
+impl fmt::Display for SoakCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = &self.config;
+        write!(
+            f,
+            "case {}: {} in {} scaled by 2^{}, ",
+            self.seed, config.example, config.field, self.doublings
+        )?;
+        match self.inputs {
+            CaseInputs::Seed(seed) => write!(f, "inputs from seed {}, ", seed)?,
+            CaseInputs::Statement(index) => write!(f, "statement {}, ", index)?,
+        }
+        let options = config.proof_options.to_proof_options();
+        let fri_options = options.to_fri_options();
+        write!(
+            f,
+            "{} queries, blowup factor {}, grinding factor {}, {}, field extension of degree {}, \
+             FRI folding factor {} and max remainder size {}",
+            options.num_queries(),
+            options.blowup_factor(),
+            options.grinding_factor(),
+            hash_fn_name(options.hash_fn()),
+            options.field_extension().degree(),
+            fri_options.folding_factor(),
+            fri_options.max_remainder_size()
+        )
+    }
+}
+
+/// Result of running a [SoakCase].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SoakOutcome {
+    /// The proof was generated and verified.
+    Verified,
+    /// The drawn parameters were rejected before proving; the reason is attached.
+    Skipped(String),
+    /// The prover panicked or the proof was rejected; the reason is attached.
+    Failed(String),
+}
+
+// SOAK RUN
+// ================================================================================================
+
+/// Counts of the outcomes of a soak run, and the failed cases.
+#[derive(Debug, Clone, Default)]
+pub struct SoakSummary {
+    pub num_cases: usize,
+    pub num_verified: usize,
+    pub num_skipped: usize,
+    /// Every failed case with the reason it failed.
+    pub failures: Vec<(SoakCase, String)>,
+    pub elapsed: Duration,
+}
+
+impl SoakSummary {
+    /// Returns true if no case failed.
+    pub fn is_passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for SoakSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ran {} cases in {:.1} min: {} verified, {} skipped, {} failed",
+            self.num_cases,
+            self.elapsed.as_secs_f64() / 60f64,
+            self.num_verified,
+            self.num_skipped,
+            self.failures.len()
+        )?;
+        for (case, err) in self.failures.iter() {
+            write!(f, "\n{}\n  {}", case, err)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs cases drawn from seeds which are themselves drawn from `seed`, until `duration` has
+/// passed, and calls `observe` with every case and its outcome as soon as it is known.
+///
+/// The last case may end after `duration`, since a case is not interrupted once it is started.
+pub fn soak(
+    duration: Duration,
+    seed: u64,
+    mut observe: impl FnMut(&SoakCase, &SoakOutcome),
+) -> SoakSummary {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut summary = SoakSummary::default();
+    let start = Instant::now();
+    while start.elapsed() < duration {
+        let case = SoakCase::from_seed(rng.gen());
+        let outcome = case.run();
+        observe(&case, &outcome);
+        summary.num_cases += 1;
+        match outcome {
+            SoakOutcome::Verified => summary.num_verified += 1,
+            SoakOutcome::Skipped(_) => summary.num_skipped += 1,
+            SoakOutcome::Failed(err) => summary.failures.push((case, err)),
+        }
+    }
+    summary.elapsed = start.elapsed();
+    summary
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a config which runs the example at the smallest size of a soak case.
+pub(crate) fn base_config(example: &str) -> RunConfig {
+    let mut config = RunConfig {
+        example: example.to_string(),
+        ..RunConfig::default()
+    };
+    match example {
+        "vdf" => config.vdf.trace_length = BASE_TRACE_LENGTH,
+        "rescue" => config.rescue.chain_length = 4,
+        "poseidon" => config.poseidon.chain_length = 2,
+        "counter" => config.counter.num_blocks = 4,
+        "lamport" => config.lamport.num_signatures = 1,
+        "sum" => config.sum.length = BASE_TRACE_LENGTH,
+        "vm" => config.vm.trace_length = BASE_TRACE_LENGTH,
+        "fib" => config.fib.trace_length = BASE_TRACE_LENGTH,
+        "quad" => config.quad.trace_length = BASE_TRACE_LENGTH,
+        "iszero" => {
+            config.iszero.trace_length = BASE_TRACE_LENGTH;
+            config.iszero.start = BASE_TRACE_LENGTH as u64 / 2;
+        }
+        "rom" => config.rom.trace_length = BASE_TRACE_LENGTH,
+        "sqrt" => config.sqrt.trace_length = BASE_TRACE_LENGTH,
+        "merkle" => config.merkle.num_steps = BASE_TRACE_LENGTH,
+        "bitwise" => config.bitwise.trace_length = BASE_TRACE_LENGTH,
+        "compare" => config.compare.trace_length = BASE_TRACE_LENGTH,
+        "fibmod" => config.fibmod.trace_length = BASE_TRACE_LENGTH,
+        "sort" => config.sort.trace_length = 16,
+        "fibchunk" => config.fibchunk.trace_length = BASE_TRACE_LENGTH,
+        "fixedexp" => config.fixedexp.num_exponents = 1,
+        "modexp" => config.modexp.num_messages = 2,
+        "runsum" => config.runsum.trace_length = BASE_TRACE_LENGTH,
+        "horner" => config.horner.trace_length = BASE_TRACE_LENGTH,
+        "wrap" => config.wrap.trace_length = BASE_TRACE_LENGTH,
+        "window" => config.window.trace_length = BASE_TRACE_LENGTH,
+        "fibdiv" => config.fibdiv.trace_length = BASE_TRACE_LENGTH,
+        // the other examples run at their default sizes, which are small
+        _ => {}
+    }
+    config
+}
+
+/// Draws proof options which winterfell accepts in the specified field; the blowup factor is at
+/// least 8, which fits the degree 7 S-boxes of the 64-bit field.
+fn random_options(rng: &mut StdRng, field: FieldType) -> ProofOptionsConfig {
+    // winterfell does not implement a cubic extension of the 128-bit field
+    let num_extensions = match field {
+        FieldType::F128 => 2,
+        _ => FIELD_EXTENSIONS.len(),
+    };
+    let folding_factor: usize = [4, 8, 16][rng.gen_range(0..3)];
+    // the max remainder size is a power of two from the square of the folding factor to 1024
+    let min_remainder_bits = 2 * folding_factor.trailing_zeros();
+    let remainder_bits = rng.gen_range(min_remainder_bits.max(5)..=10);
+    ProofOptionsConfig {
+        num_queries: Some(rng.gen_range(16..=40)),
+        blowup_factor: Some([8, 16][rng.gen_range(0..2)]),
+        grinding_factor: Some(rng.gen_range(0..=4)),
+        hash_fn: Some(HASH_FNS[rng.gen_range(0..HASH_FNS.len())]),
+        field_extension: Some(FIELD_EXTENSIONS[rng.gen_range(0..num_extensions)]),
+        fri_folding_factor: Some(folding_factor),
+        fri_max_remainder_size: Some(1 << remainder_bits),
+    }
+}
+
+fn panic_message(err: &(dyn std::any::Any + Send)) -> &str {
+    match err.downcast_ref::<String>() {
+        Some(message) => message,
+        None => err.downcast_ref::<&str>().copied().unwrap_or("no message"),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{SoakCase, SoakOutcome};
+
+    #[test]
+    fn soak_cases_are_reproducible_from_their_seeds() {
+        for seed in 0..16 {
+            let case = SoakCase::from_seed(seed);
+            assert_eq!(seed, case.seed);
+            assert_eq!(case.to_string(), SoakCase::from_seed(seed).to_string());
+        }
+        assert_ne!(
+            SoakCase::from_seed(0).to_string(),
+            SoakCase::from_seed(1).to_string()
+        );
+    }
+
+    #[test]
+    fn soak_cases_verify() {
+        use std::time::Duration;
+
+        for seed in 0..4 {
+            let case = SoakCase::from_seed(seed);
+            let outcome = case.run();
+            assert!(
+                !matches!(outcome, SoakOutcome::Failed(_)),
+                "{}: {:?}",
+                case,
+                outcome
+            );
+        }
+
+        let mut num_observed = 0;
+        let summary = super::soak(Duration::ZERO, 0, |_, _| num_observed += 1);
+        assert_eq!((0, 0), (summary.num_cases, num_observed));
+        assert!(summary.is_passed());
+    }
+}
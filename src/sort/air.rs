@@ -0,0 +1,208 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::{collections::Vec, string::String};
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits of the sorted values.
+pub const VALUE_BITS: usize = 32;
+
+/// Trace column layout. `VALUE` holds the sorted list, one value per row, and the columns from
+/// `DIFF_BITS` on the bits of the difference between the value of the next row and that of the
+/// current one, least significant bit first. The columns from `SELECTORS` on hold one selector
+/// per entry of the unsorted list, of which exactly the one of the entry placed in a row is set,
+/// and the columns which follow them one count per entry of the number of times it was placed
+/// in all previous rows.
+pub const VALUE: usize = 0;
+pub const DIFF_BITS: usize = 1;
+pub const SELECTORS: usize = DIFF_BITS + VALUE_BITS;
+
+/// Smallest and largest number of values in the list; the trace has two columns per value and a
+/// row per value, plus a last row, so the number of values is one less than a power of two.
+pub const MIN_LIST_SIZE: usize = 7;
+pub const MAX_LIST_SIZE: usize = 63;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct SortInputs<B: ExampleField> {
+    /// The unsorted list.
+    pub values: Vec<B>,
+    pub min: B,
+    pub median: B,
+    pub max: B,
+}
+
+impl<B: ExampleField> SortInputs<B> {
+    /// Returns the number of columns in the trace of this statement.
+    pub fn trace_width(&self) -> usize {
+        SELECTORS + 2 * self.values.len()
+    }
+
+    /// Returns the index of the first column which counts the placements of an entry.
+    pub fn counts(&self) -> usize {
+        SELECTORS + self.values.len()
+    }
+
+    /// Checks that every entry of the list is below 2^32.
+    ///
+    /// The differences of consecutive sorted values are only range-checked to 32 bits, which
+    /// orders values below 2^32; a larger entry could be placed after a smaller one by a
+    /// difference which wraps around the modulus.
+    pub fn check_values(&self) -> Result<(), String> {
+        match self
+            .values
+            .iter()
+            .position(|value| value.as_int() >> VALUE_BITS as u32 != 0u32.into())
+        {
+            Some(index) => Err(format!(
+                "entry {} of the list is not a {}-bit value",
+                index, VALUE_BITS
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<B: ExampleField> Serializable for SortInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.values.len() as u32);
+        target.write(&self.values[..]);
+        target.write(self.min);
+        target.write(self.median);
+        target.write(self.max);
+    }
+}
+
+impl<B: ExampleField> Deserializable for SortInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let size = source.read_u32()? as usize;
+        if !(MIN_LIST_SIZE..=MAX_LIST_SIZE).contains(&size) || !(size + 1).is_power_of_two() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid list size {}",
+                size
+            )));
+        }
+        let inputs = SortInputs {
+            values: B::read_batch_from(source, size)?,
+            min: B::read_from(source)?,
+            median: B::read_from(source)?,
+            max: B::read_from(source)?,
+        };
+        inputs
+            .check_values()
+            .map_err(DeserializationError::InvalidValue)?;
+        Ok(inputs)
+    }
+}
+
+// SORT AIR
+// ================================================================================================
+
+/// AIR of a sorted copy of a public list of 32-bit values, whose smallest, median, and largest
+/// values are public.
+///
+/// Sortedness is checked between consecutive rows: the difference `value' - value` is decomposed
+/// into 32 binary columns, which range-checks it to `[0, 2^32)`. The values are entries of the
+/// list and thus below `2^32`, so a difference in the range is one by which the value grows
+/// rather than one which wraps around the modulus.
+///
+/// That the sorted copy is a permutation of the list is checked with one selector `s_i` per entry
+/// `t_i`, as in the `rom` example, and one count `c_i` per entry:
+///
+/// * `s_i * s_i = s_i` and `sum(s_i) = 1`, so that each row selects exactly one entry;
+/// * `value = sum(t_i * s_i)`, so that the value is the selected entry;
+/// * `c_i' = c_i + s_i`, together with assertions that every count goes from zero to one, so
+///   that every entry is selected exactly once.
+///
+/// A permutation argument would instead compare grand products of the two columns at a random
+/// point, which needs a verifier challenge that winterfell 0.3 cannot provide; the selectors
+/// cost two columns per entry and thus suit short lists only. The list is public, because the
+/// selectors can only pick entries known to the AIR.
+pub struct SortAir<B: ExampleField> {
+    context: AirContext<B>,
+    pub_inputs: SortInputs<B>,
+}
+
+impl<B: ExampleField> Air for SortAir<B> {
+    type BaseField = B;
+    type PublicInputs = SortInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: SortInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.trace_width(), trace_info.width());
+        let n = pub_inputs.values.len();
+        let mut degrees = vec![2; n + VALUE_BITS];
+        degrees.extend(vec![1; n + 3]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let values = &self.pub_inputs.values;
+        let n = values.len();
+        let counts = self.pub_inputs.counts();
+        let selectors = &current[SELECTORS..counts];
+        let diff_bits = &current[DIFF_BITS..SELECTORS];
+
+        // exactly one entry is selected, and the value is that entry
+        result.enforce_binary(0, selectors);
+        let mut num_selected = E::ZERO;
+        let mut value = E::ZERO;
+        for (&selector, &entry) in selectors.iter().zip(values) {
+            num_selected += selector;
+            value += selector * E::from(entry);
+        }
+        let offset = n + VALUE_BITS;
+        result[offset] = are_equal(num_selected, E::ONE);
+        result[offset + 1] = are_equal(current[VALUE], value);
+
+        // the next value is not smaller than the current one
+        result.enforce_binary(n, diff_bits);
+        result[offset + 2] = are_equal(next[VALUE] - current[VALUE], combine_bits(diff_bits));
+
+        // the selected entry is counted
+        for (i, &selector) in selectors.iter().enumerate() {
+            result[offset + 3 + i] = are_equal(next[counts + i], current[counts + i] + selector);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // every entry is placed exactly once, and the order statistics are at their rows
+        let trace_length = self.trace_length();
+        let n = self.pub_inputs.values.len();
+        let counts = self.pub_inputs.counts();
+        let mut assertions = Vec::with_capacity(2 * n + 3);
+        for i in 0..n {
+            assertions.push(first_row_equals(counts + i, B::ZERO));
+            assertions.push(last_row_equals(counts + i, trace_length, B::ONE));
+        }
+        assertions.push(first_row_equals(VALUE, self.pub_inputs.min));
+        assertions.push(Assertion::single(VALUE, n / 2, self.pub_inputs.median));
+        assertions.push(Assertion::single(VALUE, n - 1, self.pub_inputs.max));
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
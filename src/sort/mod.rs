@@ -0,0 +1,162 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::SortConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{SortAir, SortInputs, MAX_LIST_SIZE, MIN_LIST_SIZE, VALUE_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::SortProver;
+
+#[cfg(test)]
+mod tests;
+
+// SORTING EXAMPLE
+// ================================================================================================
+
+/// Proves that the results are the smallest, the median, and the largest value of a public list
+/// of 32-bit values, by sorting the list in the trace.
+///
+/// The sorted copy is tied to the list by one-hot selector columns and a count per entry rather
+/// than by a permutation argument, which winterfell 0.3 cannot express; see [SortAir] for the
+/// constraints.
+#[cfg(feature = "std")]
+pub struct SortExample<B: ExampleField> {
+    seed: u64,
+    values: Vec<u32>,
+    min: B,
+    median: B,
+    max: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> SortExample<B> {
+    pub fn new(config: &SortConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let values = (0..config.trace_length - 1)
+            .map(|_| rng.gen())
+            .collect::<Vec<u32>>();
+
+        // compute results
+        let now = Instant::now();
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+        info!(
+            "Sorted {} values in {} ms",
+            values.len(),
+            now.elapsed().as_millis()
+        );
+
+        let example = Self {
+            seed: config.seed,
+            min: B::from(sorted[0]),
+            median: B::from(sorted[sorted.len() / 2]),
+            max: B::from(sorted[sorted.len() - 1]),
+            values,
+        };
+        example
+            .pub_inputs()
+            .check_values()
+            .map_err(ExampleError::Config)?;
+        Ok(example)
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &SortConfig) -> Result<(), String> {
+        validate::check_trace_length("sort.trace_length", config.trace_length)?;
+        // the last row is not covered by transition constraints, so it places no value
+        if !(MIN_LIST_SIZE + 1..=MAX_LIST_SIZE + 1).contains(&config.trace_length) {
+            return Err(format!(
+                "trace length must be between {} and {}, but sort.trace_length is {}; the trace \
+                 has two columns per list entry",
+                MIN_LIST_SIZE + 1,
+                MAX_LIST_SIZE + 1,
+                config.trace_length
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for SortExample<B> {
+    type Air = SortAir<B>;
+    type Prover = SortProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        SortProver::build_trace(&self.values)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = SortConfig::default();
+        ExampleDescriptor::new(
+            "sort",
+            "the smallest, median, and largest values of a public list",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        let n = self.values.len();
+        AirMetadata {
+            columns: vec![
+                ("VALUE", 1),
+                ("DIFF_BITS", VALUE_BITS),
+                ("SELECTORS", n),
+                ("COUNTS", n),
+            ],
+            constraints: vec![
+                ("binary selectors", n),
+                ("binary difference bits", VALUE_BITS),
+                ("one selector set", 1),
+                ("value lookup", 1),
+                ("ascending difference", 1),
+                ("placement count", n),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> SortProver<B> {
+        SortProver::new(options, self.pub_inputs().values)
+    }
+
+    fn pub_inputs(&self) -> SortInputs<B> {
+        SortInputs {
+            values: self.values.iter().map(|&value| B::from(value)).collect(),
+            min: self.min,
+            median: self.median,
+            max: self.max,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> SortInputs<B> {
+        SortInputs {
+            median: self.median + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::{
+    air::{DIFF_BITS, SELECTORS, VALUE, VALUE_BITS},
+    SortAir, SortInputs,
+};
+use crate::{field::ExampleField, gadgets::to_bits};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct SortProver<B: ExampleField> {
+    options: ProofOptions,
+    values: Vec<B>,
+}
+
+impl<B: ExampleField> SortProver<B> {
+    pub fn new(options: ProofOptions, values: Vec<B>) -> Self {
+        Self { options, values }
+    }
+
+    /// Builds a trace which places the entries of `values` in ascending order, one per row, and
+    /// counts how often each entry was placed; the last row, which follows the largest value,
+    /// repeats it and selects no entry.
+    pub fn build_trace(values: &[u32]) -> TraceTable<B> {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| values[index]);
+        Self::place(values, &order)
+    }
+
+    /// Builds a trace which places the entries of `values` at the indices in `order`, one per
+    /// row, as [SortProver::build_trace()] does for the ascending order. The difference columns
+    /// hold the difference of consecutive values modulo `2^32`, which does not match the
+    /// difference in the field where the order descends.
+    pub fn place(values: &[u32], order: &[usize]) -> TraceTable<B> {
+        let n = values.len();
+        let counts = SELECTORS + n;
+        let mut columns = vec![vec![B::ZERO; order.len() + 1]; SELECTORS + 2 * n];
+        for (step, &index) in order.iter().enumerate() {
+            let value = values[index];
+            let next = values[*order.get(step + 1).unwrap_or(&index)];
+            columns[VALUE][step] = B::from(value);
+            for (i, bit) in to_bits(next.wrapping_sub(value) as u64, VALUE_BITS).enumerate() {
+                columns[DIFF_BITS + i][step] = bit;
+            }
+            columns[SELECTORS + index][step] = B::ONE;
+            for i in counts..counts + n {
+                columns[i][step + 1] = columns[i][step] + columns[i - n][step];
+            }
+        }
+        columns[VALUE][order.len()] = columns[VALUE][order.len() - 1];
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for SortProver<B> {
+    type BaseField = B;
+    type Air = SortAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> SortInputs<B> {
+        let n = trace.length() - 1;
+        SortInputs {
+            values: self.values.clone(),
+            min: trace.get(VALUE, 0),
+            median: trace.get(VALUE, n / 2),
+            max: trace.get(VALUE, n - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
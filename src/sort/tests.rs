@@ -0,0 +1,171 @@
+use super::{
+    air::{SELECTORS, VALUE},
+    SortExample, SortInputs, SortProver,
+};
+use crate::{
+    config::SortConfig,
+    error::ExampleError,
+    example::{self, Example},
+    pub_input_digest::{verify_with_digest, PubInputDigest},
+};
+use winter_utils::SliceReader;
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement, StarkField},
+    Deserializable, FieldExtension, HashFunction, ProofOptions, Prover, Serializable, Trace,
+    TraceTable,
+};
+
+#[test]
+fn sort_inputs_are_checked_against_digest() {
+    let report = example::run(&build_example(), build_options());
+    let digest = PubInputDigest::of(&report.pub_inputs_bytes);
+    assert_eq!(Ok(digest), PubInputDigest::from_hex(&digest.to_string()));
+    assert_eq!(
+        Ok(()),
+        verify_with_digest(
+            "sort",
+            &report.proof_bytes,
+            &digest,
+            &report.pub_inputs_bytes
+        )
+    );
+
+    // the proof is valid for its inputs, but they are not the ones the digest commits to
+    let mut other_inputs_bytes = report.pub_inputs_bytes.clone();
+    other_inputs_bytes.push(0);
+    let other_digest = PubInputDigest::of(&other_inputs_bytes);
+    assert!(verify_with_digest(
+        "sort",
+        &report.proof_bytes,
+        &other_digest,
+        &report.pub_inputs_bytes
+    )
+    .is_err());
+}
+
+#[test]
+fn sort_trace_places_values_in_ascending_order() {
+    let trace = SortProver::<BaseElement>::build_trace(&VALUES);
+    let sorted = [1u64, 3, 3, 4, 8, 9, 12];
+    let order = [4usize, 2, 5, 0, 6, 1, 3];
+    let counts = SELECTORS + VALUES.len();
+    for step in 0..VALUES.len() {
+        assert_eq!(BaseElement::from(sorted[step]), trace.get(VALUE, step));
+        assert_eq!(BaseElement::ONE, trace.get(SELECTORS + order[step], step));
+        assert_eq!(
+            BaseElement::from(step as u64),
+            (0..VALUES.len()).fold(BaseElement::ZERO, |sum, i| sum
+                + trace.get(counts + i, step))
+        );
+    }
+    for i in 0..VALUES.len() {
+        assert_eq!(BaseElement::ONE, trace.get(counts + i, VALUES.len()));
+    }
+
+    let example = SortExample::<BaseElement>::new(&SortConfig {
+        trace_length: 8,
+        seed: 3,
+        ..SortConfig::default()
+    })
+    .unwrap();
+    let pub_inputs = example.pub_inputs();
+    let mut values = pub_inputs
+        .values
+        .iter()
+        .map(|value| value.as_int())
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    assert_eq!(BaseElement::new(values[0]), pub_inputs.min);
+    assert_eq!(BaseElement::new(values[3]), pub_inputs.median);
+    assert_eq!(BaseElement::new(values[6]), pub_inputs.max);
+}
+
+#[test]
+fn sort_descending_step_is_rejected() {
+    // swap two values in the middle of the sorted order; the selectors and counts stay valid,
+    // so only the range check of the differences catches the descent
+    assert!(is_accepted(SortProver::build_trace(&VALUES)));
+    let trace = SortProver::place(&VALUES, &[4, 2, 5, 6, 0, 1, 3]);
+    assert!(!is_accepted(trace));
+}
+
+#[test]
+fn sort_repeated_entry_is_rejected() {
+    // place the entry holding 3 twice and leave out the other one; the order is still ascending,
+    // so only the counts catch the forgery
+    let trace = SortProver::place(&VALUES, &[4, 2, 2, 0, 6, 1, 3]);
+    assert!(!is_accepted(trace));
+}
+
+#[test]
+fn sort_entries_above_32_bits_are_rejected() {
+    let mut inputs = build_example().pub_inputs();
+    assert_eq!(Ok(()), inputs.check_values());
+    inputs.values[5] = BaseElement::new(1 << 32);
+    let err = inputs.check_values().unwrap_err();
+    assert!(err.starts_with("entry 5 of the list"), "{}", err);
+
+    let bytes = inputs.to_bytes();
+    assert!(SortInputs::<BaseElement>::read_from(&mut SliceReader::new(&bytes)).is_err());
+}
+
+#[test]
+fn sort_long_list_is_not_accepted() {
+    let err = SortExample::<BaseElement>::new(&SortConfig {
+        trace_length: 128,
+        ..SortConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(
+        err.to_string().contains("trace length must be between"),
+        "{}",
+        err
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 32;
+const VALUES: [u32; 7] = [4, 9, 3, 12, 1, 3, 8];
+
+fn build_example() -> SortExample<BaseElement> {
+    SortExample::new(&SortConfig {
+        trace_length: TRACE_LENGTH,
+        ..SortConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+/// Proves the trace against the public inputs its rows claim, and returns whether the proof is
+/// accepted.
+fn is_accepted(trace: TraceTable<BaseElement>) -> bool {
+    let values = VALUES
+        .iter()
+        .map(|&value| BaseElement::from(value))
+        .collect::<Vec<_>>();
+    let pub_inputs = SortInputs {
+        values: values.clone(),
+        min: trace.get(VALUE, 0),
+        median: trace.get(VALUE, VALUES.len() / 2),
+        max: trace.get(VALUE, VALUES.len() - 1),
+    };
+    let proof = SortProver::new(build_options(), values)
+        .prove(trace)
+        .unwrap();
+    winterfell::verify::<<SortExample<BaseElement> as Example>::Air>(proof, pub_inputs).is_ok()
+}
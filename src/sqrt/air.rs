@@ -0,0 +1,147 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of fractional bits of the fixed-point numbers; a number `x` is encoded as the integer
+/// `x * 2^FRACTION_BITS`.
+pub const FRACTION_BITS: u32 = 16;
+
+/// Encoded root from which the iteration starts: 256, which is at least the root of every
+/// value the example accepts, so that the iteration approaches the root from above.
+pub const INITIAL_ROOT: u64 = 256 << FRACTION_BITS;
+
+/// Number of bits in the decompositions of the remainder, the slack, and the quotient. The root
+/// stays below `2^(NUM_BITS + 1)`, so the product of the root and the quotient plus the
+/// remainder is far below the modulus of every supported field, and the division constraint
+/// holds over the integers.
+pub const NUM_BITS: usize = 25;
+
+/// Trace column layout. `ROOT` holds the encoded approximation `x` of the root, `QUOTIENT` the
+/// quotient `q` of dividing the scaled value by `x`, and `PARITY` the bit which is dropped when
+/// `x + q` is halved. The bit columns hold the binary decompositions of the remainder `r` of the
+/// division, of the slack `x - 1 - r` between the root and the remainder, and of the quotient.
+pub const ROOT: usize = 0;
+pub const QUOTIENT: usize = 1;
+pub const PARITY: usize = 2;
+pub const REMAINDER_BITS: usize = 3;
+pub const SLACK_BITS: usize = REMAINDER_BITS + NUM_BITS;
+pub const QUOTIENT_BITS: usize = SLACK_BITS + NUM_BITS;
+pub const TRACE_WIDTH: usize = QUOTIENT_BITS + NUM_BITS;
+
+/// Number of transition constraints other than the ones keeping the bit columns binary.
+const NUM_STEP_CONSTRAINTS: usize = 5;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Encoded value whose root is approximated, and the encoded approximation in the last row.
+#[derive(Clone)]
+pub struct SqrtInputs<B: ExampleField> {
+    pub value: B,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for SqrtInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.value);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for SqrtInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(SqrtInputs {
+            value: B::read_from(source)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// SQUARE ROOT AIR
+// ================================================================================================
+
+/// AIR of the Babylonian method on fixed-point numbers, which replaces an approximation `x` of
+/// the root of `a` with `(x + a / x) / 2`.
+///
+/// In fixed point the division becomes a division of the integers `n = a * 2^FRACTION_BITS`
+/// and `x` with rounding down, which the AIR checks as `n = q * x + r` with `0 <= r < x`; the
+/// bounds on `r` follow from the decompositions of `r` and of the slack `x - 1 - r`, which also
+/// bound `x`. Halving is checked as `x + q = 2 * x' + p` with a binary `p`. Field elements have
+/// no order, so every bound is a range check on bit columns, and the bounds keep all values far
+/// enough below the modulus that the constraints hold over the integers.
+pub struct SqrtAir<B: ExampleField> {
+    context: AirContext<B>,
+    scaled_value: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for SqrtAir<B> {
+    type BaseField = B;
+    type PublicInputs = SqrtInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: SqrtInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = transition_degrees(&[2, 1, 1, 1, 2]);
+        degrees.append(&mut transition_degrees(&[2; 3 * NUM_BITS]));
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            scaled_value: pub_inputs.value * B::from(1u64 << FRACTION_BITS),
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let root = current[ROOT];
+        let quotient = current[QUOTIENT];
+        let remainder = combine_bits(&current[REMAINDER_BITS..REMAINDER_BITS + NUM_BITS]);
+        let slack = combine_bits(&current[SLACK_BITS..SLACK_BITS + NUM_BITS]);
+
+        // n = q * x + r with 0 <= r < x
+        result[0] = are_equal(E::from(self.scaled_value), quotient * root + remainder);
+        result[1] = are_equal(root, remainder + slack + E::ONE);
+        result[2] = are_equal(
+            quotient,
+            combine_bits(&current[QUOTIENT_BITS..QUOTIENT_BITS + NUM_BITS]),
+        );
+
+        // x' = (x + q) / 2, rounded down
+        result[3] = are_equal(root + quotient, next[ROOT].double() + current[PARITY]);
+        result[4] = is_binary(current[PARITY]);
+
+        result.enforce_binary(
+            NUM_STEP_CONSTRAINTS,
+            &current[REMAINDER_BITS..REMAINDER_BITS + 3 * NUM_BITS],
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let trace_length = self.trace_length();
+        vec![
+            first_row_equals(ROOT, B::from(INITIAL_ROOT)),
+            last_row_equals(ROOT, trace_length, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
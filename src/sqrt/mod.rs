@@ -0,0 +1,177 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::SqrtConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+use air::FRACTION_BITS;
+#[cfg(feature = "std")]
+use air::{SqrtAir, SqrtInputs, INITIAL_ROOT, NUM_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::SqrtProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Largest encoded value whose root the example approximates; its root is just below
+/// [INITIAL_ROOT].
+const MAX_VALUE: u64 = (1 << 32) - 1;
+
+// SQUARE ROOT EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the approximation of the square root of a public fixed-point
+/// number after `trace_length - 1` iterations of the Babylonian method.
+///
+/// Numbers are encoded with 16 fractional bits, so every step divides and halves integers and
+/// rounds the results down. Starting from 256, the approximations decrease towards the root and
+/// then stay at the root rounded down to a multiple of 2^-16.
+#[cfg(feature = "std")]
+pub struct SqrtExample<B: ExampleField> {
+    value: u64,
+    trace_length: usize,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> SqrtExample<B> {
+    pub fn new(config: &SqrtConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let value = encode(config.value);
+
+        // compute result
+        let now = Instant::now();
+        let scaled_value = scale(value);
+        let root = (1..config.trace_length)
+            .fold(INITIAL_ROOT, |root, _| babylonian_step(scaled_value, root));
+        info!(
+            "Computed square root {} of {} in {} ms",
+            decode(root),
+            decode(value),
+            now.elapsed().as_millis()
+        );
+
+        Ok(Self {
+            value,
+            trace_length: config.trace_length,
+            result: B::from(root),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &SqrtConfig) -> Result<(), String> {
+        validate::check_trace_length("sqrt.trace_length", config.trace_length)?;
+        if !(1..=MAX_VALUE).contains(&encode(config.value)) {
+            return Err(format!(
+                "value must be at least 2^-{} and below {}, but sqrt.value is {}",
+                FRACTION_BITS,
+                (MAX_VALUE + 1) >> FRACTION_BITS,
+                config.value
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for SqrtExample<B> {
+    type Air = SqrtAir<B>;
+    type Prover = SqrtProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        SqrtProver::build_trace(self.value, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = SqrtConfig::default();
+        ExampleDescriptor::new(
+            "sqrt",
+            "a fixed-point square root approximated by the Babylonian method",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("value", config.value)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("ROOT", 1),
+                ("QUOTIENT", 1),
+                ("PARITY", 1),
+                ("REMAINDER_BITS", NUM_BITS),
+                ("SLACK_BITS", NUM_BITS),
+                ("QUOTIENT_BITS", NUM_BITS),
+            ],
+            constraints: vec![
+                ("division", 1),
+                ("remainder below root", 1),
+                ("quotient bits", 1),
+                ("halving", 1),
+                ("binary parity", 1),
+                ("binary bits", 3 * NUM_BITS),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> SqrtProver<B> {
+        SqrtProver::new(options, self.value)
+    }
+
+    fn pub_inputs(&self) -> SqrtInputs<B> {
+        SqrtInputs {
+            value: B::from(self.value),
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> SqrtInputs<B> {
+        SqrtInputs {
+            result: self.result + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the fixed-point encoding of `value`, rounded to the nearest multiple of 2^-16.
+#[cfg(feature = "std")]
+fn encode(value: f64) -> u64 {
+    (value * (1u64 << FRACTION_BITS) as f64).round() as u64
+}
+
+/// Returns the number with the specified fixed-point encoding.
+fn decode(value: u64) -> f64 {
+    value as f64 / (1u64 << FRACTION_BITS) as f64
+}
+
+/// Returns the encoded value multiplied by the fixed-point scale, so that dividing it by an
+/// encoded root yields an encoded quotient.
+fn scale(value: u64) -> u64 {
+    value << FRACTION_BITS
+}
+
+/// Returns the next approximation of the root of `scaled_value / 2^16` after `root`, using
+/// integer division rounded down.
+fn babylonian_step(scaled_value: u64, root: u64) -> u64 {
+    (root + scaled_value / root) / 2
+}
@@ -0,0 +1,91 @@
+use super::{
+    air::{
+        INITIAL_ROOT, NUM_BITS, PARITY, QUOTIENT, QUOTIENT_BITS, REMAINDER_BITS, ROOT, SLACK_BITS,
+        TRACE_WIDTH,
+    },
+    babylonian_step, scale, SqrtAir, SqrtInputs,
+};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct SqrtProver<B: ExampleField> {
+    options: ProofOptions,
+    value: u64,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> SqrtProver<B> {
+    pub fn new(options: ProofOptions, value: u64) -> Self {
+        Self {
+            options,
+            value,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace with a row per approximation of the root of the encoded `value`, starting
+    /// from [INITIAL_ROOT]; every row but the last also holds the division and the halving which
+    /// produce the approximation in the next row.
+    pub fn build_trace(value: u64, trace_length: usize) -> TraceTable<B> {
+        let mut columns = (0..TRACE_WIDTH)
+            .map(|_| Vec::with_capacity(trace_length))
+            .collect::<Vec<_>>();
+        let scaled_value = scale(value);
+        let mut root = INITIAL_ROOT;
+        for _ in 0..trace_length {
+            let (quotient, remainder) = (scaled_value / root, scaled_value % root);
+            let mut row = [B::ZERO; TRACE_WIDTH];
+            row[ROOT] = B::from(root);
+            row[QUOTIENT] = B::from(quotient);
+            row[PARITY] = B::from((root + quotient) & 1);
+            write_bits(
+                &mut row[REMAINDER_BITS..REMAINDER_BITS + NUM_BITS],
+                remainder,
+            );
+            write_bits(
+                &mut row[SLACK_BITS..SLACK_BITS + NUM_BITS],
+                root - 1 - remainder,
+            );
+            write_bits(&mut row[QUOTIENT_BITS..QUOTIENT_BITS + NUM_BITS], quotient);
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+
+            root = babylonian_step(scaled_value, root);
+        }
+
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for SqrtProver<B> {
+    type BaseField = B;
+    type Air = SqrtAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> SqrtInputs<B> {
+        let last_step = trace.length() - 1;
+        SqrtInputs {
+            value: B::from(self.value),
+            result: trace.get(ROOT, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the binary decomposition of `value` into `bits`, least significant bit first.
+fn write_bits<B: ExampleField>(bits: &mut [B], value: u64) {
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = B::from((value >> i) & 1);
+    }
+}
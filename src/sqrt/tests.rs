@@ -0,0 +1,110 @@
+use super::{
+    air::{NUM_BITS, PARITY, QUOTIENT, QUOTIENT_BITS, REMAINDER_BITS, ROOT, SLACK_BITS},
+    encode, scale, SqrtExample,
+};
+use crate::{config::SqrtConfig, error::ExampleError, example::Example, tamper};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement, StarkField},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn sqrt_converges_to_fixed_point_root() {
+    // the root of 2 rounded down to a multiple of 2^-16 is 92681 / 2^16
+    assert_eq!(BaseElement::new(92681), build_example().pub_inputs().result);
+
+    for value in [2.0f64.powi(-16), 0.5, 1.0, 3.0, 1000.25, 65535.99] {
+        let example = SqrtExample::<BaseElement>::new(&SqrtConfig {
+            value,
+            ..SqrtConfig::default()
+        })
+        .unwrap();
+        let root = example.pub_inputs().result.as_int();
+        let scaled_value = scale(encode(value)) as u128;
+        assert!(
+            root * root <= scaled_value && scaled_value < (root + 1) * (root + 1),
+            "sqrt({}) = {}",
+            value,
+            root
+        );
+    }
+}
+
+#[test]
+fn sqrt_forged_division_is_rejected() {
+    // at the fixed point, underestimating the quotient by two with a remainder of more than
+    // the root still satisfies n = q * x + r, and would lower the claimed root by one
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let step = trace.length() - 2;
+    let root = trace.get(ROOT, step).as_int() as u64;
+    let quotient = trace.get(QUOTIENT, step).as_int() as u64 - 2;
+    let remainder = scale(encode(SqrtConfig::default().value)) - quotient * root;
+    let (next_root, parity) = ((root + quotient) / 2, (root + quotient) & 1);
+    trace.set(QUOTIENT, step, BaseElement::from(quotient));
+    trace.set(PARITY, step, BaseElement::from(parity));
+    trace.set(ROOT, step + 1, BaseElement::from(next_root));
+    for i in 0..NUM_BITS {
+        let bit = |value: u64| BaseElement::from((value >> i) & 1);
+        trace.set(REMAINDER_BITS + i, step, bit(remainder));
+        trace.set(SLACK_BITS + i, step, BaseElement::ZERO);
+        trace.set(QUOTIENT_BITS + i, step, bit(quotient));
+    }
+
+    let mut pub_inputs = example.pub_inputs();
+    pub_inputs.result = BaseElement::from(next_root);
+    assert_eq!(
+        pub_inputs.result + BaseElement::ONE,
+        example.pub_inputs().result
+    );
+    let proof = example.prover(build_options()).prove(trace).unwrap();
+    assert!(
+        winterfell::verify::<<SqrtExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn sqrt_value_out_of_range_is_rejected() {
+    let err = SqrtExample::<BaseElement>::new(&SqrtConfig {
+        value: 65536.0,
+        ..SqrtConfig::default()
+    })
+    .err()
+    .expect("the config is rejected");
+    assert!(matches!(err, ExampleError::Config(_)));
+    assert!(
+        err.to_string()
+            .contains("value must be at least 2^-16 and below 65536"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn sqrt_corrupted_trace_after_convergence_is_rejected() {
+    let example = build_example();
+    for (column, step) in [(ROOT, 40), (SLACK_BITS + 2, 50)] {
+        let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, step);
+        assert!(verdict.is_rejected(), "({}, {}): {}", column, step, verdict);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> SqrtExample<BaseElement> {
+    SqrtExample::new(&SqrtConfig::default()).unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,146 @@
+//! Proving over standard input and output, for shell pipelines and for integration tests
+//! written in other languages.
+//!
+//! `prove --stdin` reads one JSON object per line, each naming an example under `example` and
+//! otherwise shaped like a [ProveRequest]: the settings of the config section of the example,
+//! and optionally `field`, `preset`, and `options`. For every line it writes the serialized
+//! proof of the example as a line of base64, so that the n-th line of output is the proof
+//! requested by the n-th request. A request which cannot be proven stops the stream with an
+//! error naming its line.
+
+use crate::{
+    config::{ProveRequest, RunConfig},
+    error::ExampleError,
+    example::RunReport,
+    run_example,
+    tamper::catch_panic,
+    verify::VerifyError,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::io::{BufRead, Write};
+
+// REQUESTS
+// ================================================================================================
+
+/// A request read from one line of input.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamRequest {
+    /// Name of the example to prove.
+    pub example: String,
+    /// Settings of the example, field, and proof options.
+    #[serde(flatten)]
+    pub request: ProveRequest,
+}
+
+// PROVING
+// ================================================================================================
+
+/// Proves the example requested by a line of JSON and returns the report of the run.
+///
+/// Returns an error if the line is not a valid request, or for the same reasons as
+/// [prove_config()].
+pub fn prove_line(line: &str) -> Result<RunReport, ExampleError> {
+    let request = serde_json::from_str::<StreamRequest>(line)
+        .map_err(|err| ExampleError::Config(format!("invalid request: {}", err)))?;
+    let config = request
+        .request
+        .into_config(&request.example)
+        .map_err(ExampleError::Config)?;
+    prove_config(&config)
+}
+
+/// Proves the example selected in the config and returns the report of the run.
+///
+/// Returns an error if the example rejects its settings, if the prover fails or panics, or if
+/// the proof fails to verify.
+pub fn prove_config(config: &RunConfig) -> Result<RunReport, ExampleError> {
+    let report = catch_panic(|| run_example(config)).map_err(ExampleError::Proving)??;
+    match &report.verification_error {
+        None => Ok(report),
+        Some(err) => Err(VerifyError::Rejected(err.clone()).into()),
+    }
+}
+
+/// Returns the serialized proof of the run encoded as base64.
+pub fn encode_proof(report: &RunReport) -> String {
+    STANDARD.encode(&report.proof_bytes)
+}
+
+/// Proves the example requested by every line of `input`, and writes each proof to `output` as
+/// a line of base64 as soon as it is generated; blank lines are skipped.
+///
+/// Returns the number of proofs written, or an error naming the line on which proving failed;
+/// the proofs of the lines before it have already been written.
+pub fn prove_lines<R: BufRead, W: Write>(input: R, mut output: W) -> Result<usize, ExampleError> {
+    let mut num_proofs = 0;
+    for (i, line) in input.lines().enumerate() {
+        let line =
+            line.map_err(|err| ExampleError::read(&format!("line {} of", i + 1), "input", err))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let report = prove_line(&line).map_err(|err| on_line(i + 1, err))?;
+        writeln!(output, "{}", encode_proof(&report))
+            .and_then(|_| output.flush())
+            .map_err(|err| ExampleError::write("proof to", "output", err))?;
+        num_proofs += 1;
+    }
+    Ok(num_proofs)
+}
+
+/// Names the line of input in the message of an error of proving its request; the variant, and
+/// so the exit code, stays that of the stage which failed.
+fn on_line(line: usize, err: ExampleError) -> ExampleError {
+    let message = |message: String| format!("line {}: {}", line, message);
+    match err {
+        ExampleError::Config(err) => ExampleError::Config(message(err)),
+        ExampleError::Trace(err) => ExampleError::Trace(message(err)),
+        ExampleError::Proving(err) => ExampleError::Proving(message(err)),
+        ExampleError::Verification(err) => VerifyError::Rejected(message(err.to_string())).into(),
+        err => err,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::prove_lines;
+    use crate::{config::RunConfig, error::ExampleError, run_example};
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    #[test]
+    fn proofs_stream_as_lines_of_base64() {
+        let input = concat!(
+            "{\"example\":\"fib\",\"trace_length\":16}\n",
+            "\n",
+            "{\"example\":\"fib\",\"trace_length\":32,\"options\":{\"num_queries\":20}}\n",
+            "{\"example\":\"fib\",\"trace_length\":15}\n",
+        );
+        let mut output = Vec::new();
+        let err = prove_lines(input.as_bytes(), &mut output).unwrap_err();
+        assert!(matches!(err, ExampleError::Config(_)), "{}", err);
+        assert!(
+            err.to_string()
+                .starts_with("line 4: trace length must be a power of two"),
+            "{}",
+            err
+        );
+
+        // the proofs of the lines before the failing one are written, each as the proof which a
+        // run of the same config generates
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(2, lines.len());
+        let mut config = RunConfig {
+            example: "fib".to_string(),
+            ..RunConfig::default()
+        };
+        config.fib.trace_length = 32;
+        config.proof_options.num_queries = Some(20);
+        let report = run_example(&config).unwrap();
+        assert_eq!(report.proof_bytes, STANDARD.decode(lines[1]).unwrap());
+    }
+}
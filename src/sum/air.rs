@@ -0,0 +1,163 @@
+use crate::{
+    air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; the weight column is present only when the example computes an inner
+/// product.
+pub const VALUE: usize = 0;
+pub const SUM: usize = 1;
+pub const WEIGHT: usize = 2;
+
+/// Sequence assertions need a stride of at least two, so each vector is placed into its column
+/// with one assertion for the even steps and one for the odd steps.
+const STRIDE: usize = 2;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct SumInputs<B: ExampleField> {
+    pub values: Vec<B>,
+    pub weights: Option<Vec<B>>,
+    pub result: B,
+}
+
+impl<B: ExampleField> SumInputs<B> {
+    /// Returns the number of columns in the trace of this statement.
+    pub fn trace_width(&self) -> usize {
+        if self.weights.is_some() {
+            WEIGHT + 1
+        } else {
+            SUM + 1
+        }
+    }
+
+    /// Returns the weight of the value at the specified step; one if there are no weights.
+    pub fn weight(&self, step: usize) -> B {
+        self.weights
+            .as_ref()
+            .map_or(B::ONE, |weights| weights[step])
+    }
+}
+
+impl<B: ExampleField> Serializable for SumInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.values.len() as u32);
+        target.write(&self.values[..]);
+        match &self.weights {
+            Some(weights) => {
+                target.write_u8(1);
+                target.write(&weights[..]);
+            }
+            None => target.write_u8(0),
+        }
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for SumInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let length = source.read_u32()? as usize;
+        let values = B::read_batch_from(source, length)?;
+        let weights = match source.read_u8()? {
+            0 => None,
+            1 => Some(B::read_batch_from(source, length)?),
+            flag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid weights flag {}",
+                    flag
+                )))
+            }
+        };
+        Ok(SumInputs {
+            values,
+            weights,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// SUM AIR
+// ================================================================================================
+
+pub struct SumAir<B: ExampleField> {
+    context: AirContext<B>,
+    pub_inputs: SumInputs<B>,
+}
+
+impl<B: ExampleField> Air for SumAir<B> {
+    type BaseField = B;
+    type PublicInputs = SumInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: SumInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.trace_width(), trace_info.width());
+        assert_eq!(
+            trace_info.length(),
+            pub_inputs.values.len(),
+            "expected one vector element per step"
+        );
+
+        // multiplying by a weight from the trace raises the degree of the constraint to two
+        let degree = if pub_inputs.weights.is_some() { 2 } else { 1 };
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&[degree]), options),
+            pub_inputs,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // every step adds its value, multiplied by its weight if there is one, to the sum
+        let term = if self.pub_inputs.weights.is_some() {
+            next[VALUE] * next[WEIGHT]
+        } else {
+            next[VALUE]
+        };
+        result[0] = are_equal(next[SUM], current[SUM] + term);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the vectors fill their columns, and the sum goes from the first term to the result
+        let inputs = &self.pub_inputs;
+        let mut assertions = sequence_assertions(VALUE, &inputs.values);
+        if let Some(weights) = &inputs.weights {
+            assertions.append(&mut sequence_assertions(WEIGHT, weights));
+        }
+        assertions.push(first_row_equals(SUM, inputs.values[0] * inputs.weight(0)));
+        assertions.push(last_row_equals(SUM, self.trace_length(), inputs.result));
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns assertions which place `values` into every step of the specified column.
+fn sequence_assertions<B: ExampleField>(column: usize, values: &[B]) -> Vec<Assertion<B>> {
+    (0..STRIDE)
+        .map(|first_step| {
+            let values = values.iter().skip(first_step).step_by(STRIDE).copied();
+            Assertion::sequence(column, first_step, STRIDE, values.collect())
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::SumConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{SumAir, SumInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::SumProver;
+
+#[cfg(test)]
+mod tests;
+
+// SUM EXAMPLE
+// ================================================================================================
+
+/// Proves that the result is the sum of a public vector or, when weights are given, its inner
+/// product with a second public vector of the same length.
+///
+/// The execution trace holds the vectors in one column each, next to a column which accumulates
+/// the running sum. Both vectors are public inputs, so they are absorbed into the proof's
+/// Fiat-Shamir transcript together with the rest of the statement, and the AIR places them into
+/// the trace with sequence assertions; unlike the other examples, the public inputs of this one
+/// grow with the computation.
+#[cfg(feature = "std")]
+pub struct SumExample<B: ExampleField> {
+    seed: Option<u64>,
+    values: Vec<B>,
+    weights: Option<Vec<B>>,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> SumExample<B> {
+    pub fn new(config: &SumConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut random_vector = |length: usize| {
+            (0..length)
+                .map(|_| B::from(rng.gen::<u64>()))
+                .collect::<Vec<_>>()
+        };
+        let values = match &config.values {
+            Some(values) => values.iter().map(|&value| B::from(value)).collect(),
+            None => random_vector(config.length),
+        };
+        let weights = match &config.weights {
+            Some(weights) => Some(weights.iter().map(|&weight| B::from(weight)).collect()),
+            None if config.dot_product => Some(random_vector(values.len())),
+            None => None,
+        };
+
+        // compute result
+        let now = Instant::now();
+        let result = inner_product(&values, weights.as_deref());
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            seed: config.draws_random_inputs().then_some(config.seed),
+            values,
+            weights,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &SumConfig) -> Result<(), String> {
+        let (setting, length) = match &config.values {
+            Some(values) => ("the number of sum.values", values.len()),
+            None => ("sum.length", config.length),
+        };
+        validate::check_power_of_two(
+            "number of elements",
+            setting,
+            length,
+            validate::MIN_TRACE_LENGTH,
+        )?;
+        match &config.weights {
+            Some(weights) if weights.len() != length => Err(format!(
+                "number of weights must match the number of elements, but sum.weights has {} \
+                 entries for {} elements",
+                weights.len(),
+                length
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for SumExample<B> {
+    type Air = SumAir<B>;
+    type Prover = SumProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        SumProver::build_trace(&self.values, self.weights.as_deref())
+    }
+
+    fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = SumConfig::default();
+        ExampleDescriptor::new(
+            "sum",
+            "the sum of a public vector, or its inner product with public weights",
+        )
+        .with_default("length", config.length)
+        .with_default("seed", config.seed)
+        .with_default("dot_product", config.dot_product)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        let mut columns = vec![("VALUE", 1), ("SUM", 1)];
+        if self.weights.is_some() {
+            columns.push(("WEIGHT", 1));
+        }
+        AirMetadata {
+            columns,
+            constraints: vec![("sum accumulation", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> SumProver<B> {
+        SumProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> SumInputs<B> {
+        SumInputs {
+            values: self.values.clone(),
+            weights: self.weights.clone(),
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> SumInputs<B> {
+        SumInputs {
+            result: self.result + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the sum of `values`, each multiplied by the corresponding weight if weights are
+/// given.
+fn inner_product<B: ExampleField>(values: &[B], weights: Option<&[B]>) -> B {
+    match weights {
+        Some(weights) => values
+            .iter()
+            .zip(weights)
+            .fold(B::ZERO, |sum, (&value, &weight)| sum + value * weight),
+        None => values.iter().fold(B::ZERO, |sum, &value| sum + value),
+    }
+}
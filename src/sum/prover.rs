@@ -0,0 +1,60 @@
+use super::{
+    air::{SUM, VALUE, WEIGHT},
+    SumAir, SumInputs,
+};
+use crate::field::ExampleField;
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct SumProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> SumProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn build_trace(values: &[B], weights: Option<&[B]>) -> TraceTable<B> {
+        let weight = |step: usize| weights.map_or(B::ONE, |weights| weights[step]);
+
+        let mut sums = Vec::with_capacity(values.len());
+        let mut sum = B::ZERO;
+        for (step, &value) in values.iter().enumerate() {
+            sum += value * weight(step);
+            sums.push(sum);
+        }
+
+        let mut columns = vec![values.to_vec(), sums];
+        if let Some(weights) = weights {
+            columns.push(weights.to_vec());
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for SumProver<B> {
+    type BaseField = B;
+    type Air = SumAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> SumInputs<B> {
+        let last_step = trace.length() - 1;
+        SumInputs {
+            values: trace.get_register(VALUE).to_vec(),
+            weights: (trace.width() > WEIGHT).then(|| trace.get_register(WEIGHT).to_vec()),
+            result: trace.get(SUM, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
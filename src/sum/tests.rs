@@ -0,0 +1,177 @@
+use super::{SumExample, SumInputs, SumProver};
+use crate::{
+    config::{RunConfig, SumConfig},
+    degrees,
+    example::{self, Example},
+    pub_input_digest::{verify_with_digest, PubInputDigest},
+    verify::verify_proof,
+    witness::apply_witness,
+};
+use std::{env, fs, process};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover,
+};
+
+#[test]
+fn sum_inputs_are_checked_against_digest() {
+    let report = example::run(&build_example(true), build_options());
+    let digest = PubInputDigest::of(&report.pub_inputs_bytes);
+    assert_eq!(Ok(digest), PubInputDigest::from_hex(&digest.to_string()));
+    assert_eq!(
+        Ok(()),
+        verify_with_digest(
+            "sum",
+            &report.proof_bytes,
+            &digest,
+            &report.pub_inputs_bytes
+        )
+    );
+
+    // the proof is valid for its inputs, but they are not the ones the digest commits to
+    let mut other_inputs_bytes = report.pub_inputs_bytes.clone();
+    other_inputs_bytes.push(0);
+    let other_digest = PubInputDigest::of(&other_inputs_bytes);
+    assert!(verify_with_digest(
+        "sum",
+        &report.proof_bytes,
+        &other_digest,
+        &report.pub_inputs_bytes
+    )
+    .is_err());
+}
+
+#[test]
+fn sum_changed_vector_is_rejected() {
+    // changing a single element of either vector, at an even or an odd step, must invalidate
+    // the proof even though the result stays the same
+    let example = build_example(true);
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    for step in [10, 11] {
+        let pub_inputs = example.pub_inputs();
+        let mut values = pub_inputs.values.clone();
+        values[step] += BaseElement::ONE;
+        let mut weights = pub_inputs.weights.clone().unwrap();
+        weights[step] += BaseElement::ONE;
+
+        for (values, weights) in [
+            (values.clone(), pub_inputs.weights.clone()),
+            (pub_inputs.values.clone(), Some(weights)),
+        ] {
+            let wrong_inputs = SumInputs {
+                values,
+                weights,
+                result: pub_inputs.result,
+            };
+            assert!(
+                winterfell::verify::<<SumExample<BaseElement> as Example>::Air>(
+                    proof.clone(),
+                    wrong_inputs
+                )
+                .is_err()
+            );
+        }
+    }
+}
+
+#[test]
+fn sum_trace_accumulates_inner_product() {
+    let values = (1..=8u8).map(BaseElement::from).collect::<Vec<_>>();
+    let weights = (1..=8u8).rev().map(BaseElement::from).collect::<Vec<_>>();
+    let trace = SumProver::build_trace(&values, Some(&weights));
+    let pub_inputs = SumProver::new(build_options()).get_pub_inputs(&trace);
+
+    // 1 * 8 + 2 * 7 + ... + 8 * 1
+    assert_eq!(BaseElement::from(120u8), pub_inputs.result);
+    let trace = SumProver::build_trace(&values, None);
+    let pub_inputs = SumProver::new(build_options()).get_pub_inputs(&trace);
+    assert_eq!(BaseElement::from(36u8), pub_inputs.result);
+    assert!(pub_inputs.weights.is_none());
+}
+
+#[test]
+fn sum_witness_file_sets_vectors() {
+    let path = env::temp_dir().join(format!("stark-examples-sum-witness-{}.json", process::id()));
+    let mut config = RunConfig {
+        example: "sum".to_string(),
+        ..RunConfig::default()
+    };
+
+    fs::write(&path, r#"{ "values": [1, 2, 3, 4, 5, 6, 7, 8] }"#).unwrap();
+    apply_witness(&mut config, &path).unwrap();
+    let example = SumExample::<BaseElement>::new(&config.sum).unwrap();
+    assert_eq!(BaseElement::from(36u8), example.pub_inputs().result);
+    assert!(example::run(&example, build_options()).is_verified());
+
+    fs::write(
+        &path,
+        r#"{ "values": [1, 2, 3, 4, 5, 6, 7, 8], "weights": [8, 7, 6, 5, 4, 3, 2, 1] }"#,
+    )
+    .unwrap();
+    apply_witness(&mut config, &path).unwrap();
+    let example = SumExample::<BaseElement>::new(&config.sum).unwrap();
+    assert_eq!(BaseElement::from(120u8), example.pub_inputs().result);
+
+    // fields of other examples are not accepted
+    fs::write(&path, r#"{ "values": [1, 2], "exponent": 3 }"#).unwrap();
+    assert!(apply_witness(&mut config, &path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn sum_dot_product_constraint_degrees_match_declared() {
+    // the default settings sum a single vector; the dot product raises the degree
+    let report = degrees::check_degrees(&build_example(true), build_options(), 0);
+    assert!(report.is_consistent(), "{}", report);
+}
+
+#[test]
+fn sum_mismatched_weights_are_rejected() {
+    let config = SumConfig {
+        values: Some(vec![1; 8]),
+        weights: Some(vec![2; 4]),
+        ..SumConfig::default()
+    };
+    let err = SumExample::<BaseElement>::validate(&config).unwrap_err();
+    assert!(
+        err.contains("sum.weights has 4 entries for 8 elements"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn sum_proof_without_dot_product_verifies() {
+    let report = example::run(&build_example(false), build_options());
+    assert_eq!(
+        Ok(()),
+        verify_proof("sum", &report.proof_bytes, &report.pub_inputs_bytes)
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example(dot_product: bool) -> SumExample<BaseElement> {
+    SumExample::new(&SumConfig {
+        length: 256,
+        dot_product,
+        ..SumConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
@@ -0,0 +1,537 @@
+use crate::example::{Example, PublicInputs};
+use rand::Rng;
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+use winter_utils::SliceReader;
+use winterfell::{
+    math::{log2, FieldElement},
+    ByteWriter, Deserializable, FieldExtension, HashFunction, ProofOptions, Prover, Serializable,
+    StarkProof, Trace,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of proofs with a single flipped byte checked in tamper mode.
+const NUM_BYTE_FLIPS: usize = 16;
+
+// VERDICT
+// ================================================================================================
+
+/// Outcome of attempting to get a tampered statement accepted.
+#[derive(Debug)]
+pub enum Verdict {
+    /// The tampered statement was accepted; this indicates a soundness bug.
+    Accepted,
+    /// Proof generation failed or panicked.
+    RejectedByProver(String),
+    /// The proof could not be deserialized.
+    RejectedByParser(String),
+    /// The verifier returned an error or panicked.
+    RejectedByVerifier(String),
+}
+
+impl Verdict {
+    pub fn is_rejected(&self) -> bool {
+        !matches!(self, Verdict::Accepted)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Accepted => write!(f, "ACCEPTED"),
+            Verdict::RejectedByProver(reason) => write!(f, "rejected by prover: {}", reason),
+            Verdict::RejectedByParser(reason) => write!(f, "rejected by parser: {}", reason),
+            Verdict::RejectedByVerifier(reason) => write!(f, "rejected by verifier: {}", reason),
+        }
+    }
+}
+
+// TAMPERING STRATEGIES
+// ================================================================================================
+
+/// XORs the byte at `index` of a serialized proof with `mask` and tries to verify the result.
+pub fn flip_proof_byte<E: Example>(
+    example: &E,
+    proof_bytes: &[u8],
+    index: usize,
+    mask: u8,
+) -> Verdict {
+    assert_ne!(mask, 0, "mask must change the byte");
+    let mut tampered = proof_bytes.to_vec();
+    tampered[index] ^= mask;
+
+    // the parser panics rather than returning an error for some malformed proof options
+    match catch_panic(|| StarkProof::from_bytes(&tampered)) {
+        Ok(Ok(proof)) => verify::<E>(proof, example.pub_inputs()),
+        Ok(Err(err)) => Verdict::RejectedByParser(err.to_string()),
+        Err(reason) => Verdict::RejectedByParser(reason),
+    }
+}
+
+/// Adds one to the trace cell at the specified column and step, then tries to prove and verify
+/// the computation against the original public inputs.
+///
+/// The prover does not check the trace against the AIR, so an invalid proof is produced and is
+/// expected to be rejected by the verifier; panics raised while proving are still caught in case
+/// the prover is built with its debug checks enabled.
+pub fn corrupt_trace_cell<E: Example>(
+    example: &E,
+    options: ProofOptions,
+    column: usize,
+    step: usize,
+) -> Verdict {
+    let prover = example.prover(options);
+    let mut trace = example.build_trace();
+    let value = trace.get(column, step);
+    trace.set(column, step, value + FieldElement::ONE);
+
+    match catch_panic(|| prover.prove(trace)) {
+        Ok(Ok(proof)) => verify::<E>(proof, example.pub_inputs()),
+        Ok(Err(err)) => Verdict::RejectedByProver(err.to_string()),
+        Err(reason) => Verdict::RejectedByProver(reason),
+    }
+}
+
+/// Tries to verify a valid proof against public inputs which differ from the ones the proof was
+/// generated for.
+pub fn use_wrong_inputs<E: Example>(example: &E, proof: StarkProof) -> Verdict {
+    verify::<E>(proof, example.wrong_pub_inputs())
+}
+
+// TAMPER MODE
+// ================================================================================================
+
+/// Generates a valid proof for the example and then checks that each tampering strategy leads
+/// to rejection, printing the verdict for every attempt.
+///
+/// Returns the number of tampered statements which were accepted.
+pub fn run<E: Example>(example: &E, options: ProofOptions) -> usize {
+    let mut rng = rand::thread_rng();
+    let mut num_accepted = 0;
+    let mut report = |description: String, verdict: Verdict| {
+        if !verdict.is_rejected() {
+            num_accepted += 1;
+        }
+        println!("{}: {}", description, verdict);
+    };
+
+    let proof = example
+        .prover(options.clone())
+        .prove(example.build_trace())
+        .unwrap();
+    let proof_bytes = proof.to_bytes();
+
+    // the message of a panic raised while parsing, proving, or verifying a tampered statement
+    // is captured in the verdict, so the default hook is silenced while tampering
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    // (a) flip random bytes in the serialized proof
+    for _ in 0..NUM_BYTE_FLIPS {
+        let index = rng.gen_range(0..proof_bytes.len());
+        let mask = rng.gen_range(1..=u8::MAX);
+        report(
+            format!(
+                "Flipped byte {} of the proof with mask {:#04x}",
+                index, mask
+            ),
+            flip_proof_byte(example, &proof_bytes, index, mask),
+        );
+    }
+
+    // (b) corrupt one trace cell before proving
+    let trace = example.build_trace();
+    let column = rng.gen_range(0..trace.width());
+    let step = rng.gen_range(1..trace.length() - 1);
+    drop(trace);
+    report(
+        format!("Corrupted trace cell ({}, {})", column, step),
+        corrupt_trace_cell(example, options, column, step),
+    );
+
+    // (c) supply wrong public inputs
+    report(
+        "Verified against wrong public inputs".to_string(),
+        use_wrong_inputs(example, proof),
+    );
+
+    panic::set_hook(hook);
+    num_accepted
+}
+
+// CONTEXT BINDING
+// ================================================================================================
+
+/// Parameters of the proof context which a verifier assumes in place of those the proof was
+/// generated with; parameters which are `None` are taken from the proof.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextOverrides {
+    pub options: Option<ProofOptions>,
+    pub trace_width: Option<usize>,
+    pub trace_length: Option<usize>,
+}
+
+/// Verifies a valid proof of the example as a verifier would which builds the AIR from the
+/// context parameters in `overrides` rather than from those in the proof.
+///
+/// The winterfell verifier constructs the AIR from the context of the proof and seeds its public
+/// coin with the serialized context, so a verifier which assumes any other parameter draws
+/// other challenges and query positions than the prover did.
+pub fn verify_with_context<E: Example>(
+    example: &E,
+    mut proof: StarkProof,
+    overrides: &ContextOverrides,
+) -> Verdict {
+    let context = &proof.context;
+    let trace_info = context.get_trace_info();
+    let options = overrides
+        .options
+        .as_ref()
+        .unwrap_or_else(|| proof.options());
+
+    // winterfell does not export the context type, so the context is rebuilt from its encoding
+    let mut context_bytes = Vec::new();
+    context_bytes.write_u8(overrides.trace_width.unwrap_or_else(|| trace_info.width()) as u8);
+    context_bytes.write_u8(log2(
+        overrides
+            .trace_length
+            .unwrap_or_else(|| trace_info.length()),
+    ) as u8);
+    context_bytes.write_u16(trace_info.meta().len() as u16);
+    context_bytes.write_u8_slice(trace_info.meta());
+    context_bytes.write_u8(context.field_modulus_bytes().len() as u8);
+    context_bytes.write_u8_slice(context.field_modulus_bytes());
+    options.write_into(&mut context_bytes);
+    match Deserializable::read_from(&mut SliceReader::new(&context_bytes)) {
+        Ok(context) => proof.context = context,
+        Err(err) => return Verdict::RejectedByParser(err.to_string()),
+    }
+    verify::<E>(proof, example.pub_inputs())
+}
+
+/// Returns the overrides which change a single parameter of the context of a proof with the
+/// specified options and trace shape, one for every parameter, each with a description of the
+/// parameter the verifier assumes.
+pub fn context_changes(
+    options: &ProofOptions,
+    trace_width: usize,
+    trace_length: usize,
+) -> Vec<(String, ContextOverrides)> {
+    let fri_options = options.to_fri_options();
+    let values = OptionValues {
+        num_queries: options.num_queries(),
+        blowup_factor: options.blowup_factor(),
+        grinding_factor: options.grinding_factor(),
+        hash_fn: options.hash_fn(),
+        field_extension: options.field_extension(),
+        fri_folding_factor: fri_options.folding_factor(),
+        fri_max_remainder_size: fri_options.max_remainder_size(),
+    };
+
+    let num_queries = values.num_queries + 1;
+    let blowup_factor = values.blowup_factor * 2;
+    let grinding_factor = match values.grinding_factor {
+        0 => 1,
+        factor => factor - 1,
+    };
+    let hash_fn = match values.hash_fn {
+        HashFunction::Sha3_256 => HashFunction::Blake3_256,
+        _ => HashFunction::Sha3_256,
+    };
+    let field_extension = match values.field_extension {
+        FieldExtension::None => FieldExtension::Quadratic,
+        _ => FieldExtension::None,
+    };
+    let fri_folding_factor = if values.fri_folding_factor == 4 { 8 } else { 4 };
+    let fri_max_remainder_size = match values.fri_max_remainder_size {
+        1024 => 512,
+        size => size * 2,
+    };
+    let option_changes = [
+        (
+            format!("{} queries", num_queries),
+            OptionValues {
+                num_queries,
+                ..values
+            },
+        ),
+        (
+            format!("blowup factor {}", blowup_factor),
+            OptionValues {
+                blowup_factor,
+                ..values
+            },
+        ),
+        (
+            format!("grinding factor {}", grinding_factor),
+            OptionValues {
+                grinding_factor,
+                ..values
+            },
+        ),
+        (
+            format!("hash function {:?}", hash_fn),
+            OptionValues { hash_fn, ..values },
+        ),
+        (
+            format!("field extension {:?}", field_extension),
+            OptionValues {
+                field_extension,
+                ..values
+            },
+        ),
+        (
+            format!("FRI folding factor {}", fri_folding_factor),
+            OptionValues {
+                fri_folding_factor,
+                ..values
+            },
+        ),
+        (
+            format!("FRI max remainder size {}", fri_max_remainder_size),
+            OptionValues {
+                fri_max_remainder_size,
+                ..values
+            },
+        ),
+    ];
+
+    let mut changes = option_changes
+        .iter()
+        .map(|(description, values)| {
+            let overrides = ContextOverrides {
+                options: Some(values.to_options()),
+                ..ContextOverrides::default()
+            };
+            (description.clone(), overrides)
+        })
+        .collect::<Vec<_>>();
+    changes.push((
+        format!("trace width {}", trace_width + 1),
+        ContextOverrides {
+            trace_width: Some(trace_width + 1),
+            ..ContextOverrides::default()
+        },
+    ));
+    changes.push((
+        format!("trace length {}", trace_length * 2),
+        ContextOverrides {
+            trace_length: Some(trace_length * 2),
+            ..ContextOverrides::default()
+        },
+    ));
+    changes
+}
+
+/// Generates a valid proof for the example, verifies it under the context it was generated with,
+/// and then under each of the [context_changes()], printing the verdict of every attempt.
+///
+/// Returns the number of changed contexts under which the proof was accepted, plus one if it was
+/// rejected under its own context.
+pub fn demo_binding<E: Example>(example: &E, options: ProofOptions) -> usize {
+    let trace = example.build_trace();
+    let (trace_width, trace_length) = (trace.width(), trace.length());
+    let proof = example.prover(options.clone()).prove(trace).unwrap();
+
+    // the AIRs of the examples panic on a trace of another width, so the default hook is
+    // silenced while verifying
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let verdict = verify_with_context(example, proof.clone(), &ContextOverrides::default());
+    println!("Verifier assumes the context of the proof: {}", verdict);
+    let mut num_unexpected = usize::from(verdict.is_rejected());
+    for (description, overrides) in context_changes(&options, trace_width, trace_length) {
+        let verdict = verify_with_context(example, proof.clone(), &overrides);
+        if !verdict.is_rejected() {
+            num_unexpected += 1;
+        }
+        println!("Verifier assumes {}: {}", description, verdict);
+    }
+
+    panic::set_hook(hook);
+    num_unexpected
+}
+
+/// The parameters of [ProofOptions], which can be changed one at a time.
+#[derive(Clone, Copy)]
+struct OptionValues {
+    num_queries: usize,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    hash_fn: HashFunction,
+    field_extension: FieldExtension,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+}
+
+impl OptionValues {
+    fn to_options(self) -> ProofOptions {
+        ProofOptions::new(
+            self.num_queries,
+            self.blowup_factor,
+            self.grinding_factor,
+            self.hash_fn,
+            self.field_extension,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn verify<E: Example>(proof: StarkProof, pub_inputs: PublicInputs<E>) -> Verdict {
+    match catch_panic(|| winterfell::verify::<E::Air>(proof, pub_inputs)) {
+        Ok(Ok(_)) => Verdict::Accepted,
+        Ok(Err(err)) => Verdict::RejectedByVerifier(err.to_string()),
+        Err(reason) => Verdict::RejectedByVerifier(reason),
+    }
+}
+
+/// Runs the closure and converts a panic into an error carrying the panic message.
+pub(crate) fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", message)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        context_changes, corrupt_trace_cell, flip_proof_byte, use_wrong_inputs,
+        verify_with_context, ContextOverrides, Verdict,
+    };
+    use crate::{
+        config::ProofOptionsConfig,
+        example::Example,
+        runner::{self, ExampleCommand},
+        test_utils::{example_config, vdf_example, vdf_options},
+        EXAMPLES,
+    };
+    use winterfell::{Prover, Trace};
+
+    #[test]
+    fn proofs_are_rejected_under_changed_contexts() {
+        let example = vdf_example();
+        let trace = example.build_trace();
+        let (trace_width, trace_length) = (trace.width(), trace.length());
+        let proof = example.prover(vdf_options()).prove(trace).unwrap();
+
+        let verdict = verify_with_context(&example, proof.clone(), &ContextOverrides::default());
+        assert!(matches!(verdict, Verdict::Accepted), "{}", verdict);
+
+        let changes = context_changes(&vdf_options(), trace_width, trace_length);
+        assert_eq!(9, changes.len());
+        for (description, overrides) in changes.iter() {
+            let verdict = verify_with_context(&example, proof.clone(), overrides);
+            assert!(verdict.is_rejected(), "{}: {}", description, verdict);
+        }
+    }
+
+    #[test]
+    fn tampered_proof_bytes_of_every_example_are_rejected() {
+        struct FlipProofBytes;
+
+        impl ExampleCommand for FlipProofBytes {
+            type Output = ();
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+                let proof_bytes = example
+                    .prover(options.to_proof_options())
+                    .prove(example.build_trace())
+                    .unwrap()
+                    .to_bytes();
+                for index in (0..proof_bytes.len()).step_by(proof_bytes.len() / 16) {
+                    let verdict = flip_proof_byte(example, &proof_bytes, index, 1);
+                    let name = E::descriptor().name;
+                    assert!(
+                        verdict.is_rejected(),
+                        "{} byte {}: {}",
+                        name,
+                        index,
+                        verdict
+                    );
+                }
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            runner::with_example(&example_config(name), FlipProofBytes).unwrap();
+        }
+    }
+
+    #[test]
+    fn corrupted_trace_of_every_example_is_rejected() {
+        // every column is constrained on a row in the middle of the trace; rows which the
+        // constraints of an example treat differently are corrupted by the tests of the example
+        struct CorruptEveryColumn;
+
+        impl ExampleCommand for CorruptEveryColumn {
+            type Output = ();
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+                for column in 0..example.build_trace().width() {
+                    let verdict =
+                        corrupt_trace_cell(example, options.to_proof_options(), column, 17);
+                    let name = E::descriptor().name;
+                    assert!(
+                        verdict.is_rejected(),
+                        "{} column {}: {}",
+                        name,
+                        column,
+                        verdict
+                    );
+                }
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            runner::with_example(&example_config(name), CorruptEveryColumn).unwrap();
+        }
+    }
+
+    #[test]
+    fn wrong_inputs_of_every_example_are_rejected() {
+        struct UseWrongInputs;
+
+        impl ExampleCommand for UseWrongInputs {
+            type Output = ();
+
+            fn execute<E: Example>(self, example: &E, options: ProofOptionsConfig) {
+                let proof = example
+                    .prover(options.to_proof_options())
+                    .prove(example.build_trace())
+                    .unwrap();
+                let verdict = use_wrong_inputs(example, proof);
+                assert!(
+                    verdict.is_rejected(),
+                    "{}: {}",
+                    E::descriptor().name,
+                    verdict
+                );
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            runner::with_example(&example_config(name), UseWrongInputs).unwrap();
+        }
+    }
+}
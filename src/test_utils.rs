@@ -0,0 +1,87 @@
+//! Fixtures shared by the tests of the tooling, which runs on the vdf example unless a test needs
+//! another one.
+
+use crate::{
+    config::{FieldExt, HashFn, ProofOptionsConfig, RunConfig, VdfConfig},
+    vdf::VdfExample,
+};
+use winterfell::{math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions};
+
+/// Returns the vdf example over a trace of 128 steps, which proves in milliseconds.
+pub fn vdf_example() -> VdfExample<BaseElement> {
+    VdfExample::new(&VdfConfig {
+        trace_length: 128,
+        ..VdfConfig::default()
+    })
+    .unwrap()
+}
+
+/// Returns proof options of 28 queries under a blowup factor of 8, without grinding.
+pub fn vdf_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+/// Returns a config which selects the named example at the size its tests prove it, under the
+/// proof options of [vdf_options()]; the golden proofs are recorded for these configs.
+pub fn example_config(name: &str) -> RunConfig {
+    let mut config = RunConfig {
+        example: name.to_string(),
+        proof_options: ProofOptionsConfig {
+            num_queries: Some(28),
+            blowup_factor: Some(8),
+            grinding_factor: Some(0),
+            hash_fn: Some(HashFn::Blake3_256),
+            field_extension: Some(FieldExt::None),
+            fri_folding_factor: Some(4),
+            fri_max_remainder_size: Some(32),
+        },
+        ..RunConfig::default()
+    };
+    match name {
+        "vdf" => config.vdf.trace_length = 128,
+        "rescue" => config.rescue.chain_length = 16,
+        "poseidon" => config.poseidon.chain_length = 16,
+        "counter" => config.counter.num_blocks = 16,
+        "lamport" => config.lamport.num_signatures = 4,
+        "sum" => {
+            config.sum.length = 256;
+            config.sum.dot_product = true;
+        }
+        "vm" => config.vm.trace_length = 128,
+        "fib" => config.fib.trace_length = 256,
+        "quad" => config.quad.trace_length = 128,
+        "iszero" => {
+            config.iszero.trace_length = 128;
+            config.iszero.start = 100;
+        }
+        "rom" => {
+            config.rom.trace_length = 128;
+            config.rom.table = vec![4, 9, 5, 1];
+        }
+        "bitwise" => config.bitwise.trace_length = 64,
+        "compare" => config.compare.trace_length = 64,
+        "fibmod" => config.fibmod.trace_length = 64,
+        "sort" => config.sort.trace_length = 32,
+        "fibchunk" => config.fibchunk.trace_length = 128,
+        "fixedexp" => config.fixedexp.num_exponents = 4,
+        "modexp" => config.modexp.num_messages = 2,
+        "runsum" => config.runsum.trace_length = 64,
+        "horner" => config.horner.trace_length = 64,
+        "wrap" => config.wrap.trace_length = 64,
+        "window" => {
+            config.window.trace_length = 64;
+            config.window.width = 4;
+        }
+        "fibdiv" => config.fibdiv.trace_length = 64,
+        _ => (),
+    }
+    config
+}
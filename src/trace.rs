@@ -0,0 +1,374 @@
+use crate::error::ExampleError;
+use log::{debug, info};
+use std::{
+    fs,
+    io::{self, BufWriter},
+    ops::Range,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use winter_utils::SliceReader;
+use winterfell::{
+    math::{log2, StarkField},
+    ByteReader, ByteWriter, Trace, TraceTable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of trace rows written into each checkpoint file unless specified otherwise.
+pub const DEFAULT_CHUNK_LENGTH: usize = 1 << 20;
+
+/// Number of rows between two calls of the callback of [build_trace_observed()].
+pub const ROWS_PER_UPDATE: usize = 1 << 12;
+
+/// Bytes at the start of every checkpoint file.
+const CHUNK_MAGIC: &[u8; 8] = b"STXTRACE";
+
+// TRACE STEPS
+// ================================================================================================
+
+/// An execution trace described row by row: the first row is produced on its own, and every
+/// following row is derived from the one before it.
+///
+/// This is the same description [TraceTable::fill()] works with; unlike a pair of closures, it
+/// can be handed to builders other than [TraceTable], such as [ChunkedTraceBuilder].
+pub trait TraceSteps<B: StarkField> {
+    /// Number of columns in the trace.
+    fn width(&self) -> usize;
+
+    /// Number of rows in the trace; must be a power of two.
+    fn length(&self) -> usize;
+
+    /// Writes the first row of the trace into `row`.
+    fn init(&self, row: &mut [B]);
+
+    /// Replaces the row at `step` with the row at `step + 1`.
+    fn update(&self, step: usize, row: &mut [B]);
+}
+
+/// Builds the entire trace in memory.
+pub fn build_trace<B: StarkField>(steps: &dyn TraceSteps<B>) -> TraceTable<B> {
+    build_trace_observed(steps, &|_| {})
+}
+
+/// Builds the entire trace in memory, and calls `on_rows` with the number of rows built so far
+/// after every [ROWS_PER_UPDATE] rows and once the trace is complete.
+pub fn build_trace_observed<B: StarkField>(
+    steps: &dyn TraceSteps<B>,
+    on_rows: &dyn Fn(usize),
+) -> TraceTable<B> {
+    let length = steps.length();
+    let mut trace = TraceTable::new(steps.width(), length);
+    trace.fill(
+        |row| steps.init(row),
+        |step, row| {
+            steps.update(step, row);
+            // the update produces the row at step + 1
+            let num_rows = step + 2;
+            if num_rows % ROWS_PER_UPDATE == 0 {
+                on_rows(num_rows);
+            }
+        },
+    );
+    if length % ROWS_PER_UPDATE != 0 {
+        on_rows(length);
+    }
+    trace
+}
+
+// CHUNKED TRACE BUILDER
+// ================================================================================================
+
+/// Builds a trace in chunks of consecutive rows and writes each chunk to a file in a checkpoint
+/// directory as soon as it is complete.
+///
+/// Chunks which are already present in the directory are read back instead of being recomputed,
+/// so a build which was interrupted picks up from the last chunk it wrote. Every file records
+/// the shape and the first row of the trace it belongs to, and files written for a different
+/// trace are rejected rather than silently mixed in. The files are kept once the trace is
+/// complete; remove the directory to start over.
+pub struct ChunkedTraceBuilder {
+    dir: PathBuf,
+    chunk_length: usize,
+}
+
+impl ChunkedTraceBuilder {
+    /// Returns a builder which writes chunks of `chunk_length` rows into `dir`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_length` is not a power of two.
+    pub fn new(dir: impl Into<PathBuf>, chunk_length: usize) -> Self {
+        assert!(
+            chunk_length.is_power_of_two(),
+            "chunk length must be a power of two"
+        );
+        Self {
+            dir: dir.into(),
+            chunk_length,
+        }
+    }
+
+    /// Returns the directory into which chunks are written.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Builds the trace, reading completed chunks from the checkpoint directory and computing
+    /// and writing the missing ones.
+    ///
+    /// Returns an error if the directory cannot be written to, or if it holds chunks of a
+    /// different trace.
+    pub fn build<B: StarkField>(&self, steps: &dyn TraceSteps<B>) -> Result<TraceTable<B>, String> {
+        let width = steps.width();
+        let length = steps.length();
+        let chunk_length = self.chunk_length.min(length);
+        let num_chunks = length / chunk_length;
+
+        fs::create_dir_all(&self.dir).map_err(|err| {
+            format!(
+                "failed to create checkpoint directory {}: {}",
+                self.dir.display(),
+                err
+            )
+        })?;
+
+        let mut row = vec![B::ZERO; width];
+        steps.init(&mut row);
+        let header = ChunkHeader {
+            width,
+            length,
+            chunk_length,
+            first_row: row.clone(),
+        };
+
+        let mut columns = vec![Vec::with_capacity(length); width];
+        let mut num_resumed = 0;
+        for index in 0..num_chunks {
+            let path = self.chunk_path(index);
+            let start = index * chunk_length;
+
+            let rows = if path.exists() {
+                num_resumed += 1;
+                header.read_chunk(&path, index)?
+            } else {
+                // the first row of a chunk follows from the last row of the previous one
+                if index > 0 {
+                    steps.update(start - 1, &mut row);
+                }
+                let mut rows = Vec::with_capacity(chunk_length * width);
+                rows.extend_from_slice(&row);
+                for step in start..start + chunk_length - 1 {
+                    steps.update(step, &mut row);
+                    rows.extend_from_slice(&row);
+                }
+                header.write_chunk(&path, index, &rows)?;
+                debug!("Wrote trace chunk {} of {}", index + 1, num_chunks);
+                rows
+            };
+
+            for chunk_row in rows.chunks(width) {
+                for (column, &value) in columns.iter_mut().zip(chunk_row) {
+                    column.push(value);
+                }
+            }
+            row.copy_from_slice(&rows[rows.len() - width..]);
+        }
+
+        if num_resumed > 0 {
+            info!(
+                "Resumed {} of {} trace chunks from {}",
+                num_resumed,
+                num_chunks,
+                self.dir.display()
+            );
+        }
+
+        Ok(TraceTable::init(columns))
+    }
+
+    fn chunk_path(&self, index: usize) -> PathBuf {
+        self.dir.join(format!("chunk-{:06}.bin", index))
+    }
+}
+
+// CSV EXPORT
+// ================================================================================================
+
+/// A range of trace rows given on the command line as `START..END` or `START..`; the end is
+/// exclusive, and an open range extends to the last row of the trace. The default range covers
+/// the entire trace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RowRange {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+impl RowRange {
+    /// Returns the rows of a trace of `trace_length` steps covered by this range.
+    ///
+    /// Returns an error if the range is empty or extends past the end of the trace.
+    pub fn resolve(&self, trace_length: usize) -> Result<Range<usize>, String> {
+        let end = self.end.unwrap_or(trace_length);
+        if self.start >= end {
+            return Err(format!("row range {}..{} is empty", self.start, end));
+        }
+        if end > trace_length {
+            return Err(format!(
+                "row range {}..{} extends past the end of the trace of {} steps",
+                self.start, end, trace_length
+            ));
+        }
+        Ok(self.start..end)
+    }
+}
+
+impl FromStr for RowRange {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let (start, end) = source
+            .split_once("..")
+            .ok_or_else(|| format!("row range '{}' is not of the form START..END", source))?;
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<usize>()
+                .map_err(|err| format!("invalid row '{}' in row range: {}", value, err))
+        };
+        let start = if start.trim().is_empty() {
+            0
+        } else {
+            parse(start)?
+        };
+        let end = if end.trim().is_empty() {
+            None
+        } else {
+            Some(parse(end)?)
+        };
+        Ok(Self { start, end })
+    }
+}
+
+/// Writes the specified rows of the trace as CSV: a header of `step,col_0,col_1,...` followed
+/// by a line per step, with each value printed as an integer in the field.
+///
+/// # Panics
+/// Panics if `rows` extends past the end of the trace.
+pub fn write_csv<B: StarkField>(
+    trace: &TraceTable<B>,
+    rows: Range<usize>,
+    writer: &mut impl io::Write,
+) -> io::Result<()> {
+    assert!(
+        rows.end <= trace.length(),
+        "row range extends past the end of the trace"
+    );
+    write!(writer, "step")?;
+    for column in 0..trace.width() {
+        write!(writer, ",col_{}", column)?;
+    }
+    writeln!(writer)?;
+    for step in rows {
+        write!(writer, "{}", step)?;
+        for column in 0..trace.width() {
+            write!(writer, ",{}", trace.get(column, step))?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()
+}
+
+/// Writes the specified rows of the trace into a CSV file at `path`; see [write_csv()].
+///
+/// Returns a [Config](ExampleError::Config) error if the range does not fit into the trace, and
+/// an [Io](ExampleError::Io) error if the file cannot be written.
+pub fn dump_csv<B: StarkField>(
+    trace: &TraceTable<B>,
+    rows: RowRange,
+    path: &Path,
+) -> Result<Range<usize>, ExampleError> {
+    let rows = rows.resolve(trace.length()).map_err(ExampleError::Config)?;
+    fs::File::create(path)
+        .and_then(|file| write_csv(trace, rows.clone(), &mut BufWriter::new(file)))
+        .map_err(|err| ExampleError::write("trace", path, err))?;
+    Ok(rows)
+}
+
+// CHUNK FILES
+// ================================================================================================
+
+/// Identifies the trace a chunk file belongs to.
+struct ChunkHeader<B: StarkField> {
+    width: usize,
+    length: usize,
+    chunk_length: usize,
+    first_row: Vec<B>,
+}
+
+impl<B: StarkField> ChunkHeader<B> {
+    /// Writes the chunk into a temporary file and moves it into place once it is complete, so
+    /// that an interrupted write never leaves a truncated chunk behind.
+    fn write_chunk(&self, path: &Path, index: usize, rows: &[B]) -> Result<(), String> {
+        let mut bytes = Vec::new();
+        bytes.write_u8_slice(CHUNK_MAGIC);
+        bytes.write_u32(self.width as u32);
+        bytes.write_u8(log2(self.length) as u8);
+        bytes.write_u8(log2(self.chunk_length) as u8);
+        bytes.write_u64(index as u64);
+        bytes.write(&self.first_row[..]);
+        bytes.write(rows);
+
+        let tmp_path = path.with_extension("part");
+        fs::write(&tmp_path, &bytes)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|err| format!("failed to write trace chunk {}: {}", path.display(), err))
+    }
+
+    fn read_chunk(&self, path: &Path, index: usize) -> Result<Vec<B>, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("failed to read trace chunk {}: {}", path.display(), err))?;
+        let malformed = |err: String| {
+            format!(
+                "failed to read trace chunk {}: {}; remove the checkpoint directory to start over",
+                path.display(),
+                err
+            )
+        };
+
+        let mut reader = SliceReader::new(&bytes);
+        let magic = reader
+            .read_u8_array::<8>()
+            .map_err(|err| malformed(err.to_string()))?;
+        if &magic != CHUNK_MAGIC {
+            return Err(malformed("not a trace checkpoint".to_string()));
+        }
+        let width = reader
+            .read_u32()
+            .map_err(|err| malformed(err.to_string()))? as usize;
+        let log_length = reader.read_u8().map_err(|err| malformed(err.to_string()))?;
+        let log_chunk_length = reader.read_u8().map_err(|err| malformed(err.to_string()))?;
+        let chunk_index = reader
+            .read_u64()
+            .map_err(|err| malformed(err.to_string()))?;
+        let first_row =
+            B::read_batch_from(&mut reader, width).map_err(|err| malformed(err.to_string()))?;
+        if width != self.width
+            || log_length as u32 != log2(self.length)
+            || log_chunk_length as u32 != log2(self.chunk_length)
+            || chunk_index != index as u64
+            || first_row != self.first_row
+        {
+            return Err(malformed(
+                "chunk was written for a different trace".to_string(),
+            ));
+        }
+
+        let rows = B::read_batch_from(&mut reader, self.width * self.chunk_length)
+            .map_err(|err| malformed(err.to_string()))?;
+        if reader.has_more_bytes() {
+            return Err(malformed("unexpected trailing bytes".to_string()));
+        }
+        Ok(rows)
+    }
+}
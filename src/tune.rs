@@ -0,0 +1,487 @@
+use crate::{
+    config::{FieldExt, HashFn, ProofOptionsConfig},
+    example::{BaseField, Example},
+    proof_file::hash_fn_name,
+    security::{protocol_security, security_level, SecurityBound},
+    validate::{MAX_BLOWUP_FACTOR, MAX_NUM_QUERIES, MIN_BLOWUP_FACTOR},
+};
+use std::time::{Duration, Instant};
+use winterfell::{
+    math::{log2, StarkField},
+    Air, ProofOptions, Prover, Trace,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Security level in bits targeted when none is specified on the command line.
+pub const DEFAULT_TARGET_SECURITY: u32 = 100;
+
+/// Grinding factors considered when none are specified on the command line.
+pub const DEFAULT_GRINDING_FACTORS: [u32; 4] = [0, 8, 16, 20];
+
+/// Security levels in bits compared when none are specified on the command line.
+pub const DEFAULT_SECURITY_LEVELS: [u32; 3] = [80, 100, 128];
+
+/// Field extensions tried in order of increasing cost when comparing security levels.
+const FIELD_EXTENSIONS: [FieldExt; 3] = [FieldExt::None, FieldExt::Quadratic, FieldExt::Cubic];
+
+/// Hash functions compared by [compare_hash()], in the order they are printed in.
+const HASH_FUNCTIONS: [HashFn; 3] = [HashFn::Blake3_192, HashFn::Blake3_256, HashFn::Sha3_256];
+
+// TUNING TARGET
+// ================================================================================================
+
+/// Requirements which a recommended set of proof options has to meet.
+#[derive(Debug, Clone)]
+pub struct TuneTarget {
+    /// Minimum security level in bits.
+    pub security: u32,
+    /// The bound under which the security level is estimated.
+    pub bound: SecurityBound,
+    /// Maximum size of the proof in bytes, if any.
+    pub max_proof_size: Option<usize>,
+}
+
+/// A set of proof options which reaches the target security level, together with the results
+/// of proving the example with it.
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub options: ProofOptions,
+    pub security: u32,
+    pub proof_size: usize,
+    pub prover_time: Duration,
+}
+
+/// The result of proving an example with one of the hash functions compared by
+/// [compare_hash()].
+#[derive(Debug, Clone)]
+pub struct HashCandidate {
+    pub options: ProofOptions,
+    /// Conjectured security level of the proof in bits.
+    pub conjectured: u32,
+    /// Proven security level of the proof in bits.
+    pub proven: u32,
+    /// Conjectured security level the other options reach when the hash function is ignored.
+    pub protocol_conjectured: u32,
+    /// Proven security level the other options reach when the hash function is ignored.
+    pub protocol_proven: u32,
+    pub proof_size: usize,
+    pub prover_time: Duration,
+}
+
+impl HashCandidate {
+    /// Returns the collision resistance of the hash function in bits.
+    pub fn collision_resistance(&self) -> u32 {
+        self.options.hash_fn().collision_resistance()
+    }
+
+    /// Returns true if the hash function, rather than the other options, determines the
+    /// security level under the specified bound.
+    pub fn is_capped_by_hash(&self, bound: SecurityBound) -> bool {
+        let protocol = match bound {
+            SecurityBound::Conjectured => self.protocol_conjectured,
+            SecurityBound::Proven => self.protocol_proven,
+        };
+        self.collision_resistance() < protocol
+    }
+}
+
+// PARAMETER SEARCH
+// ================================================================================================
+
+/// Searches for the proof options which reach the target security level with the fastest
+/// prover while keeping the proof within the maximum size, and prints every candidate tried.
+///
+/// For every admissible blowup factor and every grinding factor, the smallest number of queries
+/// which reaches the target is computed from the security formulas; each resulting candidate is
+/// then proven once to measure its proof size and prover time. The hash function, field
+/// extension, and FRI options are taken from `options` and are not changed.
+///
+/// Returns `None` if no candidate meets the target.
+pub fn tune<E: Example>(
+    example: &E,
+    options: &ProofOptionsConfig,
+    target: &TuneTarget,
+    grinding_factors: &[u32],
+) -> Option<Candidate> {
+    let trace_length = example.build_trace().length();
+    println!(
+        "Searching for proof options with at least {} bits of {} security for a trace of 2^{} \
+        steps",
+        target.security,
+        target.bound,
+        log2(trace_length)
+    );
+    println!(
+        "{:>7} | {:>8} | {:>7} | {:>15} | {:>15} | {:>16}",
+        "blowup", "grinding", "queries", "security (bits)", "proof size (KB)", "prover time (ms)"
+    );
+
+    search(
+        example,
+        options,
+        target,
+        grinding_factors,
+        |candidate, fits| {
+            println!(
+                "{:>7} | {:>8} | {:>7} | {:>15} | {:>15.1} | {:>16}{}",
+                candidate.options.blowup_factor(),
+                candidate.options.grinding_factor(),
+                candidate.options.num_queries(),
+                candidate.security,
+                candidate.proof_size as f64 / 1024f64,
+                candidate.prover_time.as_millis(),
+                if fits { "" } else { " (too large)" }
+            );
+        },
+    )
+}
+
+// SECURITY COMPARISON
+// ================================================================================================
+
+/// Tunes proof options for the example at each of the specified security levels and prints the
+/// recommended options side by side.
+///
+/// Each level is searched for as in [tune()], first without a field extension and then with
+/// progressively larger extensions until the level is reached; small base fields cannot reach
+/// high security levels otherwise. The hash function and FRI options are taken from `options`.
+///
+/// Returns the recommended candidate for each level, or `None` for levels which cannot be
+/// reached.
+pub fn compare_security<E: Example>(
+    example: &E,
+    options: &ProofOptionsConfig,
+    levels: &[u32],
+    bound: SecurityBound,
+    grinding_factors: &[u32],
+) -> Vec<Option<Candidate>> {
+    let trace_length = example.build_trace().length();
+    println!(
+        "Comparing {} security levels for a trace of 2^{} steps",
+        bound,
+        log2(trace_length)
+    );
+
+    let candidates = levels
+        .iter()
+        .map(|&level| {
+            let target = TuneTarget {
+                security: level,
+                bound,
+                max_proof_size: None,
+            };
+            FIELD_EXTENSIONS.iter().find_map(|&extension| {
+                let options = ProofOptionsConfig {
+                    field_extension: Some(extension),
+                    ..options.clone()
+                };
+                search(example, &options, &target, grinding_factors, |_, _| {})
+            })
+        })
+        .collect::<Vec<_>>();
+
+    println!(
+        "{:>6} | {:>8} | {:>7} | {:>6} | {:>8} | {:>9} | {:>15} | {:>16}",
+        "target",
+        "achieved",
+        "queries",
+        "blowup",
+        "grinding",
+        "extension",
+        "proof size (KB)",
+        "prover time (ms)"
+    );
+    for (level, candidate) in levels.iter().zip(candidates.iter()) {
+        match candidate {
+            Some(candidate) => println!(
+                "{:>6} | {:>8} | {:>7} | {:>6} | {:>8} | {:>9} | {:>15.1} | {:>16}",
+                level,
+                candidate.security,
+                candidate.options.num_queries(),
+                candidate.options.blowup_factor(),
+                candidate.options.grinding_factor(),
+                format!("{:?}", candidate.options.field_extension()).to_lowercase(),
+                candidate.proof_size as f64 / 1024f64,
+                candidate.prover_time.as_millis()
+            ),
+            None => println!("{:>6} | cannot be reached", level),
+        }
+    }
+
+    candidates
+}
+
+// HASH COMPARISON
+// ================================================================================================
+
+/// Proves the example with each of the hash functions winterfell supports, keeping all other
+/// options as set in `options`, and prints the proof size and security levels of each one side
+/// by side.
+///
+/// Merkle tree nodes, and so the authentication paths which make up most of a proof, are as
+/// large as a digest, so a shorter digest gives a smaller proof. In exchange, a digest of `n`
+/// bits offers only `n / 2` bits of collision resistance, and a prover who can find collisions
+/// can open a commitment to different values; the security level of a proof can therefore never
+/// exceed the collision resistance of its hash. Levels which the hash rather than the other
+/// options determines are marked, together with the level the other options would reach.
+pub fn compare_hash<E: Example>(example: &E, options: &ProofOptionsConfig) -> Vec<HashCandidate> {
+    let trace_length = example.build_trace().length();
+    let base_field_bits = BaseField::<E>::MODULUS_BITS;
+    println!(
+        "Comparing hash functions for a trace of 2^{} steps",
+        log2(trace_length)
+    );
+
+    let candidates = HASH_FUNCTIONS
+        .iter()
+        .map(|&hash_fn| {
+            let options = ProofOptionsConfig {
+                hash_fn: Some(hash_fn),
+                ..options.clone()
+            }
+            .to_proof_options();
+            let level = |bound| security_level(&options, base_field_bits, trace_length, bound);
+            let protocol =
+                |bound| protocol_security(&options, base_field_bits, trace_length, bound);
+            let conjectured = level(SecurityBound::Conjectured);
+            let proven = level(SecurityBound::Proven);
+            let protocol_conjectured = protocol(SecurityBound::Conjectured);
+            let protocol_proven = protocol(SecurityBound::Proven);
+
+            let prover = example.prover(options.clone());
+            let trace = example.build_trace();
+            let now = Instant::now();
+            let proof = prover.prove(trace).unwrap();
+            let prover_time = now.elapsed();
+
+            HashCandidate {
+                options,
+                conjectured,
+                proven,
+                protocol_conjectured,
+                protocol_proven,
+                proof_size: proof.to_bytes().len(),
+                prover_time,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    println!(
+        "{:>10} | {:>11} | {:>11} | {:>8} | {:>15} | {:>16}",
+        "hash", "hash (bits)", "conjectured", "proven", "proof size (KB)", "prover time (ms)"
+    );
+    let level = |candidate: &HashCandidate, bound| {
+        let level = match bound {
+            SecurityBound::Conjectured => candidate.conjectured,
+            SecurityBound::Proven => candidate.proven,
+        };
+        if candidate.is_capped_by_hash(bound) {
+            format!("{}*", level)
+        } else {
+            level.to_string()
+        }
+    };
+    for candidate in candidates.iter() {
+        println!(
+            "{:>10} | {:>11} | {:>11} | {:>8} | {:>15.1} | {:>16}",
+            hash_fn_name(candidate.options.hash_fn()),
+            candidate.collision_resistance(),
+            level(candidate, SecurityBound::Conjectured),
+            level(candidate, SecurityBound::Proven),
+            candidate.proof_size as f64 / 1024f64,
+            candidate.prover_time.as_millis()
+        );
+    }
+    // the other options do not depend on the hash, so neither do the levels they reach
+    if let Some(candidate) = candidates.iter().find(|candidate| {
+        candidate.is_capped_by_hash(SecurityBound::Conjectured)
+            || candidate.is_capped_by_hash(SecurityBound::Proven)
+    }) {
+        println!();
+        println!(
+            "* capped by the collision resistance of the hash; without the cap, the other \
+            options reach {} bits of conjectured and {} bits of proven security",
+            candidate.protocol_conjectured, candidate.protocol_proven
+        );
+    }
+
+    candidates
+}
+
+// PARAMETER SEARCH
+// ================================================================================================
+
+/// Proves the example with the cheapest options reaching the target for every admissible
+/// blowup factor and grinding factor, reports each candidate to `report` together with whether
+/// it fits within the maximum proof size, and returns the fastest candidate which fits.
+fn search<E: Example, F: FnMut(&Candidate, bool)>(
+    example: &E,
+    options: &ProofOptionsConfig,
+    target: &TuneTarget,
+    grinding_factors: &[u32],
+    mut report: F,
+) -> Option<Candidate> {
+    let trace_info = example.build_trace().get_info();
+    let trace_length = trace_info.length();
+    let base_field_bits = BaseField::<E>::MODULUS_BITS;
+
+    // the constraints determine the smallest blowup factor the example can be proven with
+    let largest_blowup = with_blowup(options, MAX_BLOWUP_FACTOR).to_proof_options();
+    let air = E::Air::new(trace_info, example.pub_inputs(), largest_blowup);
+    let min_blowup = air.ce_blowup_factor().max(MIN_BLOWUP_FACTOR);
+
+    let mut best: Option<Candidate> = None;
+    let blowup_factors = (log2(min_blowup)..=log2(MAX_BLOWUP_FACTOR)).map(|i| 1usize << i);
+    for blowup_factor in blowup_factors {
+        for &grinding_factor in grinding_factors {
+            let candidate_options = ProofOptionsConfig {
+                grinding_factor: Some(grinding_factor),
+                ..with_blowup(options, blowup_factor)
+            };
+            let options =
+                match min_queries(&candidate_options, base_field_bits, trace_length, target) {
+                    Some(options) => options,
+                    None => continue,
+                };
+
+            let candidate = prove(
+                example,
+                options,
+                base_field_bits,
+                trace_length,
+                target.bound,
+            );
+            let fits = target
+                .max_proof_size
+                .is_none_or(|max_size| candidate.proof_size <= max_size);
+            report(&candidate, fits);
+
+            let is_better = best.as_ref().is_none_or(|best| {
+                (candidate.prover_time, candidate.proof_size) < (best.prover_time, best.proof_size)
+            });
+            if fits && is_better {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn with_blowup(options: &ProofOptionsConfig, blowup_factor: usize) -> ProofOptionsConfig {
+    ProofOptionsConfig {
+        blowup_factor: Some(blowup_factor),
+        ..options.clone()
+    }
+}
+
+/// Returns the options with the smallest number of queries which reach the target security
+/// level, or `None` if the target cannot be reached with any number of queries.
+fn min_queries(
+    options: &ProofOptionsConfig,
+    base_field_bits: u32,
+    trace_length: usize,
+    target: &TuneTarget,
+) -> Option<ProofOptions> {
+    (1..=MAX_NUM_QUERIES)
+        .map(|num_queries| {
+            ProofOptionsConfig {
+                num_queries: Some(num_queries),
+                ..options.clone()
+            }
+            .to_proof_options()
+        })
+        .find(|options| {
+            security_level(options, base_field_bits, trace_length, target.bound) >= target.security
+        })
+}
+
+fn prove<E: Example>(
+    example: &E,
+    options: ProofOptions,
+    base_field_bits: u32,
+    trace_length: usize,
+    bound: SecurityBound,
+) -> Candidate {
+    let security = security_level(&options, base_field_bits, trace_length, bound);
+    let prover = example.prover(options.clone());
+    let trace = example.build_trace();
+
+    let now = Instant::now();
+    let proof = prover.prove(trace).unwrap();
+    let prover_time = now.elapsed();
+
+    Candidate {
+        options,
+        security,
+        proof_size: proof.to_bytes().len(),
+        prover_time,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::ProofOptionsConfig, security::SecurityBound, test_utils::vdf_example};
+    use winterfell::{FieldExtension, HashFunction};
+
+    #[test]
+    fn security_comparison_reaches_each_level() {
+        let levels = [80, 128];
+        let candidates = super::compare_security(
+            &vdf_example(),
+            &ProofOptionsConfig::default(),
+            &levels,
+            SecurityBound::Conjectured,
+            &[0],
+        );
+        for (&level, candidate) in levels.iter().zip(candidates.iter()) {
+            let candidate = candidate.as_ref().unwrap();
+            assert!(candidate.security >= level);
+        }
+
+        // 128 bits are out of reach of the base field on its own
+        let options = &candidates[1].as_ref().unwrap().options;
+        assert_ne!(FieldExtension::None, options.field_extension());
+    }
+
+    #[test]
+    fn hash_comparison_shows_the_cap_of_192_bit_hashing() {
+        let options = ProofOptionsConfig {
+            num_queries: Some(64),
+            blowup_factor: Some(8),
+            ..ProofOptionsConfig::default()
+        };
+        let candidates = super::compare_hash(&vdf_example(), &options);
+        let hash_fns = candidates
+            .iter()
+            .map(|candidate| candidate.options.hash_fn())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                HashFunction::Blake3_192,
+                HashFunction::Blake3_256,
+                HashFunction::Sha3_256
+            ],
+            hash_fns
+        );
+
+        // the shorter digest gives a smaller proof, but caps the level below what the options reach
+        let (blake3_192, blake3_256) = (&candidates[0], &candidates[1]);
+        assert!(blake3_192.proof_size < blake3_256.proof_size);
+        assert_eq!(96, blake3_192.conjectured);
+        assert!(blake3_192.is_capped_by_hash(SecurityBound::Conjectured));
+        assert!(blake3_192.protocol_conjectured > 96);
+        for candidate in &candidates[1..] {
+            assert!(!candidate.is_capped_by_hash(SecurityBound::Conjectured));
+            assert_eq!(blake3_192.protocol_conjectured, candidate.conjectured);
+        }
+    }
+}
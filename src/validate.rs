@@ -0,0 +1,659 @@
+//! Checks of a run configuration which fail before any work is done.
+//!
+//! The examples and winterfell check their inputs with assertions, which fire only once the
+//! result of the computation has been computed or the trace has been allocated, and whose
+//! messages do not say which setting is to blame. [validate()] runs the checks of the selected
+//! example and of its proof options up front, estimates whether the security level of the proof
+//! and the memory of the prover are acceptable, and returns an error which names the setting
+//! and suggests a value that would be accepted.
+
+use crate::{
+    bitwise::{self, BitwiseExample},
+    bytecount::{self, ByteCountExample},
+    compare::{self, CompareExample},
+    config::{ProofOptionsConfig, RunConfig},
+    counter::{self, CounterExample},
+    degrees::DegreeBounds,
+    estimate, exp,
+    fib::{self, FibExample},
+    fibchunk::{self, FibChunkExample},
+    fibdiv::{self, FibDivExample},
+    fibmod::{self, FibModExample},
+    field::{ExampleField, FieldType},
+    fixedexp::{self, FixedExpExample},
+    gcd::{self, GcdExample},
+    horner::{self, HornerExample},
+    iszero::{self, IsZeroExample},
+    lamport::{self, LamportExample},
+    memory,
+    merkle::{self, MerkleExample},
+    modexp::{self, ModExpExample},
+    poseidon::{self, PoseidonExample},
+    quad::{self, QuadExample},
+    rescue::{self, RescueExample},
+    rom::{self, RomExample},
+    runsum::{self, RunSumExample},
+    security::{security_level, SecurityBound},
+    sort::{self, SortExample},
+    sqrt::{self, SqrtExample},
+    sum::{self, SumExample},
+    vdf::VdfExample,
+    vm::{self, VmExample},
+    window::WindowExample,
+    wrap::{self, WrapExample},
+};
+use winterfell::{
+    math::{fields::f128, FieldElement},
+    ProofOptions, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Smallest number of rows of an execution trace which winterfell accepts.
+pub const MIN_TRACE_LENGTH: usize = 8;
+
+/// Conjectured security level in bits below which a proof is not worth generating; the
+/// [Fast](crate::presets::Preset::Fast) preset stays above it.
+pub const MIN_SECURITY_LEVEL: u32 = 32;
+
+/// Range of numbers of queries accepted by [ProofOptions::new()].
+pub const MIN_NUM_QUERIES: usize = 1;
+pub const MAX_NUM_QUERIES: usize = 128;
+
+/// Range of blowup factors accepted by [ProofOptions::new()].
+pub const MIN_BLOWUP_FACTOR: usize = 4;
+pub const MAX_BLOWUP_FACTOR: usize = 128;
+
+/// Largest grinding factor accepted by [ProofOptions::new()].
+pub const MAX_GRINDING_FACTOR: u32 = 32;
+
+/// Range of FRI folding factors accepted by [ProofOptions::new()].
+pub const MIN_FRI_FOLDING_FACTOR: usize = 4;
+pub const MAX_FRI_FOLDING_FACTOR: usize = 16;
+
+/// Range of FRI max remainder sizes accepted by [ProofOptions::new()].
+pub const MIN_FRI_MAX_REMAINDER_SIZE: usize = 32;
+pub const MAX_FRI_MAX_REMAINDER_SIZE: usize = 1024;
+
+// VALIDATION
+// ================================================================================================
+
+/// Checks that the inputs of the example selected in the config are accepted by the example,
+/// that its proof options are accepted by winterfell and reach [MIN_SECURITY_LEVEL], and that
+/// proving it does not need more memory than the machine has.
+///
+/// Returns an error which names the offending setting; an unknown example is not an error here,
+/// it is reported by [with_example()](crate::with_example).
+pub fn validate<B: ExampleField>(config: &RunConfig) -> Result<(), String> {
+    let (inputs, options) = match config.example.as_str() {
+        "vdf" => (
+            VdfExample::<B>::validate(&config.vdf),
+            &config.vdf.proof_options,
+        ),
+        "rescue" => (
+            RescueExample::<B>::validate(&config.rescue),
+            &config.rescue.proof_options,
+        ),
+        "poseidon" => (
+            PoseidonExample::<B>::validate(&config.poseidon),
+            &config.poseidon.proof_options,
+        ),
+        "counter" => (
+            CounterExample::<B>::validate(&config.counter),
+            &config.counter.proof_options,
+        ),
+        "lamport" => (
+            LamportExample::<B>::validate(&config.lamport),
+            &config.lamport.proof_options,
+        ),
+        "exp" => (Ok(()), &config.exp.proof_options),
+        "sum" => (
+            SumExample::<B>::validate(&config.sum),
+            &config.sum.proof_options,
+        ),
+        "vm" => (
+            VmExample::<B>::validate(&config.vm),
+            &config.vm.proof_options,
+        ),
+        "fib" => (
+            FibExample::<B>::validate(&config.fib),
+            &config.fib.proof_options,
+        ),
+        "gcd" => (
+            GcdExample::<B>::validate(&config.gcd),
+            &config.gcd.proof_options,
+        ),
+        "quad" => (
+            QuadExample::<B>::validate(&config.quad),
+            &config.quad.proof_options,
+        ),
+        "iszero" => (
+            IsZeroExample::<B>::validate(&config.iszero),
+            &config.iszero.proof_options,
+        ),
+        "rom" => (
+            RomExample::<B>::validate(&config.rom),
+            &config.rom.proof_options,
+        ),
+        "sqrt" => (
+            SqrtExample::<B>::validate(&config.sqrt),
+            &config.sqrt.proof_options,
+        ),
+        "merkle" => (
+            MerkleExample::<B>::validate(&config.merkle),
+            &config.merkle.proof_options,
+        ),
+        "bitwise" => (
+            BitwiseExample::<B>::validate(&config.bitwise),
+            &config.bitwise.proof_options,
+        ),
+        "compare" => (
+            CompareExample::<B>::validate(&config.compare),
+            &config.compare.proof_options,
+        ),
+        "fibmod" => (
+            FibModExample::<B>::validate(&config.fibmod),
+            &config.fibmod.proof_options,
+        ),
+        "sort" => (
+            SortExample::<B>::validate(&config.sort),
+            &config.sort.proof_options,
+        ),
+        "fibchunk" => (
+            FibChunkExample::<B>::validate(&config.fibchunk),
+            &config.fibchunk.proof_options,
+        ),
+        "fixedexp" => (
+            FixedExpExample::<B>::validate(&config.fixedexp),
+            &config.fixedexp.proof_options,
+        ),
+        "modexp" => (
+            ModExpExample::<B>::validate(&config.modexp),
+            &config.modexp.proof_options,
+        ),
+        "bytecount" => (
+            ByteCountExample::<B>::validate(&config.bytecount),
+            &config.bytecount.proof_options,
+        ),
+        "runsum" => (
+            RunSumExample::<B>::validate(&config.runsum),
+            &config.runsum.proof_options,
+        ),
+        "horner" => (
+            HornerExample::<B>::validate(&config.horner),
+            &config.horner.proof_options,
+        ),
+        "wrap" => (
+            WrapExample::<B>::validate(&config.wrap),
+            &config.wrap.proof_options,
+        ),
+        "window" => (
+            WindowExample::<B>::validate(&config.window),
+            &config.window.proof_options,
+        ),
+        "fibdiv" => (
+            FibDivExample::<B>::validate(&config.fibdiv),
+            &config.fibdiv.proof_options,
+        ),
+        _ => return Ok(()),
+    };
+    inputs?;
+
+    let options = config.proof_options_for(options);
+    check_proof_options(&options)?;
+    let options = options.to_proof_options();
+    let (width, length) = trace_shape(config);
+    check_security(&options, config.field, length)?;
+    check_memory(&options, config.field, width, length)
+}
+
+/// Checks that the blowup factor of the options is at least the one which the transition
+/// constraints of the example need, given their degree bounds (see
+/// [degree_bounds()](crate::degrees::degree_bounds)).
+///
+/// The degrees depend on the AIR and, for some examples, on their settings and the base field,
+/// so they cannot be checked by [validate()]; winterfell itself only checks them with an
+/// assertion once the trace has been built.
+pub fn check_blowup_factor(
+    example: &str,
+    bounds: DegreeBounds,
+    options: &ProofOptions,
+) -> Result<(), String> {
+    if options.blowup_factor() >= bounds.min_blowup_factor {
+        return Ok(());
+    }
+    Err(format!(
+        "blowup_factor is {}, but the transition constraints of {} have degree up to {}, and \
+         evaluating them needs a blowup factor of at least {}; raise blowup_factor to {}",
+        options.blowup_factor(),
+        example,
+        bounds.max_degree,
+        bounds.min_blowup_factor,
+        bounds.min_blowup_factor
+    ))
+}
+
+/// Checks that every proof option is within the range accepted by [ProofOptions::new()], and
+/// that the FRI max remainder size is at least the square of the folding factor.
+///
+/// Options which are not set are not checked.
+pub fn check_proof_options(options: &ProofOptionsConfig) -> Result<(), String> {
+    if let Some(num_queries) = options.num_queries {
+        check_range("num_queries", num_queries, MIN_NUM_QUERIES, MAX_NUM_QUERIES)?;
+    }
+    if let Some(blowup_factor) = options.blowup_factor {
+        check_power_of_two_range(
+            "blowup_factor",
+            blowup_factor,
+            MIN_BLOWUP_FACTOR,
+            MAX_BLOWUP_FACTOR,
+        )?;
+    }
+    if let Some(grinding_factor) = options.grinding_factor {
+        check_range(
+            "grinding_factor",
+            grinding_factor as usize,
+            0,
+            MAX_GRINDING_FACTOR as usize,
+        )?;
+    }
+    if let Some(folding_factor) = options.fri_folding_factor {
+        check_power_of_two_range(
+            "fri_folding_factor",
+            folding_factor,
+            MIN_FRI_FOLDING_FACTOR,
+            MAX_FRI_FOLDING_FACTOR,
+        )?;
+    }
+    if let Some(max_remainder_size) = options.fri_max_remainder_size {
+        check_power_of_two_range(
+            "fri_max_remainder_size",
+            max_remainder_size,
+            MIN_FRI_MAX_REMAINDER_SIZE,
+            MAX_FRI_MAX_REMAINDER_SIZE,
+        )?;
+    }
+    if let (Some(folding_factor), Some(max_remainder_size)) =
+        (options.fri_folding_factor, options.fri_max_remainder_size)
+    {
+        if max_remainder_size < folding_factor * folding_factor {
+            return Err(format!(
+                "fri_max_remainder_size is {}, which is too small for fri_folding_factor {}; \
+                 set it to at least {} or lower the folding factor",
+                max_remainder_size,
+                folding_factor,
+                folding_factor * folding_factor
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a trace length set by `setting` is a power of two of at least
+/// [MIN_TRACE_LENGTH].
+pub fn check_trace_length(setting: &str, trace_length: usize) -> Result<(), String> {
+    check_power_of_two("trace length", setting, trace_length, MIN_TRACE_LENGTH)
+}
+
+/// Checks that `value`, set by `setting`, is a power of two of at least `min`; `what` describes
+/// the value in the error message, such as "number of signatures".
+pub fn check_power_of_two(
+    what: &str,
+    setting: &str,
+    value: usize,
+    min: usize,
+) -> Result<(), String> {
+    if value.is_power_of_two() && value >= min {
+        return Ok(());
+    }
+    let requirement = if min > 1 {
+        format!("a power of two of at least {}", min)
+    } else {
+        "a power of two".to_string()
+    };
+    let suggestion = match value.checked_next_power_of_two() {
+        _ if value <= min => format!("try {}", min.next_power_of_two()),
+        Some(next) => format!("the nearest ones are {} and {}", next / 2, next),
+        None => format!("the largest one is {}", 1usize << (usize::BITS - 1)),
+    };
+    Err(format!(
+        "{} must be {}, but {} is {}; {}",
+        what, requirement, setting, value, suggestion
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_range(setting: &str, value: usize, min: usize, max: usize) -> Result<(), String> {
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} must be between {} and {}, but is {}",
+            setting, min, max, value
+        ))
+    }
+}
+
+fn check_power_of_two_range(
+    setting: &str,
+    value: usize,
+    min: usize,
+    max: usize,
+) -> Result<(), String> {
+    if value.is_power_of_two() && (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} must be a power of two between {} and {}, but is {}",
+            setting, min, max, value
+        ))
+    }
+}
+
+/// Returns an error if proofs with the specified options provide less than [MIN_SECURITY_LEVEL]
+/// bits of conjectured security, suggesting the number of queries which would reach it.
+fn check_security(
+    options: &ProofOptions,
+    field: FieldType,
+    trace_length: usize,
+) -> Result<(), String> {
+    let level = |options: &ProofOptions| {
+        security_level(
+            options,
+            field.modulus_bits(),
+            trace_length,
+            SecurityBound::Conjectured,
+        )
+    };
+    let security = level(options);
+    if security >= MIN_SECURITY_LEVEL {
+        return Ok(());
+    }
+
+    let min_queries = (MIN_NUM_QUERIES..=MAX_NUM_QUERIES)
+        .find(|&num_queries| level(&with_num_queries(options, num_queries)) >= MIN_SECURITY_LEVEL);
+    let suggestion = match min_queries {
+        Some(num_queries) => format!(
+            "raise num_queries to at least {} or increase the blowup factor",
+            num_queries
+        ),
+        None => "use a field extension or a larger base field".to_string(),
+    };
+    Err(format!(
+        "proof options provide only {} bits of conjectured security, below the minimum of {}; {}",
+        security, MIN_SECURITY_LEVEL, suggestion
+    ))
+}
+
+/// Returns an error if the prover is estimated to need more memory than the machine has; the
+/// check is skipped where the size of the memory cannot be determined.
+fn check_memory(
+    options: &ProofOptions,
+    field: FieldType,
+    trace_width: usize,
+    trace_length: usize,
+) -> Result<(), String> {
+    let available = match memory::total_memory() {
+        Some(available) => available,
+        None => return Ok(()),
+    };
+    let required = estimate::prover_memory(trace_width, trace_length, field, options);
+    if required <= available {
+        return Ok(());
+    }
+
+    // every halving of the trace halves the memory estimate
+    let mut max_length = trace_length;
+    while max_length > MIN_TRACE_LENGTH
+        && estimate::prover_memory(trace_width, max_length, field, options) > available
+    {
+        max_length /= 2;
+    }
+    Err(format!(
+        "proving a trace of {} rows and {} columns needs at least {:.1} GB of memory, but this \
+         machine has {:.1} GB; reduce the trace to at most {} rows or lower the blowup factor",
+        trace_length,
+        trace_width,
+        gigabytes(required),
+        gigabytes(available),
+        max_length
+    ))
+}
+
+fn with_num_queries(options: &ProofOptions, num_queries: usize) -> ProofOptions {
+    ProofOptions::new(
+        num_queries,
+        options.blowup_factor(),
+        options.grinding_factor(),
+        options.hash_fn(),
+        options.field_extension(),
+        options.to_fri_options().folding_factor(),
+        options.to_fri_options().max_remainder_size(),
+    )
+}
+
+fn gigabytes(bytes: usize) -> f64 {
+    bytes as f64 / (1024f64 * 1024f64 * 1024f64)
+}
+
+/// Returns the shape of the execution trace of the example selected in the config, which must
+/// have passed the checks of the example.
+pub(crate) fn trace_info(config: &RunConfig) -> TraceInfo {
+    let (width, length) = trace_shape(config);
+    TraceInfo::new(width, length)
+}
+
+/// Returns the width and the length of the execution trace of the example selected in the
+/// config, which must have passed the checks of the example.
+fn trace_shape(config: &RunConfig) -> (usize, usize) {
+    match config.example.as_str() {
+        "vdf" => (1, config.vdf.trace_length),
+        "rescue" => (
+            rescue::rescue::STATE_WIDTH,
+            config.rescue.chain_length * rescue::rescue::CYCLE_LENGTH,
+        ),
+        "poseidon" => (
+            poseidon::poseidon::STATE_WIDTH,
+            config.poseidon.chain_length * poseidon::poseidon::CYCLE_LENGTH,
+        ),
+        "counter" => (
+            counter::air::TRACE_WIDTH,
+            config.counter.num_blocks * counter::BLOCK_LENGTH,
+        ),
+        "lamport" => (
+            rescue::rescue::STATE_WIDTH,
+            config.lamport.num_signatures
+                * lamport::signature::MESSAGE_BITS
+                * rescue::rescue::CYCLE_LENGTH,
+        ),
+        "exp" => (exp::air::TRACE_WIDTH, exp::TRACE_LENGTH),
+        "sum" => {
+            let width = if config.sum.dot_product || config.sum.weights.is_some() {
+                sum::air::WEIGHT + 1
+            } else {
+                sum::air::SUM + 1
+            };
+            let length = match &config.sum.values {
+                Some(values) => values.len(),
+                None => config.sum.length,
+            };
+            (width, length)
+        }
+        "vm" => (vm::air::TRACE_WIDTH, config.vm.trace_length),
+        "fib" => {
+            let width = match config.fib.num_terms {
+                Some(_) => fib::air::COUNTED_TRACE_WIDTH,
+                None => fib::air::TRACE_WIDTH,
+            };
+            (width, config.fib.trace_length)
+        }
+        "gcd" => (
+            gcd::air::TRACE_WIDTH,
+            gcd::trace_length(config.gcd.a, config.gcd.b),
+        ),
+        "quad" => (quad::air::TRACE_WIDTH, config.quad.trace_length),
+        "iszero" => (iszero::air::TRACE_WIDTH, config.iszero.trace_length),
+        "rom" => (
+            rom::air::SELECTORS + config.rom.table.len(),
+            config.rom.trace_length,
+        ),
+        "sqrt" => (sqrt::air::TRACE_WIDTH, config.sqrt.trace_length),
+        "merkle" => {
+            let inputs = merkle::air::MerkleInputs {
+                seed: f128::BaseElement::ZERO,
+                num_leaves: config.merkle.num_leaves,
+                num_steps: config.merkle.num_steps,
+                root: f128::BaseElement::ZERO,
+            };
+            (inputs.trace_width(), inputs.trace_length())
+        }
+        "bitwise" => (bitwise::air::TRACE_WIDTH, config.bitwise.trace_length),
+        "compare" => (compare::air::TRACE_WIDTH, config.compare.trace_length),
+        "fibmod" => (fibmod::air::TRACE_WIDTH, config.fibmod.trace_length),
+        "sort" => (
+            sort::air::SELECTORS + 2 * (config.sort.trace_length - 1),
+            config.sort.trace_length,
+        ),
+        "fibchunk" => (fibchunk::air::TRACE_WIDTH, config.fibchunk.trace_length),
+        "fixedexp" => (
+            fixedexp::air::TRACE_WIDTH,
+            config.fixedexp.num_exponents * fixedexp::air::CYCLE_LENGTH,
+        ),
+        "modexp" => (
+            modexp::air::TRACE_WIDTH,
+            config.modexp.num_messages * modexp::air::CYCLE_LENGTH,
+        ),
+        "bytecount" => (
+            bytecount::air::TRACE_WIDTH,
+            bytecount::trace_length(config.bytecount.text.len()),
+        ),
+        "runsum" => (runsum::air::TRACE_WIDTH, config.runsum.trace_length),
+        "horner" => (horner::air::TRACE_WIDTH, config.horner.trace_length),
+        "wrap" => (wrap::air::TRACE_WIDTH, config.wrap.trace_length),
+        "window" => (config.window.width, config.window.trace_length),
+        "fibdiv" => (fibdiv::air::TRACE_WIDTH, config.fibdiv.trace_length),
+        example => unreachable!("example '{}' is validated", example),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{trace_info, validate};
+    use crate::{
+        config::{ProofOptionsConfig, RunConfig, VdfConfig},
+        example::Example,
+        memory, run_example,
+        runner::{self, ExampleCommand},
+        EXAMPLES,
+    };
+    use winterfell::{math::fields::f128::BaseElement, Trace};
+
+    #[test]
+    fn invalid_inputs_are_rejected_before_proving() {
+        let mut config = RunConfig {
+            vdf: VdfConfig {
+                trace_length: 1000,
+                ..VdfConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let err = run_example(&config).unwrap_err().to_string();
+        assert!(err.contains("vdf.trace_length is 1000"), "{}", err);
+        assert!(err.contains("512 and 1024"), "{}", err);
+
+        config.vdf.trace_length = 4;
+        let err = run_example(&config).unwrap_err().to_string();
+        assert!(err.contains("at least 8"), "{}", err);
+
+        config.vdf.trace_length = 128;
+        config.override_proof_options(&ProofOptionsConfig {
+            blowup_factor: Some(6),
+            ..ProofOptionsConfig::default()
+        });
+        let err = run_example(&config).unwrap_err().to_string();
+        assert!(
+            err.starts_with("blowup_factor must be a power of two"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn options_below_security_floor_are_rejected() {
+        let mut config = RunConfig {
+            vdf: VdfConfig {
+                trace_length: 128,
+                ..VdfConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        config.override_proof_options(&ProofOptionsConfig {
+            num_queries: Some(8),
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        });
+        let err = run_example(&config).unwrap_err().to_string();
+        assert!(err.contains("only 15 bits"), "{}", err);
+        assert!(err.contains("num_queries to at least 17"), "{}", err);
+
+        config.override_proof_options(&ProofOptionsConfig {
+            num_queries: Some(17),
+            ..ProofOptionsConfig::default()
+        });
+        assert!(validate::<BaseElement>(&config).is_ok());
+    }
+
+    #[test]
+    fn trace_larger_than_memory_is_rejected() {
+        if memory::total_memory().is_none() {
+            return;
+        }
+        let config = RunConfig {
+            vdf: VdfConfig {
+                trace_length: 1 << 40,
+                ..VdfConfig::default()
+            },
+            ..RunConfig::default()
+        };
+        let err = validate::<BaseElement>(&config).unwrap_err();
+        assert!(err.contains("reduce the trace to at most"), "{}", err);
+    }
+
+    #[test]
+    fn trace_shapes_of_configs_match_the_built_traces() {
+        // the blowup factor is checked against an AIR over a trace of the shape the config
+        // selects, without building the trace
+        struct BuildTrace;
+
+        impl ExampleCommand for BuildTrace {
+            type Output = (usize, usize);
+
+            const NEEDS_PROVABLE_OPTIONS: bool = false;
+
+            fn execute<E: Example>(
+                self,
+                example: &E,
+                _options: ProofOptionsConfig,
+            ) -> Self::Output {
+                let trace = example.build_trace();
+                (trace.width(), trace.length())
+            }
+        }
+
+        for &name in EXAMPLES.iter() {
+            let config = RunConfig {
+                example: name.to_string(),
+                ..RunConfig::default()
+            };
+            let shape = runner::with_example(&config, BuildTrace).unwrap();
+            let info = trace_info(&config);
+            assert_eq!(shape, (info.width(), info.length()), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::FORTY_TWO;
+use crate::{
+    air_utils::{first_row_equals, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct VdfInputs<B: ExampleField> {
+    pub seed: B,
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for VdfInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for VdfInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(VdfInputs {
+            seed: B::read_from(source)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// VDF AIR
+// ================================================================================================
+
+pub struct VdfAir<B: ExampleField> {
+    context: AirContext<B>,
+    seed: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for VdfAir<B> {
+    type BaseField = B;
+    type PublicInputs = VdfInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: VdfInputs<B>, options: ProofOptions) -> Self {
+        let degrees = transition_degrees(&[B::ALPHA as usize]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current_state = frame.current()[0];
+        let next_state = frame.next()[0];
+
+        result[0] = current_state - (next_state.exp(B::ALPHA.into()) + FORTY_TWO.into());
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![
+            first_row_equals(0, self.seed),
+            last_row_equals(0, self.trace_length(), self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
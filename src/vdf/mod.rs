@@ -0,0 +1,137 @@
+use crate::field::ExampleField;
+#[cfg(feature = "std")]
+use crate::{
+    config::VdfConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    trace::TraceSteps,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, Trace, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{VdfAir, VdfInputs};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::{VdfProver, VdfTrace};
+
+// CONSTANTS
+// ================================================================================================
+
+pub(crate) const FORTY_TWO: u8 = 42;
+
+// VDF EXAMPLE
+// ================================================================================================
+
+/// Proves that the result was obtained by repeatedly computing `(state - 42)^(1 / alpha)`
+/// starting from a public seed, where `alpha` is the smallest exponent for which raising to the
+/// power of `alpha` is a permutation of the base field. Verification needs only `alpha`-th
+/// powers, which are much cheaper than the roots computed by the prover.
+#[cfg(feature = "std")]
+pub struct VdfExample<B: ExampleField> {
+    num_steps: usize,
+    seed: B,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> VdfExample<B> {
+    pub fn new(config: &VdfConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let num_steps = config.trace_length;
+        let seed = B::from(config.seed);
+
+        // compute result
+        let now = Instant::now();
+        let result = vdf(seed, num_steps);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            num_steps,
+            seed,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &VdfConfig) -> Result<(), String> {
+        validate::check_trace_length("vdf.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for VdfExample<B> {
+    type Air = VdfAir<B>;
+    type Prover = VdfProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        let trace = VdfProver::build_trace(self.seed, self.num_steps);
+        assert_eq!(self.result, trace.get(0, self.num_steps - 1));
+        trace
+    }
+
+    fn trace_steps(&self) -> Option<Box<dyn TraceSteps<B> + '_>> {
+        Some(Box::new(VdfTrace {
+            seed: self.seed,
+            n: self.num_steps,
+        }))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = VdfConfig::default();
+        ExampleDescriptor::new(
+            "vdf",
+            "a verifiable delay function (VDF) based on iterated cube roots",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("seed", config.seed)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("STATE", 1)],
+            constraints: vec![("VDF step", 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> VdfProver<B> {
+        VdfProver::new(options)
+    }
+
+    fn pub_inputs(&self) -> VdfInputs<B> {
+        VdfInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> VdfInputs<B> {
+        VdfInputs {
+            seed: self.seed,
+            result: self.result + B::ONE,
+        }
+    }
+}
+
+// VDF FUNCTION
+// ================================================================================================
+
+/// Returns the state after `n - 1` steps of the VDF, each of which takes the cube root of the
+/// state minus 42, starting from `seed`.
+pub fn vdf<B: ExampleField>(seed: B, n: usize) -> B {
+    let mut state = seed;
+    for _ in 0..(n - 1) {
+        state = (state - B::from(FORTY_TWO)).exp(B::INV_ALPHA);
+    }
+    state
+}
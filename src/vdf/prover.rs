@@ -0,0 +1,73 @@
+use super::{VdfAir, VdfInputs, FORTY_TWO};
+use crate::{
+    field::ExampleField,
+    trace::{self, TraceSteps},
+};
+use std::marker::PhantomData;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct VdfProver<B: ExampleField> {
+    options: ProofOptions,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> VdfProver<B> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn build_trace(seed: B, n: usize) -> TraceTable<B> {
+        trace::build_trace(&VdfTrace { seed, n })
+    }
+}
+
+// TRACE
+// ================================================================================================
+
+/// Execution trace of the VDF with `n` steps starting from `seed`, described row by row.
+pub struct VdfTrace<B: ExampleField> {
+    pub seed: B,
+    pub n: usize,
+}
+
+impl<B: ExampleField> TraceSteps<B> for VdfTrace<B> {
+    fn width(&self) -> usize {
+        1
+    }
+
+    fn length(&self) -> usize {
+        self.n
+    }
+
+    fn init(&self, row: &mut [B]) {
+        row[0] = self.seed;
+    }
+
+    fn update(&self, _step: usize, row: &mut [B]) {
+        row[0] = (row[0] - B::from(FORTY_TWO)).exp(B::INV_ALPHA);
+    }
+}
+
+impl<B: ExampleField> Prover for VdfProver<B> {
+    type BaseField = B;
+    type Air = VdfAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> VdfInputs<B> {
+        let last_step = trace.length() - 1;
+        VdfInputs {
+            seed: trace.get(0, 0),
+            result: trace.get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
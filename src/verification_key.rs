@@ -0,0 +1,415 @@
+//! Verification keys, which fix everything a verifier has to agree on with the prover before it
+//! looks at a proof.
+//!
+//! A winterfell proof carries its own proof options and trace shape, and the verifier accepts a
+//! proof under whatever options it was generated with, so a proof with a single query verifies
+//! just as well as one with the options of the config. A [VerificationKey] records the example,
+//! the field, the shape of the trace, and the proof options of a config, and
+//! [VerificationKey::verify()] rejects proofs which differ from it in any of them before
+//! verifying them. `vk export` writes the key of a config into a file, and `verify --vk` checks
+//! proofs against it without the config or the code which chose the options.
+
+use crate::{
+    error::ExampleError,
+    example::{BaseField, Example, PublicInputs},
+    field::FieldType,
+    proof_file::{hash_fn_name, ProofFile},
+    verify::{verify_proof, VerifyError},
+};
+use std::{any::type_name, fmt, fs, path::Path};
+use winter_utils::SliceReader;
+use winterfell::{
+    math::StarkField, ByteReader, ByteWriter, Deserializable, ProofOptions, Serializable,
+    StarkProof, Trace,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes at the start of every verification key file.
+const KEY_MAGIC: &[u8; 8] = b"STXVRKEY";
+
+/// Version of the key file format written by [VerificationKey::to_bytes()].
+pub const KEY_FORMAT_VERSION: u8 = 1;
+
+// VERIFICATION KEY
+// ================================================================================================
+
+/// The parameters which every proof of an example under a config shares.
+///
+/// A key is serialized as the magic bytes `STXVRKEY` and a format version byte, followed by the
+/// example name, the field name, and the name of the public inputs type, each prefixed by its
+/// length as a `u8`, the trace width and length as `u32`s, and the proof options in the
+/// encoding of winterfell. All integers are little-endian.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationKey {
+    /// Name of the example.
+    pub example: String,
+    /// Base field of the proofs.
+    pub field: FieldType,
+    /// Number of columns of the execution trace.
+    pub trace_width: usize,
+    /// Number of steps of the execution trace.
+    pub trace_length: usize,
+    /// Options the proofs must have been generated with.
+    pub options: ProofOptions,
+    /// Name of the type of the public inputs of the example, whose documentation describes how
+    /// they are serialized.
+    pub pub_inputs: String,
+}
+
+impl VerificationKey {
+    /// Returns the key of proofs of the named example under the specified options; the shape of
+    /// the trace is taken from the trace the example builds.
+    pub fn new<E: Example>(name: &str, example: &E, options: ProofOptions) -> Self {
+        let trace = example.build_trace();
+        let modulus_bytes = BaseField::<E>::get_modulus_le_bytes();
+        Self {
+            example: name.to_string(),
+            field: FieldType::from_modulus_bytes(&modulus_bytes)
+                .expect("examples are instantiated in supported fields"),
+            trace_width: trace.width(),
+            trace_length: trace.length(),
+            options,
+            pub_inputs: short_type_name::<PublicInputs<E>>(),
+        }
+    }
+
+    /// Verifies a serialized proof of the example of this key against serialized public inputs,
+    /// as [verify_proof()] does, after checking that the proof has the field, the trace shape,
+    /// and the proof options of this key.
+    pub fn verify(&self, proof_bytes: &[u8], pub_inputs_bytes: &[u8]) -> Result<(), VerifyError> {
+        let proof_file;
+        let bare_proof_bytes = if ProofFile::is_proof_file(proof_bytes) {
+            proof_file = ProofFile::from_bytes(proof_bytes).map_err(VerifyError::MalformedProof)?;
+            &proof_file.proof_bytes[..]
+        } else {
+            proof_bytes
+        };
+        let proof = StarkProof::from_bytes(bare_proof_bytes)
+            .map_err(|err| VerifyError::MalformedProof(err.to_string()))?;
+        self.check_proof(&proof).map_err(VerifyError::Rejected)?;
+        verify_proof(&self.example, proof_bytes, pub_inputs_bytes)
+    }
+
+    /// Returns an error which names the first parameter in which the proof differs from this
+    /// key.
+    fn check_proof(&self, proof: &StarkProof) -> Result<(), String> {
+        let context = &proof.context;
+        let field = FieldType::from_modulus_bytes(context.field_modulus_bytes());
+        if field != Some(self.field) {
+            return Err(match field {
+                Some(field) => format!(
+                    "proof is over field {}, but the key is for {}",
+                    field, self.field
+                ),
+                None => format!(
+                    "proof is over an unsupported field, but the key is for {}",
+                    self.field
+                ),
+            });
+        }
+        if context.trace_width() != self.trace_width || context.trace_length() != self.trace_length
+        {
+            return Err(format!(
+                "proof has a trace of {} columns and {} steps, but the key is for {} columns and \
+                 {} steps",
+                context.trace_width(),
+                context.trace_length(),
+                self.trace_width,
+                self.trace_length
+            ));
+        }
+        let differences = option_differences(proof.options(), &self.options);
+        if !differences.is_empty() {
+            return Err(format!(
+                "proof was generated with other proof options than the key: {}",
+                differences.join(", ")
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the serialized key.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.write_u8_slice(KEY_MAGIC);
+        bytes.write_u8(KEY_FORMAT_VERSION);
+        for value in [&self.example, &self.field.to_string(), &self.pub_inputs] {
+            bytes.write_u8(value.len() as u8);
+            bytes.write_u8_slice(value.as_bytes());
+        }
+        bytes.write_u32(self.trace_width as u32);
+        bytes.write_u32(self.trace_length as u32);
+        self.options.write_into(&mut bytes);
+        bytes
+    }
+
+    /// Parses a key from bytes written by [VerificationKey::to_bytes()].
+    ///
+    /// Returns an error if the bytes are not a key, or if the key was written in another format
+    /// version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !bytes.starts_with(KEY_MAGIC) {
+            return Err("not a verification key".to_string());
+        }
+        let mut reader = SliceReader::new(&bytes[KEY_MAGIC.len()..]);
+        let format_version = reader.read_u8().map_err(|err| err.to_string())?;
+        if format_version != KEY_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported verification key format version {}; this build reads version {}",
+                format_version, KEY_FORMAT_VERSION
+            ));
+        }
+
+        let example = read_string(&mut reader)?;
+        let field_name = read_string(&mut reader)?;
+        let field = [FieldType::F128, FieldType::F64, FieldType::F62]
+            .iter()
+            .copied()
+            .find(|field| field.to_string() == field_name)
+            .ok_or_else(|| format!("unknown field '{}'", field_name))?;
+        let pub_inputs = read_string(&mut reader)?;
+        let trace_width = reader.read_u32().map_err(|err| err.to_string())? as usize;
+        let trace_length = reader.read_u32().map_err(|err| err.to_string())? as usize;
+        let options = ProofOptions::read_from(&mut reader).map_err(|err| err.to_string())?;
+        if reader.has_more_bytes() {
+            return Err("unexpected trailing bytes".to_string());
+        }
+
+        Ok(Self {
+            example,
+            field,
+            trace_width,
+            trace_length,
+            options,
+            pub_inputs,
+        })
+    }
+
+    /// Writes the serialized key to the specified path.
+    pub fn write(&self, path: &Path) -> Result<(), ExampleError> {
+        fs::write(path, self.to_bytes())
+            .map_err(|err| ExampleError::write("verification key", path, err))
+    }
+
+    /// Reads a key from the specified path.
+    pub fn read(path: &Path) -> Result<Self, ExampleError> {
+        let bytes =
+            fs::read(path).map_err(|err| ExampleError::read("verification key", path, err))?;
+        Self::from_bytes(&bytes).map_err(|err| {
+            ExampleError::Config(format!(
+                "failed to parse verification key {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+}
+
+impl fmt::Display for VerificationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = &self.options;
+        let fri_options = options.to_fri_options();
+        writeln!(f, "Example: {} over field {}", self.example, self.field)?;
+        writeln!(
+            f,
+            "Trace: {} columns and {} steps",
+            self.trace_width, self.trace_length
+        )?;
+        writeln!(
+            f,
+            "Proof options: {} queries, blowup factor {}, grinding factor {}, hash function {}, \
+             field extension of degree {}",
+            options.num_queries(),
+            options.blowup_factor(),
+            options.grinding_factor(),
+            hash_fn_name(options.hash_fn()),
+            options.field_extension().degree()
+        )?;
+        writeln!(
+            f,
+            "FRI: folding factor {} and max remainder size {}",
+            fri_options.folding_factor(),
+            fri_options.max_remainder_size()
+        )?;
+        write!(f, "Public inputs: {}", self.pub_inputs)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a description of every option in which `actual` differs from `expected`.
+fn option_differences(actual: &ProofOptions, expected: &ProofOptions) -> Vec<String> {
+    let (actual_fri, expected_fri) = (actual.to_fri_options(), expected.to_fri_options());
+    let pairs = [
+        ("num_queries", actual.num_queries(), expected.num_queries()),
+        (
+            "blowup_factor",
+            actual.blowup_factor(),
+            expected.blowup_factor(),
+        ),
+        (
+            "grinding_factor",
+            actual.grinding_factor() as usize,
+            expected.grinding_factor() as usize,
+        ),
+        (
+            "field_extension",
+            actual.field_extension().degree() as usize,
+            expected.field_extension().degree() as usize,
+        ),
+        (
+            "fri_folding_factor",
+            actual_fri.folding_factor(),
+            expected_fri.folding_factor(),
+        ),
+        (
+            "fri_max_remainder_size",
+            actual_fri.max_remainder_size(),
+            expected_fri.max_remainder_size(),
+        ),
+    ];
+    let mut differences = pairs
+        .iter()
+        .filter(|(_, actual, expected)| actual != expected)
+        .map(|(name, actual, expected)| format!("{} {} instead of {}", name, actual, expected))
+        .collect::<Vec<_>>();
+    if actual.hash_fn() != expected.hash_fn() {
+        differences.push(format!(
+            "hash_fn {} instead of {}",
+            hash_fn_name(actual.hash_fn()),
+            hash_fn_name(expected.hash_fn())
+        ));
+    }
+    differences
+}
+
+/// Returns the name of a type without its module path and generic parameters.
+fn short_type_name<T>() -> String {
+    let name = type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+fn read_string(reader: &mut SliceReader) -> Result<String, String> {
+    let len = reader.read_u8().map_err(|err| err.to_string())?;
+    let bytes = reader
+        .read_u8_vec(len as usize)
+        .map_err(|err| err.to_string())?;
+    String::from_utf8(bytes).map_err(|_| "key field is not valid UTF-8".to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::VerificationKey;
+    use crate::{
+        config::VdfConfig,
+        example,
+        field::FieldType,
+        proof_file::ProofFile,
+        test_utils::{vdf_example, vdf_options},
+        vdf::VdfExample,
+        verify::{verify_proof, VerifyError},
+    };
+    use winterfell::{math::fields::f128::BaseElement, FieldExtension, HashFunction, ProofOptions};
+
+    #[test]
+    fn verification_key_round_trips_and_verifies() {
+        let example = vdf_example();
+        let key = VerificationKey::new("vdf", &example, vdf_options());
+        assert_eq!(
+            (FieldType::F128, 1, 128, "VdfInputs"),
+            (
+                key.field,
+                key.trace_width,
+                key.trace_length,
+                &*key.pub_inputs
+            )
+        );
+        assert_eq!(key, VerificationKey::from_bytes(&key.to_bytes()).unwrap());
+
+        let report = example::run(&example, vdf_options());
+        assert_eq!(
+            Ok(()),
+            key.verify(&report.proof_bytes, &report.pub_inputs_bytes)
+        );
+        let file = ProofFile::new("vdf", report.proof_bytes.clone()).unwrap();
+        assert_eq!(
+            Ok(()),
+            key.verify(&file.to_bytes(), &report.pub_inputs_bytes)
+        );
+        let mut wrong_inputs = report.pub_inputs_bytes.clone();
+        wrong_inputs[0] ^= 1;
+        assert!(key.verify(&report.proof_bytes, &wrong_inputs).is_err());
+
+        let mut reformatted = key.to_bytes();
+        reformatted[8] = 2;
+        let err = VerificationKey::from_bytes(&reformatted).unwrap_err();
+        assert!(err.contains("format version 2"), "{}", err);
+        assert_eq!(
+            Err("not a verification key".to_string()),
+            VerificationKey::from_bytes(&report.proof_bytes)
+        );
+    }
+
+    #[test]
+    fn verification_key_rejects_proofs_with_other_parameters() {
+        let example = vdf_example();
+        let key = VerificationKey::new("vdf", &example, vdf_options());
+
+        // a proof with fewer queries verifies on its own, but not under the key
+        let weaker_options = ProofOptions::new(
+            14,
+            8,
+            0,
+            HashFunction::Blake3_256,
+            FieldExtension::None,
+            4,
+            32,
+        );
+        let report = example::run(&example, weaker_options);
+        assert_eq!(
+            Ok(()),
+            verify_proof("vdf", &report.proof_bytes, &report.pub_inputs_bytes)
+        );
+        assert_eq!(
+            Err(VerifyError::Rejected(
+                "proof was generated with other proof options than the key: num_queries 14 instead \
+                 of 28"
+                    .to_string()
+            )),
+            key.verify(&report.proof_bytes, &report.pub_inputs_bytes)
+        );
+
+        let longer = VdfExample::<BaseElement>::new(&VdfConfig {
+            trace_length: 256,
+            ..VdfConfig::default()
+        })
+        .unwrap();
+        let report = example::run(&longer, vdf_options());
+        let err = key
+            .verify(&report.proof_bytes, &report.pub_inputs_bytes)
+            .unwrap_err();
+        assert!(err.to_string().contains("256 steps"), "{}", err);
+
+        let f64_example =
+            VdfExample::<winterfell::math::fields::f64::BaseElement>::new(&VdfConfig {
+                trace_length: 128,
+                ..VdfConfig::default()
+            })
+            .unwrap();
+        let report = example::run(&f64_example, vdf_options());
+        let err = key
+            .verify(&report.proof_bytes, &report.pub_inputs_bytes)
+            .unwrap_err();
+        assert_eq!(
+            "proof is over field f64, but the key is for f128",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{plugins, proof_file::ProofFile, tamper::catch_panic, verify_core};
+
+pub use crate::verify_core::{parse_pub_inputs, VerifyError};
+
+// PROOF VERIFICATION
+// ================================================================================================
+
+/// Verifies a serialized proof of the named example against serialized public inputs.
+///
+/// The base field is determined from the proof context, so the proof can be verified without
+/// knowing the config it was generated with. The proof may also be given as a proof file
+/// written by [ProofFile::to_bytes()], in which case its header is checked first. Unlike the rest
+/// of this crate, this function does not measure time, which makes it usable on targets without
+/// a system clock, such as WASM. Examples registered by other crates (see [plugins]) are
+/// verified as well.
+///
+/// Winterfell and the constructors of some AIRs panic rather than return an error for some
+/// malformed proofs and public inputs, such as public inputs which do not match the trace
+/// length of the proof; such a panic is caught and reported as a rejection.
+pub fn verify_proof(
+    example: &str,
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    catch_panic(|| verify_unguarded(example, proof_bytes, pub_inputs_bytes))
+        .map_err(VerifyError::Rejected)?
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the proof as [verify_proof()] does, but lets panics of the verifier unwind.
+fn verify_unguarded(
+    example: &str,
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    let proof_file;
+    let proof_bytes = if ProofFile::is_proof_file(proof_bytes) {
+        proof_file = ProofFile::from_bytes(proof_bytes).map_err(VerifyError::MalformedProof)?;
+        if proof_file.header.example != example {
+            return Err(VerifyError::Rejected(format!(
+                "proof file holds a proof of example '{}'",
+                proof_file.header.example
+            )));
+        }
+        &proof_file.proof_bytes[..]
+    } else {
+        proof_bytes
+    };
+    match verify_core::verify(example, proof_bytes, pub_inputs_bytes) {
+        Err(VerifyError::UnknownExample(name)) => match plugins::registered_example(&name) {
+            Some(registered) => registered.verify(proof_bytes, pub_inputs_bytes),
+            None => Err(VerifyError::UnknownExample(name)),
+        },
+        result => result,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pub_inputs, verify_proof};
+    use crate::{
+        example,
+        field::FieldType,
+        run_example,
+        test_utils::{example_config, vdf_example, vdf_options},
+        EXAMPLES,
+    };
+
+    #[test]
+    fn mutated_proofs_and_inputs_are_rejected_without_panicking() {
+        // the same entry points as the proof and public input fuzz targets, over truncated and
+        // bit-flipped valid inputs
+        let report = example::run(&vdf_example(), vdf_options());
+        let proof_bytes = &report.proof_bytes;
+        let pub_inputs_bytes = &report.pub_inputs_bytes;
+        for len in (0..proof_bytes.len()).step_by(61) {
+            assert!(verify_proof("vdf", &proof_bytes[..len], &[]).is_err());
+        }
+        for index in (0..proof_bytes.len()).step_by(61) {
+            let mut mutated = proof_bytes.clone();
+            mutated[index] ^= 0x80;
+            assert!(verify_proof("vdf", &mutated, &[]).is_err());
+        }
+        for len in 0..pub_inputs_bytes.len() {
+            assert!(parse_pub_inputs("vdf", FieldType::F128, &pub_inputs_bytes[..len]).is_err());
+        }
+        assert!(parse_pub_inputs("vdf", FieldType::F128, pub_inputs_bytes).is_ok());
+    }
+
+    #[test]
+    fn serialized_proof_of_every_example_verifies() {
+        for &name in EXAMPLES.iter() {
+            let report = run_example(&example_config(name)).unwrap();
+            assert_eq!(
+                Ok(()),
+                verify_proof(name, &report.proof_bytes, &report.pub_inputs_bytes),
+                "{}",
+                name
+            );
+
+            // trailing bytes are not ignored
+            let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+            pub_inputs_bytes.push(0);
+            assert!(
+                verify_proof(name, &report.proof_bytes, &pub_inputs_bytes).is_err(),
+                "{}",
+                name
+            );
+        }
+    }
+}
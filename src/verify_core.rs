@@ -0,0 +1,327 @@
+//! Verifier side of the examples, which needs no more than `alloc`.
+//!
+//! Unlike the rest of this crate, this module is also built without the `std` feature, so that
+//! proofs of the examples can be verified in constrained environments: it depends only on the
+//! AIRs of the examples and on the winterfell verifier. With `std`,
+//! [verify_proof()](crate::verify::verify_proof) builds on it to also accept proof files.
+
+use crate::{
+    bitwise::air::BitwiseAir,
+    bytecount::air::ByteCountAir,
+    compare::air::CompareAir,
+    counter::air::CounterAir,
+    exp::air::ExpAir,
+    fib::air::FibAir,
+    fibchunk::air::FibChunkAir,
+    fibdiv::air::FibDivAir,
+    fibmod::air::FibModAir,
+    field::{ExampleField, FieldType},
+    fixedexp::air::FixedExpAir,
+    gcd::air::GcdAir,
+    horner::air::HornerAir,
+    iszero::air::IsZeroAir,
+    lamport::air::LamportAir,
+    merkle::air::MerkleAir,
+    modexp::air::ModExpAir,
+    poseidon::air::PoseidonAir,
+    quad::air::QuadAir,
+    rescue::air::RescueAir,
+    rom::air::RomAir,
+    runsum::air::RunSumAir,
+    sort::air::SortAir,
+    sqrt::air::SqrtAir,
+    sum::air::SumAir,
+    vdf::air::VdfAir,
+    vm::air::VmAir,
+    window::air::WindowAir,
+    wrap::air::WrapAir,
+    EXAMPLES,
+};
+use core::fmt;
+use winter_utils::{
+    string::{String, ToString},
+    SliceReader,
+};
+use winterfell::{
+    math::fields::{f128, f62, f64},
+    Air, ByteReader, Deserializable, StarkProof,
+};
+
+// VERIFICATION ERROR
+// ================================================================================================
+
+/// Reason a serialized proof was not accepted by [verify()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// No example has the specified name.
+    UnknownExample(String),
+    /// The proof could not be deserialized.
+    MalformedProof(String),
+    /// The proof is for a base field which none of the examples can be instantiated in.
+    UnsupportedField,
+    /// The public inputs could not be deserialized.
+    MalformedPublicInputs(String),
+    /// The proof was parsed, but the verifier rejected it.
+    Rejected(String),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::UnknownExample(name) => write!(
+                f,
+                "unknown example '{}'; available examples: {}",
+                name,
+                EXAMPLES.join(", ")
+            ),
+            VerifyError::MalformedProof(err) => write!(f, "failed to parse proof: {}", err),
+            VerifyError::UnsupportedField => write!(f, "proof is for an unsupported base field"),
+            VerifyError::MalformedPublicInputs(err) => {
+                write!(f, "failed to parse public inputs: {}", err)
+            }
+            VerifyError::Rejected(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
+
+// PROOF VERIFICATION
+// ================================================================================================
+
+/// Verifies a serialized proof of the named example against serialized public inputs.
+///
+/// The base field is determined from the proof context, so the proof can be verified without
+/// knowing the config it was generated with. The proof must be a bare serialized proof, as
+/// written by `StarkProof::to_bytes()`.
+pub fn verify(
+    example: &str,
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    visit_air(example, proof_bytes, pub_inputs_bytes, WinterfellVerifier)?
+}
+
+/// Parses serialized public inputs of the named example in the specified base field, without
+/// verifying a proof against them.
+///
+/// Returns an error if the example is unknown, or if the bytes are not exactly one encoding of
+/// the public inputs of the example.
+pub fn parse_pub_inputs(
+    example: &str,
+    field: FieldType,
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    match field {
+        FieldType::F128 => parse_in_field::<f128::BaseElement>(example, pub_inputs_bytes),
+        FieldType::F64 => parse_in_field::<f64::BaseElement>(example, pub_inputs_bytes),
+        FieldType::F62 => parse_in_field::<f62::BaseElement>(example, pub_inputs_bytes),
+    }
+}
+
+// AIR DISPATCH
+// ================================================================================================
+
+/// Something done with a proof once the AIR of its example and its public inputs are known.
+///
+/// [verify()] hands proofs to the winterfell verifier this way; other verifiers implement this
+/// trait to reuse the mapping from example names and base fields to AIRs.
+pub(crate) trait AirVisitor {
+    type Output;
+
+    fn visit<A: Air>(self, proof: StarkProof, pub_inputs: A::PublicInputs) -> Self::Output
+    where
+        A::PublicInputs: Deserializable;
+}
+
+/// Parses a serialized proof and the serialized public inputs of the named example in the base
+/// field of the proof, and passes them to the visitor together with the AIR of the example.
+pub(crate) fn visit_air<V: AirVisitor>(
+    example: &str,
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+    visitor: V,
+) -> Result<V::Output, VerifyError> {
+    let proof = StarkProof::from_bytes(proof_bytes)
+        .map_err(|err| VerifyError::MalformedProof(err.to_string()))?;
+    let field = FieldType::from_modulus_bytes(proof.context.field_modulus_bytes())
+        .ok_or(VerifyError::UnsupportedField)?;
+
+    match field {
+        FieldType::F128 => {
+            visit_in_field::<f128::BaseElement, V>(example, proof, pub_inputs_bytes, visitor)
+        }
+        FieldType::F64 => {
+            visit_in_field::<f64::BaseElement, V>(example, proof, pub_inputs_bytes, visitor)
+        }
+        FieldType::F62 => {
+            visit_in_field::<f62::BaseElement, V>(example, proof, pub_inputs_bytes, visitor)
+        }
+    }
+}
+
+/// Verifies a bare serialized proof against serialized public inputs of the specified AIR, for
+/// examples which are not matched by name.
+pub(crate) fn verify_air<A: Air>(
+    proof_bytes: &[u8],
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError>
+where
+    A::PublicInputs: Deserializable,
+{
+    let proof = StarkProof::from_bytes(proof_bytes)
+        .map_err(|err| VerifyError::MalformedProof(err.to_string()))?;
+    visit::<A, _>(proof, pub_inputs_bytes, WinterfellVerifier)?
+}
+
+/// Verifies proofs with the winterfell verifier.
+struct WinterfellVerifier;
+
+impl AirVisitor for WinterfellVerifier {
+    type Output = Result<(), VerifyError>;
+
+    fn visit<A: Air>(self, proof: StarkProof, pub_inputs: A::PublicInputs) -> Self::Output
+    where
+        A::PublicInputs: Deserializable,
+    {
+        winterfell::verify::<A>(proof, pub_inputs)
+            .map_err(|err| VerifyError::Rejected(err.to_string()))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn visit_in_field<B: ExampleField, V: AirVisitor>(
+    example: &str,
+    proof: StarkProof,
+    pub_inputs_bytes: &[u8],
+    visitor: V,
+) -> Result<V::Output, VerifyError> {
+    match example {
+        "vdf" => visit::<VdfAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "rescue" => visit::<RescueAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "poseidon" => visit::<PoseidonAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "counter" => visit::<CounterAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "lamport" => visit::<LamportAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "exp" => visit::<ExpAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "sum" => visit::<SumAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "vm" => visit::<VmAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "fib" => visit::<FibAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "gcd" => visit::<GcdAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "quad" => visit::<QuadAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "iszero" => visit::<IsZeroAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "rom" => visit::<RomAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "sqrt" => visit::<SqrtAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "merkle" => visit::<MerkleAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "bitwise" => visit::<BitwiseAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "compare" => visit::<CompareAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "fibmod" => visit::<FibModAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "sort" => visit::<SortAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "fibchunk" => visit::<FibChunkAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "fixedexp" => visit::<FixedExpAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "modexp" => visit::<ModExpAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "bytecount" => visit::<ByteCountAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "runsum" => visit::<RunSumAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "horner" => visit::<HornerAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "wrap" => visit::<WrapAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "window" => visit::<WindowAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        "fibdiv" => visit::<FibDivAir<B>, V>(proof, pub_inputs_bytes, visitor),
+        name => Err(VerifyError::UnknownExample(name.to_string())),
+    }
+}
+
+fn parse_in_field<B: ExampleField>(
+    example: &str,
+    pub_inputs_bytes: &[u8],
+) -> Result<(), VerifyError> {
+    match example {
+        "vdf" => read_pub_inputs::<VdfAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "rescue" => read_pub_inputs::<RescueAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "poseidon" => read_pub_inputs::<PoseidonAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "counter" => read_pub_inputs::<CounterAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "lamport" => read_pub_inputs::<LamportAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "exp" => read_pub_inputs::<ExpAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "sum" => read_pub_inputs::<SumAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "vm" => read_pub_inputs::<VmAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "fib" => read_pub_inputs::<FibAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "gcd" => read_pub_inputs::<GcdAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "quad" => read_pub_inputs::<QuadAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "iszero" => read_pub_inputs::<IsZeroAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "rom" => read_pub_inputs::<RomAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "sqrt" => read_pub_inputs::<SqrtAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "merkle" => read_pub_inputs::<MerkleAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "bitwise" => read_pub_inputs::<BitwiseAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "compare" => read_pub_inputs::<CompareAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "fibmod" => read_pub_inputs::<FibModAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "sort" => read_pub_inputs::<SortAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "fibchunk" => read_pub_inputs::<FibChunkAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "fixedexp" => read_pub_inputs::<FixedExpAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "modexp" => read_pub_inputs::<ModExpAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "bytecount" => read_pub_inputs::<ByteCountAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "runsum" => read_pub_inputs::<RunSumAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "horner" => read_pub_inputs::<HornerAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "wrap" => read_pub_inputs::<WrapAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "window" => read_pub_inputs::<WindowAir<B>>(pub_inputs_bytes).map(|_| ()),
+        "fibdiv" => read_pub_inputs::<FibDivAir<B>>(pub_inputs_bytes).map(|_| ()),
+        name => Err(VerifyError::UnknownExample(name.to_string())),
+    }
+}
+
+fn visit<A: Air, V: AirVisitor>(
+    proof: StarkProof,
+    pub_inputs_bytes: &[u8],
+    visitor: V,
+) -> Result<V::Output, VerifyError>
+where
+    A::PublicInputs: Deserializable,
+{
+    let pub_inputs = read_pub_inputs::<A>(pub_inputs_bytes)?;
+    Ok(visitor.visit::<A>(proof, pub_inputs))
+}
+
+fn read_pub_inputs<A: Air>(pub_inputs_bytes: &[u8]) -> Result<A::PublicInputs, VerifyError>
+where
+    A::PublicInputs: Deserializable,
+{
+    let mut reader = SliceReader::new(pub_inputs_bytes);
+    let pub_inputs = A::PublicInputs::read_from(&mut reader)
+        .map_err(|err| VerifyError::MalformedPublicInputs(err.to_string()))?;
+    if reader.has_more_bytes() {
+        return Err(VerifyError::MalformedPublicInputs(
+            "unexpected trailing bytes".to_string(),
+        ));
+    }
+    Ok(pub_inputs)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        example,
+        test_utils::{vdf_example, vdf_options},
+        verify::verify_proof,
+    };
+
+    #[test]
+    fn core_verifier_agrees_with_proof_verifier() {
+        let report = example::run(&vdf_example(), vdf_options());
+        assert_eq!(
+            Ok(()),
+            super::verify("vdf", &report.proof_bytes, &report.pub_inputs_bytes)
+        );
+
+        let mut pub_inputs_bytes = report.pub_inputs_bytes.clone();
+        pub_inputs_bytes.push(0);
+        assert_eq!(
+            verify_proof("vdf", &report.proof_bytes, &pub_inputs_bytes),
+            super::verify("vdf", &report.proof_bytes, &pub_inputs_bytes)
+        );
+        assert!(super::verify("fib", &report.proof_bytes, &report.pub_inputs_bytes).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Instruction, NUM_OPCODES};
+use crate::{
+    air_utils::{
+        and, are_equal, first_row_equals, last_row_equals, not, transition_degrees,
+        EvaluationResult,
+    },
+    claims::{claim_assertions, read_claimed_values, write_claimed_values, ClaimedValue},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::{polynom, FieldElement},
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout; the flag columns are ordered by [Instruction::opcode()].
+pub const PC: usize = 0;
+pub const FLAGS: usize = 1;
+pub const OPERAND: usize = FLAGS + NUM_OPCODES;
+pub const R0: usize = OPERAND + 1;
+pub const R1: usize = R0 + 1;
+/// Inverse of `r0`, or zero when `r0` is zero; this column lets the AIR tell whether `r0` is
+/// zero with constraints of low degree.
+pub const R0_INV: usize = R1 + 1;
+pub const TRACE_WIDTH: usize = R0_INV + 1;
+
+/// Columns which claims can name.
+pub const REGISTERS: [(&str, usize); 3] = [("pc", PC), ("r0", R0), ("r1", R1)];
+
+const ADD: usize = FLAGS;
+const MUL: usize = FLAGS + 1;
+const LOAD: usize = FLAGS + 2;
+const JZ: usize = FLAGS + 3;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct VmInputs<B: ExampleField> {
+    pub program: Vec<Instruction>,
+    pub registers: [B; 2],
+    pub result: [B; 2],
+    pub final_pc: B,
+    /// Values of the registers at steps other than the first and the last.
+    pub claims: Vec<ClaimedValue<B>>,
+}
+
+impl<B: ExampleField> Serializable for VmInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.program.len() as u32);
+        target.write(&self.program[..]);
+        target.write(&self.registers[..]);
+        target.write(&self.result[..]);
+        target.write(self.final_pc);
+        write_claimed_values(&self.claims, target);
+    }
+}
+
+impl<B: ExampleField> Deserializable for VmInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program_length = source.read_u32()? as usize;
+        // the decoding polynomials are interpolated over the instructions of the program
+        if program_length == 0 {
+            return Err(DeserializationError::InvalidValue(
+                "the program has no instructions".into(),
+            ));
+        }
+        Ok(VmInputs {
+            program: Instruction::read_batch_from(source, program_length)?,
+            registers: [B::read_from(source)?, B::read_from(source)?],
+            result: [B::read_from(source)?, B::read_from(source)?],
+            final_pc: B::read_from(source)?,
+            claims: read_claimed_values(source, &REGISTERS.map(|(_, column)| column))?,
+        })
+    }
+}
+
+// VM AIR
+// ================================================================================================
+
+pub struct VmAir<B: ExampleField> {
+    context: AirContext<B>,
+    program_length: usize,
+    flag_polys: Vec<Vec<B>>,
+    operand_poly: Vec<B>,
+    registers: [B; 2],
+    result: [B; 2],
+    final_pc: B,
+    claims: Vec<ClaimedValue<B>>,
+}
+
+impl<B: ExampleField> Air for VmAir<B> {
+    type BaseField = B;
+    type PublicInputs = VmInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: VmInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let program = &pub_inputs.program;
+
+        // interpolate the flags and the operands of the program over instruction addresses, so
+        // that the instruction at the program counter can be decoded by evaluating polynomials;
+        // polynom::interpolate() cannot handle x = 0, so the polynomials are interpolated over
+        // pc + 1 instead
+        let addresses = (1..=program.len() as u64).map(B::from).collect::<Vec<_>>();
+        let flag_polys = (0..NUM_OPCODES)
+            .map(|opcode| {
+                let flags = program
+                    .iter()
+                    .map(|instruction| B::from((instruction.opcode() == opcode) as u8))
+                    .collect::<Vec<_>>();
+                polynom::interpolate(&addresses, &flags, false)
+            })
+            .collect();
+        let operands = program
+            .iter()
+            .map(|instruction| instruction.operand())
+            .collect::<Vec<_>>();
+        let operand_poly = polynom::interpolate(&addresses, &operands, false);
+
+        // decoding constraints evaluate polynomials of degree up to program_length - 1 at pc,
+        // and the address range check is a product with one term per address; they are followed
+        // by the constraints on pc, r0, r1, and the two constraints on R0_INV
+        let program_length = program.len();
+        let decoding_degree = usize::max(program_length - 1, 1);
+        let mut degrees = transition_degrees(&[decoding_degree; NUM_OPCODES + 1]);
+        degrees.append(&mut transition_degrees(&[program_length, 4, 3, 2, 3, 3]));
+
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            program_length,
+            flag_polys,
+            operand_poly,
+            registers: pub_inputs.registers,
+            result: pub_inputs.result,
+            final_pc: pub_inputs.final_pc,
+            claims: pub_inputs.claims,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let pc = current[PC];
+        let operand = current[OPERAND];
+        let [r0, r1] = [current[R0], current[R1]];
+
+        // --- instruction decoding ---------------------------------------------------------------
+
+        // flags and the operand are those of the instruction at pc; since the flags of every
+        // instruction are binary and exactly one of them is set, the same holds for the flags in
+        // the trace
+        let x = pc + E::ONE;
+        for (i, poly) in self.flag_polys.iter().enumerate() {
+            result[i] = are_equal(current[FLAGS + i], polynom::eval(poly, x));
+        }
+        result[NUM_OPCODES] = are_equal(operand, polynom::eval(&self.operand_poly, x));
+
+        // pc is the address of one of the instructions of the program; without this, a value of
+        // pc outside of the program could decode into arbitrary flags
+        result[NUM_OPCODES + 1] = (0..self.program_length as u64)
+            .fold(E::ONE, |product, address| product * (pc - E::from(address)));
+
+        // --- state transition -------------------------------------------------------------------
+        let i = NUM_OPCODES + 2;
+
+        // r0_is_zero is one when r0 is zero, and zero otherwise; this is guaranteed by the last
+        // two constraints below
+        let r0_is_zero = E::ONE - r0 * current[R0_INV];
+
+        // JZ moves pc to the operand when r0 is zero; all other instructions increment pc
+        let next_pc = pc + E::ONE;
+        result[i] =
+            are_equal(next[PC], next_pc) - and(current[JZ], r0_is_zero) * (operand - next_pc);
+
+        // ADD and MUL update r0 from both registers, LOAD shifts r0 into r1 and sets r0 to the
+        // operand, and JZ leaves the registers unchanged
+        result.agg_constraint(i + 1, current[ADD], are_equal(next[R0], r0 + r1));
+        result.agg_constraint(i + 1, current[MUL], are_equal(next[R0], r0 * r1));
+        result.agg_constraint(i + 1, current[LOAD], are_equal(next[R0], operand));
+        result.agg_constraint(i + 1, current[JZ], are_equal(next[R0], r0));
+        result.agg_constraint(i + 2, not(current[LOAD]), are_equal(next[R1], r1));
+        result.agg_constraint(i + 2, current[LOAD], are_equal(next[R1], r0));
+
+        // r0 * r0_is_zero = 0 forces R0_INV to be the inverse of r0 whenever r0 is not zero, and
+        // R0_INV * r0_is_zero = 0 forces it to be zero otherwise
+        result[i + 3] = r0 * r0_is_zero;
+        result[i + 4] = current[R0_INV] * r0_is_zero;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the machine starts at the first instruction with the initial registers, ends with the
+        // final registers and program counter, and passes through the claimed values in between
+        let trace_length = self.trace_length();
+        let mut assertions = vec![
+            first_row_equals(PC, B::ZERO),
+            first_row_equals(R0, self.registers[0]),
+            first_row_equals(R1, self.registers[1]),
+            last_row_equals(PC, trace_length, self.final_pc),
+            last_row_equals(R0, trace_length, self.result[0]),
+            last_row_equals(R1, trace_length, self.result[1]),
+        ];
+        assertions.append(&mut claim_assertions(&self.claims, trace_length));
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
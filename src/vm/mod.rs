@@ -0,0 +1,265 @@
+#[cfg(feature = "std")]
+use crate::{
+    claims::{self, ClaimedValue},
+    config::VmConfig,
+    describe::AirMetadata,
+    emulator::{EmulatedColumn, Emulator},
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{VmAir, VmInputs, FLAGS, OPERAND, PC, R0, R1, REGISTERS};
+
+mod program;
+pub use program::Instruction;
+#[cfg(feature = "std")]
+use program::State;
+use program::NUM_OPCODES;
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::VmProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Names of the flag columns, ordered by [Instruction::opcode()].
+const FLAG_NAMES: [&str; NUM_OPCODES] = ["ADD flag", "MUL flag", "LOAD flag", "JZ flag"];
+
+// VM EXAMPLE
+// ================================================================================================
+
+/// Proves that running a public program on a toy register machine for `trace_length - 1` steps
+/// takes the machine from the initial to the final state.
+///
+/// Every row of the execution trace holds the program counter, one flag per instruction, the
+/// immediate operand of the instruction, and the two registers of the machine. The AIR checks
+/// that the flags and the operand of each row are those of the instruction at the row's program
+/// counter, and that the registers and the program counter are updated as the instruction
+/// prescribes. Programs have no explicit halt instruction; a program ends in a loop which jumps
+/// to itself, such as `JZ <own address>` executed while `r0` is zero.
+#[cfg(feature = "std")]
+pub struct VmExample<B: ExampleField> {
+    program: Vec<Instruction>,
+    trace_length: usize,
+    initial: State<B>,
+    result: State<B>,
+    claims: Vec<ClaimedValue<B>>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> VmExample<B> {
+    pub fn new(config: &VmConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let program = config.program.clone();
+        let trace_length = config.trace_length;
+        let initial = State {
+            pc: 0,
+            registers: [B::from(config.registers[0]), B::from(config.registers[1])],
+        };
+
+        // compute result
+        let now = Instant::now();
+        let result = (1..trace_length).fold(initial, |state, _| state.step(&program));
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            program,
+            trace_length,
+            initial,
+            result,
+            claims: claims::to_claimed_values(&config.claims, &REGISTERS),
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &VmConfig) -> Result<(), String> {
+        validate::check_trace_length("vm.trace_length", config.trace_length)?;
+        validate_program(&config.program)?;
+        // the registers are asserted in the first and the last row
+        let last_step = config.trace_length - 1;
+        claims::check_claims(
+            "vm.claims",
+            &config.claims,
+            &REGISTERS,
+            config.trace_length,
+            |_, step| step == 0 || step == last_step,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for VmExample<B> {
+    type Air = VmAir<B>;
+    type Prover = VmProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        VmProver::build_trace(&self.program, self.initial.registers, self.trace_length)
+    }
+
+    fn emulator(&self) -> Option<Box<dyn Emulator<B> + '_>> {
+        Some(Box::new(VmEmulator {
+            program: &self.program,
+            state: self.initial,
+        }))
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = VmConfig::default();
+        let program = config
+            .program
+            .iter()
+            .map(|instruction| instruction.to_string());
+        ExampleDescriptor::new(
+            "vm",
+            "a toy register machine running a program given in the config",
+        )
+        .with_default("program", program.collect::<Vec<_>>())
+        .with_default("registers", config.registers)
+        .with_default("trace_length", config.trace_length)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![
+                ("PC", 1),
+                ("FLAGS", NUM_OPCODES),
+                ("OPERAND", 1),
+                ("R0", 1),
+                ("R1", 1),
+                ("R0_INV", 1),
+            ],
+            constraints: vec![
+                ("flag decoding", NUM_OPCODES),
+                ("operand decoding", 1),
+                ("pc in program", 1),
+                ("pc update", 1),
+                ("r0 update", 1),
+                ("r1 update", 1),
+                ("r0 inverse", 2),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> VmProver<B> {
+        VmProver::new(self.program.clone(), self.claims.clone(), options)
+    }
+
+    fn pub_inputs(&self) -> VmInputs<B> {
+        VmInputs {
+            program: self.program.clone(),
+            registers: self.initial.registers,
+            result: self.result.registers,
+            final_pc: B::from(self.result.pc as u64),
+            claims: self.claims.clone(),
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> VmInputs<B> {
+        VmInputs {
+            result: [self.result.registers[0] + B::ONE, self.result.registers[1]],
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// EMULATOR
+// ================================================================================================
+
+/// Runs the program on the register machine and predicts the program counter, the decoded
+/// instruction, and the registers in every row of the trace.
+#[cfg(feature = "std")]
+struct VmEmulator<'a, B: ExampleField> {
+    program: &'a [Instruction],
+    state: State<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Emulator<B> for VmEmulator<'_, B> {
+    fn columns(&self) -> Vec<EmulatedColumn> {
+        let mut columns = vec![EmulatedColumn {
+            name: "pc",
+            index: PC,
+        }];
+        columns.extend(
+            FLAG_NAMES
+                .iter()
+                .enumerate()
+                .map(|(opcode, &name)| EmulatedColumn {
+                    name,
+                    index: FLAGS + opcode,
+                }),
+        );
+        columns.extend([
+            EmulatedColumn {
+                name: "operand",
+                index: OPERAND,
+            },
+            EmulatedColumn {
+                name: "r0",
+                index: R0,
+            },
+            EmulatedColumn {
+                name: "r1",
+                index: R1,
+            },
+        ]);
+        columns
+    }
+
+    fn state(&self) -> Vec<B> {
+        let instruction = self.state.instruction(self.program);
+        let mut state = vec![B::from(self.state.pc as u64)];
+        state
+            .extend((0..NUM_OPCODES).map(|opcode| B::from((opcode == instruction.opcode()) as u8)));
+        state.push(instruction.operand());
+        state.extend(self.state.registers);
+        state
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.step(self.program);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure that the program is not empty and that all jump targets are within the program.
+#[cfg(feature = "std")]
+fn validate_program(program: &[Instruction]) -> Result<(), String> {
+    if program.is_empty() {
+        return Err(
+            "program must contain at least one instruction, but vm.program is empty".to_string(),
+        );
+    }
+    for (address, instruction) in program.iter().enumerate() {
+        if let Instruction::Jz(target) = instruction {
+            if *target >= program.len() {
+                return Err(format!(
+                    "instruction {} jumps to address {} which is outside of the program; \
+                     addresses of vm.program range from 0 to {}",
+                    address,
+                    target,
+                    program.len() - 1
+                ));
+            }
+        }
+    }
+    Ok(())
+}
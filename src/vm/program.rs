@@ -0,0 +1,173 @@
+use crate::field::ExampleField;
+use core::{convert::TryFrom, fmt};
+use winter_utils::{collections::Vec, string::String};
+use winterfell::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// INSTRUCTIONS
+// ================================================================================================
+
+/// Number of distinct instructions; each of them has its own flag column in the trace.
+pub const NUM_OPCODES: usize = 4;
+
+/// An instruction of the toy register machine.
+///
+/// The machine has two registers, `r0` and `r1`, and a program counter `pc`. In config files,
+/// instructions are written as `"ADD"`, `"MUL"`, `"LOAD <value>"`, and `"JZ <target>"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Deserialize),
+    serde(try_from = "String")
+)]
+pub enum Instruction {
+    /// Sets `r0` to `r0 + r1`.
+    Add,
+    /// Sets `r0` to `r0 * r1`.
+    Mul,
+    /// Moves `r0` into `r1` and sets `r0` to the specified value; negative values are reduced
+    /// modulo the field modulus.
+    Load(i64),
+    /// Jumps to the specified address if `r0` is zero.
+    Jz(usize),
+}
+
+impl Instruction {
+    /// Returns the index of the flag column which is set when this instruction is executed.
+    pub fn opcode(&self) -> usize {
+        match self {
+            Instruction::Add => 0,
+            Instruction::Mul => 1,
+            Instruction::Load(_) => 2,
+            Instruction::Jz(_) => 3,
+        }
+    }
+
+    /// Returns the immediate operand of this instruction as a field element; instructions
+    /// without an operand have an operand of zero.
+    pub fn operand<B: ExampleField>(&self) -> B {
+        match *self {
+            Instruction::Add | Instruction::Mul => B::ZERO,
+            Instruction::Load(value) if value < 0 => -B::from(value.unsigned_abs()),
+            Instruction::Load(value) => B::from(value as u64),
+            Instruction::Jz(target) => B::from(target as u64),
+        }
+    }
+}
+
+impl Serializable for Instruction {
+    /// Writes the opcode followed by the operand, if the instruction has one.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.opcode() as u8);
+        match *self {
+            Instruction::Add | Instruction::Mul => (),
+            Instruction::Load(value) => target.write_u64(value as u64),
+            Instruction::Jz(address) => target.write_u64(address as u64),
+        }
+    }
+}
+
+impl Deserializable for Instruction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Instruction::Add),
+            1 => Ok(Instruction::Mul),
+            2 => Ok(Instruction::Load(source.read_u64()? as i64)),
+            3 => Ok(Instruction::Jz(source.read_u64()? as usize)),
+            opcode => Err(DeserializationError::InvalidValue(format!(
+                "unknown opcode {}",
+                opcode
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for Instruction {
+    type Error = String;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        let parts = source.split_whitespace().collect::<Vec<_>>();
+        let instruction = match parts.as_slice() {
+            ["ADD"] => Instruction::Add,
+            ["MUL"] => Instruction::Mul,
+            ["LOAD", value] => Instruction::Load(
+                value
+                    .parse()
+                    .map_err(|err| format!("invalid value in '{}': {}", source, err))?,
+            ),
+            ["JZ", target] => Instruction::Jz(
+                target
+                    .parse()
+                    .map_err(|err| format!("invalid jump target in '{}': {}", source, err))?,
+            ),
+            _ => {
+                return Err(format!(
+                    "invalid instruction '{}'; expected ADD, MUL, LOAD <value>, or JZ <target>",
+                    source
+                ))
+            }
+        };
+        Ok(instruction)
+    }
+}
+
+impl fmt::Display for Instruction {
+    /// Writes the instruction as it is written in config files.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Add => write!(f, "ADD"),
+            Instruction::Mul => write!(f, "MUL"),
+            Instruction::Load(value) => write!(f, "LOAD {}", value),
+            Instruction::Jz(target) => write!(f, "JZ {}", target),
+        }
+    }
+}
+
+// MACHINE STATE
+// ================================================================================================
+
+/// State of the register machine between two instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State<B: ExampleField> {
+    pub pc: usize,
+    pub registers: [B; 2],
+}
+
+impl<B: ExampleField> State<B> {
+    /// Returns the instruction at the current `pc`.
+    ///
+    /// # Panics
+    /// Panics if `pc` points past the end of the program.
+    pub fn instruction(&self, program: &[Instruction]) -> Instruction {
+        *program.get(self.pc).unwrap_or_else(|| {
+            panic!(
+                "program counter {} points past the end of a program of {} instructions",
+                self.pc,
+                program.len()
+            )
+        })
+    }
+
+    /// Returns the state of the machine after executing the instruction at the current `pc`.
+    pub fn step(&self, program: &[Instruction]) -> Self {
+        let [r0, r1] = self.registers;
+        let instruction = self.instruction(program);
+        match instruction {
+            Instruction::Add => Self {
+                pc: self.pc + 1,
+                registers: [r0 + r1, r1],
+            },
+            Instruction::Mul => Self {
+                pc: self.pc + 1,
+                registers: [r0 * r1, r1],
+            },
+            Instruction::Load(_) => Self {
+                pc: self.pc + 1,
+                registers: [instruction.operand(), r0],
+            },
+            Instruction::Jz(target) => Self {
+                pc: if r0 == B::ZERO { target } else { self.pc + 1 },
+                registers: self.registers,
+            },
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::{
+    air::{FLAGS, OPERAND, PC, R0, R0_INV, R1, TRACE_WIDTH},
+    Instruction, State, VmAir, VmInputs, NUM_OPCODES,
+};
+use crate::{claims::ClaimedValue, field::ExampleField};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct VmProver<B: ExampleField> {
+    program: Vec<Instruction>,
+    claims: Vec<ClaimedValue<B>>,
+    options: ProofOptions,
+}
+
+impl<B: ExampleField> VmProver<B> {
+    pub fn new(
+        program: Vec<Instruction>,
+        claims: Vec<ClaimedValue<B>>,
+        options: ProofOptions,
+    ) -> Self {
+        Self {
+            program,
+            claims,
+            options,
+        }
+    }
+
+    pub fn build_trace(
+        program: &[Instruction],
+        registers: [B; 2],
+        trace_length: usize,
+    ) -> TraceTable<B> {
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+        let mut state = State { pc: 0, registers };
+
+        for step in 0..trace_length {
+            // record the machine state together with the decoded instruction
+            let instruction = state.instruction(program);
+            let [r0, r1] = state.registers;
+            trace.set(PC, step, B::from(state.pc as u64));
+            for opcode in 0..NUM_OPCODES {
+                trace.set(
+                    FLAGS + opcode,
+                    step,
+                    B::from((instruction.opcode() == opcode) as u8),
+                );
+            }
+            trace.set(OPERAND, step, instruction.operand());
+            trace.set(R0, step, r0);
+            trace.set(R1, step, r1);
+            // f62 may represent zero as the modulus, which its inv() does not terminate on
+            let r0_inv = if r0 == B::ZERO { B::ZERO } else { r0.inv() };
+            trace.set(R0_INV, step, r0_inv);
+
+            // the state after the last step is not part of the trace
+            if step < trace_length - 1 {
+                state = state.step(program);
+            }
+        }
+
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for VmProver<B> {
+    type BaseField = B;
+    type Air = VmAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> VmInputs<B> {
+        let last_step = trace.length() - 1;
+        VmInputs {
+            program: self.program.clone(),
+            registers: [trace.get(R0, 0), trace.get(R1, 0)],
+            result: [trace.get(R0, last_step), trace.get(R1, last_step)],
+            final_pc: trace.get(PC, last_step),
+            claims: self.claims.clone(),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
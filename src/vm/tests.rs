@@ -0,0 +1,259 @@
+use super::{
+    air::{VmInputs, R0, R0_INV},
+    Instruction, VmExample,
+};
+use crate::{
+    claims::{apply_claims, Claim},
+    config::{ProofOptionsConfig, RunConfig, VmConfig},
+    emulator,
+    error::ExampleError,
+    example::Example,
+    run_example, tamper,
+    verify::verify_proof,
+    witness::apply_witness,
+};
+use std::{convert::TryFrom, env, fs, process};
+use winter_utils::SliceReader;
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Deserializable, FieldExtension, HashFunction, ProofOptions, Prover, Serializable, Trace,
+};
+
+#[test]
+fn vm_inputs_without_program_are_rejected() {
+    let mut inputs = build_example().pub_inputs();
+    inputs.program.clear();
+    let bytes = inputs.to_bytes();
+    assert!(VmInputs::<BaseElement>::read_from(&mut SliceReader::new(&bytes)).is_err());
+}
+
+#[test]
+fn vm_program_without_mul_verifies() {
+    let example = build_countdown_example();
+    let proof = example
+        .prover(build_options())
+        .prove(example.build_trace())
+        .unwrap();
+    assert!(
+        winterfell::verify::<<VmExample<BaseElement> as Example>::Air>(proof, example.pub_inputs())
+            .is_ok()
+    );
+}
+
+#[test]
+fn vm_inverse_of_zero_register_is_enforced() {
+    // once the program has halted r0 is zero, so its inverse column must be zero as well
+    let example = build_countdown_example();
+    let verdict = tamper::corrupt_trace_cell(&example, build_options(), R0_INV, 60);
+    assert!(verdict.is_rejected(), "{}", verdict);
+}
+
+#[test]
+fn vm_instructions_are_parsed() {
+    let parse = |source: &str| Instruction::try_from(source.to_string());
+    assert_eq!(Ok(Instruction::Add), parse("ADD"));
+    assert_eq!(Ok(Instruction::Mul), parse("MUL"));
+    assert_eq!(Ok(Instruction::Load(-1)), parse("LOAD -1"));
+    assert_eq!(Ok(Instruction::Jz(3)), parse("JZ  3"));
+    assert!(parse("JMP 3").is_err());
+    assert!(parse("LOAD x").is_err());
+    assert!(parse("JZ -1").is_err());
+    assert!(parse("ADD 1").is_err());
+}
+
+#[test]
+fn vm_witness_file_replaces_program() {
+    let path = env::temp_dir().join(format!("stark-examples-vm-witness-{}.json", process::id()));
+    fs::write(
+        &path,
+        r#"{ "program": ["LOAD -1", "ADD", "JZ 3", "LOAD 0", "JZ 3"], "registers": [0, 5] }"#,
+    )
+    .unwrap();
+
+    let mut config = RunConfig {
+        example: "vm".to_string(),
+        vm: VmConfig {
+            trace_length: 64,
+            ..VmConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            blowup_factor: Some(8),
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    apply_witness(&mut config, &path).unwrap();
+    assert_eq!(5, config.vm.program.len());
+    assert_eq!(Instruction::Jz(3), config.vm.program[4]);
+    assert_eq!([0, 5], config.vm.registers);
+    assert!(run_example(&config).unwrap().is_verified());
+
+    config.example = "vdf".to_string();
+    assert!(apply_witness(&mut config, &path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn vm_blowup_factor_below_the_constraint_degree_is_refused() {
+    let mut config = RunConfig {
+        example: "vm".to_string(),
+        vm: VmConfig {
+            trace_length: 64,
+            ..VmConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            blowup_factor: Some(4),
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    // the program has 8 instructions, so its address range check is a constraint of degree 8
+    match run_example(&config) {
+        Err(ExampleError::Config(message)) => assert!(
+            message.contains("degree up to 8") && message.contains("raise blowup_factor to 8"),
+            "{}",
+            message
+        ),
+        other => panic!("expected a config error, got {:?}", other.map(|_| ())),
+    }
+
+    config.proof_options.blowup_factor = Some(8);
+    assert!(run_example(&config).unwrap().is_verified());
+}
+
+#[test]
+fn vm_claims_file_adds_assertions() {
+    let mut config = RunConfig {
+        example: "vm".to_string(),
+        vm: VmConfig {
+            trace_length: 64,
+            ..VmConfig::default()
+        },
+        proof_options: ProofOptionsConfig {
+            blowup_factor: Some(8),
+            grinding_factor: Some(0),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    apply_claims(&mut config, "configs/vm.claims.json".as_ref()).unwrap();
+    assert_eq!(3, config.vm.claims.len());
+    let report = run_example(&config).unwrap();
+    assert!(report.is_verified());
+    assert_eq!(
+        Ok(()),
+        verify_proof("vm", &report.proof_bytes, &report.pub_inputs_bytes)
+    );
+
+    // the same AIR proves another statement about the same run, and rejects a false one
+    config.vm.claims[0].value = 155;
+    assert!(!run_example(&config).unwrap().is_verified());
+    config.vm.claims[0] = claim("r0", 5, 155);
+    assert!(run_example(&config).unwrap().is_verified());
+
+    config.example = "vdf".to_string();
+    let err = apply_claims(&mut config, "configs/vm.claims.json".as_ref()).unwrap_err();
+    assert_eq!("example 'vdf' does not accept claims", err);
+}
+
+#[test]
+fn vm_invalid_claims_are_rejected() {
+    let validate = |claims| {
+        VmExample::<BaseElement>::validate(&VmConfig {
+            trace_length: 64,
+            claims,
+            ..VmConfig::default()
+        })
+        .unwrap_err()
+    };
+    assert_eq!(
+        "vm.claims[0] names register 'r2', but only pc, r0, r1 can be claimed",
+        validate(vec![claim("r2", 3, 0)])
+    );
+    assert_eq!(
+        "vm.claims[1] is at step 64, outside of a trace of 64 steps",
+        validate(vec![claim("r0", 3, 0), claim("r1", 64, 0)])
+    );
+    assert_eq!(
+        "vm.claims[0] claims pc at step 63, which the AIR asserts already",
+        validate(vec![claim("pc", 63, 5)])
+    );
+    assert_eq!(
+        "vm.claims[1] claims r0 at step 3 a second time",
+        validate(vec![claim("r0", 3, 0), claim("r0", 3, 1)])
+    );
+}
+
+#[test]
+fn vm_trace_matches_emulator() {
+    let check = emulator::check_trace(&build_countdown_example()).unwrap();
+    assert!(check.is_match(), "{}", check);
+}
+
+#[test]
+fn vm_emulator_reports_first_divergence() {
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let actual = trace.get(R0, 17) + BaseElement::ONE;
+    trace.set(R0, 17, actual);
+    trace.set(R0, 40, BaseElement::ZERO);
+
+    let check = emulator::compare(&trace, &mut *example.emulator().unwrap());
+    let divergence = check.divergence.unwrap();
+    assert_eq!(17, divergence.step);
+    assert_eq!("r0", divergence.column.name);
+    assert_eq!(actual, divergence.actual);
+    assert_eq!(actual - BaseElement::ONE, divergence.expected);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_example() -> VmExample<BaseElement> {
+    VmExample::new(&VmConfig {
+        trace_length: 128,
+        ..VmConfig::default()
+    })
+    .unwrap()
+}
+
+/// Builds an example which counts r1 down to zero without executing MUL, and then stays at the
+/// last instruction with r0 = 0 for the rest of the trace.
+fn build_countdown_example() -> VmExample<BaseElement> {
+    VmExample::new(&VmConfig {
+        program: vec![
+            Instruction::Add,
+            Instruction::Load(-1),
+            Instruction::Add,
+            Instruction::Jz(3),
+            Instruction::Load(0),
+            Instruction::Jz(0),
+        ],
+        registers: [0, 5],
+        trace_length: 64,
+        ..VmConfig::default()
+    })
+    .unwrap()
+}
+
+fn claim(register: &str, step: usize, value: u64) -> Claim {
+    Claim {
+        register: register.to_string(),
+        step,
+        value,
+    }
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
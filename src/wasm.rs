@@ -0,0 +1,194 @@
+//! Bindings for verifying proofs of the examples in the browser.
+//!
+//! Proofs and public inputs are generated natively with `run --save-proof <file> --save-inputs
+//! <file>`, and are passed to these functions as byte arrays. Only verification is supported:
+//! building traces and proving measure time with [std::time::Instant], which is not available
+//! on `wasm32-unknown-unknown`.
+
+use crate::verify::verify_proof;
+use wasm_bindgen::prelude::*;
+
+/// Verifies a proof of the VDF example.
+#[wasm_bindgen]
+pub fn verify_vdf(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("vdf", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Rescue hash chain example.
+#[wasm_bindgen]
+pub fn verify_rescue(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("rescue", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Poseidon hash chain example.
+#[wasm_bindgen]
+pub fn verify_poseidon(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("poseidon", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the counter example.
+#[wasm_bindgen]
+pub fn verify_counter(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("counter", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Lamport signature aggregation example.
+#[wasm_bindgen]
+pub fn verify_lamport(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("lamport", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the exponentiation example.
+#[wasm_bindgen]
+pub fn verify_exp(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("exp", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the sum and inner product example.
+#[wasm_bindgen]
+pub fn verify_sum(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("sum", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the register machine example.
+#[wasm_bindgen]
+pub fn verify_vm(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("vm", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Fibonacci example.
+#[wasm_bindgen]
+pub fn verify_fib(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("fib", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the greatest common divisor example.
+#[wasm_bindgen]
+pub fn verify_gcd(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("gcd", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the quadratic map example.
+#[wasm_bindgen]
+pub fn verify_quad(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("quad", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the is-zero gadget example.
+#[wasm_bindgen]
+pub fn verify_iszero(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("iszero", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the read-only memory example.
+#[wasm_bindgen]
+pub fn verify_rom(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("rom", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the square root example.
+#[wasm_bindgen]
+pub fn verify_sqrt(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("sqrt", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Merkle root example.
+#[wasm_bindgen]
+pub fn verify_merkle(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("merkle", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the bitwise operations example.
+#[wasm_bindgen]
+pub fn verify_bitwise(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("bitwise", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the less-than comparison example.
+#[wasm_bindgen]
+pub fn verify_compare(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("compare", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Fibonacci modulo a small modulus example.
+#[wasm_bindgen]
+pub fn verify_fibmod(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("fibmod", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the sorting example.
+#[wasm_bindgen]
+pub fn verify_sort(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("sort", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of a single chunk of the chunked Fibonacci example.
+#[wasm_bindgen]
+pub fn verify_fibchunk(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("fibchunk", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the fixed-base exponentiation example.
+#[wasm_bindgen]
+pub fn verify_fixedexp(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("fixedexp", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the modular exponentiation example.
+#[wasm_bindgen]
+pub fn verify_modexp(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("modexp", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the character count example.
+#[wasm_bindgen]
+pub fn verify_bytecount(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("bytecount", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the running sum example.
+#[wasm_bindgen]
+pub fn verify_runsum(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("runsum", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the polynomial evaluation example.
+#[wasm_bindgen]
+pub fn verify_horner(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("horner", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the wrapping counter example.
+#[wasm_bindgen]
+pub fn verify_wrap(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("wrap", proof_bytes, pub_inputs).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the sliding window sum example.
+#[wasm_bindgen]
+pub fn verify_window(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("window", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Verifies a proof of the Fibonacci divisibility example.
+#[wasm_bindgen]
+pub fn verify_fibdiv(proof_bytes: &[u8], pub_inputs: &[u8]) -> Result<(), JsValue> {
+    verify_proof("fibdiv", proof_bytes, pub_inputs)
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+}
@@ -0,0 +1,142 @@
+use super::window_sum;
+use crate::{
+    air_utils::{are_equal, first_row_equals, last_row_equals, transition_degrees},
+    field::ExampleField,
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Trace column layout. `TERM` holds the term of the sequence computed on a row, and the column
+/// `LAG + j - 1` holds the term `j` steps before it, for `j` from one to `width - 1`; the trace
+/// is as wide as the window of terms a step reads.
+pub const TERM: usize = 0;
+pub const LAG: usize = 1;
+
+/// Bounds of the number of terms each term is the sum of.
+pub const MIN_WIDTH: usize = 2;
+pub const MAX_WIDTH: usize = 16;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct WindowInputs<B: ExampleField> {
+    /// Number of terms each term is the sum of; also the width of the trace.
+    pub width: usize,
+    /// First term of the sequence; the terms before it are zero.
+    pub start: B,
+    /// Term on the last row.
+    pub result: B,
+}
+
+impl<B: ExampleField> Serializable for WindowInputs<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.width as u8);
+        target.write(self.start);
+        target.write(self.result);
+    }
+}
+
+impl<B: ExampleField> Deserializable for WindowInputs<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let width = source.read_u8()? as usize;
+        if !(MIN_WIDTH..=MAX_WIDTH).contains(&width) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid window width {}",
+                width
+            )));
+        }
+        Ok(WindowInputs {
+            width,
+            start: B::read_from(source)?,
+            result: B::read_from(source)?,
+        })
+    }
+}
+
+// WINDOW AIR
+// ================================================================================================
+
+/// AIR of a sequence in which every term is the sum of the `width` terms before it, with one
+/// term per row.
+///
+/// A transition constraint of winterfell 0.3 sees two rows of the trace, the current one and
+/// the next, so a constraint can only relate a term to the one right before it. A term which
+/// depends on terms further back is constrained by carrying them along: besides the term of
+/// the step, every row holds copies of the `width - 1` terms before it, each shifted one column
+/// to the right of where it was on the row before. The constraints of a step are:
+///
+/// * `term' = term + lag_1 + ... + lag_{width-1}`, the recurrence, which now only reads the
+///   current row;
+/// * `lag_1' = term` and `lag_j' = lag_{j-1}` for `j > 1`, which shift the window by one term.
+///
+/// The shift constraints are what make the copies trustworthy: without them, a prover could put
+/// any values into the lag columns and the recurrence would hold on every row. With them, the
+/// value in `lag_j` is forced to be the term `j` rows up, so each row is a frame of `width` rows
+/// of the one-column sequence. The first row is fixed by assertions on all columns, with zeros
+/// standing in for the terms before the start, and the term on the last row is pinned to the
+/// public result.
+///
+/// The cost of the technique is one column per extra row of reach, which the trace pays on every
+/// row whether or not the step uses it. Later winterfell versions let an AIR read a wider frame
+/// directly, but the shifted columns work with any prover which sees consecutive rows.
+pub struct WindowAir<B: ExampleField> {
+    context: AirContext<B>,
+    width: usize,
+    start: B,
+    result: B,
+}
+
+impl<B: ExampleField> Air for WindowAir<B> {
+    type BaseField = B;
+    type PublicInputs = WindowInputs<B>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: WindowInputs<B>, options: ProofOptions) -> Self {
+        assert_eq!(pub_inputs.width, trace_info.width());
+        let degrees = transition_degrees(&vec![1; pub_inputs.width]);
+        Self {
+            context: AirContext::new(trace_info, degrees, options),
+            width: pub_inputs.width,
+            start: pub_inputs.start,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // the next term is the sum of the window held by the current row
+        result[TERM] = are_equal(next[TERM], window_sum(current));
+
+        // every term moves one column to the right, and the oldest one drops out
+        for column in LAG..self.width {
+            result[column] = are_equal(next[column], current[column - 1]);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the sequence starts with the public start after zeros, and ends with the public result
+        let mut assertions = vec![first_row_equals(TERM, self.start)];
+        for column in LAG..self.width {
+            assertions.push(first_row_equals(column, B::ZERO));
+        }
+        assertions.push(last_row_equals(TERM, self.trace_length(), self.result));
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::WindowConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::time::Instant;
+use winterfell::math::FieldElement;
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{WindowAir, WindowInputs, MAX_WIDTH, MIN_WIDTH, TERM};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::WindowProver;
+
+#[cfg(test)]
+mod tests;
+
+// WINDOW EXAMPLE
+// ================================================================================================
+
+/// Proves the term after `trace_length - 1` steps of a sequence in which every term is the sum
+/// of the `width` terms before it, started from a public term after zeros.
+///
+/// A width of two gives the Fibonacci sequence, three the tribonacci sequence, and so on. A step
+/// reads terms up to `width` rows back, more than the two rows a transition constraint sees, so
+/// every row carries the earlier terms in columns which shift by one on every step; see
+/// [WindowAir] for the constraints.
+#[cfg(feature = "std")]
+pub struct WindowExample<B: ExampleField> {
+    trace_length: usize,
+    width: usize,
+    start: B,
+    result: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> WindowExample<B> {
+    pub fn new(config: &WindowConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+        let start = B::from(config.start);
+
+        // compute result
+        let now = Instant::now();
+        let result = last_term(config.width, start, config.trace_length);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            width: config.width,
+            start,
+            result,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &WindowConfig) -> Result<(), String> {
+        validate::check_trace_length("window.trace_length", config.trace_length)?;
+        if !(MIN_WIDTH..=MAX_WIDTH).contains(&config.width) {
+            return Err(format!(
+                "window width must be between {} and {}, but window.width is {}",
+                MIN_WIDTH, MAX_WIDTH, config.width
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for WindowExample<B> {
+    type Air = WindowAir<B>;
+    type Prover = WindowProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        WindowProver::build_trace(self.width, self.start, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = WindowConfig::default();
+        ExampleDescriptor::new(
+            "window",
+            "a sequence of sums of the previous terms, reaching back with shifted columns",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("width", config.width)
+        .with_default("start", config.start)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("TERM", 1), ("LAG", self.width - 1)],
+            constraints: vec![("window sum", 1), ("window shift", self.width - 1)],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> WindowProver<B> {
+        WindowProver::new(options, self.width, self.start)
+    }
+
+    fn pub_inputs(&self) -> WindowInputs<B> {
+        WindowInputs {
+            width: self.width,
+            start: self.start,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> WindowInputs<B> {
+        WindowInputs {
+            result: self.result + B::ONE,
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// SEQUENCE FUNCTIONS
+// ================================================================================================
+
+/// Returns the term on the last of `n` rows of the sequence in which every term is the sum of
+/// the `width` terms before it, starting from `start` after zeros.
+#[cfg(feature = "std")]
+pub fn last_term<B: ExampleField>(width: usize, start: B, n: usize) -> B {
+    let mut window = vec![B::ZERO; width];
+    window[TERM] = start;
+    for _ in 1..n {
+        let next = window_sum(&window);
+        window.rotate_right(1);
+        window[TERM] = next;
+    }
+    window[TERM]
+}
+
+/// Returns the sum of a window of terms, which is the term after them.
+pub fn window_sum<E: FieldElement>(window: &[E]) -> E {
+    window.iter().fold(E::ZERO, |sum, &term| sum + term)
+}
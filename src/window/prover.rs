@@ -0,0 +1,62 @@
+use super::{
+    air::{WindowAir, WindowInputs, TERM},
+    window_sum,
+};
+use crate::field::ExampleField;
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct WindowProver<B: ExampleField> {
+    options: ProofOptions,
+    width: usize,
+    start: B,
+}
+
+impl<B: ExampleField> WindowProver<B> {
+    pub fn new(options: ProofOptions, width: usize, start: B) -> Self {
+        Self {
+            options,
+            width,
+            start,
+        }
+    }
+
+    /// Builds a trace whose rows each hold a term of the sequence followed by the `width - 1`
+    /// terms before it, most recent first; the terms before `start` are zero.
+    pub fn build_trace(width: usize, start: B, trace_length: usize) -> TraceTable<B> {
+        let mut trace = TraceTable::new(width, trace_length);
+        trace.fill(
+            |state| {
+                state.fill(B::ZERO);
+                state[TERM] = start;
+            },
+            |_, state| {
+                // shift the window right by one term, then put the sum of the old window first
+                let next = window_sum(state);
+                state.rotate_right(1);
+                state[TERM] = next;
+            },
+        );
+        trace
+    }
+}
+
+impl<B: ExampleField> Prover for WindowProver<B> {
+    type BaseField = B;
+    type Air = WindowAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> WindowInputs<B> {
+        WindowInputs {
+            width: self.width,
+            start: self.start,
+            result: trace.get(TERM, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,127 @@
+use super::{
+    air::{LAG, TERM},
+    last_term, WindowExample, WindowProver,
+};
+use crate::{
+    config::{RunConfig, WindowConfig},
+    example::Example,
+    run_example, tamper,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn window_trace_holds_the_earlier_terms_on_every_row() {
+    // tribonacci from 1: 1, 1, 2, 4, 7, 13, 24, 44
+    let trace = WindowProver::<BaseElement>::build_trace(3, BaseElement::ONE, 8);
+    let terms = [0u64, 0, 1, 1, 2, 4, 7, 13, 24, 44];
+    for step in 0..8 {
+        for lag in 0..3 {
+            assert_eq!(
+                BaseElement::from(terms[step + 2 - lag]),
+                trace.get(TERM + lag, step),
+                "step {} lag {}",
+                step,
+                lag
+            );
+        }
+    }
+    assert_eq!(BaseElement::from(44u64), last_term(3, BaseElement::ONE, 8));
+    // a width of two is the Fibonacci sequence
+    assert_eq!(BaseElement::from(55u64), last_term(2, BaseElement::ONE, 10));
+}
+
+#[test]
+fn window_swapped_lags_are_rejected() {
+    // swapping two lag columns keeps the sum of the window, so the recurrence holds on every
+    // row, and only the shift constraints tell that the copies are out of order
+    let example = build_example();
+    let mut trace = example.build_trace();
+    let step = 9;
+    let (first, second) = (trace.get(LAG, step), trace.get(LAG + 1, step));
+    assert_ne!(first, second);
+    trace.set(LAG, step, second);
+    trace.set(LAG + 1, step, first);
+
+    let pub_inputs = example.pub_inputs();
+    let proof = WindowProver::new(build_options(), pub_inputs.width, pub_inputs.start)
+        .prove(trace)
+        .unwrap();
+    assert!(
+        winterfell::verify::<<WindowExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn window_statements_differ_by_start() {
+    let config = RunConfig {
+        example: "window".to_string(),
+        window: WindowConfig {
+            trace_length: TRACE_LENGTH,
+            ..WindowConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    assert!(config.clone().set_seed(5).is_err());
+    let first = run_example(&config.statement(1)).unwrap();
+    let second = run_example(&config.statement(2)).unwrap();
+    assert_ne!(first.pub_inputs_bytes, second.pub_inputs_bytes);
+}
+
+#[test]
+fn window_width_out_of_range_is_rejected() {
+    for width in [1, 17] {
+        let config = WindowConfig {
+            width,
+            ..WindowConfig::default()
+        };
+        assert!(WindowExample::<BaseElement>::validate(&config).is_err());
+    }
+}
+
+#[test]
+fn window_corrupted_first_and_last_rows_are_rejected() {
+    let example = build_example();
+    for column in [TERM, LAG, LAG + WIDTH - 2] {
+        for step in [0, TRACE_LENGTH - 1] {
+            let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, step);
+            assert!(
+                verdict.is_rejected(),
+                "column {} step {}: {}",
+                column,
+                step,
+                verdict
+            );
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+const WIDTH: usize = 4;
+
+fn build_example() -> WindowExample<BaseElement> {
+    WindowExample::new(&WindowConfig {
+        trace_length: TRACE_LENGTH,
+        width: WIDTH,
+        ..WindowConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
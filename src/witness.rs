@@ -0,0 +1,141 @@
+//! Inputs of the examples read from witness files.
+//!
+//! By default, every example synthesizes its inputs from the settings in its config. Examples
+//! whose inputs are more than a seed and a size can instead take them from a JSON file passed
+//! with `--witness <file>`; the fields of the file replace the corresponding config settings.
+
+use crate::{config::RunConfig, vm::Instruction};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{fs, path::Path};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Names of the examples which take a witness file.
+pub const WITNESS_EXAMPLES: [&str; 4] = ["exp", "sum", "vm", "gcd"];
+
+// WITNESS TYPES
+// ================================================================================================
+
+/// Witness of the exponentiation example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpWitness {
+    /// Secret exponent.
+    pub exponent: u64,
+}
+
+/// Witness of the sum and inner product example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SumWitness {
+    /// Elements of the vector; the number of elements must be a power of two.
+    pub values: Vec<u64>,
+    /// Weights of the inner product; when omitted, the plain sum of the vector is proven.
+    pub weights: Option<Vec<u64>>,
+}
+
+/// Witness of the register machine example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VmWitness {
+    /// Instructions of the program, such as `"LOAD 5"` or `"JZ 3"`.
+    pub program: Vec<Instruction>,
+    /// Initial values of registers `r0` and `r1`; when omitted, the values from the config
+    /// are kept.
+    pub registers: Option<[u64; 2]>,
+}
+
+/// Witness of the greatest common divisor example.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GcdWitness {
+    /// First input.
+    pub a: u16,
+    /// Second input; must not be zero.
+    pub b: u16,
+}
+
+// LOADING
+// ================================================================================================
+
+/// Reads the witness of the example selected in the config from the specified JSON file, and
+/// replaces the corresponding settings of the example with it.
+///
+/// Returns an error if the file cannot be read or parsed, or if the selected example does not
+/// take a witness file.
+pub fn apply_witness(config: &mut RunConfig, path: &Path) -> Result<(), String> {
+    let source = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read witness {}: {}", path.display(), err))?;
+    apply_witness_source(config, &source, &path.display().to_string())
+}
+
+/// Same as [apply_witness()], but takes the contents of the witness file; `origin` names the
+/// witness in error messages.
+pub fn apply_witness_source(
+    config: &mut RunConfig,
+    source: &str,
+    origin: &str,
+) -> Result<(), String> {
+    match config.example.as_str() {
+        "exp" => {
+            let witness: ExpWitness = parse(source, origin)?;
+            config.exp.exponent = witness.exponent;
+        }
+        "sum" => {
+            let witness: SumWitness = parse(source, origin)?;
+            config.sum.dot_product = witness.weights.is_some();
+            config.sum.values = Some(witness.values);
+            config.sum.weights = witness.weights;
+        }
+        "vm" => {
+            let witness: VmWitness = parse(source, origin)?;
+            config.vm.program = witness.program;
+            if let Some(registers) = witness.registers {
+                config.vm.registers = registers;
+            }
+        }
+        "gcd" => {
+            let witness: GcdWitness = parse(source, origin)?;
+            config.gcd.a = witness.a;
+            config.gcd.b = witness.b;
+        }
+        example => {
+            return Err(format!(
+                "example '{}' does not take a witness file",
+                example
+            ))
+        }
+    }
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn parse<W: DeserializeOwned>(source: &str, origin: &str) -> Result<W, String> {
+    serde_json::from_str(source)
+        .map_err(|err| format!("failed to parse witness {}: {}", origin, err))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::apply_witness_source;
+    use crate::config::RunConfig;
+
+    #[test]
+    fn truncated_witness_files_are_rejected_without_panicking() {
+        // the same entry point as the witness fuzz target
+        let source = r#"{ "program": ["LOAD 7", "JZ 0", "ADD"], "registers": [1, 2] }"#;
+        for len in 0..source.len() {
+            let mut config = RunConfig {
+                example: "vm".to_string(),
+                ..RunConfig::default()
+            };
+            assert!(apply_witness_source(&mut config, &source[..len], "input").is_err());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    air_utils::{
+        are_equal, combine_bits, first_row_equals, is_binary, last_row_equals, transition_degrees,
+        EvaluationResult,
+    },
+    field::ExampleField,
+    gadgets::{to_bits, wrapping_add},
+};
+use winter_utils::collections::Vec;
+use winterfell::{
+    math::FieldElement, Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits of the counter.
+pub const COUNTER_BITS: usize = 8;
+
+/// Trace column layout. `VALUE` holds the counter, `FLAG` is one where the increment which
+/// produced the counter of a row wrapped around, and the columns from `BITS` on hold the bits
+/// of the counter, least significant bit first.
+pub const VALUE: usize = 0;
+pub const FLAG: usize = 1;
+pub const BITS: usize = 2;
+pub const TRACE_WIDTH: usize = BITS + COUNTER_BITS;
+
+/// Indices of the transition constraints: one per bit, then the constraints on the flag, on
+/// the decomposition of the counter, and on the increment.
+const FLAG_IS_BINARY: usize = COUNTER_BITS;
+const DECOMPOSITION: usize = FLAG_IS_BINARY + 1;
+const INCREMENT: usize = DECOMPOSITION + 1;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct WrapInputs {
+    /// Value of the counter on the first row.
+    pub start: u8,
+    /// Amount added to the counter on every step.
+    pub increment: u8,
+    /// Value of the counter on the last row.
+    pub result: u8,
+}
+
+impl Serializable for WrapInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.start);
+        target.write_u8(self.increment);
+        target.write_u8(self.result);
+    }
+}
+
+impl Deserializable for WrapInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(WrapInputs {
+            start: source.read_u8()?,
+            increment: source.read_u8()?,
+            result: source.read_u8()?,
+        })
+    }
+}
+
+// WRAP AIR
+// ================================================================================================
+
+/// AIR of an 8-bit counter which is incremented by a public amount on every step and wraps
+/// around at 256.
+///
+/// The constraints of a step are on the row it leads to:
+///
+/// * `b_i' * b_i' = b_i'` for every bit of the counter, and `value' = sum(2^i * b_i')`, which
+///   range-check the counter to `[0, 256)`;
+/// * `flag' * flag' = flag'`, which makes the overflow flag binary;
+/// * `value' = value + increment - 256 * flag'`, the wrapping addition of [crate::gadgets].
+///
+/// The sum `value + increment` is below 512, so it is the new counter either as it is or minus
+/// 256, and the range check leaves the flag only one of the two choices; without it, a flag of
+/// one could be set on any step, and the counter would run off below zero. The constraints are
+/// on the next row so that every row after the first is range-checked, the last one included;
+/// the first row is fixed by assertions on the counter, its bits, and the flag, which is zero
+/// as no increment produced it. The counter on the last row is pinned to the public result.
+pub struct WrapAir<B: ExampleField> {
+    context: AirContext<B>,
+    start: u8,
+    increment: u8,
+    result: u8,
+}
+
+impl<B: ExampleField> Air for WrapAir<B> {
+    type BaseField = B;
+    type PublicInputs = WrapInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: WrapInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let mut degrees = vec![2; COUNTER_BITS + 1];
+        degrees.extend([1, 1]);
+        Self {
+            context: AirContext::new(trace_info, transition_degrees(&degrees), options),
+            start: pub_inputs.start,
+            increment: pub_inputs.increment,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let bits = &next[BITS..BITS + COUNTER_BITS];
+
+        // the next counter is range-checked by its bits
+        result.enforce_binary(0, bits);
+        result[DECOMPOSITION] = are_equal(next[VALUE], combine_bits(bits));
+
+        // the flag is the overflow of adding the increment to the counter
+        result[FLAG_IS_BINARY] = is_binary(next[FLAG]);
+        result[INCREMENT] = wrapping_add(
+            current[VALUE],
+            E::from(self.increment),
+            next[VALUE],
+            next[FLAG],
+            COUNTER_BITS,
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the first row holds the start and its bits, without an overflow, and the counter ends
+        // with the public result
+        let mut assertions = vec![
+            first_row_equals(VALUE, B::from(self.start)),
+            first_row_equals(FLAG, B::ZERO),
+        ];
+        for (i, bit) in to_bits(self.start as u64, COUNTER_BITS).enumerate() {
+            assertions.push(first_row_equals(BITS + i, bit));
+        }
+        assertions.push(last_row_equals(
+            VALUE,
+            self.trace_length(),
+            B::from(self.result),
+        ));
+        assertions
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(feature = "std")]
+use crate::{
+    config::WrapConfig,
+    describe::AirMetadata,
+    error::ExampleError,
+    example::{Example, ExampleDescriptor},
+    field::ExampleField,
+    validate,
+};
+#[cfg(feature = "std")]
+use log::info;
+#[cfg(feature = "std")]
+use std::{marker::PhantomData, time::Instant};
+#[cfg(feature = "std")]
+use winterfell::{ProofOptions, TraceTable};
+
+pub(crate) mod air;
+#[cfg(feature = "std")]
+use air::{WrapAir, WrapInputs, COUNTER_BITS};
+
+#[cfg(feature = "std")]
+pub(crate) mod prover;
+#[cfg(feature = "std")]
+use prover::WrapProver;
+
+#[cfg(test)]
+mod tests;
+
+// WRAP EXAMPLE
+// ================================================================================================
+
+/// Proves that an 8-bit counter which starts at a public value and is incremented by a public
+/// amount on every step ends with the result, wrapping around at 256.
+///
+/// The counter is range-checked by its bits, and an overflow flag marks the steps which wrap
+/// around, tied to the counter and the increment by the wrapping addition of [crate::gadgets];
+/// see [WrapAir] for the constraints. The same gadget fits any register of fixed width which
+/// is incremented, such as a program counter.
+#[cfg(feature = "std")]
+pub struct WrapExample<B: ExampleField> {
+    trace_length: usize,
+    start: u8,
+    increment: u8,
+    result: u8,
+    _field: PhantomData<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> WrapExample<B> {
+    pub fn new(config: &WrapConfig) -> Result<Self, ExampleError> {
+        Self::validate(config).map_err(ExampleError::Config)?;
+
+        // compute result
+        let now = Instant::now();
+        let result = counter(config.start, config.increment, config.trace_length);
+        info!("Computed result in {} ms", now.elapsed().as_millis());
+
+        Ok(Self {
+            trace_length: config.trace_length,
+            start: config.start,
+            increment: config.increment,
+            result,
+            _field: PhantomData,
+        })
+    }
+
+    /// Checks that the config describes a computation this example can prove; [new()](Self::new)
+    /// returns the same error otherwise.
+    pub fn validate(config: &WrapConfig) -> Result<(), String> {
+        validate::check_trace_length("wrap.trace_length", config.trace_length)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: ExampleField> Example for WrapExample<B> {
+    type Air = WrapAir<B>;
+    type Prover = WrapProver<B>;
+
+    fn build_trace(&self) -> TraceTable<B> {
+        WrapProver::build_trace(self.start, self.increment, self.trace_length)
+    }
+
+    fn descriptor() -> ExampleDescriptor {
+        let config = WrapConfig::default();
+        ExampleDescriptor::new(
+            "wrap",
+            "an 8-bit counter which wraps around, with a range check and an overflow flag",
+        )
+        .with_default("trace_length", config.trace_length)
+        .with_default("start", config.start)
+        .with_default("increment", config.increment)
+    }
+
+    fn metadata(&self) -> AirMetadata {
+        AirMetadata {
+            columns: vec![("VALUE", 1), ("FLAG", 1), ("BITS", COUNTER_BITS)],
+            constraints: vec![
+                ("binary bit", COUNTER_BITS),
+                ("binary flag", 1),
+                ("bit decomposition", 1),
+                ("wrapping increment", 1),
+            ],
+        }
+    }
+
+    fn prover(&self, options: ProofOptions) -> WrapProver<B> {
+        WrapProver::new(options, self.start, self.increment)
+    }
+
+    fn pub_inputs(&self) -> WrapInputs {
+        WrapInputs {
+            start: self.start,
+            increment: self.increment,
+            result: self.result,
+        }
+    }
+
+    fn wrong_pub_inputs(&self) -> WrapInputs {
+        WrapInputs {
+            result: self.result.wrapping_add(1),
+            ..self.pub_inputs()
+        }
+    }
+}
+
+// COUNTER FUNCTION
+// ================================================================================================
+
+/// Returns the counter on the last of `n` rows, which starts at `start` and is incremented by
+/// `increment` modulo 256 on every step.
+pub fn counter(start: u8, increment: u8, n: usize) -> u8 {
+    let num_steps = ((n - 1) % 256) as u8;
+    start.wrapping_add(increment.wrapping_mul(num_steps))
+}
@@ -0,0 +1,68 @@
+use super::{
+    air::{BITS, COUNTER_BITS, FLAG, TRACE_WIDTH, VALUE},
+    counter, WrapAir, WrapInputs,
+};
+use crate::{
+    field::ExampleField,
+    gadgets::{to_bits, wrapping_add_witness},
+};
+use std::{iter, marker::PhantomData};
+use winterfell::{ProofOptions, Prover, Trace, TraceTable};
+
+// PROVER
+// ================================================================================================
+
+pub struct WrapProver<B: ExampleField> {
+    options: ProofOptions,
+    start: u8,
+    increment: u8,
+    _field: PhantomData<B>,
+}
+
+impl<B: ExampleField> WrapProver<B> {
+    pub fn new(options: ProofOptions, start: u8, increment: u8) -> Self {
+        Self {
+            options,
+            start,
+            increment,
+            _field: PhantomData,
+        }
+    }
+
+    /// Builds a trace which holds the counter on every row, starting from `start` and adding
+    /// `increment` modulo 256 on every step, together with its bits and the overflow flag of the
+    /// step which produced it.
+    pub fn build_trace(start: u8, increment: u8, trace_length: usize) -> TraceTable<B> {
+        let mut columns = vec![vec![B::ZERO; trace_length]; TRACE_WIDTH];
+        // every row holds the counter and whether the step into it overflowed
+        let rows = iter::successors(Some((start as u64, false)), |&(value, _)| {
+            Some(wrapping_add_witness(value, increment as u64, COUNTER_BITS))
+        });
+        for (step, (value, flag)) in rows.take(trace_length).enumerate() {
+            columns[VALUE][step] = B::from(value);
+            columns[FLAG][step] = B::from(flag as u64);
+            for (i, bit) in to_bits(value, COUNTER_BITS).enumerate() {
+                columns[BITS + i][step] = bit;
+            }
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<B: ExampleField> Prover for WrapProver<B> {
+    type BaseField = B;
+    type Air = WrapAir<B>;
+    type Trace = TraceTable<B>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> WrapInputs {
+        WrapInputs {
+            start: self.start,
+            increment: self.increment,
+            result: counter(self.start, self.increment, trace.length()),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,113 @@
+use super::{
+    air::{BITS, COUNTER_BITS, FLAG, VALUE},
+    counter, WrapExample, WrapProver,
+};
+use crate::{
+    config::{RunConfig, WrapConfig},
+    example::Example,
+    run_example, tamper,
+};
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    FieldExtension, HashFunction, ProofOptions, Prover, Trace,
+};
+
+#[test]
+fn wrap_trace_flags_the_steps_which_overflow() {
+    // 250, 253, 0, 3, ... wraps on the step into the third row only
+    let trace = WrapProver::<BaseElement>::build_trace(250, 3, 8);
+    let values = [250u64, 253, 0, 3, 6, 9, 12, 15];
+    for (step, &value) in values.iter().enumerate() {
+        assert_eq!(BaseElement::from(value), trace.get(VALUE, step));
+        assert_eq!(BaseElement::from((step == 2) as u64), trace.get(FLAG, step));
+        assert_eq!(
+            BaseElement::from(value & 1),
+            trace.get(BITS, step),
+            "step {}",
+            step
+        );
+    }
+    assert_eq!(15, counter(250, 3, 8));
+    // every increment of 255 takes one off the counter
+    assert_eq!(1, counter(0, 255, 256));
+}
+
+#[test]
+fn wrap_early_overflow_is_rejected() {
+    // 250, 253, 0 becomes 250, -3, 0: raising the flag on the step into the second row and
+    // lowering it on the next keeps every increment satisfied and the result unchanged, so only
+    // the range check rules it out
+    let example = build_example();
+    let mut trace = example.build_trace();
+    assert_eq!(BaseElement::ONE, trace.get(FLAG, 2));
+    trace.set(VALUE, 1, trace.get(VALUE, 1) - BaseElement::from(256u64));
+    trace.set(FLAG, 1, BaseElement::ONE);
+    trace.set(FLAG, 2, BaseElement::ZERO);
+
+    let pub_inputs = example.pub_inputs();
+    let proof = WrapProver::new(build_options(), pub_inputs.start, pub_inputs.increment)
+        .prove(trace)
+        .unwrap();
+    assert!(
+        winterfell::verify::<<WrapExample<BaseElement> as Example>::Air>(proof, pub_inputs)
+            .is_err()
+    );
+}
+
+#[test]
+fn wrap_statements_differ_by_start() {
+    let config = RunConfig {
+        example: "wrap".to_string(),
+        wrap: WrapConfig {
+            trace_length: TRACE_LENGTH,
+            ..WrapConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    assert!(config.clone().set_seed(5).is_err());
+    let first = run_example(&config.statement(1)).unwrap();
+    let second = run_example(&config.statement(2)).unwrap();
+    assert_ne!(first.pub_inputs_bytes, second.pub_inputs_bytes);
+}
+
+#[test]
+fn wrap_corrupted_first_and_last_rows_are_rejected() {
+    let example = build_example();
+    for column in [VALUE, FLAG, BITS, BITS + COUNTER_BITS - 1] {
+        for step in [0, TRACE_LENGTH - 1] {
+            let verdict = tamper::corrupt_trace_cell(&example, build_options(), column, step);
+            assert!(
+                verdict.is_rejected(),
+                "column {} step {}: {}",
+                column,
+                step,
+                verdict
+            );
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const TRACE_LENGTH: usize = 64;
+
+fn build_example() -> WrapExample<BaseElement> {
+    WrapExample::new(&WrapConfig {
+        trace_length: TRACE_LENGTH,
+        ..WrapConfig::default()
+    })
+    .unwrap()
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
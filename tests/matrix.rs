@@ -0,0 +1,148 @@
+//! Proves and verifies every example at a small size under every supported combination of base
+//! field, hash function, and field extension.
+//!
+//! The matrix runs a few hundred proofs, so it is ignored by default; run it with:
+//!
+//! ```text
+//! cargo test --release --test matrix -- --ignored
+//! ```
+
+use stark_examples::{
+    config::{FieldExt, HashFn, ProofOptionsConfig, RunConfig},
+    field::FieldType,
+    run_example,
+    verify::verify_proof,
+    EXAMPLES,
+};
+use std::panic::{self, AssertUnwindSafe};
+
+// CONSTANTS
+// ================================================================================================
+
+const FIELDS: [FieldType; 3] = [FieldType::F128, FieldType::F64, FieldType::F62];
+const HASH_FNS: [HashFn; 3] = [HashFn::Blake3_192, HashFn::Blake3_256, HashFn::Sha3_256];
+const FIELD_EXTENSIONS: [FieldExt; 3] = [FieldExt::None, FieldExt::Quadratic, FieldExt::Cubic];
+
+/// Trace length of the examples whose trace length can be set directly.
+const TRACE_LENGTH: usize = 256;
+
+// TEST MATRIX
+// ================================================================================================
+
+#[test]
+#[ignore]
+fn all_examples_verify_in_all_fields_with_all_hashes_and_extensions() {
+    let mut failures = Vec::new();
+    let mut num_runs = 0;
+    for example in EXAMPLES {
+        for field in FIELDS {
+            for hash_fn in HASH_FNS {
+                for extension in FIELD_EXTENSIONS {
+                    // winterfell does not implement a cubic extension of the 128-bit field
+                    if field == FieldType::F128 && matches!(extension, FieldExt::Cubic) {
+                        continue;
+                    }
+                    num_runs += 1;
+                    let config = build_config(example, field, hash_fn, extension);
+                    if let Err(err) = prove_and_verify(&config) {
+                        failures.push(format!(
+                            "{} in {:?} with {:?} and {:?} extension: {}",
+                            example, field, hash_fn, extension, err
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {} combinations failed:\n{}",
+        failures.len(),
+        num_runs,
+        failures.join("\n")
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Proves the selected example, checks the run report, and verifies the serialized proof as a
+/// separate verifier would; panics of the prover are reported as errors.
+fn prove_and_verify(config: &RunConfig) -> Result<(), String> {
+    let report = panic::catch_unwind(AssertUnwindSafe(|| run_example(config))).map_err(
+        |err| match err.downcast_ref::<String>() {
+            Some(message) => format!("panicked: {}", message),
+            None => match err.downcast_ref::<&str>() {
+                Some(message) => format!("panicked: {}", message),
+                None => "panicked".to_string(),
+            },
+        },
+    )??;
+    if let Some(err) = report.verification_error {
+        return Err(format!("proof was rejected: {}", err));
+    }
+    verify_proof(
+        &config.example,
+        &report.proof_bytes,
+        &report.pub_inputs_bytes,
+    )
+    .map_err(|err| format!("serialized proof was rejected: {}", err))
+}
+
+/// Returns a config which runs the example at a small size with cheap proof options; the
+/// blowup factor of 8 fits the degree 7 S-boxes of the 64-bit field.
+fn build_config(
+    example: &str,
+    field: FieldType,
+    hash_fn: HashFn,
+    extension: FieldExt,
+) -> RunConfig {
+    let mut config = RunConfig {
+        example: example.to_string(),
+        field,
+        proof_options: ProofOptionsConfig {
+            num_queries: Some(16),
+            blowup_factor: Some(8),
+            grinding_factor: Some(0),
+            hash_fn: Some(hash_fn),
+            field_extension: Some(extension),
+            ..ProofOptionsConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    match example {
+        "vdf" => config.vdf.trace_length = TRACE_LENGTH,
+        "rescue" => config.rescue.chain_length = 16,
+        "poseidon" => config.poseidon.chain_length = 4,
+        "counter" => config.counter.num_blocks = 16,
+        "lamport" => config.lamport.num_signatures = 2,
+        "exp" => {}
+        "sum" => config.sum.length = TRACE_LENGTH,
+        "vm" => config.vm.trace_length = TRACE_LENGTH,
+        "fib" => config.fib.trace_length = TRACE_LENGTH,
+        "gcd" => {}
+        "quad" => config.quad.trace_length = TRACE_LENGTH,
+        "iszero" => {
+            config.iszero.trace_length = TRACE_LENGTH;
+            config.iszero.start = TRACE_LENGTH as u64 / 2;
+        }
+        "rom" => config.rom.trace_length = TRACE_LENGTH,
+        "sqrt" => config.sqrt.trace_length = TRACE_LENGTH,
+        "merkle" => config.merkle.num_steps = TRACE_LENGTH,
+        "bitwise" => config.bitwise.trace_length = TRACE_LENGTH,
+        "compare" => config.compare.trace_length = TRACE_LENGTH,
+        "fibmod" => config.fibmod.trace_length = TRACE_LENGTH,
+        "sort" => config.sort.trace_length = 16,
+        "fibchunk" => config.fibchunk.trace_length = TRACE_LENGTH,
+        "fixedexp" => config.fixedexp.num_exponents = TRACE_LENGTH / 64,
+        "modexp" => config.modexp.num_messages = TRACE_LENGTH / 32,
+        "bytecount" => config.bytecount.text = "abracadabra".repeat(TRACE_LENGTH / 16),
+        "runsum" => config.runsum.trace_length = TRACE_LENGTH,
+        "horner" => config.horner.trace_length = TRACE_LENGTH,
+        "wrap" => config.wrap.trace_length = TRACE_LENGTH,
+        "window" => config.window.trace_length = TRACE_LENGTH,
+        "fibdiv" => config.fibdiv.trace_length = TRACE_LENGTH,
+        name => panic!("no small configuration for example '{}'", name),
+    }
+    config
+}
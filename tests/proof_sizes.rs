@@ -0,0 +1,36 @@
+//! Proves every example at fixed small parameters and checks that its proof stays within the
+//! size band recorded for it in `stark_examples::proof_sizes`, so that a change which balloons
+//! proof sizes fails locally as well as in CI:
+//!
+//! ```text
+//! cargo test --test proof_sizes
+//! ```
+
+use stark_examples::{
+    proof_sizes::{check_all_proof_sizes, size_band, SIZE_BANDS},
+    EXAMPLES,
+};
+
+#[test]
+fn every_example_has_a_size_band() {
+    for example in EXAMPLES {
+        assert!(
+            size_band(example).is_some(),
+            "example '{}' has no proof size band",
+            example
+        );
+    }
+    assert_eq!(EXAMPLES.len(), SIZE_BANDS.len());
+}
+
+#[test]
+fn proof_sizes_stay_within_their_bands() {
+    let failures = check_all_proof_sizes();
+    assert!(
+        failures.is_empty(),
+        "{} of {} proofs are outside of their size bands:\n{}",
+        failures.len(),
+        EXAMPLES.len(),
+        failures.join("\n")
+    );
+}
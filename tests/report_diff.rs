@@ -0,0 +1,48 @@
+//! Runs `report diff` as the README shows it, on reports of the same example which are named
+//! after the stage of a change rather than as artifacts:
+//!
+//! ```text
+//! cargo test --test report_diff
+//! ```
+
+use stark_examples::{
+    config::{FibConfig, RunConfig},
+    run_example,
+};
+use std::{env, fs, process::Command};
+
+#[test]
+fn readme_report_diff_does_not_note_different_examples() {
+    let dir = env::temp_dir().join(format!("stark-examples-report-diff-{}", std::process::id()));
+    let results = dir.join("results");
+    fs::create_dir_all(&results).unwrap();
+    let config = RunConfig {
+        example: "fib".to_string(),
+        fib: FibConfig {
+            trace_length: 64,
+            ..FibConfig::default()
+        },
+        ..RunConfig::default()
+    };
+    let report = run_example(&config).unwrap();
+    let json = serde_json::to_vec(&report).unwrap();
+    fs::write(results.join("before-fib-report.json"), &json).unwrap();
+    fs::write(results.join("after-fib-report.json"), &json).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_stark-examples"))
+        .current_dir(&dir)
+        .args([
+            "report",
+            "diff",
+            "results/before-fib-report.json",
+            "results/after-fib-report.json",
+            "--threshold",
+            "10",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(!stdout.contains("different examples"), "{}", stdout);
+    fs::remove_dir_all(&dir).unwrap();
+}